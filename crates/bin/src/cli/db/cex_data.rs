@@ -66,7 +66,7 @@ impl CexDB {
 
         let block_timestamp = metadata.microseconds_block_timestamp();
 
-        let cex_trades = &metadata.cex_trades.as_ref().unwrap().0;
+        let cex_trades = &metadata.cex_trades.as_ref().unwrap().spot;
         let exchanges_to_use = &cex_config.exchanges_to_use;
 
         let pair_exists = exchanges_to_use.iter().any(|exchange| {
@@ -13,7 +13,9 @@ pub struct Clear {
         value_delimiter = ',',
         default_value = "CexPrice,DexPrice,CexTrades,BlockInfo,InitializedState,MevBlocks,\
                          TokenDecimals,AddressToProtocolInfo,PoolCreationBlocks,Builder,\
-                         AddressMeta,SearcherEOAs,SearcherContracts,SubGraphs,TxTraces"
+                         AddressMeta,SearcherEOAs,SearcherContracts,SubGraphs,TxTraces,\
+                         BlockAuctions,SearcherStats,UniswapV4PoolKeys,CexPerpTickers,\
+                         CexPerpTrades"
     )]
     pub tables:                  Vec<Tables>,
     /// Mark metadata as uninitialized in the initialized state table
@@ -66,7 +68,13 @@ impl Clear {
                 AddressMeta,
                 SearcherEOAs,
                 SearcherContracts,
-                TxTraces
+                TxTraces,
+                MempoolFirstSeen,
+                BlockAuctions,
+                SearcherStats,
+                UniswapV4PoolKeys,
+                CexPerpTickers,
+                CexPerpTrades
             )
         });
 
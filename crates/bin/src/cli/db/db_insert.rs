@@ -53,6 +53,12 @@ impl Insert {
             SearcherEOAs,
             SearcherContracts,
             InitializedState,
+            MempoolFirstSeen,
+            BlockAuctions,
+            SearcherStats,
+            UniswapV4PoolKeys,
+            CexPerpTickers,
+            CexPerpTrades,
             PoolCreationBlocks = &self.key,
             &self.value
         );
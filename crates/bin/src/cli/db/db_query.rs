@@ -77,7 +77,13 @@ impl DatabaseQuery {
                     AddressMeta,
                     SearcherEOAs,
                     SearcherContracts,
-                    TxTraces
+                    TxTraces,
+                    MempoolFirstSeen,
+                    BlockAuctions,
+                    SearcherStats,
+                    UniswapV4PoolKeys,
+                    CexPerpTickers,
+                    CexPerpTrades
                 );
             } else {
                 match_table!(
@@ -97,6 +103,12 @@ impl DatabaseQuery {
                     SearcherEOAs,
                     SearcherContracts,
                     TxTraces,
+                    MempoolFirstSeen,
+                    BlockAuctions,
+                    SearcherStats,
+                    UniswapV4PoolKeys,
+                    CexPerpTickers,
+                    CexPerpTrades,
                     PoolCreationBlocks = &self.key
                 );
             }
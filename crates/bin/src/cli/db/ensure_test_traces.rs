@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::{path::Path, sync::Arc};
 
 use brontes_core::decoding::Parser as DParser;
 use brontes_metrics::ParserMetricsListener;
@@ -37,8 +37,9 @@ impl TestTraceArgs {
         ctx.task_executor
             .spawn_critical("metrics", metrics_listener);
 
-        let libmdbx =
-            static_object(load_database(&ctx.task_executor, brontes_db_path, None, None).await?);
+        let libmdbx = static_object(
+            load_database(&ctx.task_executor, brontes_db_path, None, None, Arc::default()).await?,
+        );
 
         let tracer =
             get_tracing_provider(Path::new(&db_path), max_tasks, ctx.task_executor.clone());
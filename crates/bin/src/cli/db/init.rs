@@ -1,10 +1,26 @@
-use std::{path::Path, sync::Arc};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
-use brontes_database::{libmdbx::LibmdbxInit, Tables};
-use brontes_types::{db::cex::CexExchange, init_thread_pools};
+use brontes_database::{
+    libmdbx::LibmdbxInit,
+    parquet::{
+        relay_fixtures::load_relay_fixtures,
+        tardis::{TardisCexReader, TARDIS_CHUNK_BLOCKS},
+    },
+    Tables,
+};
+use brontes_types::{
+    db::{block_times::BlockTimes, cex::CexExchange},
+    init_thread_pools,
+    traits::TracingProvider,
+};
 use clap::Parser;
+use futures::{StreamExt, TryStreamExt};
 use indicatif::MultiProgress;
 use itertools::Itertools;
+use tracing::info;
 
 use crate::{
     cli::{get_env_vars, get_tracing_provider, load_clickhouse, load_database, static_object},
@@ -61,6 +77,19 @@ pub struct Init {
     /// false it will run the dex pricing locally using raw on-chain data
     #[arg(long, short, default_value = "false")]
     pub download_dex_pricing:      bool,
+    /// Directory of recorded relay bids and payloads to backfill the relay
+    /// metadata of the block range from instead of querying the relays
+    #[arg(long, requires_all = ["start_block", "end_block"])]
+    pub relay_fixtures:            Option<PathBuf>,
+    /// Directory of Tardis trades, quotes and derivative ticker exports to
+    /// build the CexPrice, CexTrades, CexPerpTrades and CexPerpTickers tables
+    /// of the block range from instead of clickhouse
+    #[arg(long, requires_all = ["start_block", "end_block", "cex_symbols"])]
+    pub cex_data:                  Option<PathBuf>,
+    /// Csv file mapping the exchange symbols of `--cex-data` to token pairs,
+    /// with the columns exchange, symbol_pair, base_token and quote_token
+    #[arg(long, requires = "cex_data")]
+    pub cex_symbols:               Option<PathBuf>,
 }
 
 impl Init {
@@ -70,11 +99,16 @@ impl Init {
         init_thread_pools(10);
         let task_executor = ctx.task_executor;
 
-        let libmdbx =
-            static_object(load_database(&task_executor, brontes_db_path, None, None).await?);
-        let clickhouse = static_object(load_clickhouse(Default::default(), None).await?);
+        let libmdbx = static_object(
+            load_database(&task_executor, brontes_db_path, None, None, Arc::default()).await?,
+        );
 
         let tracer = Arc::new(get_tracing_provider(Path::new(&db_path), 10, task_executor.clone()));
+        // the init task takes ownership of the args
+        let (relay_fixtures, start_block, end_block) =
+            (self.relay_fixtures.clone(), self.start_block, self.end_block);
+        let (cex_data, cex_symbols, cex_exchanges) =
+            (self.cex_data.clone(), self.cex_symbols.clone(), self.cex_exchanges.clone());
 
         if self.init_libmdbx {
             // currently inits all tables
@@ -84,6 +118,10 @@ impl Init {
                 None
             };
 
+            // only the table init reads from clickhouse, so the relay fixture and cex
+            // data imports run without it
+            let clickhouse = static_object(load_clickhouse(Default::default(), None).await?);
+            let tracer = tracer.clone();
             task_executor
                 .spawn_critical("init", async move {
                     let tables = Tables::ALL.to_vec();
@@ -128,6 +166,85 @@ impl Init {
                 .unwrap();
         }
 
+        if let Some(dir) = relay_fixtures {
+            let fixtures = load_relay_fixtures(&dir)?;
+            let (start, end) = (start_block.unwrap(), end_block.unwrap());
+            let backfilled = libmdbx
+                .backfill_relay_metadata(&fixtures, tracer.as_ref(), start, end)
+                .await?;
+            info!(target: "brontes", backfilled, start, end, "backfilled relay metadata");
+        }
+
+        if let (Some(dir), Some(symbols)) = (cex_data, cex_symbols) {
+            let (start, end) = (start_block.unwrap(), end_block.unwrap());
+            let block_times = block_times(tracer.as_ref(), start, end).await?;
+            let reader = TardisCexReader::new(&dir, &symbols, &cex_exchanges)?;
+
+            // the trades go first, their volume over the whole range ranks the
+            // exchanges the prices are taken from
+            let mut trades_loader = reader.trades_loader(&block_times)?;
+            for chunk in block_times.chunks(TARDIS_CHUNK_BLOCKS) {
+                let (start, end) = (chunk[0].block_number, chunk[chunk.len() - 1].block_number);
+                let (trades, perp_trades, perp_tickers) =
+                    trades_loader.load(chunk)?.cex_trades(chunk.to_vec());
+                info!(
+                    target: "brontes",
+                    trades = trades.len(),
+                    perp_trades = perp_trades.len(),
+                    perp_tickers = perp_tickers.len(),
+                    start,
+                    end,
+                    "imported cex trades"
+                );
+                libmdbx
+                    .import_cex_data(
+                        start,
+                        end,
+                        None,
+                        Some(trades),
+                        Some(perp_trades),
+                        Some(perp_tickers),
+                    )
+                    .await?;
+            }
+
+            let best_cex_per_pair = trades_loader.best_cex_per_pair();
+            let mut quotes_loader = reader.quotes_loader(&block_times)?;
+            for chunk in block_times.chunks(TARDIS_CHUNK_BLOCKS) {
+                let (start, end) = (chunk[0].block_number, chunk[chunk.len() - 1].block_number);
+                let prices = quotes_loader
+                    .load(chunk)?
+                    .cex_prices(chunk.to_vec(), best_cex_per_pair.clone());
+                info!(target: "brontes", quotes = prices.len(), start, end, "imported cex quotes");
+                libmdbx
+                    .import_cex_data(start, end, Some(prices), None, None, None)
+                    .await?;
+            }
+        }
+
         Ok(())
     }
 }
+
+/// The timestamps of the blocks in microseconds, as the cex data uses
+async fn block_times<T: TracingProvider>(
+    tracer: &T,
+    start: u64,
+    end: u64,
+) -> eyre::Result<Vec<BlockTimes>> {
+    futures::stream::iter(start..=end)
+        .map(|block_number| async move {
+            let header = tracer
+                .header_by_number(block_number)
+                .await?
+                .ok_or_else(|| eyre::eyre!("Missing header of block {block_number}"))?;
+
+            Ok::<_, eyre::Report>(BlockTimes {
+                block_number,
+                timestamp: header.timestamp * 1_000_000,
+            })
+        })
+        .buffered(64)
+        .try_collect()
+        .await
+}
@@ -1,6 +1,7 @@
 use clap::{Parser, Subcommand};
 mod r2_uploader;
 mod snapshot;
+mod snapshot_create;
 use crate::runner::CliContext;
 mod cex_data;
 #[cfg(feature = "local-clickhouse")]
@@ -14,6 +15,7 @@ mod discovery;
 mod ensure_test_traces;
 mod export;
 mod init;
+mod searcher_stats;
 mod table_stats;
 #[cfg(feature = "local-clickhouse")]
 mod tip_tracer;
@@ -51,6 +53,9 @@ pub enum DatabaseCommands {
     /// Libmbdx Table Stats
     #[command(name = "table-stats")]
     TableStats(table_stats::Stats),
+    /// Prints the most profitable searchers over a block range
+    #[command(name = "searcher-stats")]
+    SearcherStats(searcher_stats::SearcherStats),
     /// Export libmbdx data to parquet
     #[command(name = "export")]
     Export(export::Export),
@@ -59,6 +64,10 @@ pub enum DatabaseCommands {
     /// merges it into the current database.
     #[command(name = "download-snapshot")]
     DownloadSnapshot(snapshot::Snapshot),
+    /// Partitions the db into snapshot tarballs in a local directory, which
+    /// can be served as a snapshot mirror for `download-snapshot`
+    #[command(name = "snapshot-create")]
+    SnapshotCreate(snapshot_create::SnapshotCreate),
     #[cfg(feature = "local-clickhouse")]
     /// Downloads the db data from clickhouse
     #[command(name = "download-clickhouse")]
@@ -93,7 +102,9 @@ impl Database {
             DatabaseCommands::UploadSnapshot(cmd) => cmd.execute(brontes_db_path, ctx).await,
             DatabaseCommands::Export(cmd) => cmd.execute(brontes_db_path, ctx).await,
             DatabaseCommands::TableStats(cmd) => cmd.execute(brontes_db_path),
+            DatabaseCommands::SearcherStats(cmd) => cmd.execute(brontes_db_path, ctx).await,
             DatabaseCommands::DownloadSnapshot(cmd) => cmd.execute(brontes_db_path, ctx).await,
+            DatabaseCommands::SnapshotCreate(cmd) => cmd.execute(brontes_db_path, ctx).await,
             DatabaseCommands::CexData(cmd) => cmd.execute(brontes_db_path, ctx).await,
             #[cfg(feature = "local-clickhouse")]
             DatabaseCommands::DownloadClickhouse(cmd) => cmd.execute(brontes_db_path, ctx).await,
@@ -5,6 +5,7 @@ use brontes_database::libmdbx::{
     FULL_RANGE_NAME,
 };
 use clap::Parser;
+use futures::TryFutureExt;
 
 use crate::runner::CliContext;
 
@@ -48,8 +49,10 @@ impl R2Uploader {
 
         if self.full_db {
             tracing::info!("uploading full database");
+            let table_rows = db.snapshot_table_rows()?;
             if let Err(e) = r2wrapper
-                .tar_ball_dir(&PathBuf::from(database_path), Some(FULL_RANGE_NAME))
+                .tar_ball_dir(&PathBuf::from(database_path), Some(FULL_RANGE_NAME), table_rows)
+                .and_then(|file| r2wrapper.update_manifest(vec![file]))
                 .await
             {
                 tracing::error!(error=%e);
@@ -60,7 +63,7 @@ impl R2Uploader {
 
         tracing::info!("Partitioning new data into respective files");
 
-        if let Err(e) = LibmdbxPartitioner::new(
+        let partition_rows = match LibmdbxPartitioner::new(
             db,
             self.partition_db_folder.clone(),
             start_block,
@@ -68,16 +71,19 @@ impl R2Uploader {
         )
         .execute(self.rayon_tasks)
         {
-            tracing::error!(error=%e);
-            return Ok(())
-        }
+            Ok(rows) => rows,
+            Err(e) => {
+                tracing::error!(error=%e);
+                return Ok(())
+            }
+        };
 
         tracing::info!(
             "Partitioning complete, uploading files, this will take a while. ~10 min per partition"
         );
 
         if let Err(e) = r2wrapper
-            .tar_ball_and_upload_files(self.partition_db_folder, start_block)
+            .tar_ball_and_upload_files(self.partition_db_folder, start_block, partition_rows)
             .await
         {
             tracing::error!(error=%e);
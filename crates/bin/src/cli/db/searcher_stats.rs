@@ -0,0 +1,76 @@
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_types::{
+    db::{
+        searcher::SearcherEoaContract,
+        searcher_stats::{searcher_leaderboard, searcher_stats_bucket, SEARCHER_STATS_BUCKET_SIZE},
+    },
+    mev::MevType,
+};
+use clap::Parser;
+use comfy_table::{Cell, Row, Table as ComfyTable};
+
+use crate::{cli::load_libmdbx, runner::CliContext};
+
+#[derive(Debug, Parser)]
+/// The arguments for the `brontes db searcher-stats` command
+pub struct SearcherStats {
+    /// Start of the block range, rounded down to the start of its stats
+    /// bucket
+    #[arg(long, short)]
+    pub start_block: u64,
+    /// End of the block range
+    #[arg(long, short)]
+    pub end_block:   u64,
+    /// Only count bundles of this mev type
+    #[arg(long, short)]
+    pub mev_type:    Option<MevType>,
+    /// Rank mev contracts instead of searcher eoas
+    #[arg(long, default_value = "false")]
+    pub contracts:   bool,
+    /// Amount of searchers to show
+    #[arg(long, default_value = "20")]
+    pub top:         usize,
+}
+
+impl SearcherStats {
+    pub async fn execute(self, brontes_db_path: String, ctx: CliContext) -> eyre::Result<()> {
+        let libmdbx = load_libmdbx(&ctx.task_executor, brontes_db_path)?;
+        let stats = libmdbx.fetch_searcher_stats(self.start_block, self.end_block)?;
+
+        let eoa_or_contract =
+            if self.contracts { SearcherEoaContract::Contract } else { SearcherEoaContract::EOA };
+        let leaderboard = searcher_leaderboard(&stats, eoa_or_contract, self.mev_type);
+
+        let mut table = ComfyTable::new();
+        table.load_preset(comfy_table::presets::ASCII_MARKDOWN);
+        table.set_header([
+            "Rank",
+            if self.contracts { "Contract" } else { "EOA" },
+            "Bundles",
+            "Profit (USD)",
+            "Bribes (USD)",
+            "Top Mev Type",
+        ]);
+
+        for (rank, entry) in leaderboard.into_iter().take(self.top).enumerate() {
+            let mut row = Row::new();
+            row.add_cell(Cell::new(rank + 1))
+                .add_cell(Cell::new(entry.address))
+                .add_cell(Cell::new(entry.bundle_count))
+                .add_cell(Cell::new(format!("{:.2}", entry.profit_usd)))
+                .add_cell(Cell::new(format!("{:.2}", entry.bribe_usd)))
+                .add_cell(Cell::new(entry.top_mev_type));
+            table.add_row(row);
+        }
+
+        println!(
+            "searchers from block {} to {} (buckets of {} blocks)",
+            searcher_stats_bucket(self.start_block),
+            self.end_block,
+            SEARCHER_STATS_BUCKET_SIZE
+        );
+        println!("{table}");
+
+        Ok(())
+    }
+}
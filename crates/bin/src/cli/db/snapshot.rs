@@ -1,7 +1,13 @@
-use std::{env::temp_dir, path::PathBuf, str::FromStr};
+use std::{
+    env::temp_dir,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
+};
 
 use brontes_database::libmdbx::{
-    merge_libmdbx_dbs, rclone_wrapper::BlockRangeList, LibmdbxReadWriter, FULL_RANGE_NAME,
+    merge_libmdbx_dbs, rclone_wrapper::BlockRangeList, LibmdbxReadWriter, SnapshotFile,
+    SnapshotManifest, FULL_RANGE_NAME, RANGES_AVAILABLE, SNAPSHOT_MANIFEST,
 };
 use brontes_types::{
     buf_writer::DownloadBufWriterWithProgress, unordered_buffer_map::BrontesStreamExt,
@@ -13,7 +19,10 @@ use fs_extra::dir::{move_dir, CopyOptions};
 use futures::{stream::StreamExt, Stream};
 use indicatif::MultiProgress;
 use itertools::Itertools;
-use reqwest::Url;
+use reqwest::{
+    header::{ETAG, IF_RANGE, LAST_MODIFIED, RANGE},
+    StatusCode, Url,
+};
 use tar::Archive;
 
 use crate::runner::CliContext;
@@ -21,12 +30,12 @@ use crate::runner::CliContext;
 const NAME: &str = "brontes-db-partition";
 const FIXED_DB: &str = "full-range-tables";
 const SIZE_PATH: &str = "byte-count.txt";
-const RANGES_AVAILABLE: &str = "brontes-available-ranges.json";
 const BYTES_TO_MB: u64 = 1_000_000;
 
 #[derive(Debug, Parser)]
 pub struct Snapshot {
-    /// Snapshot endpoint
+    /// Snapshot endpoint, either a http server or a `file://` directory
+    /// written by `snapshot-create`
     #[arg(long, default_value = "https://data.brontes.xyz/")]
     pub endpoint:         Url,
     /// Optional start block
@@ -54,14 +63,40 @@ impl Snapshot {
             })
             .unwrap_or_default();
 
+        let manifest = self
+            .get_manifest(&client)
+            .await
+            .inspect_err(|e| {
+                tracing::warn!(
+                    err=%e,
+                    "failed to fetch the snapshot manifest, downloads will not be verified"
+                )
+            })
+            .ok();
+
         let ranges_to_download = self.ranges_to_download(ranges_avail)?;
         fs_extra::dir::create_all(&brontes_db_endpoint, false)?;
 
-        let curl_queries = self
+        let mut curl_queries = self
             .meets_space_requirement(&client, ranges_to_download, &brontes_db_endpoint)
             .await
             .map_err(|e| eyre::eyre!("meeting space requirement failed, error={}", e))?;
 
+        if let Some(manifest) = &manifest {
+            curl_queries.iter_mut().for_each(|query| {
+                query.manifest_entry = manifest.file(&query.file_name).cloned();
+                // the byte count files hold the size on disk, which can differ
+                // from the length of the file that resumes are checked against
+                if let Some(entry) = &query.manifest_entry {
+                    query.size_bytes = entry.size_bytes;
+                }
+            });
+        }
+        let manifest_entries = curl_queries
+            .iter()
+            .filter_map(|query| query.manifest_entry.clone())
+            .collect_vec();
+
         // download db tarball
         let multi_bar = MultiProgress::new();
 
@@ -71,43 +106,56 @@ impl Snapshot {
         let mut cloned_download_dir = download_dir.clone();
         fs_extra::dir::create_all(&download_dir, false)?;
 
-        ctx.task_executor
-            .spawn_critical("download_streams", async move {
-                futures::stream::iter(curl_queries)
-                    .map(|DbRequestWithBytes { url, size_bytes, file_name }| {
-                        let client = client.clone();
-                        let mb = multi_bar.clone();
-                        tracing::info!(?url, ?size_bytes, ?file_name);
-                        let mut download_dir = download_dir.clone();
-                        async move {
-                            download_dir.push(file_name);
-
-                            tracing::info!("creating file");
-                            let file = tokio::fs::File::create(&download_dir).await?;
-
-                            let stream = client.get(url).send().await?.bytes_stream();
-                            DownloadBufWriterWithProgress::new(
-                                Some(size_bytes),
-                                stream,
-                                file,
-                                40 * 1024 * 1024,
-                                &mb,
-                            )
-                            .await?;
-                            tracing::info!("download of file complete, decompressing");
-                            Self::handle_downloaded_file(&download_dir)?;
-
-                            eyre::Ok(())
+        futures::stream::iter(curl_queries)
+            .map(|DbRequestWithBytes { url, size_bytes, file_name, manifest_entry }| {
+                let client = client.clone();
+                let mb = multi_bar.clone();
+                tracing::info!(?url, ?size_bytes, ?file_name);
+                let download_dir = download_dir.clone();
+                async move {
+                    // tarballs of a file:// endpoint are unpacked in place
+                    let (tarball, downloaded) = match Url::parse(&url)?.to_file_path() {
+                        Ok(tarball) => (tarball, false),
+                        Err(_) => {
+                            let tarball = download_dir.join(&file_name);
+                            Self::download_file(&client, url, &tarball, size_bytes, &mb).await?;
+                            (tarball, true)
                         }
-                    })
-                    .unordered_buffer_map(10, |f| tokio::spawn(f))
-                    .map(|s| s.map_err(eyre::Error::from))
-                    .collect_vec_transpose_double()
-                    .await
-                    .unwrap()
-                    .unwrap();
+                    };
+
+                    if let Some(entry) = manifest_entry {
+                        tracing::info!("download of file complete, verifying");
+                        if let Err(e) = entry.verify(&tarball) {
+                            if !downloaded {
+                                return Err(e.wrap_err("corrupted tarball on the mirror"))
+                            }
+
+                            // the bad bytes can't be resumed from
+                            fs_extra::file::remove(&tarball)?;
+                            return Err(e.wrap_err(
+                                "removed the corrupted download, rerun to fetch it again",
+                            ))
+                        }
+                    } else {
+                        tracing::warn!(
+                            %file_name,
+                            "file is not in the snapshot manifest, skipping verification"
+                        );
+                    }
+
+                    tracing::info!("decompressing");
+                    Self::handle_downloaded_file(&tarball, &download_dir)?;
+                    if downloaded {
+                        fs_extra::file::remove(&tarball)?;
+                    }
+
+                    eyre::Ok(())
+                }
             })
-            .await?;
+            .unordered_buffer_map(10, |f| tokio::spawn(f))
+            .map(|s| s.map_err(eyre::Error::from))
+            .collect_vec_transpose_double()
+            .await??;
 
         if self.should_merge() {
             tracing::info!(
@@ -115,17 +163,25 @@ impl Snapshot {
                 brontes_db_endpoint
             );
 
-            let final_db =
-                LibmdbxReadWriter::init_db(brontes_db_endpoint, None, &ctx.task_executor, false)?;
+            let final_db = Arc::new(LibmdbxReadWriter::init_db(
+                brontes_db_endpoint,
+                None,
+                &ctx.task_executor,
+                false,
+            )?);
 
+            let merge_db = final_db.clone();
             let db = cloned_download_dir.clone();
             let ex = ctx.task_executor.clone();
             ctx.task_executor
                 .spawn_blocking(async move {
-                    merge_libmdbx_dbs(final_db, &db, ex, self.rayon_tasks_db_merge).unwrap();
+                    merge_libmdbx_dbs(&merge_db, &db, ex, self.rayon_tasks_db_merge).unwrap();
                 })
                 .await?;
 
+            tracing::info!("verifying the merged tables against the snapshot manifest");
+            final_db.verify_snapshot_rows(&manifest_entries)?;
+
             tracing::info!("cleaning up tmp libmdbx partitions");
             fs_extra::dir::remove(cloned_download_dir)?;
         } else {
@@ -138,6 +194,10 @@ impl Snapshot {
             fs_extra::dir::create_all(&home_dir, true).expect("failed to create home dir folder");
             cloned_download_dir.push(FULL_RANGE_NAME);
 
+            tracing::info!("verifying the downloaded tables against the snapshot manifest");
+            LibmdbxReadWriter::init_db(&cloned_download_dir, None, &ctx.task_executor, false)?
+                .verify_snapshot_rows(&manifest_entries)?;
+
             let opt = CopyOptions::new().overwrite(true);
             move_dir(cloned_download_dir, &home_dir, &opt)?;
 
@@ -222,12 +282,110 @@ impl Snapshot {
         &self,
         client: &reqwest::Client,
     ) -> eyre::Result<Vec<BlockRangeList>> {
-        Ok(client
-            .get(format!("{}{}", self.endpoint, RANGES_AVAILABLE))
-            .send()
-            .await?
-            .json()
-            .await?)
+        Ok(serde_json::from_str(&self.fetch_text(client, RANGES_AVAILABLE).await?)?)
+    }
+
+    async fn get_manifest(&self, client: &reqwest::Client) -> eyre::Result<SnapshotManifest> {
+        Ok(serde_json::from_str(&self.fetch_text(client, SNAPSHOT_MANIFEST).await?)?)
+    }
+
+    /// reads a file from the endpoint, which is either a http server or a
+    /// `file://` directory such as one written by `snapshot-create`
+    async fn fetch_text(&self, client: &reqwest::Client, file_name: &str) -> eyre::Result<String> {
+        let url = format!("{}{}", self.endpoint, file_name);
+        match Url::parse(&url)?.to_file_path() {
+            Ok(path) => Ok(tokio::fs::read_to_string(path).await?),
+            Err(_) => Ok(client
+                .get(url)
+                .send()
+                .await?
+                .error_for_status()?
+                .text()
+                .await?),
+        }
+    }
+
+    /// downloads the file, resuming from the bytes already on disk if a
+    /// previous download was interrupted. Resumes are sent with the `If-Range`
+    /// of the first response, so a republished snapshot restarts from scratch
+    /// instead of being appended to the stale bytes
+    async fn download_file(
+        client: &reqwest::Client,
+        url: String,
+        path: &Path,
+        size_bytes: u64,
+        multi_bar: &MultiProgress,
+    ) -> eyre::Result<()> {
+        let mut downloaded = tokio::fs::metadata(path)
+            .await
+            .map(|metadata| metadata.len())
+            .unwrap_or_default();
+
+        if downloaded == size_bytes {
+            tracing::info!("file already downloaded");
+            return Ok(())
+        } else if downloaded > size_bytes {
+            tracing::info!("partial file is larger than the snapshot, restarting download");
+            downloaded = 0;
+        }
+
+        let validator_path = validator_path(path);
+        let mut request = client.get(url);
+        if downloaded != 0 {
+            match tokio::fs::read_to_string(&validator_path).await {
+                Ok(validator) => {
+                    tracing::info!(%downloaded, "resuming download");
+                    request = request
+                        .header(RANGE, format!("bytes={downloaded}-"))
+                        .header(IF_RANGE, validator);
+                }
+                Err(_) => {
+                    tracing::info!(
+                        "partial file can't be matched to the snapshot, restarting download"
+                    )
+                }
+            }
+        }
+        let response = request.send().await?.error_for_status()?;
+
+        // servers without range support, or whose file changed since the partial
+        // download, send the whole file
+        let resume_from =
+            if response.status() == StatusCode::PARTIAL_CONTENT { downloaded } else { 0 };
+        let file = if resume_from == 0 {
+            let validator = response
+                .headers()
+                .get(ETAG)
+                .or_else(|| response.headers().get(LAST_MODIFIED))
+                .and_then(|value| value.to_str().ok());
+            match validator {
+                Some(validator) => tokio::fs::write(&validator_path, validator).await?,
+                None => {
+                    let _ = tokio::fs::remove_file(&validator_path).await;
+                }
+            }
+
+            tracing::info!("creating file");
+            tokio::fs::File::create(path).await?
+        } else {
+            tokio::fs::OpenOptions::new()
+                .append(true)
+                .open(path)
+                .await?
+        };
+
+        DownloadBufWriterWithProgress::new(
+            Some(size_bytes),
+            response.bytes_stream(),
+            file,
+            40 * 1024 * 1024,
+            multi_bar,
+        )
+        .resume_from(resume_from)
+        .await?;
+
+        let _ = tokio::fs::remove_file(&validator_path).await;
+        Ok(())
     }
 
     /// returns a error if there is not enough space remaining. If the overwrite
@@ -243,49 +401,52 @@ impl Snapshot {
         let mut res = vec![];
         match ranges {
             RangeOrFull::Full => {
-                let url = format!("{}{}-{}", self.endpoint, FULL_RANGE_NAME, SIZE_PATH);
-                let size = client.get(url).send().await?.text().await?;
+                let size = self
+                    .fetch_text(client, &format!("{}-{}", FULL_RANGE_NAME, SIZE_PATH))
+                    .await?;
                 let size = u64::from_str(&size)?;
                 res.push(DbRequestWithBytes {
-                    url:        format!("{}{}.tar.gz", self.endpoint, FULL_RANGE_NAME),
-                    file_name:  format!("{}.tar.gz", FULL_RANGE_NAME),
-                    size_bytes: size,
+                    url:            format!("{}{}.tar.gz", self.endpoint, FULL_RANGE_NAME),
+                    file_name:      format!("{}.tar.gz", FULL_RANGE_NAME),
+                    size_bytes:     size,
+                    manifest_entry: None,
                 });
 
                 new_db_size += size;
             }
             RangeOrFull::Range(ranges) => {
                 for range in ranges {
-                    let url = format!(
-                        "{}{}-{}-{}-{}",
-                        self.endpoint, NAME, range.start_block, range.end_block, SIZE_PATH
-                    );
-                    let size = client.get(url).send().await?.text().await?;
+                    let size_file =
+                        format!("{}-{}-{}-{}", NAME, range.start_block, range.end_block, SIZE_PATH);
+                    let size = self.fetch_text(client, &size_file).await?;
                     let size = u64::from_str(&size)?;
                     res.push(DbRequestWithBytes {
-                        url:        format!(
+                        url:            format!(
                             "{}{}-{}-{}.tar.gz",
                             self.endpoint, NAME, range.start_block, range.end_block
                         ),
-                        file_name:  format!(
+                        file_name:      format!(
                             "{}-{}-{}.tar.gz",
                             NAME, range.start_block, range.end_block
                         ),
-                        size_bytes: size,
+                        size_bytes:     size,
+                        manifest_entry: None,
                     });
 
                     new_db_size += size;
                 }
 
                 // query 1 off table
-                let url = format!("{}{}-{}-{}", self.endpoint, NAME, FIXED_DB, SIZE_PATH);
-                let size = client.get(url).send().await?.text().await?;
+                let size = self
+                    .fetch_text(client, &format!("{}-{}-{}", NAME, FIXED_DB, SIZE_PATH))
+                    .await?;
                 let size = u64::from_str(&size)?;
 
                 res.push(DbRequestWithBytes {
-                    url:        format!("{}{}-{}.tar.gz", self.endpoint, NAME, FIXED_DB),
-                    file_name:  format!("{}-{}.tar.gz", NAME, FIXED_DB),
-                    size_bytes: size,
+                    url:            format!("{}{}-{}.tar.gz", self.endpoint, NAME, FIXED_DB),
+                    file_name:      format!("{}-{}.tar.gz", NAME, FIXED_DB),
+                    size_bytes:     size,
+                    manifest_entry: None,
                 });
                 new_db_size += size;
             }
@@ -305,15 +466,12 @@ impl Snapshot {
         }
     }
 
-    fn handle_downloaded_file(tarball_location: &PathBuf) -> eyre::Result<()> {
+    fn handle_downloaded_file(tarball_location: &Path, unpack: &Path) -> eyre::Result<()> {
         let tar_gz = std::fs::File::open(tarball_location)?;
         let tar = GzDecoder::new(tar_gz);
         let mut archive = Archive::new(tar);
-        let mut unpack = tarball_location.clone();
-        unpack.pop();
-        archive.unpack(&unpack)?;
+        archive.unpack(unpack)?;
 
-        fs_extra::file::remove(tarball_location)?;
         tracing::info!("decompressing complete");
 
         Ok(())
@@ -324,15 +482,24 @@ impl Snapshot {
     }
 }
 
+/// the file holding the `ETag` or `Last-Modified` of a partial download
+fn validator_path(path: &Path) -> PathBuf {
+    let mut validator_path = path.as_os_str().to_owned();
+    validator_path.push(".validator");
+    validator_path.into()
+}
+
 pub enum RangeOrFull {
     Full,
     Range(Vec<BlockRangeList>),
 }
 
 pub struct DbRequestWithBytes {
-    pub url:        String,
-    pub file_name:  String,
-    pub size_bytes: u64,
+    pub url:            String,
+    pub file_name:      String,
+    pub size_bytes:     u64,
+    /// `None` when the endpoint has no manifest or the file isn't in it
+    pub manifest_entry: Option<SnapshotFile>,
 }
 
 impl<S> AsyncFlatten for S where S: Stream + Sized {}
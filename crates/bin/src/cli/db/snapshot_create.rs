@@ -0,0 +1,81 @@
+use std::path::PathBuf;
+
+use brontes_database::libmdbx::{
+    LibmdbxInit, LibmdbxPartitioner, LibmdbxReadWriter, LocalSnapshotDir, FULL_RANGE_NAME,
+};
+use clap::Parser;
+
+use crate::runner::CliContext;
+
+#[derive(Debug, Parser)]
+pub struct SnapshotCreate {
+    /// Directory the snapshot tarballs, available ranges and manifest are
+    /// written to. Serve it over http or pass it to `download-snapshot` as a
+    /// `file://` endpoint
+    #[clap(short, long)]
+    output_dir:          PathBuf,
+    /// Start block, defaults to the end of the latest partition in the output
+    /// directory
+    #[clap(short, long)]
+    start_block:         Option<u64>,
+    /// End block, defaults to the end of the db. Only whole partitions are
+    /// created
+    #[clap(short, long)]
+    end_block:           Option<u64>,
+    /// Path to db partition folder
+    #[clap(short, long, default_value = "/tmp/brontes-snapshot-partitions/")]
+    partition_db_folder: PathBuf,
+    /// should also write the full db
+    #[clap(short, long, default_value_t = false)]
+    full_db:             bool,
+    /// the amount of dbs to partition and tarball at a time
+    #[clap(short, long, default_value_t = 10)]
+    rayon_tasks:         usize,
+}
+
+impl SnapshotCreate {
+    pub async fn execute(self, database_path: String, ctx: CliContext) -> eyre::Result<()> {
+        let local = LocalSnapshotDir::new(self.output_dir.clone())?;
+
+        let db = LibmdbxReadWriter::init_db(&database_path, None, &ctx.task_executor, false)?;
+
+        let start_block = if let Some(b) = self.start_block {
+            b
+        } else {
+            local.get_most_recent_partition_block().unwrap_or_else(|e| {
+                tracing::warn!(err=%e,"using databases first block");
+                db.get_db_range().expect("empty libmdbx").0
+            })
+        };
+
+        if self.full_db {
+            tracing::info!("writing full database");
+            let table_rows = db.snapshot_table_rows()?;
+            let file = local.tar_ball_dir(
+                &PathBuf::from(&database_path),
+                Some(FULL_RANGE_NAME),
+                table_rows,
+            )?;
+            local.update_manifest(vec![file])?;
+        }
+
+        tracing::info!(%start_block, end_block=?self.end_block, "partitioning db");
+        let mut partitioner = LibmdbxPartitioner::new(
+            db,
+            self.partition_db_folder.clone(),
+            start_block,
+            ctx.task_executor.clone(),
+        );
+        if let Some(end_block) = self.end_block {
+            partitioner = partitioner.with_end_block(end_block);
+        }
+        let partition_rows = partitioner.execute(self.rayon_tasks)?;
+
+        tracing::info!("partitioning complete, writing tarballs");
+        local.tar_ball_partitions(&self.partition_db_folder, partition_rows, self.rayon_tasks)?;
+
+        tracing::info!(output_dir=?self.output_dir, "snapshot created");
+
+        Ok(())
+    }
+}
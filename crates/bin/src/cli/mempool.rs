@@ -0,0 +1,155 @@
+use std::{
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use alloy_primitives::TxHash;
+use brontes_database::libmdbx::LibmdbxReadWriter;
+use brontes_types::db::traits::DBWriter;
+use clap::Parser;
+use eyre::WrapErr;
+use futures::{SinkExt, StreamExt};
+use serde::Deserialize;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, info};
+
+use super::{load_libmdbx, static_object};
+use crate::runner::CliContext;
+
+const SUBSCRIBE_PENDING_TXES: &str =
+    r#"{"jsonrpc":"2.0","id":1,"method":"eth_subscribe","params":["newPendingTransactions"]}"#;
+
+#[derive(Debug, Parser)]
+pub struct MempoolArgs {
+    /// Websocket rpc of the node whose txpool is subscribed to
+    #[arg(long, conflicts_with = "pending_tx_file", required_unless_present = "pending_tx_file")]
+    pub ws_url:          Option<String>,
+    /// Recorded pending txes to load instead of listening to a node, one json
+    /// object per line with a `tx_hash` and a `timestamp` in unix microseconds
+    #[arg(long)]
+    pub pending_tx_file: Option<PathBuf>,
+    /// Amount of first seen times buffered before they are written
+    #[arg(long, default_value = "500")]
+    pub batch_size:      usize,
+}
+
+/// A pending tx as recorded in a pending tx file.
+#[derive(Debug, Deserialize)]
+struct PendingTx {
+    tx_hash:   TxHash,
+    timestamp: u64,
+}
+
+/// The notification a node sends for every new pending tx.
+#[derive(Debug, Deserialize)]
+struct SubscriptionNotification {
+    params: SubscriptionParams,
+}
+
+#[derive(Debug, Deserialize)]
+struct SubscriptionParams {
+    result: TxHash,
+}
+
+impl MempoolArgs {
+    pub async fn execute(self, brontes_db_path: String, ctx: CliContext) -> eyre::Result<()> {
+        let libmdbx = static_object(load_libmdbx(&ctx.task_executor, brontes_db_path)?);
+        let mut batch = FirstSeenBatch::new(libmdbx, self.batch_size);
+
+        if let Some(file) = self.pending_tx_file {
+            return load_pending_tx_file(file, batch).await
+        }
+
+        let ws_url = self.ws_url.expect("clap requires a ws url without a file");
+        let (mut ws, _) = tokio_tungstenite::connect_async(&ws_url)
+            .await
+            .wrap_err_with(|| format!("Could not connect to {ws_url}"))?;
+        ws.send(Message::Text(SUBSCRIBE_PENDING_TXES.to_string()))
+            .await?;
+        info!(target: "brontes", %ws_url, "recording first seen times of pending txes");
+
+        let mut flush = tokio::time::interval(Duration::from_secs(1));
+        loop {
+            tokio::select! {
+                msg = ws.next() => {
+                    let Some(msg) = msg else { break };
+                    let Message::Text(msg) = msg? else { continue };
+                    // the first message is the subscription id, all others are
+                    // pending txes
+                    match serde_json::from_str::<SubscriptionNotification>(&msg) {
+                        Ok(notification) => {
+                            batch.push(notification.params.result, now_micros()).await?
+                        }
+                        Err(_) => debug!(target: "brontes::mempool", %msg, "non tx message"),
+                    }
+                }
+                _ = flush.tick() => batch.flush().await?,
+            }
+        }
+
+        batch.flush().await?;
+        info!(target: "brontes", "mempool subscription closed");
+
+        Ok(())
+    }
+}
+
+async fn load_pending_tx_file(file: PathBuf, mut batch: FirstSeenBatch) -> eyre::Result<()> {
+    let reader = tokio::fs::File::open(&file)
+        .await
+        .wrap_err_with(|| format!("Could not open {}", file.display()))?;
+    let mut lines = BufReader::new(reader).lines();
+
+    let mut loaded = 0usize;
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue
+        }
+        let tx: PendingTx = serde_json::from_str(&line)
+            .wrap_err_with(|| format!("Invalid pending tx in {}: {line}", file.display()))?;
+        batch.push(tx.tx_hash, tx.timestamp).await?;
+        loaded += 1;
+    }
+    batch.flush().await?;
+
+    info!(target: "brontes", loaded, file = %file.display(), "loaded pending txes");
+    Ok(())
+}
+
+struct FirstSeenBatch {
+    libmdbx:    &'static LibmdbxReadWriter,
+    batch_size: usize,
+    pending:    Vec<(TxHash, u64)>,
+}
+
+impl FirstSeenBatch {
+    fn new(libmdbx: &'static LibmdbxReadWriter, batch_size: usize) -> Self {
+        Self { libmdbx, batch_size, pending: Vec::with_capacity(batch_size) }
+    }
+
+    async fn push(&mut self, tx_hash: TxHash, timestamp: u64) -> eyre::Result<()> {
+        self.pending.push((tx_hash, timestamp));
+        if self.pending.len() >= self.batch_size {
+            self.flush().await?;
+        }
+
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> eyre::Result<()> {
+        if self.pending.is_empty() {
+            return Ok(())
+        }
+
+        let first_seen = std::mem::replace(&mut self.pending, Vec::with_capacity(self.batch_size));
+        self.libmdbx.write_mempool_first_seen(first_seen).await
+    }
+}
+
+fn now_micros() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time before unix epoch")
+        .as_micros() as u64
+}
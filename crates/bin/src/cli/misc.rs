@@ -19,6 +19,10 @@ pub struct Verbosity {
     /// Silence all log output.
     #[clap(long, alias = "silent", global = true, help_heading = "Display")]
     quiet: bool,
+
+    /// Write the logs to stderr instead of stdout.
+    #[clap(long, global = true, help_heading = "Display")]
+    log_stderr: bool,
 }
 
 impl Verbosity {
@@ -39,4 +43,14 @@ impl Verbosity {
             format!("{level}").parse().unwrap()
         }
     }
+
+    pub fn log_stderr(&self) -> bool {
+        self.log_stderr
+    }
+
+    /// Whether logs are written to stdout, in which case nothing else should
+    /// write to it.
+    pub fn logs_to_stdout(&self) -> bool {
+        !self.quiet && !self.log_stderr
+    }
 }
@@ -1,8 +1,11 @@
 use clap::{Parser, Subcommand};
 
 mod db;
+mod mempool;
 mod misc;
+mod replay;
 mod run;
+mod serve;
 mod utils;
 mod version_data;
 pub use utils::*;
@@ -38,4 +41,15 @@ pub enum Commands {
     /// Brontes database commands
     #[command(name = "db")]
     Database(db::Database),
+    /// Replay a single transaction, printing its classified call tree and the
+    /// mev found in it
+    #[command(name = "replay")]
+    Replay(replay::ReplayArgs),
+    /// Serve the brontes database over a read-only http json api
+    #[command(name = "serve")]
+    Serve(serve::ServeArgs),
+    /// Record when pending txes are first seen in the mempool, used to label
+    /// private flow without clickhouse
+    #[command(name = "mempool")]
+    Mempool(mempool::MempoolArgs),
 }
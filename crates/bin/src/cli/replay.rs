@@ -0,0 +1,190 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use alloy_primitives::{Address, TxHash};
+use brontes_classifier::Classifier;
+use brontes_inspect::Inspectors;
+use brontes_types::{
+    constants::USDT_ADDRESS_STRING,
+    db::{
+        cex::{trades::CexDexTradeConfig, CexExchange, CexFeeSchedule},
+        metadata::Metadata,
+        traits::LibmdbxReader,
+    },
+    init_thread_pools,
+    mev::Bundle,
+    normalized_actions::{Action, NormalizedAction},
+    traits::TracingProvider,
+    tree::{GasDetails, Node, NodeData},
+    BlockData, MultiBlockData,
+};
+use clap::Parser;
+use eyre::eyre;
+use reth_primitives::{BlockId, BlockNumberOrTag};
+use serde::Serialize;
+use tokio::sync::mpsc::unbounded_channel;
+
+use super::{get_env_vars, get_tracing_provider, init_inspectors, load_libmdbx, static_object};
+use crate::runner::CliContext;
+
+#[derive(Debug, Parser)]
+pub struct ReplayArgs {
+    /// Hash of the transaction to replay
+    #[arg(long)]
+    pub tx:               TxHash,
+    /// Inspectors to run on the block of the transaction. If omitted it
+    /// defaults to running all inspectors
+    #[arg(long, short, value_delimiter = ',')]
+    pub inspectors:       Option<Vec<Inspectors>>,
+    /// Quote asset for the inspectors, if omitted it will default to USDT
+    #[arg(long, short, default_value = USDT_ADDRESS_STRING)]
+    pub quote_asset:      String,
+    /// CEX exchanges to consider for cex-dex analysis
+    #[arg(
+        long,
+        short,
+        default_value = "Binance,Coinbase,Okex,BybitSpot,Kucoin",
+        value_delimiter = ','
+    )]
+    pub cex_exchanges:    Vec<CexExchange>,
+    /// Toml file with the cex fee schedule, the maker & taker fees by
+    /// exchange, fee tier, instrument and pair. If omitted it defaults to
+    /// `config/cex_fee_schedule.toml`
+    #[arg(long)]
+    pub cex_fee_schedule: Option<PathBuf>,
+    /// Output the call tree and bundles as JSON instead of pretty printing
+    /// them. Logs are written to stderr to keep stdout parseable
+    #[arg(long, default_value = "false")]
+    pub json:             bool,
+}
+
+impl ReplayArgs {
+    pub async fn execute(self, brontes_db_path: String, ctx: CliContext) -> eyre::Result<()> {
+        let db_path = get_env_vars()?;
+        init_thread_pools(10);
+
+        let quote_asset: Address = self.quote_asset.parse()?;
+        let cex_fee_schedule = CexFeeSchedule::load_or_default(self.cex_fee_schedule.as_deref())?;
+        let libmdbx = static_object(
+            load_libmdbx(&ctx.task_executor, brontes_db_path)?
+                .with_cex_fee_schedule(Arc::new(cex_fee_schedule)),
+        );
+        let tracer = Arc::new(get_tracing_provider(Path::new(&db_path), 10, ctx.task_executor));
+
+        let (block, tx_index) = tracer.block_and_tx_index(self.tx).await?;
+        let header = tracer
+            .header_by_number(block)
+            .await?
+            .ok_or_else(|| eyre!("no header found for block {block}"))?;
+        // the full block is traced so that the inspectors can see the
+        // transactions surrounding the replayed one
+        let traces = tracer
+            .replay_block_transactions(BlockId::Number(BlockNumberOrTag::Number(block)))
+            .await?
+            .ok_or_else(|| eyre!("no traces found for block {block}"))?;
+
+        // the receiver has to outlive the classifier as it sends the pool updates
+        let (tx, _rx) = unbounded_channel();
+        let classifier = Classifier::new(libmdbx, tx, tracer);
+        let tree = Arc::new(classifier.build_block_tree(traces, header, false).await);
+
+        let root = tree
+            .get_root(self.tx)
+            .ok_or_else(|| eyre!("transaction {:?} not found in block {block}", self.tx))?;
+
+        let metadata = libmdbx
+            .get_metadata(block, quote_asset)
+            .or_else(|_| libmdbx.get_metadata_no_dex_price(block, quote_asset))
+            .unwrap_or_else(|e| {
+                tracing::warn!(%e, block, "no metadata found, running inspectors without it");
+                Metadata::default()
+            });
+
+        let inspectors = init_inspectors(
+            quote_asset,
+            libmdbx,
+            self.inspectors,
+            self.cex_exchanges,
+            CexDexTradeConfig::new(libmdbx.cex_fee_schedule()),
+            1,
+            None,
+            false,
+        );
+
+        let data = MultiBlockData {
+            per_block_data: vec![BlockData { metadata: metadata.into(), tree: tree.clone() }],
+            blocks:         1,
+        };
+        let bundles = inspectors
+            .iter()
+            .flat_map(|inspector| inspector.inspect_block(data.clone()))
+            .filter(|bundle| bundle.data.mev_transaction_hashes().contains(&self.tx))
+            .collect::<Vec<_>>();
+
+        if self.json {
+            let output = ReplayOutput {
+                tx_hash: self.tx,
+                block_number: block,
+                tx_index,
+                gas_details: root.gas_details,
+                call_tree: collect_frames(&root.head, &root.data_store),
+                bundles,
+            };
+            println!("{}", serde_json::to_string_pretty(&output)?);
+            return Ok(())
+        }
+
+        println!("{root}");
+        if bundles.is_empty() {
+            println!("no bundles found for {:?}", self.tx);
+        }
+        for bundle in bundles {
+            println!("{bundle}");
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ReplayOutput {
+    tx_hash:      TxHash,
+    block_number: u64,
+    tx_index:     usize,
+    gas_details:  GasDetails,
+    call_tree:    Vec<ReplayFrame>,
+    bundles:      Vec<Bundle>,
+}
+
+/// A call frame of the replayed transaction with its classified actions.
+#[derive(Debug, Serialize)]
+struct ReplayFrame {
+    trace_address: Vec<usize>,
+    address:       Address,
+    actions:       Vec<Action>,
+}
+
+/// Flattens the call tree in execution order.
+fn collect_frames(node: &Node, data: &NodeData<Action>) -> Vec<ReplayFrame> {
+    let frame = ReplayFrame {
+        trace_address: node.trace_address.clone(),
+        address:       node.address,
+        actions:       data
+            .get_ref(node.data)
+            .into_iter()
+            .flatten()
+            .filter(|action| action.is_classified())
+            .cloned()
+            .collect(),
+    };
+
+    std::iter::once(frame)
+        .chain(
+            node.inner
+                .iter()
+                .flat_map(|child| collect_frames(child, data)),
+        )
+        .collect()
+}
@@ -1,23 +1,37 @@
-use std::{path::Path, time::Duration};
+use std::{
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
 
 use brontes_core::decoding::Parser as DParser;
-use brontes_database::clickhouse::cex_config::CexDownloadConfig;
-use brontes_inspect::Inspectors;
+use brontes_database::{
+    clickhouse::cex_config::CexDownloadConfig,
+    libmdbx::DBWriter,
+    parquet::{l2_swaps::load_l2_swaps, relay_fixtures::load_relay_fixtures},
+};
+use brontes_inspect::{composer::MevFilters, Inspectors};
 use brontes_metrics::ParserMetricsListener;
 use brontes_types::{
+    block_metadata::RelayHttpClient,
     constants::USDT_ADDRESS_STRING,
-    db::cex::{trades::CexDexTradeConfig, CexExchange},
+    db::cex::{trades::CexDexTradeConfig, CexExchange, CexFeeSchedule},
     db_write_trigger::{backup_server_heartbeat, start_hr_monitor, HeartRateMonitor},
-    init_thread_pools, UnboundedYapperReceiver,
+    init_thread_pools,
+    mev::MevType,
+    BrontesTaskExecutor, UnboundedYapperReceiver,
 };
 use clap::Parser;
 use tokio::sync::mpsc::unbounded_channel;
 
 use super::{determine_max_tasks, get_env_vars, load_clickhouse, load_database, static_object};
 use crate::{
+    auction::AuctionRecorder,
     banner::rain,
     cli::{get_tracing_provider, init_inspectors, load_tip_database},
     runner::CliContext,
+    stream::{serve_websocket, write_ndjson, MevStream, MevStreamFilter, NdjsonSink},
     BrontesRunConfig, MevProcessor, RangeType,
 };
 
@@ -56,6 +70,12 @@ pub struct RunArgs {
     /// Time window arguments for cex data downloads
     #[clap(flatten)]
     pub time_window_args:     TimeWindowArgs,
+    /// Mev-boost auction recording arguments
+    #[clap(flatten)]
+    pub auction_args:         AuctionArgs,
+    /// Live stream arguments for the mev found
+    #[clap(flatten)]
+    pub stream_args:          StreamArgs,
     /// CEX exchanges to consider for cex-dex analysis
     #[arg(
         long,
@@ -64,6 +84,26 @@ pub struct RunArgs {
         value_delimiter = ','
     )]
     pub cex_exchanges:        Vec<CexExchange>,
+    /// Amount of blocks a sandwich can span. Defaults to single block
+    /// sandwiches, set to 2 or more to also catch frontruns and backruns
+    /// landing in consecutive blocks
+    #[arg(long, default_value = "1")]
+    pub sandwich_window:      usize,
+    /// Toml file with the compose and deduplication rules of the composer. If
+    /// omitted it defaults to `config/mev_filter_config.toml`
+    #[arg(long)]
+    pub mev_filter_config:    Option<PathBuf>,
+    /// Toml file with the cex fee schedule, the maker & taker fees by
+    /// exchange, fee tier, instrument and pair. Cex-dex pnl is reported under
+    /// each of its report tiers. If omitted it defaults to
+    /// `config/cex_fee_schedule.toml`
+    #[arg(long)]
+    pub cex_fee_schedule:     Option<PathBuf>,
+    /// Parquet file, or directory of them, with the L2 swaps the cross domain
+    /// inspector matches mainnet transactions against. Without it no cross
+    /// domain arbitrage is detected
+    #[arg(long)]
+    pub l2_swaps:             Option<PathBuf>,
     /// Force DEX price calculation for every block, ignoring existing database
     /// values.
     #[arg(long, short, default_value = "false")]
@@ -73,7 +113,8 @@ pub struct RunArgs {
     /// database.
     #[arg(long, default_value = "false")]
     pub force_no_dex_pricing: bool,
-    /// Number of blocks to lag behind the chain tip when processing.
+    /// Number of blocks to lag behind the chain tip when processing. Reorged
+    /// blocks are reverted and reprocessed, so this can safely be 0.
     #[arg(long, default_value = "10")]
     pub behind_tip:           u64,
     /// Legacy, run in CLI only mode (no TUI) - will output progress bars to
@@ -104,8 +145,15 @@ pub struct RunArgs {
 }
 
 impl RunArgs {
-    pub async fn execute(mut self, brontes_db_path: String, ctx: CliContext) -> eyre::Result<()> {
+    pub async fn execute(
+        mut self,
+        brontes_db_path: String,
+        logs_to_stdout: bool,
+        ctx: CliContext,
+    ) -> eyre::Result<()> {
         self.check_proper_range()?;
+        self.stream_args
+            .check_stdout_free(logs_to_stdout || self.waterfall)?;
 
         if self.waterfall {
             rain();
@@ -123,6 +171,18 @@ impl RunArgs {
 
         let max_tasks = determine_max_tasks(self.max_tasks);
         init_thread_pools(max_tasks as usize);
+        let mev_filters = static_object(MevFilters::load(self.mev_filter_config.as_deref())?);
+        let cex_fee_schedule =
+            static_object(CexFeeSchedule::load_or_default(self.cex_fee_schedule.as_deref())?);
+        let trade_config = self.time_window_args.trade_config(cex_fee_schedule);
+        // the metadata eth price is adjusted with the fees of the trade config
+        let metadata_fee_schedule = Arc::new(trade_config.cex_fee_schedule.clone());
+        let l2_swaps = self
+            .l2_swaps
+            .as_deref()
+            .map(load_l2_swaps)
+            .transpose()?
+            .map(static_object);
 
         let (metrics_tx, metrics_rx) = unbounded_channel();
         let metrics_listener = ParserMetricsListener::new(UnboundedYapperReceiver::new(
@@ -132,15 +192,25 @@ impl RunArgs {
         ));
 
         task_executor.spawn_critical("metrics", metrics_listener);
+        let stream = self.stream_args.start(&task_executor);
 
         let hr = self.try_start_fallback_server().await;
 
         tracing::info!(target: "brontes", "starting database initialization at: '{}'", brontes_db_path);
-        let libmdbx =
-            static_object(load_database(&task_executor, brontes_db_path, hr, self.run_id).await?);
+        let libmdbx = static_object(
+            load_database(
+                &task_executor,
+                brontes_db_path,
+                hr,
+                self.run_id,
+                metadata_fee_schedule.clone(),
+            )
+            .await?,
+        );
 
         let tip = static_object(load_tip_database(libmdbx)?);
         tracing::info!(target: "brontes", "initialized libmdbx database");
+        let auctions = self.auction_args.start(&task_executor, libmdbx)?;
 
         let load_window = self.load_time_window();
 
@@ -148,6 +218,7 @@ impl RunArgs {
             // the run time window. notably we download the max window
             (load_window as u64, load_window as u64),
             self.cex_exchanges.clone(),
+            metadata_fee_schedule,
         );
 
         let range_type = self.get_range_type()?;
@@ -167,14 +238,14 @@ impl RunArgs {
             self.force_no_dex_pricing = true;
         }
 
-        let trade_config = self.time_window_args.trade_config();
-
         let inspectors = init_inspectors(
             quote_asset,
             libmdbx,
             self.inspectors,
             self.cex_exchanges,
             trade_config,
+            self.sandwich_window,
+            l2_swaps,
             self.with_metrics,
         );
 
@@ -202,6 +273,7 @@ impl RunArgs {
                     self.with_metrics,
                     snapshot_mode,
                     load_window,
+                    MevProcessor::new(mev_filters, stream, auctions),
                 )
                 .build(task_executor, shutdown)
                 .await
@@ -386,10 +458,15 @@ pub struct TimeWindowArgs {
     /// Cex Dex Quotes price time offset from block timestamp
     #[arg(long = "quote-offset", default_value = "0.0")]
     pub quote_offset: f64,
+
+    /// How long a perp hedge of a cex-dex arb is held, in seconds, to model
+    /// the funding it pays
+    #[arg(long = "perp-holding-horizon", default_value = "28800.0")]
+    pub perp_holding_horizon: f64,
 }
 
 impl TimeWindowArgs {
-    fn trade_config(&self) -> CexDexTradeConfig {
+    fn trade_config(&self, fee_schedule: &'static CexFeeSchedule) -> CexDexTradeConfig {
         CexDexTradeConfig {
             initial_vwap_pre_block_us:  (self.initial_vwap_pre * SECONDS_TO_US_FLOAT) as u64,
             initial_vwap_post_block_us: (self.initial_vwap_post * SECONDS_TO_US_FLOAT) as u64,
@@ -417,6 +494,107 @@ impl TimeWindowArgs {
             pre_decay_weight_op:               self.pre_decay_weight_optimistic,
             post_decay_weight_op:              self.post_decay_weight_optimistic,
             quote_offset_from_block_us:        (self.quote_offset * SECONDS_TO_US_FLOAT) as u64,
+            perp_holding_horizon_us:           (self.perp_holding_horizon * SECONDS_TO_US_FLOAT)
+                as u64,
+            cex_fee_schedule:                  fee_schedule,
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct StreamArgs {
+    /// Address to serve a websocket stream of the mev found on
+    #[arg(long = "stream-ws")]
+    pub ws_addr:        Option<SocketAddr>,
+    /// File to append the mev found to as newline delimited json, `-` for
+    /// stdout. Streaming to stdout requires the logs to go to stderr
+    /// (`--log-stderr`) or to be silenced (`--quiet`)
+    #[arg(long = "stream-ndjson")]
+    pub ndjson:         Option<String>,
+    /// Only stream bundles of these mev types
+    #[arg(long = "stream-mev-types", value_delimiter = ',')]
+    pub mev_types:      Option<Vec<MevType>>,
+    /// Only stream bundles with at least this profit in usd
+    #[arg(long = "stream-min-profit")]
+    pub min_profit_usd: Option<f64>,
+}
+
+impl StreamArgs {
+    /// The ndjson events written to stdout would be interleaved with anything
+    /// else written to it, like the logs or the startup banner.
+    fn check_stdout_free(&self, stdout_in_use: bool) -> eyre::Result<()> {
+        if stdout_in_use
+            && matches!(self.ndjson.clone().map(NdjsonSink::from), Some(NdjsonSink::Stdout))
+        {
+            eyre::bail!(
+                "`--stream-ndjson -` writes to stdout, run with `--log-stderr` or `--quiet` and \
+                 without `--waterfall`"
+            )
+        }
+
+        Ok(())
+    }
+
+    /// Spawns the configured sinks, returning the stream they are fed by. Does
+    /// nothing if none are set.
+    fn start(&self, executor: &BrontesTaskExecutor) -> Option<&'static MevStream> {
+        if self.ws_addr.is_none() && self.ndjson.is_none() {
+            return None
         }
+
+        let stream = static_object(MevStream::new(MevStreamFilter {
+            mev_types:      self.mev_types.clone(),
+            min_profit_usd: self.min_profit_usd,
+        }));
+
+        if let Some(addr) = self.ws_addr {
+            executor.spawn(async move {
+                if let Err(e) = serve_websocket(stream, addr).await {
+                    tracing::error!(err=%e, "websocket mev stream stopped");
+                }
+            });
+        }
+        if let Some(sink) = self.ndjson.clone() {
+            executor.spawn(async move {
+                if let Err(e) = write_ndjson(stream, NdjsonSink::from(sink)).await {
+                    tracing::error!(err=%e, "ndjson mev stream stopped");
+                }
+            });
+        }
+
+        Some(stream)
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct AuctionArgs {
+    /// Record the mev-boost auction of every block from the relay bids
+    #[arg(long, default_value = "false")]
+    pub record_auctions: bool,
+    /// Directory of recorded relay data api dumps to read the bids from
+    /// instead of querying the relays, to backfill auctions offline. See
+    /// `db init --relay-fixtures`
+    #[arg(long, requires = "record_auctions")]
+    pub relay_fixtures:  Option<PathBuf>,
+}
+
+impl AuctionArgs {
+    /// Spawns the auction recorder, returning the handle blocks are recorded
+    /// through. Does nothing unless recording is enabled.
+    fn start<DB: DBWriter + Sync>(
+        &self,
+        executor: &BrontesTaskExecutor,
+        database: &'static DB,
+    ) -> eyre::Result<Option<&'static AuctionRecorder>> {
+        if !self.record_auctions {
+            return Ok(None)
+        }
+
+        let recorder = match &self.relay_fixtures {
+            Some(dir) => AuctionRecorder::spawn(executor, load_relay_fixtures(dir)?, database),
+            None => AuctionRecorder::spawn(executor, RelayHttpClient, database),
+        };
+
+        Ok(Some(static_object(recorder)))
     }
 }
@@ -0,0 +1,37 @@
+use std::{convert::Infallible, net::SocketAddr};
+
+use clap::Parser;
+use eyre::WrapErr;
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Server,
+};
+use tracing::info;
+
+use super::{load_libmdbx, static_object};
+use crate::runner::CliContext;
+
+mod routes;
+
+#[derive(Debug, Parser)]
+pub struct ServeArgs {
+    /// Address the http server binds to
+    #[arg(long, default_value = "127.0.0.1:8080")]
+    pub listen_addr: SocketAddr,
+}
+
+impl ServeArgs {
+    pub async fn execute(self, brontes_db_path: String, ctx: CliContext) -> eyre::Result<()> {
+        let libmdbx = static_object(load_libmdbx(&ctx.task_executor, brontes_db_path)?);
+
+        let make_svc = make_service_fn(move |_| async move {
+            Ok::<_, Infallible>(service_fn(move |req| routes::handle(libmdbx, req)))
+        });
+        let server = Server::try_bind(&self.listen_addr)
+            .wrap_err("Could not bind to address")?
+            .serve(make_svc);
+
+        info!(target: "brontes", addr = %self.listen_addr, "serving the libmdbx database over http");
+        server.await.wrap_err("Http server crashed")
+    }
+}
@@ -0,0 +1,396 @@
+//! Read-only json endpoints over the libmdbx database.
+//!
+//! - `GET /mev/blocks` mev blocks with their bundles
+//! - `GET /mev/bundles` all bundles, optionally filtered by `mev_type`
+//! - `GET /mev/tx/{hash}` bundles containing the given transaction
+//! - `GET /mev/searcher/{address}` bundles of a searcher eoa or contract
+//! - `GET /dex/quote?block=&tx_index=&token0=&token1=` dex price of a pair
+//! - `GET /searcher/{eoa}?contract=` searcher info
+//! - `GET /address/{address}` address metadata
+//! - `GET /builder/{address}` builder info
+//!
+//! All mev endpoints require a `start_block` and `end_block` (inclusive) range
+//! of at most [`MAX_BLOCK_RANGE`] blocks and are paginated with `offset` and
+//! `limit`.
+
+use std::{collections::HashMap, convert::Infallible, fmt::Display, str::FromStr};
+
+use alloy_primitives::{Address, TxHash};
+use brontes_types::{
+    db::{mev_block::MevBlockWithClassified, searcher::SearcherInfo, traits::LibmdbxReader},
+    mev::{Bundle, MevType},
+    pair::Pair,
+    ToFloatNearest,
+};
+use clap::ValueEnum;
+use hyper::{header::CONTENT_TYPE, Body, Method, Request, Response, StatusCode};
+use serde::Serialize;
+use serde_json::{json, Value};
+use thiserror::Error;
+
+const DEFAULT_PAGE_SIZE: usize = 100;
+const MAX_PAGE_SIZE: usize = 1000;
+/// Widest block range a single mev request reads, so that a request can't
+/// scan the whole mev blocks table.
+const MAX_BLOCK_RANGE: u64 = 10_000;
+
+pub(super) async fn handle<DB: LibmdbxReader>(
+    db: &'static DB,
+    req: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+    if req.method() != Method::GET {
+        return Ok(json_response(
+            StatusCode::METHOD_NOT_ALLOWED,
+            &json!({ "error": "only GET requests are supported" }),
+        ))
+    }
+
+    let path = req.uri().path().trim_matches('/').to_string();
+    let query = Query::parse(req.uri().query());
+
+    // libmdbx reads are blocking and range queries can take a while
+    let res = tokio::task::spawn_blocking(move || route(db, &path, &query))
+        .await
+        .map_err(|e| ApiError::Internal(e.to_string()))
+        .and_then(|res| res);
+
+    Ok(match res {
+        Ok(body) => json_response(StatusCode::OK, &body),
+        Err(e) => json_response(e.status(), &json!({ "error": e.to_string() })),
+    })
+}
+
+fn route<DB: LibmdbxReader>(db: &DB, path: &str, query: &Query) -> Result<Value, ApiError> {
+    let segments = path.split('/').collect::<Vec<_>>();
+
+    match segments.as_slice() {
+        ["mev", "blocks"] => to_value(Page::new(fetch_mev_blocks(db, query)?, query)?),
+        ["mev", "bundles"] => mev_bundles(db, query, |_| true),
+        ["mev", "tx", hash] => {
+            let hash = parse_segment::<TxHash>(hash)?;
+            mev_bundles(db, query, |bundle| bundle.data.mev_transaction_hashes().contains(&hash))
+        }
+        ["mev", "searcher", address] => {
+            let address = parse_segment::<Address>(address)?;
+            mev_bundles(db, query, |bundle| {
+                bundle.header.eoa == address || bundle.header.mev_contract == Some(address)
+            })
+        }
+        ["dex", "quote"] => dex_quote(db, query),
+        ["searcher", eoa] => {
+            let eoa = parse_segment::<Address>(eoa)?;
+            let contract = query.get::<Address>("contract")?;
+            match db.try_fetch_searcher_info(eoa, contract)? {
+                (None, None) => Err(ApiError::NotFound(format!("no searcher info for {eoa:?}"))),
+                (eoa, contract) => to_value(SearcherInfoResponse { eoa, contract }),
+            }
+        }
+        ["address", address] => {
+            let address = parse_segment::<Address>(address)?;
+            db.try_fetch_address_metadata(address)?
+                .ok_or_else(|| ApiError::NotFound(format!("no metadata for {address:?}")))
+                .and_then(to_value)
+        }
+        ["builder", address] => {
+            let address = parse_segment::<Address>(address)?;
+            db.try_fetch_builder_info(address)?
+                .ok_or_else(|| ApiError::NotFound(format!("no builder info for {address:?}")))
+                .and_then(to_value)
+        }
+        _ => Err(ApiError::NotFound(format!("unknown route /{path}"))),
+    }
+}
+
+fn fetch_mev_blocks<DB: LibmdbxReader>(
+    db: &DB,
+    query: &Query,
+) -> Result<Vec<MevBlockWithClassified>, ApiError> {
+    let (start_block, end_block) = block_range(query)?;
+    Ok(db.try_fetch_mev_blocks(Some(start_block), end_block)?)
+}
+
+fn block_range(query: &Query) -> Result<(u64, u64), ApiError> {
+    let start_block = query.require::<u64>("start_block")?;
+    let end_block = query.require::<u64>("end_block")?;
+
+    if start_block > end_block {
+        return Err(ApiError::BadRequest(format!(
+            "start_block {start_block} is after end_block {end_block}"
+        )))
+    }
+    if end_block - start_block >= MAX_BLOCK_RANGE {
+        return Err(ApiError::BadRequest(format!(
+            "block range is limited to {MAX_BLOCK_RANGE} blocks"
+        )))
+    }
+
+    Ok((start_block, end_block))
+}
+
+fn mev_bundles<DB: LibmdbxReader>(
+    db: &DB,
+    query: &Query,
+    filter: impl Fn(&Bundle) -> bool,
+) -> Result<Value, ApiError> {
+    let mev_type = query
+        .get_raw("mev_type")
+        .map(|mev_type| {
+            <MevType as ValueEnum>::from_str(mev_type, true)
+                .map_err(|e| ApiError::BadRequest(format!("invalid mev_type: {e}")))
+        })
+        .transpose()?;
+
+    let bundles = fetch_mev_blocks(db, query)?
+        .into_iter()
+        .flat_map(|block| block.mev)
+        .filter(|bundle| mev_type.map_or(true, |mev_type| bundle.mev_type() == mev_type))
+        .filter(filter)
+        .collect::<Vec<_>>();
+
+    to_value(Page::new(bundles, query)?)
+}
+
+fn dex_quote<DB: LibmdbxReader>(db: &DB, query: &Query) -> Result<Value, ApiError> {
+    let block = query.require::<u64>("block")?;
+    let tx_index = query.require::<usize>("tx_index")?;
+    let pair = Pair(query.require("token0")?, query.require("token1")?);
+
+    if !db.has_dex_quotes(block)? {
+        return Err(ApiError::NotFound(format!("no dex quotes for block {block}")))
+    }
+
+    let price = db
+        .get_dex_quotes(block)?
+        .price_at(pair, tx_index)
+        .ok_or_else(|| {
+            ApiError::NotFound(format!("no price for {pair:?} at or before tx {tx_index}"))
+        })?;
+
+    to_value(DexQuoteResponse {
+        block,
+        tx_index,
+        pair,
+        pre_state: price.pre_state.to_float(),
+        post_state: price.post_state.to_float(),
+        pool_liquidity: price.pool_liquidity.to_float(),
+        goes_through: price.goes_through,
+        is_transfer: price.is_transfer,
+        first_hop_connections: price.first_hop_connections,
+    })
+}
+
+#[derive(Debug, Error)]
+enum ApiError {
+    #[error("{0}")]
+    BadRequest(String),
+    #[error("{0}")]
+    NotFound(String),
+    #[error("database error: {0}")]
+    Database(eyre::Report),
+    #[error("internal error: {0}")]
+    Internal(String),
+}
+
+impl ApiError {
+    fn status(&self) -> StatusCode {
+        match self {
+            Self::BadRequest(_) => StatusCode::BAD_REQUEST,
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Database(_) | Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl From<eyre::Report> for ApiError {
+    fn from(value: eyre::Report) -> Self {
+        Self::Database(value)
+    }
+}
+
+/// Percent-decoded url query parameters of a request.
+struct Query(HashMap<String, String>);
+
+impl Query {
+    fn parse(query: Option<&str>) -> Self {
+        Self(
+            form_urlencoded::parse(query.unwrap_or_default().as_bytes())
+                .into_owned()
+                .collect(),
+        )
+    }
+
+    fn get_raw(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    fn get<T: FromStr>(&self, key: &str) -> Result<Option<T>, ApiError>
+    where
+        T::Err: Display,
+    {
+        self.get_raw(key)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|e| ApiError::BadRequest(format!("invalid {key} `{value}`: {e}")))
+            })
+            .transpose()
+    }
+
+    fn require<T: FromStr>(&self, key: &str) -> Result<T, ApiError>
+    where
+        T::Err: Display,
+    {
+        self.get(key)?
+            .ok_or_else(|| ApiError::BadRequest(format!("missing query parameter {key}")))
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Page<T> {
+    total:  usize,
+    offset: usize,
+    limit:  usize,
+    data:   Vec<T>,
+}
+
+impl<T> Page<T> {
+    fn new(items: Vec<T>, query: &Query) -> Result<Self, ApiError> {
+        let offset = query.get("offset")?.unwrap_or_default();
+        let limit = query
+            .get("limit")?
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .min(MAX_PAGE_SIZE);
+
+        Ok(Self {
+            total: items.len(),
+            offset,
+            limit,
+            data: items.into_iter().skip(offset).take(limit).collect(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SearcherInfoResponse {
+    eoa:      Option<SearcherInfo>,
+    contract: Option<SearcherInfo>,
+}
+
+#[derive(Debug, Serialize)]
+struct DexQuoteResponse {
+    block:                 u64,
+    tx_index:              usize,
+    pair:                  Pair,
+    pre_state:             f64,
+    post_state:            f64,
+    pool_liquidity:        f64,
+    goes_through:          Pair,
+    is_transfer:           bool,
+    first_hop_connections: usize,
+}
+
+fn parse_segment<T: FromStr>(segment: &str) -> Result<T, ApiError>
+where
+    T::Err: Display,
+{
+    segment
+        .parse()
+        .map_err(|e| ApiError::BadRequest(format!("invalid path segment `{segment}`: {e}")))
+}
+
+fn to_value<T: Serialize>(value: T) -> Result<Value, ApiError> {
+    serde_json::to_value(value).map_err(|e| ApiError::Internal(e.to_string()))
+}
+
+fn json_response(status: StatusCode, body: &Value) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .expect("valid response")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(query: &str) -> Query {
+        Query::parse(Some(query))
+    }
+
+    fn bad_request<T: std::fmt::Debug>(res: Result<T, ApiError>) -> String {
+        match res {
+            Err(ApiError::BadRequest(e)) => e,
+            res => panic!("expected a bad request, got {res:?}"),
+        }
+    }
+
+    #[test]
+    fn test_block_range_bounds() {
+        assert_eq!(block_range(&query("start_block=10&end_block=10")).unwrap(), (10, 10));
+        assert_eq!(
+            block_range(&query(&format!("start_block=0&end_block={}", MAX_BLOCK_RANGE - 1)))
+                .unwrap(),
+            (0, MAX_BLOCK_RANGE - 1)
+        );
+
+        let too_wide = format!("start_block=0&end_block={MAX_BLOCK_RANGE}");
+        assert_eq!(
+            bad_request(block_range(&query(&too_wide))),
+            format!("block range is limited to {MAX_BLOCK_RANGE} blocks")
+        );
+        assert_eq!(
+            bad_request(block_range(&query("start_block=11&end_block=10"))),
+            "start_block 11 is after end_block 10"
+        );
+        assert_eq!(
+            bad_request(block_range(&query("start_block=10"))),
+            "missing query parameter end_block"
+        );
+        assert!(bad_request(block_range(&query("start_block=-1&end_block=10")))
+            .starts_with("invalid start_block `-1`"));
+    }
+
+    #[test]
+    fn test_query_decoding() {
+        let query = query("mev_type=cex%20dex&contract=0x%30%31&empty=&flag&token0=a+b");
+
+        assert_eq!(query.get_raw("mev_type"), Some("cex dex"));
+        assert_eq!(query.get_raw("contract"), Some("0x01"));
+        assert_eq!(query.get_raw("empty"), Some(""));
+        assert_eq!(query.get_raw("flag"), Some(""));
+        assert_eq!(query.get_raw("token0"), Some("a b"));
+        assert_eq!(query.get_raw("missing"), None);
+        assert_eq!(query.get::<u64>("missing").unwrap(), None);
+
+        let address = Query::parse(Some("contract=0x%41bc000000000000000000000000000000000001"))
+            .require::<Address>("contract")
+            .unwrap();
+        assert_eq!(
+            address,
+            Address::from_str("0xabc0000000000000000000000000000000000001").unwrap()
+        );
+
+        assert!(Query::parse(None).get_raw("start_block").is_none());
+    }
+
+    #[test]
+    fn test_page_bounds() {
+        let items = (0..10).collect::<Vec<_>>();
+
+        let page = Page::new(items.clone(), &query("offset=8&limit=5")).unwrap();
+        assert_eq!((page.total, page.offset, page.limit), (10, 8, 5));
+        assert_eq!(page.data, vec![8, 9]);
+
+        let page =
+            Page::new(items.clone(), &query(&format!("limit={}", MAX_PAGE_SIZE + 1))).unwrap();
+        assert_eq!(page.limit, MAX_PAGE_SIZE);
+        assert_eq!(page.data, items);
+
+        assert!(Page::new(items.clone(), &query("offset=20"))
+            .unwrap()
+            .data
+            .is_empty());
+        assert!(bad_request(Page::new(items, &query("limit=-1"))).starts_with("invalid limit"));
+    }
+}
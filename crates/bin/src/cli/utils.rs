@@ -1,4 +1,4 @@
-use std::{env, path::Path};
+use std::{env, path::Path, sync::Arc};
 
 use alloy_primitives::Address;
 #[cfg(not(feature = "local-reth"))]
@@ -22,7 +22,8 @@ use brontes_metrics::inspectors::OutlierMetrics;
 use brontes_types::UnboundedYapperReceiver;
 use brontes_types::{
     db::{
-        cex::{trades::CexDexTradeConfig, CexExchange},
+        cex::{trades::CexDexTradeConfig, CexExchange, CexFeeSchedule},
+        l2_swaps::L2SwapDataset,
         traits::LibmdbxReader,
     },
     db_write_trigger::HeartRateMonitor,
@@ -41,8 +42,10 @@ pub async fn load_database(
     db_endpoint: String,
     _: Option<HeartRateMonitor>,
     _: Option<u64>,
+    cex_fee_schedule: Arc<CexFeeSchedule>,
 ) -> eyre::Result<LibmdbxReadWriter> {
-    LibmdbxReadWriter::init_db(db_endpoint, None, executor, true)
+    Ok(LibmdbxReadWriter::init_db(db_endpoint, None, executor, true)?
+        .with_cex_fee_schedule(cex_fee_schedule))
 }
 
 #[cfg(not(feature = "local-clickhouse"))]
@@ -57,13 +60,17 @@ pub async fn load_database(
     db_endpoint: String,
     hr: Option<HeartRateMonitor>,
     run_id: Option<u64>,
+    cex_fee_schedule: Arc<CexFeeSchedule>,
 ) -> eyre::Result<ClickhouseMiddleware<LibmdbxReadWriter>> {
-    let inner = LibmdbxReadWriter::init_db(db_endpoint, None, executor, true)?;
+    let inner = LibmdbxReadWriter::init_db(db_endpoint, None, executor, true)?
+        .with_cex_fee_schedule(cex_fee_schedule);
 
     let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
-    spawn_db_writer_thread(executor, rx, hr);
+    let (flush_tx, flush_rx) = tokio::sync::mpsc::unbounded_channel();
+    spawn_db_writer_thread(executor, rx, flush_rx, hr);
     let mut clickhouse = Clickhouse::new_default(run_id).await;
     clickhouse.buffered_insert_tx = Some(tx);
+    clickhouse.buffered_flush_tx = Some(flush_tx);
 
     Ok(ClickhouseMiddleware::new(clickhouse, inner.into()))
 }
@@ -111,12 +118,14 @@ pub async fn load_clickhouse(
 
 #[cfg(not(feature = "local-clickhouse"))]
 pub async fn load_clickhouse(
-    _: CexDownloadConfig,
+    cex_download_config: CexDownloadConfig,
     _: Option<u64>,
 ) -> eyre::Result<ClickhouseHttpClient> {
     let clickhouse_api = env::var("CLICKHOUSE_API")?;
     let clickhouse_api_key = env::var("CLICKHOUSE_API_KEY").ok();
-    Ok(ClickhouseHttpClient::new(clickhouse_api, clickhouse_api_key).await)
+    Ok(ClickhouseHttpClient::new(clickhouse_api, clickhouse_api_key)
+        .await
+        .with_cex_fee_schedule(cex_download_config.cex_fee_schedule))
 }
 
 #[cfg(not(feature = "local-reth"))]
@@ -156,6 +165,8 @@ pub fn init_inspectors<DB: LibmdbxReader>(
     inspectors: Option<Vec<Inspectors>>,
     cex_exchanges: Vec<CexExchange>,
     trade_config: CexDexTradeConfig,
+    sandwich_block_window: usize,
+    l2_swaps: Option<&'static L2SwapDataset>,
     metrics: bool,
 ) -> &'static [&'static dyn Inspector<Result = Vec<Bundle>>] {
     let mut res = Vec::new();
@@ -169,6 +180,8 @@ pub fn init_inspectors<DB: LibmdbxReader>(
             db,
             &cex_exchanges,
             trade_config,
+            sandwich_block_window,
+            l2_swaps,
             metrics.clone(),
         ));
     }
@@ -187,6 +200,7 @@ pub fn get_env_vars() -> eyre::Result<String> {
 fn spawn_db_writer_thread(
     executor: &BrontesTaskExecutor,
     buffered_rx: tokio::sync::mpsc::UnboundedReceiver<Vec<BrontesClickhouseData>>,
+    flush_rx: tokio::sync::mpsc::UnboundedReceiver<tokio::sync::oneshot::Sender<()>>,
     hr: Option<HeartRateMonitor>,
 ) {
     let shutdown = executor.get_graceful_shutdown();
@@ -196,6 +210,7 @@ fn spawn_db_writer_thread(
         5000,
         800,
         hr,
+        Some(flush_rx),
     )
     .run(shutdown);
     tracing::info!("started writer");
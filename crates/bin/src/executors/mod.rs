@@ -17,7 +17,6 @@ use futures::pin_mut;
 use shared::multi_block_window::MultiBlockWindow;
 mod tip;
 use std::{
-    marker::PhantomData,
     pin::Pin,
     sync::{atomic::AtomicBool, Arc},
     task::{Context, Poll},
@@ -65,7 +64,7 @@ pub struct BrontesRunConfig<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseH
     pub metrics: bool,
     pub is_snapshot: bool,
     pub cex_window: usize,
-    _p: PhantomData<P>,
+    pub processor: P,
 }
 
 impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
@@ -88,6 +87,7 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
         metrics: bool,
         is_snapshot: bool,
         cex_window: usize,
+        processor: P,
     ) -> Self {
         Self {
             clickhouse,
@@ -105,7 +105,7 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
             tip_db,
             is_snapshot,
             cex_window,
-            _p: PhantomData,
+            processor,
         }
     }
 
@@ -143,6 +143,10 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
 
         let pricing_metrics = self.metrics.then(DexPricingMetrics::default);
         let (should_run_tip_inspector, end_block) = self.should_run_tip_inspector().await;
+        let range_start = self
+            .range_type
+            .get_start_block(self.libmdbx)
+            .unwrap_or(end_block);
 
         if self.is_snapshot {
             let (start_block, db_end_block) = self.libmdbx.get_db_range()?;
@@ -186,11 +190,12 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
                     .await;
             }
             tracing::info!("starting tip inspector");
+            let back_from_tip = self.range_type.back_from_tip();
             let tip_inspector = self.build_tip_inspector(
                 usize::MAX,
                 executor.clone(),
                 end_block,
-                self.range_type.back_from_tip(),
+                back_from_tip,
                 pricing_metrics,
             );
 
@@ -202,13 +207,7 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
 
         let metrics = FinishedRange::default();
         metrics.running_ranges.increment(futures.len() as f64);
-        metrics.total_set_range.increment(
-            end_block
-                - self
-                    .range_type
-                    .get_start_block(self.libmdbx)
-                    .unwrap_or(end_block),
-        );
+        metrics.total_set_range.increment(end_block - range_start);
 
         Ok(Brontes { futures, metrics })
     }
@@ -332,6 +331,7 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
                         self.inspectors,
                         prgrs_bar,
                         metrics,
+                        self.processor,
                     )
                 }
             },
@@ -340,28 +340,46 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
     }
 
     fn build_tip_inspector(
-        &self,
+        self,
         range_id: usize,
         executor: BrontesTaskExecutor,
         start_block: u64,
         back_from_tip: u64,
         pricing_metrics: Option<DexPricingMetrics>,
     ) -> TipInspector<T, DB, CH, P> {
-        let state_collector = self.init_state_collector(
+        let (parser, tip_db, inspectors, processor) =
+            (self.parser, self.tip_db, self.inspectors, self.processor);
+        let config = static_object(self);
+
+        let state_collector = config.init_state_collector(
             range_id,
-            executor,
+            executor.clone(),
             start_block,
             start_block,
             true,
-            pricing_metrics,
+            pricing_metrics.clone(),
         );
+        // used to start over from the fork point when the chain reorgs
+        let new_state_collector = Box::new(move |block| {
+            config.init_state_collector(
+                range_id,
+                executor.clone(),
+                block,
+                block,
+                true,
+                pricing_metrics.clone(),
+            )
+        });
+
         TipInspector::new(
             start_block,
             back_from_tip,
             state_collector,
-            self.parser,
-            self.tip_db,
-            self.inspectors,
+            new_state_collector,
+            parser,
+            tip_db,
+            inspectors,
+            processor,
         )
     }
 
@@ -404,16 +422,18 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
             .protocols_created_range(start_block + 1, end_block)
             .unwrap()
             .into_iter()
-            .flat_map(|(_, pools)| {
-                pools
-                    .into_iter()
-                    .filter(|(_, p, _)| p.has_state_updater())
-                    .map(|(addr, protocol, pair)| (addr, (protocol, pair)))
-                    .collect::<Vec<_>>()
-            })
-            .collect::<FastHashMap<_, _>>();
+            .flat_map(|(_, pools)| pools)
+            .filter(|(_, p, _)| p.has_state_updater())
+            .fold(FastHashMap::<_, (_, Vec<_>)>::default(), |mut acc, (addr, protocol, pair)| {
+                acc.entry(addr)
+                    .or_insert_with(|| (protocol, vec![]))
+                    .1
+                    .push(pair);
+                acc
+            });
 
         let pair_graph = GraphManager::init_from_db_state(pairs, pricing_metrics.clone());
+        let pool_keys = self.libmdbx.fetch_uniswap_v4_pool_keys().unwrap();
 
         let data_req = Arc::new(AtomicBool::new(true));
 
@@ -426,6 +446,7 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
             self.parser.get_tracer(),
             start_block,
             rest_pairs,
+            pool_keys,
             data_req.clone(),
             pricing_metrics.clone(),
             executor.clone(),
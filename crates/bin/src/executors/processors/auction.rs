@@ -0,0 +1,70 @@
+//! Records the mev-boost auction of every processed block.
+//!
+//! Fetching the bids hits every relay once per block, so recording runs as its
+//! own task, fed the processed blocks by the
+//! [`MevProcessor`](super::MevProcessor) and only started when enabled in the
+//! run arguments. The bids are read from a [`RelayDataProvider`], the relays
+//! themselves or recorded fixtures of them to backfill auctions offline.
+
+use brontes_database::libmdbx::DBWriter;
+use brontes_types::{
+    block_metadata::{RelayDataProvider, Relays},
+    db::block_auction::BlockAuction,
+    mev::MevBlock,
+    BrontesTaskExecutor,
+};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use tracing::debug;
+
+#[derive(Debug)]
+pub struct AuctionRecorder {
+    tx: UnboundedSender<MevBlock>,
+}
+
+impl AuctionRecorder {
+    /// Spawns the recording task, writing the auctions to the database.
+    pub fn spawn<P, DB>(executor: &BrontesTaskExecutor, provider: P, database: &'static DB) -> Self
+    where
+        P: RelayDataProvider + 'static,
+        DB: DBWriter + Sync,
+    {
+        let (tx, rx) = unbounded_channel();
+        executor.spawn(record_auctions(provider, database, rx));
+
+        Self { tx }
+    }
+
+    /// Queues the auction of the block to be recorded. Blocks that weren't
+    /// delivered by a relay have no auction and are skipped.
+    pub fn record(&self, block: &MevBlock) {
+        if block.proposer_mev_reward.is_none() {
+            return
+        }
+
+        if self.tx.send(block.clone()).is_err() {
+            tracing::error!(block_number = block.block_number, "auction recorder stopped");
+        }
+    }
+}
+
+async fn record_auctions<P: RelayDataProvider, DB: DBWriter + Sync>(
+    provider: P,
+    database: &'static DB,
+    mut rx: UnboundedReceiver<MevBlock>,
+) {
+    while let Some(block) = rx.recv().await {
+        let bids = Relays::get_block_bids_from(&provider, block.block_number).await;
+        let Some(auction) = BlockAuction::new(&block, bids) else {
+            debug!(block_number = block.block_number, "no relay bid for the delivered block");
+            continue
+        };
+
+        if let Err(e) = database.write_block_auction(auction).await {
+            tracing::error!(
+                "Failed to insert block auction into db: {:?} at block: {}",
+                e,
+                block.block_number
+            );
+        }
+    }
+}
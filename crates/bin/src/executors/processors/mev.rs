@@ -3,7 +3,7 @@ use std::sync::Arc;
 
 use brontes_database::libmdbx::{DBWriter, LibmdbxReader};
 use brontes_inspect::{
-    composer::{run_block_inspection, ComposerResults},
+    composer::{run_block_inspection, ComposerResults, DedupDecision, MevFilters},
     Inspector,
 };
 #[cfg(feature = "local-clickhouse")]
@@ -22,16 +22,35 @@ use brontes_types::{
 };
 use tracing::debug;
 
+use super::{auction::AuctionRecorder, stream::MevStream};
 use crate::Processor;
 
 #[derive(Debug, Clone, Copy)]
-pub struct MevProcessor;
+pub struct MevProcessor {
+    /// Compose and deduplication rules of the composer
+    filters:  &'static MevFilters,
+    /// Live stream the results are published to, if any sinks are configured
+    stream:   Option<&'static MevStream>,
+    /// Records the auction of every block, if enabled
+    auctions: Option<&'static AuctionRecorder>,
+}
+
+impl MevProcessor {
+    pub fn new(
+        filters: &'static MevFilters,
+        stream: Option<&'static MevStream>,
+        auctions: Option<&'static AuctionRecorder>,
+    ) -> Self {
+        Self { filters, stream, auctions }
+    }
+}
 
 impl Processor for MevProcessor {
     type InspectType = Vec<Bundle>;
 
     #[allow(unused_variables)]
     async fn process_results<DB: DBWriter + LibmdbxReader>(
+        self,
         db: &'static DB,
         inspectors: &'static [&dyn Inspector<Result = Self::InspectType>],
         data: MultiBlockData,
@@ -51,10 +70,19 @@ impl Processor for MevProcessor {
             insert_tree(db, inner_tree, metadata.block_num).await;
         }
 
-        let ComposerResults { block_details, mev_details, block_analysis, .. } =
-            execute_on!(async_inspect, { run_block_inspection(inspectors, data, db) }).await;
+        let filters = self.filters;
+        let ComposerResults { block_details, mev_details, block_analysis, dedup_decisions, .. } =
+            execute_on!(async_inspect, { run_block_inspection(inspectors, filters, data, db) })
+                .await;
+
+        if let Some(stream) = self.stream {
+            stream.publish(&block_details, &mev_details);
+        }
+        if let Some(auctions) = self.auctions {
+            auctions.record(&block_details);
+        }
 
-        insert_mev_results(db, block_details, mev_details, block_analysis).await;
+        insert_mev_results(db, block_details, mev_details, block_analysis, dedup_decisions).await;
     }
 }
 
@@ -79,6 +107,7 @@ async fn insert_mev_results<DB: DBWriter + LibmdbxReader>(
     block_details: MevBlock,
     mev_details: Vec<Bundle>,
     analysis: BlockAnalysis,
+    dedup_decisions: Vec<DedupDecision>,
 ) {
     debug!(
         target: "brontes::results",
@@ -88,6 +117,7 @@ async fn insert_mev_results<DB: DBWriter + LibmdbxReader>(
 
     let block_number = block_details.block_number;
     output_mev_and_update_searcher_info(database, &mev_details).await;
+    update_searcher_stats(database, block_number, &mev_details).await;
 
     // Attempt to save the MEV block details
     if let Err(e) = database
@@ -107,7 +137,43 @@ async fn insert_mev_results<DB: DBWriter + LibmdbxReader>(
             block_number
         );
     }
+    if dedup_decisions.is_empty() {
+        return
+    }
+
+    if let Err(e) = database.write_dedup_decisions(dedup_decisions).await {
+        tracing::error!(
+            "Failed to insert dedup decisions into db: {:?} at block: {}",
+            e,
+            block_number
+        );
+    }
 }
+
+async fn update_searcher_stats<DB: DBWriter>(
+    database: &DB,
+    block_number: u64,
+    mev_details: &[Bundle],
+) {
+    let bundles = mev_details
+        .iter()
+        .filter(|mev| !matches!(mev.header.mev_type, MevType::Unknown | MevType::SearcherTx))
+        .map(|mev| mev.header.clone())
+        .collect::<Vec<_>>();
+
+    if bundles.is_empty() {
+        return
+    }
+
+    if let Err(e) = database.write_searcher_stats(block_number, bundles).await {
+        tracing::error!(
+            "Failed to update searcher stats in the database: {:?} at block: {}",
+            e,
+            block_number
+        );
+    }
+}
+
 async fn output_mev_and_update_searcher_info<DB: DBWriter + LibmdbxReader>(
     database: &DB,
     mev_details: &Vec<Bundle>,
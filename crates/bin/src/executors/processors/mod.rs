@@ -1,4 +1,6 @@
+pub mod auction;
 pub mod mev;
+pub mod stream;
 
 use brontes_database::libmdbx::{DBWriter, LibmdbxReader};
 use brontes_inspect::Inspector;
@@ -10,6 +12,7 @@ pub trait Processor: Send + Sync + 'static + Unpin + Copy + Clone {
     type InspectType: Send + Sync + Unpin;
 
     fn process_results<DB: DBWriter + LibmdbxReader>(
+        self,
         db: &'static DB,
         inspectors: &'static [&dyn Inspector<Result = Self::InspectType>],
         data: MultiBlockData,
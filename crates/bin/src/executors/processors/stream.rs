@@ -0,0 +1,251 @@
+//! Live stream of the mev found by the [`MevProcessor`](super::MevProcessor).
+//!
+//! Every processed block is published as a `block` event followed by a
+//! `bundle` event for each bundle passing the [`MevStreamFilter`]. Events are
+//! json encoded once and fanned out to every sink, a websocket server and / or
+//! a newline delimited json writer. The stream is built from the run arguments
+//! and handed to the processor, which publishes to it.
+
+use std::{net::SocketAddr, path::PathBuf, sync::Arc};
+
+use brontes_types::mev::{Bundle, MevBlock, MevType};
+use eyre::WrapErr;
+use futures::SinkExt;
+use serde::Serialize;
+use tokio::{
+    io::{AsyncWrite, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    sync::broadcast::{self, error::RecvError},
+};
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, info, warn};
+
+/// Amount of events a slow sink can fall behind before it starts skipping.
+const STREAM_CAPACITY: usize = 4096;
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum MevStreamEvent<'a> {
+    Block(&'a MevBlock),
+    Bundle(&'a Bundle),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MevStreamFilter {
+    /// Only stream bundles of these types, all types if `None`
+    pub mev_types:      Option<Vec<MevType>>,
+    /// Only stream bundles with at least this profit
+    pub min_profit_usd: Option<f64>,
+}
+
+impl MevStreamFilter {
+    pub fn matches(&self, bundle: &Bundle) -> bool {
+        self.mev_types
+            .as_ref()
+            .map_or(true, |types| types.contains(&bundle.mev_type()))
+            && self
+                .min_profit_usd
+                .map_or(true, |min| bundle.header.profit_usd >= min)
+    }
+}
+
+#[derive(Debug)]
+pub struct MevStream {
+    tx:     broadcast::Sender<Arc<str>>,
+    filter: MevStreamFilter,
+}
+
+impl MevStream {
+    pub fn new(filter: MevStreamFilter) -> Self {
+        let (tx, _) = broadcast::channel(STREAM_CAPACITY);
+        Self { tx, filter }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<Arc<str>> {
+        self.tx.subscribe()
+    }
+
+    /// Publishes the results of a block to every sink.
+    pub fn publish(&self, block: &MevBlock, bundles: &[Bundle]) {
+        std::iter::once(MevStreamEvent::Block(block))
+            .chain(
+                bundles
+                    .iter()
+                    .filter(|bundle| self.filter.matches(bundle))
+                    .map(MevStreamEvent::Bundle),
+            )
+            .for_each(|event| match serde_json::to_string(&event) {
+                // errors only when there are no sinks subscribed
+                Ok(json) => {
+                    let _ = self.tx.send(json.into());
+                }
+                Err(e) => warn!(err=%e, block=block.block_number, "failed to encode mev event"),
+            });
+    }
+}
+
+/// Accepts websocket connections on the given address and forwards every
+/// event to them as a text message.
+pub async fn serve_websocket(stream: &'static MevStream, addr: SocketAddr) -> eyre::Result<()> {
+    let listener = TcpListener::bind(addr)
+        .await
+        .wrap_err("Could not bind to address")?;
+    info!(target: "brontes", %addr, "streaming mev over websocket");
+
+    loop {
+        let (socket, peer) = listener.accept().await?;
+        tokio::spawn(async move {
+            if let Err(e) = forward_to_websocket(stream.subscribe(), socket).await {
+                debug!(target: "brontes::stream", %peer, err=%e, "websocket connection closed");
+            }
+        });
+    }
+}
+
+async fn forward_to_websocket(
+    mut rx: broadcast::Receiver<Arc<str>>,
+    socket: TcpStream,
+) -> eyre::Result<()> {
+    let mut ws = tokio_tungstenite::accept_async(socket).await?;
+
+    loop {
+        match rx.recv().await {
+            Ok(event) => ws.send(Message::Text(event.to_string())).await?,
+            Err(RecvError::Lagged(skipped)) => {
+                warn!(target: "brontes::stream", skipped, "websocket client lagging, skipped events")
+            }
+            Err(RecvError::Closed) => return Ok(()),
+        }
+    }
+}
+
+/// Where the newline delimited json events are written to.
+#[derive(Debug, Clone)]
+pub enum NdjsonSink {
+    Stdout,
+    File(PathBuf),
+}
+
+impl From<String> for NdjsonSink {
+    fn from(value: String) -> Self {
+        if value == "-" {
+            Self::Stdout
+        } else {
+            Self::File(value.into())
+        }
+    }
+}
+
+/// Writes every event as a line of json, appending when writing to a file.
+pub async fn write_ndjson(stream: &'static MevStream, sink: NdjsonSink) -> eyre::Result<()> {
+    let mut writer: Box<dyn AsyncWrite + Send + Unpin> = match &sink {
+        NdjsonSink::Stdout => Box::new(tokio::io::stdout()),
+        NdjsonSink::File(path) => Box::new(
+            tokio::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .await
+                .wrap_err_with(|| format!("Could not open {}", path.display()))?,
+        ),
+    };
+    info!(target: "brontes", ?sink, "streaming mev as ndjson");
+
+    write_lines(stream.subscribe(), &mut writer).await
+}
+
+async fn write_lines<W: AsyncWrite + Unpin>(
+    mut rx: broadcast::Receiver<Arc<str>>,
+    writer: &mut W,
+) -> eyre::Result<()> {
+    loop {
+        match rx.recv().await {
+            Ok(event) => {
+                writer.write_all(event.as_bytes()).await?;
+                writer.write_all(b"\n").await?;
+                writer.flush().await?;
+            }
+            Err(RecvError::Lagged(skipped)) => {
+                warn!(target: "brontes::stream", skipped, "ndjson sink lagging, skipped events")
+            }
+            Err(RecvError::Closed) => return Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brontes_types::mev::{BundleData, BundleHeader};
+
+    use super::*;
+
+    fn bundle(mev_type: MevType, profit_usd: f64) -> Bundle {
+        Bundle {
+            header: BundleHeader { mev_type, profit_usd, ..Default::default() },
+            data:   BundleData::default(),
+        }
+    }
+
+    #[test]
+    fn test_filter_matches() {
+        let sandwich = bundle(MevType::Sandwich, 10.0);
+        let arb = bundle(MevType::AtomicArb, 100.0);
+
+        assert!(MevStreamFilter::default().matches(&sandwich));
+
+        let by_type = MevStreamFilter {
+            mev_types:      Some(vec![MevType::AtomicArb]),
+            min_profit_usd: None,
+        };
+        assert!(!by_type.matches(&sandwich));
+        assert!(by_type.matches(&arb));
+
+        let by_profit = MevStreamFilter { mev_types: None, min_profit_usd: Some(10.0) };
+        assert!(by_profit.matches(&sandwich));
+        assert!(!by_profit.matches(&bundle(MevType::Sandwich, 9.99)));
+
+        let both = MevStreamFilter {
+            mev_types:      Some(vec![MevType::Sandwich]),
+            min_profit_usd: Some(50.0),
+        };
+        assert!(!both.matches(&sandwich));
+        assert!(!both.matches(&arb));
+        assert!(both.matches(&bundle(MevType::Sandwich, 50.0)));
+    }
+
+    #[tokio::test]
+    async fn test_ndjson_output() {
+        let stream =
+            MevStream::new(MevStreamFilter { mev_types: None, min_profit_usd: Some(1.0) });
+        let rx = stream.subscribe();
+
+        let block = MevBlock { block_number: 19_000_000, ..Default::default() };
+        stream.publish(
+            &block,
+            &[
+                bundle(MevType::Sandwich, 5.0),
+                bundle(MevType::AtomicArb, 0.5),
+                bundle(MevType::Liquidation, 1.0),
+            ],
+        );
+        // closes the channel so the writer returns once it's drained
+        drop(stream);
+
+        let mut out = Vec::new();
+        write_lines(rx, &mut out).await.unwrap();
+
+        let events = String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0]["type"], "block");
+        assert_eq!(events[0]["data"]["block_number"], 19_000_000);
+        assert_eq!(events[1]["type"], "bundle");
+        assert_eq!(events[1]["data"]["header"]["profit_usd"], 5.0);
+        assert_eq!(events[2]["type"], "bundle");
+        assert_eq!(events[2]["data"]["header"]["profit_usd"], 1.0);
+    }
+}
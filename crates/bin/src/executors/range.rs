@@ -1,5 +1,4 @@
 use std::{
-    marker::PhantomData,
     pin::Pin,
     task::{Context, Poll},
 };
@@ -36,7 +35,7 @@ pub struct RangeExecutorWithPricing<
     inspectors:     &'static [&'static dyn Inspector<Result = P::InspectType>],
     progress_bar:   Option<ProgressBar>,
     global_metrics: Option<GlobalRangeMetrics>,
-    _p:             PhantomData<P>,
+    processor:      P,
 }
 
 impl<T: TracingProvider, DB: LibmdbxReader + DBWriter, CH: ClickhouseHandle, P: Processor>
@@ -51,6 +50,7 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter, CH: ClickhouseHandle, P:
         inspectors: &'static [&'static dyn Inspector<Result = P::InspectType>],
         progress_bar: Option<ProgressBar>,
         global_metrics: Option<GlobalRangeMetrics>,
+        processor: P,
     ) -> Self {
         Self {
             id,
@@ -62,7 +62,7 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter, CH: ClickhouseHandle, P:
             inspectors,
             progress_bar,
             global_metrics,
-            _p: PhantomData,
+            processor,
         }
     }
 
@@ -98,13 +98,16 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter, CH: ClickhouseHandle, P:
         let metrics = self.global_metrics.clone();
         let inspectors = self.inspectors;
         let libmdbx = self.libmdbx;
+        let processor = self.processor;
         self.insert_futures.push(Box::pin(async move {
             if let Some(metrics) = metrics {
                 metrics
-                    .meter_processing(|| Box::pin(P::process_results(libmdbx, inspectors, data)))
+                    .meter_processing(|| {
+                        Box::pin(processor.process_results(libmdbx, inspectors, data))
+                    })
                     .await
             } else {
-                P::process_results(libmdbx, inspectors, data).await
+                processor.process_results(libmdbx, inspectors, data).await
             }
         }));
     }
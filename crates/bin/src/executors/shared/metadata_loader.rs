@@ -177,8 +177,10 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
         meta.builder_info = libmdbx
             .try_fetch_builder_info(tree.header.beneficiary)
             .expect("failed to fetch builder info table in libmdbx");
+        label_private_flow_from_mempool(&tree, libmdbx, &mut meta);
 
         meta.cex_trades = self.load_cex_trades(libmdbx, block);
+        meta.perp_tickers = libmdbx.get_perp_tickers(block).ok();
 
         tracing::debug!(?block, "waiting for dex price");
 
@@ -208,9 +210,11 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
         meta.builder_info = libmdbx
             .try_fetch_builder_info(tree.header.beneficiary)
             .expect("failed to fetch builder info table in libmdbx");
+        label_private_flow_from_mempool(&tree, libmdbx, &mut meta);
 
         let mut meta = meta.into_full_metadata(DexQuotes(vec![]));
         meta.cex_trades = self.load_cex_trades(libmdbx, block);
+        meta.perp_tickers = libmdbx.get_perp_tickers(block).ok();
 
         self.result_buf
             .push_back(BlockData { metadata: meta.into(), tree: tree.into() });
@@ -235,8 +239,10 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
         meta.builder_info = libmdbx
             .try_fetch_builder_info(tree.header.beneficiary)
             .expect("failed to fetch builder info table in libmdbx");
+        label_private_flow_from_mempool(&tree, libmdbx, &mut meta);
 
         meta.cex_trades = self.load_cex_trades(libmdbx, block);
+        meta.perp_tickers = libmdbx.get_perp_tickers(block).ok();
 
         tracing::debug!(?block, "caching result buf");
         self.result_buf
@@ -319,6 +325,19 @@ impl<T: TracingProvider, CH: ClickhouseHandle> MetadataLoader<T, CH> {
             };
 
             meta.cex_trades = Some(trades);
+            // perp tickers are optional for the cex-dex arbs, so a failed
+            // download isn't retried
+            meta.perp_tickers = clickhouse
+                .get_perp_tickers(brontes_database::libmdbx::cex_utils::CexRangeOrArbitrary::Range(
+                    block, block,
+                ))
+                .await
+                .inspect_err(|e| {
+                    error!(err=?e);
+                })
+                .ok()
+                .and_then(|tickers| tickers.into_iter().find(|t| t.key == block))
+                .map(|tickers| tickers.value);
             meta.builder_info = builder_info;
             (block, tree, meta)
         });
@@ -366,3 +385,24 @@ impl<T: TracingProvider, CH: ClickhouseHandle> Stream for MetadataLoader<T, CH>
         }
     }
 }
+
+/// Metadata initialized from clickhouse already carries the private flow of
+/// the block. Otherwise it is derived from the first seen times recorded by
+/// the local mempool listener.
+fn label_private_flow_from_mempool<DB: LibmdbxReader>(
+    tree: &BlockTree<Action>,
+    libmdbx: &'static DB,
+    meta: &mut Metadata,
+) {
+    if !meta.private_flow.is_empty() {
+        return
+    }
+
+    let block_txes = tree.get_hashes();
+    match libmdbx.fetch_mempool_first_seen(&block_txes) {
+        Ok(first_seen) => meta
+            .block_metadata
+            .set_private_flow_from_mempool(&block_txes, &first_seen),
+        Err(err) => tracing::warn!(%err, "failed to load mempool first seen times"),
+    }
+}
@@ -1,10 +1,12 @@
 use std::{
-    marker::PhantomData,
+    collections::VecDeque,
     pin::Pin,
+    sync::atomic::Ordering::SeqCst,
     task::{Context, Poll},
     time::Duration,
 };
 
+use alloy_primitives::B256;
 use brontes_core::decoding::{Parser, TracingProvider};
 use brontes_database::{
     clickhouse::ClickhouseHandle,
@@ -12,7 +14,7 @@ use brontes_database::{
 };
 use brontes_inspect::Inspector;
 use brontes_types::MultiBlockData;
-use futures::{pin_mut, stream::FuturesUnordered, Future, StreamExt};
+use futures::{pin_mut, stream::FuturesUnordered, Future, FutureExt, StreamExt};
 use reth_tasks::shutdown::GracefulShutdown;
 use tokio::time::{interval, Interval};
 use tracing::debug;
@@ -20,21 +22,37 @@ use tracing::debug;
 use super::shared::state_collector::StateCollector;
 use crate::Processor;
 
+/// Amount of processed blocks that are checked for reorgs
+const REORG_DEPTH: usize = 64;
+
+/// Builds a fresh state collector starting at the given block
+pub type StateCollectorFactory<T, DB, CH> =
+    Box<dyn Fn(u64) -> StateCollector<T, DB, CH> + Send + 'static>;
+
+type ReorgFut = Pin<Box<dyn Future<Output = Option<u64>> + Send + 'static>>;
+type RewindFut = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
 pub struct TipInspector<
     T: TracingProvider,
     DB: LibmdbxReader + DBWriter,
     CH: ClickhouseHandle,
     P: Processor,
 > {
-    current_block:      u64,
-    back_from_tip:      u64,
-    parser:             &'static Parser<T, DB>,
-    state_collector:    StateCollector<T, DB, CH>,
-    database:           &'static DB,
-    inspectors:         &'static [&'static dyn Inspector<Result = P::InspectType>],
-    processing_futures: FuturesUnordered<Pin<Box<dyn Future<Output = ()> + Send + 'static>>>,
-    poll_interval:      Interval,
-    _p:                 PhantomData<P>,
+    current_block:       u64,
+    back_from_tip:       u64,
+    parser:              &'static Parser<T, DB>,
+    state_collector:     StateCollector<T, DB, CH>,
+    new_state_collector: StateCollectorFactory<T, DB, CH>,
+    database:            &'static DB,
+    inspectors:          &'static [&'static dyn Inspector<Result = P::InspectType>],
+    processing_futures:  FuturesUnordered<Pin<Box<dyn Future<Output = ()> + Send + 'static>>>,
+    poll_interval:       Interval,
+    /// hashes of the blocks that were processed, oldest first
+    processed_blocks:    VecDeque<(u64, B256)>,
+    reorg_check:         Option<ReorgFut>,
+    /// waits for in flight results before reverting the reorged blocks
+    rewind:              Option<RewindFut>,
+    processor:           P,
 }
 
 impl<T: TracingProvider, DB: DBWriter + LibmdbxReader, CH: ClickhouseHandle, P: Processor>
@@ -44,20 +62,26 @@ impl<T: TracingProvider, DB: DBWriter + LibmdbxReader, CH: ClickhouseHandle, P:
         current_block: u64,
         back_from_tip: u64,
         state_collector: StateCollector<T, DB, CH>,
+        new_state_collector: StateCollectorFactory<T, DB, CH>,
         parser: &'static Parser<T, DB>,
         database: &'static DB,
         inspectors: &'static [&'static dyn Inspector<Result = P::InspectType>],
+        processor: P,
     ) -> Self {
         Self {
             back_from_tip,
             state_collector,
+            new_state_collector,
             inspectors,
             current_block,
             parser,
             processing_futures: FuturesUnordered::new(),
             database,
             poll_interval: interval(Duration::from_secs(3)),
-            _p: PhantomData,
+            processed_blocks: VecDeque::with_capacity(REORG_DEPTH + 1),
+            reorg_check: None,
+            rewind: None,
+            processor,
         }
     }
 
@@ -74,6 +98,9 @@ impl<T: TracingProvider, DB: DBWriter + LibmdbxReader, CH: ClickhouseHandle, P:
             },
         }
 
+        if let Some(rewind) = tip.rewind.take() {
+            rewind.await;
+        }
         while tip.processing_futures.next().await.is_some() {}
 
         drop(graceful_guard);
@@ -116,12 +143,81 @@ impl<T: TracingProvider, DB: DBWriter + LibmdbxReader, CH: ClickhouseHandle, P:
 
     fn on_price_finish(&mut self, data: MultiBlockData) {
         debug!(target:"brontes::tip_inspector","Completed DEX pricing");
-        self.processing_futures.push(Box::pin(P::process_results(
-            self.database,
-            self.inspectors,
-            data,
-        )));
+        let header = &data.get_most_recent_block().tree.header;
+
+        // a block that was reorged out while it was being traced and priced is
+        // caught by the next reorg check
+        self.processed_blocks
+            .push_back((header.number, header.hash_slow()));
+        if self.processed_blocks.len() > REORG_DEPTH {
+            self.processed_blocks.pop_front();
+        }
+
+        self.processing_futures
+            .push(Box::pin(
+                self.processor
+                    .process_results(self.database, self.inspectors, data),
+            ));
+    }
+
+    fn start_reorg_check(&mut self) {
+        let parser = self.parser;
+        let processed = self.processed_blocks.iter().copied().collect();
+        self.reorg_check = Some(Box::pin(find_reorged_block(processed, move |block| {
+            parser.get_block_hash_for_number(block)
+        })));
+    }
+
+    /// Reverts everything written for the blocks from `block` onwards and
+    /// restarts processing from it with state rebuilt from `block - 1`.
+    fn rewind_to(&mut self, block: u64) {
+        let end_block = self.current_block.max(block + 1);
+        tracing::warn!(from=%block, to=%(end_block - 1), "reorg detected, reprocessing blocks");
+
+        // the pricer and block window hold state of the reorged blocks, so both
+        // are replaced instead of continuing on top of them
+        self.state_collector.get_shutdown().store(true, SeqCst);
+        self.state_collector = (self.new_state_collector)(block);
+
+        let database = self.database;
+        let processing = std::mem::take(&mut self.processing_futures);
+        self.rewind = Some(Box::pin(async move {
+            // results still being written would land after the revert
+            processing.collect::<Vec<_>>().await;
+
+            for block in block..end_block {
+                if let Err(e) = database.revert_block(block).await {
+                    tracing::error!(err=%e, %block, "failed to revert reorged block");
+                }
+            }
+        }));
+
+        self.processed_blocks
+            .retain(|(processed, _)| *processed < block);
+        self.current_block = block;
+    }
+}
+
+/// Walks back the processed blocks (oldest first) until one matches the
+/// canonical chain, returning the oldest block that was reorged out.
+async fn find_reorged_block<F, Fut>(processed: Vec<(u64, B256)>, canonical_hash: F) -> Option<u64>
+where
+    F: Fn(u64) -> Fut,
+    Fut: Future<Output = eyre::Result<Option<B256>>>,
+{
+    let mut reorged = None;
+    for (block, hash) in processed.into_iter().rev() {
+        match canonical_hash(block).await {
+            Ok(Some(canonical)) if canonical == hash => break,
+            Ok(_) => reorged = Some(block),
+            Err(e) => {
+                tracing::error!(err=%e, %block, "failed to fetch canonical block hash");
+                return None
+            }
+        }
     }
+
+    reorged
 }
 
 impl<T: TracingProvider, DB: DBWriter + LibmdbxReader, CH: ClickhouseHandle, P: Processor> Future
@@ -132,9 +228,30 @@ impl<T: TracingProvider, DB: DBWriter + LibmdbxReader, CH: ClickhouseHandle, P:
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         // given we pull the next block sync, we use this to trigger looking
         // for the next block.
-        while self.poll_interval.poll_tick(cx).is_ready() {}
+        let mut ticked = false;
+        while self.poll_interval.poll_tick(cx).is_ready() {
+            ticked = true;
+        }
+
+        if ticked && self.reorg_check.is_none() && self.rewind.is_none() {
+            self.start_reorg_check();
+        }
+
+        if let Some(Poll::Ready(reorged)) = self.reorg_check.as_mut().map(|f| f.poll_unpin(cx)) {
+            self.reorg_check = None;
+            if let Some(block) = reorged {
+                self.rewind_to(block);
+            }
+        }
 
-        if self.start_block_inspector() && self.state_collector.should_process_next_block() {
+        if let Some(Poll::Ready(())) = self.rewind.as_mut().map(|f| f.poll_unpin(cx)) {
+            self.rewind = None;
+        }
+
+        if self.rewind.is_none()
+            && self.start_block_inspector()
+            && self.state_collector.should_process_next_block()
+        {
             let block = self.current_block;
             tracing::info!(%block,"starting new tip block");
             self.state_collector.fetch_state_for(block, 0, None);
@@ -153,3 +270,52 @@ impl<T: TracingProvider, DB: DBWriter + LibmdbxReader, CH: ClickhouseHandle, P:
         Poll::Pending
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::B256;
+    use brontes_types::FastHashMap;
+
+    use super::find_reorged_block;
+
+    fn canonical(chain: &[(u64, u8)]) -> FastHashMap<u64, B256> {
+        chain
+            .iter()
+            .map(|(block, hash)| (*block, B256::repeat_byte(*hash)))
+            .collect()
+    }
+
+    async fn reorged(processed: &[(u64, u8)], chain: &FastHashMap<u64, B256>) -> Option<u64> {
+        let processed = processed
+            .iter()
+            .map(|(block, hash)| (*block, B256::repeat_byte(*hash)))
+            .collect();
+        find_reorged_block(processed, |block| std::future::ready(Ok(chain.get(&block).copied())))
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_no_reorg() {
+        let chain = canonical(&[(10, 1), (11, 2), (12, 3)]);
+        assert_eq!(reorged(&[(10, 1), (11, 2), (12, 3)], &chain).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_reorg_rewinds_to_oldest_replaced_block() {
+        let chain = canonical(&[(10, 1), (11, 7), (12, 8)]);
+        assert_eq!(reorged(&[(10, 1), (11, 2), (12, 3)], &chain).await, Some(11));
+    }
+
+    #[tokio::test]
+    async fn test_reorged_block_missing_from_new_chain() {
+        // the new chain is shorter than what was processed
+        let chain = canonical(&[(10, 1), (11, 7)]);
+        assert_eq!(reorged(&[(10, 1), (11, 2), (12, 3)], &chain).await, Some(11));
+    }
+
+    #[tokio::test]
+    async fn test_reorg_deeper_than_processed_blocks() {
+        let chain = canonical(&[(10, 5), (11, 6)]);
+        assert_eq!(reorged(&[(10, 1), (11, 2)], &chain).await, Some(10));
+    }
+}
@@ -54,14 +54,18 @@ fn run() -> eyre::Result<()> {
         .brontes_db_path
         .unwrap_or(env::var("BRONTES_DB_PATH").expect("No BRONTES_DB_PATH in .env"));
 
-    init_tracing(opt.verbosity.directive());
+    // the json of a replay goes to stdout, so the logs move out of its way
+    let log_stderr = opt.verbosity.log_stderr()
+        || matches!(&opt.command, Commands::Replay(replay) if replay.json);
+    init_tracing(opt.verbosity.directive(), log_stderr);
 
     let metrics_port = if opt.skip_prometheus { None } else { Some(opt.metrics_port) };
 
     match opt.command {
         Commands::Run(command) => {
+            let logs_to_stdout = opt.verbosity.logs_to_stdout();
             runner::run_command_until_exit(metrics_port, Duration::from_secs(3600), |ctx| {
-                command.execute(brontes_db_path, ctx)
+                command.execute(brontes_db_path, logs_to_stdout, ctx)
             })
         }
         Commands::Database(command) => {
@@ -69,21 +73,39 @@ fn run() -> eyre::Result<()> {
                 command.execute(brontes_db_path, ctx)
             })
         }
+        Commands::Replay(command) => {
+            runner::run_command_until_exit(None, Duration::from_secs(5), |ctx| {
+                command.execute(brontes_db_path, ctx)
+            })
+        }
+        Commands::Serve(command) => {
+            runner::run_command_until_exit(None, Duration::from_secs(5), |ctx| {
+                command.execute(brontes_db_path, ctx)
+            })
+        }
+        Commands::Mempool(command) => {
+            runner::run_command_until_exit(None, Duration::from_secs(5), |ctx| {
+                command.execute(brontes_db_path, ctx)
+            })
+        }
     }
 }
 
-fn init_tracing(verbosity: Directive) {
+fn init_tracing(verbosity: Directive, log_stderr: bool) {
+    let log_layer = if log_stderr {
+        brontes_tracing::stderr(verbosity)
+    } else {
+        brontes_tracing::stdout(verbosity)
+    };
+
     let layers = if cfg!(feature = "sorella-server") {
         vec![
-            brontes_tracing::stdout(verbosity),
+            log_layer,
             brontes_metrics::error_layer::BrontesErrorMetrics::default().boxed(),
             initialize_telegram_error_layer(),
         ]
     } else {
-        vec![
-            brontes_tracing::stdout(verbosity),
-            brontes_metrics::error_layer::BrontesErrorMetrics::default().boxed(),
-        ]
+        vec![log_layer, brontes_metrics::error_layer::BrontesErrorMetrics::default().boxed()]
     };
 
     brontes_tracing::init(layers);
@@ -0,0 +1,110 @@
+use brontes_macros::action_impl;
+use brontes_types::{
+    normalized_actions::NormalizedLiquidation, structured_trace::CallInfo, utils::ToScaledRational,
+    Protocol,
+};
+use malachite::{num::basic::traits::Zero, Rational};
+
+// Spark is a fork of Aave V3. It's registered on the pool proxy rather than
+// the implementation, so the pool is the called contract
+action_impl!(
+    Protocol::Spark,
+    crate::AaveV3::liquidationCallCall,
+    Liquidation,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: liquidationCallCall,
+    db_tx: &DB | {
+        let debt_info = db_tx.try_fetch_token_info(call_data.debtAsset)?;
+        let collateral_info = db_tx.try_fetch_token_info(call_data.collateralAsset)?;
+
+        let covered_debt = call_data.debtToCover.to_scaled_rational(debt_info.decimals);
+
+        Ok(NormalizedLiquidation {
+            protocol: Protocol::Spark,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            liquidator: info.msg_sender,
+            debtor: call_data.user,
+            collateral_asset: collateral_info,
+            debt_asset: debt_info,
+            covered_debt,
+            // filled in later
+            liquidated_collateral: Rational::ZERO,
+            collateral_discount: None,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{hex, Address, Bytes, U256};
+    use alloy_sol_types::SolCall;
+    use brontes_types::{
+        constants::{USDC_ADDRESS, WETH_ADDRESS},
+        normalized_actions::Action,
+        structured_trace::CallFrameInfo,
+    };
+
+    use super::*;
+    use crate::{test_utils::ClassifierTestUtils, AaveV3};
+
+    const POOL: Address = Address::new(hex!("c13e21b648a5ee794902342038ff3adab66be987"));
+    const LIQUIDATOR: Address = Address::new(hex!("80d4230c0a68fc59cb264329d3a717fcaa472a13"));
+    const BORROWER: Address = Address::new(hex!("e967954b9b48cb1a0079d76466e82c4d52a8f5d3"));
+
+    #[brontes_macros::test]
+    async fn test_spark_liquidation() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        classifier_utils.ensure_protocol(
+            Protocol::Spark,
+            POOL,
+            USDC_ADDRESS,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        let call_info = CallFrameInfo {
+            trace_idx:      0,
+            call_data:      AaveV3::liquidationCallCall {
+                collateralAsset: WETH_ADDRESS,
+                debtAsset:       USDC_ADDRESS,
+                user:            BORROWER,
+                debtToCover:     U256::from(2_900_000_000u64),
+                receiveAToken:   false,
+            }
+            .abi_encode()
+            .into(),
+            return_data:    Bytes::new(),
+            target_address: POOL,
+            from_address:   LIQUIDATOR,
+            logs:           &[],
+            delegate_logs:  vec![],
+            msg_sender:     LIQUIDATOR,
+            msg_value:      U256::ZERO,
+        };
+
+        // the seized collateral is filled in from the transfers of the call
+        let eq_action = Action::Liquidation(NormalizedLiquidation {
+            protocol:              Protocol::Spark,
+            trace_index:           0,
+            pool:                  POOL,
+            liquidator:            LIQUIDATOR,
+            debtor:                BORROWER,
+            collateral_asset:      classifier_utils.get_token_info(WETH_ADDRESS),
+            debt_asset:            classifier_utils.get_token_info(USDC_ADDRESS),
+            covered_debt:          Rational::from(2900),
+            liquidated_collateral: Rational::ZERO,
+            collateral_discount:   None,
+            msg_value:             U256::ZERO,
+        });
+
+        assert_eq!(classifier_utils.classify_call_frame(call_info), Some(eq_action));
+    }
+}
@@ -1,6 +1,9 @@
 use brontes_macros::action_impl;
 use brontes_types::{
-    normalized_actions::{NormalizedFlashLoan, NormalizedLiquidation},
+    normalized_actions::{
+        NormalizedFlashLoan, NormalizedLiquidation, NormalizedLoan, NormalizedRepayment,
+        NormalizedSupply, NormalizedWithdraw,
+    },
     structured_trace::CallInfo,
     utils::ToScaledRational,
     Protocol,
@@ -33,6 +36,7 @@ action_impl!(
             covered_debt,
             // filled in later
             liquidated_collateral: Rational::ZERO,
+            collateral_discount: None,
             msg_value: info.msg_value,
         })
     }
@@ -74,3 +78,328 @@ action_impl!(
 
     }
 );
+
+// the amounts are read from the logs as repay and withdraw accept
+// `type(uint256).max` to close out the whole position
+action_impl!(
+    Protocol::AaveV2,
+    crate::AaveV2::borrowCall,
+    Loan,
+    [..Borrow],
+    logs: true,
+    include_delegated_logs: true,
+    |
+    info: CallInfo,
+    log_data: AaveV2BorrowCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.borrow_field?;
+        let loaned_token = db_tx.try_fetch_token_info(logs.reserve)?;
+        let loan_amount = logs.amount.to_scaled_rational(loaned_token.decimals);
+
+        Ok(NormalizedLoan {
+            protocol: Protocol::AaveV2,
+            trace_index: info.trace_idx,
+            lender: info.from_address,
+            borrower: logs.onBehalfOf,
+            loaned_token,
+            loan_amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV2,
+    crate::AaveV2::depositCall,
+    Supply,
+    [..Deposit],
+    logs: true,
+    include_delegated_logs: true,
+    |
+    info: CallInfo,
+    log_data: AaveV2DepositCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.deposit_field?;
+        let supplied_token = db_tx.try_fetch_token_info(logs.reserve)?;
+        let supply_amount = logs.amount.to_scaled_rational(supplied_token.decimals);
+
+        Ok(NormalizedSupply {
+            protocol: Protocol::AaveV2,
+            trace_index: info.trace_idx,
+            pool: info.from_address,
+            supplier: logs.user,
+            on_behalf_of: logs.onBehalfOf,
+            supplied_token,
+            supply_amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV2,
+    crate::AaveV2::repayCall,
+    Repayment,
+    [..Repay],
+    logs: true,
+    include_delegated_logs: true,
+    |
+    info: CallInfo,
+    log_data: AaveV2RepayCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.repay_field?;
+        let repayed_token = db_tx.try_fetch_token_info(logs.reserve)?;
+        let repayment_amount = logs.amount.to_scaled_rational(repayed_token.decimals);
+
+        Ok(NormalizedRepayment {
+            protocol: Protocol::AaveV2,
+            trace_index: info.trace_idx,
+            lender: info.from_address,
+            borrower: logs.user,
+            payer: logs.repayer,
+            repayed_token,
+            repayment_amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV2,
+    crate::AaveV2::withdrawCall,
+    Withdraw,
+    [..Withdraw],
+    logs: true,
+    include_delegated_logs: true,
+    |
+    info: CallInfo,
+    log_data: AaveV2WithdrawCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.withdraw_field?;
+        let withdrawn_token = db_tx.try_fetch_token_info(logs.reserve)?;
+        let withdraw_amount = logs.amount.to_scaled_rational(withdrawn_token.decimals);
+
+        Ok(NormalizedWithdraw {
+            protocol: Protocol::AaveV2,
+            trace_index: info.trace_idx,
+            pool: info.from_address,
+            supplier: logs.user,
+            recipient: logs.to,
+            withdrawn_token,
+            withdraw_amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{hex, Address, Bytes, Log, LogData, U256};
+    use alloy_sol_types::{SolCall, SolEvent};
+    use brontes_types::{
+        normalized_actions::{
+            Action, NormalizedLoan, NormalizedRepayment, NormalizedSupply, NormalizedWithdraw,
+        },
+        structured_trace::CallFrameInfo,
+        Protocol,
+    };
+    use malachite::Rational;
+
+    use crate::{test_utils::ClassifierTestUtils, AaveV2};
+
+    const POOL: Address = Address::new(hex!("7d2768de32b0b80b7a3454c06bdac94a69ddc7a9"));
+    const USDC: Address = Address::new(hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"));
+    const USER: Address = Address::new(hex!("e967954b9b48cb1a0079d76466e82c4d52a8f5d3"));
+    const RECEIVER: Address = Address::new(hex!("80d4230c0a68fc59cb264329d3a717fcaa472a13"));
+
+    /// 1500 USDC
+    const AMOUNT: u64 = 1_500_000_000;
+
+    fn setup(classifier_utils: &ClassifierTestUtils) {
+        classifier_utils.ensure_protocol(
+            Protocol::AaveV2,
+            POOL,
+            USDC,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+    }
+
+    fn pool_call(call_data: Vec<u8>, logs: &[Log]) -> CallFrameInfo<'_> {
+        CallFrameInfo {
+            trace_idx: 0,
+            call_data: call_data.into(),
+            return_data: Bytes::new(),
+            target_address: POOL,
+            from_address: POOL,
+            logs,
+            delegate_logs: vec![],
+            msg_sender: USER,
+            msg_value: U256::ZERO,
+        }
+    }
+
+    fn pool_log(data: LogData) -> Log {
+        Log { address: POOL, data }
+    }
+
+    #[brontes_macros::test]
+    async fn test_aave_v2_borrow() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        setup(&classifier_utils);
+
+        let logs = [pool_log(
+            AaveV2::Borrow {
+                reserve:        USDC,
+                user:           RECEIVER,
+                onBehalfOf:     USER,
+                amount:         U256::from(AMOUNT),
+                borrowRateMode: U256::from(2),
+                borrowRate:     U256::from(10u128.pow(26)),
+                referral:       0,
+            }
+            .encode_log_data(),
+        )];
+        let call_data = AaveV2::borrowCall {
+            asset:            USDC,
+            amount:           U256::from(AMOUNT),
+            interestRateMode: U256::from(2),
+            referralCode:     0,
+            onBehalfOf:       USER,
+        }
+        .abi_encode();
+
+        let eq_action = Action::Loan(NormalizedLoan {
+            protocol:     Protocol::AaveV2,
+            trace_index:  0,
+            lender:       POOL,
+            borrower:     USER,
+            loaned_token: classifier_utils.get_token_info(USDC),
+            loan_amount:  Rational::from(1500),
+            msg_value:    U256::ZERO,
+        });
+
+        assert_eq!(
+            classifier_utils.classify_call_frame(pool_call(call_data, &logs)),
+            Some(eq_action)
+        );
+    }
+
+    #[brontes_macros::test]
+    async fn test_aave_v2_deposit() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        setup(&classifier_utils);
+
+        let logs = [pool_log(
+            AaveV2::Deposit {
+                reserve:    USDC,
+                user:       RECEIVER,
+                onBehalfOf: USER,
+                amount:     U256::from(AMOUNT),
+                referral:   0,
+            }
+            .encode_log_data(),
+        )];
+        let call_data = AaveV2::depositCall {
+            asset:        USDC,
+            amount:       U256::from(AMOUNT),
+            onBehalfOf:   USER,
+            referralCode: 0,
+        }
+        .abi_encode();
+
+        let eq_action = Action::Supply(NormalizedSupply {
+            protocol:       Protocol::AaveV2,
+            trace_index:    0,
+            pool:           POOL,
+            supplier:       RECEIVER,
+            on_behalf_of:   USER,
+            supplied_token: classifier_utils.get_token_info(USDC),
+            supply_amount:  Rational::from(1500),
+            msg_value:      U256::ZERO,
+        });
+
+        assert_eq!(
+            classifier_utils.classify_call_frame(pool_call(call_data, &logs)),
+            Some(eq_action)
+        );
+    }
+
+    #[brontes_macros::test]
+    async fn test_aave_v2_repay_max() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        setup(&classifier_utils);
+
+        // repaying with `type(uint256).max` closes out the debt, the amount
+        // repaid is only in the log
+        let logs = [pool_log(
+            AaveV2::Repay {
+                reserve: USDC,
+                user:    USER,
+                repayer: RECEIVER,
+                amount:  U256::from(AMOUNT),
+            }
+            .encode_log_data(),
+        )];
+        let call_data = AaveV2::repayCall {
+            asset:      USDC,
+            amount:     U256::MAX,
+            rateMode:   U256::from(2),
+            onBehalfOf: USER,
+        }
+        .abi_encode();
+
+        let eq_action = Action::Repayment(NormalizedRepayment {
+            protocol:         Protocol::AaveV2,
+            trace_index:      0,
+            lender:           POOL,
+            borrower:         USER,
+            payer:            RECEIVER,
+            repayed_token:    classifier_utils.get_token_info(USDC),
+            repayment_amount: Rational::from(1500),
+            msg_value:        U256::ZERO,
+        });
+
+        assert_eq!(
+            classifier_utils.classify_call_frame(pool_call(call_data, &logs)),
+            Some(eq_action)
+        );
+    }
+
+    #[brontes_macros::test]
+    async fn test_aave_v2_withdraw_max() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        setup(&classifier_utils);
+
+        let logs = [pool_log(
+            AaveV2::Withdraw {
+                reserve: USDC,
+                user:    USER,
+                to:      RECEIVER,
+                amount:  U256::from(AMOUNT),
+            }
+            .encode_log_data(),
+        )];
+        let call_data =
+            AaveV2::withdrawCall { asset: USDC, amount: U256::MAX, to: RECEIVER }.abi_encode();
+
+        let eq_action = Action::Withdraw(NormalizedWithdraw {
+            protocol:        Protocol::AaveV2,
+            trace_index:     0,
+            pool:            POOL,
+            supplier:        USER,
+            recipient:       RECEIVER,
+            withdrawn_token: classifier_utils.get_token_info(USDC),
+            withdraw_amount: Rational::from(1500),
+            msg_value:       U256::ZERO,
+        });
+
+        assert_eq!(
+            classifier_utils.classify_call_frame(pool_call(call_data, &logs)),
+            Some(eq_action)
+        );
+    }
+}
@@ -1,6 +1,9 @@
 use brontes_macros::action_impl;
 use brontes_types::{
-    normalized_actions::{NormalizedFlashLoan, NormalizedLiquidation},
+    normalized_actions::{
+        NormalizedFlashLoan, NormalizedLiquidation, NormalizedLoan, NormalizedRepayment,
+        NormalizedSupply, NormalizedWithdraw,
+    },
     structured_trace::CallInfo,
     utils::ToScaledRational,
     Protocol,
@@ -34,6 +37,7 @@ action_impl!(
             covered_debt,
             // filled in later
             liquidated_collateral: Rational::ZERO,
+            collateral_discount: None,
             msg_value: info.msg_value,
         })
     }
@@ -113,16 +117,285 @@ action_impl!(
     }
 );
 
+// the amounts are read from the logs as repay and withdraw accept
+// `type(uint256).max` to close out the whole position
+action_impl!(
+    Protocol::AaveV3,
+    crate::AaveV3::borrowCall,
+    Loan,
+    [..Borrow],
+    logs: true,
+    include_delegated_logs: true,
+    |
+    info: CallInfo,
+    log_data: AaveV3BorrowCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.borrow_field?;
+        let loaned_token = db_tx.try_fetch_token_info(logs.reserve)?;
+        let loan_amount = logs.amount.to_scaled_rational(loaned_token.decimals);
+
+        Ok(NormalizedLoan {
+            protocol: Protocol::AaveV3,
+            trace_index: info.trace_idx,
+            lender: info.from_address,
+            borrower: logs.onBehalfOf,
+            loaned_token,
+            loan_amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV3,
+    crate::AaveV3::supplyCall,
+    Supply,
+    [..Supply],
+    logs: true,
+    include_delegated_logs: true,
+    |
+    info: CallInfo,
+    log_data: AaveV3SupplyCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.supply_field?;
+        let supplied_token = db_tx.try_fetch_token_info(logs.reserve)?;
+        let supply_amount = logs.amount.to_scaled_rational(supplied_token.decimals);
+
+        Ok(NormalizedSupply {
+            protocol: Protocol::AaveV3,
+            trace_index: info.trace_idx,
+            pool: info.from_address,
+            supplier: logs.user,
+            on_behalf_of: logs.onBehalfOf,
+            supplied_token,
+            supply_amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV3,
+    crate::AaveV3::supplyWithPermitCall,
+    Supply,
+    [..Supply],
+    logs: true,
+    include_delegated_logs: true,
+    |
+    info: CallInfo,
+    log_data: AaveV3SupplyWithPermitCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.supply_field?;
+        let supplied_token = db_tx.try_fetch_token_info(logs.reserve)?;
+        let supply_amount = logs.amount.to_scaled_rational(supplied_token.decimals);
+
+        Ok(NormalizedSupply {
+            protocol: Protocol::AaveV3,
+            trace_index: info.trace_idx,
+            pool: info.from_address,
+            supplier: logs.user,
+            on_behalf_of: logs.onBehalfOf,
+            supplied_token,
+            supply_amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV3,
+    crate::AaveV3::depositCall,
+    Supply,
+    [..Supply],
+    logs: true,
+    include_delegated_logs: true,
+    |
+    info: CallInfo,
+    log_data: AaveV3DepositCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.supply_field?;
+        let supplied_token = db_tx.try_fetch_token_info(logs.reserve)?;
+        let supply_amount = logs.amount.to_scaled_rational(supplied_token.decimals);
+
+        Ok(NormalizedSupply {
+            protocol: Protocol::AaveV3,
+            trace_index: info.trace_idx,
+            pool: info.from_address,
+            supplier: logs.user,
+            on_behalf_of: logs.onBehalfOf,
+            supplied_token,
+            supply_amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV3,
+    crate::AaveV3::repayCall,
+    Repayment,
+    [..Repay],
+    logs: true,
+    include_delegated_logs: true,
+    |
+    info: CallInfo,
+    log_data: AaveV3RepayCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.repay_field?;
+        let repayed_token = db_tx.try_fetch_token_info(logs.reserve)?;
+        let repayment_amount = logs.amount.to_scaled_rational(repayed_token.decimals);
+
+        Ok(NormalizedRepayment {
+            protocol: Protocol::AaveV3,
+            trace_index: info.trace_idx,
+            lender: info.from_address,
+            borrower: logs.user,
+            payer: logs.repayer,
+            repayed_token,
+            repayment_amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV3,
+    crate::AaveV3::repayWithPermitCall,
+    Repayment,
+    [..Repay],
+    logs: true,
+    include_delegated_logs: true,
+    |
+    info: CallInfo,
+    log_data: AaveV3RepayWithPermitCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.repay_field?;
+        let repayed_token = db_tx.try_fetch_token_info(logs.reserve)?;
+        let repayment_amount = logs.amount.to_scaled_rational(repayed_token.decimals);
+
+        Ok(NormalizedRepayment {
+            protocol: Protocol::AaveV3,
+            trace_index: info.trace_idx,
+            lender: info.from_address,
+            borrower: logs.user,
+            payer: logs.repayer,
+            repayed_token,
+            repayment_amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV3,
+    crate::AaveV3::repayWithATokensCall,
+    Repayment,
+    [..Repay],
+    logs: true,
+    include_delegated_logs: true,
+    |
+    info: CallInfo,
+    log_data: AaveV3RepayWithATokensCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.repay_field?;
+        let repayed_token = db_tx.try_fetch_token_info(logs.reserve)?;
+        let repayment_amount = logs.amount.to_scaled_rational(repayed_token.decimals);
+
+        Ok(NormalizedRepayment {
+            protocol: Protocol::AaveV3,
+            trace_index: info.trace_idx,
+            lender: info.from_address,
+            borrower: logs.user,
+            payer: logs.repayer,
+            repayed_token,
+            repayment_amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV3,
+    crate::AaveV3::withdrawCall,
+    Withdraw,
+    [..Withdraw],
+    logs: true,
+    include_delegated_logs: true,
+    |
+    info: CallInfo,
+    log_data: AaveV3WithdrawCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.withdraw_field?;
+        let withdrawn_token = db_tx.try_fetch_token_info(logs.reserve)?;
+        let withdraw_amount = logs.amount.to_scaled_rational(withdrawn_token.decimals);
+
+        Ok(NormalizedWithdraw {
+            protocol: Protocol::AaveV3,
+            trace_index: info.trace_idx,
+            pool: info.from_address,
+            supplier: logs.user,
+            recipient: logs.to,
+            withdrawn_token,
+            withdraw_amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
 #[cfg(test)]
 mod tests {
-    use alloy_primitives::{hex, Address, B256, U256};
+    use alloy_primitives::{hex, Address, Bytes, Log, LogData, B256, U256};
+    use alloy_sol_types::{SolCall, SolEvent};
     use brontes_types::{
-        normalized_actions::{Action, NormalizedLiquidation},
+        normalized_actions::{
+            Action, NormalizedLiquidation, NormalizedLoan, NormalizedRepayment, NormalizedSupply,
+            NormalizedWithdraw,
+        },
+        structured_trace::CallFrameInfo,
         Protocol, TreeSearchBuilder,
     };
     use malachite::Rational;
 
-    use crate::test_utils::ClassifierTestUtils;
+    use crate::{test_utils::ClassifierTestUtils, AaveV3};
+
+    const POOL: Address = Address::new(hex!("87870bca3f3fd6335c3f4ce8392d69350b4fa4e2"));
+    const USDC: Address = Address::new(hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"));
+    const USER: Address = Address::new(hex!("e967954b9b48cb1a0079d76466e82c4d52a8f5d3"));
+    const RECEIVER: Address = Address::new(hex!("80d4230c0a68fc59cb264329d3a717fcaa472a13"));
+
+    /// 1500 USDC
+    const AMOUNT: u64 = 1_500_000_000;
+
+    fn setup(classifier_utils: &ClassifierTestUtils) {
+        classifier_utils.ensure_protocol(
+            Protocol::AaveV3,
+            POOL,
+            USDC,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+    }
+
+    fn pool_call(call_data: Vec<u8>, logs: &[Log]) -> CallFrameInfo<'_> {
+        CallFrameInfo {
+            trace_idx: 0,
+            call_data: call_data.into(),
+            return_data: Bytes::new(),
+            target_address: POOL,
+            from_address: POOL,
+            logs,
+            delegate_logs: vec![],
+            msg_sender: USER,
+            msg_value: U256::ZERO,
+        }
+    }
+
+    fn pool_log(data: LogData) -> Log {
+        Log { address: POOL, data }
+    }
 
     #[brontes_macros::test]
     async fn test_aave_v3_liquidation() {
@@ -142,6 +415,7 @@ mod tests {
             liquidator:            Address::from(hex!("80d4230c0a68fc59cb264329d3a717fcaa472a13")),
             pool:                  Address::from(hex!("87870bca3f3fd6335c3f4ce8392d69350b4fa4e2")),
             trace_index:           6,
+            collateral_discount:   None,
             msg_value:             U256::ZERO,
         });
 
@@ -155,4 +429,162 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[brontes_macros::test]
+    async fn test_aave_v3_borrow() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        setup(&classifier_utils);
+
+        let logs = [pool_log(
+            AaveV3::Borrow {
+                reserve:          USDC,
+                user:             RECEIVER,
+                onBehalfOf:       USER,
+                amount:           U256::from(AMOUNT),
+                interestRateMode: 2,
+                borrowRate:       U256::from(10u128.pow(26)),
+                referralCode:     0,
+            }
+            .encode_log_data(),
+        )];
+        let call_data = AaveV3::borrowCall {
+            asset:            USDC,
+            amount:           U256::from(AMOUNT),
+            interestRateMode: U256::from(2),
+            referralCode:     0,
+            onBehalfOf:       USER,
+        }
+        .abi_encode();
+
+        let eq_action = Action::Loan(NormalizedLoan {
+            protocol:     Protocol::AaveV3,
+            trace_index:  0,
+            lender:       POOL,
+            borrower:     USER,
+            loaned_token: classifier_utils.get_token_info(USDC),
+            loan_amount:  Rational::from(1500),
+            msg_value:    U256::ZERO,
+        });
+
+        assert_eq!(
+            classifier_utils.classify_call_frame(pool_call(call_data, &logs)),
+            Some(eq_action)
+        );
+    }
+
+    #[brontes_macros::test]
+    async fn test_aave_v3_supply() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        setup(&classifier_utils);
+
+        let logs = [pool_log(
+            AaveV3::Supply {
+                reserve:      USDC,
+                user:         RECEIVER,
+                onBehalfOf:   USER,
+                amount:       U256::from(AMOUNT),
+                referralCode: 0,
+            }
+            .encode_log_data(),
+        )];
+        let call_data = AaveV3::supplyCall {
+            asset:        USDC,
+            amount:       U256::from(AMOUNT),
+            onBehalfOf:   USER,
+            referralCode: 0,
+        }
+        .abi_encode();
+
+        let eq_action = Action::Supply(NormalizedSupply {
+            protocol:       Protocol::AaveV3,
+            trace_index:    0,
+            pool:           POOL,
+            supplier:       RECEIVER,
+            on_behalf_of:   USER,
+            supplied_token: classifier_utils.get_token_info(USDC),
+            supply_amount:  Rational::from(1500),
+            msg_value:      U256::ZERO,
+        });
+
+        assert_eq!(
+            classifier_utils.classify_call_frame(pool_call(call_data, &logs)),
+            Some(eq_action)
+        );
+    }
+
+    #[brontes_macros::test]
+    async fn test_aave_v3_repay_max() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        setup(&classifier_utils);
+
+        // repaying with `type(uint256).max` closes out the debt, the amount
+        // repaid is only in the log
+        let logs = [pool_log(
+            AaveV3::Repay {
+                reserve:    USDC,
+                user:       USER,
+                repayer:    RECEIVER,
+                amount:     U256::from(AMOUNT),
+                useATokens: false,
+            }
+            .encode_log_data(),
+        )];
+        let call_data = AaveV3::repayCall {
+            asset:            USDC,
+            amount:           U256::MAX,
+            interestRateMode: U256::from(2),
+            onBehalfOf:       USER,
+        }
+        .abi_encode();
+
+        let eq_action = Action::Repayment(NormalizedRepayment {
+            protocol:         Protocol::AaveV3,
+            trace_index:      0,
+            lender:           POOL,
+            borrower:         USER,
+            payer:            RECEIVER,
+            repayed_token:    classifier_utils.get_token_info(USDC),
+            repayment_amount: Rational::from(1500),
+            msg_value:        U256::ZERO,
+        });
+
+        assert_eq!(
+            classifier_utils.classify_call_frame(pool_call(call_data, &logs)),
+            Some(eq_action)
+        );
+    }
+
+    #[brontes_macros::test]
+    async fn test_aave_v3_withdraw_max() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        setup(&classifier_utils);
+
+        let logs = [pool_log(
+            AaveV3::Withdraw {
+                reserve: USDC,
+                user:    USER,
+                to:      RECEIVER,
+                amount:  U256::from(AMOUNT),
+            }
+            .encode_log_data(),
+        )];
+        let call_data =
+            AaveV3::withdrawCall { asset: USDC, amount: U256::MAX, to: RECEIVER }.abi_encode();
+
+        let eq_action = Action::Withdraw(NormalizedWithdraw {
+            protocol:        Protocol::AaveV3,
+            trace_index:     0,
+            pool:            POOL,
+            supplier:        USER,
+            recipient:       RECEIVER,
+            withdrawn_token: classifier_utils.get_token_info(USDC),
+            withdraw_amount: Rational::from(1500),
+            msg_value:       U256::ZERO,
+        });
+
+        assert_eq!(
+            classifier_utils.classify_call_frame(pool_call(call_data, &logs)),
+            Some(eq_action)
+        );
+    }
 }
@@ -210,7 +210,8 @@ action_impl!(
             trace_index: info.trace_idx,
             protocol: Protocol::BalancerV2,
             pool_address,
-            tokens: logs.tokens
+            tokens: logs.tokens,
+            pool_key: None,
         })
     }
 );
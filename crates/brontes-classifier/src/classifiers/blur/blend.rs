@@ -0,0 +1,80 @@
+use alloy_primitives::U256;
+use brontes_macros::action_impl;
+use brontes_types::{
+    normalized_actions::{NftTradeKind, NormalizedNftTrade},
+    structured_trace::CallInfo,
+    Protocol, ToScaledRational,
+};
+use eyre::eyre;
+use malachite::{num::basic::traits::Zero, Rational};
+
+use super::{fee_amount, fetch_payment_token, BLUR_POOL_ADDRESS};
+
+action_impl!(
+    Protocol::BlurBlend,
+    crate::BlurBlend::buyLockedCall,
+    NftTrade,
+    [..BuyLocked],
+    call_data: true,
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo,
+    call_data: buyLockedCall,
+    log_data: BlurBlendBuyLockedCallLogs,
+    db_tx: &DB| {
+        let bought = log_data.buy_locked_field?;
+        let offer = &call_data.offer;
+        let payment_token = fetch_payment_token(BLUR_POOL_ADDRESS, db_tx)?;
+
+        // the price first repays the lien, the borrower receives the rest after fees
+        let fees = fee_amount(offer.price, offer.fees.iter().map(|fee| fee.rate));
+
+        Ok(NormalizedNftTrade {
+            protocol: Protocol::BlurBlend,
+            trace_index: info.trace_idx,
+            kind: NftTradeKind::LockedSale,
+            marketplace: info.target_address,
+            buyer: bought.buyer,
+            seller: bought.seller,
+            collection: bought.collection,
+            token_id: bought.tokenId,
+            amount: U256::from(1),
+            price: offer.price.to_scaled_rational(payment_token.decimals),
+            fees: fees.to_scaled_rational(payment_token.decimals),
+            payment_token,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::BlurBlend,
+    crate::BlurBlend::seizeCall,
+    NftTrade,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: seizeCall, db_tx: &DB| {
+        // a seize of multiple liens doesn't map onto a single trade
+        let [pointer] = call_data.lienPointers.as_slice() else {
+            return Err(eyre!("seized {} liens in a single call", call_data.lienPointers.len()))
+        };
+        let lien = &pointer.lien;
+        let payment_token = fetch_payment_token(BLUR_POOL_ADDRESS, db_tx)?;
+
+        Ok(NormalizedNftTrade {
+            protocol: Protocol::BlurBlend,
+            trace_index: info.trace_idx,
+            kind: NftTradeKind::Liquidation,
+            marketplace: info.target_address,
+            buyer: lien.lender,
+            seller: lien.borrower,
+            collection: lien.collection,
+            token_id: lien.tokenId,
+            amount: U256::from(1),
+            price: lien.amount.to_scaled_rational(payment_token.decimals),
+            fees: Rational::ZERO,
+            payment_token,
+            msg_value: info.msg_value,
+        })
+    }
+);
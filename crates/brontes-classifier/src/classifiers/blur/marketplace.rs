@@ -0,0 +1,42 @@
+use brontes_macros::action_impl;
+use brontes_types::{
+    normalized_actions::{NftTradeKind, NormalizedNftTrade},
+    structured_trace::CallInfo,
+    Protocol, ToScaledRational,
+};
+
+use super::{fee_amount, fetch_payment_token};
+
+action_impl!(
+    Protocol::BlurMarketplace,
+    crate::BlurExchange::executeCall,
+    NftTrade,
+    [..OrdersMatched],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: BlurMarketplaceExecuteCallLogs, db_tx: &DB| {
+        let matched = log_data.orders_matched_field?;
+        let (sell, buy) = (&matched.sell, &matched.buy);
+
+        // the policies execute at the price of the maker, the older of the two orders
+        let price = if matched.maker == sell.trader { sell.price } else { buy.price };
+        let fees = fee_amount(price, sell.fees.iter().map(|fee| fee.rate));
+        let payment_token = fetch_payment_token(sell.paymentToken, db_tx)?;
+
+        Ok(NormalizedNftTrade {
+            protocol: Protocol::BlurMarketplace,
+            trace_index: info.trace_idx,
+            kind: NftTradeKind::Sale,
+            marketplace: info.target_address,
+            buyer: buy.trader,
+            seller: sell.trader,
+            collection: sell.collection,
+            token_id: sell.tokenId,
+            amount: sell.amount,
+            price: price.to_scaled_rational(payment_token.decimals),
+            fees: fees.to_scaled_rational(payment_token.decimals),
+            payment_token,
+            msg_value: info.msg_value,
+        })
+    }
+);
@@ -0,0 +1,50 @@
+use alloy_primitives::{hex, Address, U256};
+use brontes_database::libmdbx::{DBWriter, LibmdbxReader};
+use brontes_types::{constants::ETH_ADDRESS, db::token_info::TokenInfoWithAddress};
+
+#[allow(non_snake_case)]
+mod blend;
+#[allow(non_snake_case)]
+mod marketplace;
+
+pub use blend::*;
+pub use marketplace::*;
+
+/// Blur Pool, the 1:1 eth wrapper Blur bids and Blend loans are settled in
+pub const BLUR_POOL_ADDRESS: Address =
+    Address::new(hex!("0000000000A39bb272e79075ade125fd351887Ac"));
+
+/// Native eth and Blur Pool payments are both valued as eth.
+fn fetch_payment_token<DB: LibmdbxReader + DBWriter>(
+    token: Address,
+    db: &DB,
+) -> eyre::Result<TokenInfoWithAddress> {
+    db.try_fetch_token_info(if token.is_zero() || token == BLUR_POOL_ADDRESS {
+        ETH_ADDRESS
+    } else {
+        token
+    })
+}
+
+/// Fee rates are in basis points of the price
+fn fee_amount(price: U256, rates: impl Iterator<Item = u16>) -> U256 {
+    let rate = rates.fold(U256::ZERO, |total, rate| total + U256::from(rate));
+
+    price * rate / U256::from(10_000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fee_amount() {
+        let price = U256::from(2_000_000_000_000_000_000u128);
+        // 0.5% marketplace fee and 5% royalty
+        assert_eq!(
+            fee_amount(price, [50u16, 500].into_iter()),
+            U256::from(110_000_000_000_000_000u128)
+        );
+        assert_eq!(fee_amount(price, std::iter::empty()), U256::ZERO);
+    }
+}
@@ -0,0 +1,59 @@
+use brontes_macros::action_impl;
+use brontes_types::{
+    normalized_actions::{NormalizedBridgeDeposit, NormalizedBridgeFill},
+    structured_trace::CallInfo,
+    Protocol, ToScaledRational,
+};
+
+// the input token is what the depositor locks on mainnet, the relayer pays out
+// the output token on the destination chain
+action_impl!(
+    Protocol::AcrossSpokePool,
+    crate::AcrossSpokePool::depositV3Call,
+    BridgeDeposit,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: depositV3Call, db_tx: &DB| {
+        let token = db_tx.try_fetch_token_info(call_data.inputToken)?;
+        let amount = call_data.inputAmount.to_scaled_rational(token.decimals);
+
+        Ok(NormalizedBridgeDeposit {
+            protocol: Protocol::AcrossSpokePool,
+            trace_index: info.trace_idx,
+            bridge: info.target_address,
+            depositor: call_data.depositor,
+            recipient: call_data.recipient,
+            destination_chain_id: u64::try_from(call_data.destinationChainId)?,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+// the relayer fronts the output token on mainnet and is repaid on the
+// repayment chain once the fill is proven
+action_impl!(
+    Protocol::AcrossSpokePool,
+    crate::AcrossSpokePool::fillV3RelayCall,
+    BridgeFill,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: fillV3RelayCall, db_tx: &DB| {
+        let relay = call_data.relayData;
+        let token = db_tx.try_fetch_token_info(relay.outputToken)?;
+        let amount = relay.outputAmount.to_scaled_rational(token.decimals);
+
+        Ok(NormalizedBridgeFill {
+            protocol: Protocol::AcrossSpokePool,
+            trace_index: info.trace_idx,
+            bridge: info.target_address,
+            relayer: info.msg_sender,
+            recipient: relay.recipient,
+            origin_chain_id: u64::try_from(relay.originChainId)?,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
@@ -0,0 +1,84 @@
+use brontes_macros::action_impl;
+use brontes_types::{
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::{NormalizedBridgeDeposit, NormalizedBridgeFill},
+    structured_trace::CallInfo,
+    Protocol, ToScaledRational,
+};
+
+use super::ARBITRUM_CHAIN_ID;
+
+action_impl!(
+    Protocol::ArbitrumBridge,
+    crate::ArbitrumGatewayRouter::outboundTransferCall,
+    BridgeDeposit,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: outboundTransferCall, db_tx: &DB| {
+        let token = db_tx.try_fetch_token_info(call_data._token)?;
+        let amount = call_data._amount.to_scaled_rational(token.decimals);
+
+        Ok(NormalizedBridgeDeposit {
+            protocol: Protocol::ArbitrumBridge,
+            trace_index: info.trace_idx,
+            bridge: info.target_address,
+            depositor: info.msg_sender,
+            recipient: call_data._to,
+            destination_chain_id: ARBITRUM_CHAIN_ID,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+// the eth is credited to the sender on arbitrum, aliased if the sender is a
+// contract
+action_impl!(
+    Protocol::ArbitrumBridge,
+    crate::ArbitrumInbox::depositEthCall,
+    BridgeDeposit,
+    [],
+    |info: CallInfo, _db_tx: &DB| {
+        let token = TokenInfoWithAddress::native_eth();
+        let amount = info.msg_value.to_scaled_rational(token.decimals);
+
+        Ok(NormalizedBridgeDeposit {
+            protocol: Protocol::ArbitrumBridge,
+            trace_index: info.trace_idx,
+            bridge: info.target_address,
+            depositor: info.msg_sender,
+            recipient: info.msg_sender,
+            destination_chain_id: ARBITRUM_CHAIN_ID,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+// withdrawals are executed through the outbox, which has the gateway of the
+// token release it to the recipient
+action_impl!(
+    Protocol::ArbitrumBridge,
+    crate::ArbitrumGateway::finalizeInboundTransferCall,
+    BridgeFill,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: finalizeInboundTransferCall, db_tx: &DB| {
+        let token = db_tx.try_fetch_token_info(call_data._token)?;
+        let amount = call_data._amount.to_scaled_rational(token.decimals);
+
+        Ok(NormalizedBridgeFill {
+            protocol: Protocol::ArbitrumBridge,
+            trace_index: info.trace_idx,
+            bridge: info.target_address,
+            relayer: info.target_address,
+            recipient: call_data._to,
+            origin_chain_id: ARBITRUM_CHAIN_ID,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
@@ -0,0 +1,17 @@
+mod across;
+
+pub use across::*;
+
+mod arbitrum;
+
+pub use arbitrum::*;
+
+#[allow(non_snake_case)]
+mod op_stack;
+
+pub use op_stack::*;
+
+/// Chain ids of the destination and origin chains of the canonical bridges
+const ARBITRUM_CHAIN_ID: u64 = 42161;
+const OPTIMISM_CHAIN_ID: u64 = 10;
+const BASE_CHAIN_ID: u64 = 8453;
@@ -0,0 +1,302 @@
+use alloy_primitives::{Address, U256};
+use brontes_database::libmdbx::{DBWriter, LibmdbxReader};
+use brontes_macros::action_impl;
+use brontes_types::{
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::{NormalizedBridgeDeposit, NormalizedBridgeFill},
+    structured_trace::CallInfo,
+    Protocol, ToScaledRational,
+};
+
+use super::{BASE_CHAIN_ID, OPTIMISM_CHAIN_ID};
+
+/// Optimism and Base run the same L1 standard bridge, eth is bridged as the
+/// zero address token
+fn bridged_token<DB: LibmdbxReader + DBWriter>(
+    token: Address,
+    db: &DB,
+) -> eyre::Result<TokenInfoWithAddress> {
+    if token.is_zero() {
+        Ok(TokenInfoWithAddress::native_eth())
+    } else {
+        db.try_fetch_token_info(token)
+    }
+}
+
+fn deposit<DB: LibmdbxReader + DBWriter>(
+    protocol: Protocol,
+    destination_chain_id: u64,
+    info: &CallInfo,
+    l1_token: Address,
+    recipient: Address,
+    amount: U256,
+    db: &DB,
+) -> eyre::Result<NormalizedBridgeDeposit> {
+    let token = bridged_token(l1_token, db)?;
+    let amount = amount.to_scaled_rational(token.decimals);
+
+    Ok(NormalizedBridgeDeposit {
+        protocol,
+        trace_index: info.trace_idx,
+        bridge: info.target_address,
+        depositor: info.msg_sender,
+        recipient,
+        destination_chain_id,
+        token,
+        amount,
+        msg_value: info.msg_value,
+    })
+}
+
+// withdrawals are finalized by the cross domain messenger once the withdrawal
+// is proven, the bridge pays out the tokens it holds
+fn fill<DB: LibmdbxReader + DBWriter>(
+    protocol: Protocol,
+    origin_chain_id: u64,
+    info: &CallInfo,
+    l1_token: Address,
+    recipient: Address,
+    amount: U256,
+    db: &DB,
+) -> eyre::Result<NormalizedBridgeFill> {
+    let token = bridged_token(l1_token, db)?;
+    let amount = amount.to_scaled_rational(token.decimals);
+
+    Ok(NormalizedBridgeFill {
+        protocol,
+        trace_index: info.trace_idx,
+        bridge: info.target_address,
+        relayer: info.target_address,
+        recipient,
+        origin_chain_id,
+        token,
+        amount,
+        msg_value: info.msg_value,
+    })
+}
+
+action_impl!(
+    Protocol::OptimismBridge,
+    crate::OpStandardBridge::depositETHCall,
+    BridgeDeposit,
+    [],
+    |info: CallInfo, db_tx: &DB| {
+        deposit(
+            Protocol::OptimismBridge,
+            OPTIMISM_CHAIN_ID,
+            &info,
+            Address::ZERO,
+            info.msg_sender,
+            info.msg_value,
+            db_tx,
+        )
+    }
+);
+
+action_impl!(
+    Protocol::OptimismBridge,
+    crate::OpStandardBridge::depositETHToCall,
+    BridgeDeposit,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: depositETHToCall, db_tx: &DB| {
+        deposit(
+            Protocol::OptimismBridge,
+            OPTIMISM_CHAIN_ID,
+            &info,
+            Address::ZERO,
+            call_data._to,
+            info.msg_value,
+            db_tx,
+        )
+    }
+);
+
+action_impl!(
+    Protocol::OptimismBridge,
+    crate::OpStandardBridge::depositERC20Call,
+    BridgeDeposit,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: depositERC20Call, db_tx: &DB| {
+        deposit(
+            Protocol::OptimismBridge,
+            OPTIMISM_CHAIN_ID,
+            &info,
+            call_data._l1Token,
+            info.msg_sender,
+            call_data._amount,
+            db_tx,
+        )
+    }
+);
+
+action_impl!(
+    Protocol::OptimismBridge,
+    crate::OpStandardBridge::depositERC20ToCall,
+    BridgeDeposit,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: depositERC20ToCall, db_tx: &DB| {
+        deposit(
+            Protocol::OptimismBridge,
+            OPTIMISM_CHAIN_ID,
+            &info,
+            call_data._l1Token,
+            call_data._to,
+            call_data._amount,
+            db_tx,
+        )
+    }
+);
+
+action_impl!(
+    Protocol::OptimismBridge,
+    crate::OpStandardBridge::finalizeETHWithdrawalCall,
+    BridgeFill,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: finalizeETHWithdrawalCall, db_tx: &DB| {
+        fill(
+            Protocol::OptimismBridge,
+            OPTIMISM_CHAIN_ID,
+            &info,
+            Address::ZERO,
+            call_data._to,
+            call_data._amount,
+            db_tx,
+        )
+    }
+);
+
+action_impl!(
+    Protocol::OptimismBridge,
+    crate::OpStandardBridge::finalizeERC20WithdrawalCall,
+    BridgeFill,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: finalizeERC20WithdrawalCall, db_tx: &DB| {
+        fill(
+            Protocol::OptimismBridge,
+            OPTIMISM_CHAIN_ID,
+            &info,
+            call_data._l1Token,
+            call_data._to,
+            call_data._amount,
+            db_tx,
+        )
+    }
+);
+
+action_impl!(
+    Protocol::BaseBridge,
+    crate::OpStandardBridge::depositETHCall,
+    BridgeDeposit,
+    [],
+    |info: CallInfo, db_tx: &DB| {
+        deposit(
+            Protocol::BaseBridge,
+            BASE_CHAIN_ID,
+            &info,
+            Address::ZERO,
+            info.msg_sender,
+            info.msg_value,
+            db_tx,
+        )
+    }
+);
+
+action_impl!(
+    Protocol::BaseBridge,
+    crate::OpStandardBridge::depositETHToCall,
+    BridgeDeposit,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: depositETHToCall, db_tx: &DB| {
+        deposit(
+            Protocol::BaseBridge,
+            BASE_CHAIN_ID,
+            &info,
+            Address::ZERO,
+            call_data._to,
+            info.msg_value,
+            db_tx,
+        )
+    }
+);
+
+action_impl!(
+    Protocol::BaseBridge,
+    crate::OpStandardBridge::depositERC20Call,
+    BridgeDeposit,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: depositERC20Call, db_tx: &DB| {
+        deposit(
+            Protocol::BaseBridge,
+            BASE_CHAIN_ID,
+            &info,
+            call_data._l1Token,
+            info.msg_sender,
+            call_data._amount,
+            db_tx,
+        )
+    }
+);
+
+action_impl!(
+    Protocol::BaseBridge,
+    crate::OpStandardBridge::depositERC20ToCall,
+    BridgeDeposit,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: depositERC20ToCall, db_tx: &DB| {
+        deposit(
+            Protocol::BaseBridge,
+            BASE_CHAIN_ID,
+            &info,
+            call_data._l1Token,
+            call_data._to,
+            call_data._amount,
+            db_tx,
+        )
+    }
+);
+
+action_impl!(
+    Protocol::BaseBridge,
+    crate::OpStandardBridge::finalizeETHWithdrawalCall,
+    BridgeFill,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: finalizeETHWithdrawalCall, db_tx: &DB| {
+        fill(
+            Protocol::BaseBridge,
+            BASE_CHAIN_ID,
+            &info,
+            Address::ZERO,
+            call_data._to,
+            call_data._amount,
+            db_tx,
+        )
+    }
+);
+
+action_impl!(
+    Protocol::BaseBridge,
+    crate::OpStandardBridge::finalizeERC20WithdrawalCall,
+    BridgeFill,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: finalizeERC20WithdrawalCall, db_tx: &DB| {
+        fill(
+            Protocol::BaseBridge,
+            BASE_CHAIN_ID,
+            &info,
+            call_data._l1Token,
+            call_data._to,
+            call_data._amount,
+            db_tx,
+        )
+    }
+);
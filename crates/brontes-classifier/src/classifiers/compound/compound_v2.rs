@@ -1,8 +1,15 @@
 use brontes_macros::action_impl;
 use brontes_pricing::Protocol;
 use brontes_types::{
-    normalized_actions::NormalizedLiquidation, structured_trace::CallInfo, utils::ToScaledRational,
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::{
+        NormalizedLiquidation, NormalizedLoan, NormalizedRepayment, NormalizedSupply,
+        NormalizedWithdraw,
+    },
+    structured_trace::CallInfo,
+    utils::ToScaledRational,
 };
+use malachite::{num::basic::traits::Zero, Rational};
 
 action_impl!(
     Protocol::CompoundV2,
@@ -32,6 +39,194 @@ action_impl!(
             debt_asset: debt_info,
             covered_debt: debt_covered,
             liquidated_collateral: collateral_liquidated,
+            collateral_discount: None,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+// cTokens are only registered with their own address, the underlying token and
+// the amount in its decimals are taken from the transfer of the underlying in
+// the multi frame classification
+action_impl!(
+    Protocol::CompoundV2,
+    crate::CompoundV2CToken::borrowCall,
+    Loan,
+    [..Borrow],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: CompoundV2BorrowCallLogs, _| {
+        let logs = log_data.borrow_field?;
+        Ok(NormalizedLoan {
+            protocol: Protocol::CompoundV2,
+            trace_index: info.trace_idx,
+            lender: info.target_address,
+            borrower: logs.borrower,
+            // filled in later
+            loaned_token: Default::default(),
+            loan_amount: Rational::ZERO,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::CompoundV2,
+    crate::CompoundV2CToken::repayBorrow_0Call,
+    Repayment,
+    [..RepayBorrow],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: CompoundV2RepayBorrow_0CallLogs, _| {
+        let logs = log_data.repay_borrow_field?;
+        Ok(NormalizedRepayment {
+            protocol: Protocol::CompoundV2,
+            trace_index: info.trace_idx,
+            lender: info.target_address,
+            borrower: logs.borrower,
+            payer: logs.payer,
+            // filled in later
+            repayed_token: Default::default(),
+            repayment_amount: Rational::ZERO,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+// cEther's payable repay, there is no transfer of the underlying as the eth is
+// sent along with the call
+action_impl!(
+    Protocol::CompoundV2,
+    crate::CompoundV2CToken::repayBorrow_1Call,
+    Repayment,
+    [..RepayBorrow],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: CompoundV2RepayBorrow_1CallLogs, _| {
+        let logs = log_data.repay_borrow_field?;
+        Ok(NormalizedRepayment {
+            protocol: Protocol::CompoundV2,
+            trace_index: info.trace_idx,
+            lender: info.target_address,
+            borrower: logs.borrower,
+            payer: logs.payer,
+            repayed_token: TokenInfoWithAddress::native_eth(),
+            repayment_amount: info.msg_value.to_scaled_rational(18),
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::CompoundV2,
+    crate::CompoundV2CToken::repayBorrowBehalfCall,
+    Repayment,
+    [..RepayBorrow],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: CompoundV2RepayBorrowBehalfCallLogs, _| {
+        let logs = log_data.repay_borrow_field?;
+        Ok(NormalizedRepayment {
+            protocol: Protocol::CompoundV2,
+            trace_index: info.trace_idx,
+            lender: info.target_address,
+            borrower: logs.borrower,
+            payer: logs.payer,
+            // filled in later
+            repayed_token: Default::default(),
+            repayment_amount: Rational::ZERO,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::CompoundV2,
+    crate::CompoundV2CToken::mint_0Call,
+    Supply,
+    [..Mint],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: CompoundV2Mint_0CallLogs, _| {
+        let logs = log_data.mint_field?;
+        Ok(NormalizedSupply {
+            protocol: Protocol::CompoundV2,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            supplier: logs.minter,
+            on_behalf_of: logs.minter,
+            // filled in later
+            supplied_token: Default::default(),
+            supply_amount: Rational::ZERO,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+// cEther's payable mint, the supplied eth is the value of the call
+action_impl!(
+    Protocol::CompoundV2,
+    crate::CompoundV2CToken::mint_1Call,
+    Supply,
+    [..Mint],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: CompoundV2Mint_1CallLogs, _| {
+        let logs = log_data.mint_field?;
+        Ok(NormalizedSupply {
+            protocol: Protocol::CompoundV2,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            supplier: logs.minter,
+            on_behalf_of: logs.minter,
+            supplied_token: TokenInfoWithAddress::native_eth(),
+            supply_amount: info.msg_value.to_scaled_rational(18),
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::CompoundV2,
+    crate::CompoundV2CToken::redeemCall,
+    Withdraw,
+    [..Redeem],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: CompoundV2RedeemCallLogs, _| {
+        let logs = log_data.redeem_field?;
+        Ok(NormalizedWithdraw {
+            protocol: Protocol::CompoundV2,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            supplier: logs.redeemer,
+            recipient: logs.redeemer,
+            // filled in later
+            withdrawn_token: Default::default(),
+            withdraw_amount: Rational::ZERO,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::CompoundV2,
+    crate::CompoundV2CToken::redeemUnderlyingCall,
+    Withdraw,
+    [..Redeem],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: CompoundV2RedeemUnderlyingCallLogs, _| {
+        let logs = log_data.redeem_field?;
+        Ok(NormalizedWithdraw {
+            protocol: Protocol::CompoundV2,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            supplier: logs.redeemer,
+            recipient: logs.redeemer,
+            // filled in later
+            withdrawn_token: Default::default(),
+            withdraw_amount: Rational::ZERO,
             msg_value: info.msg_value,
         })
     }
@@ -39,15 +234,22 @@ action_impl!(
 
 #[cfg(test)]
 mod tests {
-    use alloy_primitives::{hex, Address, B256, U256};
+    use alloy_primitives::{hex, Address, Bytes, Log, B256, U256};
+    use alloy_sol_types::{SolCall, SolEvent};
     use brontes_types::{
         db::token_info::TokenInfoWithAddress,
-        normalized_actions::{Action, NormalizedLiquidation},
+        normalized_actions::{
+            Action, NormalizedLiquidation, NormalizedRepayment, NormalizedSupply,
+        },
+        structured_trace::CallFrameInfo,
         Protocol, TreeSearchBuilder,
     };
     use malachite::Rational;
 
-    use crate::test_utils::ClassifierTestUtils;
+    use crate::{test_utils::ClassifierTestUtils, CompoundV2CToken};
+
+    const C_ETHER: Address = Address::new(hex!("4ddc2d193948926d02f9b1fe9e1daa0718270ed5"));
+    const USER: Address = Address::new(hex!("De74395831F3Ba9EdC7cBEE1fcB441cf24c0AF4d"));
 
     #[brontes_macros::test]
     async fn test_compound_v2_liquidation() {
@@ -97,6 +299,7 @@ mod tests {
             liquidator:            Address::from(hex!("D911560979B78821D7b045C79E36E9CbfC2F6C6F")),
             pool:                  Address::from(hex!("39AA39c021dfbaE8faC545936693aC917d5E7563")),
             trace_index:           2,
+            collateral_discount:   None,
             msg_value:             U256::ZERO,
         });
 
@@ -110,4 +313,110 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[brontes_macros::test]
+    async fn test_compound_v2_c_ether_mint() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        classifier_utils.ensure_protocol(
+            Protocol::CompoundV2,
+            C_ETHER,
+            C_ETHER,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        // the supplied eth is only the value of the call, there is no transfer of
+        // the underlying to fill it in from
+        let value = U256::from(2_000_000_000_000_000_000u128);
+        let logs = [Log {
+            address: C_ETHER,
+            data:    CompoundV2CToken::Mint {
+                minter:     USER,
+                mintAmount: value,
+                mintTokens: U256::from(9_950_000_000u64),
+            }
+            .encode_log_data(),
+        }];
+
+        let call_info = CallFrameInfo {
+            trace_idx:      0,
+            call_data:      CompoundV2CToken::mint_1Call {}.abi_encode().into(),
+            return_data:    Bytes::new(),
+            target_address: C_ETHER,
+            from_address:   USER,
+            logs:           &logs,
+            delegate_logs:  vec![],
+            msg_sender:     USER,
+            msg_value:      value,
+        };
+
+        let eq_action = Action::Supply(NormalizedSupply {
+            protocol:       Protocol::CompoundV2,
+            trace_index:    0,
+            pool:           C_ETHER,
+            supplier:       USER,
+            on_behalf_of:   USER,
+            supplied_token: TokenInfoWithAddress::native_eth(),
+            supply_amount:  Rational::from(2),
+            msg_value:      value,
+        });
+
+        assert_eq!(classifier_utils.classify_call_frame(call_info), Some(eq_action));
+    }
+
+    #[brontes_macros::test]
+    async fn test_compound_v2_c_ether_repay_borrow() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        classifier_utils.ensure_protocol(
+            Protocol::CompoundV2,
+            C_ETHER,
+            C_ETHER,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        let value = U256::from(500_000_000_000_000_000u128);
+        let logs = [Log {
+            address: C_ETHER,
+            data:    CompoundV2CToken::RepayBorrow {
+                payer:          USER,
+                borrower:       USER,
+                repayAmount:    value,
+                accountBorrows: U256::ZERO,
+                totalBorrows:   U256::from(10u128.pow(21)),
+            }
+            .encode_log_data(),
+        }];
+
+        let call_info = CallFrameInfo {
+            trace_idx:      0,
+            call_data:      CompoundV2CToken::repayBorrow_1Call {}.abi_encode().into(),
+            return_data:    Bytes::new(),
+            target_address: C_ETHER,
+            from_address:   USER,
+            logs:           &logs,
+            delegate_logs:  vec![],
+            msg_sender:     USER,
+            msg_value:      value,
+        };
+
+        let eq_action = Action::Repayment(NormalizedRepayment {
+            protocol:         Protocol::CompoundV2,
+            trace_index:      0,
+            lender:           C_ETHER,
+            borrower:         USER,
+            payer:            USER,
+            repayed_token:    TokenInfoWithAddress::native_eth(),
+            repayment_amount: Rational::from_signeds(1, 2),
+            msg_value:        value,
+        });
+
+        assert_eq!(classifier_utils.classify_call_frame(call_info), Some(eq_action));
+    }
 }
@@ -0,0 +1,123 @@
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    normalized_actions::NormalizedLiquidation, structured_trace::CallInfo, utils::ToScaledRational,
+};
+
+// an absorb only moves the debt and the collateral of the account onto the
+// protocol. The absorbed collateral is then sold at a discount for the base
+// token, this is where liquidators take their profit. The collateral comes out
+// of the protocol reserves so the market itself is the debtor
+action_impl!(
+    Protocol::CompoundV3,
+    crate::CompoundV3Comet::buyCollateralCall,
+    Liquidation,
+    [..BuyCollateral],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: CompoundV3BuyCollateralCallLogs, db_tx: &DB| {
+        let logs = log_data.buy_collateral_field?;
+
+        let details = db_tx.get_protocol_details(info.target_address)?;
+        let debt_asset = db_tx.try_fetch_token_info(details.token0)?;
+        let collateral_asset = db_tx.try_fetch_token_info(logs.asset)?;
+
+        Ok(NormalizedLiquidation {
+            protocol: Protocol::CompoundV3,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            liquidator: logs.buyer,
+            debtor: info.target_address,
+            covered_debt: logs.baseAmount.to_scaled_rational(debt_asset.decimals),
+            liquidated_collateral: logs
+                .collateralAmount
+                .to_scaled_rational(collateral_asset.decimals),
+            collateral_asset,
+            debt_asset,
+            collateral_discount: None,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{hex, Address, Bytes, Log, U256};
+    use alloy_sol_types::{SolCall, SolEvent};
+    use brontes_types::{
+        constants::{USDC_ADDRESS, WETH_ADDRESS},
+        normalized_actions::{Action, NormalizedLiquidation},
+        structured_trace::CallFrameInfo,
+        Protocol,
+    };
+    use malachite::Rational;
+
+    use crate::{test_utils::ClassifierTestUtils, CompoundV3Comet};
+
+    const COMET: Address = Address::new(hex!("c3d688b66703497daa19211eedff47f25384cdc3"));
+    const BUYER: Address = Address::new(hex!("80d4230c0a68fc59cb264329d3a717fcaa472a13"));
+
+    #[brontes_macros::test]
+    async fn test_compound_v3_buy_collateral() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        // the base token of the market is its first token
+        classifier_utils.ensure_protocol(
+            Protocol::CompoundV3,
+            COMET,
+            USDC_ADDRESS,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        let logs = [Log {
+            address: COMET,
+            data:    CompoundV3Comet::BuyCollateral {
+                buyer:            BUYER,
+                asset:            WETH_ADDRESS,
+                baseAmount:       U256::from(2_950_000_000u64),
+                collateralAmount: U256::from(10u128.pow(18)),
+            }
+            .encode_log_data(),
+        }];
+
+        let call_info = CallFrameInfo {
+            trace_idx:      0,
+            call_data:      CompoundV3Comet::buyCollateralCall {
+                asset:      WETH_ADDRESS,
+                minAmount:  U256::from(10u128.pow(18)),
+                baseAmount: U256::from(2_950_000_000u64),
+                recipient:  BUYER,
+            }
+            .abi_encode()
+            .into(),
+            return_data:    Bytes::new(),
+            target_address: COMET,
+            from_address:   BUYER,
+            logs:           &logs,
+            delegate_logs:  vec![],
+            msg_sender:     BUYER,
+            msg_value:      U256::ZERO,
+        };
+
+        // the absorbed collateral comes out of the reserves, so the market is
+        // the debtor
+        let eq_action = Action::Liquidation(NormalizedLiquidation {
+            protocol:              Protocol::CompoundV3,
+            trace_index:           0,
+            pool:                  COMET,
+            liquidator:            BUYER,
+            debtor:                COMET,
+            collateral_asset:      classifier_utils.get_token_info(WETH_ADDRESS),
+            debt_asset:            classifier_utils.get_token_info(USDC_ADDRESS),
+            covered_debt:          Rational::from(2950),
+            liquidated_collateral: Rational::from(1),
+            collateral_discount:   None,
+            msg_value:             U256::ZERO,
+        });
+
+        assert_eq!(classifier_utils.classify_call_frame(call_info), Some(eq_action));
+    }
+}
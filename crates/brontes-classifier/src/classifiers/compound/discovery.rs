@@ -35,6 +35,25 @@ action_impl!(
     }
 );
 
+// setting the configuration of a comet on the configurator is the first step
+// of deploying a new Compound V3 market, the base token is the only token that
+// can be borrowed from it
+action_impl!(
+    Protocol::CompoundV3,
+    crate::CompoundV3Configurator::setConfigurationCall,
+    NewPool,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: setConfigurationCall, _| {
+        Ok(NormalizedNewPool {
+            trace_index: info.trace_idx,
+            protocol: Protocol::CompoundV3,
+            pool_address: call_data.cometProxy,
+            tokens: vec![call_data.newConfiguration.baseToken]
+        })
+    }
+);
+
 #[cfg(test)]
 mod tests {
     use alloy_primitives::{hex, B256};
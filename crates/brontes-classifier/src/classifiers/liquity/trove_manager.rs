@@ -0,0 +1,220 @@
+use alloy_primitives::Address;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    constants::{ETH_ADDRESS, LUSD_ADDRESS},
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::NormalizedLiquidation,
+    structured_trace::CallInfo,
+    utils::ToScaledRational,
+};
+use eyre::Result;
+use malachite::{num::basic::traits::Zero, Rational};
+
+use crate::LiquityTroveManager::Liquidation;
+
+action_impl!(
+    Protocol::Liquity,
+    crate::LiquityTroveManager::liquidateCall,
+    Liquidation,
+    [..Liquidation],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: liquidateCall,
+    log_data: LiquityLiquidateCallLogs,
+    db_tx: &DB| {
+        let (collateral_asset, debt_asset) =
+            (db_tx.try_fetch_token_info(ETH_ADDRESS)?, db_tx.try_fetch_token_info(LUSD_ADDRESS)?);
+
+        let logs = log_data.liquidation_field?;
+
+        liquidation(info, call_data._borrower, logs, collateral_asset, debt_asset)
+    }
+);
+
+// the troves liquidated in the sequence aren't part of the call, the debtor is
+// only known when a single trove is liquidated
+action_impl!(
+    Protocol::Liquity,
+    crate::LiquityTroveManager::liquidateTrovesCall,
+    Liquidation,
+    [..Liquidation],
+    logs: true,
+    |info: CallInfo, log_data: LiquityLiquidateTrovesCallLogs, db_tx: &DB| {
+        let (collateral_asset, debt_asset) =
+            (db_tx.try_fetch_token_info(ETH_ADDRESS)?, db_tx.try_fetch_token_info(LUSD_ADDRESS)?);
+
+        liquidation(info, Address::ZERO, log_data.liquidation_field?, collateral_asset, debt_asset)
+    }
+);
+
+// the liquidation event only has the totals of the batch, a batch of several
+// troves has no single debtor
+action_impl!(
+    Protocol::Liquity,
+    crate::LiquityTroveManager::batchLiquidateTrovesCall,
+    Liquidation,
+    [..Liquidation],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: batchLiquidateTrovesCall,
+    log_data: LiquityBatchLiquidateTrovesCallLogs,
+    db_tx: &DB| {
+        let (collateral_asset, debt_asset) =
+            (db_tx.try_fetch_token_info(ETH_ADDRESS)?, db_tx.try_fetch_token_info(LUSD_ADDRESS)?);
+        let debtor = match call_data._troveArray[..] {
+            [debtor] => debtor,
+            _ => Address::ZERO,
+        };
+
+        liquidation(info, debtor, log_data.liquidation_field?, collateral_asset, debt_asset)
+    }
+);
+
+/// Troves are always ETH collateralized LUSD debt. The stability pool absorbs
+/// the debt and the collateral, the liquidator doesn't repay anything and only
+/// receives the gas compensation: a share of the collateral and a fixed LUSD
+/// amount out of the gas pool. The LUSD part reaches the liquidator as a
+/// regular transfer.
+fn liquidation(
+    info: CallInfo,
+    debtor: Address,
+    logs: Liquidation,
+    collateral_asset: TokenInfoWithAddress,
+    debt_asset: TokenInfoWithAddress,
+) -> Result<NormalizedLiquidation> {
+    Ok(NormalizedLiquidation {
+        protocol: Protocol::Liquity,
+        trace_index: info.trace_idx,
+        pool: info.target_address,
+        liquidator: info.msg_sender,
+        debtor,
+        covered_debt: Rational::ZERO,
+        liquidated_collateral: logs
+            ._collGasCompensation
+            .to_scaled_rational(collateral_asset.decimals),
+        collateral_asset,
+        debt_asset,
+        collateral_discount: None,
+        msg_value: info.msg_value,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{hex, Bytes, Log, U256};
+    use alloy_sol_types::{SolCall, SolEvent};
+    use brontes_types::{
+        db::token_info::TokenInfo, normalized_actions::Action, structured_trace::CallFrameInfo,
+    };
+
+    use super::*;
+    use crate::{test_utils::ClassifierTestUtils, LiquityTroveManager};
+
+    const TROVE_MANAGER: Address = Address::new(hex!("a39739ef8b0231dbfa0dcda07d7e29faabcf4bb2"));
+    const LIQUIDATOR: Address = Address::new(hex!("80d4230c0a68fc59cb264329d3a717fcaa472a13"));
+    const BORROWER: Address = Address::new(hex!("e967954b9b48cb1a0079d76466e82c4d52a8f5d3"));
+
+    fn setup(classifier_utils: &ClassifierTestUtils) {
+        classifier_utils.ensure_protocol(
+            Protocol::Liquity,
+            TROVE_MANAGER,
+            LUSD_ADDRESS,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        classifier_utils.ensure_token(TokenInfoWithAddress {
+            address: ETH_ADDRESS,
+            inner:   TokenInfo { decimals: 18, symbol: "ETH".to_string() },
+        });
+    }
+
+    /// A trove of 10,000 LUSD debt against 5 ETH. 0.5% of the collateral goes
+    /// to the liquidator as gas compensation, the rest goes to the stability
+    /// pool
+    fn liquidation_log() -> [Log; 1] {
+        [Log {
+            address: TROVE_MANAGER,
+            data:    Liquidation {
+                _liquidatedDebt:      U256::from(10_000u128 * 10u128.pow(18)),
+                _liquidatedColl:      U256::from(4_975u128 * 10u128.pow(15)),
+                _collGasCompensation: U256::from(25u128 * 10u128.pow(15)),
+                _LUSDGasCompensation: U256::from(200u128 * 10u128.pow(18)),
+            }
+            .encode_log_data(),
+        }]
+    }
+
+    fn trove_manager_call(call_data: Vec<u8>, logs: &[Log]) -> CallFrameInfo<'_> {
+        CallFrameInfo {
+            trace_idx: 0,
+            call_data: call_data.into(),
+            return_data: Bytes::new(),
+            target_address: TROVE_MANAGER,
+            from_address: LIQUIDATOR,
+            logs,
+            delegate_logs: vec![],
+            msg_sender: LIQUIDATOR,
+            msg_value: U256::ZERO,
+        }
+    }
+
+    // the stability pool burns the debt and takes the rest of the collateral.
+    // the liquidator repays nothing and only receives the collateral share of
+    // the gas compensation, so that is the collateral it liquidated
+    #[brontes_macros::test]
+    async fn test_liquity_liquidate() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        setup(&classifier_utils);
+
+        let logs = liquidation_log();
+        let call_data = LiquityTroveManager::liquidateCall { _borrower: BORROWER }.abi_encode();
+
+        let eq_action = Action::Liquidation(NormalizedLiquidation {
+            protocol:              Protocol::Liquity,
+            trace_index:           0,
+            pool:                  TROVE_MANAGER,
+            liquidator:            LIQUIDATOR,
+            debtor:                BORROWER,
+            collateral_asset:      classifier_utils.get_token_info(ETH_ADDRESS),
+            debt_asset:            classifier_utils.get_token_info(LUSD_ADDRESS),
+            covered_debt:          Rational::ZERO,
+            liquidated_collateral: Rational::from_signeds(1, 40),
+            collateral_discount:   None,
+            msg_value:             U256::ZERO,
+        });
+
+        assert_eq!(
+            classifier_utils.classify_call_frame(trove_manager_call(call_data, &logs)),
+            Some(eq_action)
+        );
+    }
+
+    #[brontes_macros::test]
+    async fn test_liquity_batch_liquidate_has_no_single_debtor() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        setup(&classifier_utils);
+
+        let logs = liquidation_log();
+        let call_data = LiquityTroveManager::batchLiquidateTrovesCall {
+            _troveArray: vec![BORROWER, LIQUIDATOR],
+        }
+        .abi_encode();
+
+        let liquidation = classifier_utils
+            .classify_call_frame(trove_manager_call(call_data, &logs))
+            .and_then(|action| action.try_liquidation())
+            .unwrap();
+
+        assert_eq!(liquidation.debtor, Address::ZERO);
+        assert_eq!(liquidation.covered_debt, Rational::ZERO);
+        assert_eq!(liquidation.liquidated_collateral, Rational::from_signeds(1, 40));
+    }
+}
@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use alloy_primitives::U256;
+use alloy_sol_types::SolCall;
+use brontes_macros::action_impl;
+use brontes_pricing::make_call_request;
+use brontes_types::{
+    constants::DAI_ADDRESS,
+    normalized_actions::NormalizedLiquidation,
+    structured_trace::{CallInfo, TraceActions, TransactionTraceWithLogs},
+    traits::TracingProvider,
+    Protocol, ToScaledRational,
+};
+use malachite::{num::basic::traits::Zero, Rational};
+
+use super::{RAD, RAY};
+use crate::{MakerAbacus::priceCall, MakerClipper::bufCall};
+
+// barking an unsafe vault moves its collateral and debt into a clipper
+// auction, the keeper is paid a dai incentive inside the vat. The collateral
+// and debt are liquidated by the takes of the auction, so the bark carries no
+// amounts to keep them from being counted twice
+action_impl!(
+    Protocol::MakerDog,
+    crate::MakerDog::barkCall,
+    Liquidation,
+    [..Bark],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: barkCall,
+    log_data: MakerDogBarkCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.bark_field?;
+
+        let details = db_tx.get_protocol_details(logs.clip)?;
+        let collateral_asset = db_tx.try_fetch_token_info(details.token0)?;
+        let debt_asset = db_tx.try_fetch_token_info(DAI_ADDRESS)?;
+
+        Ok(NormalizedLiquidation {
+            protocol: Protocol::MakerDog,
+            trace_index: info.trace_idx,
+            pool: logs.clip,
+            liquidator: call_data.kpr,
+            debtor: logs.urn,
+            collateral_asset,
+            debt_asset,
+            covered_debt: Rational::ZERO,
+            liquidated_collateral: Rational::ZERO,
+            collateral_discount: None,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+// the collateral bought is never emitted, it's the dai owed at the current
+// auction price. When data is passed the collateral is handed to the callee
+// before the dai is taken, which is how keepers sell it on a dex within the
+// same take. The discount needs the clipper's `buf`, so it's set by the tree
+// builder, see [`take_discount`]
+action_impl!(
+    Protocol::MakerClipper,
+    crate::MakerClipper::takeCall,
+    Liquidation,
+    [..Take],
+    logs: true,
+    |info: CallInfo, log_data: MakerClipperTakeCallLogs, db_tx: &DB| {
+        let logs = log_data.take_field?;
+        eyre::ensure!(!logs.price.is_zero(), "take at a zero auction price");
+
+        let details = db_tx.get_protocol_details(info.target_address)?;
+        let collateral_asset = db_tx.try_fetch_token_info(details.token0)?;
+        let debt_asset = db_tx.try_fetch_token_info(DAI_ADDRESS)?;
+
+        let covered_debt = logs.owe.to_scaled_rational(RAD);
+        let liquidated_collateral = &covered_debt / logs.price.to_scaled_rational(RAY);
+
+        Ok(NormalizedLiquidation {
+            protocol: Protocol::MakerClipper,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            liquidator: info.msg_sender,
+            debtor: logs.usr,
+            collateral_asset,
+            debt_asset,
+            covered_debt,
+            liquidated_collateral,
+            collateral_discount: None,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+/// The discount of a take versus the oracle price. The take prices the
+/// auction through the abacus, which is passed the price the auction started
+/// at. That is the oracle price at the kick times the clipper's `buf`.
+pub async fn take_discount<T: TracingProvider>(
+    take: &NormalizedLiquidation,
+    trace: &TransactionTraceWithLogs,
+    full_trace: &[TransactionTraceWithLogs],
+    provider: &Arc<T>,
+    block: u64,
+) -> Option<Rational> {
+    let parent = &trace.trace.trace_address;
+    let top = full_trace
+        .iter()
+        .filter(|call| {
+            call.trace.trace_address.len() == parent.len() + 1
+                && call.trace.trace_address.starts_with(parent)
+                && call.is_static_call()
+        })
+        .find_map(|call| priceCall::abi_decode(&call.get_calldata(), false).ok())?
+        .top;
+    let buf = make_call_request(bufCall::new(()), provider, take.pool, Some(block))
+        .await
+        .ok()?
+        ._0;
+
+    auction_discount(take, top, buf)
+}
+
+fn auction_discount(take: &NormalizedLiquidation, top: U256, buf: U256) -> Option<Rational> {
+    if buf.is_zero() {
+        return None
+    }
+    let oracle_price = top.to_scaled_rational(RAY) / buf.to_scaled_rational(RAY);
+
+    take.discount(&oracle_price)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auction_discount() {
+        // 1 collateral bought for 1_800 dai out of an auction that started at 2_400,
+        // 120% of the 2_000 oracle price
+        let take = NormalizedLiquidation {
+            covered_debt: Rational::from(1_800),
+            liquidated_collateral: Rational::from(1),
+            ..Default::default()
+        };
+        let ray = U256::from(10).pow(U256::from(27));
+        let buf = U256::from(12) * ray / U256::from(10);
+
+        assert_eq!(
+            auction_discount(&take, U256::from(2_400) * ray, buf),
+            Some(Rational::from_signeds(1, 10))
+        );
+        assert_eq!(auction_discount(&take, U256::from(2_400) * ray, U256::ZERO), None);
+        assert_eq!(auction_discount(&take, U256::ZERO, buf), None);
+    }
+}
@@ -6,3 +6,11 @@ pub use psm::*;
 mod dss_flash;
 
 pub use dss_flash::*;
+
+mod clipper;
+
+pub use clipper::*;
+
+/// Decimals of the fixed point numbers used by the vat
+const RAY: u8 = 27;
+const RAD: u8 = 45;
@@ -41,6 +41,21 @@ pub use clipper::*;
 pub mod dodo;
 pub use dodo::*;
 
+pub mod seaport;
+pub use seaport::*;
+
+pub mod blur;
+pub use blur::*;
+
+pub mod morpho;
+pub use morpho::*;
+
+pub mod liquity;
+pub use liquity::*;
+
+pub mod bridges;
+pub use bridges::*;
+
 discovery_dispatch!(
     DiscoveryClassifier,
     SushiSwapV2Discovery,
@@ -81,6 +96,9 @@ action_dispatch!(
     UniswapV3MintCall,
     UniswapV3BurnCall,
     UniswapV3CollectCall,
+    UniswapV4InitializeCall,
+    UniswapV4SwapCall,
+    UniswapV4ModifyLiquidityCall,
     SushiSwapV3SwapCall,
     SushiSwapV3MintCall,
     SushiSwapV3BurnCall,
@@ -150,6 +168,18 @@ action_dispatch!(
     AaveV2FlashLoanCall,
     AaveV3FlashLoanCall,
     AaveV3FlashLoanSimpleCall,
+    AaveV2BorrowCall,
+    AaveV2DepositCall,
+    AaveV2RepayCall,
+    AaveV2WithdrawCall,
+    AaveV3BorrowCall,
+    AaveV3SupplyCall,
+    AaveV3SupplyWithPermitCall,
+    AaveV3DepositCall,
+    AaveV3RepayCall,
+    AaveV3RepayWithPermitCall,
+    AaveV3RepayWithATokensCall,
+    AaveV3WithdrawCall,
     BalancerV1SwapExactAmountInCall,
     BalancerV1SwapExactAmountOutCall,
     BalancerV1BindCall,
@@ -160,8 +190,25 @@ action_dispatch!(
     BalancerV2ExitPoolCall,
     BalancerV2RegisterTokensCall,
     CompoundV2LiquidateBorrowCall,
+    CompoundV2BorrowCall,
+    CompoundV2RepayBorrow_0Call,
+    CompoundV2RepayBorrow_1Call,
+    CompoundV2RepayBorrowBehalfCall,
+    CompoundV2Mint_0Call,
+    CompoundV2Mint_1Call,
+    CompoundV2RedeemCall,
+    CompoundV2RedeemUnderlyingCall,
     CompoundV2Initialize_0Call,
     CompoundV2Initialize_1Call,
+    CompoundV3BuyCollateralCall,
+    CompoundV3SetConfigurationCall,
+    MorphoBlueLiquidateCall,
+    SparkLiquidationCallCall,
+    LiquityLiquidateCall,
+    LiquityLiquidateTrovesCall,
+    LiquityBatchLiquidateTrovesCall,
+    MakerDogBarkCall,
+    MakerClipperTakeCall,
     OneInchV5SwapCall,
     OneInchV5ClipperSwapCall,
     OneInchV5ClipperSwapToCall,
@@ -208,5 +255,29 @@ action_dispatch!(
     DodoSellSharesCall,
     DodoSellBaseCall,
     DodoSellQuoteCall,
-    DodoFlashLoanCall
+    DodoFlashLoanCall,
+    SeaportFulfillBasicOrderCall,
+    SeaportFulfillBasicOrder_efficient_6GL6ycCall,
+    SeaportFulfillOrderCall,
+    SeaportFulfillAdvancedOrderCall,
+    BlurMarketplaceExecuteCall,
+    BlurBlendBuyLockedCall,
+    BlurBlendSeizeCall,
+    AcrossSpokePoolDepositV3Call,
+    AcrossSpokePoolFillV3RelayCall,
+    ArbitrumBridgeOutboundTransferCall,
+    ArbitrumBridgeDepositEthCall,
+    ArbitrumBridgeFinalizeInboundTransferCall,
+    OptimismBridgeDepositETHCall,
+    OptimismBridgeDepositETHToCall,
+    OptimismBridgeDepositERC20Call,
+    OptimismBridgeDepositERC20ToCall,
+    OptimismBridgeFinalizeETHWithdrawalCall,
+    OptimismBridgeFinalizeERC20WithdrawalCall,
+    BaseBridgeDepositETHCall,
+    BaseBridgeDepositETHToCall,
+    BaseBridgeDepositERC20Call,
+    BaseBridgeDepositERC20ToCall,
+    BaseBridgeFinalizeETHWithdrawalCall,
+    BaseBridgeFinalizeERC20WithdrawalCall
 );
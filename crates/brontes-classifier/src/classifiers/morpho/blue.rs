@@ -0,0 +1,133 @@
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    normalized_actions::NormalizedLiquidation, structured_trace::CallInfo, utils::ToScaledRational,
+};
+
+// markets live on the singleton and are fully described by the market params
+// in the calldata, so there is nothing to discover
+action_impl!(
+    Protocol::MorphoBlue,
+    crate::MorphoBlue::liquidateCall,
+    Liquidation,
+    [..Liquidate],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: liquidateCall,
+    log_data: MorphoBlueLiquidateCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.liquidate_field?;
+
+        let debt_asset = db_tx.try_fetch_token_info(call_data.marketParams.loanToken)?;
+        let collateral_asset =
+            db_tx.try_fetch_token_info(call_data.marketParams.collateralToken)?;
+
+        Ok(NormalizedLiquidation {
+            protocol: Protocol::MorphoBlue,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            liquidator: logs.caller,
+            debtor: logs.borrower,
+            covered_debt: logs.repaidAssets.to_scaled_rational(debt_asset.decimals),
+            liquidated_collateral: logs.seizedAssets.to_scaled_rational(collateral_asset.decimals),
+            collateral_asset,
+            debt_asset,
+            collateral_discount: None,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{hex, Address, Bytes, Log, B256, U256};
+    use alloy_sol_types::{SolCall, SolEvent};
+    use brontes_types::{
+        constants::{USDC_ADDRESS, WETH_ADDRESS},
+        normalized_actions::{Action, NormalizedLiquidation},
+        structured_trace::CallFrameInfo,
+        Protocol,
+    };
+    use malachite::Rational;
+
+    use crate::{test_utils::ClassifierTestUtils, MorphoBlue};
+
+    const MORPHO: Address = Address::new(hex!("bbbbbbbbbb9cc5e90e3b3af64bdaf62c37eeffcb"));
+    const LIQUIDATOR: Address = Address::new(hex!("80d4230c0a68fc59cb264329d3a717fcaa472a13"));
+    const BORROWER: Address = Address::new(hex!("e967954b9b48cb1a0079d76466e82c4d52a8f5d3"));
+
+    #[brontes_macros::test]
+    async fn test_morpho_blue_liquidate() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        classifier_utils.ensure_protocol(
+            Protocol::MorphoBlue,
+            MORPHO,
+            USDC_ADDRESS,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        let logs = [Log {
+            address: MORPHO,
+            data:    MorphoBlue::Liquidate {
+                id:            B256::repeat_byte(1),
+                caller:        LIQUIDATOR,
+                borrower:      BORROWER,
+                repaidAssets:  U256::from(2_900_000_000u64),
+                repaidShares:  U256::from(2_850_000_000_000_000u64),
+                seizedAssets:  U256::from(10u128.pow(18)),
+                badDebtAssets: U256::ZERO,
+                badDebtShares: U256::ZERO,
+            }
+            .encode_log_data(),
+        }];
+
+        // the market is only known through the params in the calldata
+        let call_info = CallFrameInfo {
+            trace_idx:      0,
+            call_data:      MorphoBlue::liquidateCall {
+                marketParams: MorphoBlue::MarketParams {
+                    loanToken:       USDC_ADDRESS,
+                    collateralToken: WETH_ADDRESS,
+                    oracle:          Address::repeat_byte(2),
+                    irm:             Address::repeat_byte(3),
+                    lltv:            U256::from(860_000_000_000_000_000u64),
+                },
+                borrower:     BORROWER,
+                seizedAssets: U256::from(10u128.pow(18)),
+                repaidShares: U256::ZERO,
+                data:         Bytes::new(),
+            }
+            .abi_encode()
+            .into(),
+            return_data:    Bytes::new(),
+            target_address: MORPHO,
+            from_address:   LIQUIDATOR,
+            logs:           &logs,
+            delegate_logs:  vec![],
+            msg_sender:     LIQUIDATOR,
+            msg_value:      U256::ZERO,
+        };
+
+        let eq_action = Action::Liquidation(NormalizedLiquidation {
+            protocol:              Protocol::MorphoBlue,
+            trace_index:           0,
+            pool:                  MORPHO,
+            liquidator:            LIQUIDATOR,
+            debtor:                BORROWER,
+            collateral_asset:      classifier_utils.get_token_info(WETH_ADDRESS),
+            debt_asset:            classifier_utils.get_token_info(USDC_ADDRESS),
+            covered_debt:          Rational::from(2900),
+            liquidated_collateral: Rational::from(1),
+            collateral_discount:   None,
+            msg_value:             U256::ZERO,
+        });
+
+        assert_eq!(classifier_utils.classify_call_frame(call_info), Some(eq_action));
+    }
+}
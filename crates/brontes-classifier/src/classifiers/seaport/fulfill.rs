@@ -0,0 +1,334 @@
+use alloy_primitives::{Address, U256};
+use brontes_database::libmdbx::{DBWriter, LibmdbxReader};
+use brontes_macros::action_impl;
+use brontes_types::{
+    constants::ETH_ADDRESS,
+    normalized_actions::{NftTradeKind, NormalizedNftTrade},
+    structured_trace::CallInfo,
+    Protocol, ToScaledRational,
+};
+use eyre::eyre;
+use itertools::Itertools;
+
+use crate::Seaport::OrderFulfilled;
+
+// only the single order fulfillments are classified, the orders filled by
+// `fulfillAvailableOrders` and `matchOrders` don't map onto a single trade
+action_impl!(
+    Protocol::Seaport,
+    crate::Seaport::fulfillBasicOrderCall,
+    NftTrade,
+    [..OrderFulfilled],
+    logs: true,
+    |info: CallInfo, log_data: SeaportFulfillBasicOrderCallLogs, db_tx: &DB| {
+        order_to_trade(info, &log_data.order_fulfilled_field?, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::Seaport,
+    crate::Seaport::fulfillBasicOrder_efficient_6GL6ycCall,
+    NftTrade,
+    [..OrderFulfilled],
+    logs: true,
+    |info: CallInfo,
+    log_data: SeaportFulfillBasicOrder_efficient_6GL6ycCallLogs,
+    db_tx: &DB| {
+        order_to_trade(info, &log_data.order_fulfilled_field?, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::Seaport,
+    crate::Seaport::fulfillOrderCall,
+    NftTrade,
+    [..OrderFulfilled],
+    logs: true,
+    |info: CallInfo, log_data: SeaportFulfillOrderCallLogs, db_tx: &DB| {
+        order_to_trade(info, &log_data.order_fulfilled_field?, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::Seaport,
+    crate::Seaport::fulfillAdvancedOrderCall,
+    NftTrade,
+    [..OrderFulfilled],
+    logs: true,
+    |info: CallInfo, log_data: SeaportFulfillAdvancedOrderCallLogs, db_tx: &DB| {
+        order_to_trade(info, &log_data.order_fulfilled_field?, db_tx)
+    }
+);
+
+const ITEM_TYPE_ERC20: u8 = 1;
+
+/// Native eth, erc20 and the nft item types. Criteria items are already
+/// resolved to the filled token id in `OrderFulfilled`.
+fn is_nft(item_type: u8) -> bool {
+    item_type > ITEM_TYPE_ERC20
+}
+
+/// An order fill in raw token amounts. The payment token is the zero address
+/// for native eth.
+#[derive(Debug, PartialEq, Eq)]
+struct OrderFill {
+    buyer:         Address,
+    seller:        Address,
+    collection:    Address,
+    token_id:      U256,
+    amount:        U256,
+    payment_token: Address,
+    price:         U256,
+    fees:          U256,
+}
+
+fn order_to_trade<DB: LibmdbxReader + DBWriter>(
+    info: CallInfo,
+    order: &OrderFulfilled,
+    db: &DB,
+) -> eyre::Result<NormalizedNftTrade> {
+    let fill = split_order(order)?;
+    let payment_token = db.try_fetch_token_info(if fill.payment_token.is_zero() {
+        ETH_ADDRESS
+    } else {
+        fill.payment_token
+    })?;
+
+    Ok(NormalizedNftTrade {
+        protocol: Protocol::Seaport,
+        trace_index: info.trace_idx,
+        kind: NftTradeKind::Sale,
+        marketplace: info.target_address,
+        buyer: fill.buyer,
+        seller: fill.seller,
+        collection: fill.collection,
+        token_id: fill.token_id,
+        amount: fill.amount,
+        price: fill.price.to_scaled_rational(payment_token.decimals),
+        fees: fill.fees.to_scaled_rational(payment_token.decimals),
+        payment_token,
+        msg_value: info.msg_value,
+    })
+}
+
+/// Listings offer the nft and ask for the payment, bids offer the payment and
+/// ask for the nft. In both cases every payment in the consideration that
+/// doesn't go to the seller is a marketplace fee or royalty.
+fn split_order(order: &OrderFulfilled) -> eyre::Result<OrderFill> {
+    let offered_nfts = order
+        .offer
+        .iter()
+        .filter(|item| is_nft(item.itemType))
+        .collect_vec();
+    let asked_nfts = order
+        .consideration
+        .iter()
+        .filter(|item| is_nft(item.itemType))
+        .collect_vec();
+    let consideration_payments = order
+        .consideration
+        .iter()
+        .filter(|item| !is_nft(item.itemType));
+
+    match (offered_nfts.as_slice(), asked_nfts.as_slice()) {
+        ([nft], []) => {
+            let (payment_token, price) = sum_payments(
+                consideration_payments
+                    .clone()
+                    .map(|item| (item.token, item.amount)),
+            )?;
+            let (_, fees) = sum_payments(
+                consideration_payments
+                    .filter(|item| item.recipient != order.offerer)
+                    .map(|item| (item.token, item.amount)),
+            )
+            .unwrap_or((payment_token, U256::ZERO));
+
+            Ok(OrderFill {
+                buyer: order.recipient,
+                seller: order.offerer,
+                collection: nft.token,
+                token_id: nft.identifier,
+                amount: nft.amount,
+                payment_token,
+                price,
+                fees,
+            })
+        }
+        ([], [nft]) => {
+            let (payment_token, price) =
+                sum_payments(order.offer.iter().map(|item| (item.token, item.amount)))?;
+            let (_, fees) =
+                sum_payments(consideration_payments.map(|item| (item.token, item.amount)))
+                    .unwrap_or((payment_token, U256::ZERO));
+
+            Ok(OrderFill {
+                buyer: order.offerer,
+                seller: order.recipient,
+                collection: nft.token,
+                token_id: nft.identifier,
+                amount: nft.amount,
+                payment_token,
+                price,
+                fees,
+            })
+        }
+        _ => Err(eyre!(
+            "order {:?} is not a single nft sale, offered {} and asked {} nfts",
+            order.orderHash,
+            offered_nfts.len(),
+            asked_nfts.len()
+        )),
+    }
+}
+
+/// Sums up the payments of an order, they all have to be in the same token.
+fn sum_payments(
+    mut payments: impl Iterator<Item = (Address, U256)>,
+) -> eyre::Result<(Address, U256)> {
+    let (token, first) = payments
+        .next()
+        .ok_or_else(|| eyre!("order has no payment"))?;
+
+    payments.try_fold((token, first), |(token, total), (next_token, amount)| {
+        if next_token != token {
+            return Err(eyre!("order is paid in multiple tokens"))
+        }
+        Ok((token, total + amount))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{hex, B256};
+
+    use super::*;
+    use crate::Seaport::{ReceivedItem, SpentItem};
+
+    const OFFERER: Address = Address::new(hex!("1111111111111111111111111111111111111111"));
+    const FULFILLER: Address = Address::new(hex!("2222222222222222222222222222222222222222"));
+    const FEE_RECIPIENT: Address = Address::new(hex!("0000a26b00c1f0df003000390027140000faa719"));
+    const COLLECTION: Address = Address::new(hex!("bc4ca0eda7647a8ab7c2061c2e118a18a936f13d"));
+    const WETH: Address = Address::new(hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2"));
+
+    fn eth(amount: u64) -> U256 {
+        U256::from(amount) * U256::from(10u64.pow(15))
+    }
+
+    fn order(offer: Vec<SpentItem>, consideration: Vec<ReceivedItem>) -> OrderFulfilled {
+        OrderFulfilled {
+            orderHash: B256::ZERO,
+            offerer: OFFERER,
+            zone: Address::ZERO,
+            recipient: FULFILLER,
+            offer,
+            consideration,
+        }
+    }
+
+    #[test]
+    fn test_split_listing() {
+        let listing = order(
+            vec![SpentItem {
+                itemType:   2,
+                token:      COLLECTION,
+                identifier: U256::from(42),
+                amount:     U256::from(1),
+            }],
+            vec![
+                ReceivedItem {
+                    itemType:   0,
+                    token:      Address::ZERO,
+                    identifier: U256::ZERO,
+                    amount:     eth(975),
+                    recipient:  OFFERER,
+                },
+                ReceivedItem {
+                    itemType:   0,
+                    token:      Address::ZERO,
+                    identifier: U256::ZERO,
+                    amount:     eth(25),
+                    recipient:  FEE_RECIPIENT,
+                },
+            ],
+        );
+
+        assert_eq!(
+            split_order(&listing).unwrap(),
+            OrderFill {
+                buyer:         FULFILLER,
+                seller:        OFFERER,
+                collection:    COLLECTION,
+                token_id:      U256::from(42),
+                amount:        U256::from(1),
+                payment_token: Address::ZERO,
+                price:         eth(1000),
+                fees:          eth(25),
+            }
+        );
+    }
+
+    #[test]
+    fn test_split_bid() {
+        let bid = order(
+            vec![SpentItem {
+                itemType:   1,
+                token:      WETH,
+                identifier: U256::ZERO,
+                amount:     eth(1000),
+            }],
+            vec![
+                ReceivedItem {
+                    itemType:   4,
+                    token:      COLLECTION,
+                    identifier: U256::from(7),
+                    amount:     U256::from(1),
+                    recipient:  OFFERER,
+                },
+                ReceivedItem {
+                    itemType:   1,
+                    token:      WETH,
+                    identifier: U256::ZERO,
+                    amount:     eth(5),
+                    recipient:  FEE_RECIPIENT,
+                },
+            ],
+        );
+
+        assert_eq!(
+            split_order(&bid).unwrap(),
+            OrderFill {
+                buyer:         OFFERER,
+                seller:        FULFILLER,
+                collection:    COLLECTION,
+                token_id:      U256::from(7),
+                amount:        U256::from(1),
+                payment_token: WETH,
+                price:         eth(1000),
+                fees:          eth(5),
+            }
+        );
+    }
+
+    #[test]
+    fn test_split_nft_swap_is_rejected() {
+        let nft = |id: u64| SpentItem {
+            itemType:   2,
+            token:      COLLECTION,
+            identifier: U256::from(id),
+            amount:     U256::from(1),
+        };
+        let swap = order(
+            vec![nft(1)],
+            vec![ReceivedItem {
+                itemType:   2,
+                token:      COLLECTION,
+                identifier: U256::from(2),
+                amount:     U256::from(1),
+                recipient:  OFFERER,
+            }],
+        );
+
+        assert!(split_order(&swap).is_err());
+    }
+}
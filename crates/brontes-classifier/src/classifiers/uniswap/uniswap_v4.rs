@@ -0,0 +1,199 @@
+use alloy_primitives::{Address, I256, U256};
+use brontes_database::libmdbx::{DBWriter, LibmdbxReader};
+use brontes_macros::action_impl;
+use brontes_pricing::{uniswap_v4, Protocol};
+use brontes_types::{
+    constants::ETH_ADDRESS,
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::{
+        Action, NormalizedBurn, NormalizedCollect, NormalizedMint, NormalizedPoolConfigUpdate,
+        NormalizedSwap,
+    },
+    structured_trace::CallInfo,
+    ToScaledRational,
+};
+
+use crate::PoolKey;
+
+action_impl!(
+    Protocol::UniswapV4,
+    crate::UniswapV4PoolManager::initializeCall,
+    PoolConfigUpdate,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: initializeCall, _| {
+        let key = to_pricing_key(&call_data.key);
+
+        Ok(NormalizedPoolConfigUpdate {
+            trace_index: info.trace_idx,
+            protocol: Protocol::UniswapV4,
+            pool_address: key.pool_address(),
+            tokens: key.tokens().to_vec(),
+            pool_key: Some(key.into()),
+        })
+    }
+);
+
+action_impl!(
+    Protocol::UniswapV4,
+    crate::UniswapV4PoolManager::swapCall,
+    Swap,
+    [],
+    call_data: true,
+    return_data: true,
+    |info: CallInfo, call_data: swapCall, return_data: swapReturn, db_tx: &DB| {
+        let pool = pool_address(&call_data.key);
+        let t0_info = fetch_currency_info(call_data.key.currency0, db_tx)?;
+        let t1_info = fetch_currency_info(call_data.key.currency1, db_tx)?;
+
+        // the returned delta already includes the deltas of any hook, so it is what
+        // the locker settles with the pool manager once the unlock callback returns
+        let (amount0, amount1) = split_balance_delta(return_data.swapDelta);
+
+        let (amount_in, amount_out, token_in, token_out) = if call_data.params.zeroForOne {
+            (
+                amount0.unsigned_abs().to_scaled_rational(t0_info.decimals),
+                amount1.unsigned_abs().to_scaled_rational(t1_info.decimals),
+                t0_info,
+                t1_info,
+            )
+        } else {
+            (
+                amount1.unsigned_abs().to_scaled_rational(t1_info.decimals),
+                amount0.unsigned_abs().to_scaled_rational(t0_info.decimals),
+                t1_info,
+                t0_info,
+            )
+        };
+
+        Ok(NormalizedSwap {
+            protocol: Protocol::UniswapV4,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            // the output is only sent out with a later `take` by the locker
+            recipient: info.from_address,
+            pool,
+            token_in,
+            token_out,
+            amount_in,
+            amount_out,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::UniswapV4,
+    crate::UniswapV4PoolManager::modifyLiquidityCall,
+    Action,
+    [],
+    call_data: true,
+    return_data: true,
+    |info: CallInfo,
+    call_data: modifyLiquidityCall,
+    return_data: modifyLiquidityReturn,
+    db_tx: &DB| {
+        let pool = pool_address(&call_data.key);
+        let t0_info = fetch_currency_info(call_data.key.currency0, db_tx)?;
+        let t1_info = fetch_currency_info(call_data.key.currency1, db_tx)?;
+
+        // the caller delta includes the fees accrued by the position, split them out
+        // so that mints and burns only contain the principal
+        let (caller0, caller1) = split_balance_delta(return_data.callerDelta);
+        let (fees0, fees1) = split_balance_delta(return_data.feesAccrued);
+        let liquidity_delta = call_data.params.liquidityDelta;
+
+        let (amount0, amount1) = if liquidity_delta.is_zero() {
+            (fees0, fees1)
+        } else {
+            (caller0 - fees0, caller1 - fees1)
+        };
+
+        let token = vec![t0_info.clone(), t1_info.clone()];
+        let amount = vec![
+            amount0.unsigned_abs().to_scaled_rational(t0_info.decimals),
+            amount1.unsigned_abs().to_scaled_rational(t1_info.decimals),
+        ];
+
+        if liquidity_delta.is_zero() {
+            Ok(Action::Collect(NormalizedCollect {
+                protocol: Protocol::UniswapV4,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                recipient: info.from_address,
+                pool,
+                token,
+                amount,
+            }))
+        } else if liquidity_delta.is_negative() {
+            Ok(Action::Burn(NormalizedBurn {
+                protocol: Protocol::UniswapV4,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                recipient: info.from_address,
+                pool,
+                token,
+                amount,
+            }))
+        } else {
+            Ok(Action::Mint(NormalizedMint {
+                protocol: Protocol::UniswapV4,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                recipient: info.from_address,
+                pool,
+                token,
+                amount,
+            }))
+        }
+    }
+);
+
+/// V4 pools are keyed by their `PoolId`, they are represented by the first 20
+/// bytes of the id. The key is stored when the pool is initialized.
+fn pool_address(key: &PoolKey) -> Address {
+    to_pricing_key(key).pool_address()
+}
+
+fn to_pricing_key(key: &PoolKey) -> uniswap_v4::PoolKey {
+    uniswap_v4::PoolKey {
+        currency0:   key.currency0,
+        currency1:   key.currency1,
+        fee:         key.fee,
+        tickSpacing: key.tickSpacing,
+        hooks:       key.hooks,
+    }
+}
+
+/// Native eth is the zero address in V4.
+fn fetch_currency_info<DB: LibmdbxReader + DBWriter>(
+    currency: Address,
+    db: &DB,
+) -> eyre::Result<TokenInfoWithAddress> {
+    db.try_fetch_token_info(if currency.is_zero() { ETH_ADDRESS } else { currency })
+}
+
+/// A `BalanceDelta` packs amount0 in the upper and amount1 in the lower 128
+/// bits. Deltas are from the callers perspective, negative amounts are owed to
+/// the pool manager.
+fn split_balance_delta(delta: I256) -> (i128, i128) {
+    let raw = delta.into_raw();
+    let amount0 = (raw >> 128).to::<u128>() as i128;
+    let amount1 = (raw & U256::from(u128::MAX)).to::<u128>() as i128;
+
+    (amount0, amount1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_balance_delta() {
+        let amount0: i128 = -1_000_000;
+        let amount1: i128 = 2_500;
+        let packed = (U256::from(amount0 as u128) << 128) | U256::from(amount1 as u128);
+
+        assert_eq!(split_balance_delta(I256::from_raw(packed)), (amount0, amount1));
+    }
+}
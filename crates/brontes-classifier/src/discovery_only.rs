@@ -228,6 +228,16 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> DiscoveryOnlyClassif
                 {
                     error!(pool=?p.pool_address,"failed to update pool config");
                 }
+                if let Some(key) = p.pool_key.clone() {
+                    if self
+                        .libmdbx
+                        .insert_uniswap_v4_pool_key(p.pool_address, key)
+                        .await
+                        .is_err()
+                    {
+                        error!(pool=?p.pool_address,"failed to insert uniswap v4 pool key");
+                    }
+                }
             }
         } else {
             self.classify_transfer(trace_index, &trace, block).await
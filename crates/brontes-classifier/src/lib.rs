@@ -122,6 +122,524 @@ sol! {
     }
 }
 
+// Uniswap V4 PoolManager
+sol! {
+    struct PoolKey {
+        address currency0;
+        address currency1;
+        uint24 fee;
+        int24 tickSpacing;
+        address hooks;
+    }
+
+    struct SwapParams {
+        bool zeroForOne;
+        int256 amountSpecified;
+        uint160 sqrtPriceLimitX96;
+    }
+
+    struct ModifyLiquidityParams {
+        int24 tickLower;
+        int24 tickUpper;
+        int256 liquidityDelta;
+        bytes32 salt;
+    }
+
+    interface UniswapV4PoolManager {
+        event Initialize(
+            bytes32 indexed id,
+            address indexed currency0,
+            address indexed currency1,
+            uint24 fee,
+            int24 tickSpacing,
+            address hooks,
+            uint160 sqrtPriceX96,
+            int24 tick
+        );
+
+        function initialize(PoolKey memory key, uint160 sqrtPriceX96)
+            external returns (int24 tick);
+        function swap(PoolKey memory key, SwapParams memory params, bytes calldata hookData)
+            external returns (int256 swapDelta);
+        function modifyLiquidity(
+            PoolKey memory key,
+            ModifyLiquidityParams memory params,
+            bytes calldata hookData
+        ) external returns (int256 callerDelta, int256 feesAccrued);
+    }
+}
+
+// Seaport. Enums are declared as their `uint8` abi type
+sol! {
+    interface Seaport {
+        struct AdditionalRecipient {
+            uint256 amount;
+            address recipient;
+        }
+
+        struct BasicOrderParameters {
+            address considerationToken;
+            uint256 considerationIdentifier;
+            uint256 considerationAmount;
+            address offerer;
+            address zone;
+            address offerToken;
+            uint256 offerIdentifier;
+            uint256 offerAmount;
+            uint8 basicOrderType;
+            uint256 startTime;
+            uint256 endTime;
+            bytes32 zoneHash;
+            uint256 salt;
+            bytes32 offererConduitKey;
+            bytes32 fulfillerConduitKey;
+            uint256 totalOriginalAdditionalRecipients;
+            AdditionalRecipient[] additionalRecipients;
+            bytes signature;
+        }
+
+        struct OfferItem {
+            uint8 itemType;
+            address token;
+            uint256 identifierOrCriteria;
+            uint256 startAmount;
+            uint256 endAmount;
+        }
+
+        struct ConsiderationItem {
+            uint8 itemType;
+            address token;
+            uint256 identifierOrCriteria;
+            uint256 startAmount;
+            uint256 endAmount;
+            address recipient;
+        }
+
+        struct OrderParameters {
+            address offerer;
+            address zone;
+            OfferItem[] offer;
+            ConsiderationItem[] consideration;
+            uint8 orderType;
+            uint256 startTime;
+            uint256 endTime;
+            bytes32 zoneHash;
+            uint256 salt;
+            bytes32 conduitKey;
+            uint256 totalOriginalConsiderationItems;
+        }
+
+        struct Order {
+            OrderParameters parameters;
+            bytes signature;
+        }
+
+        struct AdvancedOrder {
+            OrderParameters parameters;
+            uint120 numerator;
+            uint120 denominator;
+            bytes signature;
+            bytes extraData;
+        }
+
+        struct CriteriaResolver {
+            uint256 orderIndex;
+            uint8 side;
+            uint256 index;
+            uint256 identifier;
+            bytes32[] criteriaProof;
+        }
+
+        struct SpentItem {
+            uint8 itemType;
+            address token;
+            uint256 identifier;
+            uint256 amount;
+        }
+
+        struct ReceivedItem {
+            uint8 itemType;
+            address token;
+            uint256 identifier;
+            uint256 amount;
+            address recipient;
+        }
+
+        event OrderFulfilled(
+            bytes32 orderHash,
+            address indexed offerer,
+            address indexed zone,
+            address recipient,
+            SpentItem[] offer,
+            ReceivedItem[] consideration
+        );
+
+        function fulfillBasicOrder(BasicOrderParameters calldata parameters)
+            external payable returns (bool fulfilled);
+        function fulfillBasicOrder_efficient_6GL6yc(BasicOrderParameters calldata parameters)
+            external payable returns (bool fulfilled);
+        function fulfillOrder(Order calldata order, bytes32 fulfillerConduitKey)
+            external payable returns (bool fulfilled);
+        function fulfillAdvancedOrder(
+            AdvancedOrder calldata advancedOrder,
+            CriteriaResolver[] calldata criteriaResolvers,
+            bytes32 fulfillerConduitKey,
+            address recipient
+        ) external payable returns (bool fulfilled);
+    }
+}
+
+// Blur Marketplace (BlurExchange)
+sol! {
+    interface BlurExchange {
+        struct Fee {
+            uint16 rate;
+            address recipient;
+        }
+
+        struct Order {
+            address trader;
+            uint8 side;
+            address matchingPolicy;
+            address collection;
+            uint256 tokenId;
+            uint256 amount;
+            address paymentToken;
+            uint256 price;
+            uint256 listingTime;
+            uint256 expirationTime;
+            Fee[] fees;
+            uint256 salt;
+            bytes extraParams;
+        }
+
+        struct Input {
+            Order order;
+            uint8 v;
+            bytes32 r;
+            bytes32 s;
+            bytes extraSignature;
+            uint8 signatureVersion;
+            uint256 blockNumber;
+        }
+
+        event OrdersMatched(
+            address indexed maker,
+            address indexed taker,
+            Order sell,
+            bytes32 sellHash,
+            Order buy,
+            bytes32 buyHash
+        );
+
+        function execute(Input calldata sell, Input calldata buy) external payable;
+    }
+}
+
+// Blur Blend, peer to peer nft lending
+sol! {
+    interface BlurBlend {
+        struct Fee {
+            uint16 rate;
+            address recipient;
+        }
+
+        struct Lien {
+            address lender;
+            address borrower;
+            address collection;
+            uint256 tokenId;
+            uint256 amount;
+            uint256 startTime;
+            uint256 rate;
+            uint256 auctionStartBlock;
+            uint256 auctionDuration;
+        }
+
+        struct LienPointer {
+            Lien lien;
+            uint256 lienId;
+        }
+
+        struct SellOffer {
+            address borrower;
+            uint256 lienId;
+            uint256 price;
+            uint256 expirationTime;
+            uint256 salt;
+            address oracle;
+            Fee[] fees;
+        }
+
+        event BuyLocked(
+            uint256 lienId,
+            address collection,
+            address buyer,
+            address seller,
+            uint256 tokenId
+        );
+        event Seize(uint256 lienId, address collection);
+
+        function buyLocked(Lien calldata lien, SellOffer calldata offer, bytes calldata signature)
+            external;
+        function seize(LienPointer[] calldata lienPointers) external;
+    }
+}
+
+// Compound V3, a market per base token
+sol! {
+    interface CompoundV3Comet {
+        event BuyCollateral(
+            address indexed buyer,
+            address indexed asset,
+            uint256 baseAmount,
+            uint256 collateralAmount
+        );
+
+        function buyCollateral(
+            address asset,
+            uint256 minAmount,
+            uint256 baseAmount,
+            address recipient
+        ) external;
+    }
+}
+
+// Compound V3 configurator, sets up the markets
+sol! {
+    interface CompoundV3Configurator {
+        struct AssetConfig {
+            address asset;
+            address priceFeed;
+            uint8 decimals;
+            uint64 borrowCollateralFactor;
+            uint64 liquidateCollateralFactor;
+            uint64 liquidationFactor;
+            uint128 supplyCap;
+        }
+
+        struct Configuration {
+            address governor;
+            address pauseGuardian;
+            address baseToken;
+            address baseTokenPriceFeed;
+            address extensionDelegate;
+            uint64 supplyKink;
+            uint64 supplyPerYearInterestRateSlopeLow;
+            uint64 supplyPerYearInterestRateSlopeHigh;
+            uint64 supplyPerYearInterestRateBase;
+            uint64 borrowKink;
+            uint64 borrowPerYearInterestRateSlopeLow;
+            uint64 borrowPerYearInterestRateSlopeHigh;
+            uint64 borrowPerYearInterestRateBase;
+            uint64 storeFrontPriceFactor;
+            uint64 trackingIndexScale;
+            uint64 baseTrackingSupplySpeed;
+            uint64 baseTrackingBorrowSpeed;
+            uint104 baseMinForRewards;
+            uint104 baseBorrowMin;
+            uint104 targetReserves;
+            AssetConfig[] assetConfigs;
+        }
+
+        function setConfiguration(address cometProxy, Configuration calldata newConfiguration)
+            external;
+    }
+}
+
+// Morpho Blue singleton, markets are identified by the hash of their params
+sol! {
+    interface MorphoBlue {
+        struct MarketParams {
+            address loanToken;
+            address collateralToken;
+            address oracle;
+            address irm;
+            uint256 lltv;
+        }
+
+        event Liquidate(
+            bytes32 indexed id,
+            address indexed caller,
+            address indexed borrower,
+            uint256 repaidAssets,
+            uint256 repaidShares,
+            uint256 seizedAssets,
+            uint256 badDebtAssets,
+            uint256 badDebtShares
+        );
+
+        function liquidate(
+            MarketParams memory marketParams,
+            address borrower,
+            uint256 seizedAssets,
+            uint256 repaidShares,
+            bytes memory data
+        ) external returns (uint256, uint256);
+    }
+}
+
+// Liquity trove manager
+sol! {
+    interface LiquityTroveManager {
+        event Liquidation(
+            uint256 _liquidatedDebt,
+            uint256 _liquidatedColl,
+            uint256 _collGasCompensation,
+            uint256 _LUSDGasCompensation
+        );
+
+        function liquidate(address _borrower) external;
+        function liquidateTroves(uint256 _n) external;
+        function batchLiquidateTroves(address[] calldata _troveArray) external;
+    }
+}
+
+// Maker liquidations 2.0, the dog starts collateral auctions that are run by
+// a clipper per collateral type. The clipper prices the auction through its
+// abacus, from the starting price of the auction and the time since the kick
+sol! {
+    interface MakerDog {
+        event Bark(
+            bytes32 indexed ilk,
+            address indexed urn,
+            uint256 ink,
+            uint256 art,
+            uint256 due,
+            address clip,
+            uint256 indexed id
+        );
+
+        function bark(bytes32 ilk, address urn, address kpr) external returns (uint256 id);
+    }
+
+    interface MakerClipper {
+        event Take(
+            uint256 indexed id,
+            uint256 max,
+            uint256 price,
+            uint256 owe,
+            uint256 tab,
+            uint256 lot,
+            address indexed usr
+        );
+
+        function take(uint256 id, uint256 amt, uint256 max, address who, bytes calldata data)
+            external;
+        function buf() external view returns (uint256);
+    }
+
+    interface MakerAbacus {
+        function price(uint256 top, uint256 dur) external view returns (uint256);
+    }
+}
+
+// Across intents, a deposit on the origin chain spoke pool is filled by a
+// relayer on the destination chain spoke pool
+sol! {
+    interface AcrossSpokePool {
+        struct V3RelayData {
+            address depositor;
+            address recipient;
+            address exclusiveRelayer;
+            address inputToken;
+            address outputToken;
+            uint256 inputAmount;
+            uint256 outputAmount;
+            uint256 originChainId;
+            uint32 depositId;
+            uint32 fillDeadline;
+            uint32 exclusivityDeadline;
+            bytes message;
+        }
+
+        function depositV3(
+            address depositor,
+            address recipient,
+            address inputToken,
+            address outputToken,
+            uint256 inputAmount,
+            uint256 outputAmount,
+            uint256 destinationChainId,
+            address exclusiveRelayer,
+            uint32 quoteTimestamp,
+            uint32 fillDeadline,
+            uint32 exclusivityDeadline,
+            bytes calldata message
+        ) external payable;
+
+        function fillV3Relay(V3RelayData calldata relayData, uint256 repaymentChainId)
+            external;
+    }
+}
+
+// Arbitrum canonical bridge, eth goes through the inbox and tokens through the
+// gateway router, which routes them to the gateway of the token
+sol! {
+    interface ArbitrumGatewayRouter {
+        function outboundTransfer(
+            address _token,
+            address _to,
+            uint256 _amount,
+            uint256 _maxGas,
+            uint256 _gasPriceBid,
+            bytes calldata _data
+        ) external payable returns (bytes memory);
+    }
+
+    interface ArbitrumInbox {
+        function depositEth() external payable returns (uint256);
+    }
+
+    interface ArbitrumGateway {
+        function finalizeInboundTransfer(
+            address _token,
+            address _from,
+            address _to,
+            uint256 _amount,
+            bytes calldata _data
+        ) external payable;
+    }
+}
+
+// The L1 standard bridge of the OP stack, shared by Optimism and Base
+sol! {
+    interface OpStandardBridge {
+        function depositETH(uint32 _minGasLimit, bytes calldata _extraData) external payable;
+        function depositETHTo(address _to, uint32 _minGasLimit, bytes calldata _extraData)
+            external payable;
+        function depositERC20(
+            address _l1Token,
+            address _l2Token,
+            uint256 _amount,
+            uint32 _minGasLimit,
+            bytes calldata _extraData
+        ) external;
+        function depositERC20To(
+            address _l1Token,
+            address _l2Token,
+            address _to,
+            uint256 _amount,
+            uint32 _minGasLimit,
+            bytes calldata _extraData
+        ) external;
+        function finalizeETHWithdrawal(
+            address _from,
+            address _to,
+            uint256 _amount,
+            bytes calldata _extraData
+        ) external payable;
+        function finalizeERC20Withdrawal(
+            address _l1Token,
+            address _l2Token,
+            address _from,
+            address _to,
+            uint256 _amount,
+            bytes calldata _extraData
+        ) external;
+    }
+}
+
 sol! {
     event Transfer(address indexed from, address indexed to, uint256 value);
     function name() public view returns (string);
@@ -0,0 +1,183 @@
+use alloy_primitives::Address;
+use brontes_types::{
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::{
+        Action, MultiCallFrameClassification, MultiFrameAction, MultiFrameRequest, NodeDataIndex,
+    },
+    utils::ToScaledRational,
+    Protocol, TreeSearchBuilder,
+};
+use malachite::Rational;
+
+use crate::multi_frame_classification::MultiCallFrameClassifier;
+
+pub struct CompoundV2;
+
+impl MultiCallFrameClassifier for CompoundV2 {
+    const KEY: [u8; 2] = [Protocol::CompoundV2 as u8, MultiFrameAction::Lending as u8];
+
+    fn create_classifier(
+        request: MultiFrameRequest,
+    ) -> Option<MultiCallFrameClassification<Action>> {
+        Some(MultiCallFrameClassification {
+            trace_index:         request.trace_idx,
+            tree_search_builder: TreeSearchBuilder::new()
+                .with_actions([Action::is_transfer, Action::is_eth_transfer]),
+            parse_fn:            Box::new(parse_compound_v2),
+        })
+    }
+}
+
+/// Fills in the underlying token and amount of a cToken action. The transfers
+/// are left in the tree as the lending actions don't account for any deltas.
+fn parse_compound_v2(
+    this: &mut Action,
+    child_nodes: Vec<(NodeDataIndex, Action)>,
+) -> Vec<NodeDataIndex> {
+    match this {
+        Action::Loan(loan) => {
+            if let Some((token, amount)) =
+                underlying_transfer(&child_nodes, loan.lender, loan.lender, loan.borrower)
+            {
+                loan.loaned_token = token;
+                loan.loan_amount = amount;
+            }
+        }
+        Action::Repayment(repayment) => {
+            if let Some((token, amount)) = underlying_transfer(
+                &child_nodes,
+                repayment.lender,
+                repayment.payer,
+                repayment.lender,
+            ) {
+                repayment.repayed_token = token;
+                repayment.repayment_amount = amount;
+            }
+        }
+        Action::Supply(supply) => {
+            if let Some((token, amount)) =
+                underlying_transfer(&child_nodes, supply.pool, supply.supplier, supply.pool)
+            {
+                supply.supplied_token = token;
+                supply.supply_amount = amount;
+            }
+        }
+        Action::Withdraw(withdraw) => {
+            if let Some((token, amount)) =
+                underlying_transfer(&child_nodes, withdraw.pool, withdraw.pool, withdraw.recipient)
+            {
+                withdraw.withdrawn_token = token;
+                withdraw.withdraw_amount = amount;
+            }
+        }
+        _ => {}
+    }
+
+    vec![]
+}
+
+/// The first transfer between `from` and `to` that isn't a transfer of the
+/// cToken itself. cEther pays out in native eth.
+fn underlying_transfer(
+    child_nodes: &[(NodeDataIndex, Action)],
+    c_token: Address,
+    from: Address,
+    to: Address,
+) -> Option<(TokenInfoWithAddress, Rational)> {
+    child_nodes.iter().find_map(|(_, action)| match action {
+        Action::Transfer(transfer)
+            if transfer.from == from && transfer.to == to && transfer.token.address != c_token =>
+        {
+            Some((transfer.token.clone(), transfer.amount.clone()))
+        }
+        Action::EthTransfer(transfer) if transfer.from == from && transfer.to == to => {
+            Some((TokenInfoWithAddress::native_eth(), transfer.value.to_scaled_rational(18)))
+        }
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{hex, U256};
+    use brontes_types::{
+        db::token_info::TokenInfo,
+        normalized_actions::{
+            NormalizedEthTransfer, NormalizedLoan, NormalizedSupply, NormalizedTransfer,
+        },
+    };
+
+    use super::*;
+
+    const C_TOKEN: Address = Address::new(hex!("39aa39c021dfbae8fac545936693ac917d5e7563"));
+    const USER: Address = Address::new(hex!("1111111111111111111111111111111111111111"));
+
+    fn token(address: Address, symbol: &str) -> TokenInfoWithAddress {
+        TokenInfoWithAddress {
+            address,
+            inner: TokenInfo { decimals: 6, symbol: symbol.to_string() },
+        }
+    }
+
+    fn transfer(token: TokenInfoWithAddress, from: Address, to: Address, amount: u64) -> Action {
+        Action::Transfer(NormalizedTransfer {
+            from,
+            to,
+            token,
+            amount: Rational::from(amount),
+            ..Default::default()
+        })
+    }
+
+    fn node(idx: u64) -> NodeDataIndex {
+        NodeDataIndex { trace_index: idx, data_idx: 0, multi_data_idx: 0 }
+    }
+
+    #[test]
+    fn test_fills_underlying_of_supply() {
+        let usdc = token(hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48").into(), "USDC");
+        let mut supply = Action::Supply(NormalizedSupply {
+            protocol: Protocol::CompoundV2,
+            pool: C_TOKEN,
+            supplier: USER,
+            on_behalf_of: USER,
+            ..Default::default()
+        });
+
+        // the minted cTokens are sent back to the supplier from the cToken
+        let children = vec![
+            (node(1), transfer(usdc.clone(), USER, C_TOKEN, 100)),
+            (node(2), transfer(token(C_TOKEN, "cUSDC"), C_TOKEN, USER, 4_000)),
+        ];
+        assert!(parse_compound_v2(&mut supply, children).is_empty());
+
+        let supply = supply.try_supply().unwrap();
+        assert_eq!(supply.supplied_token, usdc);
+        assert_eq!(supply.supply_amount, Rational::from(100));
+    }
+
+    #[test]
+    fn test_fills_native_eth_of_c_ether_loan() {
+        let mut loan = Action::Loan(NormalizedLoan {
+            protocol: Protocol::CompoundV2,
+            lender: C_TOKEN,
+            borrower: USER,
+            ..Default::default()
+        });
+
+        let children = vec![(
+            node(1),
+            Action::EthTransfer(NormalizedEthTransfer {
+                from: C_TOKEN,
+                to: USER,
+                value: U256::from(10u64.pow(18)),
+                ..Default::default()
+            }),
+        )];
+        parse_compound_v2(&mut loan, children);
+
+        let loan = loan.try_loan().unwrap();
+        assert_eq!(loan.loaned_token, TokenInfoWithAddress::native_eth());
+        assert_eq!(loan.loan_amount, Rational::from(1));
+    }
+}
@@ -9,6 +9,7 @@ use crate::multi_frame_classification::MultiCallFrameClassifier;
 
 pub struct AaveV2;
 pub struct AaveV3;
+pub struct Spark;
 
 impl MultiCallFrameClassifier for AaveV2 {
     const KEY: [u8; 2] = [Protocol::AaveV2 as u8, MultiFrameAction::Liquidation as u8];
@@ -38,6 +39,20 @@ impl MultiCallFrameClassifier for AaveV3 {
     }
 }
 
+impl MultiCallFrameClassifier for Spark {
+    const KEY: [u8; 2] = [Protocol::Spark as u8, MultiFrameAction::Liquidation as u8];
+
+    fn create_classifier(
+        request: MultiFrameRequest,
+    ) -> Option<MultiCallFrameClassification<Action>> {
+        Some(MultiCallFrameClassification {
+            trace_index:         request.trace_idx,
+            tree_search_builder: TreeSearchBuilder::new().with_action(Action::is_transfer),
+            parse_fn:            Box::new(parse_v2_v3),
+        })
+    }
+}
+
 fn parse_v2_v3(this: &mut Action, child_nodes: Vec<(NodeDataIndex, Action)>) -> Vec<NodeDataIndex> {
     let this = this.try_liquidation_mut().unwrap();
     child_nodes
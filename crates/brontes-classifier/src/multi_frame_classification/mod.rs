@@ -7,6 +7,7 @@
 pub mod aggregator;
 pub mod batch;
 pub mod flash_loan;
+pub mod lending;
 pub mod liquidations;
 
 use aggregator::{OneInchAggregator, OneInchFusion, ZeroXAgg};
@@ -14,7 +15,8 @@ use batch::{Cowswap, UniswapX, ZeroXBatch};
 use brontes_types::normalized_actions::{Action, MultiCallFrameClassification, MultiFrameRequest};
 use flash_loan::{BalancerV2, MakerDss};
 use itertools::Itertools;
-use liquidations::{AaveV2, AaveV3};
+use lending::CompoundV2;
+use liquidations::{AaveV2, AaveV3, Spark};
 use tracing::debug;
 
 use self::flash_loan::Dodo;
@@ -44,10 +46,12 @@ pub fn parse_multi_frame_requests(
             BalancerV2::KEY => BalancerV2::create_classifier(request),
             AaveV2::KEY => AaveV2::create_classifier(request),
             AaveV3::KEY => AaveV3::create_classifier(request),
+            Spark::KEY => Spark::create_classifier(request),
             ZeroXAgg::KEY => ZeroXAgg::create_classifier(request),
             ZeroXBatch::KEY => ZeroXBatch::create_classifier(request),
             MakerDss::KEY => MakerDss::create_classifier(request),
             Dodo::KEY => Dodo::create_classifier(request),
+            CompoundV2::KEY => CompoundV2::create_classifier(request),
             _ => {
                 debug!(?request, "no multi frame classification impl for this request");
                 None
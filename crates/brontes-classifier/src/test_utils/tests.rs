@@ -24,7 +24,7 @@ use brontes_types::{
         address_to_protocol_info::ProtocolInfo, dex::DexQuotes, token_info::TokenInfoWithAddress,
     },
     normalized_actions::{pool::NormalizedNewPool, NormalizedTransfer},
-    structured_trace::TraceActions,
+    structured_trace::{CallFrameInfo, TraceActions},
     tree::BlockTree,
     BrontesTaskManager, FastHashMap, TreeCollector, TreeSearchBuilder, UnboundedYapperReceiver,
 };
@@ -80,16 +80,24 @@ impl ClassifierTestUtils {
                 .protocols_created_range(block + 1, end_block)
                 .unwrap()
                 .into_iter()
-                .flat_map(|(_, pools)| {
-                    pools
-                        .into_iter()
-                        .map(|(addr, protocol, pair)| (addr, (protocol, pair)))
-                        .collect::<Vec<_>>()
-                })
-                .collect::<FastHashMap<_, _>>()
+                .flat_map(|(_, pools)| pools)
+                .fold(
+                    FastHashMap::<_, (_, Vec<_>)>::default(),
+                    |mut acc, (addr, protocol, pair)| {
+                        acc.entry(addr)
+                            .or_insert_with(|| (protocol, vec![]))
+                            .1
+                            .push(pair);
+                        acc
+                    },
+                )
         } else {
             FastHashMap::default()
         };
+        let pool_keys = self
+            .libmdbx
+            .fetch_uniswap_v4_pool_keys()
+            .map_err(|_| ClassifierTestUtilsError::LibmdbxError)?;
         let ctr = Arc::new(AtomicBool::new(false));
         let ex = BrontesTaskManager::current().executor();
 
@@ -104,6 +112,7 @@ impl ClassifierTestUtils {
                 self.get_provider(),
                 block,
                 created_pools,
+                pool_keys,
                 ctr.clone(),
                 None,
                 ex,
@@ -584,6 +593,14 @@ impl ClassifierTestUtils {
         Ok(())
     }
 
+    /// Runs a single call frame through the protocol dispatch. The protocol of
+    /// the target address and the tokens have to be in the db
+    pub fn classify_call_frame(&self, call_info: CallFrameInfo<'_>) -> Option<Action> {
+        ProtocolClassifier::default()
+            .dispatch(call_info, self.trace_loader.libmdbx, 0, 0)
+            .map(|(_, action)| action)
+    }
+
     pub async fn test_discovery_classification(
         &self,
         txes: TxHash,
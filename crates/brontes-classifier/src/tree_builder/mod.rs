@@ -22,6 +22,7 @@ use brontes_types::{
     structured_trace::{TraceActions, TransactionTraceWithLogs, TxTrace},
     traits::TracingProvider,
     tree::{BlockTree, GasDetails, Node, Root},
+    Protocol,
 };
 use futures::future::join_all;
 use itertools::Itertools;
@@ -31,7 +32,7 @@ use reth_rpc_types::trace::parity::{Action as TraceAction, CallType};
 use tokio::sync::mpsc::UnboundedSender;
 use tracing::{error, trace};
 use tree_pruning::{account_for_tax_tokens, remove_possible_transfer_double_counts};
-use utils::{decode_transfer, get_coinbase_transfer};
+use utils::{decode_nft_transfers, decode_transfer, get_coinbase_transfer};
 
 use self::erc20::try_decode_transfer;
 use crate::{
@@ -386,7 +387,7 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
             }
         }
 
-        if let Some(results) =
+        if let Some(mut results) =
             ProtocolClassifier::default().dispatch(call_info, self.libmdbx, block, tx_idx)
         {
             if results.1.is_new_pool() {
@@ -402,9 +403,26 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
                 {
                     error!(pool=?p.pool_address,"failed to update pool config");
                 }
+                if let Some(key) = p.pool_key.clone() {
+                    if self
+                        .libmdbx
+                        .insert_uniswap_v4_pool_key(p.pool_address, key)
+                        .await
+                        .is_err()
+                    {
+                        error!(pool=?p.pool_address,"failed to insert uniswap v4 pool key");
+                    }
+                }
+            } else if let Action::Liquidation(take) = &mut results.1 {
+                if take.protocol == Protocol::MakerClipper {
+                    take.collateral_discount =
+                        take_discount(take, &trace, full_trace, &self.provider, block).await;
+                }
             }
 
             (vec![results.0], vec![results.1])
+        } else if let Some(nft_transfers) = self.classify_nft_transfers(trace_index, &trace) {
+            return nft_transfers
         } else if let Some(transfer) = self
             .classify_transfer(tx_idx, trace_index, &trace, block)
             .await
@@ -420,6 +438,38 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
         }
     }
 
+    /// ERC-721 `transferFrom` shares its selector with the ERC-20 one, nft
+    /// transfers are therefore classified from the logs before trying to
+    /// decode a token transfer.
+    fn classify_nft_transfers(
+        &self,
+        trace_idx: u64,
+        trace: &TransactionTraceWithLogs,
+    ) -> Option<(Vec<DexPriceMsg>, Vec<Action>)> {
+        let mut result = trace
+            .logs
+            .iter()
+            .flat_map(|log| decode_nft_transfers(trace_idx, log))
+            .map(Action::NftTransfer)
+            .collect::<Vec<_>>();
+
+        if result.is_empty() {
+            return None
+        }
+
+        if trace.get_msg_value() != U256::ZERO && !trace.is_delegate_call() {
+            result.push(Action::EthTransfer(NormalizedEthTransfer {
+                coinbase_transfer: false,
+                trace_index:       trace_idx,
+                to:                trace.get_to_address(),
+                from:              trace.get_from_addr(),
+                value:             trace.get_msg_value(),
+            }));
+        }
+
+        Some((vec![], result))
+    }
+
     async fn classify_transfer(
         &self,
         tx_idx: u64,
@@ -1,7 +1,26 @@
 use alloy_primitives::{Address, FixedBytes, Log, B256, U256};
+use alloy_sol_types::SolEvent;
+use brontes_types::normalized_actions::{NftStandard, NormalizedNftTransfer};
 use hex_literal::hex;
 use reth_rpc_types::trace::parity::Action;
 
+alloy_sol_macro::sol!(
+    event TransferSingle(
+        address indexed operator,
+        address indexed from,
+        address indexed to,
+        uint256 id,
+        uint256 value
+    );
+    event TransferBatch(
+        address indexed operator,
+        address indexed from,
+        address indexed to,
+        uint256[] ids,
+        uint256[] values
+    );
+);
+
 pub(crate) fn get_coinbase_transfer(builder: Address, action: &Action) -> Option<u128> {
     match action {
         Action::Call(action) => {
@@ -31,3 +50,129 @@ pub(crate) fn decode_transfer(log: &Log) -> Option<(Address, Address, Address, U
 
     None
 }
+
+/// Decodes the ERC-721 `Transfer` and the ERC-1155 `TransferSingle` and
+/// `TransferBatch` logs. ERC-721 transfers share the topic of ERC-20 transfers
+/// but also index the token id.
+pub(crate) fn decode_nft_transfers(trace_index: u64, log: &Log) -> Vec<NormalizedNftTransfer> {
+    let topics = log.topics();
+    let Some(topic) = topics.first() else { return vec![] };
+
+    if *topic == TRANSFER_TOPIC && topics.len() == 4 && log.data.data.is_empty() {
+        return vec![NormalizedNftTransfer {
+            trace_index,
+            collection: log.address,
+            standard: NftStandard::Erc721,
+            from: Address::from_slice(&topics[1][12..]),
+            to: Address::from_slice(&topics[2][12..]),
+            token_id: U256::from_be_slice(topics[3].as_slice()),
+            amount: U256::from(1),
+        }]
+    }
+
+    if *topic == TransferSingle::SIGNATURE_HASH {
+        return TransferSingle::decode_log_data(&log.data, false)
+            .map(|transfer| {
+                vec![NormalizedNftTransfer {
+                    trace_index,
+                    collection: log.address,
+                    standard: NftStandard::Erc1155,
+                    from: transfer.from,
+                    to: transfer.to,
+                    token_id: transfer.id,
+                    amount: transfer.value,
+                }]
+            })
+            .unwrap_or_default()
+    }
+
+    if *topic == TransferBatch::SIGNATURE_HASH {
+        return TransferBatch::decode_log_data(&log.data, false)
+            .map(|transfer| {
+                transfer
+                    .ids
+                    .into_iter()
+                    .zip(transfer.values)
+                    .map(|(token_id, amount)| NormalizedNftTransfer {
+                        trace_index,
+                        collection: log.address,
+                        standard: NftStandard::Erc1155,
+                        from: transfer.from,
+                        to: transfer.to,
+                        token_id,
+                        amount,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    vec![]
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Bytes, LogData};
+
+    use super::*;
+
+    const COLLECTION: Address = Address::new(hex!("bc4ca0eda7647a8ab7c2061c2e118a18a936f13d"));
+    const FROM: Address = Address::new(hex!("1111111111111111111111111111111111111111"));
+    const TO: Address = Address::new(hex!("2222222222222222222222222222222222222222"));
+
+    fn log(topics: Vec<B256>, data: Bytes) -> Log {
+        Log { address: COLLECTION, data: LogData::new_unchecked(topics, data) }
+    }
+
+    #[test]
+    fn test_decode_erc721_transfer() {
+        let transfer = log(
+            vec![TRANSFER_TOPIC, FROM.into_word(), TO.into_word(), U256::from(42).into()],
+            Bytes::new(),
+        );
+
+        assert_eq!(
+            decode_nft_transfers(3, &transfer),
+            vec![NormalizedNftTransfer {
+                trace_index: 3,
+                collection:  COLLECTION,
+                standard:    NftStandard::Erc721,
+                from:        FROM,
+                to:          TO,
+                token_id:    U256::from(42),
+                amount:      U256::from(1),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_erc20_transfer_is_not_an_nft_transfer() {
+        let transfer = log(
+            vec![TRANSFER_TOPIC, FROM.into_word(), TO.into_word()],
+            U256::from(42).to_be_bytes_vec().into(),
+        );
+
+        assert!(decode_nft_transfers(3, &transfer).is_empty());
+        assert!(decode_transfer(&transfer).is_some());
+    }
+
+    #[test]
+    fn test_decode_erc1155_transfer_batch() {
+        let event = TransferBatch {
+            operator: TO,
+            from:     FROM,
+            to:       TO,
+            ids:      vec![U256::from(1), U256::from(2)],
+            values:   vec![U256::from(10), U256::from(20)],
+        };
+        let transfer = Log { address: COLLECTION, data: event.encode_log_data() };
+
+        let decoded = decode_nft_transfers(0, &transfer);
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[1].token_id, U256::from(2));
+        assert_eq!(decoded[1].amount, U256::from(20));
+        assert!(decoded
+            .iter()
+            .all(|transfer| transfer.standard == NftStandard::Erc1155));
+    }
+}
@@ -221,6 +221,7 @@ impl TraceLoader {
     ) -> eyre::Result<Metadata> {
         let mut meta = self.libmdbx.get_metadata(block_num, quote_asset)?;
         meta.cex_trades = Some(self.load_cex_trades(block_num));
+        meta.perp_tickers = self.libmdbx.get_perp_tickers(block_num).ok();
 
         Ok(meta)
     }
@@ -230,6 +231,7 @@ impl TraceLoader {
             .libmdbx
             .get_metadata_no_dex_price(block_num, quote_asset)?;
         meta.cex_trades = Some(self.load_cex_trades(block_num));
+        meta.perp_tickers = self.libmdbx.get_perp_tickers(block_num).ok();
 
         Ok(meta)
     }
@@ -1,14 +1,22 @@
-use brontes_types::db::cex::CexExchange;
+use std::sync::Arc;
+
+use brontes_types::db::cex::{CexExchange, CexFeeSchedule};
 
 #[derive(Debug, Clone)]
 pub struct CexDownloadConfig {
     pub run_time_window:  (u64, u64),
     pub exchanges_to_use: Vec<CexExchange>,
+    /// The fees the eth price of the block metadata is adjusted with
+    pub cex_fee_schedule: Arc<CexFeeSchedule>,
 }
 
 impl CexDownloadConfig {
-    pub fn new(run_time_window: (u64, u64), exchanges_to_use: Vec<CexExchange>) -> Self {
-        Self { run_time_window, exchanges_to_use }
+    pub fn new(
+        run_time_window: (u64, u64),
+        exchanges_to_use: Vec<CexExchange>,
+        cex_fee_schedule: Arc<CexFeeSchedule>,
+    ) -> Self {
+        Self { run_time_window, exchanges_to_use, cex_fee_schedule }
     }
 }
 
@@ -23,6 +31,7 @@ impl Default for CexDownloadConfig {
                 CexExchange::BybitSpot,
                 CexExchange::Kucoin,
             ],
+            cex_fee_schedule: Arc::default(),
         }
     }
 }
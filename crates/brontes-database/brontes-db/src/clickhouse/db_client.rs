@@ -10,12 +10,14 @@ use brontes_types::{
     db::{
         address_to_protocol_info::ProtocolInfoClickhouse,
         block_analysis::BlockAnalysis,
+        block_auction::BlockAuction,
         builder::BuilderInfo,
         cex::{
             quotes::{CexQuotesConverter, RawCexQuotes},
-            trades::{CexTradesConverter, RawCexTrades},
+            trades::{CexTradesConverter, RawCexTrades, RawPerpTicker},
             BestCexPerPair,
         },
+        dedup_decision::DedupDecision,
         dex::{DexQuotes, DexQuotesWithBlockNumber},
         metadata::{BlockMetadata, BlockMetadataInner, Metadata},
         normalized_actions::TransactionRoot,
@@ -41,12 +43,15 @@ use eyre::Result;
 use itertools::Itertools;
 use reth_primitives::{BlockHash, TxHash};
 use serde::{Deserialize, Serialize};
-use tokio::{sync::mpsc::UnboundedSender, time::Duration};
+use tokio::{
+    sync::{mpsc::UnboundedSender, oneshot},
+    time::Duration,
+};
 use tracing::{debug, error, warn};
 
 use super::{
     cex_config::CexDownloadConfig, dbms::*, ClickhouseHandle, MOST_VOLUME_PAIR_EXCHANGE,
-    P2P_OBSERVATIONS, PRIVATE_FLOW, RAW_CEX_QUOTES, RAW_CEX_TRADES,
+    P2P_OBSERVATIONS, PRIVATE_FLOW, RAW_CEX_QUOTES, RAW_CEX_TRADES, RAW_PERP_TICKERS,
 };
 #[cfg(feature = "local-clickhouse")]
 use super::{BLOCK_TIMES, CEX_SYMBOLS};
@@ -61,6 +66,28 @@ use crate::{
 const SECONDS_TO_US: f64 = 1_000_000.0;
 const MAX_MARKOUT_TIME: f64 = 300.0;
 
+/// Tables holding data derived from a single block
+const REORGABLE_TABLES: [&str; 18] = [
+    "mev.mev_blocks",
+    "mev.bundle_header",
+    "mev.searcher_tx",
+    "mev.cex_dex_quotes",
+    "mev.cex_dex",
+    "mev.liquidations",
+    "mev.long_tail",
+    "mev.nft",
+    "mev.cross_domain",
+    "mev.jit_sandwich",
+    "mev.jit",
+    "mev.sandwiches",
+    "mev.atomic_arbs",
+    "brontes.dex_price_mapping",
+    "brontes.block_analysis",
+    "brontes.block_auctions",
+    "brontes.dedup_decisions",
+    "brontes.tree",
+];
+
 #[derive(Clone)]
 pub struct Clickhouse {
     pub tip:                 bool,
@@ -68,6 +95,8 @@ pub struct Clickhouse {
     pub client:              ClickhouseClient<BrontesClickhouseTables>,
     pub cex_download_config: CexDownloadConfig,
     pub buffered_insert_tx:  Option<UnboundedSender<Vec<BrontesClickhouseData>>>,
+    /// Asks the buffered inserter to write out everything sent so far
+    pub buffered_flush_tx:   Option<UnboundedSender<oneshot::Sender<()>>>,
 }
 
 impl Clickhouse {
@@ -79,7 +108,14 @@ impl Clickhouse {
         run_id: Option<u64>,
     ) -> Self {
         let client = config.build();
-        let mut this = Self { client, cex_download_config, buffered_insert_tx, tip, run_id: 0 };
+        let mut this = Self {
+            client,
+            cex_download_config,
+            buffered_insert_tx,
+            buffered_flush_tx: None,
+            tip,
+            run_id: 0,
+        };
 
         this.run_id = if let Some(run_id) = run_id {
             run_id
@@ -182,6 +218,11 @@ impl Clickhouse {
                     BundleData::Liquidation(s) => {
                         tx.send(vec![(s, self.tip, self.run_id).into()])?
                     }
+                    BundleData::LongTail(s) => tx.send(vec![(s, self.tip, self.run_id).into()])?,
+                    BundleData::Nft(s) => tx.send(vec![(s, self.tip, self.run_id).into()])?,
+                    BundleData::CrossDomain(s) => {
+                        tx.send(vec![(s, self.tip, self.run_id).into()])?
+                    }
                     BundleData::Unknown(s) => tx.send(vec![(s, self.tip, self.run_id).into()])?,
                 };
 
@@ -275,10 +316,63 @@ impl Clickhouse {
         Ok(())
     }
 
+    pub async fn block_auction(&self, block_auction: BlockAuction) -> eyre::Result<()> {
+        if let Some(tx) = self.buffered_insert_tx.as_ref() {
+            tx.send(vec![(block_auction, self.tip, self.run_id).into()])?
+        };
+
+        Ok(())
+    }
+
+    pub async fn dedup_decisions(&self, decisions: Vec<DedupDecision>) -> eyre::Result<()> {
+        if let Some(tx) = self.buffered_insert_tx.as_ref() {
+            tx.send(
+                decisions
+                    .into_iter()
+                    .map(|decision| (decision, self.tip, self.run_id).into())
+                    .collect(),
+            )?
+        };
+
+        Ok(())
+    }
+
     pub async fn save_traces(&self, _block: u64, _traces: Vec<TxTrace>) -> eyre::Result<()> {
         Ok(())
     }
 
+    /// Deletes all rows of a reorged block
+    pub async fn revert_block(&self, block_number: u64) -> eyre::Result<()> {
+        // buffered inserts could contain the reverted block, so they need to land
+        // before we delete
+        self.flush_buffered_inserts().await?;
+
+        for table in REORGABLE_TABLES {
+            self.client
+                .execute_remote(
+                    &format!("DELETE FROM {table} WHERE block_number = ?"),
+                    &(block_number,),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Waits until the buffered inserter has written out everything that was
+    /// sent to it before this call
+    pub async fn flush_buffered_inserts(&self) -> eyre::Result<()> {
+        let Some(flush_tx) = self.buffered_flush_tx.as_ref() else { return Ok(()) };
+
+        let (ack_tx, ack_rx) = oneshot::channel();
+        flush_tx
+            .send(ack_tx)
+            .map_err(|_| eyre::eyre!("clickhouse buffered inserter is gone"))?;
+        ack_rx.await?;
+
+        Ok(())
+    }
+
     async fn query_many_with_retry<Q, P>(
         &self,
         query: impl AsRef<str> + Send,
@@ -464,6 +558,7 @@ impl ClickhouseHandle for Clickhouse {
             &cex_quotes.value,
             block_meta.block_timestamp * 1_000_000,
             quote_asset,
+            &self.cex_download_config.cex_fee_schedule,
         );
 
         let meta = BlockMetadata::new(
@@ -668,6 +763,118 @@ impl ClickhouseHandle for Clickhouse {
         range_or_arbitrary: CexRangeOrArbitrary,
     ) -> eyre::Result<Vec<crate::CexTradesData>> {
         debug!("Starting get_cex_trades function");
+        let block_times = self.cex_trades_block_times(range_or_arbitrary).await?;
+
+        debug!("Querying CEX symbols");
+        let symbols: Vec<CexSymbols> = self.client.query_many(CEX_SYMBOLS, &()).await?;
+        debug!("Retrieved {} CEX symbols", symbols.len());
+
+        let exchanges_str = self
+            .cex_download_config
+            .clone()
+            .exchanges_to_use
+            .into_iter()
+            .map(|s| s.to_clickhouse_filter().to_string())
+            .collect::<Vec<String>>()
+            .join(" OR ");
+        debug!("Using exchanges filter: {}", exchanges_str);
+
+        let time_filter = cex_trades_time_filter(&range_or_arbitrary, &block_times);
+        debug!("Querying raw CEX trades for {time_filter}");
+
+        let query = RAW_CEX_TRADES.replace(
+            "c.timestamp >= ? AND c.timestamp < ?",
+            &format!("({time_filter}) AND ({exchanges_str})"),
+        );
+        let data: Vec<RawCexTrades> = self.query_many_with_retry(query, &()).await?;
+
+        debug!("Retrieved {} raw CEX trades", data.len());
+
+        let trades_converter = CexTradesConverter::new(block_times, symbols, data);
+
+        debug!("Converting raw trades to CexTradesData");
+        let trades: Vec<crate::CexTradesData> = trades_converter
+            .convert_to_trades()
+            .into_iter()
+            .map(|(block_num, trade_map)| crate::CexTradesData::new(block_num, trade_map))
+            .collect();
+
+        debug!("Converted {} CexTradesData entries", trades.len());
+
+        Ok(trades)
+    }
+
+    async fn get_perp_tickers(
+        &self,
+        range_or_arbitrary: CexRangeOrArbitrary,
+    ) -> eyre::Result<Vec<crate::CexPerpTickersData>> {
+        let block_times = self.cex_trades_block_times(range_or_arbitrary).await?;
+        let symbols: Vec<CexSymbols> = self.client.query_many(CEX_SYMBOLS, &()).await?;
+
+        // the tickers are of the perpetual venues, which the exchange filter of
+        // the spot venues doesn't match, so they're filtered once parsed
+        let time_filter = cex_trades_time_filter(&range_or_arbitrary, &block_times);
+        let query = RAW_PERP_TICKERS
+            .replace("c.timestamp >= ? AND c.timestamp < ?", &format!("({time_filter})"));
+        let tickers: Vec<RawPerpTicker> = self
+            .query_many_with_retry::<RawPerpTicker, _>(query, &())
+            .await?
+            .into_iter()
+            .filter(|t| {
+                self.cex_download_config
+                    .exchanges_to_use
+                    .contains(&t.exchange)
+            })
+            .collect();
+
+        debug!("Retrieved {} raw perp tickers", tickers.len());
+
+        Ok(CexTradesConverter::new(block_times, symbols, vec![])
+            .convert_to_perp_tickers(tickers)
+            .into_iter()
+            .map(|(block_num, ticker_map)| crate::CexPerpTickersData::new(block_num, ticker_map))
+            .collect())
+    }
+}
+
+/// The filter of the trades, or perp tickers, queries on the trade window of
+/// the blocks
+fn cex_trades_time_filter(
+    range_or_arbitrary: &CexRangeOrArbitrary,
+    block_times: &[BlockTimes],
+) -> String {
+    match range_or_arbitrary {
+        CexRangeOrArbitrary::Range(..) => {
+            let start_time = block_times
+                .iter()
+                .min_by_key(|b| b.timestamp)
+                .map(|b| b.timestamp)
+                .unwrap() as f64
+                - (6.0 * SECONDS_TO_US);
+            let end_time = block_times
+                .iter()
+                .max_by_key(|b| b.timestamp)
+                .map(|b| b.timestamp)
+                .unwrap() as f64
+                + (6.0 * SECONDS_TO_US);
+
+            format!("c.timestamp >= {start_time} AND c.timestamp < {end_time}")
+        }
+        CexRangeOrArbitrary::Arbitrary(_) | CexRangeOrArbitrary::Timestamp { .. } => block_times
+            .iter()
+            .map(|b| b.convert_to_timestamp_query(6.0 * SECONDS_TO_US, 6.0 * SECONDS_TO_US))
+            .collect::<Vec<String>>()
+            .join(" OR "),
+    }
+}
+
+impl Clickhouse {
+    /// The block times of the range, widened by the run time window of the
+    /// download config
+    async fn cex_trades_block_times(
+        &self,
+        range_or_arbitrary: CexRangeOrArbitrary,
+    ) -> eyre::Result<Vec<BlockTimes>> {
         let block_times: Vec<BlockTimes> = match range_or_arbitrary {
             CexRangeOrArbitrary::Range(mut s, mut e) => {
                 s -= self.cex_download_config.run_time_window.0;
@@ -709,101 +916,9 @@ impl ClickhouseHandle for Clickhouse {
             eyre::bail!("No block times found");
         }
 
-        debug!("Querying CEX symbols");
-        let symbols: Vec<CexSymbols> = self.client.query_many(CEX_SYMBOLS, &()).await?;
-        debug!("Retrieved {} CEX symbols", symbols.len());
-
-        let exchanges_str = self
-            .cex_download_config
-            .clone()
-            .exchanges_to_use
-            .into_iter()
-            .map(|s| s.to_clickhouse_filter().to_string())
-            .collect::<Vec<String>>()
-            .join(" OR ");
-        debug!("Using exchanges filter: {}", exchanges_str);
-
-        let data: Vec<RawCexTrades> = match range_or_arbitrary {
-            CexRangeOrArbitrary::Range(..) => {
-                let start_time = block_times
-                    .iter()
-                    .min_by_key(|b| b.timestamp)
-                    .map(|b| b.timestamp)
-                    .unwrap() as f64
-                    - (6.0 * SECONDS_TO_US);
-                let end_time = block_times
-                    .iter()
-                    .max_by_key(|b| b.timestamp)
-                    .map(|b| b.timestamp)
-                    .unwrap() as f64
-                    + (6.0 * SECONDS_TO_US);
-
-                debug!(
-                    "Querying raw CEX trades for time range: start={}, end={}",
-                    start_time, end_time
-                );
-
-                let mut query = RAW_CEX_TRADES.to_string();
-                query = query.replace(
-                    "c.timestamp >= ? AND c.timestamp < ?",
-                    &format!(
-                        "c.timestamp >= {start_time} AND c.timestamp < {end_time} 
-                        and ({exchanges_str})"
-                    ),
-                );
-                self.query_many_with_retry(query, &()).await?
-            }
-            CexRangeOrArbitrary::Arbitrary(_) => {
-                let mut query = RAW_CEX_TRADES.to_string();
-                let query_mod = block_times
-                    .iter()
-                    .map(|b| b.convert_to_timestamp_query(6.0 * SECONDS_TO_US, 6.0 * SECONDS_TO_US))
-                    .collect::<Vec<String>>()
-                    .join(" OR ");
-
-                debug!("Querying raw CEX trades for arbitrary block times");
-
-                query = query.replace(
-                    "c.timestamp >= ? AND c.timestamp < ?",
-                    &format!("({query_mod}) AND ({exchanges_str})"),
-                );
-                self.query_many_with_retry(query, &()).await?
-            }
-
-            CexRangeOrArbitrary::Timestamp { block_number, block_timestamp } => {
-                let mut query = RAW_CEX_TRADES.to_string();
-                let query_mod = BlockTimes { block_number, timestamp: block_timestamp * 1000000 }
-                    .convert_to_timestamp_query(6.0 * SECONDS_TO_US, 6.0 * SECONDS_TO_US);
-
-                debug!("Querying raw CEX trades for block number {block_number}");
-
-                query = query.replace(
-                    "c.timestamp >= ? AND c.timestamp < ?",
-                    &format!("({query_mod}) AND ({exchanges_str})"),
-                );
-
-                self.query_many_with_retry(query, &()).await?
-            }
-        };
-
-        debug!("Retrieved {} raw CEX trades", data.len());
-
-        let trades_converter = CexTradesConverter::new(block_times, symbols, data);
-
-        debug!("Converting raw trades to CexTradesData");
-        let trades: Vec<crate::CexTradesData> = trades_converter
-            .convert_to_trades()
-            .into_iter()
-            .map(|(block_num, trade_map)| crate::CexTradesData::new(block_num, trade_map))
-            .collect();
-
-        debug!("Converted {} CexTradesData entries", trades.len());
-
-        Ok(trades)
+        Ok(block_times)
     }
-}
 
-impl Clickhouse {
     pub async fn fetch_symbol_rank(
         &self,
         block_times: &[BlockTimes],
@@ -1000,18 +1115,20 @@ mod tests {
     use brontes_classifier::test_utils::ClassifierTestUtils;
     use brontes_types::{
         block_metadata::RelayBlockMetadata,
-        db::{cex::CexExchange, dex::DexPrices, DbDataWithRunId},
+        db::{block_auction::AuctionBid, cex::CexExchange, dex::DexPrices, DbDataWithRunId},
         init_thread_pools,
         mev::{
-            ArbDetails, AtomicArb, BundleHeader, CexDex, CexDexQuote, JitLiquidity,
-            JitLiquiditySandwich, Liquidation, OptimisticTrade, PossibleMev, PossibleMevCollection,
+            ArbDetails, AtomicArb, BundleHeader, CexDex, CexDexQuote, CrossDomainArb, FeeTierPnl,
+            JitLiquidity, JitLiquiditySandwich, Liquidation, LongTail, MevType, NftMev,
+            OptimisticTrade, PerpHedgePnl, PossibleMev, PossibleMevCollection, QuoteFeeTierPnl,
             Sandwich,
         },
         normalized_actions::{
-            NormalizedBurn, NormalizedLiquidation, NormalizedMint, NormalizedSwap,
+            NormalizedBridgeDeposit, NormalizedBridgeFill, NormalizedBurn, NormalizedLiquidation,
+            NormalizedMint, NormalizedNftTrade, NormalizedSwap,
         },
         pair::Pair,
-        FastHashMap, GasDetails,
+        FastHashMap, GasDetails, UnboundedYapperReceiver,
     };
     use db_interfaces::{
         clickhouse::{dbms::ClickhouseDBMS, test_utils::ClickhouseTestClient},
@@ -1020,6 +1137,7 @@ mod tests {
     use malachite::{num::basic::traits::Zero, Rational};
 
     use super::*;
+    use crate::clickhouse::ClickhouseBuffered;
 
     #[brontes_macros::test]
     async fn test_get_private_flow() {
@@ -1193,6 +1311,12 @@ mod tests {
             optimistic_trade_details: vec![vec![opt_trade.clone()]],
             per_exchange_details: vec![vec![arb_detail.clone()]],
             per_exchange_pnl: vec![(cex_exchange, (Rational::ZERO, Rational::ZERO))],
+            per_exchange_tier_pnl: vec![FeeTierPnl {
+                tier: "retail".to_string(),
+                exchange: cex_exchange,
+                ..Default::default()
+            }],
+            perp_hedge_pnl: vec![PerpHedgePnl { exchange: cex_exchange, ..Default::default() }],
             ..CexDex::default()
         };
 
@@ -1234,6 +1358,10 @@ mod tests {
             t300_mid_price:    vec![0.0006263290093187073],
             exchange:          CexExchange::Binance,
             pnl:               12951.829205242997,
+            tier_pnl:          vec![QuoteFeeTierPnl {
+                tier: "best".to_string(),
+                pnl:  12951.829205242997,
+            }],
             gas_details:       GasDetails {
                 coinbase_transfer:   Some(11419369165096275986),
                 priority_fee:        0,
@@ -1299,6 +1427,54 @@ mod tests {
             .unwrap();
     }
 
+    async fn long_tail(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
+        let swap = NormalizedSwap::default();
+        let gas_details = GasDetails::default();
+        let case0 = LongTail {
+            entry_tx_hashes: vec![Default::default()],
+            entry_block_numbers: vec![0],
+            entry_swaps: vec![vec![swap.clone()]],
+            entry_gas_details: vec![gas_details],
+            exit_tx_hashes: vec![Default::default()],
+            exit_swaps: vec![vec![swap]],
+            exit_gas_details: vec![gas_details],
+            ..LongTail::default()
+        };
+
+        db.insert_one::<MevLong_Tail>(&DbDataWithRunId::new_with_run_id(case0, 0))
+            .await
+            .unwrap();
+    }
+
+    async fn nft(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
+        let trade = NormalizedNftTrade::default();
+        let case0 = NftMev {
+            tx_hashes: vec![Default::default()],
+            buys: vec![trade.clone()],
+            sells: vec![trade],
+            gas_details: vec![GasDetails::default()],
+            ..NftMev::default()
+        };
+
+        db.insert_one::<MevNft>(&DbDataWithRunId::new_with_run_id(case0, 0))
+            .await
+            .unwrap();
+    }
+
+    async fn cross_domain(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
+        let case0 = CrossDomainArb {
+            mainnet_swaps: vec![NormalizedSwap::default()],
+            deposits: vec![NormalizedBridgeDeposit::default()],
+            fills: vec![NormalizedBridgeFill::default()],
+            l2_swaps: vec![Default::default()],
+            ..CrossDomainArb::default()
+        };
+
+        db.insert_one::<MevCross_Domain>(&DbDataWithRunId::new_with_run_id(case0, 0))
+            .await
+            .unwrap();
+    }
+
     async fn bundle_header(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
         let case0 = BundleHeader::default();
 
@@ -1364,6 +1540,46 @@ mod tests {
             .unwrap();
     }
 
+    async fn block_auction(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
+        let bid = AuctionBid {
+            relay:          Relays::UltraSound,
+            builder_pubkey: "0xa32aadb23e45595fe4981114a8230128443fd5407d557dc0".to_string(),
+            block_hash:     format!("{:?}", BlockHash::with_last_byte(1)),
+            value:          50000000000000000,
+            timestamp_ms:   1705173443953,
+        };
+        let case0 = BlockAuction {
+            block_number:        19000000,
+            slot:                8203456,
+            winning_bid:         bid.clone(),
+            runner_up_bid:       Some(bid.clone()),
+            bid_margin:          Some(0),
+            builder_eth_profit:  0.01,
+            builder_profit_usd:  25.0,
+            builder_subsidy_eth: 0.0,
+            bids:                vec![bid],
+        };
+
+        db.insert_one::<BrontesBlock_Auctions>(&DbDataWithRunId::new_with_run_id(case0, 0))
+            .await
+            .unwrap();
+    }
+
+    async fn dedup_decisions(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
+        let case0 = DedupDecision {
+            block_number:     19000000,
+            rule:             0,
+            dominant_type:    MevType::AtomicArb,
+            dominant_tx_hash: TxHash::with_last_byte(1),
+            removed_type:     MevType::CexDexTrades,
+            removed_tx_hash:  TxHash::with_last_byte(2),
+        };
+
+        db.insert_one::<BrontesDedup_Decisions>(&DbDataWithRunId::new_with_run_id(case0, 0))
+            .await
+            .unwrap();
+    }
+
     async fn tree(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
         let tree = load_tree().await;
 
@@ -1387,6 +1603,9 @@ mod tests {
         sandwich(database).await;
         bundle_header(database).await;
         liquidations(database).await;
+        long_tail(database).await;
+        nft(database).await;
+        cross_domain(database).await;
         jit_sandwich(database).await;
         jit(database).await;
         cex_dex(database).await;
@@ -1396,6 +1615,8 @@ mod tests {
         token_info(database).await;
         tree(database).await;
         block_analysis(database).await;
+        block_auction(database).await;
+        dedup_decisions(database).await;
     }
 
     #[brontes_macros::test]
@@ -1408,4 +1629,58 @@ mod tests {
             .run_test_with_test_db(tables, |db| Box::pin(run_all(db)))
             .await;
     }
+
+    #[brontes_macros::test]
+    async fn test_revert_block_with_buffered_rows() {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let (flush_tx, flush_rx) = tokio::sync::mpsc::unbounded_channel();
+        // big enough buffers that nothing is inserted unless flushed
+        tokio::spawn(ClickhouseBuffered::new(
+            UnboundedYapperReceiver::new(rx, 1500, "test buffered".to_string()),
+            clickhouse_config(),
+            usize::MAX,
+            usize::MAX,
+            None,
+            Some(flush_rx),
+        ));
+
+        let mut test_db = Clickhouse::new_default(Some(0)).await;
+        test_db.buffered_insert_tx = Some(tx);
+        test_db.buffered_flush_tx = Some(flush_tx);
+
+        let block_number = 1;
+        let bid = AuctionBid {
+            relay:          Relays::UltraSound,
+            builder_pubkey: "0xa32aadb23e45595fe4981114a8230128443fd5407d557dc0".to_string(),
+            block_hash:     format!("{:?}", BlockHash::with_last_byte(1)),
+            value:          50000000000000000,
+            timestamp_ms:   1705173443953,
+        };
+        let auction = BlockAuction {
+            block_number,
+            slot: 1,
+            winning_bid: bid.clone(),
+            runner_up_bid: None,
+            bid_margin: None,
+            builder_eth_profit: 0.01,
+            builder_profit_usd: 25.0,
+            builder_subsidy_eth: 0.0,
+            bids: vec![bid],
+        };
+        test_db.block_auction(auction).await.unwrap();
+
+        test_db.revert_block(block_number).await.unwrap();
+        // nothing of the reverted block may be left to land after the delete
+        test_db.flush_buffered_inserts().await.unwrap();
+
+        let rows = test_db
+            .client
+            .query_one::<u64, _>(
+                "SELECT count() FROM brontes.block_auctions WHERE block_number = ?",
+                &(block_number,),
+            )
+            .await
+            .unwrap();
+        assert_eq!(rows, 0);
+    }
 }
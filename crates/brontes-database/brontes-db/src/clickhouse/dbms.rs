@@ -1,8 +1,9 @@
 use brontes_types::{
     db::{
         address_to_protocol_info::ProtocolInfoClickhouse, block_analysis::BlockAnalysis,
-        dex::DexQuotesWithBlockNumber, normalized_actions::TransactionRoot,
-        token_info::TokenInfoWithAddress, DbDataWithRunId, RunId,
+        block_auction::BlockAuction, dedup_decision::DedupDecision, dex::DexQuotesWithBlockNumber,
+        normalized_actions::TransactionRoot, token_info::TokenInfoWithAddress, DbDataWithRunId,
+        RunId,
     },
     mev::*,
 };
@@ -14,12 +15,17 @@ clickhouse_dbms!(
     [
         BrontesDex_Price_Mapping,
         BrontesBlock_Analysis,
+        BrontesBlock_Auctions,
+        BrontesDedup_Decisions,
         MevMev_Blocks,
         MevBundle_Header,
         MevSearcher_Tx,
         MevCex_Dex_Quotes,
         MevCex_Dex,
         MevLiquidations,
+        MevLong_Tail,
+        MevNft,
+        MevCross_Domain,
         MevJit_Sandwich,
         MevJit,
         MevSandwiches,
@@ -55,6 +61,20 @@ remote_clickhouse_table!(
     "crates/brontes-database/brontes-db/src/clickhouse/tables/"
 );
 
+remote_clickhouse_table!(
+    BrontesClickhouseTables,
+    [Brontes, Block_Auctions],
+    DbDataWithRunId<BlockAuction>,
+    "crates/brontes-database/brontes-db/src/clickhouse/tables/"
+);
+
+remote_clickhouse_table!(
+    BrontesClickhouseTables,
+    [Brontes, Dedup_Decisions],
+    DbDataWithRunId<DedupDecision>,
+    "crates/brontes-database/brontes-db/src/clickhouse/tables/"
+);
+
 remote_clickhouse_table!(
     BrontesClickhouseTables,
     [Mev, Mev_Blocks],
@@ -97,6 +117,27 @@ remote_clickhouse_table!(
     "crates/brontes-database/brontes-db/src/clickhouse/tables/"
 );
 
+remote_clickhouse_table!(
+    BrontesClickhouseTables,
+    [Mev, Long_Tail],
+    DbDataWithRunId<LongTail>,
+    "crates/brontes-database/brontes-db/src/clickhouse/tables/"
+);
+
+remote_clickhouse_table!(
+    BrontesClickhouseTables,
+    [Mev, Nft],
+    DbDataWithRunId<NftMev>,
+    "crates/brontes-database/brontes-db/src/clickhouse/tables/"
+);
+
+remote_clickhouse_table!(
+    BrontesClickhouseTables,
+    [Mev, Cross_Domain],
+    DbDataWithRunId<CrossDomainArb>,
+    "crates/brontes-database/brontes-db/src/clickhouse/tables/"
+);
+
 remote_clickhouse_table!(
     BrontesClickhouseTables,
     [Mev, Jit_Sandwich],
@@ -236,6 +277,9 @@ db_types!(
     (CexDex, MevCex_Dex, true),
     (CexDexQuote, MevCex_Dex_Quotes, true),
     (Liquidation, MevLiquidations, true),
+    (LongTail, MevLong_Tail, true),
+    (NftMev, MevNft, true),
+    (CrossDomainArb, MevCross_Domain, true),
     (JitLiquiditySandwich, MevJit_Sandwich, true),
     (JitLiquidity, MevJit, true),
     (Sandwich, MevSandwiches, true),
@@ -244,5 +288,7 @@ db_types!(
     (ProtocolInfoClickhouse, EthereumPools, false),
     (TransactionRoot, BrontesTree, true),
     (BlockAnalysis, BrontesBlock_Analysis, true),
+    (BlockAuction, BrontesBlock_Auctions, true),
+    (DedupDecision, BrontesDedup_Decisions, true),
     (RunId, BrontesRun_Id, false)
 );
@@ -1,8 +1,9 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, sync::Arc};
 
 use alloy_primitives::{Address, TxHash};
 use brontes_types::{
     db::{
+        cex::CexFeeSchedule,
         dex::{DexPrices, DexQuotes},
         metadata::{BlockMetadata, Metadata},
     },
@@ -23,9 +24,11 @@ use crate::{
 };
 
 pub struct ClickhouseHttpClient {
-    client:  reqwest::Client,
-    url:     String,
-    api_key: String,
+    client:           reqwest::Client,
+    url:              String,
+    api_key:          String,
+    /// The fees the eth price of the block metadata is adjusted with
+    cex_fee_schedule: Arc<CexFeeSchedule>,
 }
 
 impl ClickhouseHttpClient {
@@ -46,7 +49,14 @@ impl ClickhouseHttpClient {
                 text.split("key: ").collect_vec()[1].to_string()
             }
         };
-        Self { url, api_key, client }
+        Self { url, api_key, client, cex_fee_schedule: Arc::default() }
+    }
+
+    /// Sets the fee schedule of the trade config the inspectors run with, the
+    /// default schedule is used otherwise
+    pub fn with_cex_fee_schedule(mut self, cex_fee_schedule: Arc<CexFeeSchedule>) -> Self {
+        self.cex_fee_schedule = cex_fee_schedule;
+        self
     }
 
     fn process_dex_quotes(val: DexPriceData) -> DexQuotes {
@@ -107,6 +117,7 @@ impl ClickhouseHandle for ClickhouseHttpClient {
             &cex_quotes.value,
             block_meta.value.block_timestamp * 1_000_000,
             quote_asset,
+            &self.cex_fee_schedule,
         );
 
         Ok({
@@ -279,6 +290,13 @@ impl ClickhouseHandle for ClickhouseHttpClient {
     ) -> eyre::Result<Vec<crate::CexTradesData>> {
         unimplemented!()
     }
+
+    async fn get_perp_tickers(
+        &self,
+        _range_or_arbitrary: CexRangeOrArbitrary,
+    ) -> eyre::Result<Vec<crate::CexPerpTickersData>> {
+        unimplemented!()
+    }
 }
 
 #[cfg(test)]
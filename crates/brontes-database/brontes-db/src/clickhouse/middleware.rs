@@ -1,20 +1,25 @@
 use std::sync::Arc;
 
-use alloy_primitives::Address;
+use alloy_primitives::{Address, TxHash};
 use brontes_types::{
+    block_metadata::RelayDataProvider,
     db::{
         address_metadata::AddressMetadata,
         address_to_protocol_info::ProtocolInfo,
         block_analysis::BlockAnalysis,
+        block_auction::BlockAuction,
         builder::BuilderInfo,
+        dedup_decision::DedupDecision,
         dex::DexQuotes,
         metadata::Metadata,
         mev_block::MevBlockWithClassified,
         searcher::SearcherInfo,
+        searcher_stats::SearcherBucketStats,
         token_info::TokenInfoWithAddress,
         traits::{DBWriter, LibmdbxReader, ProtocolCreatedRange},
+        uniswap_v4_pool_key::UniswapV4PoolKey,
     },
-    mev::{Bundle, MevBlock},
+    mev::{Bundle, BundleHeader, MevBlock},
     normalized_actions::Action,
     pair::Pair,
     structured_trace::TxTrace,
@@ -27,7 +32,7 @@ use super::Clickhouse;
 use crate::{
     clickhouse::ClickhouseHandle,
     libmdbx::{LibmdbxInit, StateToInitialize},
-    Tables,
+    CexPerpTickersData, CexPerpTradesData, CexPriceData, CexTradesData, Tables,
 };
 
 #[derive(Clone)]
@@ -54,6 +59,16 @@ impl<I: DBWriter + Send + Sync> DBWriter for ClickhouseMiddleware<I> {
         self.client.block_analysis(block_analysis).await
     }
 
+    async fn write_dedup_decisions(&self, decisions: Vec<DedupDecision>) -> eyre::Result<()> {
+        self.client.dedup_decisions(decisions).await
+    }
+
+    async fn write_block_auction(&self, block_auction: BlockAuction) -> eyre::Result<()> {
+        self.client.block_auction(block_auction.clone()).await?;
+
+        self.inner().write_block_auction(block_auction).await
+    }
+
     async fn write_dex_quotes(
         &self,
         block_number: u64,
@@ -166,6 +181,12 @@ impl<I: DBWriter + Send + Sync> DBWriter for ClickhouseMiddleware<I> {
 
         self.inner().save_traces(block, traces).await
     }
+
+    async fn revert_block(&self, block_number: u64) -> eyre::Result<()> {
+        self.client.revert_block(block_number).await?;
+
+        self.inner().revert_block(block_number).await
+    }
 }
 
 impl<I: LibmdbxInit> LibmdbxInit for ClickhouseMiddleware<I> {
@@ -196,6 +217,32 @@ impl<I: LibmdbxInit> LibmdbxInit for ClickhouseMiddleware<I> {
         self.inner.get_db_range()
     }
 
+    async fn backfill_relay_metadata<P: RelayDataProvider, T: TracingProvider>(
+        &self,
+        provider: &P,
+        tracer: &T,
+        start_block: u64,
+        end_block: u64,
+    ) -> eyre::Result<usize> {
+        self.inner
+            .backfill_relay_metadata(provider, tracer, start_block, end_block)
+            .await
+    }
+
+    async fn import_cex_data(
+        &self,
+        start_block: u64,
+        end_block: u64,
+        prices: Option<Vec<CexPriceData>>,
+        trades: Option<Vec<CexTradesData>>,
+        perp_trades: Option<Vec<CexPerpTradesData>>,
+        perp_tickers: Option<Vec<CexPerpTickersData>>,
+    ) -> eyre::Result<()> {
+        self.inner
+            .import_cex_data(start_block, end_block, prices, trades, perp_trades, perp_tickers)
+            .await
+    }
+
     async fn initialize_table_arbitrary<
         T: brontes_types::traits::TracingProvider,
         CH: ClickhouseHandle,
@@ -256,6 +303,13 @@ impl<I: LibmdbxInit> LibmdbxReader for ClickhouseMiddleware<I> {
         self.inner.get_cex_trades(block)
     }
 
+    fn get_perp_tickers(
+        &self,
+        block: u64,
+    ) -> eyre::Result<brontes_types::db::cex::trades::PerpTickerMap> {
+        self.inner.get_perp_tickers(block)
+    }
+
     fn get_metadata_no_dex_price(
         &self,
         block_num: u64,
@@ -366,7 +420,7 @@ impl<I: LibmdbxInit> LibmdbxReader for ClickhouseMiddleware<I> {
     fn protocols_created_before(
         &self,
         start_block: u64,
-    ) -> eyre::Result<FastHashMap<(Address, Protocol), Pair>> {
+    ) -> eyre::Result<FastHashMap<(Address, Protocol), Vec<Pair>>> {
         self.inner.protocols_created_before(start_block)
     }
 
@@ -385,6 +439,25 @@ impl<I: LibmdbxInit> LibmdbxReader for ClickhouseMiddleware<I> {
     fn load_trace(&self, block_num: u64) -> eyre::Result<Vec<TxTrace>> {
         self.inner.load_trace(block_num)
     }
+
+    fn fetch_mempool_first_seen(
+        &self,
+        tx_hashes: &[TxHash],
+    ) -> eyre::Result<FastHashMap<TxHash, u64>> {
+        self.inner.fetch_mempool_first_seen(tx_hashes)
+    }
+
+    fn fetch_searcher_stats(
+        &self,
+        start_block: u64,
+        end_block: u64,
+    ) -> eyre::Result<Vec<SearcherBucketStats>> {
+        self.inner.fetch_searcher_stats(start_block, end_block)
+    }
+
+    fn fetch_uniswap_v4_pool_keys(&self) -> eyre::Result<FastHashMap<Address, UniswapV4PoolKey>> {
+        self.inner.fetch_uniswap_v4_pool_keys()
+    }
 }
 
 pub struct ReadOnlyMiddleware<I: DBWriter> {
@@ -410,6 +483,14 @@ impl<I: DBWriter + Send + Sync> DBWriter for ReadOnlyMiddleware<I> {
         self.client.block_analysis(block_analysis).await
     }
 
+    async fn write_dedup_decisions(&self, decisions: Vec<DedupDecision>) -> eyre::Result<()> {
+        self.client.dedup_decisions(decisions).await
+    }
+
+    async fn write_block_auction(&self, block_auction: BlockAuction) -> eyre::Result<()> {
+        self.client.block_auction(block_auction).await
+    }
+
     async fn write_dex_quotes(
         &self,
         block_number: u64,
@@ -494,6 +575,33 @@ impl<I: DBWriter + Send + Sync> DBWriter for ReadOnlyMiddleware<I> {
     async fn save_traces(&self, block: u64, traces: Vec<TxTrace>) -> eyre::Result<()> {
         self.client.save_traces(block, traces.clone()).await
     }
+
+    async fn revert_block(&self, block_number: u64) -> eyre::Result<()> {
+        self.client.revert_block(block_number).await
+    }
+
+    // first seen times only live in libmdbx
+    async fn write_mempool_first_seen(&self, first_seen: Vec<(TxHash, u64)>) -> eyre::Result<()> {
+        self.inner.write_mempool_first_seen(first_seen).await
+    }
+
+    // searcher stats only live in libmdbx
+    async fn write_searcher_stats(
+        &self,
+        block_number: u64,
+        bundles: Vec<BundleHeader>,
+    ) -> eyre::Result<()> {
+        self.inner.write_searcher_stats(block_number, bundles).await
+    }
+
+    // pool keys only live in libmdbx
+    async fn insert_uniswap_v4_pool_key(
+        &self,
+        address: Address,
+        key: UniswapV4PoolKey,
+    ) -> eyre::Result<()> {
+        self.inner.insert_uniswap_v4_pool_key(address, key).await
+    }
 }
 
 impl<I: LibmdbxInit> LibmdbxInit for ReadOnlyMiddleware<I> {
@@ -524,6 +632,32 @@ impl<I: LibmdbxInit> LibmdbxInit for ReadOnlyMiddleware<I> {
         self.inner.get_db_range()
     }
 
+    async fn backfill_relay_metadata<P: RelayDataProvider, T: TracingProvider>(
+        &self,
+        provider: &P,
+        tracer: &T,
+        start_block: u64,
+        end_block: u64,
+    ) -> eyre::Result<usize> {
+        self.inner
+            .backfill_relay_metadata(provider, tracer, start_block, end_block)
+            .await
+    }
+
+    async fn import_cex_data(
+        &self,
+        start_block: u64,
+        end_block: u64,
+        prices: Option<Vec<CexPriceData>>,
+        trades: Option<Vec<CexTradesData>>,
+        perp_trades: Option<Vec<CexPerpTradesData>>,
+        perp_tickers: Option<Vec<CexPerpTickersData>>,
+    ) -> eyre::Result<()> {
+        self.inner
+            .import_cex_data(start_block, end_block, prices, trades, perp_trades, perp_tickers)
+            .await
+    }
+
     async fn initialize_table_arbitrary<
         T: brontes_types::traits::TracingProvider,
         CH: ClickhouseHandle,
@@ -584,6 +718,13 @@ impl<I: LibmdbxInit> LibmdbxReader for ReadOnlyMiddleware<I> {
         self.inner.get_cex_trades(block)
     }
 
+    fn get_perp_tickers(
+        &self,
+        block: u64,
+    ) -> eyre::Result<brontes_types::db::cex::trades::PerpTickerMap> {
+        self.inner.get_perp_tickers(block)
+    }
+
     fn get_metadata_no_dex_price(
         &self,
         block_num: u64,
@@ -688,7 +829,7 @@ impl<I: LibmdbxInit> LibmdbxReader for ReadOnlyMiddleware<I> {
     fn protocols_created_before(
         &self,
         start_block: u64,
-    ) -> eyre::Result<FastHashMap<(Address, Protocol), Pair>> {
+    ) -> eyre::Result<FastHashMap<(Address, Protocol), Vec<Pair>>> {
         self.inner.protocols_created_before(start_block)
     }
 
@@ -707,4 +848,23 @@ impl<I: LibmdbxInit> LibmdbxReader for ReadOnlyMiddleware<I> {
     fn load_trace(&self, block_num: u64) -> eyre::Result<Vec<TxTrace>> {
         self.inner.load_trace(block_num)
     }
+
+    fn fetch_mempool_first_seen(
+        &self,
+        tx_hashes: &[TxHash],
+    ) -> eyre::Result<FastHashMap<TxHash, u64>> {
+        self.inner.fetch_mempool_first_seen(tx_hashes)
+    }
+
+    fn fetch_searcher_stats(
+        &self,
+        start_block: u64,
+        end_block: u64,
+    ) -> eyre::Result<Vec<SearcherBucketStats>> {
+        self.inner.fetch_searcher_stats(start_block, end_block)
+    }
+
+    fn fetch_uniswap_v4_pool_keys(&self) -> eyre::Result<FastHashMap<Address, UniswapV4PoolKey>> {
+        self.inner.fetch_uniswap_v4_pool_keys()
+    }
 }
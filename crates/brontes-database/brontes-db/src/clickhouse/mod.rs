@@ -64,6 +64,11 @@ pub trait ClickhouseHandle: Send + Sync + Unpin + 'static {
         range_or_arbitrary: CexRangeOrArbitrary,
     ) -> impl Future<Output = eyre::Result<Vec<crate::CexTradesData>>> + Send;
 
+    fn get_perp_tickers(
+        &self,
+        range_or_arbitrary: CexRangeOrArbitrary,
+    ) -> impl Future<Output = eyre::Result<Vec<crate::CexPerpTickersData>>> + Send;
+
     fn query_many_range<T, D>(
         &self,
         start_block: u64,
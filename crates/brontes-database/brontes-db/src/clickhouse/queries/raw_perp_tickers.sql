@@ -0,0 +1,21 @@
+WITH
+    grouped_time AS (
+        SELECT
+            c.exchange as exchange,
+            upper(replaceAll(replaceAll(replaceAll(c.symbol, '/', ''), '-', ''), '_', '')) AS symbol,
+            toUnixTimestamp(toDateTime(round(c.timestamp / 1000000), 'UTC')) * 1000000 AS timestamp_sec,
+            argMin(c.timestamp, abs(CAST(c.timestamp, 'Int64') - CAST(timestamp_sec, 'Int64'))) as timestamp,
+            argMin(c.mark_price, abs(CAST(c.timestamp, 'Int64') - CAST(timestamp_sec, 'Int64'))) as mark_price,
+            argMin(c.funding_rate, abs(CAST(c.timestamp, 'Int64') - CAST(timestamp_sec, 'Int64'))) as funding_rate
+        FROM cex.normalized_derivative_tickers as c
+        WHERE c.timestamp >= ? AND c.timestamp < ? AND c.mark_price IS NOT NULL AND c.funding_rate IS NOT NULL
+        GROUP BY exchange, symbol, timestamp_sec
+    )
+SELECT
+    exchange,
+    symbol,
+    timestamp,
+    mark_price,
+    funding_rate
+FROM grouped_time
+ORDER BY timestamp
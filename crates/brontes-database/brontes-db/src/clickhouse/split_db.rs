@@ -11,7 +11,10 @@ use db_interfaces::{
 };
 use futures::{stream::FuturesUnordered, Future, StreamExt};
 use reth_tasks::shutdown::GracefulShutdown;
-use tokio::task::JoinError;
+use tokio::{
+    sync::{mpsc::UnboundedReceiver, oneshot},
+    task::JoinError,
+};
 
 use crate::clickhouse::dbms::*;
 
@@ -27,6 +30,10 @@ pub struct ClickhouseBuffered {
     /// if none, will always write to db. if some. will only start writing if
     heart_rate:        Option<HeartRateMonitor>,
     skip:              bool,
+    /// requests to write out everything received so far. acked once the
+    /// inserts have landed
+    flush_rx:          Option<UnboundedReceiver<oneshot::Sender<()>>>,
+    pending_flushes:   Vec<oneshot::Sender<()>>,
 }
 
 impl ClickhouseBuffered {
@@ -36,6 +43,7 @@ impl ClickhouseBuffered {
         buffer_size_small: usize,
         buffer_size_big: usize,
         heart_rate: Option<HeartRateMonitor>,
+        flush_rx: Option<UnboundedReceiver<oneshot::Sender<()>>>,
     ) -> Self {
         Self {
             client: config.build(),
@@ -46,6 +54,32 @@ impl ClickhouseBuffered {
             skip: heart_rate.is_some(),
             heart_rate,
             futs: FuturesUnordered::default(),
+            flush_rx,
+            pending_flushes: Vec::new(),
+        }
+    }
+
+    /// Takes everything waiting in the channel and inserts all buffered
+    /// values, regardless of the buffer sizes
+    fn flush(&mut self) {
+        while let Ok(value) = self.rx.try_recv() {
+            if !self.skip && !value.is_empty() {
+                let enum_kind = value.first().as_ref().unwrap().data.get_db_enum();
+                let entry = self.value_map.entry(enum_kind).or_default();
+                entry.extend(value.into_iter().map(|v| v.data));
+            }
+        }
+
+        for (enum_kind, entry) in &mut self.value_map {
+            if entry.is_empty() {
+                continue
+            }
+
+            self.futs.push(Box::pin(tokio::spawn(Self::insert(
+                self.client.clone(),
+                std::mem::take(entry),
+                enum_kind.clone(),
+            ))));
         }
     }
 
@@ -127,11 +161,16 @@ impl ClickhouseBuffered {
             (MevSandwiches, Sandwich),
             (MevAtomic_Arbs, AtomicArb),
             (MevLiquidations, Liquidation),
+            (MevLong_Tail, LongTail),
+            (MevNft, NftMev),
+            (MevCross_Domain, CrossDomainArb),
             (BrontesDex_Price_Mapping, DexQuotesWithBlockNumber),
             (BrontesToken_Info, TokenInfoWithAddress),
             (EthereumPools, ProtocolInfoClickhouse),
             (BrontesTree, TransactionRoot),
             (BrontesBlock_Analysis, BlockAnalysis),
+            (BrontesBlock_Auctions, BlockAuction),
+            (BrontesDedup_Decisions, DedupDecision),
             (BrontesRun_Id, RunId)
         );
 
@@ -244,12 +283,30 @@ impl Future for ClickhouseBuffered {
                 }
             }
 
+            if let Some(flush_rx) = this.flush_rx.as_mut() {
+                let mut requested = vec![];
+                while let Poll::Ready(Some(ack)) = flush_rx.poll_recv(cx) {
+                    requested.push(ack);
+                }
+                if !requested.is_empty() {
+                    this.flush();
+                    this.pending_flushes.extend(requested);
+                }
+            }
+
             while let Poll::Ready(Some(val)) = this.futs.poll_next_unpin(cx) {
                 if let Err(e) = val {
                     tracing::error!(target: "brontes", "error writing to clickhouse {:?}", e);
                 }
             }
 
+            // everything sent before the flush requests has been inserted
+            if this.futs.is_empty() {
+                this.pending_flushes.drain(..).for_each(|ack| {
+                    let _ = ack.send(());
+                });
+            }
+
             work -= 1;
             if work == 0 {
                 cx.waker().wake_by_ref();
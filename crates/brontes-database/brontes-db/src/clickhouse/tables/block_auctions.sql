@@ -0,0 +1,29 @@
+CREATE TABLE brontes.block_auctions ON CLUSTER eth_cluster0
+(
+    `block_number` UInt64,
+    `slot` UInt64,
+    `winning_relay` String,
+    `winning_builder_pubkey` String,
+    `winning_block_hash` String,
+    `winning_bid` UInt128,
+    `winning_bid_timestamp_ms` UInt64,
+    `runner_up_relay` Nullable(String),
+    `runner_up_builder_pubkey` Nullable(String),
+    `runner_up_bid` Nullable(UInt128),
+    `runner_up_bid_timestamp_ms` Nullable(UInt64),
+    `bid_margin` Nullable(Int128),
+    `builder_eth_profit` Float64,
+    `builder_profit_usd` Float64,
+    `builder_subsidy_eth` Float64,
+    `bids` Nested (
+        `relay` String,
+        `builder_pubkey` String,
+        `block_hash` String,
+        `value` UInt128,
+        `timestamp_ms` UInt64
+    ),
+    `run_id` UInt64
+)
+ENGINE = ReplicatedMergeTree('/clickhouse/eth_cluster0/tables/all/brontes/block_auctions', '{replica}')
+PRIMARY KEY (`block_number`)
+ORDER BY (`block_number`)
@@ -84,6 +84,18 @@ CREATE TABLE mev.cex_dex ON CLUSTER eth_cluster0
         `pnl_maker` Tuple(UInt256, UInt256),
         `pnl_taker` Tuple(UInt256, UInt256)
     ),
+    `per_exchange_tier_pnl` Nested(
+        `tier` String,
+        `cex_exchange` String,
+        `pnl_maker` Tuple(UInt256, UInt256),
+        `pnl_taker` Tuple(UInt256, UInt256)
+    ),
+    `perp_hedge_pnl` Nested(
+        `cex_exchange` String,
+        `funding_cost` Tuple(UInt256, UInt256),
+        `pnl_maker` Tuple(UInt256, UInt256),
+        `pnl_taker` Tuple(UInt256, UInt256)
+    ),
     `gas_details` Tuple(
         `coinbase_transfer` Nullable(UInt128),
         `priority_fee` UInt128,
@@ -21,6 +21,10 @@ CREATE TABLE mev.cex_dex_quotes ON CLUSTER eth_cluster0
     `t300_mid_price` Array(Float64),
     `exchange` String,
     `pnl` Float64,
+    `tier_pnl` Nested(
+        `tier` String,
+        `pnl` Float64
+    ),
     `gas_details` Tuple(
         `coinbase_transfer` Nullable(UInt128),
         `priority_fee` UInt128,
@@ -0,0 +1,55 @@
+CREATE TABLE mev.cross_domain ON CLUSTER eth_cluster0
+(
+    `tx_hash` String,
+    `block_number` UInt64,
+    `mainnet_swaps` Nested(
+        `trace_idx` UInt64,
+        `from` String,
+        `recipient` String,
+        `pool` String,
+        `token_in` Tuple(String, String),
+        `token_out` Tuple(String, String),
+        `amount_in` Tuple(UInt256, UInt256),
+        `amount_out` Tuple(UInt256, UInt256)
+    ),
+    `deposits` Nested(
+        `trace_idx` UInt64,
+        `protocol` String,
+        `bridge` String,
+        `depositor` String,
+        `recipient` String,
+        `destination_chain_id` UInt64,
+        `token` Tuple(String, String),
+        `amount` Tuple(UInt256, UInt256)
+    ),
+    `fills` Nested(
+        `trace_idx` UInt64,
+        `protocol` String,
+        `bridge` String,
+        `relayer` String,
+        `recipient` String,
+        `origin_chain_id` UInt64,
+        `token` Tuple(String, String),
+        `amount` Tuple(UInt256, UInt256)
+    ),
+    `l2_swaps` Nested(
+        `chain_id` UInt64,
+        `block_number` UInt64,
+        `tx_hash` String,
+        `pool` String,
+        `token_in` String,
+        `token_out` String,
+        `amount_in` Float64,
+        `amount_out` Float64
+    ),
+    `gas_details` Tuple(
+        `coinbase_transfer` Nullable(UInt128), 
+        `priority_fee` UInt128,
+        `gas_used` UInt128,
+        `effective_gas_price` UInt128
+    ),
+    `run_id` UInt64
+) 
+ENGINE = ReplicatedMergeTree('/clickhouse/eth_cluster0/tables/all/mev/cross_domain', '{replica}')
+PRIMARY KEY (`block_number`, `tx_hash`)
+ORDER BY (`block_number`, `tx_hash`)
@@ -0,0 +1,13 @@
+CREATE TABLE brontes.dedup_decisions ON CLUSTER eth_cluster0
+(
+    `block_number` UInt64,
+    `rule` UInt64,
+    `dominant_type` String,
+    `dominant_tx_hash` String,
+    `removed_type` String,
+    `removed_tx_hash` String,
+    `run_id` UInt64
+)
+ENGINE = ReplicatedMergeTree('/clickhouse/eth_cluster0/tables/all/brontes/dedup_decisions', '{replica}')
+PRIMARY KEY (`block_number`)
+ORDER BY (`block_number`, `removed_tx_hash`)
@@ -20,7 +20,8 @@ CREATE TABLE mev.liquidations ON CLUSTER eth_cluster0
         `collateral_asset` Tuple(String, String),
         `debt_asset` Tuple(String, String),
         `covered_debt` Tuple(UInt256, UInt256),
-        `liquidated_collateral` Tuple(UInt256, UInt256)
+        `liquidated_collateral` Tuple(UInt256, UInt256),
+        `collateral_discount` Nullable(Float64)
       ),
     `gas_details` Tuple(
         `coinbase_transfer` Nullable(UInt128), 
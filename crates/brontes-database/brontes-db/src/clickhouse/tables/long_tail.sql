@@ -0,0 +1,56 @@
+CREATE TABLE mev.long_tail ON CLUSTER eth_cluster0
+(
+    `block_number` UInt64,
+    `sniped_token` Tuple(String, String),
+    `pool` String,
+    `pool_creation_block` UInt64,
+    `entry_tx_hashes` Array(String),
+    `entry_block_numbers` Array(UInt64),
+    `entry_swaps` Nested(
+        `tx_hash` String,
+        `trace_idx` UInt64,
+        `from` String,
+        `recipient` String,
+        `pool` String,
+        `token_in` Tuple(String, String),
+        `token_out` Tuple(String, String),
+        `amount_in` Tuple(UInt256, UInt256),
+        `amount_out` Tuple(UInt256, UInt256)
+    ),
+    `entry_gas_details` Nested(
+        `tx_hash` String,
+        `coinbase_transfer` Nullable(UInt128), 
+        `priority_fee` UInt128,
+        `gas_used` UInt128,
+        `effective_gas_price` UInt128
+    ),
+    `entry_amount` Float64,
+    `entry_size_usd` Float64,
+    `exit_tx_hashes` Array(String),
+    `exit_swaps` Nested(
+        `tx_hash` String,
+        `trace_idx` UInt64,
+        `from` String,
+        `recipient` String,
+        `pool` String,
+        `token_in` Tuple(String, String),
+        `token_out` Tuple(String, String),
+        `amount_in` Tuple(UInt256, UInt256),
+        `amount_out` Tuple(UInt256, UInt256)
+    ),
+    `exit_gas_details` Nested(
+        `tx_hash` String,
+        `coinbase_transfer` Nullable(UInt128), 
+        `priority_fee` UInt128,
+        `gas_used` UInt128,
+        `effective_gas_price` UInt128
+    ),
+    `exit_amount` Float64,
+    `exit_size_usd` Float64,
+    `realized_pnl_usd` Float64,
+    `unrealized_pnl_usd` Float64,
+    `run_id` UInt64
+) 
+ENGINE = ReplicatedMergeTree('/clickhouse/eth_cluster0/tables/all/mev/long_tail', '{replica}')
+PRIMARY KEY (`block_number`, `pool`)
+ORDER BY (`block_number`, `pool`)
@@ -0,0 +1,42 @@
+CREATE TABLE mev.nft ON CLUSTER eth_cluster0
+(
+    `block_number` UInt64,
+    `kind` String,
+    `tx_hashes` Array(String),
+    `buys` Nested(
+        `trace_idx` UInt64,
+        `marketplace` String,
+        `buyer` String,
+        `seller` String,
+        `collection` String,
+        `token_id` String,
+        `amount` String,
+        `payment_token` Tuple(String, String),
+        `price` Tuple(UInt256, UInt256),
+        `fees` Tuple(UInt256, UInt256)
+    ),
+    `sells` Nested(
+        `trace_idx` UInt64,
+        `marketplace` String,
+        `buyer` String,
+        `seller` String,
+        `collection` String,
+        `token_id` String,
+        `amount` String,
+        `payment_token` Tuple(String, String),
+        `price` Tuple(UInt256, UInt256),
+        `fees` Tuple(UInt256, UInt256)
+    ),
+    `gas_details` Nested(
+        `tx_hash` String,
+        `coinbase_transfer` Nullable(UInt128), 
+        `priority_fee` UInt128,
+        `gas_used` UInt128,
+        `effective_gas_price` UInt128
+    ),
+    `profit_eth` Float64,
+    `run_id` UInt64
+) 
+ENGINE = ReplicatedMergeTree('/clickhouse/eth_cluster0/tables/all/mev/nft', '{replica}')
+PRIMARY KEY (`block_number`, `kind`)
+ORDER BY (`block_number`, `kind`)
@@ -20,6 +20,7 @@ CREATE TABLE mev.sandwiches ON CLUSTER eth_cluster0
         `gas_used` UInt128,
         `effective_gas_price` UInt128
     ),
+    `frontrun_block_numbers` Array(UInt64),
     `victim_swaps` Nested(
         `tx_hash` String,
         `trace_idx` UInt64,
@@ -57,6 +58,7 @@ CREATE TABLE mev.sandwiches ON CLUSTER eth_cluster0
         `gas_used` UInt128,
         `effective_gas_price` UInt128
     ),
+    `backrun_block_number` UInt64,
     `run_id` UInt64
 ) 
 ENGINE = ReplicatedMergeTree('/clickhouse/eth_cluster0/tables/all/mev/sandwiches', '{replica}')
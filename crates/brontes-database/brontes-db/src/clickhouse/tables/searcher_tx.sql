@@ -12,6 +12,37 @@ CREATE TABLE mev.searcher_tx ON CLUSTER eth_cluster0
         `fee` Tuple(UInt256, UInt256),
         `msg_value` UInt256
     ),
+    `loans` Nested(
+        `trace_idx` UInt64,
+        `lender` String,
+        `borrower` String,
+        `loaned_token` Tuple(String, String),
+        `loan_amount` Tuple(UInt256, UInt256)
+    ),
+    `repayments` Nested(
+        `trace_idx` UInt64,
+        `lender` String,
+        `borrower` String,
+        `payer` String,
+        `repayed_token` Tuple(String, String),
+        `repayment_amount` Tuple(UInt256, UInt256)
+    ),
+    `supplies` Nested(
+        `trace_idx` UInt64,
+        `pool` String,
+        `supplier` String,
+        `on_behalf_of` String,
+        `supplied_token` Tuple(String, String),
+        `supply_amount` Tuple(UInt256, UInt256)
+    ),
+    `withdrawals` Nested(
+        `trace_idx` UInt64,
+        `pool` String,
+        `supplier` String,
+        `recipient` String,
+        `withdrawn_token` Tuple(String, String),
+        `withdraw_amount` Tuple(UInt256, UInt256)
+    ),
     `gas_details` Tuple(Nullable(UInt128), UInt128, UInt128, UInt128),
     `run_id` UInt64
 ) 
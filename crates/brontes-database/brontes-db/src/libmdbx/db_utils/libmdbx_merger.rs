@@ -8,7 +8,7 @@ use rayon::iter::*;
 use crate::{libmdbx::LibmdbxReadWriter, move_tables_to_partition, *};
 
 pub fn merge_libmdbx_dbs(
-    final_db: LibmdbxReadWriter,
+    final_db: &LibmdbxReadWriter,
     partition_db_folder: &PathBuf,
     executor: BrontesTaskExecutor,
     max_merge_tasks: usize,
@@ -38,8 +38,12 @@ pub fn merge_libmdbx_dbs(
                 move_tables_to_partition!(FULL_RANGE db, final_db, Some(multi.clone()),
                 CexPrice,
                 CexTrades,
+                CexPerpTickers,
+                CexPerpTrades,
                 BlockInfo,
                 MevBlocks,
+                BlockAuctions,
+                SearcherStats,
                 InitializedState,
                 PoolCreationBlocks,
                 TxTraces,
@@ -48,8 +52,10 @@ pub fn merge_libmdbx_dbs(
                 SearcherContracts,
                 Builder,
                 AddressToProtocolInfo,
+                UniswapV4PoolKeys,
                 TokenDecimals,
-                DexPrice
+                DexPrice,
+                MempoolFirstSeen
                 );
                 total_progress_bar.inc(1);
 
@@ -1,4 +1,5 @@
 use std::{
+    collections::BTreeMap,
     path::PathBuf,
     sync::Arc,
     task::{Context, Waker},
@@ -12,7 +13,7 @@ use libmdbx::libmdbx_writer::InitTables;
 use rayon::iter::*;
 use tokio::sync::Notify;
 
-use super::rclone_wrapper::BlockRangeList;
+use super::{rclone_wrapper::BlockRangeList, TableRows};
 use crate::{
     libmdbx::{types::LibmdbxData, LibmdbxInit, LibmdbxReadWriter},
     *,
@@ -53,6 +54,7 @@ pub struct LibmdbxPartitioner {
     parent_db:           LibmdbxReadWriter,
     partition_db_folder: PathBuf,
     start_block:         u64,
+    end_block:           Option<u64>,
     executor:            BrontesTaskExecutor,
 }
 
@@ -66,13 +68,25 @@ impl LibmdbxPartitioner {
         fs_extra::dir::create_all(&partition_db_folder, false)
             .expect("failed to create partition db folder");
 
-        Self { parent_db, start_block, partition_db_folder, executor }
+        Self { parent_db, start_block, end_block: None, partition_db_folder, executor }
     }
 
-    pub fn execute(self, tasks: usize) -> eyre::Result<()> {
+    /// Stops partitioning at the end block instead of the end of the db. Only
+    /// whole partitions are created, so the last one may end before it
+    pub fn with_end_block(mut self, end_block: u64) -> Self {
+        self.end_block = Some(end_block);
+        self
+    }
+
+    /// Returns the table rows of each partition created, keyed by the
+    /// partition's directory name
+    pub fn execute(self, tasks: usize) -> eyre::Result<BTreeMap<String, TableRows>> {
         // cleanup
         let mut start_block = self.start_block;
-        let end_block = self.parent_db.get_db_range()?.1;
+        let db_end_block = self.parent_db.get_db_range()?.1;
+        let end_block = self
+            .end_block
+            .map_or(db_end_block, |end_block| end_block.min(db_end_block));
 
         let mut ranges = vec![];
         while start_block + DEFAULT_PARTITION_SIZE < end_block {
@@ -89,8 +103,9 @@ impl LibmdbxPartitioner {
             .build()?;
 
         // move over full range tables
+        let full_range_name = format!("{PARTITION_FILE_NAME}-full-range-tables");
         let mut path = self.partition_db_folder.clone();
-        path.push(format!("{PARTITION_FILE_NAME}-full-range-tables/",));
+        path.push(format!("{full_range_name}/"));
         fs_extra::dir::create_all(&path, false)?;
         let db = LibmdbxReadWriter::init_db(path, None, &self.executor, false)?;
 
@@ -104,16 +119,21 @@ impl LibmdbxPartitioner {
             SearcherContracts,
             Builder,
             AddressToProtocolInfo,
-            TokenDecimals
+            UniswapV4PoolKeys,
+            TokenDecimals,
+            MempoolFirstSeen
         );
+        let full_range_rows = db.snapshot_table_rows()?;
+        drop(db);
 
         // because we are just doing read operations. we can do all this in parallel
-        pool.install(|| {
+        let mut partition_rows = pool.install(|| {
             ranges
                 .par_iter()
-                .try_for_each(|BlockRangeList { start_block, end_block }| {
+                .map(|BlockRangeList { start_block, end_block }| {
+                    let name = format!("{PARTITION_FILE_NAME}-{start_block}-{end_block}");
                     let mut path = self.partition_db_folder.clone();
-                    path.push(format!("{PARTITION_FILE_NAME}-{start_block}-{end_block}/"));
+                    path.push(format!("{name}/"));
                     tracing::info!(?path, "creating path");
                     fs_extra::dir::create_all(&path, false)?;
                     let db = LibmdbxReadWriter::init_db(path, None, &self.executor, false)?;
@@ -128,22 +148,29 @@ impl LibmdbxPartitioner {
                         None,
                         CexPrice,
                         CexTrades,
+                        CexPerpTickers,
+                        CexPerpTrades,
                         BlockInfo,
                         MevBlocks,
+                        BlockAuctions,
+                        SearcherStats,
                         InitializedState,
                         PoolCreationBlocks,
                         TxTraces
                     );
                     // manually dex pricing
-                    let r =
-                        self.parent_db
-                            .write_dex_price_range(*start_block, *end_block, &db, None);
+                    self.parent_db
+                        .write_dex_price_range(*start_block, *end_block, &db, None)?;
+                    let rows = db.snapshot_table_rows()?;
                     drop(db);
-                    r
+
+                    eyre::Ok((name, rows))
                 })
+                .collect::<eyre::Result<BTreeMap<_, _>>>()
         })?;
+        partition_rows.insert(full_range_name, full_range_rows);
 
-        Ok(())
+        Ok(partition_rows)
     }
 }
 
@@ -0,0 +1,234 @@
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+use eyre::eyre;
+use flate2::{write::GzEncoder, Compression};
+use rayon::iter::*;
+
+use super::{
+    rclone_wrapper::BlockRangeList, SnapshotFile, SnapshotManifest, TableRows, SNAPSHOT_MANIFEST,
+};
+
+pub const RANGES_AVAILABLE: &str = "brontes-available-ranges.json";
+
+/// Writes snapshots to a local directory in the same layout as the r2 bucket,
+/// so that the directory can be served as a snapshot mirror
+pub struct LocalSnapshotDir {
+    output_dir: PathBuf,
+}
+
+impl LocalSnapshotDir {
+    pub fn new(output_dir: PathBuf) -> eyre::Result<Self> {
+        fs_extra::dir::create_all(&output_dir, false)?;
+
+        Ok(Self { output_dir })
+    }
+
+    pub fn get_most_recent_partition_block(&self) -> eyre::Result<u64> {
+        self.get_blockrange_list()?
+            .into_iter()
+            .map(|range| range.end_block)
+            .max()
+            .ok_or_else(|| eyre!("no partitions found in {}", self.output_dir.display()))
+    }
+
+    pub fn get_blockrange_list(&self) -> eyre::Result<Vec<BlockRangeList>> {
+        let mut ranges = std::fs::read_dir(&self.output_dir)?
+            .filter_map(|entry| {
+                let file_name = entry.ok()?.file_name().into_string().ok()?;
+                if !file_name.ends_with(".tar.gz") {
+                    return None
+                }
+
+                BlockRangeList::from_partition_name(&file_name)
+            })
+            .collect::<Vec<_>>();
+        ranges.sort_by_key(|range| range.start_block);
+
+        Ok(ranges)
+    }
+
+    /// Tarballs the directory into the output directory, returning its
+    /// manifest entry
+    pub fn tar_ball_dir(
+        &self,
+        directory: &Path,
+        new_name: Option<&str>,
+        table_rows: TableRows,
+    ) -> eyre::Result<SnapshotFile> {
+        let directory_name = match new_name {
+            Some(name) => name,
+            None => directory
+                .file_name()
+                .and_then(|name| name.to_str())
+                .ok_or_else(|| eyre!("invalid directory {}", directory.display()))?,
+        };
+        tracing::info!(?directory, ?directory_name, "tar balling directory");
+
+        let mut tarball = self.output_dir.clone();
+        tarball.push(format!("{directory_name}.tar.gz"));
+
+        let mut builder =
+            tar::Builder::new(GzEncoder::new(File::create(&tarball)?, Compression::default()));
+        builder.append_dir_all(directory_name, directory)?;
+        builder.into_inner()?.finish()?.sync_all()?;
+
+        tracing::info!("tarball finished, hashing");
+        let snapshot_file = SnapshotFile::new(&tarball, table_rows)?;
+
+        let mut size_path = self.output_dir.clone();
+        size_path.push(format!("{directory_name}-byte-count.txt"));
+        write_atomic(&size_path, &snapshot_file.size_bytes.to_string())?;
+
+        Ok(snapshot_file)
+    }
+
+    /// Tarballs the partitions created by the partitioner, `partition_rows`
+    /// being the table rows of each partition directory
+    pub fn tar_ball_partitions(
+        &self,
+        partition_folder: &Path,
+        partition_rows: BTreeMap<String, TableRows>,
+        tasks: usize,
+    ) -> eyre::Result<()> {
+        let pool = rayon::ThreadPoolBuilder::default()
+            .num_threads(tasks)
+            .build()?;
+
+        let files = pool.install(|| {
+            partition_rows
+                .into_par_iter()
+                .map(|(directory, table_rows)| {
+                    let mut path = partition_folder.to_path_buf();
+                    path.push(directory);
+                    self.tar_ball_dir(&path, None, table_rows)
+                })
+                .collect::<eyre::Result<Vec<_>>>()
+        })?;
+
+        tracing::info!("update block range list");
+        self.update_block_range_file()?;
+        self.update_manifest(files)
+    }
+
+    /// Adds the files to the manifest in the output directory, keeping the
+    /// entries of the files written by previous runs. Errors if the existing
+    /// manifest can't be parsed, as overwriting it would drop its entries
+    pub fn update_manifest(&self, files: Vec<SnapshotFile>) -> eyre::Result<()> {
+        let mut path = self.output_dir.clone();
+        path.push(SNAPSHOT_MANIFEST);
+
+        let mut manifest = match std::fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice::<SnapshotManifest>(&bytes)
+                .map_err(|e| eyre!("invalid snapshot manifest {}: {e}", path.display()))?,
+            Err(e) if e.kind() == ErrorKind::NotFound => SnapshotManifest::default(),
+            Err(e) => return Err(e.into()),
+        };
+        files.into_iter().for_each(|file| manifest.upsert(file));
+
+        write_atomic(&path, &serde_json::to_string(&manifest)?)
+    }
+
+    fn update_block_range_file(&self) -> eyre::Result<()> {
+        let ranges = self.get_blockrange_list()?;
+        tracing::info!(partitions = ranges.len(), "writing available ranges");
+
+        let mut path = self.output_dir.clone();
+        path.push(RANGES_AVAILABLE);
+
+        write_atomic(&path, &serde_json::to_string(&ranges)?)
+    }
+}
+
+/// Writes to a temp file next to `path` and renames it over `path`, so that a
+/// mirror being served never exposes a partially written file
+fn write_atomic(path: &Path, contents: &str) -> eyre::Result<()> {
+    let mut tmp_path = path.as_os_str().to_owned();
+    tmp_path.push(".tmp");
+    let tmp_path = PathBuf::from(tmp_path);
+
+    let mut file = File::create(&tmp_path)?;
+    write!(&mut file, "{contents}")?;
+    file.sync_all()?;
+    std::fs::rename(tmp_path, path)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::libmdbx::PARTITION_FILE_NAME;
+
+    #[test]
+    fn test_local_snapshot_dir() {
+        let mut root = std::env::temp_dir();
+        root.push("brontes-local-snapshot-test");
+        let _ = std::fs::remove_dir_all(&root);
+
+        let mut partition_folder = root.clone();
+        partition_folder.push("partitions");
+        let mut partition = partition_folder.clone();
+        partition.push(format!("{PARTITION_FILE_NAME}-100-200"));
+        fs_extra::dir::create_all(&partition, false).unwrap();
+        std::fs::write(partition.join("mdbx.dat"), b"brontes snapshot").unwrap();
+
+        let mut output_dir = root.clone();
+        output_dir.push("mirror");
+        let local = LocalSnapshotDir::new(output_dir.clone()).unwrap();
+
+        let rows = TableRows::from([("BlockInfo".to_string(), 100)]);
+        local
+            .tar_ball_partitions(
+                &partition_folder,
+                BTreeMap::from([(format!("{PARTITION_FILE_NAME}-100-200"), rows.clone())]),
+                1,
+            )
+            .unwrap();
+
+        assert_eq!(
+            local.get_blockrange_list().unwrap(),
+            vec![BlockRangeList { start_block: 100, end_block: 200 }]
+        );
+        assert_eq!(local.get_most_recent_partition_block().unwrap(), 200);
+
+        let manifest: SnapshotManifest =
+            serde_json::from_slice(&std::fs::read(output_dir.join(SNAPSHOT_MANIFEST)).unwrap())
+                .unwrap();
+        let file = manifest
+            .file(&format!("{PARTITION_FILE_NAME}-100-200.tar.gz"))
+            .unwrap();
+        assert_eq!(file.table_rows, rows);
+        assert_eq!(file.block_range, Some(BlockRangeList { start_block: 100, end_block: 200 }));
+
+        let tarball = output_dir.join(&file.file_name);
+        file.verify(&tarball).unwrap();
+
+        let size = std::fs::read_to_string(
+            output_dir.join(format!("{PARTITION_FILE_NAME}-100-200-byte-count.txt")),
+        )
+        .unwrap();
+        assert_eq!(size, file.size_bytes.to_string());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_update_manifest_keeps_invalid_manifest() {
+        let mut root = std::env::temp_dir();
+        root.push("brontes-local-snapshot-invalid-manifest-test");
+        let _ = std::fs::remove_dir_all(&root);
+
+        let local = LocalSnapshotDir::new(root.clone()).unwrap();
+        std::fs::write(root.join(SNAPSHOT_MANIFEST), b"{ not a manifest").unwrap();
+
+        assert!(local.update_manifest(vec![]).is_err());
+        assert_eq!(std::fs::read(root.join(SNAPSHOT_MANIFEST)).unwrap(), b"{ not a manifest");
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
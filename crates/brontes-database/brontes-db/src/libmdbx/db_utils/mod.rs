@@ -4,4 +4,10 @@ pub use libmdbx_merger::*;
 pub mod libmdbx_partitioning;
 pub use libmdbx_partitioning::*;
 
+pub mod local_snapshot;
+pub use local_snapshot::*;
+
 pub mod rclone_wrapper;
+
+pub mod snapshot_manifest;
+pub use snapshot_manifest::*;
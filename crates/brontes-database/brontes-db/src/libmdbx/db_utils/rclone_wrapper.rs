@@ -1,4 +1,6 @@
-use std::{fs::File, io::Write, path::PathBuf, process::Stdio, str::FromStr};
+use std::{
+    collections::BTreeMap, fs::File, io::Write, path::PathBuf, process::Stdio, str::FromStr,
+};
 
 use eyre::eyre;
 use fs_extra::dir::{get_dir_content, CopyOptions};
@@ -7,7 +9,7 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use tokio::process::Command;
 
-use super::PARTITION_FILE_NAME;
+use super::{SnapshotFile, SnapshotManifest, TableRows, PARTITION_FILE_NAME, SNAPSHOT_MANIFEST};
 
 /// rclone command wrapper
 pub struct RCloneWrapper {
@@ -112,7 +114,11 @@ impl RCloneWrapper {
         }
     }
 
-    async fn upload_full_range_tables(&self, partition_folder: &PathBuf) -> eyre::Result<()> {
+    async fn upload_full_range_tables(
+        &self,
+        partition_folder: &PathBuf,
+        table_rows: TableRows,
+    ) -> eyre::Result<SnapshotFile> {
         let directory = PathBuf::from(
             get_dir_content(partition_folder)?
                 .directories
@@ -121,16 +127,16 @@ impl RCloneWrapper {
                 .expect("no full range table found"),
         );
 
-        self.tar_ball_dir(&directory, None).await?;
-
-        Ok(())
+        self.tar_ball_dir(&directory, None, table_rows).await
     }
 
+    /// Tarballs and uploads the directory, returning its manifest entry
     pub async fn tar_ball_dir(
         &self,
         directory: &PathBuf,
         new_name: Option<&str>,
-    ) -> eyre::Result<()> {
+        table_rows: TableRows,
+    ) -> eyre::Result<SnapshotFile> {
         let mut directory_name = directory
             .components()
             .last()
@@ -180,9 +186,49 @@ impl RCloneWrapper {
         let mut file = File::create(format!("/tmp/{directory_name}-byte-count.txt"))?;
         write!(&mut file, "{}", file_size).unwrap();
 
+        tracing::info!("hashing tarball");
+        let snapshot_file =
+            SnapshotFile::new(&PathBuf::from(format!("/tmp/{directory_name}.tar.gz")), table_rows)?;
+
         tracing::info!("uploading tarball");
         // upload to the r2 bucket using rclone
         self.upload_tarball(directory_name).await;
+        Ok(snapshot_file)
+    }
+
+    /// Adds the uploaded files to the manifest on r2, keeping the entries of
+    /// the files uploaded by previous runs
+    pub async fn update_manifest(&self, files: Vec<SnapshotFile>) -> eyre::Result<()> {
+        let result = Command::new("rclone")
+            .arg("cat")
+            .arg(format!("{}:brontes-db/{SNAPSHOT_MANIFEST}", self.config_name))
+            .stdout(Stdio::piped())
+            .output()
+            .await?;
+
+        let mut manifest = serde_json::from_slice::<SnapshotManifest>(&result.stdout)
+            .inspect_err(|e| tracing::warn!(err=%e, "no snapshot manifest on r2, creating it"))
+            .unwrap_or_default();
+        files.into_iter().for_each(|file| manifest.upsert(file));
+
+        let mut file = File::create(format!("/tmp/{SNAPSHOT_MANIFEST}"))?;
+        let str = serde_json::to_string(&manifest)?;
+        write!(&mut file, "{str}")?;
+
+        if !Command::new("rclone")
+            .arg("copy")
+            .arg(format!("/tmp/{SNAPSHOT_MANIFEST}"))
+            .arg(format!("{}:brontes-db/", self.config_name))
+            .spawn()
+            .unwrap()
+            .wait()
+            .await
+            .unwrap()
+            .success()
+        {
+            panic!("failed to upload snapshot manifest");
+        }
+
         Ok(())
     }
 
@@ -209,15 +255,24 @@ impl RCloneWrapper {
         Ok(())
     }
 
+    /// `partition_rows` are the table rows of each partition directory, as
+    /// returned by the partitioner
     pub async fn tar_ball_and_upload_files(
         &self,
         partition_folder: PathBuf,
         start_block: u64,
+        mut partition_rows: BTreeMap<String, TableRows>,
     ) -> eyre::Result<()> {
         tracing::info!(?partition_folder);
-        self.upload_full_range_tables(&partition_folder).await?;
-
-        futures::stream::iter(
+        let full_range_rows = partition_rows
+            .remove(&format!("{PARTITION_FILE_NAME}-full-range-tables"))
+            .unwrap_or_default();
+        let mut files = vec![
+            self.upload_full_range_tables(&partition_folder, full_range_rows)
+                .await?,
+        ];
+
+        let uploaded = futures::stream::iter(
             get_dir_content(&partition_folder)?
                 .directories
                 .iter()
@@ -244,30 +299,37 @@ impl RCloneWrapper {
                     let file_start_block = u64::from_str(end_portion.split('-').next()?).unwrap();
                     tracing::info!(%file_start_block);
                     (file_start_block >= start_block).then(|| {
+                        let table_rows = partition_rows.get(&directory).cloned();
+                        if table_rows.is_none() {
+                            tracing::warn!(%directory, "no table rows for partition");
+                        }
+
                         let mut path = partition_folder.clone();
                         path.push(directory);
-                        path
+                        (path, table_rows.unwrap_or_default())
                     })
                 }),
         )
-        .map(|directory| async move {
-            self.tar_ball_dir(&directory, None)
+        .map(|(directory, table_rows)| async move {
+            self.tar_ball_dir(&directory, None, table_rows)
                 .await
                 .expect("failed to tarball dir")
         })
         .buffer_unordered(5)
         .collect::<Vec<_>>()
         .await;
+        files.extend(uploaded);
 
-        // upload ranges for downloader
+        // upload ranges and the manifest for downloader
         tracing::info!("update block range list");
         self.update_block_range_file().await?;
+        self.update_manifest(files).await?;
 
         Ok(())
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct BlockRangeList {
     pub start_block: u64,
     pub end_block:   u64,
@@ -0,0 +1,330 @@
+//! The manifest published next to the snapshot tarballs. It lists the size,
+//! hash and table row counts of every tarball so a download can be checked
+//! before it is unpacked and the merged db checked after.
+use std::{collections::BTreeMap, fs::File, io::Read, path::Path, str::FromStr};
+
+use alloy_primitives::hex;
+use brontes_types::db::dex::make_filter_key_range;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::{rclone_wrapper::BlockRangeList, PARTITION_FILE_NAME};
+use crate::{libmdbx::LibmdbxReadWriter, *};
+
+pub const SNAPSHOT_MANIFEST: &str = "brontes-snapshot-manifest.json";
+
+/// Row count of each table, keyed by table name
+pub type TableRows = BTreeMap<String, u64>;
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SnapshotManifest {
+    pub files: Vec<SnapshotFile>,
+}
+
+impl SnapshotManifest {
+    pub fn file(&self, file_name: &str) -> Option<&SnapshotFile> {
+        self.files.iter().find(|file| file.file_name == file_name)
+    }
+
+    /// Adds the file, replacing the entry of a previous upload with the same
+    /// name
+    pub fn upsert(&mut self, file: SnapshotFile) {
+        self.files.retain(|f| f.file_name != file.file_name);
+        self.files.push(file);
+        self.files.sort_by(|a, b| a.file_name.cmp(&b.file_name));
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SnapshotFile {
+    pub file_name:   String,
+    pub size_bytes:  u64,
+    /// Hex encoded sha256 of the tarball
+    pub sha256:      String,
+    /// The blocks of a partition, `None` for the full range tables and the
+    /// complete db
+    pub block_range: Option<BlockRangeList>,
+    /// Rows of each non empty table in the tarball's db
+    pub table_rows:  TableRows,
+}
+
+impl SnapshotFile {
+    pub fn new(tarball: &Path, table_rows: TableRows) -> eyre::Result<Self> {
+        let file_name = tarball
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| eyre::eyre!("invalid tarball path {}", tarball.display()))?
+            .to_string();
+
+        Ok(Self {
+            block_range: BlockRangeList::from_partition_name(&file_name),
+            size_bytes: std::fs::metadata(tarball)?.len(),
+            sha256: sha256_file(tarball)?,
+            file_name,
+            table_rows,
+        })
+    }
+
+    /// Checks the size and hash of the downloaded tarball
+    pub fn verify(&self, tarball: &Path) -> eyre::Result<()> {
+        let size_bytes = std::fs::metadata(tarball)?.len();
+        if size_bytes != self.size_bytes {
+            eyre::bail!(
+                "{} is {} bytes, the manifest lists {} bytes",
+                self.file_name,
+                size_bytes,
+                self.size_bytes
+            )
+        }
+
+        let sha256 = sha256_file(tarball)?;
+        if sha256 != self.sha256 {
+            eyre::bail!(
+                "{} has sha256 {}, the manifest lists {}",
+                self.file_name,
+                sha256,
+                self.sha256
+            )
+        }
+
+        Ok(())
+    }
+}
+
+impl BlockRangeList {
+    /// Parses the range out of a `brontes-db-partition-{start}-{end}`
+    /// directory or tarball name
+    pub fn from_partition_name(name: &str) -> Option<Self> {
+        let range = name
+            .strip_prefix(PARTITION_FILE_NAME)?
+            .strip_prefix('-')?
+            .split('.')
+            .next()?;
+        let (start_block, end_block) = range.split_once('-')?;
+
+        Some(Self {
+            start_block: u64::from_str(start_block).ok()?,
+            end_block:   u64::from_str(end_block).ok()?,
+        })
+    }
+}
+
+pub fn sha256_file(path: &Path) -> eyre::Result<String> {
+    let mut file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; 8 * 1024 * 1024];
+
+    loop {
+        let read = file.read(&mut buf)?;
+        if read == 0 {
+            break
+        }
+        hasher.update(&buf[..read]);
+    }
+
+    Ok(hex::encode(hasher.finalize()))
+}
+
+macro_rules! table_rows {
+    ($db:expr, $($table_name:ident),*) => {
+        TableRows::from([
+            $(
+                (
+                    $table_name::NAME.to_string(),
+                    $db.get_table_entry_count::<$table_name>()? as u64,
+                ),
+            )*
+        ])
+    };
+}
+
+macro_rules! rows_in_range {
+    ($db:expr, $table:expr, $range:expr, $($table_name:ident),*) => {
+        match $table {
+            $($table_name::NAME => Some($db.rows_in_range::<$table_name>($range)?),)*
+            DexPrice::NAME => Some($db.dex_price_rows_in_range($range)?),
+            _ => None,
+        }
+    };
+}
+
+impl LibmdbxReadWriter {
+    /// Rows of every non empty table, recorded in the manifest when a snapshot
+    /// is created
+    pub fn snapshot_table_rows(&self) -> eyre::Result<TableRows> {
+        let mut rows = self.all_table_rows()?;
+        rows.retain(|_, rows| *rows != 0);
+
+        Ok(rows)
+    }
+
+    /// Checks that the db holds at least the rows the manifest lists for each
+    /// downloaded file. Partitions are checked over their block range and the
+    /// remaining files over the whole table
+    pub fn verify_snapshot_rows(&self, files: &[SnapshotFile]) -> eyre::Result<()> {
+        let all_rows = self.all_table_rows()?;
+        let mut mismatches = vec![];
+
+        for file in files {
+            for (table, expected) in &file.table_rows {
+                let rows = match &file.block_range {
+                    Some(range) => rows_in_range!(
+                        self,
+                        table.as_str(),
+                        range,
+                        CexPrice,
+                        CexTrades,
+                        CexPerpTickers,
+                        CexPerpTrades,
+                        BlockInfo,
+                        MevBlocks,
+                        BlockAuctions,
+                        SearcherStats,
+                        InitializedState,
+                        PoolCreationBlocks,
+                        TxTraces
+                    ),
+                    None => None,
+                }
+                .or_else(|| all_rows.get(table).copied())
+                .unwrap_or_default();
+
+                if rows < *expected {
+                    mismatches.push(format!(
+                        "{}: table {} has {} rows, expected at least {}",
+                        file.file_name, table, rows, expected
+                    ));
+                }
+            }
+        }
+
+        if !mismatches.is_empty() {
+            eyre::bail!("snapshot verification failed:\n{}", mismatches.join("\n"))
+        }
+
+        Ok(())
+    }
+
+    fn all_table_rows(&self) -> eyre::Result<TableRows> {
+        Ok(table_rows!(
+            self,
+            CexPrice,
+            CexTrades,
+            CexPerpTickers,
+            CexPerpTrades,
+            BlockInfo,
+            MevBlocks,
+            BlockAuctions,
+            SearcherStats,
+            InitializedState,
+            PoolCreationBlocks,
+            TxTraces,
+            AddressMeta,
+            SearcherEOAs,
+            SearcherContracts,
+            Builder,
+            AddressToProtocolInfo,
+            UniswapV4PoolKeys,
+            TokenDecimals,
+            DexPrice,
+            MempoolFirstSeen
+        ))
+    }
+
+    fn rows_in_range<T>(&self, range: &BlockRangeList) -> eyre::Result<u64>
+    where
+        T: CompressedTable<Key = u64>,
+        T::Value: From<T::DecompressedValue> + Into<T::DecompressedValue>,
+    {
+        let tx = self.db.no_timeout_ro_tx()?;
+        let mut cur = tx.cursor_read::<T>()?;
+
+        Ok(cur
+            .walk_range(range.start_block..range.end_block)?
+            .flatten()
+            .count() as u64)
+    }
+
+    // dex table has special key
+    fn dex_price_rows_in_range(&self, range: &BlockRangeList) -> eyre::Result<u64> {
+        let tx = self.db.no_timeout_ro_tx()?;
+        let mut cur = tx.cursor_read::<DexPrice>()?;
+
+        let start_key = make_filter_key_range(range.start_block).0;
+        let end_key = make_filter_key_range(range.end_block).1;
+
+        Ok(cur.walk_range(start_key..end_key)?.flatten().count() as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    fn snapshot_file(file_name: &str) -> SnapshotFile {
+        SnapshotFile {
+            file_name:   file_name.to_string(),
+            size_bytes:  16,
+            sha256:      "e5e81757fa9cece94cad59c6685bf5f1f541c4eb578c159b9f6ee133662e0853"
+                .to_string(),
+            block_range: BlockRangeList::from_partition_name(file_name),
+            table_rows:  TableRows::default(),
+        }
+    }
+
+    #[test]
+    fn test_partition_name() {
+        assert_eq!(
+            BlockRangeList::from_partition_name("brontes-db-partition-100-200.tar.gz"),
+            Some(BlockRangeList { start_block: 100, end_block: 200 })
+        );
+        assert_eq!(
+            BlockRangeList::from_partition_name("brontes-db-partition-100-200"),
+            Some(BlockRangeList { start_block: 100, end_block: 200 })
+        );
+        assert_eq!(
+            BlockRangeList::from_partition_name("brontes-db-partition-full-range-tables.tar.gz"),
+            None
+        );
+        assert_eq!(BlockRangeList::from_partition_name("brontes-complete-range.tar.gz"), None);
+    }
+
+    #[test]
+    fn test_upsert() {
+        let mut manifest = SnapshotManifest::default();
+        manifest.upsert(snapshot_file("brontes-db-partition-200-300.tar.gz"));
+        manifest.upsert(snapshot_file("brontes-db-partition-100-200.tar.gz"));
+
+        let mut replaced = snapshot_file("brontes-db-partition-200-300.tar.gz");
+        replaced.size_bytes = 32;
+        manifest.upsert(replaced.clone());
+
+        assert_eq!(manifest.files.len(), 2);
+        assert_eq!(manifest.files[0].file_name, "brontes-db-partition-100-200.tar.gz");
+        assert_eq!(manifest.file("brontes-db-partition-200-300.tar.gz"), Some(&replaced));
+    }
+
+    #[test]
+    fn test_verify_tarball() {
+        let mut path = std::env::temp_dir();
+        path.push("brontes-snapshot-manifest-test.tar.gz");
+        File::create(&path)
+            .unwrap()
+            .write_all(b"brontes snapshot")
+            .unwrap();
+
+        let mut file = snapshot_file("brontes-snapshot-manifest-test.tar.gz");
+        assert!(file.verify(&path).is_ok());
+
+        file.size_bytes = 17;
+        assert!(file.verify(&path).is_err());
+
+        file.size_bytes = 16;
+        file.sha256 = hex::encode([0u8; 32]);
+        assert!(file.verify(&path).is_err());
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
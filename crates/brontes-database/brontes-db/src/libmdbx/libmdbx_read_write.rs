@@ -1,15 +1,21 @@
 use std::{ops::RangeInclusive, path::Path, sync::Arc};
 
-use alloy_primitives::Address;
+use alloy_primitives::{Address, BlockHash, TxHash};
 use brontes_metrics::db_reads::LibmdbxMetrics;
 use brontes_pricing::Protocol;
 use brontes_types::{
+    block_metadata::{RelayDataProvider, Relays},
     constants::{ETH_ADDRESS, WETH_ADDRESS},
     db::{
         address_metadata::AddressMetadata,
         address_to_protocol_info::ProtocolInfo,
+        block_auction::BlockAuction,
         builder::BuilderInfo,
-        cex::{quotes::CexPriceMap, trades::CexTradeMap},
+        cex::{
+            quotes::CexPriceMap,
+            trades::{CexTradeMap, PerpTickerMap},
+            CexFeeSchedule,
+        },
         dex::{make_filter_key_range, DexPrices, DexQuotes},
         initialized_state::{
             InitializedStateMeta, CEX_QUOTES_FLAG, CEX_TRADES_FLAG, DATA_NOT_PRESENT_NOT_AVAILABLE,
@@ -18,10 +24,12 @@ use brontes_types::{
         metadata::{BlockMetadata, BlockMetadataInner, Metadata},
         mev_block::MevBlockWithClassified,
         searcher::SearcherInfo,
+        searcher_stats::{SearcherBucketStats, SearcherStatsKey},
         token_info::{TokenInfo, TokenInfoWithAddress},
         traits::{DBWriter, LibmdbxReader},
+        uniswap_v4_pool_key::UniswapV4PoolKey,
     },
-    mev::{Bundle, MevBlock},
+    mev::{Bundle, BundleHeader, MevBlock},
     normalized_actions::Action,
     pair::Pair,
     structured_trace::TxTrace,
@@ -35,7 +43,10 @@ use itertools::Itertools;
 use malachite::Rational;
 use reth_db::table::{Compress, Encode};
 use reth_interfaces::db::LogLevel;
-use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
+use tokio::sync::{
+    mpsc::{unbounded_channel, UnboundedSender},
+    Notify,
+};
 use tracing::{info, instrument};
 
 use super::{
@@ -90,15 +101,41 @@ pub trait LibmdbxInit: LibmdbxReader + DBWriter {
     ) -> eyre::Result<StateToInitialize>;
 
     fn get_db_range(&self) -> eyre::Result<(u64, u64)>;
+
+    /// Refetches the relay metadata of every block in the range and overwrites
+    /// it. Blocks without block info get a new entry built from their header.
+    /// Returns the amount of blocks that had relay metadata.
+    fn backfill_relay_metadata<P: RelayDataProvider, T: TracingProvider>(
+        &self,
+        provider: &P,
+        tracer: &T,
+        start_block: u64,
+        end_block: u64,
+    ) -> impl Future<Output = eyre::Result<usize>> + Send;
+
+    /// Writes cex quotes, trades, perp trades and perp tickers built outside of
+    /// clickhouse, such as from local exchange exports, and marks the range as
+    /// initialized for the quotes and trades if given
+    fn import_cex_data(
+        &self,
+        start_block: u64,
+        end_block: u64,
+        prices: Option<Vec<CexPriceData>>,
+        trades: Option<Vec<CexTradesData>>,
+        perp_trades: Option<Vec<CexPerpTradesData>>,
+        perp_tickers: Option<Vec<CexPerpTickersData>>,
+    ) -> impl Future<Output = eyre::Result<()>> + Send;
 }
 
 #[derive(Clone)]
 pub struct LibmdbxReadWriter {
-    pub db:  Arc<Libmdbx>,
-    pub tx:  UnboundedSender<StampedWriterMessage>,
-    metrics: Option<LibmdbxMetrics>,
+    pub db:           Arc<Libmdbx>,
+    pub tx:           UnboundedSender<StampedWriterMessage>,
+    metrics:          Option<LibmdbxMetrics>,
     // 100 shards for now, might change in future
-    cache:   ReadWriteCache,
+    cache:            ReadWriteCache,
+    /// The fees the eth price of the block metadata is adjusted with
+    cex_fee_schedule: Arc<CexFeeSchedule>,
 }
 
 impl LibmdbxReadWriter {
@@ -124,6 +161,7 @@ impl LibmdbxReadWriter {
             tx,
             metrics: metrics.then(LibmdbxMetrics::default),
             cache: ReadWriteCache::new(memory_per_table_mb, metrics),
+            cex_fee_schedule: Arc::default(),
         })
     }
 
@@ -138,7 +176,25 @@ impl LibmdbxReadWriter {
         let writer = LibmdbxWriter::new(db.clone(), yapper, false);
         writer.run_no_shutdown();
 
-        Ok(Self { db, tx, metrics: None, cache: ReadWriteCache::new(memory_per_table_mb, false) })
+        Ok(Self {
+            db,
+            tx,
+            metrics: None,
+            cache: ReadWriteCache::new(memory_per_table_mb, false),
+            cex_fee_schedule: Arc::default(),
+        })
+    }
+
+    /// Sets the fee schedule of the trade config the inspectors run with, the
+    /// default schedule is used otherwise
+    pub fn with_cex_fee_schedule(mut self, cex_fee_schedule: Arc<CexFeeSchedule>) -> Self {
+        self.cex_fee_schedule = cex_fee_schedule;
+        self
+    }
+
+    /// The fee schedule the eth price of the block metadata is adjusted with
+    pub fn cex_fee_schedule(&self) -> &CexFeeSchedule {
+        &self.cex_fee_schedule
     }
 }
 
@@ -348,6 +404,86 @@ impl LibmdbxInit for LibmdbxReadWriter {
 
         Ok((start_block, end_block))
     }
+
+    async fn backfill_relay_metadata<P: RelayDataProvider, T: TracingProvider>(
+        &self,
+        provider: &P,
+        tracer: &T,
+        start_block: u64,
+        end_block: u64,
+    ) -> eyre::Result<usize> {
+        let mut backfilled = 0;
+
+        for block in start_block..=end_block {
+            // a fresh db has no block info yet, so it's made from the header
+            let mut info = match self.db.view_db(|tx| Ok(tx.get::<BlockInfo>(block)?))? {
+                Some(info) => info,
+                None => {
+                    let header = tracer
+                        .header_by_number(block)
+                        .await?
+                        .ok_or_else(|| eyre!("Missing header of block {block}"))?;
+                    BlockMetadataInner::make_new(
+                        header.hash_slow(),
+                        header.timestamp,
+                        None,
+                        None,
+                        vec![],
+                    )
+                }
+            };
+
+            let block_hash = BlockHash::from(info.block_hash);
+            let Some(relay) = Relays::get_relay_metadata_from(provider, block, block_hash).await?
+            else {
+                continue
+            };
+
+            info.set_relay(relay);
+            self.db.update_db(|tx| tx.put::<BlockInfo>(block, info))??;
+            backfilled += 1;
+        }
+
+        Ok(backfilled)
+    }
+
+    async fn import_cex_data(
+        &self,
+        start_block: u64,
+        end_block: u64,
+        prices: Option<Vec<CexPriceData>>,
+        trades: Option<Vec<CexTradesData>>,
+        perp_trades: Option<Vec<CexPerpTradesData>>,
+        perp_tickers: Option<Vec<CexPerpTickersData>>,
+    ) -> eyre::Result<()> {
+        if let Some(prices) = prices {
+            self.db.write_table::<CexPrice, CexPriceData>(&prices)?;
+            let inited = self.inited_range_items(start_block..=end_block, CEX_QUOTES_FLAG)?;
+            self.db
+                .write_table::<InitializedState, InitializedStateData>(&inited)?;
+        }
+
+        if let Some(trades) = trades {
+            self.db.write_table::<CexTrades, CexTradesData>(&trades)?;
+            let inited = self.inited_range_items(start_block..=end_block, CEX_TRADES_FLAG)?;
+            self.db
+                .write_table::<InitializedState, InitializedStateData>(&inited)?;
+        }
+
+        // the perp trades & tickers are optional for cex-dex arbs, so blocks
+        // without them aren't tracked
+        if let Some(perp_trades) = perp_trades {
+            self.db
+                .write_table::<CexPerpTrades, CexPerpTradesData>(&perp_trades)?;
+        }
+
+        if let Some(perp_tickers) = perp_tickers {
+            self.db
+                .write_table::<CexPerpTickers, CexPerpTickersData>(&perp_tickers)?;
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Default)]
@@ -413,6 +549,13 @@ impl LibmdbxReader for LibmdbxReadWriter {
         self.fetch_trades(block)
     }
 
+    fn get_perp_tickers(&self, block: u64) -> eyre::Result<PerpTickerMap> {
+        self.db.view_db(|tx| {
+            tx.get::<CexPerpTickers>(block)?
+                .ok_or_else(|| eyre::eyre!("no perp tickers"))
+        })
+    }
+
     fn has_dex_quotes(&self, block_num: u64) -> eyre::Result<bool> {
         self.db.view_db(|tx| {
             let Some(state) = tx.get::<InitializedState>(block_num)? else { return Ok(false) };
@@ -432,6 +575,54 @@ impl LibmdbxReader for LibmdbxReadWriter {
         })
     }
 
+    fn fetch_mempool_first_seen(
+        &self,
+        tx_hashes: &[TxHash],
+    ) -> eyre::Result<FastHashMap<TxHash, u64>> {
+        self.db.view_db(|tx| {
+            let mut first_seen = FastHashMap::default();
+            for hash in tx_hashes {
+                if let Some(seen) = tx.get::<MempoolFirstSeen>(*hash)? {
+                    first_seen.insert(*hash, seen);
+                }
+            }
+
+            Ok(first_seen)
+        })
+    }
+
+    fn fetch_searcher_stats(
+        &self,
+        start_block: u64,
+        end_block: u64,
+    ) -> eyre::Result<Vec<SearcherBucketStats>> {
+        let (start_key, end_key) = SearcherStatsKey::range(start_block, end_block);
+        self.db.view_db(|tx| {
+            let mut cursor = tx.cursor_read::<SearcherStats>()?;
+            let mut stats = Vec::new();
+
+            for result in cursor.walk_range(start_key..=end_key)? {
+                stats.push(result?.1);
+            }
+
+            Ok(stats)
+        })
+    }
+
+    fn fetch_uniswap_v4_pool_keys(&self) -> eyre::Result<FastHashMap<Address, UniswapV4PoolKey>> {
+        self.db.view_db(|tx| {
+            let mut cursor = tx.cursor_read::<UniswapV4PoolKeys>()?;
+            let mut keys = FastHashMap::default();
+
+            for result in cursor.walk(None)? {
+                let (address, key) = result?;
+                keys.insert(address, key);
+            }
+
+            Ok(keys)
+        })
+    }
+
     #[brontes_macros::metrics_call(ptr=metrics,scope,db_read,"protocol_info")]
     fn get_protocol_details(&self, address: Address) -> eyre::Result<ProtocolInfo> {
         self.db.view_db(|tx| {
@@ -466,8 +657,12 @@ impl LibmdbxReader for LibmdbxReadWriter {
         let block_meta = self.fetch_block_metadata(block_num)?;
         let cex_quotes = self.fetch_cex_quotes(block_num)?;
 
-        let eth_price =
-            determine_eth_prices(&cex_quotes, block_meta.block_timestamp * 1_000_000, quote_asset);
+        let eth_price = determine_eth_prices(
+            &cex_quotes,
+            block_meta.block_timestamp * 1_000_000,
+            quote_asset,
+            &self.cex_fee_schedule,
+        );
 
         Ok(BlockMetadata::new(
             block_num,
@@ -489,8 +684,12 @@ impl LibmdbxReader for LibmdbxReadWriter {
         let cex_quotes = self.fetch_cex_quotes(block_num)?;
         let dex_quotes = self.fetch_dex_quotes(block_num)?;
 
-        let eth_price =
-            determine_eth_prices(&cex_quotes, block_meta.block_timestamp * 1_000_000, quote_asset);
+        let eth_price = determine_eth_prices(
+            &cex_quotes,
+            block_meta.block_timestamp * 1_000_000,
+            quote_asset,
+            &self.cex_fee_schedule,
+        );
 
         Ok({
             BlockMetadata::new(
@@ -695,7 +894,7 @@ impl LibmdbxReader for LibmdbxReadWriter {
     fn protocols_created_before(
         &self,
         block_num: u64,
-    ) -> eyre::Result<FastHashMap<(Address, Protocol), Pair>> {
+    ) -> eyre::Result<FastHashMap<(Address, Protocol), Vec<Pair>>> {
         self.db.view_db(|tx| {
         let mut cursor = tx.cursor_read::<PoolCreationBlocks>()?;
         let mut map = FastHashMap::default();
@@ -707,10 +906,7 @@ impl LibmdbxReader for LibmdbxReadWriter {
                     continue;
                 };
 
-                map.insert(
-                    (addr, protocol_info.protocol),
-                    Pair(protocol_info.token0, protocol_info.token1),
-                );
+                map.insert((addr, protocol_info.protocol), protocol_info.pairs());
             }
         }
 
@@ -736,11 +932,12 @@ impl LibmdbxReader for LibmdbxReadWriter {
                 let Some(protocol_info) = tx.get::<AddressToProtocolInfo>(addr)? else {
                     continue;
                 };
-                map.entry(block).or_insert(vec![]).push((
-                    addr,
-                    protocol_info.protocol,
-                    Pair(protocol_info.token0, protocol_info.token1),
-                ));
+                map.entry(block).or_insert(vec![]).extend(
+                    protocol_info
+                        .pairs()
+                        .into_iter()
+                        .map(|pair| (addr, protocol_info.protocol, pair)),
+                );
             }
         }
         info!(target:"brontes-libmdbx", "loaded {} pairs range: {}..{}", map.len(), start_block, end_block);
@@ -1071,6 +1268,53 @@ impl DBWriter for LibmdbxReadWriter {
         )?)
     }
 
+    async fn revert_block(&self, block_number: u64) -> eyre::Result<()> {
+        let done = Arc::new(Notify::new());
+        self.tx
+            .send(WriterMessage::RevertBlock { block_number, done: done.clone() }.stamp())?;
+        done.notified().await;
+
+        // the revert rewrote searcher infos behind the cache's back
+        self.cache
+            .searcher_eoa(false, |cache| cache.invalidate_all());
+        self.cache
+            .searcher_contract(false, |cache| cache.invalidate_all());
+
+        Ok(())
+    }
+
+    async fn write_mempool_first_seen(&self, first_seen: Vec<(TxHash, u64)>) -> eyre::Result<()> {
+        Ok(self
+            .tx
+            .send(WriterMessage::MempoolFirstSeen { first_seen }.stamp())?)
+    }
+
+    async fn write_block_auction(&self, block_auction: BlockAuction) -> eyre::Result<()> {
+        Ok(self
+            .tx
+            .send(WriterMessage::BlockAuction(Box::new(block_auction)).stamp())?)
+    }
+
+    async fn write_searcher_stats(
+        &self,
+        block_number: u64,
+        bundles: Vec<BundleHeader>,
+    ) -> eyre::Result<()> {
+        Ok(self
+            .tx
+            .send(WriterMessage::SearcherStats { block_number, bundles }.stamp())?)
+    }
+
+    async fn insert_uniswap_v4_pool_key(
+        &self,
+        address: Address,
+        key: UniswapV4PoolKey,
+    ) -> eyre::Result<()> {
+        Ok(self
+            .tx
+            .send(WriterMessage::UniswapV4PoolKey { address, key: Box::new(key) }.stamp())?)
+    }
+
     /// only for internal functionality (i.e. clickhouse)
     async fn insert_tree(&self, _tree: BlockTree<Action>) -> eyre::Result<()> {
         Ok(())
@@ -1083,6 +1327,14 @@ impl DBWriter for LibmdbxReadWriter {
     ) -> eyre::Result<()> {
         Ok(())
     }
+
+    /// only for internal functionality (i.e. clickhouse)
+    async fn write_dedup_decisions(
+        &self,
+        _: Vec<brontes_types::db::dedup_decision::DedupDecision>,
+    ) -> eyre::Result<()> {
+        Ok(())
+    }
 }
 
 impl LibmdbxReadWriter {
@@ -1223,7 +1475,8 @@ impl LibmdbxReadWriter {
 
     pub fn fetch_trades(&self, block: u64) -> eyre::Result<CexTradeMap> {
         self.db.view_db(|tx| {
-            tx.get::<CexTrades>(block)?
+            let mut trades = tx
+                .get::<CexTrades>(block)?
                 .ok_or_else(|| eyre::eyre!("no cex trades"))
                 .inspect_err(|_| {
                     let _ = self.init_state_updating(
@@ -1231,7 +1484,13 @@ impl LibmdbxReadWriter {
                         CEX_TRADES_FLAG,
                         DATA_NOT_PRESENT_NOT_AVAILABLE,
                     );
-                })
+                })?;
+            trades.perp = tx
+                .get::<CexPerpTrades>(block)?
+                .map(|perp| perp.0)
+                .unwrap_or_default();
+
+            Ok(trades)
         })
     }
 
@@ -1304,10 +1563,13 @@ impl LibmdbxReadWriter {
     }
 }
 
+/// The eth price of the block metadata, with the quotes fee adjusted with the
+/// schedule of the trade config
 pub fn determine_eth_prices(
     cex_quotes: &CexPriceMap,
     block_timestamp: u64,
     quote_asset: Address,
+    cex_fee_schedule: &CexFeeSchedule,
 ) -> Option<Rational> {
     Some(
         cex_quotes
@@ -1315,6 +1577,7 @@ pub fn determine_eth_prices(
                 &Pair(quote_asset, WETH_ADDRESS),
                 block_timestamp,
                 None,
+                cex_fee_schedule,
             )?
             .maker_taker_mid()
             .0,
@@ -5,22 +5,27 @@ use std::{
     time::{Duration, Instant},
 };
 
-use alloy_primitives::Address;
+use alloy_primitives::{Address, TxHash};
 use brontes_metrics::db_writer::WriterMetrics;
 use brontes_types::{
     db::{
         address_metadata::AddressMetadata,
         address_to_protocol_info::ProtocolInfo,
+        block_auction::BlockAuction,
         builder::BuilderInfo,
-        dex::{make_key, DexQuoteWithIndex, DexQuotes},
-        initialized_state::{DATA_PRESENT, DEX_PRICE_FLAG, TRACE_FLAG},
+        dex::{make_filter_key_range, make_key, DexQuoteWithIndex, DexQuotes},
+        initialized_state::{DATA_NOT_PRESENT_UNKNOWN, DATA_PRESENT, DEX_PRICE_FLAG, TRACE_FLAG},
         mev_block::MevBlockWithClassified,
         pool_creation_block::PoolsToAddresses,
         searcher::SearcherInfo,
+        searcher_stats::{
+            bundles_by_searcher, searcher_stats_bucket, SearcherBucketStats, SearcherStatsKey,
+        },
         token_info::TokenInfo,
         traces::TxTracesInner,
+        uniswap_v4_pool_key::UniswapV4PoolKey,
     },
-    mev::{Bundle, MevBlock},
+    mev::{Bundle, BundleHeader, MevBlock, MevType},
     structured_trace::TxTrace,
     FastHashMap, Protocol, UnboundedYapperReceiver,
 };
@@ -93,10 +98,26 @@ pub enum WriterMessage {
         curve_lp_token:  Option<Address>,
         classifier_name: Protocol,
     },
+    UniswapV4PoolKey {
+        address: Address,
+        key:     Box<UniswapV4PoolKey>,
+    },
     Traces {
         block:  u64,
         traces: Vec<TxTrace>,
     },
+    RevertBlock {
+        block_number: u64,
+        done:         Arc<Notify>,
+    },
+    MempoolFirstSeen {
+        first_seen: Vec<(TxHash, u64)>,
+    },
+    BlockAuction(Box<BlockAuction>),
+    SearcherStats {
+        block_number: u64,
+        bundles:      Vec<BundleHeader>,
+    },
     Init(InitTables, Arc<Notify>),
 }
 
@@ -242,6 +263,10 @@ impl LibmdbxWriter {
                 self.insert_pool(block, address, &tokens, curve_lp_token, classifier_name)?;
                 "pool"
             }
+            WriterMessage::UniswapV4PoolKey { address, key } => {
+                self.insert_uniswap_v4_pool_key(address, *key)?;
+                "uniswapv4poolkey"
+            }
             WriterMessage::Traces { block, traces } => {
                 self.save_traces(block, traces)?;
                 "traces"
@@ -283,6 +308,24 @@ impl LibmdbxWriter {
                 self.write_searcher_contract_info(searcher_contract, *searcher_info)?;
                 "searchercontractinfo"
             }
+            WriterMessage::RevertBlock { block_number, done } => {
+                let res = self.revert_block(block_number);
+                done.notify_one();
+                res?;
+                "revertblock"
+            }
+            WriterMessage::MempoolFirstSeen { first_seen } => {
+                self.write_mempool_first_seen(first_seen)?;
+                "mempoolfirstseen"
+            }
+            WriterMessage::BlockAuction(auction) => {
+                self.write_block_auction(*auction)?;
+                "blockauction"
+            }
+            WriterMessage::SearcherStats { block_number, bundles } => {
+                self.write_searcher_stats(block_number, bundles)?;
+                "searcherstats"
+            }
             WriterMessage::Init(init, not) => {
                 init.write_data(self.db.clone())?;
                 not.notify_one();
@@ -530,6 +573,143 @@ impl LibmdbxWriter {
         self.init_state_updating(block, TRACE_FLAG)
     }
 
+    #[instrument(target = "libmdbx_read_write::revert_block", skip_all, level = "warn")]
+    fn revert_block(&mut self, block_number: u64) -> eyre::Result<()> {
+        // queued inserts could contain the reverted block, so they need to land
+        // before we delete
+        self.insert_remaining();
+
+        let (start_key, end_key) = make_filter_key_range(block_number);
+        self.db.update_db(|tx| {
+            // undo the searcher aggregates the block's bundles were folded into
+            if let Some(mev_block) = tx.get::<MevBlocks>(block_number)? {
+                let headers = mev_block
+                    .mev
+                    .into_iter()
+                    .map(|bundle| bundle.header)
+                    .filter(|h| h.mev_type != MevType::Unknown && h.mev_type != MevType::SearcherTx)
+                    .collect::<Vec<_>>();
+
+                for header in &headers {
+                    if let Some(mut info) = tx.get::<SearcherEOAs>(header.eoa)? {
+                        info.revert_bundle(header);
+                        tx.put::<SearcherEOAs>(header.eoa, info)?;
+                    }
+                    let Some(contract) = header.mev_contract else { continue };
+                    if let Some(mut info) = tx.get::<SearcherContracts>(contract)? {
+                        info.revert_bundle(header);
+                        tx.put::<SearcherContracts>(contract, info)?;
+                    }
+                }
+
+                let bucket_start = searcher_stats_bucket(block_number);
+                for ((address, eoa_or_contract), bundles) in bundles_by_searcher(&headers) {
+                    let key = SearcherStatsKey::new(bucket_start, address, eoa_or_contract);
+                    let Some(mut stats) = tx.get::<SearcherStats>(key)? else { continue };
+                    if !stats.revert_block(block_number, &bundles) {
+                        continue
+                    }
+
+                    if stats.is_empty() {
+                        tx.delete::<SearcherStats>(key, None)?;
+                    } else {
+                        tx.put::<SearcherStats>(key, stats)?;
+                    }
+                }
+            }
+
+            tx.delete::<MevBlocks>(block_number, None)?;
+            tx.delete::<BlockAuctions>(block_number, None)?;
+            tx.delete::<TxTraces>(block_number, None)?;
+
+            let mut cursor = tx.cursor_write::<DexPrice>()?;
+            let mut walker = cursor.walk_range(start_key..=end_key)?;
+            while walker.next().transpose()?.is_some() {
+                walker.delete_current()?;
+            }
+
+            if let Some(mut state) = tx.get::<InitializedState>(block_number)? {
+                state.set(TRACE_FLAG, DATA_NOT_PRESENT_UNKNOWN);
+                state.set(DEX_PRICE_FLAG, DATA_NOT_PRESENT_UNKNOWN);
+                tx.put::<InitializedState>(block_number, state)?;
+            }
+
+            Ok::<_, DatabaseError>(())
+        })??;
+
+        Ok(())
+    }
+
+    #[instrument(
+        target = "libmdbx_read_write::insert_uniswap_v4_pool_key",
+        skip_all,
+        level = "warn"
+    )]
+    fn insert_uniswap_v4_pool_key(
+        &self,
+        address: Address,
+        key: UniswapV4PoolKey,
+    ) -> eyre::Result<()> {
+        self.instrumented_write::<UniswapV4PoolKeys, UniswapV4PoolKeysData>(&[
+            UniswapV4PoolKeysData::new(address, key),
+        ])
+        .expect("libmdbx write failure");
+
+        Ok(())
+    }
+
+    #[instrument(target = "libmdbx_read_write::write_mempool_first_seen", skip_all, level = "warn")]
+    fn write_mempool_first_seen(&self, first_seen: Vec<(TxHash, u64)>) -> eyre::Result<()> {
+        self.db.update_db(|tx| {
+            for (hash, seen) in first_seen {
+                // a tx is rebroadcast many times and sightings don't arrive in
+                // order, only the earliest one counts
+                match tx.get::<MempoolFirstSeen>(hash)? {
+                    Some(existing) if existing <= seen => {}
+                    _ => tx.put::<MempoolFirstSeen>(hash, seen)?,
+                }
+            }
+
+            Ok::<_, DatabaseError>(())
+        })??;
+
+        Ok(())
+    }
+
+    #[instrument(target = "libmdbx_read_write::write_block_auction", skip_all, level = "warn")]
+    fn write_block_auction(&self, auction: BlockAuction) -> eyre::Result<()> {
+        let data = BlockAuctionsData::new(auction.block_number, auction);
+        self.instrumented_write::<BlockAuctions, BlockAuctionsData>(&[data])
+            .expect("libmdbx write failure");
+
+        Ok(())
+    }
+
+    #[instrument(target = "libmdbx_read_write::write_searcher_stats", skip_all, level = "warn")]
+    fn write_searcher_stats(
+        &self,
+        block_number: u64,
+        bundles: Vec<BundleHeader>,
+    ) -> eyre::Result<()> {
+        let bucket_start = searcher_stats_bucket(block_number);
+
+        self.db.update_db(|tx| {
+            for ((address, eoa_or_contract), headers) in bundles_by_searcher(&bundles) {
+                let key = SearcherStatsKey::new(bucket_start, address, eoa_or_contract);
+                let mut stats = tx.get::<SearcherStats>(key)?.unwrap_or_else(|| {
+                    SearcherBucketStats::new(bucket_start, address, eoa_or_contract)
+                });
+                if stats.apply_block(block_number, &headers) {
+                    tx.put::<SearcherStats>(key, stats)?;
+                }
+            }
+
+            Ok::<_, DatabaseError>(())
+        })??;
+
+        Ok(())
+    }
+
     #[instrument(target = "libmdbx_read_write::write_builder_info", skip_all, level = "warn")]
     fn write_builder_info(
         &self,
@@ -10,10 +10,14 @@ use brontes_types::{
     db::{
         address_metadata::{AddressMetadata, AddressMetadataRedefined},
         address_to_protocol_info::{ProtocolInfo, ProtocolInfoRedefined},
+        block_auction::{BlockAuction, BlockAuctionRedefined},
         builder::{BuilderInfo, BuilderInfoRedefined},
         cex::{
             quotes::{CexPriceMap, CexPriceMapRedefined},
-            trades::{CexTradeMap, CexTradeMapRedefined},
+            trades::{
+                CexTradeMap, CexTradeMapRedefined, PerpTickerMap, PerpTickerMapRedefined,
+                PerpTradeMap, PerpTradeMapRedefined,
+            },
         },
         clickhouse_serde::tx_trace::tx_traces_inner,
         dex::{DexKey, DexQuoteWithIndex, DexQuoteWithIndexRedefined},
@@ -25,9 +29,11 @@ use brontes_types::{
         mev_block::{MevBlockWithClassified, MevBlockWithClassifiedRedefined},
         pool_creation_block::{PoolsToAddresses, PoolsToAddressesRedefined},
         searcher::{SearcherInfo, SearcherInfoRedefined},
+        searcher_stats::{SearcherBucketStats, SearcherBucketStatsRedefined, SearcherStatsKey},
         token_info::TokenInfo,
         traces::{TxTracesInner, TxTracesInnerRedefined},
         traits::LibmdbxReader,
+        uniswap_v4_pool_key::{UniswapV4PoolKey, UniswapV4PoolKeyRedefined},
     },
     serde_utils::*,
     traits::TracingProvider,
@@ -44,7 +50,7 @@ use crate::{
     parquet::ParquetExporter,
 };
 mod const_sql;
-use alloy_primitives::Address;
+use alloy_primitives::{Address, TxHash};
 //
 // use brontes_types::db::initialized_state::CEX_QUOTES_FLAG;
 //
@@ -58,7 +64,7 @@ use super::{
     CompressedTable,
 };
 
-pub const NUM_TABLES: usize = 14;
+pub const NUM_TABLES: usize = 20;
 
 macro_rules! tables {
     ($($table:ident),*) => {
@@ -276,7 +282,25 @@ impl Tables {
                     )
                     .await
             }
-            Tables::SearcherEOAs | Tables::SearcherContracts | Tables::InitializedState => Ok(()),
+            Tables::CexPerpTickers => {
+                initializer
+                    .initialize_table_from_clickhouse::<CexPerpTickers, CexPerpTickersData>(
+                        block_range,
+                        clear_table,
+                        self.fetch_progress_bar(progress_bar),
+                        Self::fetch_download_fn_range_perp_tickers,
+                        |f, not| handle.send_message(WriterMessage::Init(f.into(), not)),
+                    )
+                    .await
+            }
+            Tables::SearcherEOAs
+            | Tables::SearcherContracts
+            | Tables::InitializedState
+            | Tables::MempoolFirstSeen
+            | Tables::BlockAuctions
+            | Tables::SearcherStats
+            | Tables::UniswapV4PoolKeys
+            | Tables::CexPerpTrades => Ok(()),
             _ => unimplemented!("'initialize_table' not implemented for {:?}", self),
         }
     }
@@ -343,6 +367,19 @@ impl Tables {
                     )
                     .await
             }
+            Tables::CexPerpTickers => {
+                initializer
+                    .initialize_table_from_clickhouse_arbitrary_state::<
+                        CexPerpTickers,
+                        CexPerpTickersData,
+                    >(
+                        block_range,
+                        self.fetch_progress_bar(progress_bar),
+                        Self::fetch_download_fn_arbitrary_perp_tickers,
+                        |f, not| handle.send_message(WriterMessage::Init(f.into(), not)),
+                    )
+                    .await
+            }
             table @ (Tables::TokenDecimals
             | Tables::AddressToProtocolInfo
             | Tables::PoolCreationBlocks
@@ -373,6 +410,7 @@ impl Tables {
             Self::MevBlocks => exporter.export_mev_blocks().await,
             Self::SearcherContracts | Self::SearcherEOAs => exporter.export_searcher_info().await,
             Self::Builder => exporter.export_builder_info().await,
+            Self::SearcherStats => exporter.export_searcher_stats().await,
             _ => unreachable!("Parquet export not yet supported for this table"),
         }
     }
@@ -424,6 +462,33 @@ impl Tables {
         })
     }
 
+    fn fetch_download_fn_range_perp_tickers<CH: ClickhouseHandle, T, D>(
+        start: u64,
+        end: u64,
+        ch: &'static CH,
+    ) -> Pin<Box<dyn Future<Output = eyre::Result<Vec<D>>> + Send>>
+    where
+        T: CompressedTable,
+        T::Value: From<T::DecompressedValue> + Into<T::DecompressedValue>,
+        D: LibmdbxData<T>
+            + DbRow
+            + for<'de> Deserialize<'de>
+            + Send
+            + Sync
+            + Debug
+            + Unpin
+            + 'static,
+    {
+        Box::pin(async move {
+            unsafe {
+                std::mem::transmute(
+                    ch.get_perp_tickers(super::cex_utils::CexRangeOrArbitrary::Range(start, end))
+                        .await,
+                )
+            }
+        })
+    }
+
     pub fn fetch_download_fn_range_quotes<CH: ClickhouseHandle, T, D>(
         start: u64,
         end: u64,
@@ -496,6 +561,32 @@ impl Tables {
         })
     }
 
+    fn fetch_download_fn_arbitrary_perp_tickers<CH: ClickhouseHandle, T, D>(
+        range: &'static [u64],
+        ch: &'static CH,
+    ) -> Pin<Box<dyn Future<Output = eyre::Result<Vec<D>>> + Send>>
+    where
+        T: CompressedTable,
+        T::Value: From<T::DecompressedValue> + Into<T::DecompressedValue>,
+        D: LibmdbxData<T>
+            + DbRow
+            + for<'de> Deserialize<'de>
+            + Send
+            + Sync
+            + Debug
+            + Unpin
+            + 'static,
+    {
+        Box::pin(async move {
+            unsafe {
+                std::mem::transmute(
+                    ch.get_perp_tickers(super::cex_utils::CexRangeOrArbitrary::Arbitrary(range))
+                        .await,
+                )
+            }
+        })
+    }
+
     fn fetch_download_fn_arbitrary_quotes<CH: ClickhouseHandle, T, D>(
         range: &'static [u64],
         ch: &'static CH,
@@ -537,7 +628,13 @@ tables!(
     SearcherEOAs,
     SearcherContracts,
     InitializedState,
-    CexTrades
+    CexTrades,
+    MempoolFirstSeen,
+    BlockAuctions,
+    SearcherStats,
+    UniswapV4PoolKeys,
+    CexPerpTickers,
+    CexPerpTrades
 );
 
 /// Must be in this order when defining
@@ -811,6 +908,48 @@ compressed_table!(
     }
 );
 
+// the mark prices & funding rates of the perpetual futures
+compressed_table!(
+    Table CexPerpTickers {
+        Data {
+        // block number
+        key: u64,
+        value: PerpTickerMap,
+        compressed_value: PerpTickerMapRedefined
+        },
+        Init {
+            init_size: Some(1000),
+            init_method: Clickhouse,
+            http_endpoint: None,
+            init_flag: None
+        },
+        CLI {
+            can_insert: False
+        }
+    }
+);
+
+// the trades of the perpetual futures, apart from the spot trades of the
+// CexTrades table as they're only imported from local exports
+compressed_table!(
+    Table CexPerpTrades {
+        Data {
+        // block number
+        key: u64,
+        value: PerpTradeMap,
+        compressed_value: PerpTradeMapRedefined
+        },
+        Init {
+            init_size: None,
+            init_method: Other,
+            http_endpoint: None
+        },
+        CLI {
+            can_insert: False
+        }
+    }
+);
+
 compressed_table!(
     Table BlockInfo {
         #[serde_as]
@@ -1031,3 +1170,79 @@ compressed_table!(
         }
     }
 );
+
+// when a pending tx was first seen in the mempool, in unix microseconds
+compressed_table!(
+    Table MempoolFirstSeen {
+        Data {
+            key: TxHash,
+            value: u64
+        },
+        Init {
+            init_size: None,
+            init_method: Other,
+            http_endpoint: None
+        },
+        CLI {
+            can_insert: False
+        }
+    }
+);
+
+compressed_table!(
+    Table BlockAuctions {
+        Data {
+            key: u64,
+            value: BlockAuction,
+            compressed_value: BlockAuctionRedefined
+        },
+        Init {
+            init_size: None,
+            init_method: Other,
+            http_endpoint: None
+        },
+        CLI {
+            can_insert: False
+        }
+    }
+);
+
+// searcher pnl by mev type, keyed by the first block of the bucket and the
+// searcher
+compressed_table!(
+    Table SearcherStats {
+        Data {
+            key: SearcherStatsKey,
+            value: SearcherBucketStats,
+            compressed_value: SearcherBucketStatsRedefined
+        },
+        Init {
+            init_size: None,
+            init_method: Other,
+            http_endpoint: None
+        },
+        CLI {
+            can_insert: False
+        }
+    }
+);
+
+// the pool key of each uniswap v4 pool, keyed by the address the pool is
+// represented by
+compressed_table!(
+    Table UniswapV4PoolKeys {
+        Data {
+            key: Address,
+            value: UniswapV4PoolKey,
+            compressed_value: UniswapV4PoolKeyRedefined
+        },
+        Init {
+            init_size: None,
+            init_method: Other,
+            http_endpoint: None
+        },
+        CLI {
+            can_insert: False
+        }
+    }
+);
@@ -0,0 +1,178 @@
+//! Loads the user supplied L2 swap dataset the cross domain inspector matches
+//! mainnet transactions against.
+//!
+//! The dataset is a parquet file, or a directory of them, with a row per swap
+//! and the columns:
+//! - `chain_id`, `block_number` and `timestamp` (unix seconds) as uint64
+//! - `tx_hash`, `trader`, `pool`, `token_in` and `token_out` as hex strings
+//! - `token_in_symbol` and `token_out_symbol` as strings
+//! - `amount_in` and `amount_out` as float64, already scaled by the token
+//!   decimals
+
+use std::{fs::File, path::Path, str::FromStr};
+
+use alloy_primitives::{Address, B256};
+use arrow::{
+    array::{Array, Float64Array, StringArray, UInt64Array},
+    record_batch::RecordBatch,
+};
+use brontes_types::db::l2_swaps::{L2Swap, L2SwapDataset};
+use eyre::{eyre, Result, WrapErr};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+pub fn load_l2_swaps(path: impl AsRef<Path>) -> Result<L2SwapDataset> {
+    let path = path.as_ref();
+
+    let files = if path.is_dir() {
+        let mut files = std::fs::read_dir(path)
+            .wrap_err_with(|| format!("Could not read {}", path.display()))?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        files.retain(|file| file.extension().is_some_and(|ext| ext == "parquet"));
+        files.sort();
+        files
+    } else if path.exists() {
+        vec![path.to_path_buf()]
+    } else {
+        return Err(eyre!("L2 swap dataset {} doesn't exist", path.display()))
+    };
+
+    let mut swaps = Vec::new();
+    for file in files {
+        swaps.extend(read_parquet(&file)?);
+    }
+
+    Ok(L2SwapDataset::new(swaps))
+}
+
+fn read_parquet(path: &Path) -> Result<Vec<L2Swap>> {
+    let file = File::open(path).wrap_err_with(|| format!("Could not open {}", path.display()))?;
+    let reader = ParquetRecordBatchReaderBuilder::try_new(file)?.build()?;
+
+    let mut swaps = Vec::new();
+    for batch in reader {
+        let batch = batch?;
+        swaps.extend(
+            swaps_from_batch(&batch)
+                .wrap_err_with(|| format!("Invalid L2 swap dataset {}", path.display()))?,
+        );
+    }
+
+    Ok(swaps)
+}
+
+fn swaps_from_batch(batch: &RecordBatch) -> Result<Vec<L2Swap>> {
+    let chain_id = column::<UInt64Array>(batch, "chain_id")?;
+    let block_number = column::<UInt64Array>(batch, "block_number")?;
+    let timestamp = column::<UInt64Array>(batch, "timestamp")?;
+    let tx_hash = column::<StringArray>(batch, "tx_hash")?;
+    let trader = column::<StringArray>(batch, "trader")?;
+    let pool = column::<StringArray>(batch, "pool")?;
+    let token_in = column::<StringArray>(batch, "token_in")?;
+    let token_in_symbol = column::<StringArray>(batch, "token_in_symbol")?;
+    let token_out = column::<StringArray>(batch, "token_out")?;
+    let token_out_symbol = column::<StringArray>(batch, "token_out_symbol")?;
+    let amount_in = column::<Float64Array>(batch, "amount_in")?;
+    let amount_out = column::<Float64Array>(batch, "amount_out")?;
+
+    (0..batch.num_rows())
+        .map(|i| {
+            Ok(L2Swap {
+                chain_id:         chain_id.value(i),
+                block_number:     block_number.value(i),
+                timestamp:        timestamp.value(i),
+                tx_hash:          parse::<B256>(tx_hash.value(i))?,
+                trader:           parse::<Address>(trader.value(i))?,
+                pool:             parse::<Address>(pool.value(i))?,
+                token_in:         parse::<Address>(token_in.value(i))?,
+                token_in_symbol:  token_in_symbol.value(i).to_string(),
+                token_out:        parse::<Address>(token_out.value(i))?,
+                token_out_symbol: token_out_symbol.value(i).to_string(),
+                amount_in:        amount_in.value(i),
+                amount_out:       amount_out.value(i),
+            })
+        })
+        .collect()
+}
+
+fn parse<T: FromStr>(value: &str) -> Result<T> {
+    value.parse().map_err(|_| eyre!("Invalid hex value {value}"))
+}
+
+fn column<'a, A: Array + 'static>(batch: &'a RecordBatch, name: &str) -> Result<&'a A> {
+    batch
+        .column_by_name(name)
+        .ok_or_else(|| eyre!("Missing column {name}"))?
+        .as_any()
+        .downcast_ref::<A>()
+        .ok_or_else(|| eyre!("Column {name} has the wrong type"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::datatypes::{DataType, Field, Schema};
+    use parquet::arrow::ArrowWriter;
+
+    use super::*;
+
+    #[test]
+    fn test_load_l2_swaps() {
+        let dir = std::env::temp_dir().join("brontes-l2-swaps");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let trader = Address::with_last_byte(1);
+        let strings = |values: Vec<String>| Arc::new(StringArray::from(values)) as Arc<dyn Array>;
+        let u64s = |values: Vec<u64>| Arc::new(UInt64Array::from(values)) as Arc<dyn Array>;
+        let f64s = |values: Vec<f64>| Arc::new(Float64Array::from(values)) as Arc<dyn Array>;
+        let hex = |value: String| vec![value.clone(), value];
+
+        let schema = Schema::new(vec![
+            Field::new("chain_id", DataType::UInt64, false),
+            Field::new("block_number", DataType::UInt64, false),
+            Field::new("timestamp", DataType::UInt64, false),
+            Field::new("tx_hash", DataType::Utf8, false),
+            Field::new("trader", DataType::Utf8, false),
+            Field::new("pool", DataType::Utf8, false),
+            Field::new("token_in", DataType::Utf8, false),
+            Field::new("token_in_symbol", DataType::Utf8, false),
+            Field::new("token_out", DataType::Utf8, false),
+            Field::new("token_out_symbol", DataType::Utf8, false),
+            Field::new("amount_in", DataType::Float64, false),
+            Field::new("amount_out", DataType::Float64, false),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                u64s(vec![8453, 8453]),
+                u64s(vec![100, 101]),
+                u64s(vec![1_000, 1_002]),
+                strings(hex(B256::ZERO.to_string())),
+                strings(hex(trader.to_string())),
+                strings(hex(Address::ZERO.to_string())),
+                strings(hex(Address::ZERO.to_string())),
+                strings(vec!["WETH".to_string(), "USDC".to_string()]),
+                strings(hex(Address::ZERO.to_string())),
+                strings(vec!["USDC".to_string(), "WETH".to_string()]),
+                f64s(vec![1.0, 3_000.0]),
+                f64s(vec![3_000.0, 1.0]),
+            ],
+        )
+        .unwrap();
+
+        let file = File::create(dir.join("base.parquet")).unwrap();
+        let mut writer = ArrowWriter::try_new(file, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let dataset = load_l2_swaps(&dir).unwrap();
+        assert_eq!(dataset.len(), 2);
+
+        let swaps = dataset.swaps_around(8453, trader, 1_001, 1);
+        assert_eq!(swaps.len(), 2);
+        assert_eq!(swaps[0].token_in_symbol, "WETH");
+        assert_eq!(swaps[1].amount_in, 3_000.0);
+    }
+}
@@ -129,6 +129,9 @@ fn get_mev_count_array(mev_blocks: &Vec<MevBlock>) -> StructArray {
     let mut jit_count_builder = UInt64Builder::new();
     let mut jit_sandwich_count_builder = UInt64Builder::new();
     let mut searcher_tx_count_builder = UInt64Builder::new();
+    let mut long_tail_count_builder = UInt64Builder::new();
+    let mut nft_count_builder = UInt64Builder::new();
+    let mut cross_domain_count_builder = UInt64Builder::new();
 
     for block in mev_blocks {
         mev_count_builder.append_value(block.mev_count.bundle_count);
@@ -139,6 +142,9 @@ fn get_mev_count_array(mev_blocks: &Vec<MevBlock>) -> StructArray {
         jit_count_builder.append_option(block.mev_count.jit_count);
         jit_sandwich_count_builder.append_option(block.mev_count.jit_sandwich_count);
         searcher_tx_count_builder.append_option(block.mev_count.searcher_tx_count);
+        long_tail_count_builder.append_option(block.mev_count.long_tail_count);
+        nft_count_builder.append_option(block.mev_count.nft_count);
+        cross_domain_count_builder.append_option(block.mev_count.cross_domain_count);
     }
 
     let mev_count_array = mev_count_builder.finish();
@@ -149,6 +155,9 @@ fn get_mev_count_array(mev_blocks: &Vec<MevBlock>) -> StructArray {
     let jit_count_array = jit_count_builder.finish();
     let jit_sandwich_count_array = jit_sandwich_count_builder.finish();
     let searcher_tx_count_array = searcher_tx_count_builder.finish();
+    let long_tail_count_array = long_tail_count_builder.finish();
+    let nft_count_array = nft_count_builder.finish();
+    let cross_domain_count_array = cross_domain_count_builder.finish();
 
     let fields = vec![
         Field::new("mev_count", DataType::UInt64, false),
@@ -159,6 +168,9 @@ fn get_mev_count_array(mev_blocks: &Vec<MevBlock>) -> StructArray {
         Field::new("jit_count", DataType::UInt64, true),
         Field::new("jit_sandwich_count", DataType::UInt64, true),
         Field::new("searcher_tx_count", DataType::UInt64, true),
+        Field::new("long_tail_count", DataType::UInt64, true),
+        Field::new("nft_count", DataType::UInt64, true),
+        Field::new("cross_domain_count", DataType::UInt64, true),
     ];
 
     let arrays = vec![
@@ -170,6 +182,9 @@ fn get_mev_count_array(mev_blocks: &Vec<MevBlock>) -> StructArray {
         Arc::new(jit_count_array) as ArrayRef,
         Arc::new(jit_sandwich_count_array) as ArrayRef,
         Arc::new(searcher_tx_count_array) as ArrayRef,
+        Arc::new(long_tail_count_array) as ArrayRef,
+        Arc::new(nft_count_array) as ArrayRef,
+        Arc::new(cross_domain_count_array) as ArrayRef,
     ];
 
     StructArray::try_new(fields.into(), arrays, None).expect("Failed to init struct arrays")
@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use arrow::{
+    array::{
+        Array, ArrayBuilder, Float64Builder, ListArray, ListBuilder, StringBuilder, StructBuilder,
+        UInt64Builder,
+    },
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use brontes_types::{db::l2_swaps::L2Swap, mev::CrossDomainArb};
+use itertools::Itertools;
+
+use crate::parquet::{
+    normalized_actions::{
+        bridges::{get_normalized_bridge_deposit_list_array, get_normalized_bridge_fill_list_array},
+        gas_details::get_gas_details_array,
+        swaps::get_normalized_swap_list_array,
+    },
+    utils::{build_uint64_array, get_string_array_from_owned},
+};
+
+pub fn cross_domain_to_record_batch(arbs: Vec<CrossDomainArb>) -> Result<RecordBatch, ArrowError> {
+    let tx_hash_array = get_string_array_from_owned(
+        arbs.iter()
+            .map(|arb| Some(arb.tx_hash.to_string()))
+            .collect_vec(),
+    );
+
+    let block_number_array = build_uint64_array(arbs.iter().map(|arb| arb.block_number).collect());
+
+    let mainnet_swaps_array = get_normalized_swap_list_array(
+        arbs.iter()
+            .map(|arb| arb.mainnet_swaps.iter().collect_vec())
+            .collect_vec(),
+    );
+
+    let deposits_array =
+        get_normalized_bridge_deposit_list_array(arbs.iter().map(|arb| &arb.deposits).collect());
+
+    let fills_array =
+        get_normalized_bridge_fill_list_array(arbs.iter().map(|arb| &arb.fills).collect());
+
+    let l2_swaps_array = get_l2_swap_list_array(arbs.iter().map(|arb| &arb.l2_swaps).collect());
+
+    let gas_details_array = get_gas_details_array(arbs.iter().map(|arb| arb.gas_details).collect());
+
+    let schema = Schema::new(vec![
+        Field::new("tx_hash", DataType::Utf8, false),
+        Field::new("block_number", DataType::UInt64, false),
+        Field::new("mainnet_swaps", mainnet_swaps_array.data_type().clone(), false),
+        Field::new("deposits", deposits_array.data_type().clone(), false),
+        Field::new("fills", fills_array.data_type().clone(), false),
+        Field::new("l2_swaps", l2_swaps_array.data_type().clone(), false),
+        Field::new("gas_details", gas_details_array.data_type().clone(), false),
+    ]);
+
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(tx_hash_array),
+            Arc::new(block_number_array),
+            Arc::new(mainnet_swaps_array),
+            Arc::new(deposits_array),
+            Arc::new(fills_array),
+            Arc::new(l2_swaps_array),
+            Arc::new(gas_details_array),
+        ],
+    )
+}
+
+fn get_l2_swap_list_array(l2_swaps_list: Vec<&Vec<L2Swap>>) -> ListArray {
+    let fields = vec![
+        Field::new("chain_id", DataType::UInt64, false),
+        Field::new("block_number", DataType::UInt64, false),
+        Field::new("tx_hash", DataType::Utf8, false),
+        Field::new("pool", DataType::Utf8, false),
+        Field::new("token_in_symbol", DataType::Utf8, false),
+        Field::new("token_out_symbol", DataType::Utf8, false),
+        Field::new("amount_in", DataType::Float64, false),
+        Field::new("amount_out", DataType::Float64, false),
+    ];
+    let builder_array: Vec<Box<dyn ArrayBuilder>> = vec![
+        Box::new(UInt64Builder::new()),
+        Box::new(UInt64Builder::new()),
+        Box::new(StringBuilder::new()),
+        Box::new(StringBuilder::new()),
+        Box::new(StringBuilder::new()),
+        Box::new(StringBuilder::new()),
+        Box::new(Float64Builder::new()),
+        Box::new(Float64Builder::new()),
+    ];
+    let mut list_builder = ListBuilder::new(StructBuilder::new(fields, builder_array));
+
+    for l2_swaps in l2_swaps_list {
+        let struct_builder = list_builder.values();
+
+        for swap in l2_swaps {
+            struct_builder
+                .field_builder::<UInt64Builder>(0)
+                .unwrap()
+                .append_value(swap.chain_id);
+
+            struct_builder
+                .field_builder::<UInt64Builder>(1)
+                .unwrap()
+                .append_value(swap.block_number);
+
+            struct_builder
+                .field_builder::<StringBuilder>(2)
+                .unwrap()
+                .append_value(swap.tx_hash.to_string());
+
+            struct_builder
+                .field_builder::<StringBuilder>(3)
+                .unwrap()
+                .append_value(swap.pool.to_string());
+
+            struct_builder
+                .field_builder::<StringBuilder>(4)
+                .unwrap()
+                .append_value(&swap.token_in_symbol);
+
+            struct_builder
+                .field_builder::<StringBuilder>(5)
+                .unwrap()
+                .append_value(&swap.token_out_symbol);
+
+            struct_builder
+                .field_builder::<Float64Builder>(6)
+                .unwrap()
+                .append_value(swap.amount_in);
+
+            struct_builder
+                .field_builder::<Float64Builder>(7)
+                .unwrap()
+                .append_value(swap.amount_out);
+
+            struct_builder.append(true);
+        }
+
+        list_builder.append(true);
+    }
+
+    list_builder.finish()
+}
@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use arrow::{
+    array::Array,
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use brontes_types::mev::LongTail;
+use itertools::Itertools;
+
+use crate::parquet::{
+    normalized_actions::{
+        gas_details::get_gas_details_list_array, swaps::get_normalized_swap_list_array,
+    },
+    utils::{
+        build_float64_array, build_uint64_array, get_list_string_array_from_owned,
+        get_list_uint64_array_from_owned, get_string_array_from_owned,
+    },
+};
+
+pub fn long_tail_to_record_batch(long_tails: Vec<LongTail>) -> Result<RecordBatch, ArrowError> {
+    let block_number_array =
+        build_uint64_array(long_tails.iter().map(|lt| lt.block_number).collect());
+
+    let sniped_token_array = get_string_array_from_owned(
+        long_tails
+            .iter()
+            .map(|lt| Some(lt.sniped_token.address.to_string()))
+            .collect(),
+    );
+
+    let sniped_token_symbol_array = get_string_array_from_owned(
+        long_tails
+            .iter()
+            .map(|lt| Some(lt.sniped_token.symbol.clone()))
+            .collect(),
+    );
+
+    let pool_array = get_string_array_from_owned(
+        long_tails
+            .iter()
+            .map(|lt| Some(lt.pool.to_string()))
+            .collect(),
+    );
+
+    let pool_creation_block_array =
+        build_uint64_array(long_tails.iter().map(|lt| lt.pool_creation_block).collect());
+
+    let entry_tx_hashes_array = get_list_string_array_from_owned(
+        long_tails
+            .iter()
+            .map(|lt| {
+                lt.entry_tx_hashes
+                    .iter()
+                    .map(|hash| hash.to_string())
+                    .collect_vec()
+            })
+            .collect_vec(),
+    );
+
+    let entry_block_numbers_array = get_list_uint64_array_from_owned(
+        long_tails
+            .iter()
+            .map(|lt| lt.entry_block_numbers.clone())
+            .collect_vec(),
+    );
+
+    let entry_swaps_array = get_normalized_swap_list_array(
+        long_tails
+            .iter()
+            .map(|lt| lt.entry_swaps.iter().flatten().collect_vec())
+            .collect_vec(),
+    );
+
+    let entry_gas_details_array = get_gas_details_list_array(
+        long_tails
+            .iter()
+            .map(|lt| &lt.entry_gas_details)
+            .collect_vec(),
+    );
+
+    let entry_amount_array =
+        build_float64_array(long_tails.iter().map(|lt| lt.entry_amount).collect());
+
+    let entry_size_usd_array =
+        build_float64_array(long_tails.iter().map(|lt| lt.entry_size_usd).collect());
+
+    let exit_tx_hashes_array = get_list_string_array_from_owned(
+        long_tails
+            .iter()
+            .map(|lt| {
+                lt.exit_tx_hashes
+                    .iter()
+                    .map(|hash| hash.to_string())
+                    .collect_vec()
+            })
+            .collect_vec(),
+    );
+
+    let exit_swaps_array = get_normalized_swap_list_array(
+        long_tails
+            .iter()
+            .map(|lt| lt.exit_swaps.iter().flatten().collect_vec())
+            .collect_vec(),
+    );
+
+    let exit_gas_details_array = get_gas_details_list_array(
+        long_tails
+            .iter()
+            .map(|lt| &lt.exit_gas_details)
+            .collect_vec(),
+    );
+
+    let exit_amount_array =
+        build_float64_array(long_tails.iter().map(|lt| lt.exit_amount).collect());
+
+    let exit_size_usd_array =
+        build_float64_array(long_tails.iter().map(|lt| lt.exit_size_usd).collect());
+
+    let realized_pnl_usd_array =
+        build_float64_array(long_tails.iter().map(|lt| lt.realized_pnl_usd).collect());
+
+    let unrealized_pnl_usd_array =
+        build_float64_array(long_tails.iter().map(|lt| lt.unrealized_pnl_usd).collect());
+
+    let schema = Schema::new(vec![
+        Field::new("block_number", DataType::UInt64, false),
+        Field::new("sniped_token", DataType::Utf8, false),
+        Field::new("sniped_token_symbol", DataType::Utf8, false),
+        Field::new("pool", DataType::Utf8, false),
+        Field::new("pool_creation_block", DataType::UInt64, false),
+        Field::new("entry_tx_hashes", entry_tx_hashes_array.data_type().clone(), false),
+        Field::new("entry_block_numbers", entry_block_numbers_array.data_type().clone(), false),
+        Field::new("entry_swaps", entry_swaps_array.data_type().clone(), false),
+        Field::new("entry_gas_details", entry_gas_details_array.data_type().clone(), false),
+        Field::new("entry_amount", DataType::Float64, false),
+        Field::new("entry_size_usd", DataType::Float64, false),
+        Field::new("exit_tx_hashes", exit_tx_hashes_array.data_type().clone(), false),
+        Field::new("exit_swaps", exit_swaps_array.data_type().clone(), false),
+        Field::new("exit_gas_details", exit_gas_details_array.data_type().clone(), false),
+        Field::new("exit_amount", DataType::Float64, false),
+        Field::new("exit_size_usd", DataType::Float64, false),
+        Field::new("realized_pnl_usd", DataType::Float64, false),
+        Field::new("unrealized_pnl_usd", DataType::Float64, false),
+    ]);
+
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(block_number_array),
+            Arc::new(sniped_token_array),
+            Arc::new(sniped_token_symbol_array),
+            Arc::new(pool_array),
+            Arc::new(pool_creation_block_array),
+            Arc::new(entry_tx_hashes_array),
+            Arc::new(entry_block_numbers_array),
+            Arc::new(entry_swaps_array),
+            Arc::new(entry_gas_details_array),
+            Arc::new(entry_amount_array),
+            Arc::new(entry_size_usd_array),
+            Arc::new(exit_tx_hashes_array),
+            Arc::new(exit_swaps_array),
+            Arc::new(exit_gas_details_array),
+            Arc::new(exit_amount_array),
+            Arc::new(exit_size_usd_array),
+            Arc::new(realized_pnl_usd_array),
+            Arc::new(unrealized_pnl_usd_array),
+        ],
+    )
+}
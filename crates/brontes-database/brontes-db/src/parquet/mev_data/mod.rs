@@ -1,16 +1,22 @@
 mod atomic_arb;
 
 mod cex_dex;
+mod cross_domain;
 mod jit;
 mod jit_sandwich;
 mod liquidation;
+mod long_tail;
+mod nft;
 mod sandwich;
 mod searcher_tx;
 
 pub use atomic_arb::*;
 //pub use cex_dex::*;
+pub use cross_domain::*;
 pub use jit::*;
 pub use jit_sandwich::*;
 pub use liquidation::*;
+pub use long_tail::*;
+pub use nft::*;
 pub use sandwich::*;
 pub use searcher_tx::*;
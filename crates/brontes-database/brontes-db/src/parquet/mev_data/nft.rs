@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use arrow::{
+    array::Array,
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use brontes_types::mev::NftMev;
+use itertools::Itertools;
+
+use crate::parquet::{
+    normalized_actions::{
+        gas_details::get_gas_details_list_array, nft_trades::get_normalized_nft_trade_list_array,
+    },
+    utils::{
+        build_float64_array, build_uint64_array, get_list_string_array_from_owned,
+        get_string_array_from_owned,
+    },
+};
+
+pub fn nft_to_record_batch(nft_mevs: Vec<NftMev>) -> Result<RecordBatch, ArrowError> {
+    let block_number_array =
+        build_uint64_array(nft_mevs.iter().map(|nm| nm.block_number).collect());
+
+    let kind_array = get_string_array_from_owned(
+        nft_mevs
+            .iter()
+            .map(|nm| Some(nm.kind.to_string()))
+            .collect(),
+    );
+
+    let tx_hashes_array = get_list_string_array_from_owned(
+        nft_mevs
+            .iter()
+            .map(|nm| {
+                nm.tx_hashes
+                    .iter()
+                    .map(|hash| hash.to_string())
+                    .collect_vec()
+            })
+            .collect_vec(),
+    );
+
+    let buys_array =
+        get_normalized_nft_trade_list_array(nft_mevs.iter().map(|nm| &nm.buys).collect_vec());
+
+    let sells_array =
+        get_normalized_nft_trade_list_array(nft_mevs.iter().map(|nm| &nm.sells).collect_vec());
+
+    let gas_details_array =
+        get_gas_details_list_array(nft_mevs.iter().map(|nm| &nm.gas_details).collect_vec());
+
+    let profit_eth_array = build_float64_array(nft_mevs.iter().map(|nm| nm.profit_eth).collect());
+
+    let schema = Schema::new(vec![
+        Field::new("block_number", DataType::UInt64, false),
+        Field::new("kind", DataType::Utf8, false),
+        Field::new("tx_hashes", tx_hashes_array.data_type().clone(), false),
+        Field::new("buys", buys_array.data_type().clone(), false),
+        Field::new("sells", sells_array.data_type().clone(), false),
+        Field::new("gas_details", gas_details_array.data_type().clone(), false),
+        Field::new("profit_eth", DataType::Float64, false),
+    ]);
+
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(block_number_array),
+            Arc::new(kind_array),
+            Arc::new(tx_hashes_array),
+            Arc::new(buys_array),
+            Arc::new(sells_array),
+            Arc::new(gas_details_array),
+            Arc::new(profit_eth_array),
+        ],
+    )
+}
@@ -14,7 +14,10 @@ use crate::parquet::{
         gas_details::{get_gas_details_array, get_gas_details_list_array},
         swaps::get_normalized_swap_list_array,
     },
-    utils::{get_list_string_array_from_owned, get_string_array_from_owned},
+    utils::{
+        build_uint64_array, get_list_string_array_from_owned, get_list_uint64_array_from_owned,
+        get_string_array_from_owned,
+    },
 };
 
 pub fn sandwich_to_record_batch(sandwiches: Vec<Sandwich>) -> Result<RecordBatch, ArrowError> {
@@ -44,6 +47,13 @@ pub fn sandwich_to_record_batch(sandwiches: Vec<Sandwich>) -> Result<RecordBatch
             .collect_vec(),
     );
 
+    let frontrun_block_numbers_array = get_list_uint64_array_from_owned(
+        sandwiches
+            .iter()
+            .map(|s| s.frontrun_block_numbers.clone())
+            .collect_vec(),
+    );
+
     let victim_swaps_tx_hashes_array = get_list_string_array_from_owned(
         sandwiches
             .iter()
@@ -87,10 +97,18 @@ pub fn sandwich_to_record_batch(sandwiches: Vec<Sandwich>) -> Result<RecordBatch
     let backrun_gas_details_array =
         get_gas_details_array(sandwiches.iter().map(|s| s.backrun_gas_details).collect());
 
+    let backrun_block_number_array =
+        build_uint64_array(sandwiches.iter().map(|s| s.backrun_block_number).collect());
+
     let schema = Schema::new(vec![
         Field::new("frontrun_tx_hash", frontrun_tx_hash_array.data_type().clone(), false),
         Field::new("frontrun_swaps", frontrun_swaps_array.data_type().clone(), false),
         Field::new("frontrun_gas_details", frontrun_gas_details_array.data_type().clone(), false),
+        Field::new(
+            "frontrun_block_numbers",
+            frontrun_block_numbers_array.data_type().clone(),
+            false,
+        ),
         Field::new(
             "victim_swaps_tx_hashes",
             victim_swaps_tx_hashes_array.data_type().clone(),
@@ -105,6 +123,7 @@ pub fn sandwich_to_record_batch(sandwiches: Vec<Sandwich>) -> Result<RecordBatch
         Field::new("backrun_tx_hash", backrun_tx_hash_array.data_type().clone(), false),
         Field::new("backrun_swaps", backrun_swaps_array.data_type().clone(), false),
         Field::new("backrun_gas_details", backrun_gas_details_array.data_type().clone(), false),
+        Field::new("backrun_block_number", backrun_block_number_array.data_type().clone(), false),
     ]);
 
     RecordBatch::try_new(
@@ -113,12 +132,14 @@ pub fn sandwich_to_record_batch(sandwiches: Vec<Sandwich>) -> Result<RecordBatch
             Arc::new(frontrun_tx_hash_array),
             Arc::new(frontrun_swaps_array),
             Arc::new(frontrun_gas_details_array),
+            Arc::new(frontrun_block_numbers_array),
             Arc::new(victim_swaps_tx_hashes_array),
             Arc::new(victim_swaps_array),
             Arc::new(victim_swaps_gas_details_array),
             Arc::new(backrun_tx_hash_array),
             Arc::new(backrun_swaps_array),
             Arc::new(backrun_gas_details_array),
+            Arc::new(backrun_block_number_array),
         ],
     )
 }
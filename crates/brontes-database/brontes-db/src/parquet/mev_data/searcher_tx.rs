@@ -11,7 +11,12 @@ use itertools::Itertools;
 
 use crate::parquet::{
     normalized_actions::{
-        gas_details::get_gas_details_array, transfers::get_normalized_transfer_list_array,
+        gas_details::get_gas_details_array,
+        lending::{
+            get_normalized_loan_list_array, get_normalized_repayment_list_array,
+            get_normalized_supply_list_array, get_normalized_withdraw_list_array,
+        },
+        transfers::get_normalized_transfer_list_array,
     },
     utils::get_string_array_from_owned,
 };
@@ -30,17 +35,43 @@ pub fn searcher_tx_to_record_batch(
         searcher_txs.iter().map(|tx| &tx.transfers).collect_vec(),
     );
 
+    let loans_array =
+        get_normalized_loan_list_array(searcher_txs.iter().map(|tx| &tx.loans).collect_vec());
+
+    let repayments_array = get_normalized_repayment_list_array(
+        searcher_txs.iter().map(|tx| &tx.repayments).collect_vec(),
+    );
+
+    let supplies_array =
+        get_normalized_supply_list_array(searcher_txs.iter().map(|tx| &tx.supplies).collect_vec());
+
+    let withdrawals_array = get_normalized_withdraw_list_array(
+        searcher_txs.iter().map(|tx| &tx.withdrawals).collect_vec(),
+    );
+
     let gas_details_array =
         get_gas_details_array(searcher_txs.iter().map(|tx| tx.gas_details).collect());
 
     let schema = Schema::new(vec![
         Field::new("tx_hash", tx_hash_array.data_type().clone(), false),
         Field::new("transfers", transfers_array.data_type().clone(), false),
+        Field::new("loans", loans_array.data_type().clone(), false),
+        Field::new("repayments", repayments_array.data_type().clone(), false),
+        Field::new("supplies", supplies_array.data_type().clone(), false),
+        Field::new("withdrawals", withdrawals_array.data_type().clone(), false),
         Field::new("gas_details", gas_details_array.data_type().clone(), false),
     ]);
 
     RecordBatch::try_new(
         Arc::new(schema),
-        vec![Arc::new(tx_hash_array), Arc::new(transfers_array), Arc::new(gas_details_array)],
+        vec![
+            Arc::new(tx_hash_array),
+            Arc::new(transfers_array),
+            Arc::new(loans_array),
+            Arc::new(repayments_array),
+            Arc::new(supplies_array),
+            Arc::new(withdrawals_array),
+            Arc::new(gas_details_array),
+        ],
     )
 }
@@ -24,10 +24,14 @@ use crate::Tables;
 mod address_meta;
 mod builder;
 mod bundle_header;
+pub mod l2_swaps;
 mod mev_block;
 mod mev_data;
 mod normalized_actions;
+pub mod relay_fixtures;
 mod searcher;
+mod searcher_stats;
+pub mod tardis;
 pub mod utils;
 
 use address_meta::address_metadata_to_record_batch;
@@ -36,6 +40,7 @@ use bundle_header::bundle_headers_to_record_batch;
 use mev_block::mev_block_to_record_batch;
 use mev_data::*;
 use searcher::searcher_info_to_record_batch;
+use searcher_stats::searcher_stats_to_record_batch;
 
 pub struct ParquetExporter<DB: LibmdbxReader> {
     pub start_block:   Option<u64>,
@@ -84,6 +89,9 @@ where
             jit_sandwich,
             searcher_tx,
             liquidation,
+            long_tail,
+            nft,
+            cross_domain,
         ) = {
             let mut blocks = Vec::new();
             let mut bundle_headers = Vec::new();
@@ -94,6 +102,9 @@ where
             let mut jit_sandwich = Vec::new();
             let mut searcher_tx = Vec::new();
             let mut liquidation = Vec::new();
+            let mut long_tail = Vec::new();
+            let mut nft = Vec::new();
+            let mut cross_domain = Vec::new();
 
             for mb in mev_blocks_iter {
                 blocks.push(mb.block);
@@ -111,6 +122,11 @@ where
                         BundleData::Liquidation(liquidation_data) => {
                             liquidation.push(liquidation_data)
                         }
+                        BundleData::LongTail(long_tail_data) => long_tail.push(long_tail_data),
+                        BundleData::Nft(nft_data) => nft.push(nft_data),
+                        BundleData::CrossDomain(cross_domain_data) => {
+                            cross_domain.push(cross_domain_data)
+                        }
                         _ => continue,
                     }
                 }
@@ -126,6 +142,9 @@ where
                 jit_sandwich,
                 searcher_tx,
                 liquidation,
+                long_tail,
+                nft,
+                cross_domain,
             )
         };
 
@@ -245,6 +264,48 @@ where
             }));
         }
 
+        if !long_tail.is_empty() {
+            bundle_futures.push(tokio::task::spawn_blocking({
+                let base_dir_path = base_dir_path.clone();
+                move || {
+                    let long_tail_batch = long_tail_to_record_batch(long_tail)
+                        .wrap_err("Failed to convert Long Tail data to record batch")?;
+                    sync_write_parquet(
+                        long_tail_batch,
+                        get_path(base_dir_path, Tables::MevBlocks, Some(MevType::LongTail))?,
+                    )
+                }
+            }));
+        }
+
+        if !nft.is_empty() {
+            bundle_futures.push(tokio::task::spawn_blocking({
+                let base_dir_path = base_dir_path.clone();
+                move || {
+                    let nft_batch = nft_to_record_batch(nft)
+                        .wrap_err("Failed to convert Nft data to record batch")?;
+                    sync_write_parquet(
+                        nft_batch,
+                        get_path(base_dir_path, Tables::MevBlocks, Some(MevType::Nft))?,
+                    )
+                }
+            }));
+        }
+
+        if !cross_domain.is_empty() {
+            bundle_futures.push(tokio::task::spawn_blocking({
+                let base_dir_path = base_dir_path.clone();
+                move || {
+                    let cross_domain_batch = cross_domain_to_record_batch(cross_domain)
+                        .wrap_err("Failed to convert Cross Domain data to record batch")?;
+                    sync_write_parquet(
+                        cross_domain_batch,
+                        get_path(base_dir_path, Tables::MevBlocks, Some(MevType::CrossDomain))?,
+                    )
+                }
+            }));
+        }
+
         if !bundle_headers.is_empty() {
             bundle_futures.push(tokio::task::spawn_blocking({
                 let base_dir_path = base_dir_path.clone();
@@ -311,6 +372,33 @@ where
         Ok(())
     }
 
+    pub async fn export_searcher_stats(&self) -> Result<(), Error> {
+        let stats = self
+            .db
+            .fetch_searcher_stats(
+                self.start_block.unwrap_or_default(),
+                self.end_block.unwrap_or(u64::MAX),
+            )
+            .wrap_err("Failed to query searcher stats table")?;
+
+        if stats.is_empty() {
+            error!("No searcher stats for the given range.");
+            return Err(Error::msg("No searcher stats for the given range."))
+        }
+
+        let searcher_stats_batch = searcher_stats_to_record_batch(stats)
+            .wrap_err("Failed to convert searcher stats to record batch")?;
+
+        write_parquet(
+            searcher_stats_batch,
+            get_path(self.base_dir_path.clone(), Tables::SearcherStats, None)?,
+        )
+        .await
+        .wrap_err("Failed to write searcher stats to parquet file")?;
+
+        Ok(())
+    }
+
     pub async fn export_builder_info(&self) -> Result<(), Error> {
         let builder_info = self
             .db
@@ -424,6 +512,7 @@ impl Tables {
             Tables::SearcherEOAs => DEFAULT_SEARCHER_INFO_DIR,
             Tables::SearcherContracts => DEFAULT_SEARCHER_INFO_DIR,
             Tables::Builder => DEFAULT_BUILDER_INFO_DIR,
+            Tables::SearcherStats => DEFAULT_SEARCHER_STATS,
             _ => panic!("Unsupported table type"),
         }
     }
@@ -0,0 +1,158 @@
+use arrow::{
+    array::{
+        ArrayBuilder, Float64Builder, ListArray, ListBuilder, StringBuilder, StructBuilder,
+        UInt16Builder, UInt64Builder,
+    },
+    datatypes::{DataType, Field},
+};
+use brontes_types::{
+    normalized_actions::{NormalizedBridgeDeposit, NormalizedBridgeFill},
+    ToFloatNearest,
+};
+
+pub fn get_normalized_bridge_deposit_list_array(
+    deposits_list: Vec<&Vec<NormalizedBridgeDeposit>>,
+) -> ListArray {
+    let fields = fields("depositor", "destination_chain_id");
+    let builder_array = struct_builder();
+    let mut list_builder = ListBuilder::new(StructBuilder::new(fields, builder_array));
+
+    for deposits in deposits_list {
+        let struct_builder = list_builder.values();
+
+        for deposit in deposits {
+            append_bridge_action(
+                struct_builder,
+                BridgeAction {
+                    protocol:    deposit.protocol.to_string(),
+                    trace_index: deposit.trace_index,
+                    bridge:      deposit.bridge.to_string(),
+                    sender:      deposit.depositor.to_string(),
+                    recipient:   deposit.recipient.to_string(),
+                    chain_id:    deposit.destination_chain_id,
+                    token:       &deposit.token.symbol,
+                    amount:      deposit.amount.clone().to_float(),
+                },
+            );
+        }
+
+        list_builder.append(true);
+    }
+
+    list_builder.finish()
+}
+
+pub fn get_normalized_bridge_fill_list_array(
+    fills_list: Vec<&Vec<NormalizedBridgeFill>>,
+) -> ListArray {
+    let fields = fields("relayer", "origin_chain_id");
+    let builder_array = struct_builder();
+    let mut list_builder = ListBuilder::new(StructBuilder::new(fields, builder_array));
+
+    for fills in fills_list {
+        let struct_builder = list_builder.values();
+
+        for fill in fills {
+            append_bridge_action(
+                struct_builder,
+                BridgeAction {
+                    protocol:    fill.protocol.to_string(),
+                    trace_index: fill.trace_index,
+                    bridge:      fill.bridge.to_string(),
+                    sender:      fill.relayer.to_string(),
+                    recipient:   fill.recipient.to_string(),
+                    chain_id:    fill.origin_chain_id,
+                    token:       &fill.token.symbol,
+                    amount:      fill.amount.clone().to_float(),
+                },
+            );
+        }
+
+        list_builder.append(true);
+    }
+
+    list_builder.finish()
+}
+
+/// The columns deposits and fills share, the sender being the depositor or
+/// the relayer and the chain the destination or the origin chain
+struct BridgeAction<'a> {
+    protocol:    String,
+    trace_index: u64,
+    bridge:      String,
+    sender:      String,
+    recipient:   String,
+    chain_id:    u64,
+    token:       &'a str,
+    amount:      f64,
+}
+
+fn append_bridge_action(struct_builder: &mut StructBuilder, action: BridgeAction<'_>) {
+    struct_builder
+        .field_builder::<StringBuilder>(0)
+        .unwrap()
+        .append_value(action.protocol);
+
+    struct_builder
+        .field_builder::<UInt16Builder>(1)
+        .unwrap()
+        .append_value(action.trace_index as u16);
+
+    struct_builder
+        .field_builder::<StringBuilder>(2)
+        .unwrap()
+        .append_value(action.bridge);
+
+    struct_builder
+        .field_builder::<StringBuilder>(3)
+        .unwrap()
+        .append_value(action.sender);
+
+    struct_builder
+        .field_builder::<StringBuilder>(4)
+        .unwrap()
+        .append_value(action.recipient);
+
+    struct_builder
+        .field_builder::<UInt64Builder>(5)
+        .unwrap()
+        .append_value(action.chain_id);
+
+    struct_builder
+        .field_builder::<StringBuilder>(6)
+        .unwrap()
+        .append_value(action.token);
+
+    struct_builder
+        .field_builder::<Float64Builder>(7)
+        .unwrap()
+        .append_value(action.amount);
+
+    struct_builder.append(true);
+}
+
+fn fields(sender: &str, chain_id: &str) -> Vec<Field> {
+    vec![
+        Field::new("protocol", DataType::Utf8, false),
+        Field::new("trace_index", DataType::UInt16, false),
+        Field::new("bridge", DataType::Utf8, false),
+        Field::new(sender, DataType::Utf8, false),
+        Field::new("recipient", DataType::Utf8, false),
+        Field::new(chain_id, DataType::UInt64, false),
+        Field::new("token_symbol", DataType::Utf8, false),
+        Field::new("amount", DataType::Float64, false),
+    ]
+}
+
+fn struct_builder() -> Vec<Box<dyn ArrayBuilder>> {
+    vec![
+        Box::new(StringBuilder::new()),
+        Box::new(UInt16Builder::new()),
+        Box::new(StringBuilder::new()),
+        Box::new(StringBuilder::new()),
+        Box::new(StringBuilder::new()),
+        Box::new(UInt64Builder::new()),
+        Box::new(StringBuilder::new()),
+        Box::new(Float64Builder::new()),
+    ]
+}
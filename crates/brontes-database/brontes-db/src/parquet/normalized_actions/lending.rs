@@ -0,0 +1,155 @@
+use arrow::{
+    array::{
+        ArrayBuilder, Float64Builder, ListArray, ListBuilder, StringBuilder, StructBuilder,
+        UInt16Builder,
+    },
+    datatypes::{DataType, Field},
+};
+use brontes_types::{
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::{
+        NormalizedLoan, NormalizedRepayment, NormalizedSupply, NormalizedWithdraw,
+    },
+    Protocol, ToFloatNearest,
+};
+use malachite::Rational;
+use reth_primitives::Address;
+
+pub fn get_normalized_loan_list_array(loans_list: Vec<&Vec<NormalizedLoan>>) -> ListArray {
+    lending_list_array(loans_list, &["lender", "borrower"], |loan| LendingRow {
+        protocol:    loan.protocol,
+        trace_index: loan.trace_index,
+        accounts:    vec![loan.lender, loan.borrower],
+        token:       &loan.loaned_token,
+        amount:      &loan.loan_amount,
+    })
+}
+
+pub fn get_normalized_repayment_list_array(
+    repayments_list: Vec<&Vec<NormalizedRepayment>>,
+) -> ListArray {
+    lending_list_array(repayments_list, &["lender", "borrower", "payer"], |repayment| LendingRow {
+        protocol:    repayment.protocol,
+        trace_index: repayment.trace_index,
+        accounts:    vec![repayment.lender, repayment.borrower, repayment.payer],
+        token:       &repayment.repayed_token,
+        amount:      &repayment.repayment_amount,
+    })
+}
+
+pub fn get_normalized_supply_list_array(supplies_list: Vec<&Vec<NormalizedSupply>>) -> ListArray {
+    lending_list_array(supplies_list, &["pool", "supplier", "on_behalf_of"], |supply| LendingRow {
+        protocol:    supply.protocol,
+        trace_index: supply.trace_index,
+        accounts:    vec![supply.pool, supply.supplier, supply.on_behalf_of],
+        token:       &supply.supplied_token,
+        amount:      &supply.supply_amount,
+    })
+}
+
+pub fn get_normalized_withdraw_list_array(
+    withdrawals_list: Vec<&Vec<NormalizedWithdraw>>,
+) -> ListArray {
+    lending_list_array(withdrawals_list, &["pool", "supplier", "recipient"], |withdraw| {
+        LendingRow {
+            protocol:    withdraw.protocol,
+            trace_index: withdraw.trace_index,
+            accounts:    vec![withdraw.pool, withdraw.supplier, withdraw.recipient],
+            token:       &withdraw.withdrawn_token,
+            amount:      &withdraw.withdraw_amount,
+        }
+    })
+}
+
+/// The lending actions only differ in the accounts involved, the accounts are
+/// written in the order of `account_fields`
+struct LendingRow<'a> {
+    protocol:    Protocol,
+    trace_index: u64,
+    accounts:    Vec<Address>,
+    token:       &'a TokenInfoWithAddress,
+    amount:      &'a Rational,
+}
+
+fn lending_list_array<T>(
+    actions_list: Vec<&Vec<T>>,
+    account_fields: &[&str],
+    to_row: impl Fn(&T) -> LendingRow<'_>,
+) -> ListArray {
+    let mut fields = vec![
+        Field::new("protocol", DataType::Utf8, false),
+        Field::new("trace_index", DataType::UInt16, false),
+    ];
+    fields.extend(
+        account_fields
+            .iter()
+            .map(|name| Field::new(*name, DataType::Utf8, false)),
+    );
+    fields.extend([
+        Field::new("token", DataType::Utf8, false),
+        Field::new("token_symbol", DataType::Utf8, false),
+        Field::new("amount", DataType::Float64, false),
+    ]);
+
+    let mut builder_array: Vec<Box<dyn ArrayBuilder>> =
+        vec![Box::new(StringBuilder::new()), Box::new(UInt16Builder::new())];
+    builder_array.extend(
+        account_fields
+            .iter()
+            .map(|_| Box::new(StringBuilder::new()) as Box<dyn ArrayBuilder>),
+    );
+    builder_array.extend([
+        Box::new(StringBuilder::new()) as Box<dyn ArrayBuilder>,
+        Box::new(StringBuilder::new()),
+        Box::new(Float64Builder::new()),
+    ]);
+
+    let token_idx = 2 + account_fields.len();
+    let mut list_builder = ListBuilder::new(StructBuilder::new(fields, builder_array));
+
+    for actions in actions_list {
+        let struct_builder = list_builder.values();
+
+        for action in actions {
+            let row = to_row(action);
+
+            struct_builder
+                .field_builder::<StringBuilder>(0)
+                .unwrap()
+                .append_value(row.protocol.to_string());
+
+            struct_builder
+                .field_builder::<UInt16Builder>(1)
+                .unwrap()
+                .append_value(row.trace_index as u16);
+
+            for (i, account) in row.accounts.iter().enumerate() {
+                struct_builder
+                    .field_builder::<StringBuilder>(2 + i)
+                    .unwrap()
+                    .append_value(account.to_string());
+            }
+
+            struct_builder
+                .field_builder::<StringBuilder>(token_idx)
+                .unwrap()
+                .append_value(row.token.address.to_string());
+
+            struct_builder
+                .field_builder::<StringBuilder>(token_idx + 1)
+                .unwrap()
+                .append_value(&row.token.symbol);
+
+            struct_builder
+                .field_builder::<Float64Builder>(token_idx + 2)
+                .unwrap()
+                .append_value(row.amount.clone().to_float());
+
+            struct_builder.append(true);
+        }
+
+        list_builder.append(true);
+    }
+
+    list_builder.finish()
+}
@@ -20,6 +20,7 @@ pub fn get_normalized_liquidation_list_array(
         Field::new("debt_asset", DataType::Utf8, false),
         Field::new("covered_debt", DataType::Float64, false),
         Field::new("liquidated_collateral", DataType::Float64, false),
+        Field::new("collateral_discount", DataType::Float64, true),
         Field::new("msg_value", DataType::Utf8, false),
     ];
 
@@ -33,6 +34,7 @@ pub fn get_normalized_liquidation_list_array(
         Box::new(StringBuilder::new()),
         Box::new(Float64Builder::new()),
         Box::new(Float64Builder::new()),
+        Box::new(Float64Builder::new()),
         Box::new(StringBuilder::new()),
     ];
 
@@ -86,7 +88,17 @@ pub fn get_normalized_liquidation_list_array(
                 .append_value(liquidation.liquidated_collateral.clone().to_float());
 
             struct_builder
-                .field_builder::<StringBuilder>(9)
+                .field_builder::<Float64Builder>(9)
+                .unwrap()
+                .append_option(
+                    liquidation
+                        .collateral_discount
+                        .clone()
+                        .map(|discount| discount.to_float()),
+                );
+
+            struct_builder
+                .field_builder::<StringBuilder>(10)
                 .unwrap()
                 .append_value(liquidation.msg_value.to_string());
 
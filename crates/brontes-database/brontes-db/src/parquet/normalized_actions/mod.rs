@@ -1,6 +1,9 @@
+pub mod bridges;
 pub mod burns;
 pub mod gas_details;
+pub mod lending;
 pub mod liquidations;
 pub mod mints;
+pub mod nft_trades;
 pub mod swaps;
 pub mod transfers;
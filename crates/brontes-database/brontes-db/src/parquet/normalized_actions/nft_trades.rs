@@ -0,0 +1,115 @@
+use arrow::{
+    array::{
+        ArrayBuilder, Float64Builder, ListArray, ListBuilder, StringBuilder, StructBuilder,
+        UInt16Builder,
+    },
+    datatypes::{DataType, Field},
+};
+use brontes_types::{normalized_actions::NormalizedNftTrade, ToFloatNearest};
+
+pub fn get_normalized_nft_trade_list_array(
+    normalized_nft_trades_list: Vec<&Vec<NormalizedNftTrade>>,
+) -> ListArray {
+    let fields = fields();
+    let builder_array = struct_builder();
+    let mut list_builder = ListBuilder::new(StructBuilder::new(fields, builder_array));
+
+    for normalized_nft_trades in normalized_nft_trades_list {
+        let struct_builder = list_builder.values();
+
+        for trade in normalized_nft_trades {
+            struct_builder
+                .field_builder::<StringBuilder>(0)
+                .unwrap()
+                .append_value(trade.protocol.to_string());
+
+            struct_builder
+                .field_builder::<UInt16Builder>(1)
+                .unwrap()
+                .append_value(trade.trace_index as u16);
+
+            struct_builder
+                .field_builder::<StringBuilder>(2)
+                .unwrap()
+                .append_value(trade.marketplace.to_string());
+
+            struct_builder
+                .field_builder::<StringBuilder>(3)
+                .unwrap()
+                .append_value(trade.buyer.to_string());
+
+            struct_builder
+                .field_builder::<StringBuilder>(4)
+                .unwrap()
+                .append_value(trade.seller.to_string());
+
+            struct_builder
+                .field_builder::<StringBuilder>(5)
+                .unwrap()
+                .append_value(trade.collection.to_string());
+
+            struct_builder
+                .field_builder::<StringBuilder>(6)
+                .unwrap()
+                .append_value(trade.token_id.to_string());
+
+            struct_builder
+                .field_builder::<StringBuilder>(7)
+                .unwrap()
+                .append_value(trade.amount.to_string());
+
+            struct_builder
+                .field_builder::<StringBuilder>(8)
+                .unwrap()
+                .append_value(&trade.payment_token.symbol);
+
+            struct_builder
+                .field_builder::<Float64Builder>(9)
+                .unwrap()
+                .append_value(trade.price.clone().to_float());
+
+            struct_builder
+                .field_builder::<Float64Builder>(10)
+                .unwrap()
+                .append_value(trade.fees.clone().to_float());
+
+            struct_builder.append(true);
+        }
+
+        list_builder.append(true);
+    }
+
+    list_builder.finish()
+}
+
+fn fields() -> Vec<Field> {
+    vec![
+        Field::new("protocol", DataType::Utf8, false),
+        Field::new("trace_index", DataType::UInt16, false),
+        Field::new("marketplace", DataType::Utf8, false),
+        Field::new("buyer", DataType::Utf8, false),
+        Field::new("seller", DataType::Utf8, false),
+        Field::new("collection", DataType::Utf8, false),
+        Field::new("token_id", DataType::Utf8, false),
+        Field::new("amount", DataType::Utf8, false),
+        Field::new("payment_token_symbol", DataType::Utf8, false),
+        Field::new("price", DataType::Float64, false),
+        Field::new("fees", DataType::Float64, false),
+    ]
+}
+
+fn struct_builder() -> Vec<Box<dyn ArrayBuilder>> {
+    vec![
+        Box::new(StringBuilder::new()),
+        Box::new(UInt16Builder::new()),
+        Box::new(StringBuilder::new()),
+        Box::new(StringBuilder::new()),
+        Box::new(StringBuilder::new()),
+        Box::new(StringBuilder::new()),
+        Box::new(StringBuilder::new()),
+        Box::new(StringBuilder::new()),
+        Box::new(StringBuilder::new()),
+        Box::new(Float64Builder::new()),
+        Box::new(Float64Builder::new()),
+    ]
+}
@@ -0,0 +1,310 @@
+//! Loads recorded relay data api dumps into a [`RelayFixtures`] store so relay
+//! metadata can be backfilled without hitting the relays.
+//!
+//! A fixture directory holds:
+//! - `bids.parquet` or `bids.json`: the bids the relays received
+//! - `payloads.parquet` or `payloads.json`: the payloads the relays delivered
+//! - `ultrasound_adjustments.json`: optional, the ultrasound bid adjustments
+//!
+//! The json files are arrays of [`RelayBid`], [`RelayPayload`] and
+//! [`UltrasoundAdjBid`]. The parquet files have a column per field, with the
+//! relay stored by name and the value as a 16 byte big endian binary, the same
+//! way u128s are exported.
+
+use std::{fs::File, path::Path, str::FromStr};
+
+use arrow::{
+    array::{Array, BinaryArray, StringArray, UInt64Array},
+    record_batch::RecordBatch,
+};
+use brontes_types::block_metadata::{
+    RelayBid, RelayFixtures, RelayPayload, Relays, UltrasoundAdjBid,
+};
+use eyre::{eyre, Result, WrapErr};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use serde::de::DeserializeOwned;
+
+const BIDS: &str = "bids";
+const PAYLOADS: &str = "payloads";
+const ULTRASOUND_ADJUSTMENTS: &str = "ultrasound_adjustments.json";
+
+pub fn load_relay_fixtures(dir: impl AsRef<Path>) -> Result<RelayFixtures> {
+    let dir = dir.as_ref();
+    if !dir.is_dir() {
+        return Err(eyre!("Relay fixture directory {} doesn't exist", dir.display()))
+    }
+
+    let bids = load_records(dir, BIDS, bids_from_batch)?;
+    let payloads = load_records(dir, PAYLOADS, payloads_from_batch)?;
+
+    let adjustments_file = dir.join(ULTRASOUND_ADJUSTMENTS);
+    let adjustments = if adjustments_file.exists() {
+        read_json::<UltrasoundAdjBid>(&adjustments_file)?
+    } else {
+        vec![]
+    };
+
+    Ok(RelayFixtures::new(bids, payloads, adjustments))
+}
+
+/// Reads `{name}.parquet`, falling back to `{name}.json`. A missing file is
+/// an empty set of records.
+fn load_records<T: DeserializeOwned>(
+    dir: &Path,
+    name: &str,
+    from_batch: fn(&RecordBatch) -> Result<Vec<T>>,
+) -> Result<Vec<T>> {
+    let parquet_file = dir.join(format!("{name}.parquet"));
+    if parquet_file.exists() {
+        return read_parquet(&parquet_file, from_batch)
+    }
+
+    let json_file = dir.join(format!("{name}.json"));
+    if json_file.exists() {
+        return read_json(&json_file)
+    }
+
+    Ok(vec![])
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> Result<Vec<T>> {
+    let file = File::open(path).wrap_err_with(|| format!("Could not open {}", path.display()))?;
+    serde_json::from_reader(std::io::BufReader::new(file))
+        .wrap_err_with(|| format!("Invalid relay fixture {}", path.display()))
+}
+
+fn read_parquet<T>(path: &Path, from_batch: fn(&RecordBatch) -> Result<Vec<T>>) -> Result<Vec<T>> {
+    let file = File::open(path).wrap_err_with(|| format!("Could not open {}", path.display()))?;
+    let reader = ParquetRecordBatchReaderBuilder::try_new(file)?.build()?;
+
+    let mut records = Vec::new();
+    for batch in reader {
+        let batch = batch?;
+        records.extend(
+            from_batch(&batch)
+                .wrap_err_with(|| format!("Invalid relay fixture {}", path.display()))?,
+        );
+    }
+
+    Ok(records)
+}
+
+fn bids_from_batch(batch: &RecordBatch) -> Result<Vec<RelayBid>> {
+    let common = CommonColumns::new(batch)?;
+    let timestamp = column::<UInt64Array>(batch, "timestamp")?;
+    let timestamp_ms = column::<UInt64Array>(batch, "timestamp_ms")?;
+
+    (0..batch.num_rows())
+        .map(|i| {
+            Ok(RelayBid {
+                relay:                  common.relay(i)?,
+                slot:                   common.slot.value(i),
+                parent_hash:            common.parent_hash.value(i).to_string(),
+                block_hash:             common.block_hash.value(i).to_string(),
+                builder_pubkey:         common.builder_pubkey.value(i).to_string(),
+                proposer_fee_recipient: common.proposer_fee_recipient.value(i).to_string(),
+                gas_limit:              common.gas_limit.value(i),
+                gas_used:               common.gas_used.value(i),
+                value:                  common.value(i)?,
+                block_number:           common.block_number.value(i),
+                num_tx:                 common.num_tx.value(i),
+                timestamp:              timestamp.value(i),
+                timestamp_ms:           timestamp_ms.value(i),
+            })
+        })
+        .collect()
+}
+
+fn payloads_from_batch(batch: &RecordBatch) -> Result<Vec<RelayPayload>> {
+    let common = CommonColumns::new(batch)?;
+
+    (0..batch.num_rows())
+        .map(|i| {
+            Ok(RelayPayload {
+                relay:                  common.relay(i)?,
+                slot:                   common.slot.value(i),
+                parent_hash:            common.parent_hash.value(i).to_string(),
+                block_hash:             common.block_hash.value(i).to_string(),
+                builder_pubkey:         common.builder_pubkey.value(i).to_string(),
+                proposer_fee_recipient: common.proposer_fee_recipient.value(i).to_string(),
+                gas_limit:              common.gas_limit.value(i),
+                gas_used:               common.gas_used.value(i),
+                value:                  common.value(i)?,
+                block_number:           common.block_number.value(i),
+                num_tx:                 common.num_tx.value(i),
+            })
+        })
+        .collect()
+}
+
+/// The columns bids and payloads share
+struct CommonColumns<'a> {
+    relay:                  &'a StringArray,
+    slot:                   &'a UInt64Array,
+    parent_hash:            &'a StringArray,
+    block_hash:             &'a StringArray,
+    builder_pubkey:         &'a StringArray,
+    proposer_fee_recipient: &'a StringArray,
+    gas_limit:              &'a UInt64Array,
+    gas_used:               &'a UInt64Array,
+    value:                  &'a BinaryArray,
+    block_number:           &'a UInt64Array,
+    num_tx:                 &'a UInt64Array,
+}
+
+impl<'a> CommonColumns<'a> {
+    fn new(batch: &'a RecordBatch) -> Result<Self> {
+        Ok(Self {
+            relay:                  column(batch, "relay")?,
+            slot:                   column(batch, "slot")?,
+            parent_hash:            column(batch, "parent_hash")?,
+            block_hash:             column(batch, "block_hash")?,
+            builder_pubkey:         column(batch, "builder_pubkey")?,
+            proposer_fee_recipient: column(batch, "proposer_fee_recipient")?,
+            gas_limit:              column(batch, "gas_limit")?,
+            gas_used:               column(batch, "gas_used")?,
+            value:                  column(batch, "value")?,
+            block_number:           column(batch, "block_number")?,
+            num_tx:                 column(batch, "num_tx")?,
+        })
+    }
+
+    fn relay(&self, i: usize) -> Result<Relays> {
+        let relay = self.relay.value(i);
+        Relays::from_str(relay).map_err(|_| eyre!("Unknown relay {relay}"))
+    }
+
+    fn value(&self, i: usize) -> Result<u128> {
+        let bytes: [u8; 16] = self
+            .value
+            .value(i)
+            .try_into()
+            .map_err(|_| eyre!("Bid value isn't a 16 byte u128"))?;
+
+        Ok(u128::from_be_bytes(bytes))
+    }
+}
+
+fn column<'a, A: Array + 'static>(batch: &'a RecordBatch, name: &str) -> Result<&'a A> {
+    batch
+        .column_by_name(name)
+        .ok_or_else(|| eyre!("Missing column {name}"))?
+        .as_any()
+        .downcast_ref::<A>()
+        .ok_or_else(|| eyre!("Column {name} has the wrong type"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, sync::Arc};
+
+    use arrow::datatypes::{DataType, Field, Schema};
+    use brontes_types::block_metadata::RelayDataProvider;
+    use parquet::arrow::ArrowWriter;
+
+    use super::*;
+    use crate::parquet::utils::u128_to_binary_array;
+
+    fn payload(block_number: u64, value: u128) -> RelayPayload {
+        RelayPayload {
+            relay: Relays::Flashbots,
+            slot: block_number - 10_000_000,
+            parent_hash: "0x01".to_string(),
+            block_hash: "0x02".to_string(),
+            builder_pubkey: "0xbuilder".to_string(),
+            proposer_fee_recipient: "0x03".to_string(),
+            gas_limit: 30_000_000,
+            gas_used: 15_000_000,
+            value,
+            block_number,
+            num_tx: 100,
+        }
+    }
+
+    fn fixture_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("brontes-relay-fixtures-{name}"));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[brontes_macros::test]
+    async fn test_load_json_fixtures() {
+        let dir = fixture_dir("json");
+        let payloads = vec![payload(19_000_000, 100), payload(19_000_001, u128::MAX)];
+        std::fs::write(dir.join("payloads.json"), serde_json::to_vec(&payloads).unwrap()).unwrap();
+
+        let fixtures = load_relay_fixtures(&dir).unwrap();
+        assert_eq!(
+            fixtures
+                .delivered_payloads(Relays::Flashbots, 19_000_001)
+                .await
+                .unwrap(),
+            vec![payloads[1].clone()]
+        );
+        assert!(fixtures
+            .received_bids(Relays::Flashbots, 19_000_000)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[brontes_macros::test]
+    async fn test_load_parquet_fixtures() {
+        let dir = fixture_dir("parquet");
+        let payloads = vec![payload(19_000_000, 100), payload(19_000_001, u128::MAX)];
+
+        let strings = |f: fn(&RelayPayload) -> String| {
+            Arc::new(StringArray::from_iter_values(payloads.iter().map(f))) as Arc<dyn Array>
+        };
+        let u64s = |f: fn(&RelayPayload) -> u64| {
+            Arc::new(UInt64Array::from_iter_values(payloads.iter().map(f))) as Arc<dyn Array>
+        };
+
+        let schema = Schema::new(vec![
+            Field::new("relay", DataType::Utf8, false),
+            Field::new("slot", DataType::UInt64, false),
+            Field::new("parent_hash", DataType::Utf8, false),
+            Field::new("block_hash", DataType::Utf8, false),
+            Field::new("builder_pubkey", DataType::Utf8, false),
+            Field::new("proposer_fee_recipient", DataType::Utf8, false),
+            Field::new("gas_limit", DataType::UInt64, false),
+            Field::new("gas_used", DataType::UInt64, false),
+            Field::new("value", DataType::Binary, false),
+            Field::new("block_number", DataType::UInt64, false),
+            Field::new("num_tx", DataType::UInt64, false),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                strings(|p| p.relay.to_string()),
+                u64s(|p| p.slot),
+                strings(|p| p.parent_hash.clone()),
+                strings(|p| p.block_hash.clone()),
+                strings(|p| p.builder_pubkey.clone()),
+                strings(|p| p.proposer_fee_recipient.clone()),
+                u64s(|p| p.gas_limit),
+                u64s(|p| p.gas_used),
+                Arc::new(u128_to_binary_array(payloads.iter().map(|p| p.value).collect())),
+                u64s(|p| p.block_number),
+                u64s(|p| p.num_tx),
+            ],
+        )
+        .unwrap();
+
+        let file = File::create(dir.join("payloads.parquet")).unwrap();
+        let mut writer = ArrowWriter::try_new(file, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let fixtures = load_relay_fixtures(&dir).unwrap();
+        assert_eq!(
+            fixtures
+                .delivered_payloads(Relays::Flashbots, 19_000_001)
+                .await
+                .unwrap(),
+            vec![payloads[1].clone()]
+        );
+        assert_eq!(fixtures.blocks().len(), 2);
+    }
+}
@@ -72,6 +72,11 @@ pub fn searcher_info_to_record_batch(
         UInt64Builder::with_capacity(eoa_info.len() + contract_info.len());
     let mut searcher_tx_count_builder =
         UInt64Builder::with_capacity(eoa_info.len() + contract_info.len());
+    let mut long_tail_count_builder =
+        UInt64Builder::with_capacity(eoa_info.len() + contract_info.len());
+    let mut nft_count_builder = UInt64Builder::with_capacity(eoa_info.len() + contract_info.len());
+    let mut cross_domain_count_builder =
+        UInt64Builder::with_capacity(eoa_info.len() + contract_info.len());
 
     // Flatten TollByType fields for pnl and gas_bids
     let mut pnl_total_builder = Float64Builder::with_capacity(eoa_info.len() + contract_info.len());
@@ -88,6 +93,11 @@ pub fn searcher_info_to_record_batch(
         Float64Builder::with_capacity(eoa_info.len() + contract_info.len());
     let mut pnl_searcher_tx_builder =
         Float64Builder::with_capacity(eoa_info.len() + contract_info.len());
+    let mut pnl_long_tail_builder =
+        Float64Builder::with_capacity(eoa_info.len() + contract_info.len());
+    let mut pnl_nft_builder = Float64Builder::with_capacity(eoa_info.len() + contract_info.len());
+    let mut pnl_cross_domain_builder =
+        Float64Builder::with_capacity(eoa_info.len() + contract_info.len());
 
     let mut gas_bids_total_builder =
         Float64Builder::with_capacity(eoa_info.len() + contract_info.len());
@@ -105,6 +115,12 @@ pub fn searcher_info_to_record_batch(
         Float64Builder::with_capacity(eoa_info.len() + contract_info.len());
     let mut gas_bids_searcher_tx_builder =
         Float64Builder::with_capacity(eoa_info.len() + contract_info.len());
+    let mut gas_bids_long_tail_builder =
+        Float64Builder::with_capacity(eoa_info.len() + contract_info.len());
+    let mut gas_bids_nft_builder =
+        Float64Builder::with_capacity(eoa_info.len() + contract_info.len());
+    let mut gas_bids_cross_domain_builder =
+        Float64Builder::with_capacity(eoa_info.len() + contract_info.len());
 
     for info in eoa_info.iter().chain(&contract_info) {
         let mev_count = &info.1.mev_count;
@@ -116,6 +132,9 @@ pub fn searcher_info_to_record_batch(
         atomic_backrun_count_builder.append_option(mev_count.atomic_backrun_count);
         liquidation_count_builder.append_option(mev_count.liquidation_count);
         searcher_tx_count_builder.append_option(mev_count.searcher_tx_count);
+        long_tail_count_builder.append_option(mev_count.long_tail_count);
+        nft_count_builder.append_option(mev_count.nft_count);
+        cross_domain_count_builder.append_option(mev_count.cross_domain_count);
 
         let pnl = &info.1.pnl;
         pnl_total_builder.append_value(pnl.total);
@@ -126,6 +145,9 @@ pub fn searcher_info_to_record_batch(
         pnl_atomic_backrun_builder.append_option(pnl.atomic_backrun);
         pnl_liquidation_builder.append_option(pnl.liquidation);
         pnl_searcher_tx_builder.append_option(pnl.searcher_tx);
+        pnl_long_tail_builder.append_option(pnl.long_tail);
+        pnl_nft_builder.append_option(pnl.nft);
+        pnl_cross_domain_builder.append_option(pnl.cross_domain);
 
         let gas_bids = &info.1.gas_bids;
         gas_bids_total_builder.append_value(gas_bids.total);
@@ -136,6 +158,9 @@ pub fn searcher_info_to_record_batch(
         gas_bids_atomic_backrun_builder.append_option(gas_bids.atomic_backrun);
         gas_bids_liquidation_builder.append_option(gas_bids.liquidation);
         gas_bids_searcher_tx_builder.append_option(gas_bids.searcher_tx);
+        gas_bids_long_tail_builder.append_option(gas_bids.long_tail);
+        gas_bids_nft_builder.append_option(gas_bids.nft);
+        gas_bids_cross_domain_builder.append_option(gas_bids.cross_domain);
     }
 
     let schema = Schema::new(vec![
@@ -156,6 +181,9 @@ pub fn searcher_info_to_record_batch(
         Field::new("atomic_backrun_count", DataType::UInt64, true),
         Field::new("liquidation_count", DataType::UInt64, true),
         Field::new("searcher_tx_count", DataType::UInt64, true),
+        Field::new("long_tail_count", DataType::UInt64, true),
+        Field::new("nft_count", DataType::UInt64, true),
+        Field::new("cross_domain_count", DataType::UInt64, true),
         Field::new("pnl_total", DataType::Float64, false),
         Field::new("pnl_sandwich", DataType::Float64, true),
         Field::new("pnl_cex_dex", DataType::Float64, true),
@@ -164,6 +192,9 @@ pub fn searcher_info_to_record_batch(
         Field::new("pnl_atomic_backrun", DataType::Float64, true),
         Field::new("pnl_liquidation", DataType::Float64, true),
         Field::new("pnl_searcher_tx", DataType::Float64, true),
+        Field::new("pnl_long_tail", DataType::Float64, true),
+        Field::new("pnl_nft", DataType::Float64, true),
+        Field::new("pnl_cross_domain", DataType::Float64, true),
         Field::new("gas_bids_total", DataType::Float64, false),
         Field::new("gas_bids_sandwich", DataType::Float64, true),
         Field::new("gas_bids_cex_dex", DataType::Float64, true),
@@ -172,6 +203,9 @@ pub fn searcher_info_to_record_batch(
         Field::new("gas_bids_atomic_backrun", DataType::Float64, true),
         Field::new("gas_bids_liquidation", DataType::Float64, true),
         Field::new("gas_bids_searcher_tx", DataType::Float64, true),
+        Field::new("gas_bids_long_tail", DataType::Float64, true),
+        Field::new("gas_bids_nft", DataType::Float64, true),
+        Field::new("gas_bids_cross_domain", DataType::Float64, true),
     ]);
 
     RecordBatch::try_new(
@@ -190,6 +224,9 @@ pub fn searcher_info_to_record_batch(
             Arc::new(atomic_backrun_count_builder.finish()),
             Arc::new(liquidation_count_builder.finish()),
             Arc::new(searcher_tx_count_builder.finish()),
+            Arc::new(long_tail_count_builder.finish()),
+            Arc::new(nft_count_builder.finish()),
+            Arc::new(cross_domain_count_builder.finish()),
             Arc::new(pnl_total_builder.finish()),
             Arc::new(pnl_sandwich_builder.finish()),
             Arc::new(pnl_cex_dex_builder.finish()),
@@ -198,6 +235,9 @@ pub fn searcher_info_to_record_batch(
             Arc::new(pnl_atomic_backrun_builder.finish()),
             Arc::new(pnl_liquidation_builder.finish()),
             Arc::new(pnl_searcher_tx_builder.finish()),
+            Arc::new(pnl_long_tail_builder.finish()),
+            Arc::new(pnl_nft_builder.finish()),
+            Arc::new(pnl_cross_domain_builder.finish()),
             Arc::new(gas_bids_total_builder.finish()),
             Arc::new(gas_bids_sandwich_builder.finish()),
             Arc::new(gas_bids_cex_dex_builder.finish()),
@@ -206,6 +246,9 @@ pub fn searcher_info_to_record_batch(
             Arc::new(gas_bids_atomic_backrun_builder.finish()),
             Arc::new(gas_bids_liquidation_builder.finish()),
             Arc::new(gas_bids_searcher_tx_builder.finish()),
+            Arc::new(gas_bids_long_tail_builder.finish()),
+            Arc::new(gas_bids_nft_builder.finish()),
+            Arc::new(gas_bids_cross_domain_builder.finish()),
         ],
     )
 }
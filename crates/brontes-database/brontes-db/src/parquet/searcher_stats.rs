@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use arrow::{
+    array::{BooleanBuilder, Float64Builder, UInt64Builder},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use brontes_types::db::{searcher::SearcherEoaContract, searcher_stats::SearcherBucketStats};
+use itertools::Itertools;
+
+use super::utils::build_string_array;
+
+/// One row per searcher, mev type and bucket
+pub fn searcher_stats_to_record_batch(
+    stats: Vec<SearcherBucketStats>,
+) -> Result<RecordBatch, ArrowError> {
+    let rows = stats
+        .iter()
+        .flat_map(|stats| stats.pnl.iter().map(move |pnl| (stats, pnl)))
+        .collect_vec();
+
+    let mut bucket_start_builder = UInt64Builder::with_capacity(rows.len());
+    let mut is_contract_builder = BooleanBuilder::with_capacity(rows.len());
+    let mut bundle_count_builder = UInt64Builder::with_capacity(rows.len());
+    let mut profit_builder = Float64Builder::with_capacity(rows.len());
+    let mut bribe_builder = Float64Builder::with_capacity(rows.len());
+
+    for (stats, pnl) in &rows {
+        bucket_start_builder.append_value(stats.bucket_start);
+        is_contract_builder.append_value(stats.eoa_or_contract == SearcherEoaContract::Contract);
+        bundle_count_builder.append_value(pnl.bundle_count);
+        profit_builder.append_value(pnl.profit_usd);
+        bribe_builder.append_value(pnl.bribe_usd);
+    }
+
+    let address_array = build_string_array(
+        rows.iter()
+            .map(|(stats, _)| stats.address.to_string())
+            .collect_vec(),
+    );
+    let mev_type_array = build_string_array(
+        rows.iter()
+            .map(|(_, pnl)| pnl.mev_type.to_string())
+            .collect_vec(),
+    );
+
+    let schema = Schema::new(vec![
+        Field::new("bucket_start", DataType::UInt64, false),
+        Field::new("address", DataType::Utf8, false),
+        Field::new("is_contract", DataType::Boolean, false),
+        Field::new("mev_type", DataType::Utf8, false),
+        Field::new("bundle_count", DataType::UInt64, false),
+        Field::new("profit_usd", DataType::Float64, false),
+        Field::new("bribe_usd", DataType::Float64, false),
+    ]);
+
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(bucket_start_builder.finish()),
+            Arc::new(address_array),
+            Arc::new(is_contract_builder.finish()),
+            Arc::new(mev_type_array),
+            Arc::new(bundle_count_builder.finish()),
+            Arc::new(profit_builder.finish()),
+            Arc::new(bribe_builder.finish()),
+        ],
+    )
+}
@@ -0,0 +1,969 @@
+//! Builds the cex quotes, trades, perp trades and perp tickers of a block range
+//! from local Tardis style exports, so the `CexPrice`, `CexTrades`,
+//! `CexPerpTrades` and `CexPerpTickers` tables can be initialized without
+//! access to the Sorella clickhouse.
+//!
+//! The data directory is searched recursively for `.csv`, `.csv.gz` and
+//! `.parquet` files. A file is read as trades or quotes when a `_` separated
+//! part of its name, or the name of its parent directory, is `trades` or
+//! `quotes`, as in the `binance_trades_2024-01-01_ETHUSDT.csv.gz` files the
+//! Tardis downloader writes, and as perp tickers when its name or parent
+//! directory contains `derivative_ticker`. The files have the Tardis columns:
+//! - trades: `exchange`, `symbol`, `timestamp`, `local_timestamp`, `id`,
+//!   `side`, `price`, `amount`
+//! - quotes: `exchange`, `symbol`, `timestamp`, `local_timestamp`,
+//!   `ask_amount`, `ask_price`, `bid_price`, `bid_amount`
+//! - derivative tickers: `exchange`, `symbol`, `timestamp`, `local_timestamp`,
+//!   `funding_timestamp`, `funding_rate`, `predicted_funding_rate`,
+//!   `open_interest`, `last_price`, `index_price`, `mark_price`
+//!
+//! Timestamps are unix microseconds. Csv files must have the columns in this
+//! order, parquet files are read by column name.
+//!
+//! Quotes are only loaded for spot venues. The trades of the perpetual venues,
+//! `binance-futures`, `okex-swap` and `bybit`, are kept apart from the spot
+//! trades as perp trades.
+//!
+//! The symbols are mapped to token pairs with a csv file with the columns
+//! `exchange`, `symbol_pair`, `base_token` and `quote_token`.
+//!
+//! An export is imported [`TARDIS_CHUNK_BLOCKS`] blocks at a time with a
+//! [`TardisChunkLoader`], which reads each file once and drops its rows once
+//! the chunks have moved past its day. The trades are imported before the
+//! quotes, so the exchanges the prices are taken from are ranked by their
+//! volume over the whole range.
+
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::Read,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use arrow::{
+    array::{Array, Float64Array, Int64Array, StringArray},
+    compute::cast,
+    csv::ReaderBuilder,
+    datatypes::{DataType, Field, Schema},
+    record_batch::RecordBatch,
+};
+use brontes_types::{
+    db::{
+        block_times::BlockTimes,
+        cex::{
+            quotes::{CexQuotesConverter, RawCexQuotes},
+            trades::{CexTradesConverter, RawCexTrades, RawPerpTicker, TradeType},
+            BestCexPerPair, CexExchange, CexSymbols, InstrumentType,
+        },
+    },
+    pair::Pair,
+    FastHashMap, FastHashSet,
+};
+use chrono::{NaiveDate, NaiveTime};
+use eyre::{eyre, Result, WrapErr};
+use flate2::read::GzDecoder;
+use itertools::Itertools;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+use crate::{CexPerpTickersData, CexPerpTradesData, CexPriceData, CexTradesData};
+
+/// Quotes are kept this long around the blocks for the markouts, the same as
+/// the clickhouse download
+const MAX_MARKOUT_TIME_US: u64 = 300_000_000;
+/// Trades are assigned to the blocks within this window
+const TRADE_WINDOW_US: u64 = 6_000_000;
+const DAY_US: u64 = 86_400_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TardisDataType {
+    Trades,
+    Quotes,
+    DerivativeTicker,
+}
+
+/// Blocks converted and written at once when importing an export
+pub const TARDIS_CHUNK_BLOCKS: usize = 1_000;
+
+/// The cex data of a block range loaded from a Tardis export
+pub struct TardisCexData {
+    pub symbols:     Vec<CexSymbols>,
+    pub trades:      Vec<RawCexTrades>,
+    pub perp_trades: Vec<RawCexTrades>,
+    pub quotes:      Vec<RawCexQuotes>,
+    pub tickers:     Vec<RawPerpTicker>,
+}
+
+/// Indexes the files of a Tardis export. The files are streamed a record batch
+/// at a time, so only the rows of the imported range are kept in memory
+pub struct TardisCexReader {
+    symbols:       Vec<CexSymbols>,
+    known_symbols: FastHashSet<(CexExchange, String)>,
+    exchanges:     Vec<CexExchange>,
+    files:         Vec<(TardisDataType, PathBuf)>,
+}
+
+impl TardisCexReader {
+    /// Indexes the files of `data_dir`, keeping the `exchanges` and the
+    /// symbols of `symbols_file`
+    pub fn new(
+        data_dir: impl AsRef<Path>,
+        symbols_file: impl AsRef<Path>,
+        exchanges: &[CexExchange],
+    ) -> Result<Self> {
+        let symbols = load_cex_symbols(symbols_file)?;
+        let known_symbols = symbols
+            .iter()
+            .map(|s| (s.exchange, s.symbol_pair.clone()))
+            .collect();
+
+        Ok(Self {
+            symbols,
+            known_symbols,
+            exchanges: exchanges.to_vec(),
+            files: data_files(data_dir.as_ref())?,
+        })
+    }
+
+    /// Loads all of the trades, perp trades, quotes and perp tickers within
+    /// the markout window of `block_times` at once
+    pub fn load(&self, block_times: &[BlockTimes]) -> Result<TardisCexData> {
+        self.loader(
+            block_times,
+            &[TardisDataType::Trades, TardisDataType::Quotes, TardisDataType::DerivativeTicker],
+        )?
+        .load(block_times)
+    }
+
+    /// Loads the trades, perp trades and perp tickers of the chunks of
+    /// `block_times`
+    pub fn trades_loader(&self, block_times: &[BlockTimes]) -> Result<TardisChunkLoader<'_>> {
+        self.loader(block_times, &[TardisDataType::Trades, TardisDataType::DerivativeTicker])
+    }
+
+    /// Loads the quotes of the chunks of `block_times`
+    pub fn quotes_loader(&self, block_times: &[BlockTimes]) -> Result<TardisChunkLoader<'_>> {
+        self.loader(block_times, &[TardisDataType::Quotes])
+    }
+
+    fn loader(
+        &self,
+        block_times: &[BlockTimes],
+        data_types: &'static [TardisDataType],
+    ) -> Result<TardisChunkLoader<'_>> {
+        Ok(TardisChunkLoader {
+            reader: self,
+            data_types,
+            range: TimeWindow::of(block_times)?,
+            loaded: BTreeMap::new(),
+            volumes: TradeVolumes::default(),
+        })
+    }
+
+    /// Reads the rows of a file within `window`
+    fn read_file(
+        &self,
+        data_type: TardisDataType,
+        file: &Path,
+        window: TimeWindow,
+    ) -> Result<FileRows> {
+        let keep = |exchange: CexExchange, symbol: &str, timestamp: u64, margin: u64| {
+            self.exchanges.contains(&exchange)
+                && self.known_symbols.contains(&(exchange, symbol.to_string()))
+                && window.contains(timestamp, margin)
+        };
+
+        let mut rows = FileRows::default();
+        let invalid = || format!("Invalid {data_type:?} file {}", file.display());
+        for batch in read_batches(file, data_type)? {
+            let batch = batch.wrap_err_with(invalid)?;
+            match data_type {
+                TardisDataType::Trades => {
+                    for (instrument, trade) in trades_from_batch(&batch)
+                        .wrap_err_with(invalid)?
+                        .into_iter()
+                        .filter(|(_, t)| keep(t.exchange, &t.symbol, t.timestamp, TRADE_WINDOW_US))
+                    {
+                        match instrument {
+                            InstrumentType::Spot => rows.trades.push(trade),
+                            InstrumentType::Perpetual => rows.perp_trades.push(trade),
+                        }
+                    }
+                }
+                // sampled per batch as well to only keep a quote per second in memory
+                TardisDataType::Quotes => rows.quotes.extend(sample_per_second(
+                    quotes_from_batch(&batch)
+                        .wrap_err_with(invalid)?
+                        .into_iter()
+                        .filter(|q| keep(q.exchange, &q.symbol, q.timestamp, MAX_MARKOUT_TIME_US))
+                        .collect(),
+                    |q| (q.exchange, q.symbol.clone(), q.timestamp),
+                )),
+                TardisDataType::DerivativeTicker => rows.tickers.extend(
+                    tickers_from_batch(&batch)
+                        .wrap_err_with(invalid)?
+                        .into_iter()
+                        .filter(|t| keep(t.exchange, &t.symbol, t.timestamp, TRADE_WINDOW_US)),
+                ),
+            }
+        }
+
+        Ok(rows)
+    }
+}
+
+/// Loads the chunks of an import in block order. A file is read once, by the
+/// first chunk whose markout window overlaps its day, and dropped once the
+/// chunks have moved past that day. Files without a day in their name are
+/// kept for the whole import
+pub struct TardisChunkLoader<'a> {
+    reader:     &'a TardisCexReader,
+    data_types: &'static [TardisDataType],
+    /// The window of the whole import
+    range:      TimeWindow,
+    loaded:     BTreeMap<PathBuf, FileRows>,
+    volumes:    TradeVolumes,
+}
+
+impl TardisChunkLoader<'_> {
+    /// Loads the rows within the markout window of `block_times`, which must
+    /// come after the blocks of the previous chunk
+    pub fn load(&mut self, block_times: &[BlockTimes]) -> Result<TardisCexData> {
+        let chunk = TimeWindow::of(block_times)?;
+
+        for (data_type, file) in &self.reader.files {
+            if !self.data_types.contains(data_type)
+                || self.loaded.contains_key(file)
+                || !chunk.overlaps_day(file)
+            {
+                continue
+            }
+
+            let rows = self.reader.read_file(*data_type, file, self.range)?;
+            self.volumes.add(&rows.trades);
+            self.loaded.insert(file.clone(), rows);
+        }
+
+        let mut trades = Vec::new();
+        let mut perp_trades = Vec::new();
+        let mut quotes = Vec::new();
+        let mut tickers = Vec::new();
+        for rows in self.loaded.values() {
+            trades.extend(
+                rows.trades
+                    .iter()
+                    .filter(|t| chunk.contains(t.timestamp, TRADE_WINDOW_US))
+                    .cloned(),
+            );
+            perp_trades.extend(
+                rows.perp_trades
+                    .iter()
+                    .filter(|t| chunk.contains(t.timestamp, TRADE_WINDOW_US))
+                    .cloned(),
+            );
+            quotes.extend(
+                rows.quotes
+                    .iter()
+                    .filter(|q| chunk.contains(q.timestamp, MAX_MARKOUT_TIME_US))
+                    .cloned(),
+            );
+            tickers.extend(
+                rows.tickers
+                    .iter()
+                    .filter(|t| chunk.contains(t.timestamp, TRADE_WINDOW_US))
+                    .cloned(),
+            );
+        }
+
+        // the following chunks start after this one, so they don't reach the
+        // days before it
+        self.loaded.retain(|file, _| {
+            file_day(file).map_or(true, |day| day + DAY_US + MAX_MARKOUT_TIME_US > chunk.last)
+        });
+
+        trades.sort_by_key(|t| t.timestamp);
+        perp_trades.sort_by_key(|t| t.timestamp);
+
+        Ok(TardisCexData {
+            symbols: self.reader.symbols.clone(),
+            trades,
+            perp_trades,
+            quotes: sample_per_second(quotes, |q| (q.exchange, q.symbol.clone(), q.timestamp)),
+            tickers: sample_per_second(tickers, |t| (t.exchange, t.symbol.clone(), t.timestamp)),
+        })
+    }
+
+    /// Ranks the exchanges of each symbol by their traded volume in the quote
+    /// asset, over every trade of the range read so far
+    pub fn best_cex_per_pair(&self) -> Vec<BestCexPerPair> {
+        self.volumes.best_cex_per_pair()
+    }
+}
+
+/// The rows of a file within the window of an import
+#[derive(Default)]
+struct FileRows {
+    trades:      Vec<RawCexTrades>,
+    perp_trades: Vec<RawCexTrades>,
+    quotes:      Vec<RawCexQuotes>,
+    tickers:     Vec<RawPerpTicker>,
+}
+
+/// The first and last block timestamp of a block range
+#[derive(Debug, Clone, Copy)]
+struct TimeWindow {
+    first: u64,
+    last:  u64,
+}
+
+impl TimeWindow {
+    fn of(block_times: &[BlockTimes]) -> Result<Self> {
+        let (Some(first), Some(last)) = (
+            block_times.iter().map(|b| b.timestamp).min(),
+            block_times.iter().map(|b| b.timestamp).max(),
+        ) else {
+            return Err(eyre!("No block times to load the cex data for"))
+        };
+
+        Ok(Self { first, last })
+    }
+
+    fn contains(&self, timestamp: u64, margin: u64) -> bool {
+        timestamp + margin >= self.first && timestamp <= self.last + margin
+    }
+
+    /// Whether the markout window overlaps the day of the file. Files without
+    /// a day always do
+    fn overlaps_day(&self, file: &Path) -> bool {
+        file_day(file).map_or(true, |day| {
+            day + DAY_US + MAX_MARKOUT_TIME_US > self.first
+                && day <= self.last + MAX_MARKOUT_TIME_US
+        })
+    }
+}
+
+impl TardisCexData {
+    /// Builds the cex price maps of the blocks, taking the price of each pair
+    /// from the exchanges in the order of `best_cex_per_pair`
+    pub fn cex_prices(
+        &self,
+        block_times: Vec<BlockTimes>,
+        best_cex_per_pair: Vec<BestCexPerPair>,
+    ) -> Vec<CexPriceData> {
+        CexQuotesConverter::new(
+            block_times,
+            self.symbols.clone(),
+            self.quotes.clone(),
+            best_cex_per_pair,
+        )
+        .convert_to_prices()
+        .into_iter()
+        .map(|(block_num, price_map)| CexPriceData::new(block_num, price_map))
+        .collect()
+    }
+
+    /// Builds the cex trade maps, the perp trade maps and the perp tickers of
+    /// the blocks
+    pub fn cex_trades(
+        &self,
+        block_times: Vec<BlockTimes>,
+    ) -> (Vec<CexTradesData>, Vec<CexPerpTradesData>, Vec<CexPerpTickersData>) {
+        let converter =
+            CexTradesConverter::new(block_times, self.symbols.clone(), self.trades.clone());
+
+        let perp_trades = converter
+            .convert_to_perp_trades(self.perp_trades.clone())
+            .into_iter()
+            .map(|(block_num, trade_map)| CexPerpTradesData::new(block_num, trade_map))
+            .collect();
+        let perp_tickers = converter
+            .convert_to_perp_tickers(self.tickers.clone())
+            .into_iter()
+            .map(|(block_num, ticker_map)| CexPerpTickersData::new(block_num, ticker_map))
+            .collect();
+        let trades = converter
+            .convert_to_trades()
+            .into_iter()
+            .map(|(block_num, trade_map)| CexTradesData::new(block_num, trade_map))
+            .collect();
+
+        (trades, perp_trades, perp_tickers)
+    }
+}
+
+pub fn load_cex_symbols(path: impl AsRef<Path>) -> Result<Vec<CexSymbols>> {
+    let path = path.as_ref();
+    let schema = Schema::new(vec![
+        Field::new("exchange", DataType::Utf8, false),
+        Field::new("symbol_pair", DataType::Utf8, false),
+        Field::new("base_token", DataType::Utf8, false),
+        Field::new("quote_token", DataType::Utf8, false),
+    ]);
+    let file = File::open(path).wrap_err_with(|| format!("Could not open {}", path.display()))?;
+    let reader = ReaderBuilder::new(Arc::new(schema))
+        .with_header(true)
+        .build(file)?;
+
+    let mut symbols = Vec::new();
+    for batch in reader {
+        let batch = batch?;
+        let exchange = column::<StringArray>(&batch, "exchange", &DataType::Utf8)?;
+        let symbol_pair = column::<StringArray>(&batch, "symbol_pair", &DataType::Utf8)?;
+        let base_token = column::<StringArray>(&batch, "base_token", &DataType::Utf8)?;
+        let quote_token = column::<StringArray>(&batch, "quote_token", &DataType::Utf8)?;
+
+        for i in 0..batch.num_rows() {
+            let pair = Pair(
+                base_token
+                    .value(i)
+                    .parse()
+                    .map_err(|_| eyre!("Invalid token address {}", base_token.value(i)))?,
+                quote_token
+                    .value(i)
+                    .parse()
+                    .map_err(|_| eyre!("Invalid token address {}", quote_token.value(i)))?,
+            );
+            symbols.push(CexSymbols {
+                exchange:     CexExchange::from(exchange.value(i)),
+                symbol_pair:  normalize_symbol(symbol_pair.value(i)),
+                address_pair: pair,
+            });
+        }
+    }
+
+    Ok(symbols)
+}
+
+/// Same normalization as the clickhouse queries, `ETH-USDT`, `ETH/USDT` and
+/// `eth_usdt` all become `ETHUSDT`
+fn normalize_symbol(symbol: &str) -> String {
+    symbol.replace(['/', '-', '_'], "").to_uppercase()
+}
+
+/// Keeps the row closest to each whole second per exchange and symbol, the
+/// same sampling the clickhouse quotes query does. `key` returns the exchange,
+/// symbol and timestamp of a row
+fn sample_per_second<T: Clone>(
+    rows: Vec<T>,
+    key: impl Fn(&T) -> (CexExchange, String, u64),
+) -> Vec<T> {
+    let mut sampled: FastHashMap<(CexExchange, String, u64), (u64, T)> = FastHashMap::default();
+
+    for row in rows {
+        let (exchange, symbol, timestamp) = key(&row);
+        let second = (timestamp + 500_000) / 1_000_000 * 1_000_000;
+
+        sampled
+            .entry((exchange, symbol, second))
+            .and_modify(|closest| {
+                if timestamp.abs_diff(second) < closest.0.abs_diff(second) {
+                    *closest = (timestamp, row.clone());
+                }
+            })
+            .or_insert((timestamp, row));
+    }
+
+    sampled
+        .into_values()
+        .sorted_by_key(|(timestamp, _)| *timestamp)
+        .map(|(_, row)| row)
+        .collect()
+}
+
+/// The traded volume in the quote asset of each symbol per exchange
+#[derive(Default)]
+struct TradeVolumes {
+    volumes:         FastHashMap<String, FastHashMap<CexExchange, f64>>,
+    first_timestamp: Option<u64>,
+}
+
+impl TradeVolumes {
+    fn add(&mut self, trades: &[RawCexTrades]) {
+        for trade in trades {
+            *self
+                .volumes
+                .entry(trade.symbol.clone())
+                .or_default()
+                .entry(trade.exchange)
+                .or_default() += trade.price * trade.amount;
+        }
+
+        self.first_timestamp = trades
+            .iter()
+            .map(|t| t.timestamp)
+            .chain(self.first_timestamp)
+            .min();
+    }
+
+    /// Ranks the exchanges of each symbol by their volume
+    fn best_cex_per_pair(&self) -> Vec<BestCexPerPair> {
+        self.volumes
+            .iter()
+            .map(|(symbol, volume)| BestCexPerPair {
+                symbol:    symbol.clone(),
+                exchange:  volume
+                    .iter()
+                    .sorted_by(|a, b| b.1.total_cmp(a.1))
+                    .map(|(exchange, _)| *exchange)
+                    .collect(),
+                timestamp: self.first_timestamp.unwrap_or_default(),
+            })
+            .collect()
+    }
+}
+
+fn data_files(dir: &Path) -> Result<Vec<(TardisDataType, PathBuf)>> {
+    if !dir.is_dir() {
+        return Err(eyre!("Cex data directory {} doesn't exist", dir.display()))
+    }
+
+    let mut files = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in
+            std::fs::read_dir(&dir).wrap_err_with(|| format!("Could not read {}", dir.display()))?
+        {
+            let path = entry?.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if let Some(data_type) = data_type(&path) {
+                files.push((data_type, path));
+            }
+        }
+    }
+    files.sort();
+
+    Ok(files)
+}
+
+fn data_type(path: &Path) -> Option<TardisDataType> {
+    let name = path.file_name()?.to_str()?;
+    let stem = name
+        .strip_suffix(".csv.gz")
+        .or_else(|| name.strip_suffix(".csv"))
+        .or_else(|| name.strip_suffix(".parquet"))?;
+
+    let parent = path
+        .parent()
+        .and_then(|p| p.file_name())
+        .and_then(|p| p.to_str());
+
+    if stem.contains("derivative_ticker") || parent == Some("derivative_ticker") {
+        return Some(TardisDataType::DerivativeTicker)
+    }
+
+    stem.split('_').chain(parent).find_map(|part| match part {
+        "trades" => Some(TardisDataType::Trades),
+        "quotes" => Some(TardisDataType::Quotes),
+        _ => None,
+    })
+}
+
+/// The start of the day in the `_` separated `YYYY-MM-DD` part of the file
+/// name, as in the names the Tardis downloader writes
+fn file_day(path: &Path) -> Option<u64> {
+    path.file_name()?
+        .to_str()?
+        .split(['_', '.'])
+        .find_map(|part| NaiveDate::parse_from_str(part, "%Y-%m-%d").ok())
+        .map(|day| day.and_time(NaiveTime::MIN).and_utc().timestamp_micros() as u64)
+}
+
+type Batches = Box<dyn Iterator<Item = Result<RecordBatch>>>;
+
+/// Streams the record batches of a file
+fn read_batches(path: &Path, data_type: TardisDataType) -> Result<Batches> {
+    let file = File::open(path).wrap_err_with(|| format!("Could not open {}", path.display()))?;
+    let name = path.to_string_lossy();
+
+    if name.ends_with(".parquet") {
+        return Ok(Box::new(
+            ParquetRecordBatchReaderBuilder::try_new(file)?
+                .build()?
+                .map(|batch| batch.map_err(Into::into)),
+        ))
+    }
+
+    let reader: Box<dyn Read> =
+        if name.ends_with(".gz") { Box::new(GzDecoder::new(file)) } else { Box::new(file) };
+
+    Ok(Box::new(
+        ReaderBuilder::new(Arc::new(csv_schema(data_type)))
+            .with_header(true)
+            .build(reader)?
+            .map(|batch| batch.map_err(Into::into)),
+    ))
+}
+
+fn csv_schema(data_type: TardisDataType) -> Schema {
+    let columns: &[(&str, DataType)] = match data_type {
+        TardisDataType::Trades => &[
+            ("exchange", DataType::Utf8),
+            ("symbol", DataType::Utf8),
+            ("timestamp", DataType::Int64),
+            ("local_timestamp", DataType::Int64),
+            ("id", DataType::Utf8),
+            ("side", DataType::Utf8),
+            ("price", DataType::Float64),
+            ("amount", DataType::Float64),
+        ],
+        TardisDataType::Quotes => &[
+            ("exchange", DataType::Utf8),
+            ("symbol", DataType::Utf8),
+            ("timestamp", DataType::Int64),
+            ("local_timestamp", DataType::Int64),
+            ("ask_amount", DataType::Float64),
+            ("ask_price", DataType::Float64),
+            ("bid_price", DataType::Float64),
+            ("bid_amount", DataType::Float64),
+        ],
+        TardisDataType::DerivativeTicker => &[
+            ("exchange", DataType::Utf8),
+            ("symbol", DataType::Utf8),
+            ("timestamp", DataType::Int64),
+            ("local_timestamp", DataType::Int64),
+            ("funding_timestamp", DataType::Int64),
+            ("funding_rate", DataType::Float64),
+            ("predicted_funding_rate", DataType::Float64),
+            ("open_interest", DataType::Float64),
+            ("last_price", DataType::Float64),
+            ("index_price", DataType::Float64),
+            ("mark_price", DataType::Float64),
+        ],
+    };
+
+    Schema::new(
+        columns
+            .iter()
+            .map(|(name, data_type)| Field::new(*name, data_type.clone(), true))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// The trades with the instrument of their venue
+fn trades_from_batch(batch: &RecordBatch) -> Result<Vec<(InstrumentType, RawCexTrades)>> {
+    let exchange = column::<StringArray>(batch, "exchange", &DataType::Utf8)?;
+    let symbol = column::<StringArray>(batch, "symbol", &DataType::Utf8)?;
+    let timestamp = column::<Int64Array>(batch, "timestamp", &DataType::Int64)?;
+    let side = column::<StringArray>(batch, "side", &DataType::Utf8)?;
+    let price = column::<Float64Array>(batch, "price", &DataType::Float64)?;
+    let amount = column::<Float64Array>(batch, "amount", &DataType::Float64)?;
+
+    Ok((0..batch.num_rows())
+        .filter(|&i| {
+            [exchange.is_valid(i), symbol.is_valid(i), timestamp.is_valid(i)]
+                .into_iter()
+                .chain([side.is_valid(i), price.is_valid(i), amount.is_valid(i)])
+                .all(|valid| valid)
+        })
+        .map(|i| {
+            (
+                InstrumentType::from_venue(exchange.value(i)),
+                RawCexTrades {
+                    exchange:   CexExchange::from(exchange.value(i)),
+                    // tardis trades are reported from the side of the taker
+                    trade_type: TradeType::Taker,
+                    symbol:     normalize_symbol(symbol.value(i)),
+                    timestamp:  timestamp.value(i) as u64,
+                    side:       side.value(i).to_string(),
+                    price:      price.value(i),
+                    amount:     amount.value(i),
+                },
+            )
+        })
+        .collect())
+}
+
+/// Quotes with a missing side, from an empty book, and quotes of perpetual
+/// venues are skipped
+fn quotes_from_batch(batch: &RecordBatch) -> Result<Vec<RawCexQuotes>> {
+    let exchange = column::<StringArray>(batch, "exchange", &DataType::Utf8)?;
+    let symbol = column::<StringArray>(batch, "symbol", &DataType::Utf8)?;
+    let timestamp = column::<Int64Array>(batch, "timestamp", &DataType::Int64)?;
+    let ask_amount = column::<Float64Array>(batch, "ask_amount", &DataType::Float64)?;
+    let ask_price = column::<Float64Array>(batch, "ask_price", &DataType::Float64)?;
+    let bid_price = column::<Float64Array>(batch, "bid_price", &DataType::Float64)?;
+    let bid_amount = column::<Float64Array>(batch, "bid_amount", &DataType::Float64)?;
+
+    Ok((0..batch.num_rows())
+        .filter(|&i| {
+            [exchange.is_valid(i), symbol.is_valid(i), timestamp.is_valid(i)]
+                .into_iter()
+                .chain([ask_amount.is_valid(i), ask_price.is_valid(i)])
+                .chain([bid_price.is_valid(i), bid_amount.is_valid(i)])
+                .all(|valid| valid)
+                && InstrumentType::from_venue(exchange.value(i)) == InstrumentType::Spot
+        })
+        .map(|i| RawCexQuotes {
+            exchange:   CexExchange::from(exchange.value(i)),
+            symbol:     normalize_symbol(symbol.value(i)),
+            timestamp:  timestamp.value(i) as u64,
+            ask_amount: ask_amount.value(i),
+            ask_price:  ask_price.value(i),
+            bid_price:  bid_price.value(i),
+            bid_amount: bid_amount.value(i),
+        })
+        .collect())
+}
+
+/// Tickers without a mark price or funding rate are skipped
+fn tickers_from_batch(batch: &RecordBatch) -> Result<Vec<RawPerpTicker>> {
+    let exchange = column::<StringArray>(batch, "exchange", &DataType::Utf8)?;
+    let symbol = column::<StringArray>(batch, "symbol", &DataType::Utf8)?;
+    let timestamp = column::<Int64Array>(batch, "timestamp", &DataType::Int64)?;
+    let mark_price = column::<Float64Array>(batch, "mark_price", &DataType::Float64)?;
+    let funding_rate = column::<Float64Array>(batch, "funding_rate", &DataType::Float64)?;
+
+    Ok((0..batch.num_rows())
+        .filter(|&i| {
+            [exchange.is_valid(i), symbol.is_valid(i), timestamp.is_valid(i)]
+                .into_iter()
+                .chain([mark_price.is_valid(i), funding_rate.is_valid(i)])
+                .all(|valid| valid)
+        })
+        .map(|i| RawPerpTicker {
+            exchange:     CexExchange::from(exchange.value(i)),
+            symbol:       normalize_symbol(symbol.value(i)),
+            timestamp:    timestamp.value(i) as u64,
+            mark_price:   mark_price.value(i),
+            funding_rate: funding_rate.value(i),
+        })
+        .collect())
+}
+
+/// Reads a column cast to `data_type`, as parquet exports don't always use the
+/// same integer and timestamp types
+fn column<A: Array + Clone + 'static>(
+    batch: &RecordBatch,
+    name: &str,
+    data_type: &DataType,
+) -> Result<A> {
+    let column = batch
+        .column_by_name(name)
+        .ok_or_else(|| eyre!("Missing column {name}"))?;
+    let column = cast(column, data_type).wrap_err_with(|| format!("Invalid column {name}"))?;
+
+    column
+        .as_any()
+        .downcast_ref::<A>()
+        .cloned()
+        .ok_or_else(|| eyre!("Column {name} has the wrong type"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use alloy_primitives::Address;
+
+    use super::*;
+
+    #[test]
+    fn test_data_type() {
+        assert_eq!(
+            data_type(Path::new("binance_trades_2024-01-01_ETHUSDT.csv.gz")),
+            Some(TardisDataType::Trades)
+        );
+        assert_eq!(
+            data_type(Path::new("okex_quotes_2024-01-01_ETH-USDT.csv")),
+            Some(TardisDataType::Quotes)
+        );
+        assert_eq!(data_type(Path::new("quotes/binance.parquet")), Some(TardisDataType::Quotes));
+        assert_eq!(
+            data_type(Path::new("binance-futures_derivative_ticker_2024-01-01_ETHUSDT.csv.gz")),
+            Some(TardisDataType::DerivativeTicker)
+        );
+        assert_eq!(data_type(Path::new("binance_book_snapshot_25_2024-01-01.csv.gz")), None);
+        assert_eq!(data_type(Path::new("binance_trades.json")), None);
+    }
+
+    #[test]
+    fn test_file_day() {
+        assert_eq!(
+            file_day(Path::new("binance_trades_2024-01-02_ETHUSDT.csv.gz")),
+            Some(1_704_153_600_000_000)
+        );
+        assert_eq!(file_day(Path::new("quotes/binance.parquet")), None);
+    }
+
+    #[test]
+    fn test_sample_per_second() {
+        let quote = |timestamp: u64| RawCexQuotes {
+            exchange: CexExchange::Binance,
+            symbol: "ETHUSDT".to_string(),
+            timestamp,
+            ..Default::default()
+        };
+
+        let sampled =
+            sample_per_second(vec![quote(999_000), quote(1_200_000), quote(1_900_000)], |q| {
+                (q.exchange, q.symbol.clone(), q.timestamp)
+            });
+
+        assert_eq!(sampled.iter().map(|q| q.timestamp).collect_vec(), vec![999_000, 1_900_000]);
+    }
+
+    #[test]
+    fn test_load_tardis_csv() {
+        let dir = std::env::temp_dir().join("brontes-tardis");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let weth = Address::with_last_byte(1);
+        let usdt = Address::with_last_byte(2);
+        let symbols = dir.join("symbols.csv");
+        std::fs::write(
+            &symbols,
+            format!(
+                "exchange,symbol_pair,base_token,quote_token\nbinance,ETH-USDT,{weth},{usdt}\\
+                 nbinance-futures,ETHUSDT,{weth},{usdt}\n"
+            ),
+        )
+        .unwrap();
+
+        let trades = dir.join("binance_trades_1970-01-01_ETHUSDT.csv.gz");
+        let mut encoder =
+            flate2::write::GzEncoder::new(File::create(&trades).unwrap(), Default::default());
+        encoder
+            .write_all(
+                b"exchange,symbol,timestamp,local_timestamp,id,side,price,amount\n\
+                  binance,ETHUSDT,1000000000,1000000100,1,buy,3000.5,1.5\n\
+                  binance,BTCUSDT,1000000000,1000000100,2,sell,60000,0.1\n\
+                  binance,ETHUSDT,9000000000,9000000100,3,sell,3001,2\n",
+            )
+            .unwrap();
+        encoder.finish().unwrap();
+
+        std::fs::write(
+            dir.join("binance_quotes_1970-01-01_ETHUSDT.csv"),
+            "exchange,symbol,timestamp,local_timestamp,ask_amount,ask_price,bid_price,bid_amount\\
+             \
+             nbinance,ETHUSDT,1000000000,1000000100,3,3000.6,3000.4,2\nbinance,ETHUSDT,1000000500,\
+             1000000600,,,3000.4,2\n",
+        )
+        .unwrap();
+
+        std::fs::write(
+            dir.join("binance-futures_trades_1970-01-01_ETHUSDT.csv"),
+            "exchange,symbol,timestamp,local_timestamp,id,side,price,amount\nbinance-futures,\
+             ETHUSDT,1000000200,1000000300,4,sell,3001.5,10\n",
+        )
+        .unwrap();
+
+        std::fs::write(
+            dir.join("binance-futures_derivative_ticker_1970-01-01_ETHUSDT.csv"),
+            "exchange,symbol,timestamp,local_timestamp,funding_timestamp,funding_rate,\
+             predicted_funding_rate,open_interest,last_price,index_price,mark_price\\
+             \
+             nbinance-futures,ETHUSDT,1000000000,1000000100,1000800000,0.0001,0.0001,5000,3001.5,\
+             3000.8,3001.2\nbinance-futures,ETHUSDT,1000000200,1000000300,1000800000,,,5000,3001.\
+             5,3000.8,\n",
+        )
+        .unwrap();
+
+        // a file of another day isn't read
+        std::fs::write(
+            dir.join("binance_trades_2024-01-01_ETHUSDT.csv"),
+            "exchange,symbol,timestamp,local_timestamp,id,side,price,amount\nbinance,ETHUSDT,\
+             1000000000,1000000100,5,buy,3000.5,1.5\n",
+        )
+        .unwrap();
+
+        let block_times = vec![BlockTimes { block_number: 1, timestamp: 1_000_000_000 }];
+        let data = TardisCexReader::new(&dir, &symbols, &[CexExchange::Binance])
+            .unwrap()
+            .load(&block_times)
+            .unwrap();
+
+        assert_eq!(data.symbols.len(), 2);
+        assert_eq!(data.symbols[0].symbol_pair, "ETHUSDT");
+        assert_eq!(data.symbols[0].address_pair, Pair(weth, usdt));
+
+        // the btc trade has no symbol and the last spot trade is out of the
+        // window
+        assert_eq!(data.trades.len(), 1);
+        assert_eq!(data.trades[0].price, 3000.5);
+
+        // the trade of the perp venue is kept apart from the spot trades
+        assert_eq!(data.perp_trades.len(), 1);
+        assert_eq!(data.perp_trades[0].price, 3001.5);
+
+        // the ticker without a mark price is skipped
+        assert_eq!(data.tickers.len(), 1);
+        assert_eq!(data.tickers[0].mark_price, 3001.2);
+
+        // the quote with an empty ask is skipped
+        assert_eq!(data.quotes.len(), 1);
+        assert_eq!(data.quotes[0].bid_price, 3000.4);
+
+        let reader = TardisCexReader::new(&dir, &symbols, &[CexExchange::Binance]).unwrap();
+        let mut loader = reader.trades_loader(&block_times).unwrap();
+        loader.load(&block_times).unwrap();
+        let ranking = loader.best_cex_per_pair();
+        assert_eq!(ranking[0].exchange, vec![CexExchange::Binance]);
+
+        let (trades, perp_trades, perp_tickers) = data.cex_trades(block_times);
+        assert_eq!(trades[0].value.spot[&CexExchange::Binance][&Pair(weth, usdt)].len(), 1);
+        assert!(trades[0].value.perp.is_empty());
+        assert_eq!(perp_trades[0].value.0[&CexExchange::Binance][&Pair(weth, usdt)].len(), 1);
+        assert_eq!(perp_tickers[0].value.0[&CexExchange::Binance][&Pair(weth, usdt)].len(), 1);
+    }
+
+    #[test]
+    fn test_chunk_loader_reads_files_once() {
+        let dir = std::env::temp_dir().join("brontes-tardis-chunks");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let weth = Address::with_last_byte(1);
+        let usdt = Address::with_last_byte(2);
+        let symbols = dir.join("symbols.csv");
+        std::fs::write(
+            &symbols,
+            format!(
+                "exchange,symbol_pair,base_token,quote_token\nbinance,ETHUSDT,{weth},{usdt}\nokex,\
+                 ETHUSDT,{weth},{usdt}\n"
+            ),
+        )
+        .unwrap();
+
+        // okex has the most volume in the first chunk, binance over the range
+        let trades = dir.join("binance_trades_1970-01-01_ETHUSDT.csv");
+        std::fs::write(
+            &trades,
+            "exchange,symbol,timestamp,local_timestamp,id,side,price,amount\nbinance,ETHUSDT,\
+             1000000000,1000000100,1,buy,3000,1\nbinance,ETHUSDT,2000000000,2000000100,2,buy,3000,\
+             10\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("okex_trades_1970-01-01_ETH-USDT.csv"),
+            "exchange,symbol,timestamp,local_timestamp,id,side,price,amount\nokex,ETH-USDT,\
+             1000000000,1000000100,1,buy,3000,2\n",
+        )
+        .unwrap();
+
+        let block_times = vec![
+            BlockTimes { block_number: 1, timestamp: 1_000_000_000 },
+            BlockTimes { block_number: 2, timestamp: 2_000_000_000 },
+        ];
+        let reader =
+            TardisCexReader::new(&dir, &symbols, &[CexExchange::Binance, CexExchange::Okex])
+                .unwrap();
+        let mut loader = reader.trades_loader(&block_times).unwrap();
+
+        let first = loader.load(&block_times[..1]).unwrap();
+        assert_eq!(first.trades.len(), 2);
+
+        // the second chunk is served from the rows read by the first one
+        std::fs::remove_file(&trades).unwrap();
+        let second = loader.load(&block_times[1..]).unwrap();
+        assert_eq!(second.trades.len(), 1);
+        assert_eq!(second.trades[0].amount, 10.0);
+
+        let ranking = loader.best_cex_per_pair();
+        assert_eq!(ranking.len(), 1);
+        assert_eq!(ranking[0].exchange, vec![CexExchange::Binance, CexExchange::Okex]);
+        assert_eq!(ranking[0].timestamp, 1_000_000_000);
+    }
+}
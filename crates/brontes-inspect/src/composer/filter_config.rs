@@ -0,0 +1,327 @@
+//! Loads the compose and deduplication rules of the composer from a toml
+//! config. Without one, the rules in `config/mev_filter_config.toml` are used.
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use brontes_types::{mev::MevType, FastHashMap, FastHashSet};
+use serde::{Deserialize, Deserializer};
+use strum::IntoEnumIterator;
+
+use super::{
+    composer_filters::{get_compose_fn, ComposeFunction},
+    mev_filters::{get_filter_fn, FilterFn},
+};
+
+const DEFAULT_MEV_FILTER_CONFIG: &str = include_str!("../../../../config/mev_filter_config.toml");
+
+#[derive(Debug, thiserror::Error)]
+pub enum MevFilterConfigError {
+    #[error("failed to read mev filter config {0}: {1}")]
+    Read(PathBuf, std::io::Error),
+    #[error(transparent)]
+    Parse(#[from] toml::de::Error),
+    #[error("{0} has no compose function")]
+    NoComposeFn(MevType),
+    #[error("compose rule for {0} needs at least two child types")]
+    TooFewChildren(MevType),
+    #[error("precedence rule for {0} has no subordinate types")]
+    NoSubordinates(MevType),
+    #[error("precedence rules form a cycle: {0:?}")]
+    PrecedenceCycle(Vec<MevType>),
+}
+
+/// The compose and precedence rules, in the order the composer applies them
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize)]
+pub struct MevFilterConfig {
+    #[serde(default)]
+    pub compose:    Vec<ComposeRule>,
+    #[serde(default)]
+    pub precedence: Vec<PrecedenceRule>,
+}
+
+/// Bundles of the child types sharing a transaction are merged into one
+/// bundle of the parent type
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ComposeRule {
+    #[serde(deserialize_with = "deserialize_mev_type")]
+    pub parent:   MevType,
+    #[serde(deserialize_with = "deserialize_mev_types")]
+    pub children: Vec<MevType>,
+}
+
+/// Bundles of the subordinate types sharing a transaction with a bundle of the
+/// dominant type are dropped
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct PrecedenceRule {
+    #[serde(deserialize_with = "deserialize_mev_type")]
+    pub dominant:     MevType,
+    #[serde(deserialize_with = "deserialize_mev_types")]
+    pub subordinates: Vec<MevType>,
+}
+
+impl FromStr for MevFilterConfig {
+    type Err = MevFilterConfigError;
+
+    fn from_str(config: &str) -> Result<Self, Self::Err> {
+        let config: Self = toml::from_str(config)?;
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+impl MevFilterConfig {
+    pub fn load(path: &Path) -> Result<Self, MevFilterConfigError> {
+        std::fs::read_to_string(path)
+            .map_err(|e| MevFilterConfigError::Read(path.to_path_buf(), e))?
+            .parse()
+    }
+
+    pub fn validate(&self) -> Result<(), MevFilterConfigError> {
+        for rule in &self.compose {
+            if get_compose_fn(rule.parent).is_none() {
+                return Err(MevFilterConfigError::NoComposeFn(rule.parent))
+            }
+            if rule.children.len() < 2 {
+                return Err(MevFilterConfigError::TooFewChildren(rule.parent))
+            }
+        }
+
+        for rule in &self.precedence {
+            if rule.subordinates.is_empty() {
+                return Err(MevFilterConfigError::NoSubordinates(rule.dominant))
+            }
+        }
+
+        if let Some(cycle) = self.find_precedence_cycle() {
+            return Err(MevFilterConfigError::PrecedenceCycle(cycle))
+        }
+
+        Ok(())
+    }
+
+    /// Looks for a cycle of dominant to subordinate edges. A dominant type with
+    /// a filter function only drops some of the bundles it overlaps with, so
+    /// its edges can't make a cycle, except for a type dominating itself.
+    fn find_precedence_cycle(&self) -> Option<Vec<MevType>> {
+        let mut edges: FastHashMap<MevType, FastHashSet<MevType>> = FastHashMap::default();
+        for rule in &self.precedence {
+            if rule.subordinates.contains(&rule.dominant) {
+                return Some(vec![rule.dominant, rule.dominant])
+            }
+            if get_filter_fn(rule.dominant).is_some() {
+                continue
+            }
+            edges
+                .entry(rule.dominant)
+                .or_default()
+                .extend(rule.subordinates.iter().copied());
+        }
+
+        let mut done = FastHashSet::default();
+        for start in MevType::iter() {
+            let mut path = vec![];
+            if let Some(cycle) = visit(start, &edges, &mut path, &mut done) {
+                return Some(cycle)
+            }
+        }
+
+        None
+    }
+}
+
+/// Depth first search that returns the first cycle it runs into
+fn visit(
+    mev_type: MevType,
+    edges: &FastHashMap<MevType, FastHashSet<MevType>>,
+    path: &mut Vec<MevType>,
+    done: &mut FastHashSet<MevType>,
+) -> Option<Vec<MevType>> {
+    if let Some(start) = path.iter().position(|t| *t == mev_type) {
+        let mut cycle = path[start..].to_vec();
+        cycle.push(mev_type);
+        return Some(cycle)
+    }
+    if done.contains(&mev_type) {
+        return None
+    }
+
+    path.push(mev_type);
+    for next in edges.get(&mev_type).into_iter().flatten() {
+        if let Some(cycle) = visit(*next, edges, path, done) {
+            return Some(cycle)
+        }
+    }
+    path.pop();
+    done.insert(mev_type);
+
+    None
+}
+
+/// The rules of a [`MevFilterConfig`] with their compose and filter functions
+pub struct MevFilters {
+    pub compose: Vec<(MevType, ComposeFunction, Vec<MevType>)>,
+    pub dedup:   Vec<(MevType, FilterFn, Vec<MevType>)>,
+}
+
+impl MevFilters {
+    pub fn new(config: MevFilterConfig) -> Self {
+        Self {
+            compose: config
+                .compose
+                .into_iter()
+                .map(|rule| {
+                    (
+                        rule.parent,
+                        get_compose_fn(rule.parent).expect("validated compose rule"),
+                        rule.children,
+                    )
+                })
+                .collect(),
+            dedup:   config
+                .precedence
+                .into_iter()
+                .map(|rule| (rule.dominant, get_filter_fn(rule.dominant), rule.subordinates))
+                .collect(),
+        }
+    }
+
+    /// Loads the rules of the config at `path`, or the default rules if there
+    /// is none
+    pub fn load(path: Option<&Path>) -> Result<Self, MevFilterConfigError> {
+        let config = match path {
+            Some(path) => MevFilterConfig::load(path)?,
+            None => DEFAULT_MEV_FILTER_CONFIG.parse()?,
+        };
+
+        Ok(Self::new(config))
+    }
+}
+
+impl Default for MevFilters {
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_MEV_FILTER_CONFIG
+                .parse()
+                .expect("invalid default mev filter config"),
+        )
+    }
+}
+
+fn parse_mev_type(name: &str) -> Option<MevType> {
+    MevType::iter().find(|mev_type| mev_type.as_ref() == name)
+}
+
+fn deserialize_mev_type<'de, D: Deserializer<'de>>(deserializer: D) -> Result<MevType, D::Error> {
+    let name = String::deserialize(deserializer)?;
+    parse_mev_type(&name)
+        .ok_or_else(|| serde::de::Error::custom(format!("unknown mev type {name}")))
+}
+
+fn deserialize_mev_types<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<MevType>, D::Error> {
+    Vec::<String>::deserialize(deserializer)?
+        .into_iter()
+        .map(|name| {
+            parse_mev_type(&name)
+                .ok_or_else(|| serde::de::Error::custom(format!("unknown mev type {name}")))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config() {
+        let config: MevFilterConfig = DEFAULT_MEV_FILTER_CONFIG.parse().unwrap();
+
+        assert_eq!(
+            config.compose,
+            vec![ComposeRule {
+                parent:   MevType::JitSandwich,
+                children: vec![MevType::Sandwich, MevType::Jit],
+            }]
+        );
+        assert_eq!(config.precedence.len(), 13);
+        assert_eq!(
+            config.precedence[0],
+            PrecedenceRule {
+                dominant:     MevType::AtomicArb,
+                subordinates: vec![MevType::CexDexTrades],
+            }
+        );
+        assert_eq!(config.precedence[11].dominant, MevType::JitCexDex);
+    }
+
+    #[test]
+    fn test_precedence_cycle() {
+        let config = r#"
+            [[precedence]]
+            dominant = "Sandwich"
+            subordinates = ["Jit"]
+
+            [[precedence]]
+            dominant = "Jit"
+            subordinates = ["LongTail"]
+
+            [[precedence]]
+            dominant = "LongTail"
+            subordinates = ["Sandwich"]
+        "#;
+
+        let Err(MevFilterConfigError::PrecedenceCycle(cycle)) = config.parse::<MevFilterConfig>()
+        else {
+            panic!("cycle wasn't detected")
+        };
+        assert_eq!(cycle.len(), 4);
+        assert_eq!(cycle.first(), cycle.last());
+    }
+
+    #[test]
+    fn test_filtered_precedence_is_not_a_cycle() {
+        let config = r#"
+            [[precedence]]
+            dominant = "AtomicArb"
+            subordinates = ["CexDexTrades"]
+
+            [[precedence]]
+            dominant = "CexDexTrades"
+            subordinates = ["AtomicArb"]
+        "#;
+        assert!(config.parse::<MevFilterConfig>().is_ok());
+
+        let config = r#"
+            [[precedence]]
+            dominant = "AtomicArb"
+            subordinates = ["AtomicArb"]
+        "#;
+        assert!(matches!(
+            config.parse::<MevFilterConfig>(),
+            Err(MevFilterConfigError::PrecedenceCycle(_))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        let unknown = r#"
+            [[precedence]]
+            dominant = "Sandwhich"
+            subordinates = ["Jit"]
+        "#;
+        assert!(matches!(unknown.parse::<MevFilterConfig>(), Err(MevFilterConfigError::Parse(_))));
+
+        let no_compose_fn = r#"
+            [[compose]]
+            parent = "Sandwich"
+            children = ["Jit", "AtomicArb"]
+        "#;
+        assert!(matches!(
+            no_compose_fn.parse::<MevFilterConfig>(),
+            Err(MevFilterConfigError::NoComposeFn(MevType::Sandwich))
+        ));
+    }
+}
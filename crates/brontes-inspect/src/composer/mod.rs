@@ -9,18 +9,19 @@
 //! ## Key Components
 //! - `Composer`: A struct that orchestrates specialized inspectors. It waits
 //!   for all results and then proceeds to compose and deduplicate MEV data.
-//! - `MEV_COMPOSABILITY_FILTER` and `MEV_DEDUPLICATION_FILTER`: These filters,
-//!   defined using the `mev_composability` and `define_mev_precedence` macros,
-//!   respectively, establish rules for composing multiple MEV types and setting
-//!   precedence among them for deduplication.
+//! - `MevFilters`: The compose rules and the precedence rules for
+//!   deduplication, loaded from `config/mev_filter_config.toml` or the config
+//!   given to [`MevFilters::load`] and handed to [`run_block_inspection`]. The
+//!   precedence rules are checked for cycles when loaded.
 //! - Utility Functions: A collection of functions designed to assist in the
 //!   composition and deduplication processes of MEV data.
 //!
 //! ## Usage
 //! The `Composer` struct is central to this module. It processes a list of
 //! `Inspector` futures to extract MEV data, which is then composed and
-//! deduplicated based on the rules defined in the `MEV_COMPOSABILITY_FILTER`
-//! and `MEV_DEDUPLICATION_FILTER`.
+//! deduplicated based on the rules in the given `MevFilters`. Every bundle
+//! removed by a precedence rule is recorded as a [`DedupDecision`], which is
+//! stored alongside the block's results.
 //!
 //! ### Example
 //! ```ignore
@@ -36,19 +37,24 @@ use brontes_types::{
     BlockData, FastHashMap, MultiBlockData,
 };
 use itertools::Itertools;
-use tracing::{span, Level};
+use tracing::{debug, span, Level};
 
 mod composer_filters;
+mod filter_config;
 mod mev_filters;
 mod utils;
+pub use brontes_types::db::dedup_decision::DedupDecision;
 use brontes_types::{
     db::metadata::Metadata,
     mev::{Bundle, MevBlock, MevType, PossibleMevCollection},
     normalized_actions::Action,
     tree::BlockTree,
 };
-use composer_filters::{ComposeFunction, MEV_COMPOSABILITY_FILTER};
-use mev_filters::{FilterFn, MEV_DEDUPLICATION_FILTER};
+use composer_filters::ComposeFunction;
+pub use filter_config::{
+    ComposeRule, MevFilterConfig, MevFilterConfigError, MevFilters, PrecedenceRule,
+};
+use mev_filters::FilterFn;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use utils::{
     build_mev_header, filter_and_count_bundles, find_mev_with_matching_tx_hashes, sort_mev_by_type,
@@ -66,10 +72,13 @@ pub struct ComposerResults {
     /// all txes with coinbase.transfers that weren't classified
     pub possible_mev_txes: PossibleMevCollection,
     pub block_analysis:    BlockAnalysis,
+    /// the bundles dropped by the precedence rules
+    pub dedup_decisions:   Vec<DedupDecision>,
 }
 
 pub fn run_block_inspection<DB: LibmdbxReader>(
     orchestra: &[&dyn Inspector<Result = Vec<Bundle>>],
+    filters: &MevFilters,
     data: MultiBlockData,
     db: &'static DB,
 ) -> ComposerResults {
@@ -82,12 +91,31 @@ pub fn run_block_inspection<DB: LibmdbxReader>(
 
     let quote_token = orchestra[0].get_quote_token();
 
-    let (block_details, mev_details) =
-        on_orchestra_resolution(tree, possible_mev_txes, metadata, classified_mev, quote_token, db);
+    let (block_details, mev_details, dedup_decisions) = on_orchestra_resolution(
+        tree,
+        possible_mev_txes,
+        metadata,
+        classified_mev,
+        quote_token,
+        filters,
+        db,
+    );
+
+    if let Some(metrics) = orchestra[0].get_metrics() {
+        dedup_decisions.iter().for_each(|decision| {
+            metrics.dedup_dropped(decision.rule, decision.dominant_type, decision.removed_type)
+        });
+    }
 
     let block_analysis = BlockAnalysis::new(&block_details, &mev_details);
 
-    ComposerResults { block_details, mev_details, possible_mev_txes: possible_arbs, block_analysis }
+    ComposerResults {
+        block_details,
+        mev_details,
+        possible_mev_txes: possible_arbs,
+        block_analysis,
+        dedup_decisions,
+    }
 }
 
 fn run_inspectors(
@@ -111,7 +139,19 @@ fn run_inspectors(
             let span =
                 span!(Level::ERROR, "Inspector", inspector = %inspector.get_id(),block=&metadata.block_num);
 
-            span.in_scope(|| inspector.inspect_block(data))
+            let bundles = span.in_scope(|| inspector.inspect_block(data));
+
+            if let Some(metrics) = inspector.get_metrics() {
+                bundles
+                    .iter()
+                    .counts_by(|bundle| bundle.header.mev_type)
+                    .into_iter()
+                    .for_each(|(mev_type, count)| {
+                        metrics.bundles_found(inspector.get_id(), mev_type, count)
+                    });
+            }
+
+            bundles
         })
         .collect::<Vec<_>>();
 
@@ -140,26 +180,30 @@ fn on_orchestra_resolution<DB: LibmdbxReader>(
     metadata: Arc<Metadata>,
     orchestra_data: Vec<Bundle>,
     quote_token: Address,
+    filters: &MevFilters,
     db: &'static DB,
-) -> (MevBlock, Vec<Bundle>) {
+) -> (MevBlock, Vec<Bundle>, Vec<DedupDecision>) {
     let mut sorted_mev = sort_mev_by_type(orchestra_data);
 
-    MEV_COMPOSABILITY_FILTER
+    filters
+        .compose
         .iter()
         .for_each(|(parent_mev_type, compose_fn, child_mev_type)| {
             try_compose_mev(parent_mev_type, child_mev_type, compose_fn, &mut sorted_mev);
         });
 
-    MEV_DEDUPLICATION_FILTER.iter().for_each(
-        |(dominant_mev_type, extra_filter_fn, subordinate_mev_type)| {
-            deduplicate_mev(
+    let mut dedup_decisions = Vec::new();
+    filters.dedup.iter().enumerate().for_each(
+        |(rule, (dominant_mev_type, extra_filter_fn, subordinate_mev_type))| {
+            dedup_decisions.extend(deduplicate_mev(
                 tree.clone(),
                 db,
+                rule,
                 dominant_mev_type,
                 extra_filter_fn,
                 subordinate_mev_type,
                 &mut sorted_mev,
-            );
+            ));
         },
     );
 
@@ -184,18 +228,21 @@ fn on_orchestra_resolution<DB: LibmdbxReader>(
     // keep order
     filtered_bundles.sort_by(|a, b| a.header.tx_index.cmp(&b.header.tx_index));
 
-    (header, filtered_bundles)
+    (header, filtered_bundles, dedup_decisions)
 }
 
+/// Drops the subordinate bundles that overlap with a bundle of the dominant
+/// type, returning a decision for each dropped bundle
 fn deduplicate_mev<DB: LibmdbxReader>(
     tree: Arc<BlockTree<Action>>,
     db: &'static DB,
+    rule: usize,
     dominant_mev_type: &MevType,
     extra_filter_function: &FilterFn,
     subordinate_mev_types: &[MevType],
     sorted_mev: &mut FastHashMap<MevType, Vec<Bundle>>,
-) {
-    let Some(dominant_mev_list) = sorted_mev.get(dominant_mev_type) else { return };
+) -> Vec<DedupDecision> {
+    let Some(dominant_mev_list) = sorted_mev.get(dominant_mev_type) else { return vec![] };
 
     let mut indexes = Vec::new();
 
@@ -215,19 +262,32 @@ fn deduplicate_mev<DB: LibmdbxReader>(
                     extra_filter_function,
                     &hashes,
                 )
-                .zip(vec![sub_mev_type].into_iter().cycle()),
+                .map(|index| (index, sub_mev_type, dominate_mev.header.tx_hash)),
             )
         }
     }
 
     indexes
         .into_iter()
-        .unique()
+        .unique_by(|(index, mev_type, _)| (*index, *mev_type))
         .sorted_unstable_by(|a, b| b.0.cmp(&a.0))
-        .for_each(|(index, mev_type)| {
-            let Some(mev_list) = sorted_mev.get_mut(&mev_type) else { return };
-            mev_list.remove(index);
-        });
+        .filter_map(|(index, mev_type, dominant_tx_hash)| {
+            let mev_list = sorted_mev.get_mut(&mev_type)?;
+            let removed = mev_list.remove(index);
+
+            let decision = DedupDecision {
+                block_number: tree.header.number,
+                rule,
+                dominant_type: *dominant_mev_type,
+                dominant_tx_hash,
+                removed_type: mev_type,
+                removed_tx_hash: removed.header.tx_hash,
+            };
+            debug!(target: "brontes::composer::dedup", ?decision, "dropped bundle");
+
+            Some(decision)
+        })
+        .collect()
 }
 
 /// Attempts to compose a new complex MEV occurrence from a list of
@@ -191,6 +191,9 @@ fn update_mev_count(mev_count: &mut MevCount, mev_type: MevType, count: u64) {
         MevType::AtomicArb => mev_count.atomic_backrun_count = Some(count),
         MevType::Liquidation => mev_count.liquidation_count = Some(count),
         MevType::SearcherTx => mev_count.searcher_tx_count = Some(count),
+        MevType::LongTail => mev_count.long_tail_count = Some(count),
+        MevType::Nft => mev_count.nft_count = Some(count),
+        MevType::CrossDomain => mev_count.cross_domain_count = Some(count),
         MevType::Unknown => (),
     }
 }
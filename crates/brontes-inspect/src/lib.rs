@@ -30,10 +30,12 @@
 //!
 //! - [`atomic_backrun`](atomic_backrun/index.html)
 //! - [`cex_dex`](cex_dex/index.html)
+//! - [`cross_domain`](cross_domain/index.html)
 //! - [`jit`](jit/index.html)
 //! - [`sandwich`](sandwich/index.html)
 //! - [`liquidations`](liquidations/index.html)
 //! - [`long_tail`](long_tail/index.html)
+//! - [`nft`](nft/index.html)
 //!
 //! Each inspector implements the `Inspector` trait and provides its own
 //! implementation of the `inspect_block` method.
@@ -72,6 +74,7 @@ use atomic_arb::AtomicArbInspector;
 use brontes_types::{
     db::{
         cex::{trades::CexDexTradeConfig, CexExchange},
+        l2_swaps::L2SwapDataset,
         metadata::Metadata,
         traits::LibmdbxReader,
     },
@@ -81,8 +84,11 @@ use brontes_types::{
     MultiBlockData,
 };
 use cex_dex::{markout::CexDexMarkoutInspector, quotes::CexDexQuotesInspector};
+use cross_domain::CrossDomainInspector;
 use jit::JitCexDex;
 use liquidations::LiquidationInspector;
+use long_tail::LongTailInspector;
+use nft::NftInspector;
 use sandwich::SandwichInspector;
 
 use crate::jit::jit_liquidity::JitInspector;
@@ -98,6 +104,8 @@ pub trait Inspector: Send + Sync {
     fn get_id(&self) -> &str;
     fn inspect_block(&self, data: MultiBlockData) -> Self::Result;
     fn get_quote_token(&self) -> Address;
+    /// The metrics the inspector reports to, if enabled
+    fn get_metrics(&self) -> Option<&OutlierMetrics>;
 }
 
 #[derive(
@@ -112,6 +120,9 @@ pub enum Inspectors {
     SearcherActivity,
     CexDexMarkout,
     JitCexDex,
+    LongTail,
+    Nft,
+    CrossDomain,
 }
 
 type DynMevInspector = &'static (dyn Inspector<Result = Vec<Bundle>> + 'static);
@@ -123,6 +134,8 @@ impl Inspectors {
         db: &'static DB,
         cex_exchanges: &[CexExchange],
         trade_config: CexDexTradeConfig,
+        sandwich_block_window: usize,
+        l2_swaps: Option<&'static L2SwapDataset>,
         metrics: Option<OutlierMetrics>,
     ) -> DynMevInspector {
         match &self {
@@ -138,11 +151,15 @@ impl Inspectors {
                 db,
                 cex_exchanges,
                 trade_config.quote_offset_from_block_us,
+                trade_config.cex_fee_schedule,
+                metrics,
+            )) as DynMevInspector,
+            Self::Sandwich => static_object(SandwichInspector::new(
+                quote_token,
+                db,
+                sandwich_block_window,
                 metrics,
             )) as DynMevInspector,
-            Self::Sandwich => {
-                static_object(SandwichInspector::new(quote_token, db, metrics)) as DynMevInspector
-            }
             Self::Liquidations => {
                 static_object(LiquidationInspector::new(quote_token, db, metrics))
                     as DynMevInspector
@@ -167,6 +184,16 @@ impl Inspectors {
                 ),
                 jit:     JitInspector::new(quote_token, db, metrics),
             }) as DynMevInspector,
+            Self::LongTail => {
+                static_object(LongTailInspector::new(quote_token, db, metrics)) as DynMevInspector
+            }
+            Self::Nft => {
+                static_object(NftInspector::new(quote_token, db, metrics)) as DynMevInspector
+            }
+            Self::CrossDomain => {
+                static_object(CrossDomainInspector::new(quote_token, db, l2_swaps, metrics))
+                    as DynMevInspector
+            }
         }
     }
 }
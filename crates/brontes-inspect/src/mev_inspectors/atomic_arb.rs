@@ -50,6 +50,10 @@ impl<DB: LibmdbxReader> Inspector for AtomicArbInspector<'_, DB> {
         self.utils.quote
     }
 
+    fn get_metrics(&self) -> Option<&OutlierMetrics> {
+        self.utils.get_metrics()
+    }
+
     fn inspect_block(&self, data: MultiBlockData) -> Self::Result {
         let BlockData { metadata, tree } = data.get_most_recent_block();
 
@@ -112,6 +116,9 @@ impl<DB: LibmdbxReader> AtomicArbInspector<'_, DB> {
         data: (Vec<NormalizedSwap>, Vec<NormalizedTransfer>, Vec<NormalizedEthTransfer>),
     ) -> Option<Bundle> {
         tracing::trace!(?info, "trying atomic");
+        self.utils
+            .get_metrics()
+            .inspect(|m| m.inspector_candidate(MevType::AtomicArb));
         let (mut swaps, transfers, eth_transfers) = data;
         let mev_addresses: FastHashSet<Address> = info.collect_address_set_for_accounting();
 
@@ -123,7 +130,12 @@ impl<DB: LibmdbxReader> AtomicArbInspector<'_, DB> {
 
         swaps.extend(self.utils.try_create_swaps(&transfers, ignore_addresses));
 
-        let possible_arb_type = self.is_possible_arb(&swaps)?;
+        let Some(possible_arb_type) = self.is_possible_arb(&swaps) else {
+            self.utils
+                .get_metrics()
+                .inspect(|m| m.branch_filtering_trigger(MevType::AtomicArb, "is_possible_arb"));
+            return None
+        };
 
         let account_deltas = transfers
             .into_iter()
@@ -16,7 +16,7 @@ use brontes_types::{
         CexExchange,
     },
     display::utils::format_etherscan_url,
-    mev::{Bundle, BundleData, MevType, OptimisticTrade},
+    mev::{Bundle, BundleData, MevType, OptimisticTrade, PerpHedgePnl},
     normalized_actions::{
         accounting::{ActionAccounting, AddressDeltas},
         Action, NormalizedBatch, NormalizedSwap,
@@ -81,6 +81,10 @@ impl<DB: LibmdbxReader> Inspector for CexDexMarkoutInspector<'_, DB> {
         self.utils.quote
     }
 
+    fn get_metrics(&self) -> Option<&OutlierMetrics> {
+        self.utils.get_metrics()
+    }
+
     fn inspect_block(&self, data: MultiBlockData) -> Self::Result {
         let block = data.get_most_recent_block();
         let BlockData { metadata, tree } = block;
@@ -307,10 +311,18 @@ impl<DB: LibmdbxReader> CexDexMarkoutInspector<'_, DB> {
 
         let per_exchange_pnl = self.process_per_exchange(&cex_prices, metadata, tx_info);
 
+        let perp_hedge_pnl = self.process_perp_hedges(&merged_swaps, metadata, tx_info);
+
         let optimstic_res: Option<OptimisticDetails> =
             self.process_optimistic(cex_prices, metadata, tx_info);
 
-        CexDexProcessing::new(merged_swaps, global_vwam, per_exchange_pnl, optimstic_res)
+        CexDexProcessing::new(
+            merged_swaps,
+            global_vwam,
+            per_exchange_pnl,
+            perp_hedge_pnl,
+            optimstic_res,
+        )
     }
 
     fn process_global_vwam(
@@ -374,6 +386,42 @@ impl<DB: LibmdbxReader> CexDexMarkoutInspector<'_, DB> {
             .collect()
     }
 
+    /// The pnl of hedging all swaps on the perps of each exchange at the mark
+    /// price, paying the funding over the holding horizon
+    fn process_perp_hedges(
+        &self,
+        dex_swaps: &[NormalizedSwap],
+        metadata: &Metadata,
+        tx_info: &TxInfo,
+    ) -> Vec<PerpHedgePnl> {
+        let Some(perp_tickers) = metadata.perp_tickers.as_ref() else { return vec![] };
+        let block_timestamp = metadata.microseconds_block_timestamp();
+        let holding_horizon = self.trade_config.perp_holding_horizon_us;
+
+        self.cex_exchanges
+            .iter()
+            .filter_map(|exchange| {
+                let pnl = PerpHedgePnl { exchange: *exchange, ..Default::default() };
+
+                dex_swaps.iter().try_fold(pnl, |mut pnl, swap| {
+                    let hedge = perp_tickers.perp_hedge(
+                        *exchange,
+                        swap,
+                        block_timestamp,
+                        holding_horizon,
+                        self.trade_config.cex_fee_schedule,
+                    )?;
+                    let base_to_quote = self.token_in_price(swap, metadata, tx_info)?.reciprocal();
+
+                    pnl.funding_cost += &hedge.funding_cost * &base_to_quote;
+                    pnl.pnl_maker += (hedge.amount_maker - &swap.amount_in) * &base_to_quote;
+                    pnl.pnl_taker += (hedge.amount_taker - &swap.amount_in) * &base_to_quote;
+                    Some(pnl)
+                })
+            })
+            .collect()
+    }
+
     //TODO: Remove horendous clones, just getting ouput for debugging purposes
     // right now
     pub fn process_optimistic(
@@ -436,34 +484,10 @@ impl<DB: LibmdbxReader> CexDexMarkoutInspector<'_, DB> {
         let maker_token_delta = &output_of_cex_trade_maker - &swap.amount_in;
         let taker_token_delta = &output_of_cex_trade_taker - &swap.amount_in;
 
-        let vol = Rational::ONE;
-
-        let pair = Pair(swap.token_in.address, self.utils.quote);
-
-        let token_price = metadata
-            .cex_trades
-            .as_ref()
-            .unwrap()
-            .calculate_time_window_vwam(
-                self.trade_config,
-                &self.cex_exchanges,
-                pair,
-                &vol,
-                metadata.microseconds_block_timestamp(),
-                true,
-                swap,
-                tx_info.tx_hash,
-            )?
-            .global
-            .price_maker;
+        let token_price = self.token_in_price(swap, metadata, tx_info)?;
 
         // Amount * base_to_quote = USDT amount
-        let base_to_quote = if token_price == Rational::ZERO {
-            trace!("Token price is zero");
-            return None
-        } else {
-            token_price.clone().reciprocal()
-        };
+        let base_to_quote = token_price.clone().reciprocal();
 
         if cex_quote.price_maker == Rational::ZERO || swap.amount_out == Rational::ZERO {
             return None
@@ -511,6 +535,42 @@ impl<DB: LibmdbxReader> CexDexMarkoutInspector<'_, DB> {
         })
     }
 
+    /// The cex price of the quote asset in the token in of the swap
+    fn token_in_price(
+        &self,
+        swap: &NormalizedSwap,
+        metadata: &Metadata,
+        tx_info: &TxInfo,
+    ) -> Option<Rational> {
+        let vol = Rational::ONE;
+
+        let pair = Pair(swap.token_in.address, self.utils.quote);
+
+        let token_price = metadata
+            .cex_trades
+            .as_ref()
+            .unwrap()
+            .calculate_time_window_vwam(
+                self.trade_config,
+                &self.cex_exchanges,
+                pair,
+                &vol,
+                metadata.microseconds_block_timestamp(),
+                true,
+                swap,
+                tx_info.tx_hash,
+            )?
+            .global
+            .price_maker;
+
+        if token_price == Rational::ZERO {
+            trace!("Token price is zero");
+            return None
+        }
+
+        Some(token_price)
+    }
+
     fn cex_prices_for_swaps(
         &self,
         dex_swaps: Vec<NormalizedSwap>,
@@ -681,7 +741,7 @@ impl<DB: LibmdbxReader> CexDexMarkoutInspector<'_, DB> {
             || is_profitable_one_exchange_but_not_stable_swaps
             || is_outlier_but_not_stable_swaps
         {
-            possible_cex_dex.into_bundle(info, metadata)
+            possible_cex_dex.into_bundle(info, metadata, self.trade_config.cex_fee_schedule)
         } else {
             self.utils.get_metrics().inspect(|m| {
                 m.branch_filtering_trigger(MevType::CexDexTrades, "filter_possible_cex_dex")
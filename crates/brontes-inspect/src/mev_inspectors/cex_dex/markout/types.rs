@@ -7,16 +7,21 @@ use brontes_types::{
             optimistic::OptimisticPrice,
             time_window_vwam::{ExchangePath, WindowExchangePrice},
         },
-        CexExchange,
+        CexExchange, CexFeeSchedule, InstrumentType,
+    },
+    mev::{
+        ArbDetails, BundleData, CexDex, CexMethodology, FeeTierPnl, OptimisticTrade, PerpHedgePnl,
     },
-    mev::{ArbDetails, BundleData, CexDex, CexMethodology, OptimisticTrade},
     normalized_actions::NormalizedSwap,
     pair::Pair,
     ToFloatNearest, TxInfo,
 };
 use colored::Colorize;
 use itertools::Itertools;
-use malachite::{num::basic::traits::Zero, Rational};
+use malachite::{
+    num::basic::traits::{One, Zero},
+    Rational,
+};
 use reth_primitives::Address;
 use strum::Display;
 use tracing::warn;
@@ -113,6 +118,7 @@ pub struct CexDexProcessing {
     pub dex_swaps:           Vec<NormalizedSwap>,
     pub global_vmam_cex_dex: Option<PossibleCexDex>,
     pub per_exchange_pnl:    Vec<Option<PossibleCexDex>>,
+    pub perp_hedge_pnl:      Vec<PerpHedgePnl>,
     pub max_profit:          Option<PossibleCexDex>,
     pub optimistic_details:  Option<OptimisticDetails>,
 }
@@ -122,10 +128,12 @@ impl CexDexProcessing {
         dex_swaps: Vec<NormalizedSwap>,
         global_vmam_cex_dex: Option<PossibleCexDex>,
         per_exchange_pnl: Vec<Option<PossibleCexDex>>,
+        perp_hedge_pnl: Vec<PerpHedgePnl>,
         optimistic_details: Option<OptimisticDetails>,
     ) -> Option<Self> {
         let mut this = Self {
             per_exchange_pnl,
+            perp_hedge_pnl,
             dex_swaps,
             max_profit: None,
             global_vmam_cex_dex,
@@ -189,13 +197,21 @@ impl CexDexProcessing {
         if let Some(arb) = self.global_vmam_cex_dex.as_mut() {
             arb.adjust_for_gas_cost(gas_cost)
         }
+
+        self.perp_hedge_pnl.iter_mut().for_each(|hedge| {
+            hedge.pnl_maker -= gas_cost;
+            hedge.pnl_taker -= gas_cost;
+        });
     }
 
     pub fn into_bundle(
         self,
         tx_info: &TxInfo,
         meta: Arc<Metadata>,
+        fee_schedule: &CexFeeSchedule,
     ) -> Option<(f64, BundleData, Vec<ExchangeLegCexPrice>)> {
+        let per_exchange_tier_pnl = self.per_exchange_tier_pnl(fee_schedule);
+
         let optimistic = self
             .optimistic_details
             .as_ref()
@@ -318,6 +334,10 @@ impl CexDexProcessing {
                     .filter_map(|p| p.as_ref().map(|p| p.generate_arb_details(&self.dex_swaps)))
                     .collect(),
 
+                per_exchange_tier_pnl,
+
+                perp_hedge_pnl: self.perp_hedge_pnl,
+
                 gas_details: tx_info.gas_details,
                 swaps: self.dex_swaps,
             }),
@@ -334,6 +354,25 @@ impl CexDexProcessing {
         ))
     }
 
+    /// The pnl of each exchange route under each reported tier of the cex fee
+    /// schedule
+    fn per_exchange_tier_pnl(&self, fee_schedule: &CexFeeSchedule) -> Vec<FeeTierPnl> {
+        let tiers = fee_schedule.report_tiers();
+
+        self.per_exchange_pnl
+            .iter()
+            .flatten()
+            .filter_map(|p| Some((p, p.arb_legs.first()?.as_ref()?.exchange)))
+            .flat_map(|(p, exchange)| {
+                tiers.iter().filter_map(move |tier| {
+                    let (pnl_maker, pnl_taker) =
+                        p.aggregate_pnl_under_tier(&self.dex_swaps, fee_schedule, tier)?;
+                    Some(FeeTierPnl { tier: tier.clone(), exchange, pnl_maker, pnl_taker })
+                })
+            })
+            .collect()
+    }
+
     pub fn arb_sanity_check(&self) -> ArbSanityCheck {
         let (profitable_exchanges_maker, profitable_exchanges_taker) = self
             .per_exchange_pnl
@@ -465,6 +504,35 @@ impl PossibleCexDex {
         self.aggregate_pnl_taker -= gas_cost;
     }
 
+    /// The aggregate maker & taker pnl with the cex fees of `tier`. The leg
+    /// prices are fee adjusted with the header tier, so each leg is repriced
+    /// with the fee difference of its hops
+    pub fn aggregate_pnl_under_tier(
+        &self,
+        dex_swaps: &[NormalizedSwap],
+        fee_schedule: &CexFeeSchedule,
+        tier: &str,
+    ) -> Option<(Rational, Rational)> {
+        let mut pnl = (self.aggregate_pnl_maker.clone(), self.aggregate_pnl_taker.clone());
+
+        for (leg, swap) in self.arb_legs.iter().zip(dex_swaps) {
+            let Some(leg) = leg else { continue };
+            let (maker, taker) = fee_schedule.tier_adjustment(
+                leg.exchange,
+                tier,
+                InstrumentType::Spot,
+                &leg.pairs,
+            )?;
+
+            // usd value of the cex trade output per unit of price
+            let usd_per_price = &swap.amount_out * &leg.token_price.price0;
+            pnl.0 += &leg.price.price_maker * &usd_per_price * (maker - Rational::ONE);
+            pnl.1 += &leg.price.price_taker * &usd_per_price * (taker - Rational::ONE);
+        }
+
+        Some(pnl)
+    }
+
     pub fn generate_arb_details(&self, normalized_swaps: &[NormalizedSwap]) -> Vec<ArbDetails> {
         self.arb_legs
             .iter()
@@ -773,3 +841,53 @@ pub enum PriceCalcType {
     TimeWindowGlobal,
     TimeWindowPerEx,
 }
+
+#[cfg(test)]
+mod tests {
+    use brontes_types::constants::{USDT_ADDRESS, WETH_ADDRESS};
+    use malachite::num::{basic::traits::Two, conversion::traits::FromSciString};
+
+    use super::*;
+
+    #[test]
+    fn test_aggregate_pnl_under_tier() {
+        // the cex trade of the dex swap output returns 2 tokens for the 1 token
+        // swapped in, each worth 1 usd
+        let swap = NormalizedSwap {
+            amount_in: Rational::ONE,
+            amount_out: Rational::ONE,
+            ..Default::default()
+        };
+        let leg = ArbLeg {
+            price:       ExchangePath {
+                price_maker: Rational::TWO,
+                price_taker: Rational::TWO,
+                ..Default::default()
+            },
+            exchange:    CexExchange::Binance,
+            pnl_maker:   Rational::ONE,
+            pnl_taker:   Rational::ONE,
+            pairs:       vec![Pair(WETH_ADDRESS, USDT_ADDRESS)],
+            token_price: ExchangeLegCexPrice { price0: Rational::ONE, ..Default::default() },
+        };
+        let possible = PossibleCexDex::from_arb_legs(vec![Some(leg), None]).unwrap();
+        let swaps = [swap.clone(), swap];
+        let fees = CexFeeSchedule::default();
+
+        assert_eq!(
+            possible.aggregate_pnl_under_tier(&swaps, &fees, "best"),
+            Some((Rational::ONE, Rational::ONE))
+        );
+
+        let fee = |value: &str| Rational::from_sci_string(value).unwrap();
+        let retail = |best: &str| {
+            Rational::TWO * (Rational::ONE - fee("0.001")) / (Rational::ONE - fee(best))
+                - Rational::ONE
+        };
+        assert_eq!(
+            possible.aggregate_pnl_under_tier(&swaps, &fees, "retail"),
+            Some((retail("0.00012"), retail("0.00024")))
+        );
+        assert_eq!(possible.aggregate_pnl_under_tier(&swaps, &fees, "vip 3"), None);
+    }
+}
@@ -50,7 +50,7 @@ use alloy_primitives::Address;
 use brontes_database::libmdbx::LibmdbxReader;
 use brontes_metrics::inspectors::OutlierMetrics;
 use brontes_types::{
-    db::cex::{quotes::FeeAdjustedQuote, CexExchange},
+    db::cex::{quotes::FeeAdjustedQuote, CexExchange, CexFeeSchedule},
     display::utils::format_etherscan_url,
     mev::{Bundle, BundleData, MevType},
     normalized_actions::{accounting::ActionAccounting, Action, NormalizedSwap},
@@ -78,6 +78,7 @@ pub struct CexDexQuotesInspector<'db, DB: LibmdbxReader> {
     utils:                SharedInspectorUtils<'db, DB>,
     _quotes_fetch_offset: u64,
     _cex_exchanges:       Vec<CexExchange>,
+    cex_fee_schedule:     &'db CexFeeSchedule,
 }
 
 impl<'db, DB: LibmdbxReader> CexDexQuotesInspector<'db, DB> {
@@ -89,17 +90,20 @@ impl<'db, DB: LibmdbxReader> CexDexQuotesInspector<'db, DB> {
     /// * `db` - Database reader to our local libmdbx database
     /// * `cex_exchanges` - List of centralized exchanges to consider for
     ///   arbitrage.
+    /// * `fee_schedule` - The fees the cex quotes are adjusted with
     pub fn new(
         quote: Address,
         db: &'db DB,
         cex_exchanges: &[CexExchange],
         quotes_fetch_offset: u64,
+        fee_schedule: &'db CexFeeSchedule,
         metrics: Option<OutlierMetrics>,
     ) -> Self {
         Self {
             utils:                SharedInspectorUtils::new(quote, db, metrics),
             _quotes_fetch_offset: quotes_fetch_offset,
             _cex_exchanges:       cex_exchanges.to_owned(),
+            cex_fee_schedule:     fee_schedule,
         }
     }
 }
@@ -115,6 +119,10 @@ impl<DB: LibmdbxReader> Inspector for CexDexQuotesInspector<'_, DB> {
         self.utils.quote
     }
 
+    fn get_metrics(&self) -> Option<&OutlierMetrics> {
+        self.utils.get_metrics()
+    }
+
     fn inspect_block(&self, data: MultiBlockData) -> Self::Result {
         let block = data.get_most_recent_block();
         let BlockData { metadata, tree } = block;
@@ -335,6 +343,7 @@ impl<DB: LibmdbxReader> CexDexQuotesInspector<'_, DB> {
                 &Pair(swap.token_in.address, self.utils.quote),
                 metadata.microseconds_block_timestamp(),
                 None,
+                self.cex_fee_schedule,
             )?
             .maker_taker_mid()
             .0;
@@ -384,6 +393,7 @@ impl<DB: LibmdbxReader> CexDexQuotesInspector<'_, DB> {
                 pnl:           pnl_mid.to_float(),
                 cex_mid_price: maker_taker_mid.0.to_float(),
                 exchange:      cex_quote.exchange,
+                pairs:         cex_quote.pairs,
             },
             pairs_price,
         ))
@@ -409,6 +419,7 @@ impl<DB: LibmdbxReader> CexDexQuotesInspector<'_, DB> {
                         &pair,
                         metadata.microseconds_block_timestamp() + (time_delta * 1_000_000),
                         max_time_diff,
+                        self.cex_fee_schedule,
                     )
                     .or_else(|| {
                         debug!(
@@ -505,7 +516,16 @@ impl<DB: LibmdbxReader> CexDexQuotesInspector<'_, DB> {
                 })
                 .collect_vec();
 
-            possible_cex_dex.into_bundle(info, metadata.block_timestamp, t2, t12, t30, t60, t300)
+            possible_cex_dex.into_bundle(
+                info,
+                metadata.block_timestamp,
+                t2,
+                t12,
+                t30,
+                t60,
+                t300,
+                self.cex_fee_schedule,
+            )
         } else {
             None
         }
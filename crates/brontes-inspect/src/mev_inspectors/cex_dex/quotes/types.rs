@@ -1,10 +1,14 @@
 use brontes_types::{
-    db::cex::CexExchange,
-    mev::{BundleData, CexDexQuote},
+    db::cex::{CexExchange, CexFeeSchedule, InstrumentType},
+    mev::{BundleData, CexDexQuote, QuoteFeeTierPnl},
     normalized_actions::NormalizedSwap,
+    pair::Pair,
     ToFloatNearest, TxInfo,
 };
-use malachite::{num::basic::traits::Zero, Rational};
+use malachite::{
+    num::basic::traits::{One, Zero},
+    Rational,
+};
 use reth_primitives::Address;
 use tracing::warn;
 
@@ -38,6 +42,34 @@ impl PossibleCexDex {
     pub fn adjust_for_gas_cost(&mut self, gas_cost: Rational) {
         self.aggregate_pnl -= gas_cost.to_float();
     }
+
+    /// The aggregate pnl with the cex fees of `tier`. The mid prices are fee
+    /// adjusted with the header tier, so each leg is repriced with the fee
+    /// difference of its hops
+    pub fn aggregate_pnl_under_tier(
+        &self,
+        dex_swaps: &[NormalizedSwap],
+        fee_schedule: &CexFeeSchedule,
+        tier: &str,
+    ) -> Option<f64> {
+        let mut pnl = self.aggregate_pnl;
+
+        for ((leg, price), swap) in self.arb_legs.iter().zip(&self.trade_prices).zip(dex_swaps) {
+            let Some(leg) = leg else { continue };
+            let (maker, _) = fee_schedule.tier_adjustment(
+                leg.exchange,
+                tier,
+                InstrumentType::Spot,
+                &leg.pairs,
+            )?;
+
+            // usd value of the cex trade output per unit of price
+            let usd_per_price = (&swap.amount_out * &price.price0).to_float();
+            pnl += leg.cex_mid_price * usd_per_price * (maker - Rational::ONE).to_float();
+        }
+
+        Some(pnl)
+    }
 }
 
 #[derive(Debug)]
@@ -56,7 +88,21 @@ impl CexDexProcessing {
         t30_mid_price: Vec<f64>,
         t60_mid_price: Vec<f64>,
         t300_mid_price: Vec<f64>,
+        fee_schedule: &CexFeeSchedule,
     ) -> Option<(f64, BundleData)> {
+        let tier_pnl = fee_schedule
+            .report_tiers()
+            .iter()
+            .filter_map(|tier| {
+                Some(QuoteFeeTierPnl {
+                    tier: tier.clone(),
+                    pnl:  self
+                        .pnl
+                        .aggregate_pnl_under_tier(&self.dex_swaps, fee_schedule, tier)?,
+                })
+            })
+            .collect();
+
         Some((
             self.pnl.aggregate_pnl,
             BundleData::CexDexQuote(CexDexQuote {
@@ -75,6 +121,7 @@ impl CexDexProcessing {
                 t60_mid_price,
                 t300_mid_price,
                 pnl: self.pnl.aggregate_pnl,
+                tier_pnl,
                 exchange: self.pnl.arb_legs[0].as_ref()?.exchange,
                 gas_details: tx_info.gas_details,
                 swaps: self.dex_swaps,
@@ -88,6 +135,7 @@ pub struct ExchangeLeg {
     pub cex_mid_price: f64,
     pub pnl:           f64,
     pub exchange:      CexExchange,
+    pub pairs:         Vec<Pair>,
 }
 
 pub fn log_cex_dex_quote_delta(
@@ -0,0 +1,359 @@
+//! Detects cross domain arbitrage between mainnet and the L2s.
+//!
+//! A cross domain arbitrage is a swap on mainnet paired with the opposite swap
+//! on an L2, with the inventory moved between the chains through a canonical
+//! bridge or an intent such as an Across deposit. As only mainnet is traced,
+//! the L2 swaps come from a user supplied dataset the inspector is built with.
+//! Without one the inspector finds nothing.
+//!
+//! A mainnet transaction with a bridge deposit or fill and a swap is matched
+//! with the swaps of the searcher on the chain it bridges to or from, that
+//! happened at most [`MATCH_WINDOW_SECS`] away from the mainnet block and that
+//! trade the same pair in the opposite direction. L2 tokens are matched to
+//! mainnet by symbol, with weth and eth considered the same.
+//!
+//! The profit is the net token position over the swaps of both chains, priced
+//! on mainnet, minus the mainnet gas. Bridge fees and L2 gas aren't accounted
+//! for.
+use std::sync::Arc;
+
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_metrics::inspectors::OutlierMetrics;
+use brontes_types::{
+    db::{
+        dex::PriceAt,
+        l2_swaps::{L2Swap, L2SwapDataset},
+    },
+    mev::{Bundle, BundleData, CrossDomainArb, MevType},
+    normalized_actions::{
+        accounting::ActionAccounting, Action, NormalizedBridgeDeposit, NormalizedBridgeFill,
+        NormalizedSwap,
+    },
+    ActionIter, BlockData, FastHashMap, FastHashSet, MultiBlockData, ToFloatNearest,
+    TreeSearchBuilder, TxInfo,
+};
+use itertools::Itertools;
+use malachite::{num::basic::traits::Zero, Rational};
+use reth_primitives::Address;
+
+use crate::{shared_utils::SharedInspectorUtils, Inspector, Metadata};
+
+/// Maximum time between the mainnet block and an L2 swap for them to be
+/// matched
+pub const MATCH_WINDOW_SECS: u64 = 300;
+
+pub struct CrossDomainInspector<'db, DB: LibmdbxReader> {
+    utils:    SharedInspectorUtils<'db, DB>,
+    /// The L2 swaps mainnet transactions are matched against
+    l2_swaps: Option<&'db L2SwapDataset>,
+}
+
+impl<'db, DB: LibmdbxReader> CrossDomainInspector<'db, DB> {
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        l2_swaps: Option<&'db L2SwapDataset>,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, metrics), l2_swaps }
+    }
+}
+
+impl<DB: LibmdbxReader> Inspector for CrossDomainInspector<'_, DB> {
+    type Result = Vec<Bundle>;
+
+    fn get_id(&self) -> &str {
+        "CrossDomain"
+    }
+
+    fn get_quote_token(&self) -> Address {
+        self.utils.quote
+    }
+
+    fn get_metrics(&self) -> Option<&OutlierMetrics> {
+        self.utils.get_metrics()
+    }
+
+    fn inspect_block(&self, data: MultiBlockData) -> Self::Result {
+        let Some(dataset) = self.l2_swaps else { return vec![] };
+        let BlockData { metadata, tree } = data.get_most_recent_block();
+
+        let execution = || {
+            let (hashes, actions): (Vec<_>, Vec<_>) = tree
+                .clone()
+                .collect_all(TreeSearchBuilder::default().with_actions([
+                    Action::is_bridge,
+                    Action::is_swap,
+                    Action::is_transfer,
+                    Action::is_eth_transfer,
+                ]))
+                .unzip();
+            let tx_info = tree.get_tx_info_batch(&hashes, self.utils.db);
+
+            actions
+                .into_iter()
+                .zip(tx_info)
+                .filter_map(|(actions, info)| {
+                    self.process_tx(dataset, info?, actions, metadata.clone())
+                })
+                .collect()
+        };
+
+        self.utils
+            .get_metrics()
+            .map(|m| m.run_inspector(MevType::CrossDomain, execution))
+            .unwrap_or_else(execution)
+    }
+}
+
+impl<DB: LibmdbxReader> CrossDomainInspector<'_, DB> {
+    fn process_tx(
+        &self,
+        dataset: &L2SwapDataset,
+        info: TxInfo,
+        actions: Vec<Action>,
+        metadata: Arc<Metadata>,
+    ) -> Option<Bundle> {
+        let deposits = actions
+            .clone()
+            .into_iter()
+            .collect_action_vec(Action::try_bridge_deposit);
+        let fills = actions
+            .clone()
+            .into_iter()
+            .collect_action_vec(Action::try_bridge_fill);
+        let mainnet_swaps = actions
+            .clone()
+            .into_iter()
+            .collect_action_vec(Action::try_swaps_merged);
+
+        if mainnet_swaps.is_empty() || (deposits.is_empty() && fills.is_empty()) {
+            return None
+        }
+
+        let l2_swaps = matching_l2_swaps(
+            dataset,
+            &info,
+            &deposits,
+            &fills,
+            &mainnet_swaps,
+            metadata.block_timestamp,
+        );
+        if l2_swaps.is_empty() {
+            return None
+        }
+
+        let (profit_usd, no_pricing_calculated) =
+            match self.profit_usd(&info, &mainnet_swaps, &l2_swaps, &metadata) {
+                Some(profit) => (profit.to_float(), false),
+                None => (0.0, true),
+            };
+
+        let deltas = actions
+            .into_iter()
+            .chain(info.get_total_eth_value().iter().cloned().map(Action::from))
+            .filter(|a| a.is_eth_transfer() || a.is_transfer())
+            .account_for_actions();
+
+        let header = self.utils.build_bundle_header(
+            vec![deltas],
+            vec![info.tx_hash],
+            &info,
+            profit_usd,
+            &[info.gas_details],
+            metadata.clone(),
+            MevType::CrossDomain,
+            no_pricing_calculated,
+            |this, token, amount| {
+                this.get_token_value_dex(
+                    info.tx_index as usize,
+                    PriceAt::Average,
+                    token,
+                    &amount,
+                    &metadata,
+                )
+            },
+        );
+
+        let arb = CrossDomainArb {
+            tx_hash: info.tx_hash,
+            block_number: metadata.block_num,
+            mainnet_swaps,
+            deposits,
+            fills,
+            l2_swaps,
+            gas_details: info.gas_details,
+        };
+
+        Some(Bundle { header, data: BundleData::CrossDomain(arb) })
+    }
+
+    /// Values the net token position of the swaps on both chains with the
+    /// mainnet price of the tokens, minus the gas paid on mainnet
+    fn profit_usd(
+        &self,
+        info: &TxInfo,
+        mainnet_swaps: &[NormalizedSwap],
+        l2_swaps: &[L2Swap],
+        metadata: &Arc<Metadata>,
+    ) -> Option<Rational> {
+        let mut tokens = FastHashMap::default();
+        for swap in mainnet_swaps {
+            tokens.insert(normalize_symbol(&swap.token_in.symbol), swap.token_in.address);
+            tokens.insert(normalize_symbol(&swap.token_out.symbol), swap.token_out.address);
+        }
+
+        let position = net_position(mainnet_swaps, l2_swaps)?;
+        let mut profit = Rational::ZERO;
+        for (symbol, amount) in position {
+            let token = tokens.get(&symbol)?;
+            profit += self.utils.get_token_value_dex(
+                info.tx_index as usize,
+                PriceAt::Average,
+                *token,
+                &amount,
+                metadata,
+            )?;
+        }
+
+        Some(profit - metadata.get_gas_price_usd(info.gas_details.gas_paid(), self.utils.quote))
+    }
+}
+
+/// The swaps on the chains the transaction bridges to or from, of the
+/// searcher or the recipient of its deposits, that reverse one of the mainnet
+/// swaps
+fn matching_l2_swaps(
+    dataset: &L2SwapDataset,
+    info: &TxInfo,
+    deposits: &[NormalizedBridgeDeposit],
+    fills: &[NormalizedBridgeFill],
+    mainnet_swaps: &[NormalizedSwap],
+    block_timestamp: u64,
+) -> Vec<L2Swap> {
+    let traders = [info.eoa]
+        .into_iter()
+        .chain(info.mev_contract)
+        .chain(deposits.iter().map(|deposit| deposit.recipient))
+        .collect::<FastHashSet<_>>();
+    let chains = deposits
+        .iter()
+        .map(|deposit| deposit.destination_chain_id)
+        .chain(fills.iter().map(|fill| fill.origin_chain_id))
+        .collect::<FastHashSet<_>>();
+
+    chains
+        .into_iter()
+        .cartesian_product(traders)
+        .flat_map(|(chain_id, trader)| {
+            dataset.swaps_around(chain_id, trader, block_timestamp, MATCH_WINDOW_SECS)
+        })
+        .filter(|l2_swap| mainnet_swaps.iter().any(|swap| is_reversed(swap, l2_swap)))
+        .unique_by(|l2_swap| l2_swap.tx_hash)
+        .cloned()
+        .sorted_by_key(|l2_swap| (l2_swap.chain_id, l2_swap.timestamp))
+        .collect()
+}
+
+fn is_reversed(swap: &NormalizedSwap, l2_swap: &L2Swap) -> bool {
+    normalize_symbol(&swap.token_in.symbol) == normalize_symbol(&l2_swap.token_out_symbol)
+        && normalize_symbol(&swap.token_out.symbol) == normalize_symbol(&l2_swap.token_in_symbol)
+}
+
+/// Token amounts gained and lost over the swaps of both chains, by symbol
+fn net_position(
+    mainnet_swaps: &[NormalizedSwap],
+    l2_swaps: &[L2Swap],
+) -> Option<FastHashMap<String, Rational>> {
+    let mut position: FastHashMap<String, Rational> = FastHashMap::default();
+    for swap in mainnet_swaps {
+        *position
+            .entry(normalize_symbol(&swap.token_in.symbol))
+            .or_default() -= &swap.amount_in;
+        *position
+            .entry(normalize_symbol(&swap.token_out.symbol))
+            .or_default() += &swap.amount_out;
+    }
+    for swap in l2_swaps {
+        *position
+            .entry(normalize_symbol(&swap.token_in_symbol))
+            .or_default() -= Rational::try_from_float_simplest(swap.amount_in).ok()?;
+        *position
+            .entry(normalize_symbol(&swap.token_out_symbol))
+            .or_default() += Rational::try_from_float_simplest(swap.amount_out).ok()?;
+    }
+
+    Some(position)
+}
+
+/// Weth and eth are interchangeable across the bridges
+fn normalize_symbol(symbol: &str) -> String {
+    let symbol = symbol.to_uppercase();
+    if symbol == "WETH" {
+        "ETH".to_string()
+    } else {
+        symbol
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brontes_types::db::token_info::{TokenInfo, TokenInfoWithAddress};
+
+    use super::*;
+
+    fn token(symbol: &str) -> TokenInfoWithAddress {
+        TokenInfoWithAddress {
+            address: Address::default(),
+            inner:   TokenInfo { decimals: 18, symbol: symbol.to_string() },
+        }
+    }
+
+    fn mainnet_swap(
+        token_in: &str,
+        amount_in: u64,
+        token_out: &str,
+        amount_out: u64,
+    ) -> NormalizedSwap {
+        NormalizedSwap {
+            token_in: token(token_in),
+            amount_in: Rational::from(amount_in),
+            token_out: token(token_out),
+            amount_out: Rational::from(amount_out),
+            ..Default::default()
+        }
+    }
+
+    fn l2_swap(token_in: &str, amount_in: f64, token_out: &str, amount_out: f64) -> L2Swap {
+        L2Swap {
+            token_in_symbol: token_in.to_string(),
+            amount_in,
+            token_out_symbol: token_out.to_string(),
+            amount_out,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_is_reversed() {
+        let swap = mainnet_swap("USDC", 3_000, "WETH", 1);
+
+        assert!(is_reversed(&swap, &l2_swap("ETH", 1.0, "USDC", 3_010.0)));
+        assert!(is_reversed(&swap, &l2_swap("weth", 1.0, "usdc", 3_010.0)));
+        assert!(!is_reversed(&swap, &l2_swap("USDC", 3_000.0, "ETH", 1.0)));
+        assert!(!is_reversed(&swap, &l2_swap("ETH", 1.0, "DAI", 3_010.0)));
+    }
+
+    #[test]
+    fn test_net_position() {
+        // bought 1 eth for 3000 usdc on mainnet, sold it for 3010 usdc on the L2
+        let position = net_position(
+            &[mainnet_swap("USDC", 3_000, "WETH", 1)],
+            &[l2_swap("ETH", 1.0, "USDC", 3_010.0)],
+        )
+        .unwrap();
+
+        assert_eq!(position["ETH"], Rational::ZERO);
+        assert_eq!(position["USDC"], Rational::from(10));
+    }
+}
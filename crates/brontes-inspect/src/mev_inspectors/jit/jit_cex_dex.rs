@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use alloy_primitives::Address;
+use brontes_metrics::inspectors::OutlierMetrics;
 use brontes_types::{
     db::{metadata::Metadata, token_info::TokenInfoWithAddress, traits::LibmdbxReader},
     display::utils::format_etherscan_url,
@@ -43,6 +44,10 @@ impl<DB: LibmdbxReader> Inspector for JitCexDex<'_, DB> {
         self.jit.utils.quote
     }
 
+    fn get_metrics(&self) -> Option<&OutlierMetrics> {
+        self.jit.utils.get_metrics()
+    }
+
     fn inspect_block(&self, mut data: MultiBlockData) -> Self::Result {
         let block = data.per_block_data.pop().expect("no blocks");
         let BlockData { metadata, tree } = block;
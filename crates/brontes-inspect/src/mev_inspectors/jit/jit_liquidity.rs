@@ -44,6 +44,10 @@ impl<DB: LibmdbxReader> Inspector for JitInspector<'_, DB> {
         self.utils.quote
     }
 
+    fn get_metrics(&self) -> Option<&OutlierMetrics> {
+        self.utils.get_metrics()
+    }
+
     fn inspect_block(&self, data: MultiBlockData) -> Self::Result {
         let BlockData { metadata, tree } = data.get_most_recent_block();
 
@@ -5,7 +5,7 @@ use brontes_metrics::inspectors::OutlierMetrics;
 use brontes_types::{
     db::dex::PriceAt,
     mev::{Bundle, BundleData, Liquidation, MevType},
-    normalized_actions::{accounting::ActionAccounting, Action},
+    normalized_actions::{accounting::ActionAccounting, Action, NormalizedLiquidation},
     ActionIter, BlockData, FastHashSet, MultiBlockData, ToFloatNearest, TreeSearchBuilder, TxInfo,
 };
 use itertools::multizip;
@@ -36,6 +36,10 @@ impl<DB: LibmdbxReader> Inspector for LiquidationInspector<'_, DB> {
         self.utils.quote
     }
 
+    fn get_metrics(&self) -> Option<&OutlierMetrics> {
+        self.utils.get_metrics()
+    }
+
     fn inspect_block(&self, mut data: MultiBlockData) -> Self::Result {
         let block = data.per_block_data.pop().expect("no blocks");
         let BlockData { metadata, tree } = block;
@@ -79,7 +83,7 @@ impl<DB: LibmdbxReader> LiquidationInspector<'_, DB> {
         metadata: Arc<Metadata>,
         actions: Vec<Action>,
     ) -> Option<Bundle> {
-        let (swaps, liqs): (Vec<_>, Vec<_>) = actions
+        let (swaps, mut liqs): (Vec<_>, Vec<_>) = actions
             .clone()
             .into_iter()
             .action_split((Action::try_swaps_merged, Action::try_liquidation));
@@ -89,6 +93,14 @@ impl<DB: LibmdbxReader> LiquidationInspector<'_, DB> {
             return None
         }
 
+        // protocols that price the collateral themselves already set the discount
+        for liq in liqs
+            .iter_mut()
+            .filter(|liq| liq.collateral_discount.is_none())
+        {
+            liq.collateral_discount = self.collateral_discount(info.tx_index, liq, &metadata);
+        }
+
         let mev_addresses: FastHashSet<Address> = info.collect_address_set_for_accounting();
 
         let deltas = actions
@@ -154,6 +166,26 @@ impl<DB: LibmdbxReader> LiquidationInspector<'_, DB> {
 
         Some(Bundle { header, data: BundleData::Liquidation(new_liquidation) })
     }
+
+    /// The discount the liquidator bought the collateral at, relative to the
+    /// dex price of the collateral in the debt asset before the liquidation.
+    /// Used for protocols that don't expose the price they sold at.
+    fn collateral_discount(
+        &self,
+        tx_index: u64,
+        liquidation: &NormalizedLiquidation,
+        metadata: &Arc<Metadata>,
+    ) -> Option<Rational> {
+        let price = |token| {
+            self.utils
+                .get_token_price_on_dex(tx_index as usize, PriceAt::Before, token, metadata)
+                .filter(|price| *price != Rational::ZERO)
+        };
+        let collateral_price =
+            price(liquidation.collateral_asset.address)? / price(liquidation.debt_asset.address)?;
+
+        liquidation.discount(&collateral_price)
+    }
 }
 
 #[cfg(test)]
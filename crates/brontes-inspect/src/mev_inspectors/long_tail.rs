@@ -0,0 +1,776 @@
+//! Detects snipers of freshly created pools.
+//!
+//! A snipe is a buy of a token out of a pool that was created in the same block
+//! or at most [`SNIPE_BLOCK_RANGE`] blocks before. As the profit of a snipe is
+//! only realized once the sniper sells the token again, the bundle is emitted
+//! in the block of the exit, given that the entries are still part of the
+//! inspectors block window. Snipes that haven't been sold by the time their
+//! entries leave the window are emitted with their unrealized pnl instead.
+use std::sync::Arc;
+
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_metrics::inspectors::OutlierMetrics;
+use brontes_types::{
+    constants::{get_stable_type, WETH_ADDRESS},
+    db::{
+        dex::{BlockPrice, PriceAt},
+        token_info::TokenInfoWithAddress,
+    },
+    mev::{Bundle, BundleData, LongTail, MevType},
+    normalized_actions::{accounting::ActionAccounting, Action, NormalizedSwap},
+    ActionIter, BlockData, FastHashMap, FastHashSet, MultiBlockData, ToFloatNearest,
+    TreeSearchBuilder, TxInfo,
+};
+use itertools::Itertools;
+use malachite::{
+    num::basic::traits::{One, Zero},
+    Rational,
+};
+use reth_primitives::Address;
+
+use super::{MAX_PROFIT, MIN_PROFIT};
+use crate::{shared_utils::SharedInspectorUtils, BlockTree, Inspector, Metadata};
+
+/// Amount of blocks after the creation of a pool in which a buy is still
+/// considered a snipe.
+const SNIPE_BLOCK_RANGE: u64 = 3;
+/// Amount of blocks the sniper has to exit the position in for it to be
+/// detected.
+const LONG_TAIL_BLOCK_WINDOW: usize = 10;
+
+pub struct LongTailInspector<'db, DB: LibmdbxReader> {
+    utils: SharedInspectorUtils<'db, DB>,
+}
+
+impl<'db, DB: LibmdbxReader> LongTailInspector<'db, DB> {
+    pub fn new(quote: Address, db: &'db DB, metrics: Option<OutlierMetrics>) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, metrics) }
+    }
+}
+
+/// Buy of the sniped token out of a new pool.
+struct Entry {
+    info:           TxInfo,
+    metadata:       Arc<Metadata>,
+    token:          TokenInfoWithAddress,
+    pool:           Address,
+    creation_block: u64,
+    swaps:          Vec<NormalizedSwap>,
+    actions:        Vec<Action>,
+}
+
+impl Entry {
+    /// Amount of the sniped token bought.
+    fn amount(&self) -> Rational {
+        self.swaps.iter().map(|swap| &swap.amount_out).sum()
+    }
+
+    /// Whether the entry happened before the given transaction.
+    fn is_before(&self, block_number: u64, tx_index: u64) -> bool {
+        (self.metadata.block_num, self.info.tx_index) < (block_number, tx_index)
+    }
+}
+
+/// Sell of a previously sniped token.
+struct Exit {
+    info:    TxInfo,
+    swaps:   Vec<NormalizedSwap>,
+    actions: Vec<Action>,
+}
+
+impl Exit {
+    /// Amount of the sniped token sold.
+    fn amount(&self) -> Rational {
+        self.swaps.iter().map(|swap| &swap.amount_in).sum()
+    }
+}
+
+impl<DB: LibmdbxReader> Inspector for LongTailInspector<'_, DB> {
+    type Result = Vec<Bundle>;
+
+    // the entries of a snipe have to be in the window when the sniper exits
+    fn block_window(&self) -> usize {
+        LONG_TAIL_BLOCK_WINDOW
+    }
+
+    fn get_id(&self) -> &str {
+        "LongTail"
+    }
+
+    fn get_quote_token(&self) -> Address {
+        self.utils.quote
+    }
+
+    fn get_metrics(&self) -> Option<&OutlierMetrics> {
+        self.utils.get_metrics()
+    }
+
+    fn inspect_block(&self, data: MultiBlockData) -> Self::Result {
+        let BlockData { metadata, tree } = data.get_most_recent_block();
+
+        let execution = || {
+            let window_start = data.per_block_data[0].block_number();
+            let new_pools =
+                self.new_pools(window_start.saturating_sub(SNIPE_BLOCK_RANGE), metadata.block_num);
+            if new_pools.is_empty() {
+                return vec![]
+            }
+
+            let mut entries: FastHashMap<(Address, Address), Vec<Entry>> = FastHashMap::default();
+            data.per_block_data
+                .iter()
+                .flat_map(|block| self.collect_entries(block, &new_pools))
+                .for_each(|entry| {
+                    entries
+                        .entry((entry.info.eoa, entry.token.address))
+                        .or_default()
+                        .push(entry)
+                });
+
+            if entries.is_empty() {
+                return vec![]
+            }
+            // the position is sold off oldest entry first
+            entries.values_mut().for_each(|entries| {
+                entries.sort_by_key(|entry| (entry.metadata.block_num, entry.info.tx_index))
+            });
+
+            let exits = self.collect_exits(tree.clone(), &entries);
+            let prior_exits = if exits.is_empty() {
+                FastHashMap::default()
+            } else {
+                self.prior_exits(&data, &entries)
+            };
+
+            let mut bundles = exits
+                .into_iter()
+                .filter_map(|((eoa, token), exits)| {
+                    let first_exit = exits.first()?.info.tx_index;
+                    // only entries that happened before the sniper started selling
+                    let entries = entries
+                        .get(&(eoa, token))?
+                        .iter()
+                        .filter(|entry| entry.is_before(metadata.block_num, first_exit))
+                        .collect_vec();
+                    let remaining = remaining_entry_amounts(
+                        &entries,
+                        prior_exits
+                            .get(&(eoa, token))
+                            .map(Vec::as_slice)
+                            .unwrap_or_default(),
+                    );
+
+                    self.calculate_long_tail(
+                        entries.into_iter().zip(remaining).collect(),
+                        exits,
+                        metadata.clone(),
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            bundles.extend(self.unexited_snipes(&data, &entries, metadata.clone()));
+            bundles
+        };
+
+        self.utils
+            .get_metrics()
+            .map(|m| m.run_inspector(MevType::LongTail, execution))
+            .unwrap_or_else(execution)
+    }
+}
+
+impl<DB: LibmdbxReader> LongTailInspector<'_, DB> {
+    /// Pools created in the given block range (inclusive) with the block they
+    /// were created in.
+    fn new_pools(&self, start_block: u64, end_block: u64) -> FastHashMap<Address, u64> {
+        self.utils
+            .db
+            .protocols_created_range(start_block, end_block + 1)
+            .map_err(|e| tracing::error!(%e, "failed to load created protocols"))
+            .unwrap_or_default()
+            .into_iter()
+            .flat_map(|(block, pools)| pools.into_iter().map(move |(pool, ..)| (pool, block)))
+            .collect()
+    }
+
+    fn collect_entries(
+        &self,
+        block: &BlockData,
+        new_pools: &FastHashMap<Address, u64>,
+    ) -> Vec<Entry> {
+        let block_number = block.block_number();
+
+        self.collect_swaps(block.tree.clone())
+            .into_iter()
+            .flat_map(|(info, swaps, actions)| {
+                let mut buys: FastHashMap<Address, Entry> = FastHashMap::default();
+
+                for swap in swaps {
+                    let Some(&creation_block) = new_pools.get(&swap.pool) else { continue };
+                    if block_number < creation_block
+                        || block_number - creation_block > SNIPE_BLOCK_RANGE
+                        || !is_base_token(&swap.token_in)
+                        || is_base_token(&swap.token_out)
+                    {
+                        continue
+                    }
+
+                    buys.entry(swap.token_out.address)
+                        .or_insert_with(|| Entry {
+                            info: info.clone(),
+                            metadata: block.metadata.clone(),
+                            token: swap.token_out.clone(),
+                            pool: swap.pool,
+                            creation_block,
+                            swaps: vec![],
+                            actions: actions.clone(),
+                        })
+                        .swaps
+                        .push(swap);
+                }
+
+                buys.into_values()
+            })
+            .collect()
+    }
+
+    /// Groups the sells of sniped tokens in the block by the sniper and token.
+    fn collect_exits(
+        &self,
+        tree: Arc<BlockTree<Action>>,
+        entries: &FastHashMap<(Address, Address), Vec<Entry>>,
+    ) -> FastHashMap<(Address, Address), Vec<Exit>> {
+        let entry_txes = entries
+            .values()
+            .flatten()
+            .map(|entry| entry.info.tx_hash)
+            .collect::<FastHashSet<_>>();
+
+        let mut exits: FastHashMap<(Address, Address), Vec<Exit>> = FastHashMap::default();
+
+        for (info, swaps, actions) in self.collect_swaps(tree) {
+            if entry_txes.contains(&info.tx_hash) {
+                continue
+            }
+
+            let sold_tokens = swaps
+                .iter()
+                .map(|swap| swap.token_in.address)
+                .filter(|token| entries.contains_key(&(info.eoa, *token)))
+                .unique()
+                .collect_vec();
+
+            for token in sold_tokens {
+                exits.entry((info.eoa, token)).or_default().push(Exit {
+                    info:    info.clone(),
+                    swaps:   swaps
+                        .iter()
+                        .filter(|swap| swap.token_in.address == token)
+                        .cloned()
+                        .collect(),
+                    actions: actions.clone(),
+                });
+            }
+        }
+
+        exits
+    }
+
+    /// The sells of sniped tokens in the blocks of the window before the most
+    /// recent one, with the block and index of their transaction. These
+    /// already sold off part of the entries.
+    fn prior_exits(
+        &self,
+        data: &MultiBlockData,
+        entries: &FastHashMap<(Address, Address), Vec<Entry>>,
+    ) -> FastHashMap<(Address, Address), Vec<(u64, u64, Rational)>> {
+        let mut prior_exits: FastHashMap<_, Vec<_>> = FastHashMap::default();
+
+        let prior_blocks = data.per_block_data.len().saturating_sub(1);
+        for block in &data.per_block_data[..prior_blocks] {
+            let block_number = block.block_number();
+            for (key, exits) in self.collect_exits(block.tree.clone(), entries) {
+                prior_exits.entry(key).or_default().extend(
+                    exits
+                        .iter()
+                        .map(|exit| (block_number, exit.info.tx_index, exit.amount())),
+                );
+            }
+        }
+
+        prior_exits
+            .values_mut()
+            .for_each(|exits| exits.sort_by_key(|(block, tx_index, _)| (*block, *tx_index)));
+
+        prior_exits
+    }
+
+    /// Snipes with entries in the first block of a full window that the sniper
+    /// hasn't sold any of since. These leave the window with the next block, so
+    /// they are emitted now with the tokens still held valued at the current
+    /// block.
+    fn unexited_snipes(
+        &self,
+        data: &MultiBlockData,
+        entries: &FastHashMap<(Address, Address), Vec<Entry>>,
+        metadata: Arc<Metadata>,
+    ) -> Vec<Bundle> {
+        if data.per_block_data.len() < LONG_TAIL_BLOCK_WINDOW {
+            return vec![]
+        }
+
+        let window_start = data.per_block_data[0].block_number();
+        let expiring = entries
+            .iter()
+            .filter_map(|(key, entries)| {
+                let entries = entries
+                    .iter()
+                    .filter(|entry| entry.metadata.block_num == window_start)
+                    .collect_vec();
+                (!entries.is_empty()).then_some((*key, entries))
+            })
+            .collect::<FastHashMap<_, _>>();
+
+        if expiring.is_empty() {
+            return vec![]
+        }
+
+        let exited = data
+            .per_block_data
+            .iter()
+            .flat_map(|block| {
+                let block_number = block.block_number();
+                self.collect_exits(block.tree.clone(), entries)
+                    .into_iter()
+                    .flat_map(move |(key, exits)| {
+                        exits
+                            .into_iter()
+                            .map(move |exit| (key, block_number, exit.info.tx_index))
+                    })
+            })
+            .filter_map(|(key, block_number, tx_index)| {
+                let entries = expiring.get(&key)?;
+                (block_number > window_start
+                    || entries.iter().any(|entry| entry.info.tx_index < tx_index))
+                .then_some(key)
+            })
+            .collect::<FastHashSet<_>>();
+
+        expiring
+            .into_iter()
+            .filter(|(key, _)| !exited.contains(key))
+            .filter_map(|(_, entries)| {
+                let entries = entries
+                    .into_iter()
+                    .map(|entry| (entry, entry.amount()))
+                    .collect();
+                self.calculate_long_tail(entries, vec![], metadata.clone())
+            })
+            .collect()
+    }
+
+    fn collect_swaps(
+        &self,
+        tree: Arc<BlockTree<Action>>,
+    ) -> Vec<(TxInfo, Vec<NormalizedSwap>, Vec<Action>)> {
+        let (hashes, actions): (Vec<_>, Vec<_>) = tree
+            .clone()
+            .collect_all(TreeSearchBuilder::default().with_actions([
+                Action::is_swap,
+                Action::is_transfer,
+                Action::is_eth_transfer,
+                Action::is_aggregator,
+            ]))
+            .unzip();
+        let tx_info = tree.get_tx_info_batch(&hashes, self.utils.db);
+
+        actions
+            .into_iter()
+            .zip(tx_info)
+            .filter_map(|(actions, info)| {
+                let actions = self
+                    .utils
+                    .flatten_nested_actions_default(actions.into_iter())
+                    .collect_vec();
+                let swaps = actions
+                    .clone()
+                    .into_iter()
+                    .collect_action_vec(Action::try_swaps_merged);
+
+                (!swaps.is_empty()).then_some((info?, swaps, actions))
+            })
+            .collect()
+    }
+
+    /// Builds the bundle of the exits of a snipe. The entries come with the
+    /// amount of them that wasn't sold by earlier exits, the exits sell off the
+    /// rest oldest entry first.
+    fn calculate_long_tail(
+        &self,
+        entries: Vec<(&Entry, Rational)>,
+        exits: Vec<Exit>,
+        metadata: Arc<Metadata>,
+    ) -> Option<Bundle> {
+        // entries that were sold off entirely belong to the bundles of earlier exits
+        let (entries, remaining): (Vec<_>, Vec<_>) = entries
+            .into_iter()
+            .filter(|(_, remaining)| *remaining > Rational::ZERO)
+            .unzip();
+        let first_entry = entries.first()?;
+        let mut has_dex_price = true;
+
+        let entry_amount = remaining.iter().sum::<Rational>();
+        let exit_amount = exits.iter().map(Exit::amount).sum::<Rational>();
+
+        // the cost of each entry, gas included, for the share of it that is left
+        let mut entry_size_usd = Rational::ZERO;
+        let entry_costs_usd = entries
+            .iter()
+            .zip(&remaining)
+            .map(|(entry, remaining)| {
+                let held_share = remaining / entry.amount();
+                let size_usd = entry
+                    .swaps
+                    .iter()
+                    .map(|swap| {
+                        self.utils.get_token_value_dex(
+                            entry.info.tx_index as usize,
+                            PriceAt::Average,
+                            swap.token_in.address,
+                            &swap.amount_in,
+                            &entry.metadata,
+                        )
+                    })
+                    .sum::<Option<Rational>>()
+                    .unwrap_or_else(|| {
+                        has_dex_price = false;
+                        Rational::ZERO
+                    });
+                let gas_usd = entry
+                    .metadata
+                    .get_gas_price_usd(entry.info.gas_details.gas_paid(), self.utils.quote);
+
+                entry_size_usd += &size_usd * &held_share;
+                (size_usd + gas_usd) * held_share
+            })
+            .collect_vec();
+
+        let exit_size_usd = exits
+            .iter()
+            .flat_map(|exit| {
+                exit.swaps.iter().map(|swap| {
+                    self.utils.get_token_value_dex(
+                        exit.info.tx_index as usize,
+                        PriceAt::Average,
+                        swap.token_out.address,
+                        &swap.amount_out,
+                        &metadata,
+                    )
+                })
+            })
+            .sum::<Option<Rational>>()
+            .unwrap_or_else(|| {
+                has_dex_price = false;
+                Rational::ZERO
+            });
+
+        let exit_gas_usd = exits
+            .iter()
+            .map(|exit| {
+                metadata.get_gas_price_usd(exit.info.gas_details.gas_paid(), self.utils.quote)
+            })
+            .sum::<Rational>();
+
+        let (cost_usd, sold_share) = fifo_cost_basis(&entry_costs_usd, &remaining, &exit_amount);
+
+        // the tokens that weren't sold yet are valued at the current block
+        let held_amount =
+            if exit_amount < entry_amount { &entry_amount - &exit_amount } else { Rational::ZERO };
+        let held_value_usd = if held_amount == Rational::ZERO {
+            Rational::ZERO
+        } else {
+            self.utils
+                .get_token_value_dex_block(
+                    BlockPrice::Average,
+                    first_entry.token.address,
+                    &held_amount,
+                    &metadata,
+                )
+                .unwrap_or_else(|| {
+                    has_dex_price = false;
+                    Rational::ZERO
+                })
+        };
+
+        let (mut realized_pnl, mut unrealized_pnl) =
+            snipe_pnl(&cost_usd, &sold_share, &exit_size_usd, &exit_gas_usd, &held_value_usd);
+
+        if !has_dex_price
+            || [&realized_pnl, &unrealized_pnl]
+                .into_iter()
+                .any(|pnl| *pnl >= MAX_PROFIT || *pnl <= MIN_PROFIT)
+        {
+            has_dex_price = false;
+            realized_pnl = Rational::ZERO;
+            unrealized_pnl = Rational::ZERO;
+        }
+
+        // the header describes the exits, or the entries of a snipe that is still
+        // held
+        let (header_txes, header_metadata, profit) = if exits.is_empty() {
+            (
+                entries
+                    .iter()
+                    .map(|entry| (&entry.info, entry.actions.clone()))
+                    .collect_vec(),
+                first_entry.metadata.clone(),
+                unrealized_pnl.clone(),
+            )
+        } else {
+            (
+                exits
+                    .iter()
+                    .map(|exit| (&exit.info, exit.actions.clone()))
+                    .collect_vec(),
+                metadata.clone(),
+                realized_pnl.clone(),
+            )
+        };
+        let header_info = header_txes[0].0;
+
+        let (header_deltas, header_gas): (Vec<_>, Vec<_>) = header_txes
+            .iter()
+            .map(|(info, actions)| {
+                let deltas = actions
+                    .clone()
+                    .into_iter()
+                    .chain(info.get_total_eth_value().iter().cloned().map(Action::from))
+                    .filter(|a| a.is_eth_transfer() || a.is_transfer())
+                    .account_for_actions();
+
+                (deltas, info.gas_details)
+            })
+            .unzip();
+
+        let header = self.utils.build_bundle_header(
+            header_deltas,
+            header_txes.iter().map(|(info, _)| info.tx_hash).collect(),
+            header_info,
+            profit.to_float(),
+            &header_gas,
+            metadata.clone(),
+            MevType::LongTail,
+            !has_dex_price,
+            |this, token, amount| {
+                this.get_token_value_dex(
+                    header_info.tx_index as usize,
+                    PriceAt::Average,
+                    token,
+                    &amount,
+                    &header_metadata,
+                )
+            },
+        );
+
+        let long_tail = LongTail {
+            block_number:        metadata.block_num,
+            sniped_token:        first_entry.token.clone(),
+            pool:                first_entry.pool,
+            pool_creation_block: first_entry.creation_block,
+            entry_tx_hashes:     entries.iter().map(|entry| entry.info.tx_hash).collect(),
+            entry_block_numbers: entries
+                .iter()
+                .map(|entry| entry.metadata.block_num)
+                .collect(),
+            entry_swaps:         entries.iter().map(|entry| entry.swaps.clone()).collect(),
+            entry_gas_details:   entries.iter().map(|entry| entry.info.gas_details).collect(),
+            entry_amount:        entry_amount.to_float(),
+            entry_size_usd:      entry_size_usd.to_float(),
+            exit_tx_hashes:      exits.iter().map(|exit| exit.info.tx_hash).collect(),
+            exit_swaps:          exits.iter().map(|exit| exit.swaps.clone()).collect(),
+            exit_gas_details:    exits.iter().map(|exit| exit.info.gas_details).collect(),
+            exit_amount:         exit_amount.to_float(),
+            exit_size_usd:       exit_size_usd.to_float(),
+            realized_pnl_usd:    realized_pnl.to_float(),
+            unrealized_pnl_usd:  unrealized_pnl.to_float(),
+        };
+
+        Some(Bundle { header, data: BundleData::LongTail(long_tail) })
+    }
+}
+
+/// The amount left of each entry after the earlier exits, given as the block,
+/// transaction index and amount of each sell. An exit only sells off the
+/// entries that happened before it.
+fn remaining_entry_amounts(
+    entries: &[&Entry],
+    prior_exits: &[(u64, u64, Rational)],
+) -> Vec<Rational> {
+    let mut remaining = entries.iter().map(|entry| entry.amount()).collect_vec();
+
+    for (block_number, tx_index, amount) in prior_exits {
+        let bought_before = entries
+            .iter()
+            .take_while(|entry| entry.is_before(*block_number, *tx_index))
+            .count();
+        consume_fifo(&mut remaining[..bought_before], amount.clone());
+    }
+
+    remaining
+}
+
+/// Sells `amount` out of the remaining amounts of the entries, oldest entry
+/// first. Returns the amount sold out of each entry.
+fn consume_fifo(remaining: &mut [Rational], mut amount: Rational) -> Vec<Rational> {
+    remaining
+        .iter_mut()
+        .map(|remaining| {
+            let sold = if *remaining < amount { remaining.clone() } else { amount.clone() };
+            *remaining -= &sold;
+            amount -= &sold;
+            sold
+        })
+        .collect()
+}
+
+/// The cost basis of the position that is left and the share of it that the
+/// exits of `exit_amount` sold, with the entries sold off oldest first.
+fn fifo_cost_basis(
+    entry_costs_usd: &[Rational],
+    remaining: &[Rational],
+    exit_amount: &Rational,
+) -> (Rational, Rational) {
+    let cost_usd = entry_costs_usd.iter().sum::<Rational>();
+    let entry_amount = remaining.iter().sum::<Rational>();
+    if entry_amount == Rational::ZERO {
+        return (cost_usd, Rational::ZERO)
+    }
+
+    let sold = consume_fifo(&mut remaining.to_vec(), exit_amount.clone());
+    let sold_share = if cost_usd == Rational::ZERO {
+        (exit_amount / &entry_amount).min(Rational::ONE)
+    } else {
+        entry_costs_usd
+            .iter()
+            .zip(remaining)
+            .zip(sold)
+            .map(|((cost, remaining), sold)| cost * sold / remaining)
+            .sum::<Rational>()
+            / &cost_usd
+    };
+
+    (cost_usd, sold_share)
+}
+
+/// Splits the pnl of a snipe with the given cost basis into the realized pnl of
+/// the sold share and the unrealized pnl of the tokens still held.
+fn snipe_pnl(
+    cost_usd: &Rational,
+    sold_share: &Rational,
+    exit_size_usd: &Rational,
+    exit_gas_usd: &Rational,
+    held_value_usd: &Rational,
+) -> (Rational, Rational) {
+    let realized = exit_size_usd - cost_usd * sold_share - exit_gas_usd;
+    let unrealized = held_value_usd - cost_usd * (Rational::ONE - sold_share);
+
+    (realized, unrealized)
+}
+
+/// Tokens that a sniper pays with. The other side of the pool is the token
+/// being sniped.
+fn is_base_token(token: &TokenInfoWithAddress) -> bool {
+    token.address == WETH_ADDRESS || get_stable_type(&token.symbol).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_base_token() {
+        assert!(is_base_token(&TokenInfoWithAddress::weth()));
+        assert!(is_base_token(&TokenInfoWithAddress::native_eth()));
+        assert!(is_base_token(&TokenInfoWithAddress::usdc()));
+        assert!(!is_base_token(&TokenInfoWithAddress::default()));
+    }
+
+    #[test]
+    fn test_realized_snipe_pnl() {
+        // bought for $1000 + $50 gas, sold everything for $1500 paying $30 gas
+        let (realized, unrealized) = snipe_pnl(
+            &Rational::from(1050),
+            &Rational::ONE,
+            &Rational::from(1500),
+            &Rational::from(30),
+            &Rational::ZERO,
+        );
+
+        assert_eq!(realized, Rational::from(420));
+        assert_eq!(unrealized, Rational::ZERO);
+    }
+
+    #[test]
+    fn test_partially_exited_snipe_pnl() {
+        // sold a quarter of the position, the rest is worth $2400
+        let (realized, unrealized) = snipe_pnl(
+            &Rational::from(1000),
+            &Rational::from_unsigneds(1u32, 4u32),
+            &Rational::from(800),
+            &Rational::from(20),
+            &Rational::from(2400),
+        );
+
+        assert_eq!(realized, Rational::from(530));
+        assert_eq!(unrealized, Rational::from(1650));
+    }
+
+    #[test]
+    fn test_consume_fifo() {
+        let mut remaining = vec![Rational::from(100), Rational::from(50)];
+
+        let sold = consume_fifo(&mut remaining, Rational::from(120));
+        assert_eq!(sold, vec![Rational::from(100), Rational::from(20)]);
+        assert_eq!(remaining, vec![Rational::ZERO, Rational::from(30)]);
+
+        // selling more than is left only empties the entries
+        let sold = consume_fifo(&mut remaining, Rational::from(40));
+        assert_eq!(sold, vec![Rational::ZERO, Rational::from(30)]);
+        assert_eq!(remaining, vec![Rational::ZERO, Rational::ZERO]);
+    }
+
+    #[test]
+    fn test_fifo_cost_basis_across_exits() {
+        // bought 100 tokens for $1000 and another 100 for $3000, then sold 100
+        // in each of two blocks
+        let (cost, sold_share) = fifo_cost_basis(
+            &[Rational::from(1000), Rational::from(3000)],
+            &[Rational::from(100), Rational::from(100)],
+            &Rational::from(100),
+        );
+        assert_eq!(&cost * &sold_share, Rational::from(1000));
+        assert_eq!(cost, Rational::from(4000));
+
+        // the first entry was sold off by the first exit, the second exit is
+        // only charged the cost of the second entry
+        let (cost, sold_share) =
+            fifo_cost_basis(&[Rational::from(3000)], &[Rational::from(100)], &Rational::from(100));
+        assert_eq!(cost, Rational::from(3000));
+        assert_eq!(sold_share, Rational::ONE);
+    }
+
+    #[test]
+    fn test_unexited_snipe_pnl() {
+        // never sold, the token dropped to half of what was paid
+        let (realized, unrealized) = snipe_pnl(
+            &Rational::from(1050),
+            &Rational::ZERO,
+            &Rational::ZERO,
+            &Rational::ZERO,
+            &Rational::from(500),
+        );
+
+        assert_eq!(realized, Rational::ZERO);
+        assert_eq!(unrealized, Rational::from(-550));
+    }
+}
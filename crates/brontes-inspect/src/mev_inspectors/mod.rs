@@ -1,8 +1,11 @@
 pub mod atomic_arb;
 pub mod cex_dex;
+pub mod cross_domain;
 
 pub mod jit;
 pub mod liquidations;
+pub mod long_tail;
+pub mod nft;
 pub mod sandwich;
 pub mod searcher_activity;
 pub mod shared_utils;
@@ -0,0 +1,291 @@
+//! Detects nft arbitrage and nft snipes.
+//!
+//! An arbitrage buys an nft on one marketplace and sells it on another within
+//! the same transaction. A snipe buys an nft, usually a listing under the floor
+//! price, and sells it again in a later transaction of the same block. Only
+//! trades paid in eth or weth are considered, so the profit is calculated in
+//! eth and converted with the eth price of the block.
+use std::sync::Arc;
+
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_metrics::inspectors::OutlierMetrics;
+use brontes_types::{
+    constants::{ETH_ADDRESS, WETH_ADDRESS},
+    db::dex::PriceAt,
+    mev::{Bundle, BundleData, MevType, NftMev, NftMevKind},
+    normalized_actions::{accounting::ActionAccounting, Action, NftTradeKind, NormalizedNftTrade},
+    ActionIter, BlockData, FastHashMap, GasDetails, MultiBlockData, ToFloatNearest,
+    TreeSearchBuilder, TxInfo,
+};
+use itertools::Itertools;
+use malachite::{num::basic::traits::Zero, Rational};
+use reth_primitives::{Address, U256};
+
+use crate::{shared_utils::SharedInspectorUtils, BlockTree, Inspector, Metadata};
+
+pub struct NftInspector<'db, DB: LibmdbxReader> {
+    utils: SharedInspectorUtils<'db, DB>,
+}
+
+impl<'db, DB: LibmdbxReader> NftInspector<'db, DB> {
+    pub fn new(quote: Address, db: &'db DB, metrics: Option<OutlierMetrics>) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, metrics) }
+    }
+}
+
+/// The nft trades of a transaction with the transfers needed for the balance
+/// deltas.
+struct NftTx {
+    info:    TxInfo,
+    trades:  Vec<NormalizedNftTrade>,
+    actions: Vec<Action>,
+}
+
+impl NftTx {
+    fn is_searcher(&self, address: Address) -> bool {
+        address == self.info.eoa || Some(address) == self.info.mev_contract
+    }
+}
+
+impl<DB: LibmdbxReader> Inspector for NftInspector<'_, DB> {
+    type Result = Vec<Bundle>;
+
+    fn get_id(&self) -> &str {
+        "Nft"
+    }
+
+    fn get_quote_token(&self) -> Address {
+        self.utils.quote
+    }
+
+    fn get_metrics(&self) -> Option<&OutlierMetrics> {
+        self.utils.get_metrics()
+    }
+
+    fn inspect_block(&self, data: MultiBlockData) -> Self::Result {
+        let BlockData { metadata, tree } = data.get_most_recent_block();
+
+        let execution = || {
+            let txes = self.collect_trades(tree.clone());
+            if txes.is_empty() {
+                return vec![]
+            }
+
+            let mut bundles = vec![];
+            // buys that weren't sold again in the same transaction, keyed by the
+            // searcher and the bought item
+            let mut open_buys: FastHashMap<(Address, Address, U256), (usize, NormalizedNftTrade)> =
+                FastHashMap::default();
+
+            for (idx, tx) in txes.iter().enumerate() {
+                let (buys, sells): (Vec<_>, Vec<_>) = tx
+                    .trades
+                    .iter()
+                    .filter(|trade| tx.is_searcher(trade.buyer) || tx.is_searcher(trade.seller))
+                    .cloned()
+                    .partition(|trade| tx.is_searcher(trade.buyer));
+
+                let (arb_buys, open): (Vec<_>, Vec<_>) = buys
+                    .into_iter()
+                    .partition(|buy| sells.iter().any(|sell| sell.is_same_item(buy)));
+                let (arb_sells, sells): (Vec<_>, Vec<_>) = sells
+                    .into_iter()
+                    .partition(|sell| arb_buys.iter().any(|buy| buy.is_same_item(sell)));
+
+                if !arb_buys.is_empty() {
+                    bundles.extend(self.build_bundle(
+                        NftMevKind::Arbitrage,
+                        vec![tx],
+                        arb_buys,
+                        arb_sells,
+                        metadata.clone(),
+                    ));
+                }
+
+                for sell in sells {
+                    let key = (tx.info.eoa, sell.collection, sell.token_id);
+                    let Some((buy_idx, buy)) = open_buys.remove(&key) else { continue };
+
+                    bundles.extend(self.build_bundle(
+                        NftMevKind::Snipe,
+                        vec![&txes[buy_idx], tx],
+                        vec![buy],
+                        vec![sell],
+                        metadata.clone(),
+                    ));
+                }
+
+                for buy in open {
+                    open_buys.insert((tx.info.eoa, buy.collection, buy.token_id), (idx, buy));
+                }
+            }
+
+            bundles
+        };
+
+        self.utils
+            .get_metrics()
+            .map(|m| m.run_inspector(MevType::Nft, execution))
+            .unwrap_or_else(execution)
+    }
+}
+
+impl<DB: LibmdbxReader> NftInspector<'_, DB> {
+    /// Transactions with eth denominated nft trades, in block order.
+    fn collect_trades(&self, tree: Arc<BlockTree<Action>>) -> Vec<NftTx> {
+        let (hashes, actions): (Vec<_>, Vec<_>) = tree
+            .clone()
+            .collect_all(TreeSearchBuilder::default().with_actions([
+                Action::is_nft_trade,
+                Action::is_transfer,
+                Action::is_eth_transfer,
+            ]))
+            .unzip();
+        let tx_info = tree.get_tx_info_batch(&hashes, self.utils.db);
+
+        actions
+            .into_iter()
+            .zip(tx_info)
+            .filter_map(|(actions, info)| {
+                let trades = actions
+                    .clone()
+                    .into_iter()
+                    .collect_action_vec(Action::try_nft_trade)
+                    .into_iter()
+                    .filter(|trade| {
+                        trade.kind != NftTradeKind::Liquidation
+                            && is_eth_payment(trade.payment_token.address)
+                    })
+                    .collect_vec();
+
+                (!trades.is_empty()).then_some(NftTx { info: info?, trades, actions })
+            })
+            .sorted_by_key(|tx| tx.info.tx_index)
+            .collect()
+    }
+
+    fn build_bundle(
+        &self,
+        kind: NftMevKind,
+        txes: Vec<&NftTx>,
+        buys: Vec<NormalizedNftTrade>,
+        sells: Vec<NormalizedNftTrade>,
+        metadata: Arc<Metadata>,
+    ) -> Option<Bundle> {
+        let last_tx = txes.last()?;
+        let gas_details = txes.iter().map(|tx| tx.info.gas_details).collect_vec();
+
+        let profit_eth = profit_eth(&buys, &sells, &gas_details);
+        let eth_price = metadata.get_eth_price(self.utils.quote);
+        let profit_usd = (&profit_eth * &eth_price).to_float();
+
+        let deltas = txes
+            .iter()
+            .map(|tx| {
+                tx.actions
+                    .clone()
+                    .into_iter()
+                    .chain(
+                        tx.info
+                            .get_total_eth_value()
+                            .iter()
+                            .cloned()
+                            .map(Action::from),
+                    )
+                    .filter(|a| a.is_eth_transfer() || a.is_transfer())
+                    .account_for_actions()
+            })
+            .collect_vec();
+
+        let header = self.utils.build_bundle_header(
+            deltas,
+            txes.iter().map(|tx| tx.info.tx_hash).collect(),
+            &last_tx.info,
+            profit_usd,
+            &gas_details,
+            metadata.clone(),
+            MevType::Nft,
+            eth_price == Rational::ZERO,
+            |this, token, amount| {
+                this.get_token_value_dex(
+                    last_tx.info.tx_index as usize,
+                    PriceAt::Average,
+                    token,
+                    &amount,
+                    &metadata,
+                )
+            },
+        );
+
+        let nft = NftMev {
+            block_number: metadata.block_num,
+            kind,
+            tx_hashes: txes.iter().map(|tx| tx.info.tx_hash).collect(),
+            buys,
+            sells,
+            gas_details,
+            profit_eth: profit_eth.to_float(),
+        };
+
+        Some(Bundle { header, data: BundleData::Nft(nft) })
+    }
+}
+
+/// Weth is accepted next to native eth as bids on most marketplaces are paid
+/// in weth.
+fn is_eth_payment(token: Address) -> bool {
+    token == ETH_ADDRESS || token == WETH_ADDRESS
+}
+
+/// Proceeds of the sells minus the price paid for the buys and the gas of all
+/// transactions of the bundle, in eth.
+fn profit_eth(
+    buys: &[NormalizedNftTrade],
+    sells: &[NormalizedNftTrade],
+    gas_details: &[GasDetails],
+) -> Rational {
+    let proceeds = sells
+        .iter()
+        .map(|sell| sell.seller_proceeds())
+        .sum::<Rational>();
+    let cost = buys.iter().map(|buy| &buy.price).sum::<Rational>();
+    let gas = gas_details
+        .iter()
+        .map(|gd| Rational::from_unsigneds(gd.gas_paid(), 10u128.pow(18)))
+        .sum::<Rational>();
+
+    proceeds - cost - gas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade(price: u64, fees: u64) -> NormalizedNftTrade {
+        NormalizedNftTrade {
+            price: Rational::from(price),
+            fees: Rational::from(fees),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_is_eth_payment() {
+        assert!(is_eth_payment(ETH_ADDRESS));
+        assert!(is_eth_payment(WETH_ADDRESS));
+        assert!(!is_eth_payment(Address::ZERO));
+    }
+
+    #[test]
+    fn test_profit_eth() {
+        let gas_details = GasDetails {
+            gas_used: 100_000,
+            effective_gas_price: 10u128.pow(13),
+            ..Default::default()
+        };
+
+        // sold for 12 with 1 in fees, bought for 10 and paid 1 eth in gas
+        let profit = profit_eth(&[trade(10, 0)], &[trade(12, 1)], &[gas_details]);
+        assert_eq!(profit, Rational::ZERO);
+    }
+}
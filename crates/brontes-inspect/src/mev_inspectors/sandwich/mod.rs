@@ -37,18 +37,34 @@ const MAX_PRICE_DIFF: Rational = Rational::const_from_unsigneds(995, 1000);
 const MAX_NON_SWAP_FRONTRUN: Rational = Rational::const_from_unsigned(5000);
 
 pub struct SandwichInspector<'db, DB: LibmdbxReader> {
-    utils: SharedInspectorUtils<'db, DB>,
+    utils:        SharedInspectorUtils<'db, DB>,
+    /// Amount of blocks a sandwich can span. Builders and proposers that
+    /// control consecutive slots can frontrun in one block and backrun in the
+    /// next.
+    block_window: usize,
 }
 
 impl<'db, DB: LibmdbxReader> SandwichInspector<'db, DB> {
-    pub fn new(quote: Address, db: &'db DB, metrics: Option<OutlierMetrics>) -> Self {
-        Self { utils: SharedInspectorUtils::new(quote, db, metrics) }
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        block_window: usize,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self {
+            utils:        SharedInspectorUtils::new(quote, db, metrics),
+            block_window: block_window.max(1),
+        }
     }
 }
 
 impl<DB: LibmdbxReader> Inspector for SandwichInspector<'_, DB> {
     type Result = Vec<Bundle>;
 
+    fn block_window(&self) -> usize {
+        self.block_window
+    }
+
     fn get_id(&self) -> &str {
         "Sandwich"
     }
@@ -57,27 +73,27 @@ impl<DB: LibmdbxReader> Inspector for SandwichInspector<'_, DB> {
         self.utils.quote
     }
 
-    fn inspect_block(&self, data: MultiBlockData) -> Self::Result {
-        let BlockData { metadata, tree } = data.get_most_recent_block();
+    fn get_metrics(&self) -> Option<&OutlierMetrics> {
+        self.utils.get_metrics()
+    }
 
+    fn inspect_block(&self, data: MultiBlockData) -> Self::Result {
         self.utils
             .get_metrics()
             .map(|m| {
                 m.run_inspector(MevType::Sandwich, || {
-                    self.inspect_block_inner(tree.clone(), metadata.clone())
+                    self.inspect_block_inner(&data.per_block_data)
                 })
             })
-            .unwrap_or_else(|| self.inspect_block_inner(tree.clone(), metadata.clone()))
+            .unwrap_or_else(|| self.inspect_block_inner(&data.per_block_data))
     }
 }
 
 impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
-    fn inspect_block_inner(
-        &self,
-        tree: Arc<BlockTree<Action>>,
-        metadata: Arc<Metadata>,
-    ) -> Vec<Bundle> {
+    fn inspect_block_inner(&self, blocks: &[BlockData]) -> Vec<Bundle> {
         tracing::trace!("starting sandwich");
+        let Some(BlockData { tree, .. }) = blocks.last() else { return vec![] };
+
         let search_args = TreeSearchBuilder::default().with_actions([
             Action::is_swap,
             Action::is_transfer,
@@ -85,26 +101,189 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
             Action::is_nested_action,
         ]);
 
-        self.get_possible_sandwich(tree.clone())
+        let mut bundles = self
+            .get_possible_sandwich(tree.clone())
             .into_iter()
             .filter_map(|ps| {
-                self.collect_baseline_sandwich_data(
-                    tree.clone(),
-                    search_args.clone(),
-                    ps,
-                    metadata.clone(),
-                )
+                self.candidate();
+                self.collect_baseline_sandwich_data(tree.clone(), blocks, search_args.clone(), ps)
+            })
+            .flatten()
+            .collect::<Vec<_>>();
+
+        if blocks.len() > 1 {
+            bundles.extend(self.inspect_multi_block(blocks, search_args));
+        }
+
+        bundles
+    }
+
+    /// Finds sandwiches whose frontrun landed in one of the previous blocks of
+    /// the window while the backrun is in the most recent block.
+    fn inspect_multi_block(
+        &self,
+        blocks: &[BlockData],
+        search_args: TreeSearchBuilder<Action>,
+    ) -> Vec<Bundle> {
+        let set = get_possible_multi_block_sandwiches(blocks);
+        if set.is_empty() {
+            return vec![]
+        }
+
+        let tx_set = set
+            .iter()
+            .flat_map(|ps| {
+                ps.possible_frontruns
+                    .iter()
+                    .chain(std::iter::once(&ps.possible_backrun))
+                    .chain(ps.victims.iter().flatten())
             })
+            .copied()
+            .unique()
+            .collect::<Vec<_>>();
+
+        let tx_info_map = blocks
+            .iter()
+            .flat_map(|block| block.tree.get_tx_info_batch(&tx_set, self.utils.db))
             .flatten()
-            .collect::<Vec<_>>()
+            .map(|info| (info.tx_hash, info))
+            .collect::<FastHashMap<_, _>>();
+
+        set.into_iter()
+            .filter_map(|ps| PossibleSandwichWithTxInfo::from_ps(ps, &tx_info_map))
+            .filter_map(|ps| {
+                self.candidate();
+                self.collect_multi_block_sandwich_data(blocks, search_args.clone(), ps)
+            })
+            .flatten()
+            // sandwiches fully contained in a single block are found by the single block
+            // detection of the block they happened in
+            .filter(|bundle| {
+                matches!(&bundle.data, BundleData::Sandwich(sandwich) if sandwich.is_multi_block())
+            })
+            .collect()
+    }
+
+    fn collect_multi_block_sandwich_data(
+        &self,
+        blocks: &[BlockData],
+        search_args: TreeSearchBuilder<Action>,
+        ps: PossibleSandwichWithTxInfo,
+    ) -> Option<Vec<Bundle>> {
+        let PossibleSandwichWithTxInfo {
+            inner: PossibleSandwich { mev_executor_contract, .. },
+            victims_info,
+            possible_frontruns_info,
+            possible_backrun_info,
+        } = ps;
+
+        if victims_info.iter().flatten().count() == 0 {
+            self.reject("no_victims");
+            return None
+        };
+
+        // same as for single block sandwiches, we drop the set if any of the
+        // victims reverted or interacted with the searchers contract
+        let victim_swaps_transfers = victims_info
+            .iter()
+            .map(|set| {
+                set.iter()
+                    .map(|info| {
+                        let tree = block_for(blocks, info.block_number)?.tree.clone();
+                        let root_action = tree.get_root(info.tx_hash)?.get_root_action();
+                        if root_action.is_revert()
+                            || root_action.get_to_address() == mev_executor_contract
+                        {
+                            return None
+                        }
+
+                        Some(
+                            self.collect_tx_actions(tree, info.tx_hash, search_args.clone())
+                                .into_iter()
+                                .split_actions::<(Vec<_>, Vec<_>), _>((
+                                    Action::try_swaps_merged,
+                                    Action::try_transfer,
+                                )),
+                        )
+                    })
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect::<Option<Vec<_>>>();
+        let Some(victim_swaps_transfers) = victim_swaps_transfers else {
+            self.reject("victim_reverted_or_called_searcher");
+            return None
+        };
+
+        let searcher_actions = possible_frontruns_info
+            .iter()
+            .chain(std::iter::once(&possible_backrun_info))
+            .map(|info| {
+                let tree = block_for(blocks, info.block_number)?.tree.clone();
+                Some(self.collect_tx_actions(tree, info.tx_hash, search_args.clone()))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let black_list: FastHashSet<Address> =
+            collect_address_set_for_accounting(&possible_frontruns_info);
+
+        // the frontrun and backrun need to trade the same pool
+        let (back_run_actions, front_run_actions) = searcher_actions.split_last()?;
+        let (front_run_pools, _) = Self::collect_frontrun_data(front_run_actions, &black_list);
+        let (back_run_pools, _) = Self::collect_backrun_data(back_run_actions.clone(), &black_list);
+        if front_run_pools.is_disjoint(&back_run_pools) {
+            self.reject("no_pool_overlap");
+            return None
+        }
+
+        self.calculate_sandwich(
+            blocks,
+            possible_frontruns_info,
+            possible_backrun_info,
+            searcher_actions,
+            victims_info,
+            victim_swaps_transfers,
+            black_list,
+            0,
+        )
+    }
+
+    fn candidate(&self) {
+        self.utils
+            .get_metrics()
+            .inspect(|m| m.inspector_candidate(MevType::Sandwich));
+    }
+
+    /// Records the validation step that rejected a possible sandwich. The
+    /// recursive retries with fewer frontruns or victims aren't recorded, only
+    /// the set that came out of the possible sandwich search
+    fn reject(&self, reason: &'static str) {
+        self.utils
+            .get_metrics()
+            .inspect(|m| m.branch_filtering_trigger(MevType::Sandwich, reason));
+    }
+
+    fn collect_tx_actions(
+        &self,
+        tree: Arc<BlockTree<Action>>,
+        tx_hash: B256,
+        search_args: TreeSearchBuilder<Action>,
+    ) -> Vec<Action> {
+        tree.collect_txes(&[tx_hash], search_args)
+            .map(|actions| {
+                self.utils
+                    .flatten_nested_actions_default(actions.into_iter())
+                    .collect_vec()
+            })
+            .next()
+            .unwrap_or_default()
     }
 
     fn collect_baseline_sandwich_data(
         &self,
         tree: Arc<BlockTree<Action>>,
+        blocks: &[BlockData],
         search_args: TreeSearchBuilder<Action>,
         ps: PossibleSandwichWithTxInfo,
-        metadata: Arc<Metadata>,
     ) -> Option<Vec<Bundle>> {
         let PossibleSandwichWithTxInfo {
             inner:
@@ -121,15 +300,19 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
         } = ps;
 
         if victims.iter().flatten().count() == 0 {
+            self.reject("no_victims");
             return None
         };
 
-        let victim_swaps_transfers: Vec<_> = self.get_victim_swap_transfer(
+        let Some(victim_swaps_transfers) = self.get_victim_swap_transfer(
             victims,
             tree.clone(),
             search_args.clone(),
             mev_executor_contract,
-        )?;
+        ) else {
+            self.reject("victim_reverted_or_called_searcher");
+            return None
+        };
 
         let searcher_actions: Vec<Vec<Action>> = tree
             .clone()
@@ -153,8 +336,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
             collect_address_set_for_accounting(&possible_frontruns_info);
 
         self.calculate_sandwich(
-            tree.clone(),
-            metadata.clone(),
+            blocks,
             possible_frontruns_info,
             possible_backrun_info,
             searcher_actions,
@@ -167,8 +349,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
 
     fn calculate_sandwich(
         &self,
-        tree: Arc<BlockTree<Action>>,
-        metadata: Arc<Metadata>,
+        blocks: &[BlockData],
         possible_front_runs_info: Vec<TxInfo>,
         backrun_info: TxInfo,
         mut searcher_actions: Vec<Vec<Action>>,
@@ -177,6 +358,8 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
         black_list: FastHashSet<Address>,
         recusive: u8,
     ) -> Option<Vec<Bundle>> {
+        let metadata = blocks.last()?.metadata.clone();
+
         // if all of the sandwichers have the same eoa or the to address is an mev
         // contract then we can continue. otherwise false positive
         if !(possible_front_runs_info
@@ -192,6 +375,9 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
                 == 1)
         {
             tracing::debug!(target: "brontes_inspect::sandwich", "all sandwiches don't have same eoa and aren't all verified contracts");
+            if recusive == 0 {
+                self.reject("searchers_not_same_eoa_or_contract");
+            }
             return None
         }
 
@@ -222,6 +408,9 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
             .iter()
             .all(|searcher_tx_swaps| !searcher_tx_swaps.is_empty())
         {
+            if recusive == 0 {
+                self.reject("searcher_tx_without_swap");
+            }
             return None
         }
 
@@ -238,9 +427,8 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
             // as a sandwich, we will recursively remove orders in both directions
             // to cover the full order-set to ensure that we don't miss any
             // opportunities
-            return self.recursive_possible_sandwiches(
-                tree.clone(),
-                metadata.clone(),
+            let res = self.recursive_possible_sandwiches(
+                blocks,
                 &possible_front_runs_info,
                 backrun_info,
                 &back_run_actions,
@@ -249,7 +437,16 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
                 &victim_actions,
                 black_list,
                 recusive,
-            )
+            );
+            if res.is_none() && recusive == 0 {
+                // with multiple frontruns the smaller sets were tried as well
+                self.reject(if possible_front_runs_info.len() > 1 {
+                    "no_pool_overlap_in_subsets"
+                } else {
+                    "no_pool_overlap"
+                });
+            }
+            return res
         }
 
         // if we reach this part of the code, we have found a sandwich and
@@ -286,13 +483,15 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
             })
             .unzip();
 
-        let gas_used = frontrun_gas_details
+        // legs of multi block sandwiches are priced at the block they landed in
+        let gas_used = possible_front_runs_info
             .iter()
-            .chain([backrun_info.gas_details].iter())
-            .map(|g| g.gas_paid())
-            .sum::<u128>();
-
-        let gas_used = metadata.get_gas_price_usd(gas_used, self.utils.quote);
+            .chain(std::iter::once(&backrun_info))
+            .map(|info| {
+                leg_metadata(blocks, info, &metadata)
+                    .get_gas_price_usd(info.gas_details.gas_paid(), self.utils.quote)
+            })
+            .sum::<Rational>();
 
         let searcher_deltas = searcher_actions
             .into_iter()
@@ -313,7 +512,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
         let mut has_dex_price = true;
         for (swaps, info) in front_run_swaps.iter().zip(&possible_front_runs_info) {
             has_dex_price &= self.utils.valid_pricing(
-                metadata.clone(),
+                leg_metadata(blocks, info, &metadata),
                 swaps,
                 searcher_deltas
                     .values()
@@ -423,6 +622,10 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
 
         let sandwich = Sandwich {
             block_number: metadata.block_num,
+            frontrun_block_numbers: possible_front_runs_info
+                .iter()
+                .map(|info| info.block_number)
+                .collect(),
             frontrun_tx_hash,
             frontrun_gas_details,
             frontrun_swaps: front_run_swaps,
@@ -432,6 +635,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
             backrun_tx_hash: backrun_info.tx_hash,
             backrun_swaps: back_run_swaps,
             backrun_gas_details: backrun_info.gas_details,
+            backrun_block_number: backrun_info.block_number,
         };
         tracing::debug!("{:#?}\n{:#?}", header, sandwich);
 
@@ -446,8 +650,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
     /// possible sets of sandwiches that can occur.
     fn recursive_possible_sandwiches(
         &self,
-        tree: Arc<BlockTree<Action>>,
-        metadata: Arc<Metadata>,
+        blocks: &[BlockData],
         possible_front_runs_info: &[TxInfo],
         backrun_info: TxInfo,
         back_run_actions: &[Action],
@@ -491,8 +694,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
                 }
 
                 self.calculate_sandwich(
-                    tree.clone(),
-                    metadata.clone(),
+                    blocks,
                     possible_front_runs_info,
                     back_run_info,
                     searcher_actions.to_vec(),
@@ -529,8 +731,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
                 }
 
                 self.calculate_sandwich(
-                    tree.clone(),
-                    metadata.clone(),
+                    blocks,
                     possible_front_runs_info,
                     backrun_info,
                     searcher_actions,
@@ -1011,6 +1212,78 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
     }
 }
 
+fn block_for(blocks: &[BlockData], block_number: u64) -> Option<&BlockData> {
+    blocks
+        .iter()
+        .find(|block| block.tree.header.number == block_number)
+}
+
+/// Metadata of the block the given transaction landed in.
+fn leg_metadata(blocks: &[BlockData], info: &TxInfo, default: &Arc<Metadata>) -> Arc<Metadata> {
+    block_for(blocks, info.block_number)
+        .map(|block| block.metadata.clone())
+        .unwrap_or_else(|| default.clone())
+}
+
+/// Pairs the first transaction of a searcher in the most recent block with
+/// their last transaction in the previous blocks of the window. Searchers are
+/// matched by both eoa and mev contract, the same way as for single block
+/// sandwiches. All transactions in between are possible victims.
+fn get_possible_multi_block_sandwiches(blocks: &[BlockData]) -> Vec<PossibleSandwich> {
+    let Some((latest, previous)) = blocks.split_last() else { return vec![] };
+
+    let roots = previous
+        .iter()
+        .chain(std::iter::once(latest))
+        .flat_map(|block| block.tree.tx_roots.iter())
+        .filter(|root| !root.get_root_action().is_revert())
+        .collect_vec();
+    let first_latest = roots.len()
+        - latest
+            .tree
+            .tx_roots
+            .iter()
+            .filter(|root| !root.get_root_action().is_revert())
+            .count();
+
+    let mut last_by_eoa: FastHashMap<Address, usize> = FastHashMap::default();
+    let mut last_by_contract: FastHashMap<Address, usize> = FastHashMap::default();
+    for (i, root) in roots[..first_latest].iter().enumerate() {
+        last_by_eoa.insert(root.head.address, i);
+        last_by_contract.insert(root.get_to_address(), i);
+    }
+
+    let mut possible_sandwiches = vec![];
+    for (i, root) in roots.iter().enumerate().skip(first_latest) {
+        // only the first transaction in the block can be the backrun, the later
+        // ones are covered by the single block detection
+        let frontruns = [
+            last_by_eoa.remove(&root.head.address),
+            last_by_contract.remove(&root.get_to_address()),
+        ];
+
+        for frontrun in frontruns.into_iter().flatten().unique() {
+            let victims = roots[frontrun + 1..i]
+                .iter()
+                .map(|root| root.tx_hash)
+                .collect_vec();
+            if victims.is_empty() || victims.len() > 30 {
+                continue
+            }
+
+            possible_sandwiches.push(PossibleSandwich {
+                eoa:                   roots[frontrun].head.address,
+                possible_frontruns:    vec![roots[frontrun].tx_hash],
+                possible_backrun:      root.tx_hash,
+                mev_executor_contract: root.get_to_address(),
+                victims:               vec![victims],
+            });
+        }
+    }
+
+    possible_sandwiches
+}
+
 fn get_possible_sandwich_duplicate_senders(tree: Arc<BlockTree<Action>>) -> Vec<PossibleSandwich> {
     let mut duplicate_senders: FastHashMap<Address, B256> = FastHashMap::default();
     let mut possible_victims: FastHashMap<B256, Vec<B256>> = FastHashMap::default();
@@ -1137,7 +1410,14 @@ fn get_possible_sandwich_duplicate_contracts(
 mod tests {
 
     use alloy_primitives::hex;
-    use brontes_types::constants::{DAI_ADDRESS, USDT_ADDRESS, WETH_ADDRESS};
+    use brontes_types::{
+        constants::{DAI_ADDRESS, USDT_ADDRESS, WETH_ADDRESS},
+        db::{metadata::BlockMetadata, token_info::TokenInfoWithAddress},
+        normalized_actions::NormalizedEthTransfer,
+        tree::{Node, NodeData, Root},
+        Protocol,
+    };
+    use reth_primitives::Header;
 
     use super::*;
     use crate::{
@@ -1491,6 +1771,29 @@ mod tests {
         inspector_util.assert_no_mev(config).await.unwrap();
     }
 
+    #[brontes_macros::test]
+    async fn test_false_positive_candidate_rejected() {
+        let inspector_util = InspectorTestUtils::new(USDT_ADDRESS, 1.0).await;
+        let metrics = OutlierMetrics::new();
+
+        let config = InspectorTxRunConfig::new(Inspectors::Sandwich)
+            .with_dex_prices()
+            .with_mev_tx_hashes(vec![
+                hex!("abcc6968cd2a072b20f5e2d25d80d7ad6957efa999079c511a278dd6eb9095d6").into(),
+                hex!("a79536b1257d96b03f53ff9e0017176704535a19353beb006179f4f9f9ef69aa").into(),
+                hex!("435470d1f5e2494525f556d03303e6a1e3622777b6b718cbb77abf9d6bd0ebdb").into(),
+            ]);
+
+        inspector_util
+            .assert_no_mev_with_metrics(config, Some(metrics.clone()))
+            .await
+            .unwrap();
+
+        let rejected = metrics.branch_filtered(MevType::Sandwich);
+        assert_eq!(metrics.candidates(MevType::Sandwich), 1);
+        assert_eq!(rejected.values().sum::<u64>(), 1, "rejected by {rejected:?}");
+    }
+
     #[brontes_macros::test]
     async fn beaver_double_cex_dex_false_positive() {
         let inspector_util = InspectorTestUtils::new(USDT_ADDRESS, 1.0).await;
@@ -1529,4 +1832,192 @@ mod tests {
 
         inspector_util.run_inspector(config, None).await.unwrap();
     }
+
+    const SEARCHER: Address = Address::with_last_byte(1);
+    const SEARCHER_CONTRACT: Address = Address::with_last_byte(2);
+    const ROUTER: Address = Address::with_last_byte(3);
+    const POOL: Address = Address::with_last_byte(4);
+
+    fn tx(
+        tx_hash: u8,
+        position: usize,
+        from: Address,
+        to: Address,
+        swaps: Vec<Action>,
+    ) -> Root<Action> {
+        let root_action =
+            Action::EthTransfer(NormalizedEthTransfer { from, to, ..Default::default() });
+
+        Root {
+            head: Node::new(0, from, vec![]),
+            position,
+            tx_hash: B256::with_last_byte(tx_hash),
+            private: false,
+            gas_details: GasDetails::default(),
+            total_msg_value_transfers: vec![],
+            data_store: NodeData(vec![Some([vec![root_action], swaps].concat())]),
+        }
+    }
+
+    fn block(number: u64, txs: Vec<(u8, Address, Address)>) -> BlockData {
+        block_with_swaps(number, txs, &[])
+    }
+
+    /// Block with the given swaps added to the transactions of the same hash
+    fn block_with_swaps(
+        number: u64,
+        txs: Vec<(u8, Address, Address)>,
+        swaps: &[(u8, Action)],
+    ) -> BlockData {
+        let header = Header { number, ..Default::default() };
+        let mut tree = BlockTree::new(header, txs.len());
+        tree.tx_roots = txs
+            .into_iter()
+            .enumerate()
+            .map(|(position, (hash, from, to))| {
+                let swaps = swaps
+                    .iter()
+                    .filter(|(swap_hash, _)| *swap_hash == hash)
+                    .map(|(_, swap)| swap.clone())
+                    .collect();
+                tx(hash, position, from, to, swaps)
+            })
+            .collect();
+
+        let metadata = Metadata {
+            block_metadata: BlockMetadata { block_num: number, ..Default::default() },
+            ..Default::default()
+        };
+
+        BlockData { metadata: Arc::new(metadata), tree: Arc::new(tree) }
+    }
+
+    /// Swap of weth for usdc on the pool, or back when `buy` is false
+    fn swap(from: Address, buy: bool) -> Action {
+        let (token_in, token_out) = if buy {
+            (TokenInfoWithAddress::weth(), TokenInfoWithAddress::usdc())
+        } else {
+            (TokenInfoWithAddress::usdc(), TokenInfoWithAddress::weth())
+        };
+
+        Action::Swap(NormalizedSwap {
+            protocol: Protocol::UniswapV2,
+            from,
+            recipient: from,
+            pool: POOL,
+            token_in,
+            token_out,
+            amount_in: Rational::from(1),
+            amount_out: Rational::from(3000),
+            ..Default::default()
+        })
+    }
+
+    fn victim(i: u8) -> Address {
+        Address::with_last_byte(100 + i)
+    }
+
+    /// Frontrun in block N, backrun as the first searcher tx of block N + 1
+    fn consecutive_block_sandwich() -> Vec<BlockData> {
+        vec![
+            block(
+                100,
+                vec![
+                    (1, victim(1), ROUTER),
+                    (2, SEARCHER, SEARCHER_CONTRACT),
+                    (3, victim(2), ROUTER),
+                ],
+            ),
+            block(
+                101,
+                vec![
+                    (4, victim(3), ROUTER),
+                    (5, SEARCHER, SEARCHER_CONTRACT),
+                    (6, victim(4), ROUTER),
+                ],
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_possible_multi_block_sandwiches() {
+        let sandwiches = get_possible_multi_block_sandwiches(&consecutive_block_sandwich());
+
+        assert_eq!(sandwiches.len(), 1);
+        let ps = &sandwiches[0];
+        assert_eq!(ps.eoa, SEARCHER);
+        assert_eq!(ps.mev_executor_contract, SEARCHER_CONTRACT);
+        assert_eq!(ps.possible_frontruns, vec![B256::with_last_byte(2)]);
+        assert_eq!(ps.possible_backrun, B256::with_last_byte(5));
+        assert_eq!(ps.victims, vec![vec![B256::with_last_byte(3), B256::with_last_byte(4)]]);
+    }
+
+    #[test]
+    fn test_possible_multi_block_sandwiches_without_victims() {
+        let blocks = vec![
+            block(100, vec![(1, victim(1), ROUTER), (2, SEARCHER, SEARCHER_CONTRACT)]),
+            block(101, vec![(3, SEARCHER, SEARCHER_CONTRACT), (4, victim(2), victim(3))]),
+        ];
+
+        assert!(get_possible_multi_block_sandwiches(&blocks).is_empty());
+        // a single block holds no multi block sandwiches
+        assert!(get_possible_multi_block_sandwiches(&blocks[1..]).is_empty());
+    }
+
+    /// Frontrun in block N and the backrun as the first searcher tx of block
+    /// N + 1, with a victim buying from the same pool in each block
+    fn consecutive_block_sandwich_with_swaps() -> Vec<BlockData> {
+        vec![
+            block_with_swaps(
+                100,
+                vec![
+                    (1, victim(1), ROUTER),
+                    (2, SEARCHER, SEARCHER_CONTRACT),
+                    (3, victim(2), ROUTER),
+                ],
+                &[(2, swap(SEARCHER_CONTRACT, true)), (3, swap(ROUTER, true))],
+            ),
+            block_with_swaps(
+                101,
+                vec![
+                    (4, victim(3), ROUTER),
+                    (5, SEARCHER, SEARCHER_CONTRACT),
+                    (6, victim(4), ROUTER),
+                ],
+                &[(4, swap(ROUTER, true)), (5, swap(SEARCHER_CONTRACT, false))],
+            ),
+        ]
+    }
+
+    #[brontes_macros::test]
+    async fn test_multi_block_sandwich_leg_block_numbers() {
+        let inspector_util = InspectorTestUtils::new(USDC_ADDRESS, 1.0).await;
+        let inspector = SandwichInspector::new(
+            USDC_ADDRESS,
+            inspector_util.classifier_inspector.trace_loader.libmdbx,
+            2,
+            None,
+        );
+
+        let bundles = inspector.inspect_block(MultiBlockData {
+            per_block_data: consecutive_block_sandwich_with_swaps(),
+            blocks:         2,
+        });
+
+        assert_eq!(bundles.len(), 1, "expected a single sandwich, got: {:#?}", bundles);
+        let BundleData::Sandwich(sandwich) = &bundles[0].data else {
+            panic!("expected a sandwich, got: {:#?}", bundles[0].data)
+        };
+
+        assert!(sandwich.is_multi_block());
+        assert_eq!(sandwich.block_number, 101);
+        assert_eq!(sandwich.frontrun_tx_hash, vec![B256::with_last_byte(2)]);
+        assert_eq!(sandwich.frontrun_block_numbers, vec![100]);
+        assert_eq!(
+            sandwich.victim_swaps_tx_hashes,
+            vec![vec![B256::with_last_byte(3), B256::with_last_byte(4)]]
+        );
+        assert_eq!(sandwich.backrun_tx_hash, B256::with_last_byte(5));
+        assert_eq!(sandwich.backrun_block_number, 101);
+    }
 }
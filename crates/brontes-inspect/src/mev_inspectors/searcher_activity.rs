@@ -37,6 +37,10 @@ impl<DB: LibmdbxReader> Inspector for SearcherActivity<'_, DB> {
         self.utils.quote
     }
 
+    fn get_metrics(&self) -> Option<&OutlierMetrics> {
+        self.utils.get_metrics()
+    }
+
     fn inspect_block(&self, mut data: MultiBlockData) -> Self::Result {
         let block = data.per_block_data.pop().expect("no blocks");
         let BlockData { metadata, tree } = block;
@@ -56,18 +60,23 @@ impl<DB: LibmdbxReader> SearcherActivity<'_, DB> {
         tree: Arc<BlockTree<Action>>,
         metadata: Arc<Metadata>,
     ) -> Vec<Bundle> {
-        let search_args = TreeSearchBuilder::default()
-            .with_actions([Action::is_transfer, Action::is_eth_transfer]);
+        let search_args = TreeSearchBuilder::default().with_actions([
+            Action::is_transfer,
+            Action::is_eth_transfer,
+            Action::is_lending,
+        ]);
 
-        let (hashes, transfers): (Vec<_>, Vec<_>) = tree.clone().collect_all(search_args).unzip();
+        let (hashes, actions): (Vec<_>, Vec<_>) = tree.clone().collect_all(search_args).unzip();
         let tx_info = tree.get_tx_info_batch(&hashes, self.utils.db);
 
-        multizip((hashes, transfers, tx_info))
-            .filter_map(|(tx_hash, transfers, info)| {
-                if transfers.is_empty() {
+        multizip((hashes, actions, tx_info))
+            .filter_map(|(tx_hash, actions, info)| {
+                if actions.is_empty() {
                     return None
                 }
                 let info = info?;
+                let (lending, transfers): (Vec<_>, Vec<_>) =
+                    actions.into_iter().partition(|action| action.is_lending());
 
                 (info.searcher_eoa_info.is_some() || info.searcher_contract_info.is_some()).then(
                     || {
@@ -129,6 +138,21 @@ impl<DB: LibmdbxReader> SearcherActivity<'_, DB> {
                                 transfers: transfers
                                     .into_iter()
                                     .collect_action_vec(Action::try_transfer),
+                                loans: lending
+                                    .clone()
+                                    .into_iter()
+                                    .collect_action_vec(Action::try_loan),
+                                repayments: lending
+                                    .clone()
+                                    .into_iter()
+                                    .collect_action_vec(Action::try_repayment),
+                                supplies: lending
+                                    .clone()
+                                    .into_iter()
+                                    .collect_action_vec(Action::try_supply),
+                                withdrawals: lending
+                                    .into_iter()
+                                    .collect_action_vec(Action::try_withdraw),
                             }),
                         })
                     },
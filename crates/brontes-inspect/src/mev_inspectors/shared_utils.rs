@@ -5,6 +5,7 @@ use brontes_database::libmdbx::LibmdbxReader;
 use brontes_metrics::inspectors::OutlierMetrics;
 use brontes_types::{
     db::{
+        cex::CexFeeSchedule,
         dex::{BlockPrice, PriceAt},
         metadata::Metadata,
         token_info::TokenInfoWithAddress,
@@ -55,14 +56,16 @@ impl<DB: LibmdbxReader> SharedInspectorUtils<'_, DB> {
         self.metrics.as_ref()
     }
 
-    /// Calculates the USD value of the token balance deltas by address
+    /// Calculates the USD value of the token balance deltas by address. With
+    /// `cex_fees` the tokens are priced with the cex quotes, fee adjusted with
+    /// the schedule, instead of the dex quotes
     pub fn usd_delta_by_address(
         &self,
         tx_position: u64,
         at: PriceAt,
         deltas: &AddressDeltas,
         metadata: Arc<Metadata>,
-        cex: bool,
+        cex_fees: Option<&CexFeeSchedule>,
         at_or_before: bool,
         filter_fn: impl Fn(&Address, Option<Rational>) -> Option<Rational>,
     ) -> Option<FastHashMap<Address, Rational>> {
@@ -75,13 +78,14 @@ impl<DB: LibmdbxReader> SharedInspectorUtils<'_, DB> {
                 }
 
                 let pair = Pair(*token_addr, self.quote);
-                let price = if cex {
+                let price = if let Some(fee_schedule) = cex_fees {
                     metadata
                         .cex_quotes
                         .get_quote_from_most_liquid_exchange(
                             &pair,
                             metadata.microseconds_block_timestamp(),
                             Some(1_000_000),
+                            fee_schedule,
                         )?
                         .price_maker
                         .1
@@ -439,7 +443,7 @@ impl<DB: LibmdbxReader> SharedInspectorUtils<'_, DB> {
             at,
             deltas,
             metadata.clone(),
-            false,
+            None,
             at_or_before,
             |address, price| {
                 // if not mev address, we just zero
@@ -12,7 +12,10 @@ use brontes_types::{
 use criterion::{black_box, Criterion};
 
 use super::InspectorTestUtilsError;
-use crate::{composer::run_block_inspection, Inspectors};
+use crate::{
+    composer::{run_block_inspection, MevFilters},
+    Inspectors,
+};
 
 pub struct InspectorBenchUtils {
     classifier_inspector: ClassifierTestUtils,
@@ -47,7 +50,9 @@ impl InspectorBenchUtils {
                     self.quote_address,
                     self.classifier_inspector.libmdbx,
                     &[CexExchange::Binance],
-                    CexDexTradeConfig::default(),
+                    CexDexTradeConfig::new(self.classifier_inspector.libmdbx.cex_fee_schedule()),
+                    1,
+                    None,
                     None,
                 )
             })
@@ -98,7 +103,9 @@ impl InspectorBenchUtils {
             self.quote_address,
             self.classifier_inspector.libmdbx,
             &[CexExchange::Binance],
-            CexDexTradeConfig::default(),
+            CexDexTradeConfig::new(self.classifier_inspector.libmdbx.cex_fee_schedule()),
+            1,
+            None,
             None,
         );
 
@@ -161,7 +168,9 @@ impl InspectorBenchUtils {
             self.quote_address,
             self.classifier_inspector.libmdbx,
             &[CexExchange::Binance],
-            CexDexTradeConfig::default(),
+            CexDexTradeConfig::new(self.classifier_inspector.libmdbx.cex_fee_schedule()),
+            1,
+            None,
             None,
         );
 
@@ -215,7 +224,9 @@ impl InspectorBenchUtils {
             self.quote_address,
             self.classifier_inspector.libmdbx,
             &[CexExchange::Binance],
-            CexDexTradeConfig::default(),
+            CexDexTradeConfig::new(self.classifier_inspector.libmdbx.cex_fee_schedule()),
+            1,
+            None,
             None,
         );
 
@@ -262,7 +273,9 @@ impl InspectorBenchUtils {
                     self.quote_address,
                     self.classifier_inspector.libmdbx,
                     &[CexExchange::Binance],
-                    CexDexTradeConfig::default(),
+                    CexDexTradeConfig::new(self.classifier_inspector.libmdbx.cex_fee_schedule()),
+                    1,
+                    None,
                     None,
                 )
             })
@@ -294,10 +307,16 @@ impl InspectorBenchUtils {
         let multi = MultiBlockData { per_block_data: vec![data], blocks: 1 };
 
         let db = self.classifier_inspector.trace_loader.libmdbx;
+        let filters = MevFilters::default();
         c.bench_function(bench_name, move |b| {
             b.iter(|| {
                 for _ in 0..=iters {
-                    black_box(run_block_inspection(inspectors.as_slice(), multi.clone(), db));
+                    black_box(run_block_inspection(
+                        inspectors.as_slice(),
+                        &filters,
+                        multi.clone(),
+                        db,
+                    ));
                 }
             });
         });
@@ -321,7 +340,9 @@ impl InspectorBenchUtils {
                     self.quote_address,
                     self.classifier_inspector.libmdbx,
                     &[CexExchange::Binance],
-                    CexDexTradeConfig::default(),
+                    CexDexTradeConfig::new(self.classifier_inspector.libmdbx.cex_fee_schedule()),
+                    1,
+                    None,
                     None,
                 )
             })
@@ -345,10 +366,16 @@ impl InspectorBenchUtils {
         let data = BlockData { metadata, tree };
         let multi = MultiBlockData { per_block_data: vec![data], blocks: 1 };
         let db = self.classifier_inspector.trace_loader.libmdbx;
+        let filters = MevFilters::default();
         c.bench_function(bench_name, move |b| {
             b.iter(|| {
                 for _ in 0..=iters {
-                    black_box(run_block_inspection(inspectors.as_slice(), multi.clone(), db));
+                    black_box(run_block_inspection(
+                        inspectors.as_slice(),
+                        &filters,
+                        multi.clone(),
+                        db,
+                    ));
                 }
             });
         });
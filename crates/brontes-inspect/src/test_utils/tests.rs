@@ -24,6 +24,7 @@
 use alloy_primitives::{Address, TxHash};
 use brontes_classifier::test_utils::{ClassifierTestUtils, ClassifierTestUtilsError};
 use brontes_core::{LibmdbxReadWriter, TraceLoaderError};
+use brontes_metrics::inspectors::OutlierMetrics;
 pub use brontes_types::constants::*;
 use brontes_types::{
     db::{
@@ -38,7 +39,11 @@ use brontes_types::{
 };
 use thiserror::Error;
 
-use crate::{composer::run_block_inspection, shared_utils::SharedInspectorUtils, Inspectors};
+use crate::{
+    composer::{run_block_inspection, MevFilters},
+    shared_utils::SharedInspectorUtils,
+    Inspectors,
+};
 
 type StateTests = Option<Box<dyn for<'a> Fn(&'a Bundle)>>;
 
@@ -111,6 +116,16 @@ impl InspectorTestUtils {
     pub async fn assert_no_mev(
         &self,
         config: InspectorTxRunConfig,
+    ) -> Result<(), InspectorTestUtilsError> {
+        self.assert_no_mev_with_metrics(config, None).await
+    }
+
+    /// Same as [`Self::assert_no_mev`], recording the inspector metrics to
+    /// check why the possible mev was rejected
+    pub async fn assert_no_mev_with_metrics(
+        &self,
+        config: InspectorTxRunConfig,
+        metrics: Option<OutlierMetrics>,
     ) -> Result<(), InspectorTestUtilsError> {
         let copied = config.clone();
         let err = || InspectorTestUtilsError::InspectorConfig(Box::new(copied.clone()));
@@ -167,8 +182,10 @@ impl InspectorTestUtils {
                 CexExchange::BybitSpot,
                 CexExchange::Kucoin,
             ],
-            CexDexTradeConfig::default(),
+            CexDexTradeConfig::new(self.classifier_inspector.libmdbx.cex_fee_schedule()),
+            1,
             None,
+            metrics,
         );
         let data = BlockData { metadata: metadata.into(), tree: tree.into() };
         let multi = MultiBlockData { per_block_data: vec![data], blocks: 1 };
@@ -240,7 +257,8 @@ impl InspectorTestUtils {
             panic!("no dex quotes found in metadata. test suite will fail");
         }
 
-        let mut cex_trade_config = CexDexTradeConfig::default();
+        let mut cex_trade_config =
+            CexDexTradeConfig::new(self.classifier_inspector.libmdbx.cex_fee_schedule());
 
         if config.use_block_time_weights_for_cex_pricing {
             cex_trade_config.with_block_time_weights();
@@ -258,6 +276,8 @@ impl InspectorTestUtils {
                 CexExchange::Upbit,
             ],
             cex_trade_config,
+            1,
+            None,
             None,
         );
 
@@ -366,7 +386,9 @@ impl InspectorTestUtils {
                     self.quote_address,
                     self.classifier_inspector.libmdbx,
                     &[CexExchange::Binance],
-                    CexDexTradeConfig::default(),
+                    CexDexTradeConfig::new(self.classifier_inspector.libmdbx.cex_fee_schedule()),
+                    1,
+                    None,
                     None,
                 )
             })
@@ -375,7 +397,7 @@ impl InspectorTestUtils {
         let data = BlockData { metadata: metadata.into(), tree: tree.into() };
         let multi = MultiBlockData { blocks: 1, per_block_data: vec![data] };
 
-        let results = run_block_inspection(inspector.as_slice(), multi, db);
+        let results = run_block_inspection(inspector.as_slice(), &MevFilters::default(), multi, db);
 
         let mut results = results
             .mev_details
@@ -74,6 +74,16 @@ impl ActionMacro {
             == "poolconfigupdate"
         {
             quote!(Ok(::brontes_pricing::types::DexPriceMsg::DiscoveredPool(result)))
+        } else if action_type == "Action" {
+            // the closure picks the action variant itself
+            quote!(Ok(::brontes_pricing::types::DexPriceMsg::Update(
+                ::brontes_pricing::types::PoolUpdate {
+                    block,
+                    tx_idx,
+                    logs: call_info.logs.clone().to_vec(),
+                    action: result
+                },
+            )))
         } else {
             quote!(
                 Ok(::brontes_pricing::types::DexPriceMsg::Update(
@@ -79,6 +79,11 @@ use crate::action_classifier::{ActionDispatch, ActionMacro};
 /// SpecialTransfer)*
 ///
 ///
+/// # Dynamic Action Type
+/// if the action a call produces depends on its input, e.g. a single call that
+/// can both add and remove liquidity, pass `Action` as the call type. The
+/// closure then returns the full `Action` instead of a normalized action.
+///
 /// the fields `call_data`, `return_data` and `log_data` are only put into the
 /// closure if specified they are always in this order, for example if you put
 ///  
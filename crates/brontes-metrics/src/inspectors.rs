@@ -3,7 +3,7 @@ use std::{pin::Pin, time::Instant};
 use brontes_types::{mev::MevType, pair::Pair, FastHashMap};
 use dashmap::DashMap;
 use metrics::{Counter, Gauge};
-use prometheus::{HistogramVec, IntCounterVec};
+use prometheus::{core::Collector, HistogramVec, IntCounterVec};
 use reth_metrics::Metrics;
 use reth_primitives::Address;
 
@@ -15,6 +15,10 @@ pub struct OutlierMetrics {
     pub dex_bad_pricing:           IntCounterVec,
     pub inspector_100x_price_type: IntCounterVec,
     pub branch_filtering_trigger:  IntCounterVec,
+    // hit rates
+    inspector_candidates:          IntCounterVec,
+    inspector_bundles:             IntCounterVec,
+    dedup_dropped:                 IntCounterVec,
     // runtimes
     inspector_runtime:             HistogramVec,
     cex_dex_price_speed:           HistogramVec,
@@ -56,6 +60,28 @@ impl OutlierMetrics {
         )
         .unwrap();
 
+        let inspector_candidates = prometheus::register_int_counter_vec!(
+            "brontes_inspector_candidates",
+            "the transactions an inspector validated as a possible mev, compare with \
+             brontes_branch_filtering_trigger for the rejection rate of each branch",
+            &["mev_type"]
+        )
+        .unwrap();
+
+        let inspector_bundles = prometheus::register_int_counter_vec!(
+            "brontes_inspector_bundles_found",
+            "the bundles found by each inspector before deduplication",
+            &["inspector", "mev_type"]
+        )
+        .unwrap();
+
+        let dedup_dropped = prometheus::register_int_counter_vec!(
+            "brontes_dedup_dropped_bundles",
+            "the bundles dropped by each precedence rule of the composer",
+            &["rule", "dominant_mev_type", "dropped_mev_type"]
+        )
+        .unwrap();
+
         let buckets = prometheus::exponential_buckets(1.0, 2.0, 22).unwrap();
 
         let inspector_runtime = prometheus::register_histogram_vec!(
@@ -81,6 +107,9 @@ impl OutlierMetrics {
             dex_bad_pricing,
             cex_pair_symbols,
             cex_dex_price_speed,
+            inspector_candidates,
+            inspector_bundles,
+            dedup_dropped,
         }
     }
 
@@ -113,11 +142,12 @@ impl OutlierMetrics {
     pub fn run_inspector<R>(&self, inspector_type: MevType, f: impl FnOnce() -> R) -> R {
         let instant = Instant::now();
         let res = f();
-        let elapsed = instant.elapsed().as_millis();
+        // most blocks take less than a millisecond, keep the fraction
+        let elapsed = instant.elapsed().as_secs_f64() * 1000.0;
 
         self.inspector_runtime
             .with_label_values(&[inspector_type.as_ref()])
-            .observe(elapsed as f64);
+            .observe(elapsed);
         res
     }
 
@@ -159,6 +189,70 @@ impl OutlierMetrics {
             .unwrap()
             .inc();
     }
+
+    pub fn inspector_candidate(&self, mev_type: MevType) {
+        let t = mev_type.to_string();
+
+        self.inspector_candidates
+            .get_metric_with_label_values(&[&t])
+            .unwrap()
+            .inc();
+    }
+
+    /// The possible mev of the type the inspector validated
+    pub fn candidates(&self, mev_type: MevType) -> u64 {
+        let t = mev_type.to_string();
+
+        self.inspector_candidates
+            .get_metric_with_label_values(&[&t])
+            .unwrap()
+            .get()
+    }
+
+    /// The possible mev of the type rejected by each branch
+    pub fn branch_filtered(&self, mev_type: MevType) -> FastHashMap<String, u64> {
+        let t = mev_type.to_string();
+
+        self.branch_filtering_trigger
+            .collect()
+            .iter()
+            .flat_map(|family| family.get_metric())
+            .filter_map(|metric| {
+                let label = |name: &str| {
+                    metric
+                        .get_label()
+                        .iter()
+                        .find(|label| label.get_name() == name)
+                        .map(|label| label.get_value())
+                };
+                if label("mev_type")? != t {
+                    return None
+                }
+
+                Some((label("branch_name")?.to_string(), metric.get_counter().get_value() as u64))
+            })
+            .collect()
+    }
+
+    pub fn bundles_found(&self, inspector: &str, mev_type: MevType, count: usize) {
+        let t = mev_type.to_string();
+
+        self.inspector_bundles
+            .get_metric_with_label_values(&[inspector, &t])
+            .unwrap()
+            .inc_by(count as u64);
+    }
+
+    pub fn dedup_dropped(&self, rule: usize, dominant: MevType, dropped: MevType) {
+        let rule = rule.to_string();
+        let dominant = dominant.to_string();
+        let dropped = dropped.to_string();
+
+        self.dedup_dropped
+            .get_metric_with_label_values(&[&rule, &dominant, &dropped])
+            .unwrap()
+            .inc();
+    }
 }
 
 impl std::fmt::Debug for OutlierMetrics {
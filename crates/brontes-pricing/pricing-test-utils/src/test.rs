@@ -44,16 +44,25 @@ impl PricingTestUtils {
                 .protocols_created_range(block + 1, end_block)
                 .unwrap()
                 .into_iter()
-                .flat_map(|(_, pools)| {
-                    pools
-                        .into_iter()
-                        .map(|(addr, protocol, pair)| (addr, (protocol, pair)))
-                        .collect::<Vec<_>>()
-                })
-                .collect::<FastHashMap<_, _>>()
+                .flat_map(|(_, pools)| pools)
+                .fold(
+                    FastHashMap::<_, (_, Vec<_>)>::default(),
+                    |mut acc, (addr, protocol, pair)| {
+                        acc.entry(addr)
+                            .or_insert_with(|| (protocol, vec![]))
+                            .1
+                            .push(pair);
+                        acc
+                    },
+                )
         } else {
             FastHashMap::default()
         };
+        let pool_keys = self
+            .tracer
+            .libmdbx
+            .fetch_uniswap_v4_pool_keys()
+            .map_err(|_| PricingTestError::LibmdbxError)?;
         let ex = BrontesTaskManager::current().executor();
         Ok(BrontesBatchPricer::new(
             0,
@@ -64,6 +73,7 @@ impl PricingTestUtils {
             self.tracer.get_provider(),
             block,
             created_pools,
+            pool_keys,
             Arc::new(AtomicBool::new(false)),
             None,
             ex,
@@ -72,7 +72,7 @@ pub struct AllPairGraph {
 }
 
 impl AllPairGraph {
-    pub fn init_from_hash_map(all_pool_data: FastHashMap<(Address, Protocol), Pair>) -> Self {
+    pub fn init_from_hash_map(all_pool_data: FastHashMap<(Address, Protocol), Vec<Pair>>) -> Self {
         let mut graph = UnGraph::<(), Vec<EdgeWithInsertBlock>, usize>::default();
 
         let mut token_to_index = FastHashMap::default();
@@ -83,6 +83,7 @@ impl AllPairGraph {
 
         all_pool_data
             .into_iter()
+            .flat_map(|(pool, pairs)| pairs.into_iter().map(move |pair| (pool, pair)))
             .sorted()
             .for_each(|((pool_addr, dex), pair)| {
                 if !dex.has_state_updater() {
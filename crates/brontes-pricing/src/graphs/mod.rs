@@ -68,7 +68,7 @@ pub struct GraphManager {
 
 impl GraphManager {
     pub fn init_from_db_state(
-        all_pool_data: FastHashMap<(Address, Protocol), Pair>,
+        all_pool_data: FastHashMap<(Address, Protocol), Vec<Pair>>,
         metrics: Option<DexPricingMetrics>,
     ) -> Self {
         let graph = AllPairGraph::init_from_hash_map(all_pool_data);
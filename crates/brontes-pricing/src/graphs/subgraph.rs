@@ -202,7 +202,7 @@ impl PairSubGraph {
             .edge_weights()
             .flat_map(|weight| {
                 weight.iter().filter_map(|edge| {
-                    let (r0, r1) = state.get(&edge.pool_addr)?.tvl(edge.token_0);
+                    let (r0, r1) = state.get(&edge.pool_addr)?.tvl(edge.token_0, edge.token_1);
                     let tvl_added = r0 + r1;
 
                     Some((edge.pool_addr, tvl_added))
@@ -222,7 +222,10 @@ impl PairSubGraph {
                 weight
                     .iter()
                     .map(|edge| {
-                        let (r0, r1) = state.get(&edge.pool_addr).unwrap().tvl(edge.token_0);
+                        let (r0, r1) = state
+                            .get(&edge.pool_addr)
+                            .unwrap()
+                            .tvl(edge.token_0, edge.token_1);
                         let tvl_added = r0 + r1;
                         let start_tvl = self.start_nodes_liq.get(&edge.pool_addr).unwrap();
 
@@ -499,14 +502,14 @@ impl PairSubGraph {
                         continue;
                     };
 
-                    let Ok(pool_price) =
-                        pool_state.price(info.get_token_with_direction(is_outgoing))
-                    else {
+                    let base = info.get_token_with_direction(is_outgoing);
+                    let quote = info.get_token_with_direction(!is_outgoing);
+                    let Ok(pool_price) = pool_state.price(base, quote) else {
                         Self::bad_state(pair, info, Rational::ZERO, &mut removal_map.removal_state);
                         continue;
                     };
 
-                    let (t0, t1) = pool_state.tvl(info.get_token_with_direction(is_outgoing));
+                    let (t0, t1) = pool_state.tvl(base, quote);
                     let liq0 = prev_price.clone().reciprocal() * &t0;
 
                     let goes_through_arg = if ignore_goes_through {
@@ -893,7 +896,7 @@ impl PairSubGraph {
 
                 for pool in pools {
                     let Some(pool_e) = state.get(&pool.pool_addr) else { continue };
-                    let (_, quote) = pool_e.tvl(pool.get_base_token());
+                    let (_, quote) = pool_e.tvl(pool.get_base_token(), pool.get_quote_token());
                     if min_liq > quote {
                         min_liq = quote;
                     }
@@ -955,11 +958,12 @@ impl PairSubGraph {
                         continue;
                     };
 
-                    let Ok(pool_price) = pool_state.price(info.get_base_token()) else {
+                    let (base, quote) = (info.get_base_token(), info.get_quote_token());
+                    let Ok(pool_price) = pool_state.price(base, quote) else {
                         continue;
                     };
 
-                    let (t0, t1) = pool_state.tvl(info.get_base_token());
+                    let (t0, t1) = pool_state.tvl(base, quote);
 
                     let t0xt1 = &t0 * &t1;
                     pxw += pool_price * &t0xt1;
@@ -1097,11 +1101,15 @@ pub mod test {
     }
 
     impl ProtocolState for MockPoolState {
-        fn price(&self, _base: Address) -> Result<Rational, crate::errors::ArithmeticError> {
+        fn price(
+            &self,
+            _base: Address,
+            _quote: Address,
+        ) -> Result<Rational, crate::errors::ArithmeticError> {
             Ok(self.price.clone())
         }
 
-        fn tvl(&self, _base: Address) -> (Rational, Rational) {
+        fn tvl(&self, _base: Address, _quote: Address) -> (Rational, Rational) {
             self.tvl.clone()
         }
     }
@@ -22,7 +22,9 @@
 
 use brontes_metrics::pricing::DexPricingMetrics;
 use brontes_types::{
-    db::dex::PriceAt, execute_on, normalized_actions::pool::NormalizedPoolConfigUpdate,
+    db::{dex::PriceAt, uniswap_v4_pool_key::UniswapV4PoolKey},
+    execute_on,
+    normalized_actions::pool::NormalizedPoolConfigUpdate,
     BrontesTaskExecutor, UnboundedYapperReceiver,
 };
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
@@ -92,8 +94,9 @@ pub struct BrontesBatchPricer<T: TracingProvider> {
     buffer:          StateBuffer,
     /// holds new graph nodes / edges that can be added at every given block.
     /// this is done to ensure any route from a base to our quote asset will
-    /// only pass though valid created pools.
-    new_graph_pairs: FastHashMap<Address, (Protocol, Pair)>,
+    /// only pass though valid created pools. pools with more than two tokens
+    /// have an edge for every pair of them.
+    new_graph_pairs: FastHashMap<Address, (Protocol, Vec<Pair>)>,
     /// manages all graph related items
     graph_manager:   GraphManager,
     /// lazy loads dex pairs so we only fetch init state that is needed
@@ -120,11 +123,17 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
         update_rx: UnboundedYapperReceiver<DexPriceMsg>,
         provider: Arc<T>,
         current_block: u64,
-        new_graph_pairs: FastHashMap<Address, (Protocol, Pair)>,
+        new_graph_pairs: FastHashMap<Address, (Protocol, Vec<Pair>)>,
+        uniswap_v4_pool_keys: FastHashMap<Address, UniswapV4PoolKey>,
         needs_more_data: Arc<AtomicBool>,
         metrics: Option<DexPricingMetrics>,
         executor: BrontesTaskExecutor,
     ) -> Self {
+        let pool_keys = uniswap_v4_pool_keys
+            .into_iter()
+            .map(|(address, key)| (address, key.into()))
+            .collect();
+
         Self {
             range_id,
             finished,
@@ -135,7 +144,7 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
             update_rx,
             graph_manager,
             dex_quotes: FastHashMap::default(),
-            lazy_loader: LazyExchangeLoader::new(provider, executor),
+            lazy_loader: LazyExchangeLoader::new(provider, executor, pool_keys),
             current_block,
             completed_block: current_block,
             overlap_update: None,
@@ -199,12 +208,14 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
         updates
             .iter()
             .filter_map(|update| {
-                let (protocol, pair) = self.new_graph_pairs.remove(&update.get_pool_address())?;
-                Some((update.get_pool_address(), protocol, pair, update.block))
+                let (protocol, pairs) = self.new_graph_pairs.remove(&update.get_pool_address())?;
+                Some((update.get_pool_address(), protocol, pairs, update.block))
             })
-            .for_each(|(pool_addr, protocol, pair, block)| {
-                self.graph_manager
-                    .add_pool(pair, pool_addr, protocol, block);
+            .for_each(|(pool_addr, protocol, pairs, block)| {
+                for pair in pairs {
+                    self.graph_manager
+                        .add_pool(pair, pool_addr, protocol, block);
+                }
             });
 
         updates.iter().for_each(|msg| {
@@ -269,6 +280,18 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
         );
     }
 
+    /// Queues an edge of the pool to be added to the graph on its next update
+    fn add_new_graph_pair(&mut self, pool: Address, protocol: Protocol, pair: Pair) {
+        let (_, pairs) = self
+            .new_graph_pairs
+            .entry(pool)
+            .or_insert_with(|| (protocol, vec![]));
+
+        if !pairs.contains(&pair) {
+            pairs.push(pair);
+        }
+    }
+
     #[brontes_macros::metrics_call(ptr=metrics,function_call_count, self.range_id, "pool_updates_no_pricing")]
     fn on_pool_update_no_pricing(&mut self, updates: Vec<PoolUpdate>) {
         if let Some(msg) = updates.first() {
@@ -280,12 +303,14 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
         updates
             .iter()
             .filter_map(|update| {
-                let (protocol, pair) = self.new_graph_pairs.remove(&update.get_pool_address())?;
-                Some((update.get_pool_address(), protocol, pair, update.block))
+                let (protocol, pairs) = self.new_graph_pairs.remove(&update.get_pool_address())?;
+                Some((update.get_pool_address(), protocol, pairs, update.block))
             })
-            .for_each(|(pool_addr, protocol, pair, block)| {
-                self.graph_manager
-                    .add_pool(pair, pool_addr, protocol, block);
+            .for_each(|(pool_addr, protocol, pairs, block)| {
+                for pair in pairs {
+                    self.graph_manager
+                        .add_pool(pair, pool_addr, protocol, block);
+                }
             });
 
         updates.into_iter().for_each(|update| {
@@ -603,8 +628,7 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
                     ..
                 } = load_result
                 {
-                    self.new_graph_pairs
-                        .insert(pool_address, (protocol, pool_pair));
+                    self.add_new_graph_pair(pool_address, protocol, pool_pair);
                     self.graph_manager
                         .remove_pair_graph_address(pool_pair, pool_address);
 
@@ -666,7 +690,7 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
                                 .graph_manager
                                 .remove_pair_graph_address(bad_edge.pair, bad_edge.pool_address)
                             {
-                                self.new_graph_pairs.insert(addr, (protocol, pair));
+                                self.add_new_graph_pair(addr, protocol, pair);
                             }
                         }
                     });
@@ -682,7 +706,7 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
                                 .graph_manager
                                 .remove_pair_graph_address(bad_edge.pair, bad_edge.pool_address)
                             {
-                                self.new_graph_pairs.insert(addr, (protocol, pair));
+                                self.add_new_graph_pair(addr, protocol, pair);
                             }
                         }
                     });
@@ -1249,11 +1273,15 @@ impl<T: TracingProvider> Stream for BrontesBatchPricer<T> {
                             protocol,
                             tokens,
                             pool_address,
+                            pool_key,
                             ..
                         }) => {
+                            if let Some(key) = pool_key {
+                                self.lazy_loader.add_pool_key(pool_address, key.into());
+                            }
                             if protocol.has_state_updater() {
                                 self.new_graph_pairs
-                                    .insert(pool_address, (protocol, Pair(tokens[0], tokens[1])));
+                                    .insert(pool_address, (protocol, Pair::all_pairs(&tokens)));
                             };
                             Some(PollResult::DiscoveredPool)
                         }
@@ -0,0 +1,163 @@
+pub mod stable;
+pub mod weighted;
+
+use std::sync::Arc;
+
+use alloy_primitives::{hex, Address, FixedBytes};
+use alloy_sol_macro::sol;
+use brontes_types::{pair::Pair, traits::TracingProvider, ToScaledRational};
+pub use stable::BalancerV2StablePool;
+pub use weighted::BalancerV2WeightedPool;
+
+use super::{make_call_request, multi_token::MultiTokenBalances};
+use crate::{errors::AmmError, types::PoolVariants};
+
+pub const BALANCER_V2_VAULT: Address =
+    Address::new(hex!("BA12222222228d8Ba445958a75a0704d566BF2C8"));
+
+sol!(
+    interface IBalancerV2Vault {
+        function getPoolTokens(bytes32 poolId) external view returns (
+            address[] tokens,
+            uint256[] balances,
+            uint256 lastChangeBlock
+        );
+    }
+);
+
+sol!(
+    interface IBalancerV2Pool {
+        function getPoolId() external view returns (bytes32);
+        function getSwapFeePercentage() external view returns (uint256);
+        function getNormalizedWeights() external view returns (uint256[]);
+        function getAmplificationParameter() external view returns (
+            uint256 value,
+            bool isUpdating,
+            uint256 precision
+        );
+        function getScalingFactors() external view returns (uint256[]);
+    }
+);
+
+/// Loads the pool id and the vault balances of a pool.
+pub async fn load_balances<M: TracingProvider>(
+    address: Address,
+    block: u64,
+    middleware: &Arc<M>,
+) -> Result<(FixedBytes<32>, MultiTokenBalances), AmmError> {
+    let pool_id = make_call_request(
+        IBalancerV2Pool::getPoolIdCall::new(()),
+        middleware,
+        address,
+        Some(block),
+    )
+    .await?
+    ._0;
+
+    let pool_tokens = make_call_request(
+        IBalancerV2Vault::getPoolTokensCall::new((pool_id,)),
+        middleware,
+        BALANCER_V2_VAULT,
+        Some(block),
+    )
+    .await?;
+
+    let mut decimals = Vec::with_capacity(pool_tokens.tokens.len());
+    let mut balances = Vec::with_capacity(pool_tokens.tokens.len());
+
+    for (token, balance) in pool_tokens.tokens.iter().zip(pool_tokens.balances) {
+        let dec = MultiTokenBalances::fetch_decimals(*token, block, middleware).await?;
+        decimals.push(dec);
+        balances.push(balance.to_scaled_rational(dec));
+    }
+
+    Ok((pool_id, MultiTokenBalances::new(pool_tokens.tokens, decimals, balances)))
+}
+
+/// Balancer V2 pools share the same protocol. Weighted pools are tried first
+/// and stable pools are used as a fallback as they expose the amplification
+/// parameter instead of weights.
+pub async fn load_balancer_v2_pool<M: TracingProvider>(
+    address: Address,
+    block: u64,
+    edge: Pair,
+    middleware: Arc<M>,
+) -> Result<PoolVariants, AmmError> {
+    let (pool_id, balances) = load_balances(address, block, &middleware).await?;
+
+    let swap_fee = make_call_request(
+        IBalancerV2Pool::getSwapFeePercentageCall::new(()),
+        &middleware,
+        address,
+        Some(block),
+    )
+    .await?
+    ._0
+    .to_scaled_rational(18);
+
+    if let Ok(weights) = make_call_request(
+        IBalancerV2Pool::getNormalizedWeightsCall::new(()),
+        &middleware,
+        address,
+        Some(block),
+    )
+    .await
+    {
+        let pool = BalancerV2WeightedPool::new(
+            address,
+            pool_id,
+            balances,
+            weights
+                ._0
+                .into_iter()
+                .map(|w| w.to_scaled_rational(18))
+                .collect(),
+            swap_fee,
+            edge,
+        );
+        if !pool.data_is_populated() {
+            return Err(AmmError::NoStateError(address))
+        }
+
+        return Ok(PoolVariants::BalancerV2Weighted(Box::new(pool)))
+    }
+
+    let amp = make_call_request(
+        IBalancerV2Pool::getAmplificationParameterCall::new(()),
+        &middleware,
+        address,
+        Some(block),
+    )
+    .await?;
+    let scaling_factors = make_call_request(
+        IBalancerV2Pool::getScalingFactorsCall::new(()),
+        &middleware,
+        address,
+        Some(block),
+    )
+    .await?
+    ._0;
+
+    // scaling factors are 1e18 fixed point and fold the decimal scaling together
+    // with the rate provider value, we only want the rate
+    let rates = scaling_factors
+        .into_iter()
+        .zip(&balances.decimals)
+        .map(|(factor, decimals)| factor.to_scaled_rational(36 - (*decimals).min(18)))
+        .collect();
+
+    let pool = BalancerV2StablePool::new(
+        address,
+        pool_id,
+        balances,
+        amp.value.to_scaled_rational(0) / amp.precision.to_scaled_rational(0),
+        rates,
+        swap_fee,
+        edge,
+    );
+    if !pool.data_is_populated() {
+        return Err(AmmError::NoStateError(address))
+    }
+
+    Ok(PoolVariants::BalancerV2Stable(Box::new(pool)))
+}
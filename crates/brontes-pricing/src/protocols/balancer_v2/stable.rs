@@ -0,0 +1,118 @@
+use alloy_primitives::{Address, FixedBytes, Log};
+use async_trait::async_trait;
+use brontes_types::{normalized_actions::Action, pair::Pair, ToFloatNearest};
+use malachite::Rational;
+
+use crate::{
+    errors::{AmmError, ArithmeticError, EventLogError},
+    multi_token::MultiTokenBalances,
+    stable_math, UpdatableProtocol,
+};
+
+/// Balancer V2 stable, meta stable and composable stable pools. Pricing uses
+/// the StableSwap invariant over the rate adjusted balances.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BalancerV2StablePool {
+    pub address:  Address,
+    pub pool_id:  FixedBytes<32>,
+    pub balances: MultiTokenBalances,
+    /// amplification parameter without precision
+    pub amp:      Rational,
+    /// rate provider value for each token, 1 for tokens without a provider
+    pub rates:    Vec<Rational>,
+    pub swap_fee: Rational,
+    /// the token pair this pool is registered with in the token graph
+    pub edge:     Pair,
+}
+
+#[async_trait]
+impl UpdatableProtocol for BalancerV2StablePool {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn tokens(&self) -> Vec<Address> {
+        self.balances.tokens.clone()
+    }
+
+    fn calculate_price(&self, base_token: Address) -> Result<Rational, ArithmeticError> {
+        self.calculate_pair_price(base_token, MultiTokenBalances::edge_quote(self.edge, base_token))
+    }
+
+    fn sync_from_action(&mut self, action: Action) -> Result<(), AmmError> {
+        self.balances.apply_action(action)
+    }
+
+    fn sync_from_log(&mut self, _log: Log) -> Result<(), AmmError> {
+        // balances live in the vault, we sync from the classified actions instead
+        Err(AmmError::EventLogError(EventLogError::InvalidEventSignature))
+    }
+}
+
+impl BalancerV2StablePool {
+    pub fn new(
+        address: Address,
+        pool_id: FixedBytes<32>,
+        balances: MultiTokenBalances,
+        amp: Rational,
+        rates: Vec<Rational>,
+        swap_fee: Rational,
+        edge: Pair,
+    ) -> Self {
+        Self { address, pool_id, balances, amp, rates, swap_fee, edge }
+    }
+
+    pub fn data_is_populated(&self) -> bool {
+        self.balances.is_populated() && self.rates.len() == self.balances.tokens.len()
+    }
+
+    /// Amount of `quote` received for one unit of `base`, excluding fees.
+    pub fn spot_price(&self, base: usize, quote: usize) -> Result<Rational, ArithmeticError> {
+        // composable stable pools hold their own bpt, it isn't part of the invariant
+        let (indexes, balances): (Vec<_>, Vec<_>) = self
+            .balances
+            .tokens
+            .iter()
+            .enumerate()
+            .filter(|(_, token)| **token != self.address)
+            .map(|(i, _)| (i, (&self.balances.balances[i] * &self.rates[i]).to_float()))
+            .unzip();
+
+        let base_pos = indexes
+            .iter()
+            .position(|i| *i == base)
+            .ok_or(ArithmeticError::TokenNotInPool(self.balances.tokens[base]))?;
+        let quote_pos = indexes
+            .iter()
+            .position(|i| *i == quote)
+            .ok_or(ArithmeticError::TokenNotInPool(self.balances.tokens[quote]))?;
+
+        let ann = self.amp.clone().to_float() * balances.len() as f64;
+        let price = stable_math::spot_price(ann, &balances, base_pos, quote_pos)
+            .ok_or(ArithmeticError::InvariantNoConvergence)?;
+
+        // price is in rate adjusted units, bring it back to token units
+        Ok(Rational::try_from(price).map_err(|_| ArithmeticError::RoundingError)?
+            * &self.rates[base]
+            / &self.rates[quote])
+    }
+
+    /// Price of `base` in `quote`, both of which can be any of the pool's
+    /// tokens
+    pub fn calculate_pair_price(
+        &self,
+        base_token: Address,
+        quote_token: Address,
+    ) -> Result<Rational, ArithmeticError> {
+        let (base, quote) = self
+            .balances
+            .pair_indexes(base_token, quote_token)
+            .ok_or(ArithmeticError::TokenNotInPool(base_token))?;
+
+        self.spot_price(base, quote)
+    }
+
+    pub fn get_tvl(&self, base: Address, quote: Address) -> (Rational, Rational) {
+        self.balances.get_tvl(base, quote)
+    }
+}
@@ -0,0 +1,159 @@
+use alloy_primitives::{Address, FixedBytes, Log};
+use async_trait::async_trait;
+use brontes_types::{normalized_actions::Action, pair::Pair};
+use malachite::{num::basic::traits::Zero, Rational};
+
+use crate::{
+    errors::{AmmError, ArithmeticError, EventLogError},
+    multi_token::MultiTokenBalances,
+    UpdatableProtocol,
+};
+
+/// Balancer V2 weighted pool. The spot price between two tokens is given by
+/// the ratio of their balances divided by their normalized weights.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BalancerV2WeightedPool {
+    pub address:  Address,
+    pub pool_id:  FixedBytes<32>,
+    pub balances: MultiTokenBalances,
+    pub weights:  Vec<Rational>,
+    pub swap_fee: Rational,
+    /// the token pair this pool is registered with in the token graph
+    pub edge:     Pair,
+}
+
+#[async_trait]
+impl UpdatableProtocol for BalancerV2WeightedPool {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn tokens(&self) -> Vec<Address> {
+        self.balances.tokens.clone()
+    }
+
+    fn calculate_price(&self, base_token: Address) -> Result<Rational, ArithmeticError> {
+        self.calculate_pair_price(base_token, MultiTokenBalances::edge_quote(self.edge, base_token))
+    }
+
+    fn sync_from_action(&mut self, action: Action) -> Result<(), AmmError> {
+        self.balances.apply_action(action)
+    }
+
+    fn sync_from_log(&mut self, _log: Log) -> Result<(), AmmError> {
+        // balances live in the vault, we sync from the classified actions instead
+        Err(AmmError::EventLogError(EventLogError::InvalidEventSignature))
+    }
+}
+
+impl BalancerV2WeightedPool {
+    pub fn new(
+        address: Address,
+        pool_id: FixedBytes<32>,
+        balances: MultiTokenBalances,
+        weights: Vec<Rational>,
+        swap_fee: Rational,
+        edge: Pair,
+    ) -> Self {
+        Self { address, pool_id, balances, weights, swap_fee, edge }
+    }
+
+    pub fn data_is_populated(&self) -> bool {
+        self.balances.is_populated() && self.weights.len() == self.balances.tokens.len()
+    }
+
+    /// Amount of `quote` received for one unit of `base`, excluding fees.
+    pub fn spot_price(&self, base: usize, quote: usize) -> Result<Rational, ArithmeticError> {
+        let (b_base, w_base) = (&self.balances.balances[base], &self.weights[base]);
+        let (b_quote, w_quote) = (&self.balances.balances[quote], &self.weights[quote]);
+
+        if b_base == &Rational::ZERO || w_quote == &Rational::ZERO {
+            return Err(ArithmeticError::BalancerDivZero)
+        }
+
+        Ok((b_quote / w_quote) / (b_base / w_base))
+    }
+
+    /// Price of `base` in `quote`, both of which can be any of the pool's
+    /// tokens
+    pub fn calculate_pair_price(
+        &self,
+        base_token: Address,
+        quote_token: Address,
+    ) -> Result<Rational, ArithmeticError> {
+        let (base, quote) = self
+            .balances
+            .pair_indexes(base_token, quote_token)
+            .ok_or(ArithmeticError::TokenNotInPool(base_token))?;
+
+        self.spot_price(base, quote)
+    }
+
+    pub fn get_tvl(&self, base: Address, quote: Address) -> (Rational, Rational) {
+        self.balances.get_tvl(base, quote)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::U256;
+    use brontes_types::ToScaledRational;
+
+    use super::*;
+
+    #[test]
+    fn test_weighted_spot_price() {
+        let token_a = Address::with_last_byte(1);
+        let token_b = Address::with_last_byte(2);
+
+        // 80/20 pool with 800 A and 50 B, 1 A = (50 / 0.2) / (800 / 0.8) = 0.25 B
+        let pool = BalancerV2WeightedPool::new(
+            Address::with_last_byte(3),
+            FixedBytes::ZERO,
+            MultiTokenBalances::new(
+                vec![token_a, token_b],
+                vec![18, 18],
+                vec![Rational::from(800), Rational::from(50)],
+            ),
+            vec![U256::from(8).to_scaled_rational(1), U256::from(2).to_scaled_rational(1)],
+            U256::from(3).to_scaled_rational(3),
+            Pair(token_a, token_b),
+        );
+
+        assert_eq!(pool.calculate_price(token_a).unwrap(), Rational::from_signeds(1, 4));
+        assert_eq!(pool.calculate_price(token_b).unwrap(), Rational::from(4));
+    }
+
+    #[test]
+    fn test_weighted_pair_price_three_tokens() {
+        let token_a = Address::with_last_byte(1);
+        let token_b = Address::with_last_byte(2);
+        let token_c = Address::with_last_byte(3);
+
+        // equal weights, balances 100 A, 200 B, 400 C. loaded for the A/B edge
+        let third = Rational::from_signeds(1, 3);
+        let pool = BalancerV2WeightedPool::new(
+            Address::with_last_byte(4),
+            FixedBytes::ZERO,
+            MultiTokenBalances::new(
+                vec![token_a, token_b, token_c],
+                vec![18, 18, 18],
+                vec![Rational::from(100), Rational::from(200), Rational::from(400)],
+            ),
+            vec![third.clone(), third.clone(), third],
+            U256::from(3).to_scaled_rational(3),
+            Pair(token_a, token_b),
+        );
+
+        assert_eq!(pool.calculate_pair_price(token_a, token_b).unwrap(), Rational::from(2));
+        assert_eq!(pool.calculate_pair_price(token_a, token_c).unwrap(), Rational::from(4));
+        assert_eq!(
+            pool.calculate_pair_price(token_c, token_b).unwrap(),
+            Rational::from_signeds(1, 2)
+        );
+        assert_eq!(pool.get_tvl(token_b, token_c), (Rational::from(200), Rational::from(400)));
+        assert!(pool
+            .calculate_pair_price(token_a, Address::with_last_byte(5))
+            .is_err());
+    }
+}
@@ -0,0 +1,249 @@
+use std::sync::Arc;
+
+use alloy_primitives::{Address, Log, U256};
+use async_trait::async_trait;
+use brontes_types::{
+    normalized_actions::Action, pair::Pair, traits::TracingProvider, ToFloatNearest,
+    ToScaledRational,
+};
+use malachite::{num::basic::traits::One, Rational};
+
+use super::{load_balances, ICurvePool, ICurveTriCryptoPool};
+use crate::{
+    errors::{AmmError, ArithmeticError, EventLogError},
+    make_call_request,
+    multi_token::MultiTokenBalances,
+    UpdatableProtocol,
+};
+
+/// `A()` on cryptoswap pools is returned as `A * n^n * A_MULTIPLIER`
+const A_MULTIPLIER: f64 = 10_000.0;
+const MAX_ITERATIONS: usize = 255;
+const CONVERGENCE: f64 = 1e-12;
+
+/// Curve CryptoSwap (two coin) and TriCrypto pools.
+///
+/// The price scale is loaded with the pool and isn't moved by the actions we
+/// sync from, so the spot price drifts from the on chain value when the pool
+/// re-pegs. The state is re-queried whenever the subgraph is reloaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurveCryptoSwapPool {
+    pub address:     Address,
+    pub balances:    MultiTokenBalances,
+    /// `A()` as returned by the pool
+    pub amp:         Rational,
+    pub gamma:       Rational,
+    /// price of each coin in terms of coin 0, the first entry is always 1
+    pub price_scale: Vec<Rational>,
+    /// the token pair this pool is registered with in the token graph
+    pub edge:        Pair,
+}
+
+#[async_trait]
+impl UpdatableProtocol for CurveCryptoSwapPool {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn tokens(&self) -> Vec<Address> {
+        self.balances.tokens.clone()
+    }
+
+    fn calculate_price(&self, base_token: Address) -> Result<Rational, ArithmeticError> {
+        self.calculate_pair_price(base_token, MultiTokenBalances::edge_quote(self.edge, base_token))
+    }
+
+    fn sync_from_action(&mut self, action: Action) -> Result<(), AmmError> {
+        self.balances.apply_action(action)
+    }
+
+    fn sync_from_log(&mut self, _log: Log) -> Result<(), AmmError> {
+        Err(AmmError::EventLogError(EventLogError::InvalidEventSignature))
+    }
+}
+
+impl CurveCryptoSwapPool {
+    pub async fn new_load_on_block<M: TracingProvider>(
+        address: Address,
+        block: u64,
+        edge: Pair,
+        middleware: Arc<M>,
+    ) -> Result<Self, AmmError> {
+        let balances = load_balances(address, block, &middleware).await?;
+
+        let amp = make_call_request(ICurvePool::ACall::new(()), &middleware, address, Some(block))
+            .await?
+            ._0
+            .to_scaled_rational(0);
+        let gamma =
+            make_call_request(ICurvePool::gammaCall::new(()), &middleware, address, Some(block))
+                .await?
+                ._0
+                .to_scaled_rational(18);
+
+        // two coin pools expose a single price scale, tricrypto takes the index of
+        // the coin
+        let mut price_scale = vec![Rational::ONE];
+        if balances.tokens.len() == 2 {
+            let scale = make_call_request(
+                ICurvePool::price_scaleCall::new(()),
+                &middleware,
+                address,
+                Some(block),
+            )
+            .await?
+            ._0;
+            price_scale.push(scale.to_scaled_rational(18));
+        } else {
+            for k in 0..balances.tokens.len() - 1 {
+                let scale = make_call_request(
+                    ICurveTriCryptoPool::price_scaleCall::new((U256::from(k),)),
+                    &middleware,
+                    address,
+                    Some(block),
+                )
+                .await?
+                ._0;
+                price_scale.push(scale.to_scaled_rational(18));
+            }
+        }
+
+        let pool = Self { address, balances, amp, gamma, price_scale, edge };
+        if !pool.data_is_populated() {
+            return Err(AmmError::NoStateError(address))
+        }
+
+        Ok(pool)
+    }
+
+    pub fn data_is_populated(&self) -> bool {
+        self.balances.is_populated() && self.price_scale.len() == self.balances.tokens.len()
+    }
+
+    /// Amount of `quote` received for one unit of `base`, excluding fees.
+    pub fn spot_price(&self, base: usize, quote: usize) -> Result<Rational, ArithmeticError> {
+        let xp = self
+            .balances
+            .balances
+            .iter()
+            .zip(&self.price_scale)
+            .map(|(balance, scale)| (balance * scale).to_float())
+            .collect::<Vec<_>>();
+
+        let n = xp.len() as f64;
+        let a = self.amp.clone().to_float() / A_MULTIPLIER / n.powf(n);
+        let gamma = self.gamma.clone().to_float();
+
+        let price = crypto_spot_price(a, gamma, &xp, base, quote)
+            .ok_or(ArithmeticError::InvariantNoConvergence)?;
+
+        // price is in price scaled units, bring it back to coin units
+        Ok(Rational::try_from(price).map_err(|_| ArithmeticError::RoundingError)?
+            * &self.price_scale[base]
+            / &self.price_scale[quote])
+    }
+
+    /// Price of `base` in `quote`, both of which can be any of the pool's
+    /// tokens
+    pub fn calculate_pair_price(
+        &self,
+        base_token: Address,
+        quote_token: Address,
+    ) -> Result<Rational, ArithmeticError> {
+        let (base, quote) = self
+            .balances
+            .pair_indexes(base_token, quote_token)
+            .ok_or(ArithmeticError::TokenNotInPool(base_token))?;
+
+        self.spot_price(base, quote)
+    }
+
+    pub fn get_tvl(&self, base: Address, quote: Address) -> (Rational, Rational) {
+        self.balances.get_tvl(base, quote)
+    }
+}
+
+/// The CryptoSwap invariant
+/// `F = K * D^(n - 1) * S + P - K * D^n - (D / n)^n` with
+/// `K0 = P * n^n / D^n` and `K = A * K0 * gamma^2 / (gamma + 1 - K0)^2`.
+fn crypto_invariant(a: f64, gamma: f64, xp: &[f64], d: f64) -> f64 {
+    let n = xp.len() as f64;
+    let sum: f64 = xp.iter().sum();
+    let prod: f64 = xp.iter().product();
+
+    let k0 = prod * n.powf(n) / d.powf(n);
+    let k = a * k0 * gamma.powi(2) / (gamma + 1.0 - k0).powi(2);
+
+    k * d.powf(n - 1.0) * sum + prod - k * d.powf(n) - (d / n).powf(n)
+}
+
+/// Solves `D` with the secant method, starting from the constant product
+/// solution which is exact for a balanced pool.
+fn crypto_compute_d(a: f64, gamma: f64, xp: &[f64]) -> Option<f64> {
+    if xp.iter().any(|x| *x <= 0.0) {
+        return None
+    }
+    let n = xp.len() as f64;
+
+    let mut d0 = n * xp.iter().product::<f64>().powf(1.0 / n);
+    let mut d1 = d0 * 1.0001;
+    let mut f0 = crypto_invariant(a, gamma, xp, d0);
+
+    for _ in 0..MAX_ITERATIONS {
+        let f1 = crypto_invariant(a, gamma, xp, d1);
+        if f1 == f0 {
+            return Some(d1)
+        }
+
+        let d2 = d1 - f1 * (d1 - d0) / (f1 - f0);
+        if (d2 - d1).abs() <= CONVERGENCE * d1 {
+            return Some(d2)
+        }
+
+        (d0, f0, d1) = (d1, f1, d2);
+    }
+
+    None
+}
+
+/// Marginal price of `xp[base]` in terms of `xp[quote]` given by the ratio of
+/// the partial derivatives of the invariant at a fixed `D`.
+fn crypto_spot_price(a: f64, gamma: f64, xp: &[f64], base: usize, quote: usize) -> Option<f64> {
+    let d = crypto_compute_d(a, gamma, xp)?;
+
+    let partial = |i: usize| {
+        let h = xp[i] * 1e-6;
+        let mut up = xp.to_vec();
+        let mut down = xp.to_vec();
+        up[i] += h;
+        down[i] -= h;
+
+        (crypto_invariant(a, gamma, &up, d) - crypto_invariant(a, gamma, &down, d)) / (2.0 * h)
+    };
+
+    let quote_partial = partial(quote);
+    if quote_partial == 0.0 {
+        return None
+    }
+
+    Some(partial(base) / quote_partial)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_balanced_crypto_pool_is_at_price_scale() {
+        let xp = [1_000_000.0, 1_000_000.0];
+        let price = crypto_spot_price(10.0, 1.45e-4, &xp, 0, 1).unwrap();
+        assert!((price - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_imbalanced_crypto_pool_prices_scarce_coin_higher() {
+        let xp = [900_000.0, 1_100_000.0];
+        let price = crypto_spot_price(10.0, 1.45e-4, &xp, 0, 1).unwrap();
+        assert!(price > 1.0);
+    }
+}
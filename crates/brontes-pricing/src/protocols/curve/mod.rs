@@ -0,0 +1,115 @@
+pub mod crypto;
+pub mod stable;
+
+use std::sync::Arc;
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_macro::sol;
+use brontes_types::{traits::TracingProvider, ToScaledRational};
+pub use crypto::CurveCryptoSwapPool;
+pub use stable::CurveStableSwapPool;
+
+use super::{make_call_request, multi_token::MultiTokenBalances};
+use crate::errors::AmmError;
+
+/// Curve pools have at most 8 coins, the loop over `coins(i)` stops at the
+/// first call that reverts.
+const MAX_COINS: usize = 8;
+
+sol!(
+    interface ICurvePool {
+        function coins(uint256 i) external view returns (address);
+        function balances(uint256 i) external view returns (uint256);
+        function A() external view returns (uint256);
+        function fee() external view returns (uint256);
+        function gamma() external view returns (uint256);
+        function price_scale() external view returns (uint256);
+        function base_pool() external view returns (address);
+        function get_virtual_price() external view returns (uint256);
+        function stored_rates() external view returns (uint256[]);
+    }
+);
+
+sol!(
+    interface ICurveTriCryptoPool {
+        function price_scale(uint256 k) external view returns (uint256);
+    }
+);
+
+// the first generation of pools index coins with an int128
+sol!(
+    interface ICurveLegacyPool {
+        function coins(int128 i) external view returns (address);
+        function balances(int128 i) external view returns (uint256);
+    }
+);
+
+/// Loads the coins and balances of a curve pool. Native eth coins are
+/// stored as weth, see [`MultiTokenBalances::graph_token`].
+pub async fn load_balances<M: TracingProvider>(
+    address: Address,
+    block: u64,
+    middleware: &Arc<M>,
+) -> Result<MultiTokenBalances, AmmError> {
+    let mut tokens = Vec::new();
+    let mut decimals = Vec::new();
+    let mut balances = Vec::new();
+
+    for i in 0..MAX_COINS {
+        let Ok((token, balance)) = load_coin(address, i, block, middleware).await else { break };
+        let dec = MultiTokenBalances::fetch_decimals(token, block, middleware).await?;
+
+        tokens.push(token);
+        decimals.push(dec);
+        balances.push(balance.to_scaled_rational(dec));
+    }
+
+    if tokens.len() < 2 {
+        return Err(AmmError::NoStateError(address))
+    }
+
+    Ok(MultiTokenBalances::new(tokens, decimals, balances))
+}
+
+async fn load_coin<M: TracingProvider>(
+    address: Address,
+    i: usize,
+    block: u64,
+    middleware: &Arc<M>,
+) -> Result<(Address, U256), AmmError> {
+    if let Ok(coin) = make_call_request(
+        ICurvePool::coinsCall::new((U256::from(i),)),
+        middleware,
+        address,
+        Some(block),
+    )
+    .await
+    {
+        let balance = make_call_request(
+            ICurvePool::balancesCall::new((U256::from(i),)),
+            middleware,
+            address,
+            Some(block),
+        )
+        .await?;
+
+        return Ok((coin._0, balance._0))
+    }
+
+    let coin = make_call_request(
+        ICurveLegacyPool::coinsCall::new((i as i128,)),
+        middleware,
+        address,
+        Some(block),
+    )
+    .await?;
+    let balance = make_call_request(
+        ICurveLegacyPool::balancesCall::new((i as i128,)),
+        middleware,
+        address,
+        Some(block),
+    )
+    .await?;
+
+    Ok((coin._0, balance._0))
+}
@@ -0,0 +1,259 @@
+use std::sync::Arc;
+
+use alloy_primitives::{Address, Log};
+use async_trait::async_trait;
+use brontes_types::{
+    normalized_actions::Action, pair::Pair, traits::TracingProvider, ToFloatNearest,
+    ToScaledRational,
+};
+use malachite::{num::basic::traits::One, Rational};
+
+use super::{load_balances, ICurvePool};
+use crate::{
+    errors::{AmmError, ArithmeticError, EventLogError},
+    make_call_request,
+    multi_token::MultiTokenBalances,
+    stable_math, UpdatableProtocol,
+};
+
+/// Curve StableSwap pool, covering the base, meta, factory plain and crvUSD
+/// pools. Pricing uses the StableSwap invariant over the rate adjusted
+/// balances.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurveStableSwapPool {
+    pub address:  Address,
+    pub balances: MultiTokenBalances,
+    /// amplification parameter
+    pub amp:      Rational,
+    /// rate of each coin, the virtual price of the base pool for the lp coin
+    /// of metapools and 1 for plain coins
+    pub rates:    Vec<Rational>,
+    pub fee:      Rational,
+    /// the token pair this pool is registered with in the token graph
+    pub edge:     Pair,
+}
+
+#[async_trait]
+impl UpdatableProtocol for CurveStableSwapPool {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn tokens(&self) -> Vec<Address> {
+        self.balances.tokens.clone()
+    }
+
+    fn calculate_price(&self, base_token: Address) -> Result<Rational, ArithmeticError> {
+        self.calculate_pair_price(base_token, MultiTokenBalances::edge_quote(self.edge, base_token))
+    }
+
+    fn sync_from_action(&mut self, action: Action) -> Result<(), AmmError> {
+        self.balances.apply_action(action)
+    }
+
+    fn sync_from_log(&mut self, _log: Log) -> Result<(), AmmError> {
+        // event layouts differ per pool generation, we sync from the classified
+        // actions instead
+        Err(AmmError::EventLogError(EventLogError::InvalidEventSignature))
+    }
+}
+
+impl CurveStableSwapPool {
+    pub async fn new_load_on_block<M: TracingProvider>(
+        address: Address,
+        block: u64,
+        edge: Pair,
+        middleware: Arc<M>,
+    ) -> Result<Self, AmmError> {
+        let balances = load_balances(address, block, &middleware).await?;
+
+        let amp = make_call_request(ICurvePool::ACall::new(()), &middleware, address, Some(block))
+            .await?
+            ._0
+            .to_scaled_rational(0);
+        let fee =
+            make_call_request(ICurvePool::feeCall::new(()), &middleware, address, Some(block))
+                .await?
+                ._0
+                .to_scaled_rational(10);
+        let rates = Self::load_rates(address, &balances, block, &middleware).await;
+
+        let pool = Self { address, balances, amp, rates, fee, edge };
+        if !pool.data_is_populated() {
+            return Err(AmmError::NoStateError(address))
+        }
+
+        Ok(pool)
+    }
+
+    async fn load_rates<M: TracingProvider>(
+        address: Address,
+        balances: &MultiTokenBalances,
+        block: u64,
+        middleware: &Arc<M>,
+    ) -> Vec<Rational> {
+        // newer pools expose the rates directly, they are 1e18 fixed point with the
+        // decimal scaling folded in
+        if let Ok(rates) = make_call_request(
+            ICurvePool::stored_ratesCall::new(()),
+            middleware,
+            address,
+            Some(block),
+        )
+        .await
+        {
+            return rates
+                ._0
+                .into_iter()
+                .zip(&balances.decimals)
+                .map(|(rate, decimals)| rate.to_scaled_rational(36 - (*decimals).min(18)))
+                .collect()
+        }
+
+        let mut rates = vec![Rational::ONE; balances.tokens.len()];
+
+        // metapools pair a coin with the lp token of a base pool, which is worth the
+        // base pools virtual price
+        if let Ok(base_pool) =
+            make_call_request(ICurvePool::base_poolCall::new(()), middleware, address, Some(block))
+                .await
+        {
+            if let Ok(virtual_price) = make_call_request(
+                ICurvePool::get_virtual_priceCall::new(()),
+                middleware,
+                base_pool._0,
+                Some(block),
+            )
+            .await
+            {
+                if let Some(last) = rates.last_mut() {
+                    *last = virtual_price._0.to_scaled_rational(18);
+                }
+            }
+        }
+
+        rates
+    }
+
+    pub fn data_is_populated(&self) -> bool {
+        self.balances.is_populated() && self.rates.len() == self.balances.tokens.len()
+    }
+
+    /// Amount of `quote` received for one unit of `base`, excluding fees.
+    pub fn spot_price(&self, base: usize, quote: usize) -> Result<Rational, ArithmeticError> {
+        let balances = self
+            .balances
+            .balances
+            .iter()
+            .zip(&self.rates)
+            .map(|(balance, rate)| (balance * rate).to_float())
+            .collect::<Vec<_>>();
+
+        let ann = self.amp.clone().to_float() * balances.len() as f64;
+        let price = stable_math::spot_price(ann, &balances, base, quote)
+            .ok_or(ArithmeticError::InvariantNoConvergence)?;
+
+        // price is in rate adjusted units, bring it back to coin units
+        Ok(Rational::try_from(price).map_err(|_| ArithmeticError::RoundingError)?
+            * &self.rates[base]
+            / &self.rates[quote])
+    }
+
+    /// Price of `base` in `quote`, both of which can be any of the pool's
+    /// tokens
+    pub fn calculate_pair_price(
+        &self,
+        base_token: Address,
+        quote_token: Address,
+    ) -> Result<Rational, ArithmeticError> {
+        let (base, quote) = self
+            .balances
+            .pair_indexes(base_token, quote_token)
+            .ok_or(ArithmeticError::TokenNotInPool(base_token))?;
+
+        self.spot_price(base, quote)
+    }
+
+    pub fn get_tvl(&self, base: Address, quote: Address) -> (Rational, Rational) {
+        self.balances.get_tvl(base, quote)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::hex;
+    use brontes_types::{
+        constants::{ETH_ADDRESS, WETH_ADDRESS},
+        db::token_info::TokenInfoWithAddress,
+        normalized_actions::NormalizedSwap,
+    };
+    use malachite::num::basic::traits::Zero;
+
+    use super::*;
+
+    #[test]
+    fn test_three_coin_pair_price() {
+        let (dai, usdc, usdt) =
+            (Address::with_last_byte(1), Address::with_last_byte(2), Address::with_last_byte(3));
+
+        // usdt is scarce, loaded for the dai/usdc edge
+        let pool = CurveStableSwapPool {
+            address:  Address::with_last_byte(4),
+            balances: MultiTokenBalances::new(
+                vec![dai, usdc, usdt],
+                vec![18, 6, 6],
+                vec![Rational::from(1_000_000), Rational::from(1_000_000), Rational::from(100_000)],
+            ),
+            amp:      Rational::from(2000),
+            rates:    vec![Rational::ONE; 3],
+            fee:      Rational::ZERO,
+            edge:     Pair(dai, usdc),
+        };
+
+        let dai_usdc = pool.calculate_pair_price(dai, usdc).unwrap();
+        let usdt_dai = pool.calculate_pair_price(usdt, dai).unwrap();
+        assert!((dai_usdc.clone().to_float() - 1.0).abs() < 1e-9);
+        assert!(usdt_dai > Rational::ONE);
+        assert!(pool.calculate_pair_price(usdt, usdc).unwrap() > Rational::ONE);
+        assert_eq!(pool.calculate_price(dai).unwrap(), dai_usdc);
+        assert_eq!(pool.get_tvl(usdt, dai), (Rational::from(100_000), Rational::from(1_000_000)));
+    }
+
+    #[test]
+    fn test_eth_pool_uses_weth() {
+        let steth = Address::new(hex!("ae7ab96520DE3A18E5e111B5EaAb095312D7fE84"));
+
+        // the steth/eth pool lists native eth as its first coin
+        let mut pool = CurveStableSwapPool {
+            address:  Address::new(hex!("DC24316b9AE028F1497c275EB9192a3Ea0f67022")),
+            balances: MultiTokenBalances::new(
+                vec![ETH_ADDRESS, steth],
+                vec![18, 18],
+                vec![Rational::from(100_000), Rational::from(100_000)],
+            ),
+            amp:      Rational::from(50),
+            rates:    vec![Rational::ONE; 2],
+            fee:      Rational::ZERO,
+            edge:     Pair(WETH_ADDRESS, steth),
+        };
+
+        assert_eq!(pool.tokens(), vec![WETH_ADDRESS, steth]);
+        let price = pool.calculate_pair_price(WETH_ADDRESS, steth).unwrap();
+        assert!((price.to_float() - 1.0).abs() < 1e-9);
+
+        // swaps are classified with the eth address
+        let swap = NormalizedSwap {
+            pool: pool.address,
+            token_in: TokenInfoWithAddress { address: ETH_ADDRESS, ..Default::default() },
+            token_out: TokenInfoWithAddress { address: steth, ..Default::default() },
+            amount_in: Rational::from(1_000),
+            amount_out: Rational::from(999),
+            ..Default::default()
+        };
+        pool.sync_from_action(Action::Swap(swap)).unwrap();
+        assert_eq!(
+            pool.get_tvl(WETH_ADDRESS, steth),
+            (Rational::from(101_000), Rational::from(99_001))
+        );
+    }
+}
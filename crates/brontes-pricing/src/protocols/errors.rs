@@ -55,6 +55,8 @@ pub enum AmmError {
     AlloyError(#[from] AlloyError),
     #[error("")]
     UnsupportedProtocol,
+    #[error("Action can't be applied to the pool state")]
+    UnsupportedAction,
 }
 
 #[derive(Error, Debug)]
@@ -73,6 +75,12 @@ pub enum ArithmeticError {
     UniswapV3MathError(#[from] UniswapV3MathError),
     #[error("v2 div by zero")]
     UniV2DivZero,
+    #[error("balancer div by zero")]
+    BalancerDivZero,
+    #[error("invariant failed to converge")]
+    InvariantNoConvergence,
+    #[error("token {0:?} is not part of the pool")]
+    TokenNotInPool(Address),
 }
 
 #[derive(Error, Debug)]
@@ -14,6 +14,7 @@ use crate::{
     errors::AmmError,
     protocols::LoadState,
     types::{PairWithFirstPoolHop, PoolState},
+    uniswap_v4::PoolKey,
     Protocol,
 };
 
@@ -71,10 +72,17 @@ pub struct LazyExchangeLoader<T: TracingProvider> {
     req_per_block:     FastHashMap<BlockNumber, u64>,
     state_tracking:    LoadingStateTracker,
     ex:                BrontesTaskExecutor,
+    /// keys of the uniswap v4 pools, loaded from the db and added as new pools
+    /// are discovered
+    pool_keys:         FastHashMap<Address, PoolKey>,
 }
 
 impl<T: TracingProvider> LazyExchangeLoader<T> {
-    pub fn new(provider: Arc<T>, ex: BrontesTaskExecutor) -> Self {
+    pub fn new(
+        provider: Arc<T>,
+        ex: BrontesTaskExecutor,
+        pool_keys: FastHashMap<Address, PoolKey>,
+    ) -> Self {
         Self {
             state_tracking: LoadingStateTracker::default(),
             pool_buf: FastHashMap::default(),
@@ -82,9 +90,14 @@ impl<T: TracingProvider> LazyExchangeLoader<T> {
             provider,
             req_per_block: FastHashMap::default(),
             ex,
+            pool_keys,
         }
     }
 
+    pub fn add_pool_key(&mut self, address: Address, key: PoolKey) {
+        self.pool_keys.insert(address, key);
+    }
+
     pub fn is_loading(&self, k: &Address) -> bool {
         self.pool_buf.contains_key(k)
     }
@@ -165,7 +178,9 @@ impl<T: TracingProvider> LazyExchangeLoader<T> {
         let provider = self.provider.clone();
         self.add_state_trackers(block_number, id, address, pair);
 
-        let fut = ex_type.try_load_state(address, provider, block_number, pool_pair, pair);
+        let pool_key = self.pool_keys.get(&address).cloned();
+        let fut =
+            ex_type.try_load_state(address, provider, block_number, pool_pair, pair, pool_key);
         self.pool_load_futures.add_future(
             block_number,
             Box::pin(self.ex.handle().spawn(async move {
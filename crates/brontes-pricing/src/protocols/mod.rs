@@ -1,7 +1,12 @@
+pub mod balancer_v2;
+pub mod curve;
 pub mod errors;
 pub mod lazy;
+pub mod multi_token;
+pub mod stable_math;
 pub mod uniswap_v2;
 pub mod uniswap_v3;
+pub mod uniswap_v4;
 
 use std::{future::Future, sync::Arc};
 
@@ -13,11 +18,14 @@ use malachite::Rational;
 use tracing::{debug, warn};
 
 use crate::{
+    balancer_v2::load_balancer_v2_pool,
+    curve::{CurveCryptoSwapPool, CurveStableSwapPool},
     lazy::{PoolFetchError, PoolFetchSuccess},
     protocols::errors::{AmmError, ArithmeticError},
     types::PairWithFirstPoolHop,
     uniswap_v2::UniswapV2Pool,
     uniswap_v3::UniswapV3Pool,
+    uniswap_v4::{PoolKey, UniswapV4Pool},
     LoadResult, PoolState,
 };
 
@@ -32,6 +40,8 @@ pub trait UpdatableProtocol {
 
 pub trait LoadState {
     fn has_state_updater(&self) -> bool;
+    /// `pool_key` is only needed for Uniswap V4 pools, which can't be loaded
+    /// from their address
     fn try_load_state<T: TracingProvider>(
         self,
         address: Address,
@@ -39,6 +49,7 @@ pub trait LoadState {
         block_number: u64,
         pool_pair: Pair,
         full_pair: PairWithFirstPoolHop,
+        pool_key: Option<PoolKey>,
     ) -> impl Future<Output = Result<PoolFetchSuccess, PoolFetchError>> + Send;
 }
 
@@ -52,6 +63,18 @@ impl LoadState for Protocol {
                 | Self::SushiSwapV3
                 | Self::PancakeSwapV2
                 | Self::PancakeSwapV3
+                | Self::UniswapV4
+                | Self::BalancerV2
+                | Self::CurveBasePool2
+                | Self::CurveBasePool3
+                | Self::CurveBasePool4
+                | Self::CurveV1MetaPool
+                | Self::CurveV2MetaPool
+                | Self::CurveV2PlainPool
+                | Self::CurvecrvUSDMetaPool
+                | Self::CurvecrvUSDPlainPool
+                | Self::CurveCryptoSwapPool
+                | Self::CurveTriCryptoPool
         )
     }
 
@@ -62,6 +85,7 @@ impl LoadState for Protocol {
         block_number: u64,
         pool_pair: Pair,
         fp: PairWithFirstPoolHop,
+        pool_key: Option<PoolKey>,
     ) -> Result<PoolFetchSuccess, PoolFetchError> {
         match self {
             Self::UniswapV2 | Self::SushiSwapV2 | Self::PancakeSwapV2 => {
@@ -120,6 +144,139 @@ impl LoadState for Protocol {
                     res,
                 ))
             }
+            Self::UniswapV4 => {
+                let key = pool_key.ok_or_else(|| {
+                    debug!(?pool_pair, %block_number, pool_address=?address, "missing pool key");
+                    let err = AmmError::NoStateError(address);
+                    (address, Protocol::UniswapV4, block_number, pool_pair, fp, err)
+                })?;
+                let (pool, res) = if let Ok(pool) =
+                    UniswapV4Pool::new_load_on_block(&key, block_number - 1, provider.clone()).await
+                {
+                    (pool, LoadResult::Ok)
+                } else {
+                    (
+                        UniswapV4Pool::new_load_on_block(&key, block_number, provider)
+                            .await
+                            .map_err(|e| {
+                                debug!(?pool_pair, protocol=%self, %block_number, pool_address=?address, err=%e, "lazy load failed");
+                                (address, Protocol::UniswapV4, block_number, pool_pair, fp, e)
+                            })?,
+                        LoadResult::PoolInitOnBlock,
+                    )
+                };
+
+                Ok((
+                    block_number,
+                    address,
+                    PoolState::new(
+                        crate::types::PoolVariants::UniswapV4(Box::new(pool)),
+                        block_number,
+                    ),
+                    res,
+                ))
+            }
+            Self::BalancerV2 => {
+                let (pool, res) = if let Ok(pool) =
+                    load_balancer_v2_pool(address, block_number - 1, pool_pair, provider.clone())
+                        .await
+                {
+                    (pool, LoadResult::Ok)
+                } else {
+                    (
+                        load_balancer_v2_pool(address, block_number, pool_pair, provider)
+                            .await
+                            .map_err(|e| {
+                                debug!(?pool_pair, protocol=%self, %block_number, pool_address=?address, err=%e, "lazy load failed");
+                                (address, Protocol::BalancerV2, block_number, pool_pair, fp, e)
+                            })?,
+                        LoadResult::PoolInitOnBlock,
+                    )
+                };
+
+                Ok((block_number, address, PoolState::new(pool, block_number), res))
+            }
+            Self::CurveBasePool2
+            | Self::CurveBasePool3
+            | Self::CurveBasePool4
+            | Self::CurveV1MetaPool
+            | Self::CurveV2MetaPool
+            | Self::CurveV2PlainPool
+            | Self::CurvecrvUSDMetaPool
+            | Self::CurvecrvUSDPlainPool => {
+                let (pool, res) = if let Ok(pool) = CurveStableSwapPool::new_load_on_block(
+                    address,
+                    block_number - 1,
+                    pool_pair,
+                    provider.clone(),
+                )
+                .await
+                {
+                    (pool, LoadResult::Ok)
+                } else {
+                    (
+                        CurveStableSwapPool::new_load_on_block(
+                            address,
+                            block_number,
+                            pool_pair,
+                            provider,
+                        )
+                        .await
+                        .map_err(|e| {
+                            debug!(?pool_pair, protocol=%self, %block_number, pool_address=?address, err=%e, "lazy load failed");
+                            (address, self, block_number, pool_pair, fp, e)
+                        })?,
+                        LoadResult::PoolInitOnBlock,
+                    )
+                };
+
+                Ok((
+                    block_number,
+                    address,
+                    PoolState::new(
+                        crate::types::PoolVariants::CurveStableSwap(Box::new(pool)),
+                        block_number,
+                    ),
+                    res,
+                ))
+            }
+            Self::CurveCryptoSwapPool | Self::CurveTriCryptoPool => {
+                let (pool, res) = if let Ok(pool) = CurveCryptoSwapPool::new_load_on_block(
+                    address,
+                    block_number - 1,
+                    pool_pair,
+                    provider.clone(),
+                )
+                .await
+                {
+                    (pool, LoadResult::Ok)
+                } else {
+                    (
+                        CurveCryptoSwapPool::new_load_on_block(
+                            address,
+                            block_number,
+                            pool_pair,
+                            provider,
+                        )
+                        .await
+                        .map_err(|e| {
+                            debug!(?pool_pair, protocol=%self, %block_number, pool_address=?address, err=%e, "lazy load failed");
+                            (address, self, block_number, pool_pair, fp, e)
+                        })?,
+                        LoadResult::PoolInitOnBlock,
+                    )
+                };
+
+                Ok((
+                    block_number,
+                    address,
+                    PoolState::new(
+                        crate::types::PoolVariants::CurveCryptoSwap(Box::new(pool)),
+                        block_number,
+                    ),
+                    res,
+                ))
+            }
             rest => {
                 warn!(protocol=?rest, "no state updater is build for");
                 Err((address, self, block_number, pool_pair, fp, AmmError::UnsupportedProtocol))
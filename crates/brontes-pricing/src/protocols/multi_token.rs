@@ -0,0 +1,131 @@
+use std::sync::Arc;
+
+use alloy_primitives::Address;
+use alloy_sol_macro::sol;
+use brontes_types::{
+    constants::{ETH_ADDRESS, WETH_ADDRESS},
+    normalized_actions::Action,
+    pair::Pair,
+    traits::TracingProvider,
+};
+use malachite::{num::basic::traits::Zero, Rational};
+
+use super::make_call_request;
+use crate::errors::AmmError;
+
+sol!(
+    interface IErc20Decimals {
+        function decimals() external view returns (uint8);
+    }
+);
+
+/// Token balances of a pool that can hold more than two tokens, scaled by the
+/// token decimals.
+///
+/// A pool is shared by every edge of the token graph it backs, so prices and
+/// tvl are always taken for an explicit base and quote token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiTokenBalances {
+    pub tokens:   Vec<Address>,
+    pub decimals: Vec<u8>,
+    pub balances: Vec<Rational>,
+}
+
+impl MultiTokenBalances {
+    pub fn new(tokens: Vec<Address>, decimals: Vec<u8>, balances: Vec<Rational>) -> Self {
+        let tokens = tokens.into_iter().map(Self::graph_token).collect();
+        Self { tokens, decimals, balances }
+    }
+
+    /// Pools holding native eth list it under the 0xEeee address, the token
+    /// graph prices it as weth.
+    pub fn graph_token(token: Address) -> Address {
+        if token == ETH_ADDRESS {
+            WETH_ADDRESS
+        } else {
+            token
+        }
+    }
+
+    pub async fn fetch_decimals<M: TracingProvider>(
+        token: Address,
+        block: u64,
+        middleware: &Arc<M>,
+    ) -> Result<u8, AmmError> {
+        if token == ETH_ADDRESS {
+            return Ok(18)
+        }
+
+        Ok(make_call_request(IErc20Decimals::decimalsCall::new(()), middleware, token, Some(block))
+            .await?
+            ._0)
+    }
+
+    pub fn index_of(&self, token: Address) -> Option<usize> {
+        let token = Self::graph_token(token);
+        self.tokens.iter().position(|t| *t == token)
+    }
+
+    /// Returns the index of `base` and `quote`.
+    pub fn pair_indexes(&self, base: Address, quote: Address) -> Option<(usize, usize)> {
+        let (base, quote) = (self.index_of(base)?, self.index_of(quote)?);
+        (base != quote).then_some((base, quote))
+    }
+
+    /// The counterpart of `base` on `edge`.
+    pub fn edge_quote(edge: Pair, base: Address) -> Address {
+        if edge.0 == base {
+            edge.1
+        } else {
+            edge.0
+        }
+    }
+
+    pub fn is_populated(&self) -> bool {
+        !self.tokens.is_empty() && self.balances.iter().all(|b| b > &Rational::ZERO)
+    }
+
+    /// Applies the balance deltas of a classified swap, mint or burn.
+    pub fn apply_action(&mut self, action: Action) -> Result<(), AmmError> {
+        match action {
+            Action::Swap(s) => {
+                let token_in = self
+                    .index_of(s.token_in.address)
+                    .ok_or(AmmError::SyncError(s.pool))?;
+                let token_out = self
+                    .index_of(s.token_out.address)
+                    .ok_or(AmmError::SyncError(s.pool))?;
+
+                self.balances[token_in] += s.amount_in;
+                self.balances[token_out] -= s.amount_out;
+            }
+            Action::Mint(m) => {
+                for (token, amount) in m.token.into_iter().zip(m.amount) {
+                    let idx = self
+                        .index_of(token.address)
+                        .ok_or(AmmError::SyncError(m.pool))?;
+                    self.balances[idx] += amount;
+                }
+            }
+            Action::Burn(b) => {
+                for (token, amount) in b.token.into_iter().zip(b.amount) {
+                    let idx = self
+                        .index_of(token.address)
+                        .ok_or(AmmError::SyncError(b.pool))?;
+                    self.balances[idx] -= amount;
+                }
+            }
+            _ => return Err(AmmError::UnsupportedAction),
+        }
+
+        Ok(())
+    }
+
+    pub fn get_tvl(&self, base: Address, quote: Address) -> (Rational, Rational) {
+        let Some((base_idx, quote_idx)) = self.pair_indexes(base, quote) else {
+            return (Rational::ZERO, Rational::ZERO)
+        };
+
+        (self.balances[base_idx].clone(), self.balances[quote_idx].clone())
+    }
+}
@@ -0,0 +1,86 @@
+//! StableSwap invariant math shared by the pools that are built on top of it.
+//!
+//! The invariant is written in the `Ann` form used by both the Curve and the
+//! Balancer contracts, where `Ann = A * n`:
+//!
+//! `Ann * S + D = Ann * D + D^(n + 1) / (n^n * P)`
+//!
+//! with `S` the sum and `P` the product of the balances. Balances passed in
+//! here are expected to already be normalized to a common unit (decimal
+//! scaling and any rate provider applied).
+
+const MAX_ITERATIONS: usize = 255;
+const CONVERGENCE: f64 = 1e-12;
+
+/// Solves the invariant `D` for the given balances with Newton's method.
+/// Returns `None` if any balance is empty or the iteration doesn't converge.
+pub fn compute_invariant(ann: f64, balances: &[f64]) -> Option<f64> {
+    let n = balances.len() as f64;
+    let sum: f64 = balances.iter().sum();
+    if sum == 0.0 || balances.iter().any(|b| *b <= 0.0) {
+        return None
+    }
+
+    let mut d = sum;
+    for _ in 0..MAX_ITERATIONS {
+        let d_p = balances.iter().fold(d, |d_p, b| d_p * d / (b * n));
+        let prev = d;
+        d = (ann * sum + d_p * n) * d / ((ann - 1.0) * d + (n + 1.0) * d_p);
+
+        if (d - prev).abs() <= CONVERGENCE * d {
+            return Some(d)
+        }
+    }
+
+    None
+}
+
+/// Marginal price of `balances[base]` quoted in `balances[quote]`, i.e. how
+/// many units of quote one unit of base is worth at the current point of the
+/// curve.
+///
+/// The derivative of the invariant with respect to a balance `x_i` is
+/// `Ann + D^(n + 1) / (n^n * P * x_i)`, the price is the ratio of the two
+/// partials.
+pub fn spot_price(ann: f64, balances: &[f64], base: usize, quote: usize) -> Option<f64> {
+    let d = compute_invariant(ann, balances)?;
+    let n = balances.len() as f64;
+
+    // D^(n + 1) / (n^n * P), folded to stay in range for large pools
+    let d_p = balances.iter().fold(d, |d_p, b| d_p * d / (b * n));
+
+    let partial_base = ann + d_p / balances[base];
+    let partial_quote = ann + d_p / balances[quote];
+
+    Some(partial_base / partial_quote)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_balanced_pool_is_at_peg() {
+        let balances = [1_000_000.0, 1_000_000.0, 1_000_000.0];
+        let d = compute_invariant(200.0 * 3.0, &balances).unwrap();
+        assert!((d - 3_000_000.0).abs() < 1e-6);
+
+        let price = spot_price(200.0 * 3.0, &balances, 0, 1).unwrap();
+        assert!((price - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_imbalanced_pool_prices_scarce_token_higher() {
+        let balances = [500_000.0, 1_500_000.0];
+        let price = spot_price(100.0 * 2.0, &balances, 0, 1).unwrap();
+        assert!(price > 1.0);
+
+        let flipped = spot_price(100.0 * 2.0, &balances, 1, 0).unwrap();
+        assert!((price * flipped - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_empty_balance_has_no_invariant() {
+        assert!(compute_invariant(100.0, &[0.0, 10.0]).is_none());
+    }
+}
@@ -0,0 +1,355 @@
+use std::sync::Arc;
+
+use alloy_primitives::{hex, keccak256, Address, Log, B256, I256, U256};
+use alloy_sol_macro::sol;
+use alloy_sol_types::{SolEvent, SolValue};
+use async_trait::async_trait;
+use brontes_types::{
+    constants::WETH_ADDRESS, db::uniswap_v4_pool_key::UniswapV4PoolKey, normalized_actions::Action,
+    traits::TracingProvider, ToScaledRational,
+};
+use malachite::{
+    num::arithmetic::traits::{Pow, Reciprocal},
+    Rational,
+};
+
+use super::{make_call_request, multi_token::MultiTokenBalances};
+use crate::{
+    errors::{AmmError, ArithmeticError, EventLogError},
+    uniswap_v3::uniswap_v3_math::error::UniswapV3MathError,
+    UpdatableProtocol,
+};
+
+pub const UNISWAP_V4_POOL_MANAGER: Address =
+    Address::new(hex!("000000000004444c5dc75cB358380D2e3dE08A90"));
+
+/// Storage slot of the `_pools` mapping in the pool manager.
+const POOLS_SLOT: u64 = 6;
+/// Offset of the liquidity from the start of a `Pool.State`, after slot0 and
+/// the two fee growth globals.
+const LIQUIDITY_OFFSET: u64 = 3;
+
+sol!(
+    #[derive(Debug, PartialEq, Eq)]
+    struct PoolKey {
+        address currency0;
+        address currency1;
+        uint24 fee;
+        int24 tickSpacing;
+        address hooks;
+    }
+
+    interface IUniswapV4PoolManager {
+        function extsload(bytes32 slot) external view returns (bytes32);
+        event Initialize(
+            bytes32 indexed id,
+            address indexed currency0,
+            address indexed currency1,
+            uint24 fee,
+            int24 tickSpacing,
+            address hooks,
+            uint160 sqrtPriceX96,
+            int24 tick
+        );
+        event ModifyLiquidity(
+            bytes32 indexed id,
+            address indexed sender,
+            int24 tickLower,
+            int24 tickUpper,
+            int256 liquidityDelta,
+            bytes32 salt
+        );
+        event Swap(
+            bytes32 indexed id,
+            address indexed sender,
+            int128 amount0,
+            int128 amount1,
+            uint160 sqrtPriceX96,
+            uint128 liquidity,
+            int24 tick,
+            uint24 fee
+        );
+    }
+);
+
+/// V4 pools live inside the pool manager and are identified by a 32 byte
+/// `PoolId`. The rest of brontes keys pools by address, so the pool is
+/// represented by the first 20 bytes of its id. That truncation can't be
+/// undone, so the key is stored in the db when the pool is initialized and
+/// handed to the pricer when it loads the pool's state.
+impl PoolKey {
+    /// `keccak256(abi.encode(key))`
+    pub fn pool_id(&self) -> B256 {
+        keccak256(self.abi_encode())
+    }
+
+    pub fn pool_address(&self) -> Address {
+        pool_id_to_address(self.pool_id())
+    }
+
+    /// The currencies of the pool as priced by the token graph. Native eth is
+    /// represented by the zero address in V4 and is priced through weth.
+    pub fn tokens(&self) -> [Address; 2] {
+        [wrap_native(self.currency0), wrap_native(self.currency1)]
+    }
+}
+
+pub fn pool_id_to_address(pool_id: B256) -> Address {
+    Address::from_slice(&pool_id[0..20])
+}
+
+fn wrap_native(currency: Address) -> Address {
+    if currency.is_zero() {
+        WETH_ADDRESS
+    } else {
+        currency
+    }
+}
+
+impl From<UniswapV4PoolKey> for PoolKey {
+    fn from(key: UniswapV4PoolKey) -> Self {
+        Self {
+            currency0:   key.currency0,
+            currency1:   key.currency1,
+            fee:         key.fee,
+            tickSpacing: key.tick_spacing,
+            hooks:       key.hooks,
+        }
+    }
+}
+
+impl From<PoolKey> for UniswapV4PoolKey {
+    fn from(key: PoolKey) -> Self {
+        Self {
+            currency0:    key.currency0,
+            currency1:    key.currency1,
+            fee:          key.fee,
+            tick_spacing: key.tickSpacing,
+            hooks:        key.hooks,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UniswapV4Pool {
+    /// the first 20 bytes of the pool id
+    pub address:          Address,
+    pub pool_id:          B256,
+    pub token_a:          Address,
+    pub token_a_decimals: u8,
+    pub token_b:          Address,
+    pub token_b_decimals: u8,
+    pub hooks:            Address,
+    pub liquidity:        u128,
+    pub sqrt_price:       U256,
+    pub tick:             i32,
+    pub fee:              u32,
+}
+
+#[async_trait]
+impl UpdatableProtocol for UniswapV4Pool {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn tokens(&self) -> Vec<Address> {
+        vec![self.token_a, self.token_b]
+    }
+
+    fn calculate_price(&self, base_token: Address) -> Result<Rational, ArithmeticError> {
+        if self.liquidity <= 10_000 {
+            return Err(ArithmeticError::UniswapV3MathError(UniswapV3MathError::LiquidityTooLow(
+                self.liquidity,
+            )))
+        }
+
+        let shift = self.token_a_decimals as i64 - self.token_b_decimals as i64;
+        let price = self.raw_price() * Rational::from(10u8).pow(shift);
+
+        if base_token == self.token_a {
+            Ok(price)
+        } else if base_token == self.token_b {
+            Ok(price.reciprocal())
+        } else {
+            Err(ArithmeticError::TokenNotInPool(base_token))
+        }
+    }
+
+    fn sync_from_action(&mut self, _action: Action) -> Result<(), AmmError> {
+        // the logs carry the post swap price, we don't need to simulate the action
+        Err(AmmError::UnsupportedAction)
+    }
+
+    fn sync_from_log(&mut self, log: Log) -> Result<(), AmmError> {
+        // the pool manager emits the events of every pool, only apply our own
+        if log.topics().get(1) != Some(&self.pool_id) {
+            return Ok(())
+        }
+        let event_signature = log.topics()[0];
+
+        if event_signature == IUniswapV4PoolManager::Swap::SIGNATURE_HASH {
+            let swap = IUniswapV4PoolManager::Swap::decode_log_data(&log, false)?;
+            self.sqrt_price = swap.sqrtPriceX96;
+            self.liquidity = swap.liquidity;
+            self.tick = swap.tick;
+            self.fee = swap.fee;
+        } else if event_signature == IUniswapV4PoolManager::ModifyLiquidity::SIGNATURE_HASH {
+            let modify = IUniswapV4PoolManager::ModifyLiquidity::decode_log_data(&log, false)?;
+            // only positions around the current tick are active
+            if modify.tickLower <= self.tick && self.tick < modify.tickUpper {
+                self.apply_liquidity_delta(modify.liquidityDelta)?;
+            }
+        } else if event_signature == IUniswapV4PoolManager::Initialize::SIGNATURE_HASH {
+            let init = IUniswapV4PoolManager::Initialize::decode_log_data(&log, false)?;
+            self.sqrt_price = init.sqrtPriceX96;
+            self.tick = init.tick;
+        } else {
+            Err(EventLogError::InvalidEventSignature)?
+        }
+
+        Ok(())
+    }
+}
+
+impl UniswapV4Pool {
+    pub async fn new_load_on_block<M: TracingProvider>(
+        key: &PoolKey,
+        block: u64,
+        middleware: Arc<M>,
+    ) -> Result<Self, AmmError> {
+        let address = key.pool_address();
+        let pool_id = key.pool_id();
+        let [token_a, token_b] = key.tokens();
+
+        let state_slot =
+            U256::from_be_bytes(keccak256((pool_id, U256::from(POOLS_SLOT)).abi_encode_packed()).0);
+        let slot0 = Self::extsload(state_slot, block, &middleware).await?;
+        let liquidity =
+            Self::extsload(state_slot + U256::from(LIQUIDITY_OFFSET), block, &middleware).await?;
+
+        let (sqrt_price, tick, fee) = decode_slot0(slot0);
+
+        let pool = Self {
+            address,
+            pool_id,
+            token_a,
+            token_a_decimals: MultiTokenBalances::fetch_decimals(token_a, block, &middleware)
+                .await?,
+            token_b,
+            token_b_decimals: MultiTokenBalances::fetch_decimals(token_b, block, &middleware)
+                .await?,
+            hooks: key.hooks,
+            liquidity: liquidity.to::<u128>(),
+            sqrt_price,
+            tick,
+            fee,
+        };
+
+        if !pool.data_is_populated() {
+            return Err(AmmError::NoStateError(address))
+        }
+
+        Ok(pool)
+    }
+
+    async fn extsload<M: TracingProvider>(
+        slot: U256,
+        block: u64,
+        middleware: &Arc<M>,
+    ) -> Result<U256, AmmError> {
+        let res = make_call_request(
+            IUniswapV4PoolManager::extsloadCall::new((B256::from(slot.to_be_bytes()),)),
+            middleware,
+            UNISWAP_V4_POOL_MANAGER,
+            Some(block),
+        )
+        .await?;
+
+        Ok(U256::from_be_bytes(res._0.0))
+    }
+
+    pub fn data_is_populated(&self) -> bool {
+        !self.sqrt_price.is_zero()
+    }
+
+    fn apply_liquidity_delta(&mut self, delta: I256) -> Result<(), AmmError> {
+        let delta = i128::try_from(delta).map_err(|_| AmmError::SyncError(self.address))?;
+        self.liquidity = self
+            .liquidity
+            .checked_add_signed(delta)
+            .ok_or(AmmError::SyncError(self.address))?;
+
+        Ok(())
+    }
+
+    /// `(sqrtPriceX96 / 2^96)^2`, the price of token a in token b without
+    /// decimal scaling.
+    fn raw_price(&self) -> Rational {
+        let sqrt_price = self.sqrt_price.to_scaled_rational(0);
+        (&sqrt_price * &sqrt_price) / Rational::from(2u8).pow(192u64)
+    }
+
+    /// V4 balances are held by the pool manager for every pool, so the tvl is
+    /// approximated with the virtual reserves of the active liquidity.
+    pub fn get_tvl(&self, base: Address) -> (Rational, Rational) {
+        let liquidity = self.liquidity.to_scaled_rational(0);
+        let sqrt_price = self.sqrt_price.to_scaled_rational(0) / Rational::from(2u8).pow(96u64);
+
+        if sqrt_price == Rational::from(0u8) {
+            return (Rational::from(0u8), Rational::from(0u8))
+        }
+
+        let reserve_a = (&liquidity / &sqrt_price).to_scaled_rational(self.token_a_decimals);
+        let reserve_b = (liquidity * sqrt_price).to_scaled_rational(self.token_b_decimals);
+
+        if self.token_a == base {
+            (reserve_a, reserve_b)
+        } else {
+            (reserve_b, reserve_a)
+        }
+    }
+}
+
+/// slot0 packs `sqrtPriceX96 (160) | tick (24) | protocolFee (24) | lpFee
+/// (24)` from the least significant bit up.
+fn decode_slot0(slot0: U256) -> (U256, i32, u32) {
+    let sqrt_price = slot0 & ((U256::from(1u8) << 160) - U256::from(1u8));
+    let tick_bits = (slot0 >> 160).to::<u64>() as u32 & 0xFF_FFFF;
+    // sign extend the 24 bit tick
+    let tick = ((tick_bits << 8) as i32) >> 8;
+    let fee = ((slot0 >> 208).to::<u64>() & 0xFF_FFFF) as u32;
+
+    (sqrt_price, tick, fee)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_slot0() {
+        let sqrt_price = U256::from(79228162514264337593543950336u128);
+        let tick: i32 = -887272;
+        let fee = 3000u32;
+
+        let slot0 =
+            sqrt_price | (U256::from(tick as u32 & 0xFF_FFFF) << 160) | (U256::from(fee) << 208);
+
+        assert_eq!(decode_slot0(slot0), (sqrt_price, tick, fee));
+    }
+
+    #[test]
+    fn test_pool_address_is_id_prefix() {
+        let key = PoolKey {
+            currency0:   Address::ZERO,
+            currency1:   Address::new(hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48")),
+            fee:         500,
+            tickSpacing: 10,
+            hooks:       Address::ZERO,
+        };
+
+        let id = key.pool_id();
+        assert_eq!(key.pool_address(), Address::from_slice(&id[0..20]));
+        assert_eq!(PoolKey::from(UniswapV4PoolKey::from(key.clone())), key);
+    }
+}
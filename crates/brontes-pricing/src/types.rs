@@ -2,15 +2,20 @@ use std::fmt::{Debug, Display};
 
 use alloy_primitives::{wrap_fixed_bytes, Address, FixedBytes, Log};
 use brontes_types::{
-    constants::WETH_ADDRESS,
+    constants::{ETH_ADDRESS, WETH_ADDRESS},
     normalized_actions::{pool::NormalizedPoolConfigUpdate, Action},
     pair::Pair,
 };
 use malachite::Rational;
 
 use crate::{
-    errors::ArithmeticError, uniswap_v2::UniswapV2Pool, uniswap_v3::UniswapV3Pool, LoadState,
-    Protocol, UpdatableProtocol,
+    balancer_v2::{BalancerV2StablePool, BalancerV2WeightedPool},
+    curve::{CurveCryptoSwapPool, CurveStableSwapPool},
+    errors::ArithmeticError,
+    uniswap_v2::UniswapV2Pool,
+    uniswap_v3::UniswapV3Pool,
+    uniswap_v4::UniswapV4Pool,
+    LoadState, Protocol, UpdatableProtocol,
 };
 
 wrap_fixed_bytes!(extra_derives:[],
@@ -52,17 +57,17 @@ impl PairWithFirstPoolHop {
 }
 
 pub trait ProtocolState: Debug {
-    fn price(&self, base: Address) -> Result<Rational, ArithmeticError>;
-    fn tvl(&self, base: Address) -> (Rational, Rational);
+    fn price(&self, base: Address, quote: Address) -> Result<Rational, ArithmeticError>;
+    fn tvl(&self, base: Address, quote: Address) -> (Rational, Rational);
 }
 
 impl ProtocolState for PoolState {
-    fn tvl(&self, base: Address) -> (Rational, Rational) {
-        self.get_tvl(base)
+    fn tvl(&self, base: Address, quote: Address) -> (Rational, Rational) {
+        self.get_tvl(base, quote)
     }
 
-    fn price(&self, base: Address) -> Result<Rational, ArithmeticError> {
-        self.get_price(base)
+    fn price(&self, base: Address, quote: Address) -> Result<Rational, ArithmeticError> {
+        self.get_price(base, quote)
     }
 }
 
@@ -76,8 +81,8 @@ impl Debug for PoolState {
         f.debug_struct("Pool State")
             .field("addr", &self.address())
             .field("pair", &self.pair())
-            .field("tvl 0", &self.get_tvl(self.pair().0).0)
-            .field("tvl 1", &self.get_tvl(self.pair().0).1)
+            .field("tvl 0", &self.get_tvl(self.pair().0, self.pair().1).0)
+            .field("tvl 1", &self.get_tvl(self.pair().0, self.pair().1).1)
             .field("block", &self.last_update)
             .finish()
     }
@@ -92,6 +97,11 @@ impl PoolState {
         match &self.variant {
             PoolVariants::UniswapV2(v) => Pair(v.token_a, v.token_b),
             PoolVariants::UniswapV3(v) => Pair(v.token_a, v.token_b),
+            PoolVariants::UniswapV4(v) => Pair(v.token_a, v.token_b),
+            PoolVariants::BalancerV2Weighted(v) => v.edge,
+            PoolVariants::BalancerV2Stable(v) => v.edge,
+            PoolVariants::CurveStableSwap(v) => v.edge,
+            PoolVariants::CurveCryptoSwap(v) => v.edge,
         }
     }
 
@@ -99,6 +109,12 @@ impl PoolState {
         match &self.variant {
             PoolVariants::UniswapV2(_) => Protocol::UniswapV2,
             PoolVariants::UniswapV3(_) => Protocol::UniswapV3,
+            PoolVariants::UniswapV4(_) => Protocol::UniswapV4,
+            PoolVariants::BalancerV2Weighted(_) | PoolVariants::BalancerV2Stable(_) => {
+                Protocol::BalancerV2
+            }
+            PoolVariants::CurveStableSwap(_) => Protocol::CurveV2PlainPool,
+            PoolVariants::CurveCryptoSwap(_) => Protocol::CurveCryptoSwapPool,
         }
     }
 
@@ -108,27 +124,44 @@ impl PoolState {
             return
         }
         self.last_update = state.block;
-        self.variant.increment_state(state.logs);
+        self.variant.increment_state(state);
     }
 
     pub fn address(&self) -> Address {
         match &self.variant {
             PoolVariants::UniswapV2(v) => v.address(),
             PoolVariants::UniswapV3(v) => v.address(),
+            PoolVariants::UniswapV4(v) => v.address(),
+            PoolVariants::BalancerV2Weighted(v) => v.address(),
+            PoolVariants::BalancerV2Stable(v) => v.address(),
+            PoolVariants::CurveStableSwap(v) => v.address(),
+            PoolVariants::CurveCryptoSwap(v) => v.address(),
         }
     }
 
-    pub fn get_tvl(&self, base: Address) -> (Rational, Rational) {
+    /// Multi token pools are quoted against `quote`, two token pools ignore it
+    pub fn get_tvl(&self, base: Address, quote: Address) -> (Rational, Rational) {
         match &self.variant {
             PoolVariants::UniswapV2(v) => v.get_tvl(base),
             PoolVariants::UniswapV3(v) => v.get_tvl(base),
+            PoolVariants::UniswapV4(v) => v.get_tvl(base),
+            PoolVariants::BalancerV2Weighted(v) => v.get_tvl(base, quote),
+            PoolVariants::BalancerV2Stable(v) => v.get_tvl(base, quote),
+            PoolVariants::CurveStableSwap(v) => v.get_tvl(base, quote),
+            PoolVariants::CurveCryptoSwap(v) => v.get_tvl(base, quote),
         }
     }
 
-    pub fn get_price(&self, base: Address) -> Result<Rational, ArithmeticError> {
+    /// Multi token pools are quoted against `quote`, two token pools ignore it
+    pub fn get_price(&self, base: Address, quote: Address) -> Result<Rational, ArithmeticError> {
         match &self.variant {
             PoolVariants::UniswapV2(v) => v.calculate_price(base),
             PoolVariants::UniswapV3(v) => v.calculate_price(base),
+            PoolVariants::UniswapV4(v) => v.calculate_price(base),
+            PoolVariants::BalancerV2Weighted(v) => v.calculate_pair_price(base, quote),
+            PoolVariants::BalancerV2Stable(v) => v.calculate_pair_price(base, quote),
+            PoolVariants::CurveStableSwap(v) => v.calculate_pair_price(base, quote),
+            PoolVariants::CurveCryptoSwap(v) => v.calculate_pair_price(base, quote),
         }
     }
 }
@@ -137,15 +170,39 @@ impl PoolState {
 pub enum PoolVariants {
     UniswapV2(Box<UniswapV2Pool>),
     UniswapV3(Box<UniswapV3Pool>),
+    UniswapV4(Box<UniswapV4Pool>),
+    BalancerV2Weighted(Box<BalancerV2WeightedPool>),
+    BalancerV2Stable(Box<BalancerV2StablePool>),
+    CurveStableSwap(Box<CurveStableSwapPool>),
+    CurveCryptoSwap(Box<CurveCryptoSwapPool>),
 }
 
 impl PoolVariants {
-    fn increment_state(&mut self, logs: Vec<Log>) {
-        for log in logs {
-            let _ = match self {
-                PoolVariants::UniswapV3(a) => a.sync_from_log(log),
-                PoolVariants::UniswapV2(a) => a.sync_from_log(log),
-            };
+    fn increment_state(&mut self, update: PoolUpdate) {
+        match self {
+            PoolVariants::UniswapV3(a) => update.logs.into_iter().for_each(|log| {
+                let _ = a.sync_from_log(log);
+            }),
+            PoolVariants::UniswapV2(a) => update.logs.into_iter().for_each(|log| {
+                let _ = a.sync_from_log(log);
+            }),
+            PoolVariants::UniswapV4(a) => update.logs.into_iter().for_each(|log| {
+                let _ = a.sync_from_log(log);
+            }),
+            // balancer balances are held by the vault, so the pool call frame doesn't
+            // contain the logs we need. these are synced from the action instead
+            PoolVariants::BalancerV2Weighted(a) => {
+                let _ = a.sync_from_action(update.action);
+            }
+            PoolVariants::BalancerV2Stable(a) => {
+                let _ = a.sync_from_action(update.action);
+            }
+            PoolVariants::CurveStableSwap(a) => {
+                let _ = a.sync_from_action(update.action);
+            }
+            PoolVariants::CurveCryptoSwap(a) => {
+                let _ = a.sync_from_action(update.action);
+            }
         }
     }
 }
@@ -225,6 +282,11 @@ impl PoolUpdate {
     // we currently only use this in order to fetch the pair for when its new or to
     // fetch all pairs of it. this
     pub fn get_pair(&self, quote: Address) -> Option<Pair> {
+        self.get_action_pair(quote)
+            .map(|pair| Pair(wrap_native(pair.0), wrap_native(pair.1)))
+    }
+
+    fn get_action_pair(&self, quote: Address) -> Option<Pair> {
         match &self.action {
             Action::Swap(s) => Some(Pair(s.token_in.address, s.token_out.address)),
             Action::Mint(m) => Some(Pair(
@@ -250,3 +312,12 @@ impl PoolUpdate {
         }
     }
 }
+
+/// native eth is priced through weth
+fn wrap_native(token: Address) -> Address {
+    if token == ETH_ADDRESS {
+        WETH_ADDRESS
+    } else {
+        token
+    }
+}
@@ -13,12 +13,30 @@ pub fn init(layers: Vec<BoxedLayer<Registry>>) {
     let _ = tracing_subscriber::registry().with(layers).try_init();
 }
 
-use tracing_subscriber::{layer::Layer, util::SubscriberInitExt, EnvFilter};
+use tracing_subscriber::{fmt::MakeWriter, layer::Layer, util::SubscriberInitExt, EnvFilter};
 
 pub fn stdout<S>(default_directive: impl Display) -> BoxedLayer<S>
 where
     S: Subscriber,
     for<'a> S: LookupSpan<'a>,
+{
+    fmt_layer(default_directive, std::io::stdout)
+}
+
+/// Same as [`stdout`] but logs to stderr, keeping stdout free for output.
+pub fn stderr<S>(default_directive: impl Display) -> BoxedLayer<S>
+where
+    S: Subscriber,
+    for<'a> S: LookupSpan<'a>,
+{
+    fmt_layer(default_directive, std::io::stderr)
+}
+
+fn fmt_layer<S, W>(default_directive: impl Display, writer: W) -> BoxedLayer<S>
+where
+    S: Subscriber,
+    for<'a> S: LookupSpan<'a>,
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
 {
     let filter = EnvFilter::builder()
         .with_default_directive(default_directive.to_string().parse().unwrap())
@@ -28,6 +46,7 @@ where
     tracing_subscriber::fmt::layer()
         .with_ansi(true)
         .with_target(true)
+        .with_writer(writer)
         .with_filter(filter)
         .boxed()
 }
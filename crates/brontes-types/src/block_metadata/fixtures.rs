@@ -0,0 +1,230 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{RelayBid, RelayDataProvider, RelayPayload, Relays, UltrasoundAdjBid};
+
+/// Relay bids and payloads recorded from the data api of the relays. Serves
+/// them the same way the relays would so relay metadata can be backfilled
+/// offline and deterministically.
+#[derive(Debug, Clone, Default)]
+pub struct RelayFixtures {
+    bids:           HashMap<(Relays, u64), Vec<RelayBid>>,
+    payloads:       HashMap<(Relays, u64), Vec<RelayPayload>>,
+    /// Ultrasound adjustments keyed by block, the data api queries them by
+    /// slot so the slot of each block is taken from the bids and payloads
+    adjustments:    HashMap<u64, Vec<UltrasoundAdjBid>>,
+    slot_to_blocks: HashMap<u64, HashSet<u64>>,
+}
+
+impl RelayFixtures {
+    pub fn new(
+        bids: Vec<RelayBid>,
+        payloads: Vec<RelayPayload>,
+        adjustments: Vec<UltrasoundAdjBid>,
+    ) -> Self {
+        let mut this = Self::default();
+
+        for bid in bids {
+            this.slot_to_blocks
+                .entry(bid.slot)
+                .or_default()
+                .insert(bid.block_number);
+            this.bids
+                .entry((bid.relay, bid.block_number))
+                .or_default()
+                .push(bid);
+        }
+
+        for payload in payloads {
+            this.slot_to_blocks
+                .entry(payload.slot)
+                .or_default()
+                .insert(payload.block_number);
+            this.payloads
+                .entry((payload.relay, payload.block_number))
+                .or_default()
+                .push(payload);
+        }
+
+        for adjustment in adjustments {
+            this.adjustments
+                .entry(adjustment.block_number)
+                .or_default()
+                .push(adjustment);
+        }
+
+        this
+    }
+
+    /// The blocks that have at least one recorded bid or payload
+    pub fn blocks(&self) -> HashSet<u64> {
+        self.slot_to_blocks.values().flatten().copied().collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bids.is_empty() && self.payloads.is_empty()
+    }
+}
+
+impl RelayDataProvider for RelayFixtures {
+    async fn received_bids(&self, relay: Relays, block_number: u64) -> eyre::Result<Vec<RelayBid>> {
+        Ok(self
+            .bids
+            .get(&(relay, block_number))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn delivered_payloads(
+        &self,
+        relay: Relays,
+        block_number: u64,
+    ) -> eyre::Result<Vec<RelayPayload>> {
+        Ok(self
+            .payloads
+            .get(&(relay, block_number))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn ultrasound_adjustments(
+        &self,
+        slots: HashSet<u64>,
+    ) -> eyre::Result<Vec<UltrasoundAdjBid>> {
+        Ok(slots
+            .iter()
+            .filter_map(|slot| self.slot_to_blocks.get(slot))
+            .flatten()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .filter_map(|block| self.adjustments.get(block))
+            .flatten()
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use reth_primitives::{Address, BlockHash};
+
+    use super::*;
+
+    const BLOCK: u64 = 19_000_000;
+    const SLOT: u64 = 8_200_000;
+
+    fn fee_recipient() -> Address {
+        Address::with_last_byte(7)
+    }
+
+    fn bid(relay: Relays, block_hash: BlockHash, value: u128, timestamp_ms: u64) -> RelayBid {
+        RelayBid {
+            relay,
+            slot: SLOT,
+            parent_hash: format!("{:?}", BlockHash::ZERO),
+            block_hash: format!("{:?}", block_hash),
+            builder_pubkey: "0xbuilder".to_string(),
+            proposer_fee_recipient: format!("{:?}", fee_recipient()),
+            gas_limit: 30_000_000,
+            gas_used: 15_000_000,
+            value,
+            block_number: BLOCK,
+            num_tx: 100,
+            timestamp: timestamp_ms / 1000,
+            timestamp_ms,
+        }
+    }
+
+    fn payload(relay: Relays, block_hash: BlockHash, value: u128) -> RelayPayload {
+        RelayPayload {
+            relay,
+            slot: SLOT,
+            parent_hash: format!("{:?}", BlockHash::ZERO),
+            block_hash: format!("{:?}", block_hash),
+            builder_pubkey: "0xbuilder".to_string(),
+            proposer_fee_recipient: format!("{:?}", fee_recipient()),
+            gas_limit: 30_000_000,
+            gas_used: 15_000_000,
+            value,
+            block_number: BLOCK,
+            num_tx: 100,
+        }
+    }
+
+    #[brontes_macros::test]
+    async fn test_fixture_relay_metadata() {
+        let delivered = BlockHash::with_last_byte(1);
+        let fixtures = RelayFixtures::new(
+            vec![
+                bid(Relays::Flashbots, delivered, 100, 2_000),
+                bid(Relays::Titan, delivered, 100, 1_500),
+                bid(Relays::Titan, BlockHash::with_last_byte(2), 120, 1_000),
+            ],
+            vec![],
+            vec![],
+        );
+
+        let metadata = Relays::get_relay_metadata_from(&fixtures, BLOCK, delivered)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(metadata.relay_timestamp, Some(1_500));
+        assert_eq!(metadata.proposer_mev_reward, 100);
+        assert_eq!(metadata.proposer_fee_recipient, fee_recipient());
+
+        let bids = Relays::get_block_bids_from(&fixtures, BLOCK).await;
+        assert_eq!(bids.len(), 3);
+    }
+
+    #[brontes_macros::test]
+    async fn test_fixture_ultrasound_adjustment() {
+        let submitted = BlockHash::with_last_byte(3);
+        let adjusted = BlockHash::with_last_byte(4);
+        let fixtures = RelayFixtures::new(
+            vec![bid(Relays::UltraSound, submitted, 100, 1_000)],
+            vec![],
+            vec![UltrasoundAdjBid {
+                adjusted_block_hash:   format!("{:?}", adjusted),
+                adjusted_value:        90,
+                block_number:          BLOCK,
+                builder_pubkey:        "0xbuilder".to_string(),
+                delta:                 10,
+                submitted_block_hash:  format!("{:?}", submitted),
+                submitted_received_at: String::new(),
+                submitted_value:       100,
+            }],
+        );
+
+        let metadata = Relays::get_relay_metadata_from(&fixtures, BLOCK, adjusted)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(metadata.relay_timestamp, Some(1_000));
+        assert_eq!(metadata.proposer_mev_reward, 90);
+    }
+
+    #[brontes_macros::test]
+    async fn test_fixture_payload_fallback() {
+        let delivered = BlockHash::with_last_byte(5);
+        let fixtures =
+            RelayFixtures::new(vec![], vec![payload(Relays::UltraSound, delivered, 80)], vec![]);
+
+        let metadata = Relays::get_relay_metadata_from(&fixtures, BLOCK, delivered)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(metadata.relay_timestamp, None);
+        assert_eq!(metadata.proposer_mev_reward, 80);
+        assert_eq!(fixtures.blocks(), HashSet::from([BLOCK]));
+
+        assert!(Relays::get_relay_metadata_from(&fixtures, BLOCK + 1, delivered)
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(Relays::from_str("UltraSound").unwrap(), Relays::UltraSound);
+    }
+}
@@ -5,6 +5,9 @@ pub use relays::*;
 
 mod bids_payloads;
 pub use bids_payloads::*;
+
+mod fixtures;
+pub use fixtures::*;
 use reth_primitives::Address;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
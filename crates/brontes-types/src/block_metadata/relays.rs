@@ -1,5 +1,6 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, future::Future};
 
+use redefined::self_convert_redefined;
 use relays_openapi::apis::{
     configuration::Configuration,
     data_api::{get_delivered_payloads, get_received_bids},
@@ -17,7 +18,8 @@ macro_rules! relays {
 
         #[derive(
             Debug, Copy, Clone, serde::Serialize, serde::Deserialize, Eq, PartialEq,
-            std::hash::Hash, PartialOrd, strum::EnumIter
+            std::hash::Hash, PartialOrd, strum::EnumIter, strum::EnumString, rkyv::Archive,
+            rkyv::Serialize, rkyv::Deserialize
         )]
         pub enum Relays {
             $($relay),*
@@ -88,6 +90,8 @@ relays!(
     ]
 );
 
+self_convert_redefined!(Relays);
+
 impl Relays {
     fn configuration(&self) -> Configuration {
         Configuration { base_path: self.url().to_string(), ..Default::default() }
@@ -96,13 +100,24 @@ impl Relays {
     pub async fn get_relay_metadata(
         block_number: u64,
         block_hash: BlockHash,
+    ) -> eyre::Result<Option<RelayBlockMetadata>> {
+        Self::get_relay_metadata_from(&RelayHttpClient, block_number, block_hash).await
+    }
+
+    pub async fn get_relay_metadata_from<P: RelayDataProvider>(
+        provider: &P,
+        block_number: u64,
+        block_hash: BlockHash,
     ) -> eyre::Result<Option<RelayBlockMetadata>> {
         let block_hash = format!("{:?}", block_hash);
         let bids = futures::future::join_all(Relays::iter().map(|relay| {
             let block_hash = block_hash.clone();
 
             async move {
-                match relay.get_winning_bid(block_number, block_hash).await {
+                match relay
+                    .get_winning_bid(provider, block_number, block_hash)
+                    .await
+                {
                     Ok(r) => Some(r),
                     Err(e) => {
                         tracing::warn!(%relay, "error getting bids - {:?}", e);
@@ -127,8 +142,8 @@ impl Relays {
             try's to the get the ultrasound relay payload as their bid might not have the right hash.
              */
             let relay = Relays::UltraSound;
-            match relay.get_payload(block_number).await {
-                Ok(r) => Ok(r.map(TryInto::try_into).transpose()?),
+            match provider.delivered_payloads(relay, block_number).await {
+                Ok(r) => Ok(r.into_iter().next().map(TryInto::try_into).transpose()?),
                 Err(e) => {
                     tracing::warn!(%relay, "error getting payloads - {:?}", e);
                     Ok(None)
@@ -137,14 +152,13 @@ impl Relays {
         }
     }
 
-    async fn get_winning_bid(
+    async fn get_winning_bid<P: RelayDataProvider>(
         self,
+        provider: &P,
         block_number: u64,
         block_hash: String,
     ) -> eyre::Result<Option<RelayBid>> {
-        let mut bids = self
-            .get_received_bids(None, None, Some(block_number.to_string()), None, None)
-            .await?;
+        let mut bids = provider.received_bids(self, block_number).await?;
 
         let find_winning_bid = |bids: Vec<RelayBid>| {
             bids.into_iter()
@@ -155,20 +169,7 @@ impl Relays {
         if let Some(winning_bid) = find_winning_bid(bids.clone()) {
             Ok(Some(winning_bid))
         } else if matches!(self, Relays::UltraSound) {
-            let slots = bids.iter().map(|bid| bid.slot).collect::<HashSet<_>>();
-            let adj_bids = get_ultrasound_adj(slots).await?;
-
-            bids.iter_mut().for_each(|bid| {
-                adj_bids.iter().for_each(|adj_bid| {
-                    if adj_bid.submitted_block_hash == bid.block_hash
-                        && adj_bid.submitted_value == bid.value
-                        && adj_bid.builder_pubkey == bid.builder_pubkey
-                    {
-                        bid.block_hash = adj_bid.adjusted_block_hash.clone();
-                        bid.value = adj_bid.adjusted_value;
-                    }
-                })
-            });
+            apply_ultrasound_adj(provider, &mut bids).await?;
 
             Ok(find_winning_bid(bids))
         } else {
@@ -176,76 +177,128 @@ impl Relays {
         }
     }
 
-    async fn get_payload(self, block_number: u64) -> eyre::Result<Option<RelayPayload>> {
-        let payloads = self
-            .get_delivered_payloads(
-                None,
-                None,
-                None,
-                None,
-                Some(block_number.to_string()),
-                None,
-                None,
-                None,
-            )
-            .await?;
-
-        Ok(payloads.first().cloned())
+    /// Fetches every bid the relays received for the block, with the
+    /// ultrasound bids replaced by their adjusted bid where there is one.
+    pub async fn get_block_bids(block_number: u64) -> Vec<RelayBid> {
+        Self::get_block_bids_from(&RelayHttpClient, block_number).await
+    }
+
+    pub async fn get_block_bids_from<P: RelayDataProvider>(
+        provider: &P,
+        block_number: u64,
+    ) -> Vec<RelayBid> {
+        let bids = futures::future::join_all(Relays::iter().map(|relay| async move {
+            match provider.received_bids(relay, block_number).await {
+                Ok(mut bids) if matches!(relay, Relays::UltraSound) => {
+                    if let Err(e) = apply_ultrasound_adj(provider, &mut bids).await {
+                        tracing::warn!(%relay, "error getting bid adjustments - {:?}", e);
+                    }
+                    bids
+                }
+                Ok(bids) => bids,
+                Err(e) => {
+                    tracing::warn!(%relay, "error getting bids - {:?}", e);
+                    vec![]
+                }
+            }
+        }))
+        .await;
+
+        bids.into_iter().flatten().collect()
     }
+}
 
-    async fn get_received_bids(
+/// Where relay bids and delivered payloads are read from. Either the data api
+/// of the relays or recorded dumps of it, see [`RelayFixtures`].
+pub trait RelayDataProvider: Send + Sync {
+    /// The bids the relay received for the block
+    fn received_bids(
         &self,
-        slot: Option<String>,
-        block_hash: Option<String>,
-        block_number: Option<String>,
-        builder_pubkey: Option<String>,
-        limit: Option<String>,
-    ) -> eyre::Result<Vec<RelayBid>> {
+        relay: Relays,
+        block_number: u64,
+    ) -> impl Future<Output = eyre::Result<Vec<RelayBid>>> + Send;
+
+    /// The payloads the relay delivered for the block
+    fn delivered_payloads(
+        &self,
+        relay: Relays,
+        block_number: u64,
+    ) -> impl Future<Output = eyre::Result<Vec<RelayPayload>>> + Send;
+
+    /// The ultrasound bid adjustments made in the slots
+    fn ultrasound_adjustments(
+        &self,
+        slots: HashSet<u64>,
+    ) -> impl Future<Output = eyre::Result<Vec<UltrasoundAdjBid>>> + Send;
+}
+
+/// Queries the data api of the relays over http.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RelayHttpClient;
+
+impl RelayDataProvider for RelayHttpClient {
+    async fn received_bids(&self, relay: Relays, block_number: u64) -> eyre::Result<Vec<RelayBid>> {
         let bids = get_received_bids(
-            &self.configuration(),
-            slot.as_deref(),
-            block_hash.as_deref(),
-            block_number.as_deref(),
-            builder_pubkey.as_deref(),
-            limit.as_deref(),
+            &relay.configuration(),
+            None,
+            None,
+            Some(&block_number.to_string()),
+            None,
+            None,
         )
         .await?;
 
         Ok(bids
             .into_iter()
-            .map(|bid| RelayBid::new(bid, *self))
+            .map(|bid| RelayBid::new(bid, relay))
             .collect())
     }
 
-    async fn get_delivered_payloads(
+    async fn delivered_payloads(
         &self,
-        slot: Option<String>,
-        cursor: Option<String>,
-        limit: Option<String>,
-        block_hash: Option<String>,
-        block_number: Option<String>,
-        proposer_pubkey: Option<String>,
-        builder_pubkey: Option<String>,
-        order_by: Option<String>,
+        relay: Relays,
+        block_number: u64,
     ) -> eyre::Result<Vec<RelayPayload>> {
         let payloads = get_delivered_payloads(
-            &self.configuration(),
-            slot.as_deref(),
-            cursor.as_deref(),
-            limit.as_deref(),
-            block_hash.as_deref(),
-            block_number.as_deref(),
-            proposer_pubkey.as_deref(),
-            builder_pubkey.as_deref(),
-            order_by.as_deref(),
+            &relay.configuration(),
+            None,
+            None,
+            None,
+            None,
+            Some(&block_number.to_string()),
+            None,
+            None,
+            None,
         )
         .await?;
 
         Ok(payloads
             .into_iter()
-            .map(|payload| RelayPayload::new(payload, *self))
+            .map(|payload| RelayPayload::new(payload, relay))
             .collect())
     }
+
+    async fn ultrasound_adjustments(
+        &self,
+        slots: HashSet<u64>,
+    ) -> eyre::Result<Vec<UltrasoundAdjBid>> {
+        let client = &reqwest::Client::new();
+
+        let adjusted = futures::future::join_all(slots.into_iter().map(|slot| async move {
+            let url = format!(
+                "https://relay-analytics.ultrasound.money/ultrasound/v1/data/adjustments?\
+                 slot={slot}"
+            );
+
+            let bid: UltrasoundAdjBidResponse = client.get(url).send().await?.json().await?;
+            Ok::<_, eyre::ErrReport>(bid.data)
+        }))
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(adjusted.into_iter().flatten().collect())
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -269,20 +322,24 @@ pub struct UltrasoundAdjBid {
     pub submitted_value:       u128,
 }
 
-async fn get_ultrasound_adj(slots: HashSet<u64>) -> eyre::Result<Vec<UltrasoundAdjBid>> {
-    let client = &reqwest::Client::new();
+async fn apply_ultrasound_adj<P: RelayDataProvider>(
+    provider: &P,
+    bids: &mut [RelayBid],
+) -> eyre::Result<()> {
+    let slots = bids.iter().map(|bid| bid.slot).collect::<HashSet<_>>();
+    let adj_bids = provider.ultrasound_adjustments(slots).await?;
 
-    let adjusted = futures::future::join_all(slots.into_iter().map(|slot| async move {
-        let url = format!(
-            "https://relay-analytics.ultrasound.money/ultrasound/v1/data/adjustments?slot={slot}"
-        );
-
-        let bid: UltrasoundAdjBidResponse = client.get(url).send().await?.json().await?;
-        Ok::<_, eyre::ErrReport>(bid.data)
-    }))
-    .await
-    .into_iter()
-    .collect::<Result<Vec<_>, _>>()?;
+    bids.iter_mut().for_each(|bid| {
+        adj_bids.iter().for_each(|adj_bid| {
+            if adj_bid.submitted_block_hash == bid.block_hash
+                && adj_bid.submitted_value == bid.value
+                && adj_bid.builder_pubkey == bid.builder_pubkey
+            {
+                bid.block_hash = adj_bid.adjusted_block_hash.clone();
+                bid.value = adj_bid.adjusted_value;
+            }
+        })
+    });
 
-    Ok(adjusted.into_iter().flatten().collect())
+    Ok(())
 }
@@ -38,6 +38,16 @@ impl<S: Stream<Item = Result<Bytes, reqwest::Error>>> DownloadBufWriterWithProgr
         }
     }
 
+    /// Starts the progress bar at the bytes already downloaded when resuming a
+    /// partial file
+    pub fn resume_from(self, downloaded_bytes: u64) -> Self {
+        self.progress_bar
+            .as_ref()
+            .inspect(|bar| bar.set_position(downloaded_bytes));
+
+        self
+    }
+
     fn is_over_buffer(&self) -> bool {
         self.buffer.len() > self.buffer_cap
     }
@@ -159,6 +169,9 @@ impl WriteProgress {
             let fut = Box::pin(async move {
                 let buf_moved = buf;
                 file_handle.write_all(&buf_moved).await.unwrap();
+                // wait for the write to land so the file is complete once the
+                // download resolves
+                file_handle.flush().await.unwrap();
                 file_handle
             }) as Pin<Box<dyn Future<Output = File> + Send + 'static>>;
             #[allow(clippy::missing_transmute_annotations)]
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     db::redefined_types::primitives::AddressRedefined,
     implement_table_value_codecs_with_zc,
+    pair::Pair,
     serde_utils::{addresss, option_addresss, protocol},
     Protocol,
 };
@@ -59,6 +60,18 @@ impl ProtocolInfo {
 
         tokens
     }
+
+    /// The token pairs the pool can be traded on. Pools with more than two
+    /// tokens back an edge for every combination of them.
+    pub fn pairs(&self) -> Vec<Pair> {
+        let tokens = [Some(self.token0), Some(self.token1), self.token2, self.token3, self.token4]
+            .into_iter()
+            .flatten()
+            .filter(|token| *token != Address::default())
+            .collect::<Vec<_>>();
+
+        Pair::all_pairs(&tokens)
+    }
 }
 
 impl IntoIterator for ProtocolInfo {
@@ -125,3 +138,28 @@ impl ProtocolInfoClickhouse {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multi_token_pool_pairs() {
+        let (dai, usdc, usdt, lp) = (
+            Address::with_last_byte(1),
+            Address::with_last_byte(2),
+            Address::with_last_byte(3),
+            Address::with_last_byte(4),
+        );
+        let info = ProtocolInfo {
+            protocol: Protocol::CurveBasePool3,
+            token0: dai,
+            token1: usdc,
+            token2: Some(usdt),
+            curve_lp_token: Some(lp),
+            ..Default::default()
+        };
+
+        assert_eq!(info.pairs(), vec![Pair(dai, usdc), Pair(dai, usdt), Pair(usdc, usdt)]);
+    }
+}
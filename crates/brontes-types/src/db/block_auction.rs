@@ -0,0 +1,235 @@
+use ::clickhouse::DbRow;
+use redefined::Redefined;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{ser::SerializeStruct, Deserialize, Serialize};
+
+use crate::{
+    block_metadata::{RelayBid, Relays},
+    implement_table_value_codecs_with_zc,
+    mev::MevBlock,
+};
+
+/// A bid a builder submitted to a relay for the block, values are in wei.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct AuctionBid {
+    #[redefined(same_fields)]
+    pub relay:          Relays,
+    pub builder_pubkey: String,
+    pub block_hash:     String,
+    pub value:          u128,
+    pub timestamp_ms:   u64,
+}
+
+impl From<RelayBid> for AuctionBid {
+    fn from(bid: RelayBid) -> Self {
+        Self {
+            relay:          bid.relay,
+            builder_pubkey: bid.builder_pubkey,
+            block_hash:     bid.block_hash,
+            value:          bid.value,
+            timestamp_ms:   bid.timestamp_ms,
+        }
+    }
+}
+
+/// The mev-boost auction of a block. Holds every bid the relays received
+/// for the slot along with how the winning bid compared to the best bid of
+/// the other builders and what the winning builder actually made.
+#[derive(Debug, Clone, PartialEq, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct BlockAuction {
+    pub block_number:        u64,
+    pub slot:                u64,
+    pub winning_bid:         AuctionBid,
+    /// The highest bid of any other builder
+    pub runner_up_bid:       Option<AuctionBid>,
+    /// How much the winning bid outbid the runner up by, in wei. Negative
+    /// when a higher bid arrived after the winning bid was delivered
+    pub bid_margin:          Option<i128>,
+    pub builder_eth_profit:  f64,
+    pub builder_profit_usd:  f64,
+    /// What the builder paid the proposer on top of what the block made it,
+    /// in eth
+    pub builder_subsidy_eth: f64,
+    /// All bids ordered by the time they were received
+    pub bids:                Vec<AuctionBid>,
+}
+
+impl BlockAuction {
+    /// Builds the auction from all bids received for the block. Returns
+    /// `None` when none of the bids is for the block that was delivered.
+    pub fn new(block: &MevBlock, mut bids: Vec<RelayBid>) -> Option<Self> {
+        let block_hash = format!("{:?}", block.block_hash);
+        bids.sort_unstable();
+
+        // the first relay to receive the delivered block is the one it came from
+        let winning_bid = bids
+            .iter()
+            .find(|bid| bid.block_hash.to_lowercase() == block_hash)?
+            .clone();
+
+        let runner_up_bid = bids
+            .iter()
+            .filter(|bid| bid.builder_pubkey != winning_bid.builder_pubkey)
+            .max_by_key(|bid| bid.value)
+            .cloned();
+
+        let bid_margin = runner_up_bid
+            .as_ref()
+            .map(|runner_up| winning_bid.value as i128 - runner_up.value as i128);
+
+        Some(Self {
+            block_number:        block.block_number,
+            slot:                winning_bid.slot,
+            winning_bid:         winning_bid.into(),
+            runner_up_bid:       runner_up_bid.map(Into::into),
+            bid_margin,
+            builder_eth_profit:  block.builder_eth_profit,
+            builder_profit_usd:  block.builder_profit_usd,
+            builder_subsidy_eth: (-block.builder_eth_profit).max(0.0),
+            bids:                bids.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
+implement_table_value_codecs_with_zc!(BlockAuctionRedefined);
+
+impl Serialize for BlockAuction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut ser_struct = serializer.serialize_struct("BlockAuction", 20)?;
+
+        ser_struct.serialize_field("block_number", &self.block_number)?;
+        ser_struct.serialize_field("slot", &self.slot)?;
+
+        ser_struct.serialize_field("winning_relay", &self.winning_bid.relay.to_string())?;
+        ser_struct.serialize_field("winning_builder_pubkey", &self.winning_bid.builder_pubkey)?;
+        ser_struct.serialize_field("winning_block_hash", &self.winning_bid.block_hash)?;
+        ser_struct.serialize_field("winning_bid", &self.winning_bid.value)?;
+        ser_struct.serialize_field("winning_bid_timestamp_ms", &self.winning_bid.timestamp_ms)?;
+
+        let runner_up = self.runner_up_bid.as_ref();
+        ser_struct
+            .serialize_field("runner_up_relay", &runner_up.map(|bid| bid.relay.to_string()))?;
+        ser_struct.serialize_field(
+            "runner_up_builder_pubkey",
+            &runner_up.map(|bid| bid.builder_pubkey.clone()),
+        )?;
+        ser_struct.serialize_field("runner_up_bid", &runner_up.map(|bid| bid.value))?;
+        ser_struct.serialize_field(
+            "runner_up_bid_timestamp_ms",
+            &runner_up.map(|bid| bid.timestamp_ms),
+        )?;
+        ser_struct.serialize_field("bid_margin", &self.bid_margin)?;
+
+        ser_struct.serialize_field("builder_eth_profit", &self.builder_eth_profit)?;
+        ser_struct.serialize_field("builder_profit_usd", &self.builder_profit_usd)?;
+        ser_struct.serialize_field("builder_subsidy_eth", &self.builder_subsidy_eth)?;
+
+        let mut relays = Vec::with_capacity(self.bids.len());
+        let mut builder_pubkeys = Vec::with_capacity(self.bids.len());
+        let mut block_hashes = Vec::with_capacity(self.bids.len());
+        let mut values = Vec::with_capacity(self.bids.len());
+        let mut timestamps = Vec::with_capacity(self.bids.len());
+
+        self.bids.iter().for_each(|bid| {
+            relays.push(bid.relay.to_string());
+            builder_pubkeys.push(bid.builder_pubkey.clone());
+            block_hashes.push(bid.block_hash.clone());
+            values.push(bid.value);
+            timestamps.push(bid.timestamp_ms);
+        });
+
+        ser_struct.serialize_field("bids.relay", &relays)?;
+        ser_struct.serialize_field("bids.builder_pubkey", &builder_pubkeys)?;
+        ser_struct.serialize_field("bids.block_hash", &block_hashes)?;
+        ser_struct.serialize_field("bids.value", &values)?;
+        ser_struct.serialize_field("bids.timestamp_ms", &timestamps)?;
+
+        ser_struct.end()
+    }
+}
+
+impl DbRow for BlockAuction {
+    const COLUMN_NAMES: &'static [&'static str] = &[
+        "block_number",
+        "slot",
+        "winning_relay",
+        "winning_builder_pubkey",
+        "winning_block_hash",
+        "winning_bid",
+        "winning_bid_timestamp_ms",
+        "runner_up_relay",
+        "runner_up_builder_pubkey",
+        "runner_up_bid",
+        "runner_up_bid_timestamp_ms",
+        "bid_margin",
+        "builder_eth_profit",
+        "builder_profit_usd",
+        "builder_subsidy_eth",
+        "bids.relay",
+        "bids.builder_pubkey",
+        "bids.block_hash",
+        "bids.value",
+        "bids.timestamp_ms",
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use reth_primitives::B256;
+
+    use super::*;
+
+    fn bid(builder: &str, block_hash: B256, value: u128, timestamp_ms: u64) -> RelayBid {
+        RelayBid {
+            relay: Relays::Flashbots,
+            slot: 8_000_000,
+            parent_hash: String::new(),
+            block_hash: format!("{:?}", block_hash),
+            builder_pubkey: builder.to_string(),
+            proposer_fee_recipient: String::new(),
+            gas_limit: 30_000_000,
+            gas_used: 15_000_000,
+            value,
+            block_number: 19_000_000,
+            num_tx: 100,
+            timestamp: timestamp_ms / 1000,
+            timestamp_ms,
+        }
+    }
+
+    #[test]
+    fn test_block_auction() {
+        let (delivered, other) = (B256::with_last_byte(1), B256::with_last_byte(2));
+        let block = MevBlock {
+            block_hash:         delivered,
+            block_number:       19_000_000,
+            builder_eth_profit: -0.01,
+            ..Default::default()
+        };
+
+        let bids = vec![
+            bid("titan", other, 90, 1_000),
+            bid("beaver", delivered, 100, 2_000),
+            bid("beaver", other, 120, 3_000),
+            bid("rsync", other, 95, 2_500),
+        ];
+        let auction = BlockAuction::new(&block, bids).unwrap();
+
+        assert_eq!(auction.winning_bid.builder_pubkey, "beaver");
+        assert_eq!(auction.runner_up_bid.unwrap().builder_pubkey, "rsync");
+        assert_eq!(auction.bid_margin, Some(5));
+        assert_eq!(auction.builder_subsidy_eth, 0.01);
+        assert_eq!(auction.bids.len(), 4);
+        assert!(auction
+            .bids
+            .windows(2)
+            .all(|w| w[0].timestamp_ms <= w[1].timestamp_ms));
+
+        assert!(BlockAuction::new(&block, vec![bid("titan", other, 90, 1_000)]).is_none());
+    }
+}
@@ -1,12 +1,13 @@
 use std::default::Default;
 
 use alloy_primitives::Address;
-use malachite::{num::conversion::traits::FromSciString, Rational};
+use malachite::Rational;
 use redefined::self_convert_redefined;
 use serde::Deserialize;
 use strum::Display;
 
-use crate::constants::*;
+use super::{CexFeeSchedule, InstrumentType};
+use crate::{constants::*, pair::Pair};
 
 #[derive(
     Copy,
@@ -210,66 +211,11 @@ impl CexExchange {
         }
     }
 
-    /// Returns the maker & taker fees by exchange
-    /// Assumes best possible fee structure e.g Binanace VIP 9 for example
-    /// Does not account for special market maker rebate programs or special
-    /// pairs
-    ///
-    /// TODO: Account for special fee pairs & stableswap rates
-    /// TODO: Account for futures & spot fee deltas
-    pub fn fees(&self) -> (Rational, Rational) {
+    /// Returns the maker & taker spot fees of the exchange for `pair` under
+    /// the header tier of `schedule`. Pairs with special rates, e.g
+    /// stableswaps, use the fees the schedule sets for them
+    pub fn fees(&self, schedule: &CexFeeSchedule, pair: &Pair) -> (Rational, Rational) {
         match self {
-            CexExchange::Binance => (
-                Rational::from_sci_string("0.00012").unwrap(),
-                Rational::from_sci_string("0.00024").unwrap(),
-            ),
-            CexExchange::Bitmex => (
-                Rational::from_sci_string("-0.00025").unwrap(),
-                Rational::from_sci_string("0.00075").unwrap(),
-            ),
-            CexExchange::Deribit => {
-                (Rational::from_sci_string("0").unwrap(), Rational::from_sci_string("0").unwrap())
-            }
-            CexExchange::Okex => (
-                Rational::from_sci_string("-0.00005").unwrap(),
-                Rational::from_sci_string("0.00015").unwrap(),
-            ),
-            CexExchange::Coinbase => (
-                Rational::from_sci_string("0").unwrap(),
-                Rational::from_sci_string("0.0005").unwrap(),
-            ),
-            CexExchange::Kraken => (
-                Rational::from_sci_string("0").unwrap(),
-                Rational::from_sci_string("0.001").unwrap(),
-            ),
-            CexExchange::BybitSpot => (
-                Rational::from_sci_string("0.00005").unwrap(),
-                Rational::from_sci_string("0.00015").unwrap(),
-            ),
-            CexExchange::Kucoin => (
-                Rational::from_sci_string("-0.00005").unwrap(),
-                Rational::from_sci_string("0.00025").unwrap(),
-            ),
-            CexExchange::Upbit => (
-                Rational::from_sci_string("0.0002").unwrap(),
-                Rational::from_sci_string("0.0002").unwrap(),
-            ),
-            CexExchange::Huobi => (
-                Rational::from_sci_string("0.000097").unwrap(),
-                Rational::from_sci_string("0.000193").unwrap(),
-            ),
-            CexExchange::GateIo => (
-                Rational::from_sci_string("0").unwrap(),
-                Rational::from_sci_string("0.0002").unwrap(),
-            ),
-            CexExchange::Bitstamp => (
-                Rational::from_sci_string("0").unwrap(),
-                Rational::from_sci_string("0.0003").unwrap(),
-            ),
-            CexExchange::Gemini => (
-                Rational::from_sci_string("0").unwrap(),
-                Rational::from_sci_string("0.0003").unwrap(),
-            ),
             CexExchange::Average => {
                 unreachable!("Cannot get fees for cross exchange average quote")
             }
@@ -277,6 +223,9 @@ impl CexExchange {
             CexExchange::VWAP | CexExchange::OptimisticVWAP => {
                 unreachable!("Cannot get fees for VWAP")
             }
+            exchange => schedule
+                .fees(*exchange, schedule.header_tier(), InstrumentType::Spot, pair)
+                .expect("cex fee schedule has spot fees for every exchange"),
         }
     }
 }
@@ -0,0 +1,415 @@
+//! Cex trading fees by exchange, fee tier and instrument, loaded from a toml
+//! schedule. Without one, the schedule in `config/cex_fee_schedule.toml` is
+//! used.
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use alloy_primitives::Address;
+use malachite::{
+    num::{basic::traits::One, conversion::traits::FromSciString},
+    Rational,
+};
+use serde::{Deserialize, Deserializer};
+use strum::{Display, EnumString};
+
+use super::CexExchange;
+use crate::{pair::Pair, FastHashMap};
+
+const DEFAULT_CEX_FEE_SCHEDULE: &str = include_str!("../../../../../config/cex_fee_schedule.toml");
+
+/// Exchanges every reported tier needs spot fees for
+const SPOT_EXCHANGES: [CexExchange; 13] = [
+    CexExchange::Binance,
+    CexExchange::Bitmex,
+    CexExchange::Deribit,
+    CexExchange::Okex,
+    CexExchange::Coinbase,
+    CexExchange::Kraken,
+    CexExchange::BybitSpot,
+    CexExchange::Kucoin,
+    CexExchange::Upbit,
+    CexExchange::Huobi,
+    CexExchange::GateIo,
+    CexExchange::Bitstamp,
+    CexExchange::Gemini,
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum CexFeeScheduleError {
+    #[error("failed to read cex fee schedule {0}: {1}")]
+    Read(PathBuf, std::io::Error),
+    #[error(transparent)]
+    Parse(#[from] toml::de::Error),
+    #[error("cex fee schedule has no tiers to report")]
+    NoReportTiers,
+    #[error("unknown exchange in cex fee schedule")]
+    UnknownExchange,
+    #[error("{0} {1} {2} fees are listed twice")]
+    Duplicate(CexExchange, String, InstrumentType),
+    #[error("{0} {1} {2} fees must be below 100%")]
+    InvalidFee(CexExchange, String, InstrumentType),
+    #[error("fee tier {1} has no spot fees for {0}")]
+    MissingSpotFees(CexExchange, String),
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Deserialize, Display, EnumString)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum InstrumentType {
+    #[default]
+    Spot,
+    Perpetual,
+}
+
+impl InstrumentType {
+    /// The instrument traded on a venue, as named by Tardis. The perpetual
+    /// venues are mapped to the exchange of their spot venue by
+    /// [`CexExchange::from`], e.g. `binance-futures` is Binance USDⓈ-M and
+    /// `bybit` is Bybit linear & inverse
+    pub fn from_venue(venue: &str) -> Self {
+        match venue.to_lowercase().as_str() {
+            "binance-futures" | "okex-swap" | "bybit" => Self::Perpetual,
+            _ => Self::Spot,
+        }
+    }
+}
+
+/// The maker & taker fees of an exchange for a fee tier and instrument
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct FeeEntry {
+    exchange:   CexExchange,
+    tier:       String,
+    #[serde(default)]
+    instrument: InstrumentType,
+    #[serde(deserialize_with = "deserialize_fee")]
+    maker:      Rational,
+    #[serde(deserialize_with = "deserialize_fee")]
+    taker:      Rational,
+    #[serde(default)]
+    pairs:      Vec<PairFees>,
+}
+
+/// Fees of a pair that override the fees of its entry
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct PairFees {
+    base:  Address,
+    quote: Address,
+    #[serde(deserialize_with = "deserialize_fee")]
+    maker: Rational,
+    #[serde(deserialize_with = "deserialize_fee")]
+    taker: Rational,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct CexFeeScheduleConfig {
+    report_tiers: Vec<String>,
+    #[serde(default)]
+    fees:         Vec<FeeEntry>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct TierFees {
+    fees:  (Rational, Rational),
+    pairs: FastHashMap<Pair, (Rational, Rational)>,
+}
+
+/// Maker & taker fees keyed by exchange, instrument and fee tier
+#[derive(Debug, Clone, PartialEq)]
+pub struct CexFeeSchedule {
+    report_tiers: Vec<String>,
+    fees:         FastHashMap<(CexExchange, InstrumentType), FastHashMap<String, TierFees>>,
+}
+
+impl FromStr for CexFeeSchedule {
+    type Err = CexFeeScheduleError;
+
+    fn from_str(schedule: &str) -> Result<Self, Self::Err> {
+        let config: CexFeeScheduleConfig = toml::from_str(schedule)?;
+        if config.report_tiers.is_empty() {
+            return Err(CexFeeScheduleError::NoReportTiers)
+        }
+
+        let mut fees: FastHashMap<_, FastHashMap<_, _>> = FastHashMap::default();
+        for entry in config.fees {
+            if entry.exchange == CexExchange::Unknown {
+                return Err(CexFeeScheduleError::UnknownExchange)
+            }
+            if entry.maker >= Rational::ONE
+                || entry.taker >= Rational::ONE
+                || entry
+                    .pairs
+                    .iter()
+                    .any(|pair| pair.maker >= Rational::ONE || pair.taker >= Rational::ONE)
+            {
+                return Err(CexFeeScheduleError::InvalidFee(
+                    entry.exchange,
+                    entry.tier,
+                    entry.instrument,
+                ))
+            }
+
+            let tier_fees = TierFees {
+                fees:  (entry.maker, entry.taker),
+                pairs: entry
+                    .pairs
+                    .into_iter()
+                    .map(|pair| (Pair(pair.base, pair.quote), (pair.maker, pair.taker)))
+                    .collect(),
+            };
+            let tiers = fees.entry((entry.exchange, entry.instrument)).or_default();
+            if tiers.insert(entry.tier.clone(), tier_fees).is_some() {
+                return Err(CexFeeScheduleError::Duplicate(
+                    entry.exchange,
+                    entry.tier,
+                    entry.instrument,
+                ))
+            }
+        }
+
+        for tier in &config.report_tiers {
+            for exchange in SPOT_EXCHANGES {
+                if !fees
+                    .get(&(exchange, InstrumentType::Spot))
+                    .is_some_and(|tiers| tiers.contains_key(tier))
+                {
+                    return Err(CexFeeScheduleError::MissingSpotFees(exchange, tier.clone()))
+                }
+            }
+        }
+
+        Ok(Self { report_tiers: config.report_tiers, fees })
+    }
+}
+
+impl Default for CexFeeSchedule {
+    fn default() -> Self {
+        DEFAULT_CEX_FEE_SCHEDULE
+            .parse()
+            .expect("invalid default cex fee schedule")
+    }
+}
+
+impl CexFeeSchedule {
+    pub fn load(path: &Path) -> Result<Self, CexFeeScheduleError> {
+        std::fs::read_to_string(path)
+            .map_err(|e| CexFeeScheduleError::Read(path.to_path_buf(), e))?
+            .parse()
+    }
+
+    /// Loads the schedule at `path`, or the default schedule if there is none
+    pub fn load_or_default(path: Option<&Path>) -> Result<Self, CexFeeScheduleError> {
+        match path {
+            Some(path) => Self::load(path),
+            None => DEFAULT_CEX_FEE_SCHEDULE.parse(),
+        }
+    }
+
+    /// The tiers the cex-dex inspectors report pnl under
+    pub fn report_tiers(&self) -> &[String] {
+        &self.report_tiers
+    }
+
+    /// The tier cex prices are fee adjusted with
+    pub fn header_tier(&self) -> &str {
+        &self.report_tiers[0]
+    }
+
+    /// The maker & taker fees of `pair` on the exchange, the fees of the tier
+    /// if the pair has no fees of its own
+    pub fn fees(
+        &self,
+        exchange: CexExchange,
+        tier: &str,
+        instrument: InstrumentType,
+        pair: &Pair,
+    ) -> Option<(Rational, Rational)> {
+        let tier_fees = self.fees.get(&(exchange, instrument))?.get(tier)?;
+
+        Some(
+            tier_fees
+                .pairs
+                .get(pair)
+                .or_else(|| tier_fees.pairs.get(&pair.flip()))
+                .unwrap_or(&tier_fees.fees)
+                .clone(),
+        )
+    }
+
+    /// The maker & taker factors that turn a price fee adjusted with the
+    /// header tier over the hops of `pairs` into one fee adjusted with `tier`
+    pub fn tier_adjustment(
+        &self,
+        exchange: CexExchange,
+        tier: &str,
+        instrument: InstrumentType,
+        pairs: &[Pair],
+    ) -> Option<(Rational, Rational)> {
+        pairs
+            .iter()
+            .try_fold((Rational::ONE, Rational::ONE), |(maker, taker), pair| {
+                let header = self.fees(exchange, self.header_tier(), instrument, pair)?;
+                let fees = self.fees(exchange, tier, instrument, pair)?;
+
+                Some((
+                    maker * (Rational::ONE - fees.0) / (Rational::ONE - header.0),
+                    taker * (Rational::ONE - fees.1) / (Rational::ONE - header.1),
+                ))
+            })
+    }
+}
+
+/// Fees are toml floats, parsed from their shortest decimal representation so
+/// that `0.00012` is exactly 12 / 100_000
+fn deserialize_fee<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Rational, D::Error> {
+    let fee = f64::deserialize(deserializer)?;
+    Rational::from_sci_string(&fee.to_string())
+        .ok_or_else(|| serde::de::Error::custom(format!("invalid fee {fee}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{USDC_ADDRESS, USDT_ADDRESS, WETH_ADDRESS};
+
+    fn fee(value: &str) -> Rational {
+        Rational::from_sci_string(value).unwrap()
+    }
+
+    fn entry(exchange: &str, tier: &str, maker: &str, taker: &str) -> String {
+        format!(
+            "[[fees]]\nexchange = \"{exchange}\"\ntier = \"{tier}\"\nmaker = {maker}\ntaker = \
+             {taker}\n"
+        )
+    }
+
+    /// Best tier spot fees for every exchange followed by `extra`
+    fn schedule_with(report_tiers: &str, extra: &str) -> String {
+        let spot = SPOT_EXCHANGES
+            .iter()
+            .map(|exchange| entry(&exchange.to_string(), "best", "0.0001", "0.0002"))
+            .collect::<String>();
+
+        format!("report_tiers = {report_tiers}\n{spot}{extra}")
+    }
+
+    #[test]
+    fn test_default_schedule() {
+        let schedule: CexFeeSchedule = DEFAULT_CEX_FEE_SCHEDULE.parse().unwrap();
+        let pair = Pair(WETH_ADDRESS, USDT_ADDRESS);
+
+        assert_eq!(schedule.report_tiers(), ["best", "retail"]);
+        assert_eq!(schedule.header_tier(), "best");
+        assert_eq!(
+            schedule.fees(CexExchange::Binance, "best", InstrumentType::Spot, &pair),
+            Some((fee("0.00012"), fee("0.00024")))
+        );
+        assert_eq!(
+            schedule.fees(CexExchange::Okex, "best", InstrumentType::Spot, &pair),
+            Some((fee("-0.00005"), fee("0.00015")))
+        );
+        assert_eq!(
+            schedule.fees(CexExchange::Coinbase, "retail", InstrumentType::Spot, &pair),
+            Some((fee("0.004"), fee("0.006")))
+        );
+        assert!(schedule
+            .fees(CexExchange::Binance, "best", InstrumentType::Perpetual, &pair)
+            .is_some());
+        assert!(schedule
+            .fees(CexExchange::BybitSpot, "retail", InstrumentType::Perpetual, &pair)
+            .is_some());
+        assert!(schedule
+            .fees(CexExchange::Coinbase, "best", InstrumentType::Perpetual, &pair)
+            .is_none());
+    }
+
+    #[test]
+    fn test_instrument_from_venue() {
+        assert_eq!(InstrumentType::from_venue("binance"), InstrumentType::Spot);
+        assert_eq!(InstrumentType::from_venue("binance-futures"), InstrumentType::Perpetual);
+        assert_eq!(InstrumentType::from_venue("Bybit"), InstrumentType::Perpetual);
+        assert_eq!(InstrumentType::from_venue("bybit-spot"), InstrumentType::Spot);
+        assert_eq!("perpetual".parse(), Ok(InstrumentType::Perpetual));
+    }
+
+    #[test]
+    fn test_pair_override() {
+        let stable_pair =
+            format!("[[fees.pairs]]\nbase = \"{USDC_ADDRESS}\"\nquote = \"{USDT_ADDRESS}\"\n");
+        let schedule: CexFeeSchedule =
+            schedule_with(r#"["best"]"#, &format!("{stable_pair}maker = 0.0\ntaker = 0.00005\n"))
+                .parse()
+                .unwrap();
+
+        let gemini =
+            |pair: Pair| schedule.fees(CexExchange::Gemini, "best", InstrumentType::Spot, &pair);
+        let stable = (fee("0"), fee("0.00005"));
+        assert_eq!(gemini(Pair(USDC_ADDRESS, USDT_ADDRESS)), Some(stable.clone()));
+        assert_eq!(gemini(Pair(USDT_ADDRESS, USDC_ADDRESS)), Some(stable));
+        assert_eq!(gemini(Pair(WETH_ADDRESS, USDT_ADDRESS)), Some((fee("0.0001"), fee("0.0002"))));
+
+        let binance = schedule.fees(
+            CexExchange::Binance,
+            "best",
+            InstrumentType::Spot,
+            &Pair(USDC_ADDRESS, USDT_ADDRESS),
+        );
+        assert_eq!(binance, Some((fee("0.0001"), fee("0.0002"))));
+    }
+
+    #[test]
+    fn test_tier_adjustment() {
+        let schedule: CexFeeSchedule = DEFAULT_CEX_FEE_SCHEDULE.parse().unwrap();
+        let pairs = [Pair(WETH_ADDRESS, USDC_ADDRESS), Pair(USDC_ADDRESS, USDT_ADDRESS)];
+
+        let adjustment = schedule
+            .tier_adjustment(CexExchange::Binance, "best", InstrumentType::Spot, &pairs)
+            .unwrap();
+        assert_eq!(adjustment, (Rational::ONE, Rational::ONE));
+
+        let (maker, taker) = schedule
+            .tier_adjustment(CexExchange::Binance, "retail", InstrumentType::Spot, &pairs)
+            .unwrap();
+        let hop =
+            |retail: &str, best: &str| (Rational::ONE - fee(retail)) / (Rational::ONE - fee(best));
+        assert_eq!(maker, hop("0.001", "0.00012") * hop("0.001", "0.00012"));
+        assert_eq!(taker, hop("0.001", "0.00024") * hop("0.001", "0.00024"));
+
+        assert!(schedule
+            .tier_adjustment(CexExchange::Binance, "vip 3", InstrumentType::Spot, &pairs)
+            .is_none());
+    }
+
+    #[test]
+    fn test_invalid_schedules() {
+        assert!(matches!(
+            "report_tiers = []".parse::<CexFeeSchedule>(),
+            Err(CexFeeScheduleError::NoReportTiers)
+        ));
+
+        let missing = schedule_with(r#"["best", "retail"]"#, "");
+        assert!(matches!(
+            missing.parse::<CexFeeSchedule>(),
+            Err(CexFeeScheduleError::MissingSpotFees(CexExchange::Binance, _))
+        ));
+
+        let duplicate = schedule_with(r#"["best"]"#, &entry("Kraken", "best", "0.0", "0.0"));
+        assert!(matches!(
+            duplicate.parse::<CexFeeSchedule>(),
+            Err(CexFeeScheduleError::Duplicate(CexExchange::Kraken, _, InstrumentType::Spot))
+        ));
+
+        let invalid = schedule_with(r#"["best"]"#, &entry("Kraken", "vip 1", "0.0", "1.5"));
+        assert!(matches!(
+            invalid.parse::<CexFeeSchedule>(),
+            Err(CexFeeScheduleError::InvalidFee(CexExchange::Kraken, _, _))
+        ));
+
+        let unknown = schedule_with(r#"["best"]"#, &entry("Mtgox", "best", "0.0", "0.0"));
+        assert!(matches!(
+            unknown.parse::<CexFeeSchedule>(),
+            Err(CexFeeScheduleError::UnknownExchange)
+        ));
+    }
+}
@@ -35,7 +35,7 @@ use tracing::warn;
 use super::types::CexQuote;
 use crate::{
     db::{
-        cex::{quotes::CexQuoteRedefined, trades::Direction, CexExchange},
+        cex::{quotes::CexQuoteRedefined, trades::Direction, CexExchange, CexFeeSchedule},
         redefined_types::malachite::RationalRedefined,
     },
     implement_table_value_codecs_with_zc,
@@ -105,6 +105,7 @@ impl CexPriceMap {
         pair: &Pair,
         timestamp: u64,
         max_time_diff: Option<u64>,
+        fee_schedule: &CexFeeSchedule,
     ) -> Option<FeeAdjustedQuote> {
         self.most_liquid_ex
             .get(pair)
@@ -112,7 +113,8 @@ impl CexPriceMap {
             .and_then(|exchanges| {
                 for exchange in exchanges {
                     tracing::debug!(?exchange, ?pair);
-                    let res = self.get_quote_at(pair, exchange, timestamp, max_time_diff);
+                    let res =
+                        self.get_quote_at(pair, exchange, timestamp, max_time_diff, fee_schedule);
                     if res.is_some() {
                         return res
                     }
@@ -127,14 +129,16 @@ impl CexPriceMap {
         exchange: &CexExchange,
         timestamp: u64,
         max_time_diff: Option<u64>,
+        fee_schedule: &CexFeeSchedule,
     ) -> Option<FeeAdjustedQuote> {
-        self.get_exchange_quote_at_direct(pair, exchange, timestamp, max_time_diff)
+        self.get_exchange_quote_at_direct(pair, exchange, timestamp, max_time_diff, fee_schedule)
             .or_else(|| {
                 self.get_exchange_quote_at_via_intermediary(
                     pair,
                     exchange,
                     timestamp,
                     max_time_diff,
+                    fee_schedule,
                 )
             })
     }
@@ -145,6 +149,7 @@ impl CexPriceMap {
         exchange: &CexExchange,
         timestamp: u64,
         _max_time_diff: Option<u64>,
+        fee_schedule: &CexFeeSchedule,
     ) -> Option<FeeAdjustedQuote> {
         if pair.0 == pair.1 {
             return Some(FeeAdjustedQuote::default_one_to_one())
@@ -173,7 +178,7 @@ impl CexPriceMap {
                 let closest_quote = adjusted_quotes.get(index.saturating_sub(1))?;
                 let adjusted_quote = closest_quote.adjust_for_direction(direction);
 
-                let fees = exchange.fees();
+                let fees = exchange.fees(fee_schedule, pair);
 
                 let fee_adjusted_maker = (
                     &adjusted_quote.price.0 * (Rational::ONE - &fees.0),
@@ -202,6 +207,7 @@ impl CexPriceMap {
         exchange: &CexExchange,
         timestamp: u64,
         max_time_diff: Option<u64>,
+        fee_schedule: &CexFeeSchedule,
     ) -> Option<FeeAdjustedQuote> {
         let intermediaries = self.calculate_intermediary_addresses(exchange, pair);
 
@@ -212,8 +218,20 @@ impl CexPriceMap {
                 let pair1 = Pair(intermediary, pair.1);
 
                 if let (Some(quote1), Some(quote2)) = (
-                    self.get_exchange_quote_at_direct(&pair0, exchange, timestamp, max_time_diff),
-                    self.get_exchange_quote_at_direct(&pair1, exchange, timestamp, max_time_diff),
+                    self.get_exchange_quote_at_direct(
+                        &pair0,
+                        exchange,
+                        timestamp,
+                        max_time_diff,
+                        fee_schedule,
+                    ),
+                    self.get_exchange_quote_at_direct(
+                        &pair1,
+                        exchange,
+                        timestamp,
+                        max_time_diff,
+                        fee_schedule,
+                    ),
                 ) {
                     let combined_price_maker = (
                         &quote1.price_maker.0 * &quote2.price_maker.0,
@@ -270,7 +288,12 @@ impl CexPriceMap {
     ///   are identical.
     /// - If `token0` in the quote differs from `pair.0` parameter, the quote's
     ///   price is reciprocated to match the requested pair ordering.
-    pub fn get_vm_quote(&self, pair: &Pair, exchange: &CexExchange) -> Option<FeeAdjustedQuote> {
+    pub fn get_vm_quote(
+        &self,
+        pair: &Pair,
+        exchange: &CexExchange,
+        fee_schedule: &CexFeeSchedule,
+    ) -> Option<FeeAdjustedQuote> {
         if pair.0 == pair.1 {
             return Some(FeeAdjustedQuote::default_one_to_one())
         }
@@ -315,7 +338,7 @@ impl CexPriceMap {
                     let volume_weighted_bid = volume_price.0 / &cumulative_bbo.0;
                     let volume_weighted_ask = volume_price.1 / &cumulative_bbo.1;
 
-                    let fees = exchange.fees();
+                    let fees = exchange.fees(fee_schedule, pair);
 
                     let fee_adjusted_maker = (
                         &volume_weighted_bid * (Rational::ONE - &fees.0),
@@ -352,6 +375,7 @@ impl CexPriceMap {
         &self,
         pair: &Pair,
         exchange: &CexExchange,
+        fee_schedule: &CexFeeSchedule,
     ) -> Option<FeeAdjustedQuote> {
         let intermediaries = self.calculate_intermediary_addresses(exchange, pair);
 
@@ -361,9 +385,10 @@ impl CexPriceMap {
                 let pair0 = Pair(pair.0, intermediary);
                 let pair1 = Pair(intermediary, pair.1);
 
-                if let (Some(quote1), Some(quote2)) =
-                    (self.get_vm_quote(&pair0, exchange), self.get_vm_quote(&pair1, exchange))
-                {
+                if let (Some(quote1), Some(quote2)) = (
+                    self.get_vm_quote(&pair0, exchange, fee_schedule),
+                    self.get_vm_quote(&pair1, exchange, fee_schedule),
+                ) {
                     let combined_price_maker = (
                         &quote1.price_maker.0 * &quote2.price_maker.0,
                         &quote1.price_maker.1 * &quote2.price_maker.1,
@@ -400,9 +425,10 @@ impl CexPriceMap {
         &self,
         pair: &Pair,
         exchange: &CexExchange,
+        fee_schedule: &CexFeeSchedule,
     ) -> Option<FeeAdjustedQuote> {
-        self.get_vm_quote(pair, exchange)
-            .or_else(|| self.get_vm_quote_via_intermediary(pair, exchange))
+        self.get_vm_quote(pair, exchange, fee_schedule)
+            .or_else(|| self.get_vm_quote_via_intermediary(pair, exchange, fee_schedule))
     }
 
     pub fn get_global_vm_quote(
@@ -469,8 +495,12 @@ impl CexPriceMap {
         }
     }
 
-    pub fn get_binance_quote(&self, pair: &Pair) -> Option<FeeAdjustedQuote> {
-        self.get_vm_quote(pair, &CexExchange::Binance)
+    pub fn get_binance_quote(
+        &self,
+        pair: &Pair,
+        fee_schedule: &CexFeeSchedule,
+    ) -> Option<FeeAdjustedQuote> {
+        self.get_vm_quote(pair, &CexExchange::Binance, fee_schedule)
     }
 
     fn calculate_intermediary_addresses(
@@ -10,93 +10,150 @@ use serde::{Deserialize, Serialize};
 
 use super::{download::RawCexTrades, time_window_vwam::Direction};
 use crate::{
-    db::{cex::CexExchange, redefined_types::malachite::RationalRedefined},
+    db::{
+        cex::{CexExchange, InstrumentType},
+        redefined_types::malachite::RationalRedefined,
+    },
     implement_table_value_codecs_with_zc,
     pair::{Pair, PairRedefined},
     FastHashMap,
 };
 type RedefinedTradeMapVec = Vec<(PairRedefined, Vec<CexTradesRedefined>)>;
 
+/// The trades of each exchange and pair
+pub type ExchangeTradeMap = FastHashMap<CexExchange, FastHashMap<Pair, Vec<CexTrades>>>;
+
+/// The amount of trades of each exchange and pair
+pub type TradeOffsets = FastHashMap<CexExchange, FastHashMap<Pair, usize>>;
+
+/// The cex trades of a block by instrument. The spot trades are stored in the
+/// `CexTrades` table and the perp trades in the `CexPerpTrades` table
 #[derive(Debug, Default, Clone, Row, PartialEq, Eq, Serialize)]
-pub struct CexTradeMap(pub FastHashMap<CexExchange, FastHashMap<Pair, Vec<CexTrades>>>);
+pub struct CexTradeMap {
+    pub spot: ExchangeTradeMap,
+    /// Perpetual futures trades, keyed by the pair of the underlying and the
+    /// margin asset
+    pub perp: ExchangeTradeMap,
+}
+
+/// The amount of trades merged into a [`CexTradeMap`] per instrument
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CexTradeOffsets {
+    pub spot: TradeOffsets,
+    pub perp: TradeOffsets,
+}
 
 impl CexTradeMap {
+    /// Only the spot trades are in the `CexTrades` table
     pub fn from_redefined(map: Vec<(CexExchange, RedefinedTradeMapVec)>) -> Self {
-        Self(
-            map.into_iter()
-                .map(|(ex, trades)| {
-                    (
-                        ex,
-                        trades.into_iter().fold(
-                            FastHashMap::default(),
-                            |mut acc: FastHashMap<Pair, Vec<CexTrades>>, (pair, trades)| {
-                                let trades = trades
-                                    .into_iter()
-                                    .map(|t| t.to_source())
-                                    // ensure all trades sorted by timestamp
-                                    .sorted_unstable_by_key(|k| k.timestamp);
-
-                                acc.entry(pair.to_source()).or_default().extend(trades);
-                                acc
-                            },
-                        ),
-                    )
-                })
-                .collect(),
-        )
+        Self { spot: exchange_map_from_redefined(map), perp: FastHashMap::default() }
+    }
+
+    /// The trades of the instrument
+    pub fn trades(&self, instrument: InstrumentType) -> &ExchangeTradeMap {
+        match instrument {
+            InstrumentType::Spot => &self.spot,
+            InstrumentType::Perpetual => &self.perp,
+        }
     }
 
     /// merges in another map extending each of the pairs trades to the current
     /// map
-    pub fn merge_in_map(
-        &mut self,
-        other: Self,
-    ) -> FastHashMap<CexExchange, FastHashMap<Pair, usize>> {
-        // generate offset list for proper removal of each pair
-        other
-            .0
-            .into_iter()
-            .fold(FastHashMap::default(), |mut acc, (exchange, pairs)| {
-                // add to accumulator
-                acc.insert(
-                    exchange,
-                    pairs
-                        .iter()
-                        .map(|(pair, trades)| (*pair, trades.len()))
-                        .collect::<FastHashMap<_, _>>(),
-                );
-
-                // extend trades
-                for (pair, trades) in pairs {
-                    self.0
-                        .entry(exchange)
-                        .or_default()
-                        .entry(pair)
-                        .or_default()
-                        .extend(trades);
-                }
-
-                acc
-            })
+    pub fn merge_in_map(&mut self, other: Self) -> CexTradeOffsets {
+        CexTradeOffsets {
+            spot: merge_exchange_map(&mut self.spot, other.spot),
+            perp: merge_exchange_map(&mut self.perp, other.perp),
+        }
     }
 
     /// given the amount of entries per exchange per pair. removes
     /// the specified amount from the trade vector
-    pub fn pop_historical_trades(
-        &mut self,
-        list: FastHashMap<CexExchange, FastHashMap<Pair, usize>>,
-    ) {
-        for (ex, pairs) in list {
-            for (pair, offset) in pairs {
-                let inner = self.0.entry(ex).or_default().entry(pair).or_default();
-                inner.drain(0..offset);
+    pub fn pop_historical_trades(&mut self, offsets: CexTradeOffsets) {
+        pop_exchange_map(&mut self.spot, offsets.spot);
+        pop_exchange_map(&mut self.perp, offsets.perp);
+    }
+}
+
+fn exchange_map_from_redefined(map: Vec<(CexExchange, RedefinedTradeMapVec)>) -> ExchangeTradeMap {
+    map.into_iter()
+        .map(|(ex, trades)| {
+            (
+                ex,
+                trades.into_iter().fold(
+                    FastHashMap::default(),
+                    |mut acc: FastHashMap<Pair, Vec<CexTrades>>, (pair, trades)| {
+                        let trades = trades
+                            .into_iter()
+                            .map(|t| t.to_source())
+                            // ensure all trades sorted by timestamp
+                            .sorted_unstable_by_key(|k| k.timestamp);
+
+                        acc.entry(pair.to_source()).or_default().extend(trades);
+                        acc
+                    },
+                ),
+            )
+        })
+        .collect()
+}
+
+fn exchange_map_to_redefined(map: ExchangeTradeMap) -> Vec<(CexExchange, RedefinedTradeMapVec)> {
+    map.into_iter()
+        .map(|(exch, inner_map)| {
+            (
+                exch,
+                inner_map
+                    .into_iter()
+                    .map(|(a, b)| {
+                        (PairRedefined::from_source(a), Vec::<CexTradesRedefined>::from_source(b))
+                    })
+                    .collect_vec(),
+            )
+        })
+        .collect()
+}
+
+/// Extends the trades of `map` with `other`, returning the amount of trades
+/// added per exchange per pair
+fn merge_exchange_map(map: &mut ExchangeTradeMap, other: ExchangeTradeMap) -> TradeOffsets {
+    // generate offset list for proper removal of each pair
+    other
+        .into_iter()
+        .fold(FastHashMap::default(), |mut acc, (exchange, pairs)| {
+            // add to accumulator
+            acc.insert(
+                exchange,
+                pairs
+                    .iter()
+                    .map(|(pair, trades)| (*pair, trades.len()))
+                    .collect::<FastHashMap<_, _>>(),
+            );
+
+            // extend trades
+            for (pair, trades) in pairs {
+                map.entry(exchange)
+                    .or_default()
+                    .entry(pair)
+                    .or_default()
+                    .extend(trades);
             }
+
+            acc
+        })
+}
+
+fn pop_exchange_map(map: &mut ExchangeTradeMap, offsets: TradeOffsets) {
+    for (ex, pairs) in offsets {
+        for (pair, offset) in pairs {
+            let inner = map.entry(ex).or_default().entry(pair).or_default();
+            inner.drain(0..offset);
         }
     }
 }
 
 type ClickhouseTradeMap = Vec<(CexExchange, Vec<((String, String), Vec<RawCexTrades>)>)>;
 
+/// The clickhouse trades are spot trades
 impl<'de> Deserialize<'de> for CexTradeMap {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -104,9 +161,9 @@ impl<'de> Deserialize<'de> for CexTradeMap {
     {
         let data: ClickhouseTradeMap = Deserialize::deserialize(deserializer)?;
 
-        Ok(CexTradeMap(data.into_iter().fold(
+        let spot = data.into_iter().fold(
             FastHashMap::default(),
-            |mut acc: FastHashMap<CexExchange, FastHashMap<Pair, Vec<CexTrades>>>, (key, value)| {
+            |mut acc: ExchangeTradeMap, (key, value)| {
                 acc.entry(key).or_default().extend(value.into_iter().fold(
                     FastHashMap::default(),
                     |mut acc: FastHashMap<Pair, Vec<CexTrades>>, (pair, trades)| {
@@ -120,47 +177,50 @@ impl<'de> Deserialize<'de> for CexTradeMap {
 
                 acc
             },
-        )))
+        );
+
+        Ok(CexTradeMap { spot, perp: FastHashMap::default() })
     }
 }
 
+/// Archives the spot trades only, the perp trades are stored as a
+/// [`PerpTradeMap`]
 #[derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive, Redefined)]
 #[redefined(CexTradeMap)]
 #[redefined_attr(
     to_source = "CexTradeMap::from_redefined(self.map)",
-    from_source = "CexTradeMapRedefined::new(src.0)"
+    from_source = "CexTradeMapRedefined::new(src.spot)"
 )]
 pub struct CexTradeMapRedefined {
     pub map: Vec<(CexExchange, RedefinedTradeMapVec)>,
 }
 
 impl CexTradeMapRedefined {
-    fn new(map: FastHashMap<CexExchange, FastHashMap<Pair, Vec<CexTrades>>>) -> Self {
-        Self {
-            map: map
-                .into_iter()
-                .map(|(exch, inner_map)| {
-                    (
-                        exch,
-                        inner_map
-                            .into_iter()
-                            .map(|(a, b)| {
-                                (
-                                    PairRedefined::from_source(a),
-                                    Vec::<CexTradesRedefined>::from_source(b),
-                                )
-                            })
-                            .collect_vec(),
-                    )
-                })
-                .collect::<Vec<_>>(),
-        }
+    fn new(map: ExchangeTradeMap) -> Self {
+        Self { map: exchange_map_to_redefined(map) }
     }
 }
 
 implement_table_value_codecs_with_zc!(CexTradeMapRedefined);
 
-#[derive(Debug, Clone, Serialize, Redefined, PartialEq, Eq)]
+/// The perp trades of a block, stored apart from the spot trades in the
+/// `CexPerpTrades` table so the layout of the `CexTrades` table is unchanged
+#[derive(Debug, Default, Clone, Row, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PerpTradeMap(pub ExchangeTradeMap);
+
+#[derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive, Redefined)]
+#[redefined(PerpTradeMap)]
+#[redefined_attr(
+    to_source = "PerpTradeMap(exchange_map_from_redefined(self.map))",
+    from_source = "PerpTradeMapRedefined { map: exchange_map_to_redefined(src.0) }"
+)]
+pub struct PerpTradeMapRedefined {
+    pub map: Vec<(CexExchange, RedefinedTradeMapVec)>,
+}
+
+implement_table_value_codecs_with_zc!(PerpTradeMapRedefined);
+
+#[derive(Debug, Clone, Serialize, Deserialize, Redefined, PartialEq, Eq)]
 #[redefined_attr(derive(
     Debug,
     PartialEq,
@@ -216,3 +276,59 @@ impl From<RawCexTrades> for CexTrades {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use malachite::num::basic::traits::One;
+
+    use super::*;
+    use crate::constants::{USDT_ADDRESS, WETH_ADDRESS};
+
+    const PAIR: Pair = Pair(WETH_ADDRESS, USDT_ADDRESS);
+
+    fn trades(timestamps: &[u64]) -> ExchangeTradeMap {
+        let trades = timestamps
+            .iter()
+            .map(|&timestamp| CexTrades {
+                exchange: CexExchange::Binance,
+                timestamp,
+                price: Rational::ONE,
+                amount: Rational::ONE,
+            })
+            .collect();
+
+        FastHashMap::from_iter([(CexExchange::Binance, FastHashMap::from_iter([(PAIR, trades)]))])
+    }
+
+    fn timestamps(map: &CexTradeMap, instrument: InstrumentType) -> Vec<u64> {
+        map.trades(instrument)[&CexExchange::Binance][&PAIR]
+            .iter()
+            .map(|t| t.timestamp)
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_and_pop_per_instrument() {
+        let mut map = CexTradeMap::default();
+        let first = map.merge_in_map(CexTradeMap { spot: trades(&[1, 2]), perp: trades(&[3]) });
+        map.merge_in_map(CexTradeMap { spot: trades(&[4]), perp: trades(&[5, 6]) });
+
+        assert_eq!(timestamps(&map, InstrumentType::Spot), vec![1, 2, 4]);
+        assert_eq!(timestamps(&map, InstrumentType::Perpetual), vec![3, 5, 6]);
+
+        map.pop_historical_trades(first);
+        assert_eq!(timestamps(&map, InstrumentType::Spot), vec![4]);
+        assert_eq!(timestamps(&map, InstrumentType::Perpetual), vec![5, 6]);
+    }
+
+    #[test]
+    fn test_spot_trades_archived_apart_from_perp() {
+        let map = CexTradeMap { spot: trades(&[1]), perp: trades(&[2]) };
+
+        let spot = CexTradeMapRedefined::from_source(map.clone()).to_source();
+        assert_eq!(spot, CexTradeMap { spot: map.spot, perp: FastHashMap::default() });
+
+        let perp = PerpTradeMap(map.perp);
+        assert_eq!(PerpTradeMapRedefined::from_source(perp.clone()).to_source(), perp);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::db::cex::CexFeeSchedule;
+
 #[derive(Debug, Clone, Copy)]
 pub struct CexDexTradeConfig {
     pub initial_vwap_pre_block_us:         u64,
@@ -19,10 +21,15 @@ pub struct CexDexTradeConfig {
     pub pre_decay_weight_op:               f64,
     pub post_decay_weight_op:              f64,
     pub quote_offset_from_block_us:        u64,
+    /// How long a perp hedge is held, to model the funding it pays
+    pub perp_holding_horizon_us:           u64,
+    /// The fees the cex prices are adjusted with and the pnl is reported under
+    pub cex_fee_schedule:                  &'static CexFeeSchedule,
 }
 
-impl Default for CexDexTradeConfig {
-    fn default() -> Self {
+impl CexDexTradeConfig {
+    /// The default time windows, with the fees of `fee_schedule`
+    pub fn new(fee_schedule: &'static CexFeeSchedule) -> Self {
         Self {
             initial_vwap_pre_block_us:         50_000,
             initial_vwap_post_block_us:        50_000,
@@ -43,11 +50,11 @@ impl Default for CexDexTradeConfig {
             pre_decay_weight_op:               -0.0000003,
             post_decay_weight_op:              -0.00000012,
             quote_offset_from_block_us:        0,
+            perp_holding_horizon_us:           28_800_000_000,
+            cex_fee_schedule:                  fee_schedule,
         }
     }
-}
 
-impl CexDexTradeConfig {
     pub fn with_block_time_weights(&mut self) {
         self.use_block_time_weights_optimistic = true;
         self.use_block_time_weights_vwap = true;
@@ -9,9 +9,14 @@ use crate::{
     constants::USDC_ADDRESS,
     db::{
         block_times::{BlockTimes, CexBlockTimes},
-        cex::{cex_symbols::CexSymbols, trades::CexTradeMap, CexExchange},
+        cex::{
+            cex_symbols::CexSymbols,
+            trades::{CexTradeMap, PerpTickerMap, PerpTradeMap, RawPerpTicker},
+            CexExchange,
+        },
     },
     execute_on,
+    pair::Pair,
     serde_utils::{cex_exchange, trade_type},
     FastHashMap,
 };
@@ -82,84 +87,146 @@ impl CexTradesConverter {
         }
     }
 
+    /// Converts the spot trades to the trades of each block
     pub fn convert_to_trades(self) -> Vec<(u64, CexTradeMap)> {
-        let mut block_num_map = FastHashMap::default();
+        let Self { block_times, symbols, trades } = self;
 
-        self.trades
+        exchange_maps_by_block(&block_times, &symbols, trades, |t| {
+            (t.exchange, t.symbol.as_str(), t.timestamp)
+        })
+        .into_iter()
+        .map(|(block_num, spot)| (block_num, CexTradeMap { spot, perp: FastHashMap::default() }))
+        .collect()
+    }
+
+    /// Converts the trades of the perpetual futures to the perp trades of each
+    /// block
+    pub fn convert_to_perp_trades(&self, trades: Vec<RawCexTrades>) -> Vec<(u64, PerpTradeMap)> {
+        let trades = trades
+            .into_iter()
+            .filter(|trade| self.symbols.contains_key(&trade.symbol))
+            .collect();
+
+        exchange_maps_by_block(&self.block_times, &self.symbols, trades, |t| {
+            (t.exchange, t.symbol.as_str(), t.timestamp)
+        })
+        .into_iter()
+        .map(|(block_num, trades)| (block_num, PerpTradeMap(trades)))
+        .collect()
+    }
+
+    /// Converts the mark prices & funding rates of the perpetual futures to
+    /// the tickers of each block
+    pub fn convert_to_perp_tickers(
+        &self,
+        tickers: Vec<RawPerpTicker>,
+    ) -> Vec<(u64, PerpTickerMap)> {
+        let tickers = tickers
             .into_iter()
-            .filter_map(|t| {
-                self.block_times
-                    .iter()
-                    .find(|b| b.contains_time(t.timestamp))
-                    .map(|block_time| (block_time.block_number, t))
+            .filter(|ticker| self.symbols.contains_key(&ticker.symbol))
+            .collect();
+
+        exchange_maps_by_block(&self.block_times, &self.symbols, tickers, |t| {
+            (t.exchange, t.symbol.as_str(), t.timestamp)
+        })
+        .into_iter()
+        .map(|(block_num, tickers)| (block_num, PerpTickerMap(tickers)))
+        .collect()
+    }
+}
+
+/// Groups the entries of each block by exchange and token pair. `key` returns
+/// the exchange, symbol and timestamp of an entry
+fn exchange_maps_by_block<R, T>(
+    block_times: &[CexBlockTimes],
+    symbols: &FastHashMap<String, Vec<CexSymbols>>,
+    entries: Vec<R>,
+    key: impl Fn(&R) -> (CexExchange, &str, u64) + Sync,
+) -> Vec<(u64, FastHashMap<CexExchange, FastHashMap<Pair, Vec<T>>>)>
+where
+    R: Clone + Send + Into<T>,
+    T: Send,
+{
+    let block_num_map = by_block(block_times, entries, |e| key(e).2);
+
+    execute_on!(download, {
+        block_num_map
+            .into_par_iter()
+            .map(|(block_num, entries)| {
+                let mut exchange_map: FastHashMap<_, FastHashMap<_, Vec<_>>> =
+                    FastHashMap::default();
+
+                for entry in entries {
+                    let (exchange, symbol, timestamp) = key(&entry);
+                    for pair in address_pairs(symbols, symbol, timestamp) {
+                        exchange_map
+                            .entry(exchange)
+                            .or_default()
+                            .entry(pair)
+                            .or_default()
+                            .push(entry.clone().into());
+                    }
+                }
+
+                (block_num, exchange_map)
             })
-            .for_each(|(block_num, trade)| {
-                block_num_map
-                    .entry(block_num)
-                    .or_insert(Vec::new())
-                    .push(trade)
-            });
-
-        execute_on!(download, {
+            .collect()
+    })
+}
+
+/// Groups the entries by the block whose time window they fall in
+fn by_block<T>(
+    block_times: &[CexBlockTimes],
+    entries: Vec<T>,
+    timestamp: impl Fn(&T) -> u64,
+) -> FastHashMap<u64, Vec<T>> {
+    let mut block_num_map: FastHashMap<u64, Vec<T>> = FastHashMap::default();
+
+    for entry in entries {
+        if let Some(block_time) = block_times
+            .iter()
+            .find(|b| b.contains_time(timestamp(&entry)))
+        {
             block_num_map
-                .into_par_iter()
-                .map(|(block_num, trades)| {
-                    let mut exchange_map = FastHashMap::default();
+                .entry(block_time.block_number)
+                .or_default()
+                .push(entry);
+        }
+    }
 
-                    trades.into_iter().for_each(|trade| {
-                        exchange_map
-                            .entry(trade.exchange)
-                            .or_insert(Vec::new())
-                            .push(trade);
-                    });
-
-                    let cex_price_map = exchange_map
-                        .into_iter()
-                        .map(|(exch, trades)| {
-                            let mut exchange_symbol_map = FastHashMap::default();
-
-                            trades.into_iter().for_each(|trade| {
-                                let symbols = self.symbols.get(&trade.symbol).unwrap().clone();
-
-                                // there is a case were we have multiple addresses for
-                                // same symbol so this covers it.
-                                let mut seen = vec![];
-                                for mut symbol in symbols {
-                                    if seen.contains(&symbol.address_pair) {
-                                        continue
-                                    } else {
-                                        seen.push(symbol.address_pair)
-                                    }
-
-                                    if symbol.address_pair.1
-                                        == hex!("2f6081e3552b1c86ce4479b80062a1dda8ef23e3")
-                                    {
-                                        symbol.address_pair.1 = USDC_ADDRESS;
-                                    }
-
-                                    if symbol.address_pair.0
-                                        == hex!("15D4c048F83bd7e37d49eA4C83a07267Ec4203dA")
-                                        && trade.timestamp > 1684220400000000
-                                    {
-                                        symbol.address_pair.0 = Address::from(hex!(
-                                            "d1d2Eb1B1e90B638588728b4130137D262C87cae"
-                                        ))
-                                    }
-
-                                    exchange_symbol_map
-                                        .entry(symbol.address_pair)
-                                        .or_insert(Vec::new())
-                                        .push(trade.clone().into());
-                                }
-                            });
-
-                            (exch, exchange_symbol_map)
-                        })
-                        .collect::<FastHashMap<_, _>>();
-
-                    (block_num, CexTradeMap(cex_price_map))
-                })
-                .collect()
-        })
+    block_num_map
+}
+
+/// The token pairs of a symbol. There is a case were we have multiple
+/// addresses for the same symbol so this covers it.
+fn address_pairs(
+    symbols: &FastHashMap<String, Vec<CexSymbols>>,
+    symbol: &str,
+    timestamp: u64,
+) -> Vec<Pair> {
+    let mut seen = vec![];
+    let mut pairs = vec![];
+
+    for symbol in symbols.get(symbol).unwrap() {
+        if seen.contains(&symbol.address_pair) {
+            continue
+        } else {
+            seen.push(symbol.address_pair)
+        }
+
+        let mut pair = symbol.address_pair;
+        if pair.1 == hex!("2f6081e3552b1c86ce4479b80062a1dda8ef23e3") {
+            pair.1 = USDC_ADDRESS;
+        }
+
+        if pair.0 == hex!("15D4c048F83bd7e37d49eA4C83a07267Ec4203dA")
+            && timestamp > 1684220400000000
+        {
+            pair.0 = Address::from(hex!("d1d2Eb1B1e90B638588728b4130137D262C87cae"))
+        }
+
+        pairs.push(pair);
     }
+
+    pairs
 }
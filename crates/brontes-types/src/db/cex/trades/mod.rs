@@ -2,6 +2,7 @@ mod cex_trades;
 pub mod config;
 mod download;
 pub mod optimistic;
+mod perp;
 pub mod time_window_vwam;
 pub mod utils;
 pub mod window_loader;
@@ -12,6 +13,7 @@ pub use config::*;
 pub use download::*;
 use malachite::Rational;
 pub use optimistic::*;
+pub use perp::*;
 pub use time_window_vwam::*;
 use utils::SortedTrades;
 
@@ -71,7 +73,7 @@ impl CexTradeMap {
         dex_swap: &NormalizedSwap,
         tx_hash: FixedBytes<32>,
     ) -> Option<WindowExchangePrice> {
-        TimeWindowTrades::new_from_cex_trade_map(&self.0, block_timestamp, exchanges, pair)
+        TimeWindowTrades::new_from_cex_trade_map(&self.spot, block_timestamp, exchanges, pair)
             .get_price(
                 config,
                 exchanges,
@@ -96,7 +98,7 @@ impl CexTradeMap {
         dex_swap: &NormalizedSwap,
         tx_hash: FixedBytes<32>,
     ) -> Option<OptimisticPrice> {
-        SortedTrades::new_from_cex_trade_map(&self.0, exchanges, pair, block_timestamp)
+        SortedTrades::new_from_cex_trade_map(&self.spot, exchanges, pair, block_timestamp)
             .get_optimistic_price(
                 config,
                 exchanges,
@@ -0,0 +1,338 @@
+//! Perpetual futures mark prices and funding rates, used to evaluate hedging
+//! the output of a dex swap on a perp instead of selling it on spot.
+use clickhouse::Row;
+use itertools::Itertools;
+use malachite::{
+    num::{
+        arithmetic::traits::Reciprocal,
+        basic::traits::{NegativeOne, One, Zero},
+    },
+    Rational,
+};
+use redefined::{Redefined, RedefinedConvert};
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    db::{
+        cex::{CexExchange, CexFeeSchedule, InstrumentType},
+        redefined_types::malachite::RationalRedefined,
+    },
+    implement_table_value_codecs_with_zc,
+    normalized_actions::NormalizedSwap,
+    pair::{Pair, PairRedefined},
+    serde_utils::cex_exchange,
+    FastHashMap,
+};
+
+/// Funding is paid every 8 hours on the supported perpetual venues
+pub const FUNDING_INTERVAL_US: u64 = 8 * 60 * 60 * 1_000_000;
+
+/// Tickers further than this from the hedge are stale. The venues publish a
+/// ticker every second or so
+pub const MAX_TICKER_AGE_US: u64 = 10 * 1_000_000;
+
+#[derive(Debug, Default, Clone, Row, PartialEq, Deserialize)]
+pub struct RawPerpTicker {
+    #[serde(with = "cex_exchange")]
+    pub exchange:     CexExchange,
+    pub symbol:       String,
+    pub timestamp:    u64,
+    pub mark_price:   f64,
+    pub funding_rate: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Redefined, PartialEq, Eq)]
+#[redefined_attr(derive(
+    Debug,
+    PartialEq,
+    Eq,
+    Clone,
+    Hash,
+    Serialize,
+    rSerialize,
+    rDeserialize,
+    Archive
+))]
+pub struct PerpTicker {
+    #[redefined(same_fields)]
+    pub exchange:     CexExchange,
+    pub timestamp:    u64,
+    pub mark_price:   Rational,
+    /// Paid by longs to shorts every funding interval, as a fraction of the
+    /// position notional
+    pub funding_rate: Rational,
+}
+
+impl From<RawPerpTicker> for PerpTicker {
+    fn from(value: RawPerpTicker) -> Self {
+        Self {
+            exchange:     value.exchange,
+            timestamp:    value.timestamp,
+            mark_price:   Rational::try_from_float_simplest(value.mark_price).unwrap(),
+            funding_rate: Rational::try_from_float_simplest(value.funding_rate).unwrap(),
+        }
+    }
+}
+
+/// The output of a dex swap sold for the token in on a perp, held for the
+/// holding horizon
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PerpHedge {
+    pub exchange:     CexExchange,
+    /// In token in per token out
+    pub mark_price:   Rational,
+    /// The funding paid over the holding horizon in token in, negative when
+    /// the position receives funding
+    pub funding_cost: Rational,
+    /// The token in received net of the perp maker fee and the funding
+    pub amount_maker: Rational,
+    /// The token in received net of the perp taker fee and the funding
+    pub amount_taker: Rational,
+}
+
+type RedefinedTickerMapVec = Vec<(PairRedefined, Vec<PerpTickerRedefined>)>;
+
+/// The perp tickers of a block, by exchange and by the pair of the underlying
+/// and the margin asset. Stored apart from the
+/// [`CexTradeMap`](super::CexTradeMap) of the block, in the `CexPerpTickers`
+/// table
+#[derive(Debug, Default, Clone, Row, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PerpTickerMap(pub FastHashMap<CexExchange, FastHashMap<Pair, Vec<PerpTicker>>>);
+
+impl PerpTickerMap {
+    pub fn from_redefined(map: Vec<(CexExchange, RedefinedTickerMapVec)>) -> Self {
+        Self(
+            map.into_iter()
+                .map(|(ex, tickers)| {
+                    (
+                        ex,
+                        tickers
+                            .into_iter()
+                            .map(|(pair, tickers)| {
+                                let tickers = tickers
+                                    .into_iter()
+                                    .map(|t| t.to_source())
+                                    // ensure all tickers sorted by timestamp
+                                    .sorted_unstable_by_key(|k| k.timestamp)
+                                    .collect_vec();
+
+                                (pair.to_source(), tickers)
+                            })
+                            .collect(),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    /// The ticker of the perp of `pair` on the exchange closest to
+    /// `timestamp`, and whether the perp is listed as the flipped pair. There
+    /// is none if the closest ticker is more than [`MAX_TICKER_AGE_US`] away
+    pub fn perp_ticker(
+        &self,
+        exchange: CexExchange,
+        pair: &Pair,
+        timestamp: u64,
+    ) -> Option<(&PerpTicker, bool)> {
+        let pairs = self.0.get(&exchange)?;
+        let (tickers, flipped) = pairs
+            .get(pair)
+            .map(|tickers| (tickers, false))
+            .or_else(|| pairs.get(&pair.flip()).map(|tickers| (tickers, true)))?;
+
+        let idx = tickers.partition_point(|t| t.timestamp < timestamp);
+        let closest = [idx.checked_sub(1), Some(idx)]
+            .into_iter()
+            .flatten()
+            .filter_map(|i| tickers.get(i))
+            .min_by_key(|t| t.timestamp.abs_diff(timestamp))
+            .filter(|t| t.timestamp.abs_diff(timestamp) <= MAX_TICKER_AGE_US)?;
+
+        Some((closest, flipped))
+    }
+
+    /// Hedges the output of the swap on the perp of the exchange at the mark
+    /// price, paying the perp fees of the header tier of `fees` and the
+    /// funding over `holding_horizon_us`
+    pub fn perp_hedge(
+        &self,
+        exchange: CexExchange,
+        swap: &NormalizedSwap,
+        timestamp: u64,
+        holding_horizon_us: u64,
+        fees: &CexFeeSchedule,
+    ) -> Option<PerpHedge> {
+        let pair = Pair(swap.token_out.address, swap.token_in.address);
+        let (ticker, flipped) = self.perp_ticker(exchange, &pair, timestamp)?;
+        if ticker.mark_price == Rational::ZERO {
+            return None
+        }
+
+        // selling the token out shorts the perp if it is the underlying, and
+        // buys the underlying with the margin asset otherwise
+        let (mark_price, position) = if flipped {
+            (ticker.mark_price.clone().reciprocal(), Rational::ONE)
+        } else {
+            (ticker.mark_price.clone(), Rational::NEGATIVE_ONE)
+        };
+
+        let (maker_fee, taker_fee) =
+            fees.fees(exchange, fees.header_tier(), InstrumentType::Perpetual, &pair)?;
+
+        let notional = &mark_price * &swap.amount_out;
+        let funding_periods =
+            Rational::from(holding_horizon_us) / Rational::from(FUNDING_INTERVAL_US);
+        let funding_cost = position * &ticker.funding_rate * &notional * funding_periods;
+
+        Some(PerpHedge {
+            exchange,
+            amount_maker: &notional * (Rational::ONE - maker_fee) - &funding_cost,
+            amount_taker: &notional * (Rational::ONE - taker_fee) - &funding_cost,
+            mark_price,
+            funding_cost,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive, Redefined)]
+#[redefined(PerpTickerMap)]
+#[redefined_attr(
+    to_source = "PerpTickerMap::from_redefined(self.map)",
+    from_source = "PerpTickerMapRedefined::new(src.0)"
+)]
+pub struct PerpTickerMapRedefined {
+    pub map: Vec<(CexExchange, RedefinedTickerMapVec)>,
+}
+
+impl PerpTickerMapRedefined {
+    fn new(map: FastHashMap<CexExchange, FastHashMap<Pair, Vec<PerpTicker>>>) -> Self {
+        Self {
+            map: map
+                .into_iter()
+                .map(|(exch, inner_map)| {
+                    (
+                        exch,
+                        inner_map
+                            .into_iter()
+                            .map(|(a, b)| {
+                                (
+                                    PairRedefined::from_source(a),
+                                    Vec::<PerpTickerRedefined>::from_source(b),
+                                )
+                            })
+                            .collect_vec(),
+                    )
+                })
+                .collect::<Vec<_>>(),
+        }
+    }
+}
+
+implement_table_value_codecs_with_zc!(PerpTickerMapRedefined);
+
+#[cfg(test)]
+mod tests {
+    use malachite::num::conversion::traits::FromSciString;
+
+    use super::*;
+    use crate::{
+        constants::{USDT_ADDRESS, WETH_ADDRESS},
+        db::token_info::TokenInfoWithAddress,
+    };
+
+    fn rational(value: &str) -> Rational {
+        Rational::from_sci_string(value).unwrap()
+    }
+
+    fn ticker_map(tickers: Vec<PerpTicker>) -> PerpTickerMap {
+        let mut map = PerpTickerMap::default();
+        map.0
+            .entry(CexExchange::Binance)
+            .or_default()
+            .insert(Pair(WETH_ADDRESS, USDT_ADDRESS), tickers);
+        map
+    }
+
+    fn ticker(timestamp: u64, mark_price: &str) -> PerpTicker {
+        PerpTicker {
+            exchange: CexExchange::Binance,
+            timestamp,
+            mark_price: rational(mark_price),
+            funding_rate: rational("0.0001"),
+        }
+    }
+
+    #[test]
+    fn test_perp_ticker() {
+        let map = ticker_map(vec![ticker(100, "3000"), ticker(200, "3010")]);
+        let pair = Pair(WETH_ADDRESS, USDT_ADDRESS);
+
+        let (closest, flipped) = map.perp_ticker(CexExchange::Binance, &pair, 160).unwrap();
+        assert_eq!(closest.timestamp, 200);
+        assert!(!flipped);
+
+        let (closest, flipped) = map
+            .perp_ticker(CexExchange::Binance, &pair.flip(), 20)
+            .unwrap();
+        assert_eq!(closest.timestamp, 100);
+        assert!(flipped);
+
+        assert!(map.perp_ticker(CexExchange::Okex, &pair, 160).is_none());
+
+        // the closest ticker is too old to hedge at
+        let stale = 200 + MAX_TICKER_AGE_US + 1;
+        assert!(map
+            .perp_ticker(CexExchange::Binance, &pair, stale)
+            .is_none());
+        assert!(map
+            .perp_ticker(CexExchange::Binance, &pair, stale - 1)
+            .is_some());
+    }
+
+    #[test]
+    fn test_perp_hedge() {
+        let mut map = ticker_map(vec![ticker(100, "3010")]);
+        let fees = CexFeeSchedule::default();
+
+        // bought 1 weth for 3000 usdt on the dex, shorting the weth perp over
+        // one funding interval receives the funding
+        let short = NormalizedSwap {
+            token_in: TokenInfoWithAddress::usdt(),
+            token_out: TokenInfoWithAddress::weth(),
+            amount_in: rational("3000"),
+            amount_out: Rational::ONE,
+            ..Default::default()
+        };
+        let hedge = map
+            .perp_hedge(CexExchange::Binance, &short, 100, FUNDING_INTERVAL_US, &fees)
+            .unwrap();
+        assert_eq!(hedge.mark_price, rational("3010"));
+        assert_eq!(hedge.funding_cost, rational("-0.301"));
+        assert_eq!(hedge.amount_maker, rational("3010.602"));
+        assert_eq!(hedge.amount_taker, rational("3009.7893"));
+
+        // sold 1 weth for 3010 usdt on the dex, going long the weth perp for
+        // half a funding interval pays the funding
+        let long = NormalizedSwap {
+            token_in: TokenInfoWithAddress::weth(),
+            token_out: TokenInfoWithAddress::usdt(),
+            amount_in: Rational::ONE,
+            amount_out: rational("3010"),
+            ..Default::default()
+        };
+        let hedge = map
+            .perp_hedge(CexExchange::Binance, &long, 100, FUNDING_INTERVAL_US / 2, &fees)
+            .unwrap();
+        assert_eq!(hedge.mark_price, rational("3010").reciprocal());
+        assert_eq!(hedge.funding_cost, rational("0.00005"));
+        assert_eq!(hedge.amount_maker, rational("1.00005"));
+
+        // coinbase has no perp fees in the fee schedule
+        map.0
+            .insert(CexExchange::Coinbase, map.0[&CexExchange::Binance].clone());
+        assert!(map
+            .perp_hedge(CexExchange::Coinbase, &short, 100, FUNDING_INTERVAL_US, &fees)
+            .is_none());
+    }
+}
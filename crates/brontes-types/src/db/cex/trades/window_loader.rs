@@ -1,15 +1,11 @@
 use std::collections::VecDeque;
 
-use crate::{
-    db::cex::{trades::CexTradeMap, CexExchange},
-    pair::Pair,
-    FastHashMap,
-};
+use crate::db::cex::trades::{CexTradeMap, CexTradeOffsets};
 
 pub struct CexWindow {
     /// a queue of each pairs vec offset. this allows us to quickly trim
     /// out fields from the extended mapw
-    offset_list:           VecDeque<FastHashMap<CexExchange, FastHashMap<Pair, usize>>>,
+    offset_list:           VecDeque<CexTradeOffsets>,
     global_map:            CexTradeMap,
     /// this is the last block loaded, adjusted for the range lookahead.
     /// this is used so that we don't double load data
@@ -0,0 +1,20 @@
+use alloy_primitives::B256;
+use clickhouse::Row;
+use serde::{Deserialize, Serialize};
+
+use crate::{mev::MevType, serde_utils::txhash};
+
+/// A bundle the composer's deduplication dropped, with the precedence rule
+/// that dropped it and the dominant bundle it overlapped with
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Row)]
+pub struct DedupDecision {
+    pub block_number:     u64,
+    /// Index of the rule in the precedence rules of the mev filter config
+    pub rule:             usize,
+    pub dominant_type:    MevType,
+    #[serde(with = "txhash")]
+    pub dominant_tx_hash: B256,
+    pub removed_type:     MevType,
+    #[serde(with = "txhash")]
+    pub removed_tx_hash:  B256,
+}
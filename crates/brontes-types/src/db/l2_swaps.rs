@@ -0,0 +1,103 @@
+use alloy_primitives::{Address, B256};
+use redefined::Redefined;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+
+use crate::{db::redefined_types::primitives::*, FastHashMap};
+
+/// A swap on an L2, as found in the user supplied L2 dataset. L2 token
+/// addresses differ from their mainnet counterparts, so the tokens are matched
+/// to mainnet by symbol.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct L2Swap {
+    pub chain_id:         u64,
+    pub block_number:     u64,
+    /// Unix timestamp of the L2 block in seconds
+    pub timestamp:        u64,
+    pub tx_hash:          B256,
+    pub trader:           Address,
+    pub pool:             Address,
+    pub token_in:         Address,
+    pub token_in_symbol:  String,
+    pub token_out:        Address,
+    pub token_out_symbol: String,
+    pub amount_in:        f64,
+    pub amount_out:       f64,
+}
+
+/// The swaps of the L2 dataset by chain and trader, ordered by timestamp
+#[derive(Debug, Default, Clone)]
+pub struct L2SwapDataset {
+    swaps: FastHashMap<(u64, Address), Vec<L2Swap>>,
+}
+
+impl L2SwapDataset {
+    pub fn new(swaps: Vec<L2Swap>) -> Self {
+        let mut by_trader: FastHashMap<(u64, Address), Vec<L2Swap>> = FastHashMap::default();
+        for swap in swaps {
+            by_trader
+                .entry((swap.chain_id, swap.trader))
+                .or_default()
+                .push(swap);
+        }
+        by_trader
+            .values_mut()
+            .for_each(|swaps| swaps.sort_by_key(|swap| (swap.timestamp, swap.block_number)));
+
+        Self { swaps: by_trader }
+    }
+
+    /// The swaps of `trader` on `chain_id` at most `window_secs` away from
+    /// `timestamp`
+    pub fn swaps_around(
+        &self,
+        chain_id: u64,
+        trader: Address,
+        timestamp: u64,
+        window_secs: u64,
+    ) -> &[L2Swap] {
+        let Some(swaps) = self.swaps.get(&(chain_id, trader)) else { return &[] };
+
+        let start = swaps.partition_point(|swap| swap.timestamp + window_secs < timestamp);
+        let end = swaps.partition_point(|swap| swap.timestamp <= timestamp + window_secs);
+
+        &swaps[start..end]
+    }
+
+    pub fn len(&self) -> usize {
+        self.swaps.values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.swaps.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swap(chain_id: u64, trader: Address, timestamp: u64) -> L2Swap {
+        L2Swap { chain_id, trader, timestamp, ..Default::default() }
+    }
+
+    #[test]
+    fn test_swaps_around() {
+        let trader = Address::with_last_byte(1);
+        let dataset = L2SwapDataset::new(vec![
+            swap(10, trader, 1_000),
+            swap(10, trader, 700),
+            swap(10, trader, 1_400),
+            swap(8453, trader, 1_000),
+            swap(10, Address::with_last_byte(2), 1_000),
+        ]);
+        assert_eq!(dataset.len(), 5);
+
+        let found = dataset.swaps_around(10, trader, 1_000, 300);
+        assert_eq!(found.iter().map(|s| s.timestamp).collect::<Vec<_>>(), vec![700, 1_000]);
+
+        assert_eq!(dataset.swaps_around(10, trader, 100, 300).len(), 0);
+        assert_eq!(dataset.swaps_around(42161, trader, 1_000, 300).len(), 0);
+    }
+}
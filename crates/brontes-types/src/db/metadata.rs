@@ -9,7 +9,10 @@ use serde_with::serde_as;
 
 use super::{
     builder::BuilderInfo,
-    cex::{quotes::CexPriceMap, trades::CexTradeMap},
+    cex::{
+        quotes::CexPriceMap,
+        trades::{CexTradeMap, PerpTickerMap},
+    },
     dex::DexQuotes,
     traits::LibmdbxReader,
 };
@@ -20,7 +23,7 @@ use crate::{
     implement_table_value_codecs_with_zc,
     pair::Pair,
     serde_utils::{option_addresss, u256, vec_txhash},
-    FastHashSet,
+    FastHashMap, FastHashSet,
 };
 #[allow(unused_imports)]
 use crate::{db::cex::CexExchange, normalized_actions::NormalizedSwap};
@@ -73,6 +76,13 @@ impl BlockMetadataInner {
             private_flow,
         }
     }
+
+    /// Overwrites the relay fields with the relay metadata of the block
+    pub fn set_relay(&mut self, relay: RelayBlockMetadata) {
+        self.relay_timestamp = relay.relay_timestamp;
+        self.proposer_fee_recipient = Some(relay.proposer_fee_recipient);
+        self.proposer_mev_reward = Some(relay.proposer_mev_reward);
+    }
 }
 
 /// Aggregated Metadata
@@ -85,6 +95,7 @@ pub struct Metadata {
     pub dex_quotes:     Option<DexQuotes>,
     pub builder_info:   Option<BuilderInfo>,
     pub cex_trades:     Option<CexTradeMap>,
+    pub perp_tickers:   Option<PerpTickerMap>,
 }
 
 impl Metadata {
@@ -185,6 +196,25 @@ impl BlockMetadata {
         }
     }
 
+    /// Labels the txes of the block that were never seen in the mempool as
+    /// private flow. When none of the txes were seen the mempool wasn't being
+    /// observed for this block, so nothing is labelled.
+    pub fn set_private_flow_from_mempool(
+        &mut self,
+        block_txes: &[TxHash],
+        first_seen: &FastHashMap<TxHash, u64>,
+    ) {
+        if first_seen.is_empty() {
+            return
+        }
+
+        self.private_flow = block_txes
+            .iter()
+            .filter(|tx| !first_seen.contains_key(*tx))
+            .copied()
+            .collect();
+    }
+
     pub fn microseconds_block_timestamp(&self) -> u64 {
         self.block_timestamp * 1_000_000
     }
@@ -196,6 +226,33 @@ impl BlockMetadata {
         builder_info: Option<BuilderInfo>,
         cex_trades: Option<CexTradeMap>,
     ) -> Metadata {
-        Metadata { block_metadata: self, cex_quotes, dex_quotes, builder_info, cex_trades }
+        Metadata {
+            block_metadata: self,
+            cex_quotes,
+            dex_quotes,
+            builder_info,
+            cex_trades,
+            perp_tickers: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::B256;
+
+    use super::*;
+
+    #[test]
+    fn test_private_flow_from_mempool() {
+        let (public, private) = (B256::with_last_byte(1), B256::with_last_byte(2));
+        let mut meta = BlockMetadata::default();
+
+        meta.set_private_flow_from_mempool(&[public, private], &FastHashMap::default());
+        assert!(meta.private_flow.is_empty());
+
+        let first_seen = FastHashMap::from_iter([(public, 1_700_000_000_000_000)]);
+        meta.set_private_flow_from_mempool(&[public, private], &first_seen);
+        assert_eq!(meta.private_flow, FastHashSet::from_iter([private]));
     }
 }
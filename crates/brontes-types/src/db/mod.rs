@@ -6,6 +6,7 @@ pub mod address_to_protocol_info;
 
 #[rustfmt::skip]
 pub mod block_analysis;
+pub mod block_auction;
 pub mod block_times;
 pub mod builder;
 pub mod cex;
@@ -13,17 +14,21 @@ pub mod cex;
 pub mod clickhouse;
 pub mod clickhouse_serde;
 pub mod codecs;
+pub mod dedup_decision;
 pub mod dex;
 pub mod initialized_state;
+pub mod l2_swaps;
 pub mod metadata;
 pub mod mev_block;
 pub mod normalized_actions;
 pub mod pool_creation_block;
 pub mod redefined_types;
 pub mod searcher;
+pub mod searcher_stats;
 pub mod token_info;
 pub mod traces;
 pub mod traits;
+pub mod uniswap_v4_pool_key;
 
 /// This table is used to add run id inserts for each clickhouse table in order
 /// for us to not have to clear runs multiple times
@@ -157,6 +157,14 @@ pub enum ActionKind {
     NewPool,
     PoolConfigUpdate,
     Aggregator,
+    NftTrade,
+    NftTransfer,
+    Loan,
+    Repayment,
+    Supply,
+    Withdraw,
+    BridgeDeposit,
+    BridgeFill,
     Revert,
 }
 
@@ -178,6 +186,14 @@ impl From<&Action> for ActionKind {
             Action::NewPool(_) => ActionKind::NewPool,
             Action::PoolConfigUpdate(_) => ActionKind::PoolConfigUpdate,
             Action::Aggregator(_) => ActionKind::Aggregator,
+            Action::NftTrade(_) => ActionKind::NftTrade,
+            Action::NftTransfer(_) => ActionKind::NftTransfer,
+            Action::Loan(_) => ActionKind::Loan,
+            Action::Repayment(_) => ActionKind::Repayment,
+            Action::Supply(_) => ActionKind::Supply,
+            Action::Withdraw(_) => ActionKind::Withdraw,
+            Action::BridgeDeposit(_) => ActionKind::BridgeDeposit,
+            Action::BridgeFill(_) => ActionKind::BridgeFill,
             Action::Revert => ActionKind::Revert,
         }
     }
@@ -74,6 +74,9 @@ impl SearcherInfo {
             MevType::AtomicArb => self.mev_count.atomic_backrun_count,
             MevType::Liquidation => self.mev_count.liquidation_count,
             MevType::SearcherTx => self.mev_count.searcher_tx_count,
+            MevType::LongTail => self.mev_count.long_tail_count,
+            MevType::Nft => self.mev_count.nft_count,
+            MevType::CrossDomain => self.mev_count.cross_domain_count,
             MevType::Unknown => None,
         }
     }
@@ -145,6 +148,19 @@ impl SearcherInfo {
                 self.pnl.liquidation,
                 self.gas_bids.liquidation,
             ),
+            (
+                "LongTail",
+                self.mev_count.long_tail_count,
+                self.pnl.long_tail,
+                self.gas_bids.long_tail,
+            ),
+            ("Nft", self.mev_count.nft_count, self.pnl.nft, self.gas_bids.nft),
+            (
+                "Cross Domain",
+                self.mev_count.cross_domain_count,
+                self.pnl.cross_domain,
+                self.gas_bids.cross_domain,
+            ),
         ]
         .into_iter()
         .filter_map(|(mev_type, count, pnl, gas_bid)| {
@@ -172,6 +188,19 @@ impl SearcherInfo {
         self.mev_count.increment_count(header.mev_type);
         self.gas_bids.account_gas(header);
     }
+
+    /// Removes a bundle previously added with
+    /// [`SearcherInfo::update_with_bundle`], used when its block is reorged out
+    pub fn revert_bundle(&mut self, header: &BundleHeader) {
+        self.pnl.revert(header.mev_type, header.profit_usd);
+        self.mev_count.decrement_count(header.mev_type);
+        self.gas_bids.revert(header.mev_type, header.bribe_usd);
+
+        if self.get_bundle_count_for_type(header.mev_type).is_none() {
+            self.pnl.clear(header.mev_type);
+            self.gas_bids.clear(header.mev_type);
+        }
+    }
 }
 
 implement_table_value_codecs_with_zc!(SearcherInfoRedefined);
@@ -199,6 +228,9 @@ pub struct TollByType {
     pub atomic_backrun: Option<f64>,
     pub liquidation:    Option<f64>,
     pub searcher_tx:    Option<f64>,
+    pub long_tail:      Option<f64>,
+    pub nft:            Option<f64>,
+    pub cross_domain:   Option<f64>,
 }
 
 self_convert_redefined!(TollByType);
@@ -242,6 +274,14 @@ impl TollByType {
             MevType::SearcherTx => {
                 self.searcher_tx = Some(self.searcher_tx.unwrap_or_default().add(header.profit_usd))
             }
+            MevType::LongTail => {
+                self.long_tail = Some(self.long_tail.unwrap_or_default().add(header.profit_usd))
+            }
+            MevType::Nft => self.nft = Some(self.nft.unwrap_or_default().add(header.profit_usd)),
+            MevType::CrossDomain => {
+                self.cross_domain =
+                    Some(self.cross_domain.unwrap_or_default().add(header.profit_usd))
+            }
             _ => (),
         }
     }
@@ -284,9 +324,47 @@ impl TollByType {
             MevType::SearcherTx => {
                 self.searcher_tx = Some(self.searcher_tx.unwrap_or_default().add(header.bribe_usd))
             }
+            MevType::LongTail => {
+                self.long_tail = Some(self.long_tail.unwrap_or_default().add(header.bribe_usd))
+            }
+            MevType::Nft => self.nft = Some(self.nft.unwrap_or_default().add(header.bribe_usd)),
+            MevType::CrossDomain => {
+                self.cross_domain =
+                    Some(self.cross_domain.unwrap_or_default().add(header.bribe_usd))
+            }
             _ => (),
         }
     }
+
+    fn revert(&mut self, mev_type: MevType, amount: f64) {
+        self.total -= amount;
+        if let Some(toll) = self.toll_mut(mev_type) {
+            *toll = Some(toll.unwrap_or_default() - amount);
+        }
+    }
+
+    fn clear(&mut self, mev_type: MevType) {
+        if let Some(toll) = self.toll_mut(mev_type) {
+            *toll = None;
+        }
+    }
+
+    fn toll_mut(&mut self, mev_type: MevType) -> Option<&mut Option<f64>> {
+        match mev_type {
+            MevType::CexDexTrades => Some(&mut self.cex_dex_trades),
+            MevType::CexDexQuotes => Some(&mut self.cex_dex_quotes),
+            MevType::Sandwich => Some(&mut self.sandwich),
+            MevType::AtomicArb => Some(&mut self.atomic_backrun),
+            MevType::Jit => Some(&mut self.jit),
+            MevType::JitSandwich => Some(&mut self.jit_sandwich),
+            MevType::Liquidation => Some(&mut self.liquidation),
+            MevType::SearcherTx => Some(&mut self.searcher_tx),
+            MevType::LongTail => Some(&mut self.long_tail),
+            MevType::Nft => Some(&mut self.nft),
+            MevType::CrossDomain => Some(&mut self.cross_domain),
+            _ => None,
+        }
+    }
 }
 
 #[derive(
@@ -428,9 +506,23 @@ impl JoinedSearcherInfo {
     }
 }
 
-#[derive(Debug, PartialEq, Clone, Serialize_repr, Deserialize_repr)]
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    Clone,
+    Copy,
+    Serialize_repr,
+    Deserialize_repr,
+    rSerialize,
+    rDeserialize,
+    Archive,
+)]
 #[repr(u8)]
 pub enum SearcherEoaContract {
     EOA      = 0,
     Contract = 1,
 }
+
+self_convert_redefined!(SearcherEoaContract);
@@ -0,0 +1,353 @@
+use alloy_primitives::{wrap_fixed_bytes, Address, FixedBytes};
+use redefined::Redefined;
+use reth_db::DatabaseError;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+
+use super::searcher::SearcherEoaContract;
+use crate::{
+    db::redefined_types::primitives::AddressRedefined,
+    implement_table_value_codecs_with_zc,
+    mev::{BundleHeader, MevType},
+    FastHashMap,
+};
+
+/// Amount of blocks a searcher stats bucket covers, roughly a day of blocks
+pub const SEARCHER_STATS_BUCKET_SIZE: u64 = 7200;
+
+/// The first block of the bucket the block falls in
+pub fn searcher_stats_bucket(block_number: u64) -> u64 {
+    block_number - block_number % SEARCHER_STATS_BUCKET_SIZE
+}
+
+wrap_fixed_bytes!(
+    extra_derives: [],
+    pub struct SearcherStatsKey<29>;
+);
+
+impl reth_db::table::Encode for SearcherStatsKey {
+    type Encoded = [u8; 29];
+
+    fn encode(self) -> Self::Encoded {
+        self.0 .0
+    }
+}
+
+impl reth_db::table::Decode for SearcherStatsKey {
+    fn decode<B: AsRef<[u8]>>(value: B) -> Result<Self, DatabaseError> {
+        Ok(SearcherStatsKey::from_slice(value.as_ref()))
+    }
+}
+
+impl SearcherStatsKey {
+    /// Keys sort by bucket first so a block range is a contiguous walk
+    pub fn new(bucket_start: u64, address: Address, eoa_or_contract: SearcherEoaContract) -> Self {
+        let mut key = [0u8; 29];
+        key[..8].copy_from_slice(&bucket_start.to_be_bytes());
+        key[8..28].copy_from_slice(address.as_slice());
+        key[28] = eoa_or_contract as u8;
+
+        Self(FixedBytes(key))
+    }
+
+    /// The first and last key of the buckets the blocks fall in
+    pub fn range(start_block: u64, end_block: u64) -> (Self, Self) {
+        (
+            Self::new(searcher_stats_bucket(start_block), Address::ZERO, SearcherEoaContract::EOA),
+            Self::new(
+                searcher_stats_bucket(end_block),
+                Address::repeat_byte(u8::MAX),
+                SearcherEoaContract::Contract,
+            ),
+        )
+    }
+}
+
+/// What a searcher made with one type of mev over a bucket of blocks
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct SearcherPnl {
+    #[redefined(same_fields)]
+    pub mev_type:     MevType,
+    pub bundle_count: u64,
+    pub profit_usd:   f64,
+    pub bribe_usd:    f64,
+}
+
+/// The pnl of a searcher eoa or contract over the
+/// [`SEARCHER_STATS_BUCKET_SIZE`] blocks starting at `bucket_start`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct SearcherBucketStats {
+    pub bucket_start:    u64,
+    pub address:         Address,
+    #[redefined(same_fields)]
+    pub eoa_or_contract: SearcherEoaContract,
+    pub pnl:             Vec<SearcherPnl>,
+    /// The blocks accounted for, sorted. Applying a block twice, when a range
+    /// is rerun, or reverting one that was never applied is a no-op
+    pub applied_blocks:  Vec<u64>,
+}
+
+implement_table_value_codecs_with_zc!(SearcherBucketStatsRedefined);
+
+impl SearcherBucketStats {
+    pub fn new(bucket_start: u64, address: Address, eoa_or_contract: SearcherEoaContract) -> Self {
+        Self { bucket_start, address, eoa_or_contract, pnl: vec![], applied_blocks: vec![] }
+    }
+
+    pub fn key(&self) -> SearcherStatsKey {
+        SearcherStatsKey::new(self.bucket_start, self.address, self.eoa_or_contract)
+    }
+
+    /// Accounts the searcher's bundles of the block, returning false if the
+    /// block was already applied
+    pub fn apply_block(&mut self, block_number: u64, bundles: &[&BundleHeader]) -> bool {
+        let Err(idx) = self.applied_blocks.binary_search(&block_number) else { return false };
+        self.applied_blocks.insert(idx, block_number);
+
+        for header in bundles {
+            let pnl = self.pnl_mut(header.mev_type);
+            pnl.bundle_count += 1;
+            pnl.profit_usd += header.profit_usd;
+            pnl.bribe_usd += header.bribe_usd;
+        }
+
+        true
+    }
+
+    /// Takes the searcher's bundles of the block back out, returning false if
+    /// the block was never applied
+    pub fn revert_block(&mut self, block_number: u64, bundles: &[&BundleHeader]) -> bool {
+        let Ok(idx) = self.applied_blocks.binary_search(&block_number) else { return false };
+        self.applied_blocks.remove(idx);
+
+        for header in bundles {
+            let pnl = self.pnl_mut(header.mev_type);
+            pnl.bundle_count = pnl.bundle_count.saturating_sub(1);
+            pnl.profit_usd -= header.profit_usd;
+            pnl.bribe_usd -= header.bribe_usd;
+        }
+        self.pnl.retain(|pnl| pnl.bundle_count != 0);
+
+        true
+    }
+
+    /// No block left in the bucket, the entry can be deleted
+    pub fn is_empty(&self) -> bool {
+        self.applied_blocks.is_empty()
+    }
+
+    fn pnl_mut(&mut self, mev_type: MevType) -> &mut SearcherPnl {
+        // a searcher only runs a handful of mev types
+        let idx = self
+            .pnl
+            .iter()
+            .position(|pnl| pnl.mev_type == mev_type)
+            .unwrap_or_else(|| {
+                self.pnl.push(SearcherPnl {
+                    mev_type,
+                    bundle_count: 0,
+                    profit_usd: 0.0,
+                    bribe_usd: 0.0,
+                });
+                self.pnl.len() - 1
+            });
+
+        &mut self.pnl[idx]
+    }
+}
+
+/// Groups the bundles of a block by the eoa and the contract they are
+/// accounted to
+pub fn bundles_by_searcher(
+    bundles: &[BundleHeader],
+) -> FastHashMap<(Address, SearcherEoaContract), Vec<&BundleHeader>> {
+    let mut by_searcher: FastHashMap<_, Vec<_>> = FastHashMap::default();
+
+    for header in bundles {
+        by_searcher
+            .entry((header.eoa, SearcherEoaContract::EOA))
+            .or_default()
+            .push(header);
+        if let Some(contract) = header.mev_contract {
+            by_searcher
+                .entry((contract, SearcherEoaContract::Contract))
+                .or_default()
+                .push(header);
+        }
+    }
+
+    by_searcher
+}
+
+/// A searcher's totals over a range of buckets
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearcherLeaderboardEntry {
+    pub address:      Address,
+    pub bundle_count: u64,
+    pub profit_usd:   f64,
+    pub bribe_usd:    f64,
+    /// The mev type the searcher made the most with
+    pub top_mev_type: MevType,
+}
+
+/// Ranks the eoas or contracts by their profit over the buckets, optionally
+/// only counting one type of mev
+pub fn searcher_leaderboard(
+    stats: &[SearcherBucketStats],
+    eoa_or_contract: SearcherEoaContract,
+    mev_type: Option<MevType>,
+) -> Vec<SearcherLeaderboardEntry> {
+    let mut totals: FastHashMap<Address, (u64, f64, f64, FastHashMap<MevType, f64>)> =
+        FastHashMap::default();
+
+    stats
+        .iter()
+        .filter(|stats| stats.eoa_or_contract == eoa_or_contract)
+        .flat_map(|stats| stats.pnl.iter().map(|pnl| (stats.address, pnl)))
+        .filter(|(_, pnl)| mev_type.map_or(true, |mev_type| pnl.mev_type == mev_type))
+        .for_each(|(address, pnl)| {
+            let entry = totals.entry(address).or_default();
+            entry.0 += pnl.bundle_count;
+            entry.1 += pnl.profit_usd;
+            entry.2 += pnl.bribe_usd;
+            *entry.3.entry(pnl.mev_type).or_default() += pnl.profit_usd;
+        });
+
+    let mut leaderboard = totals
+        .into_iter()
+        .map(|(address, (bundle_count, profit_usd, bribe_usd, by_type))| SearcherLeaderboardEntry {
+            address,
+            bundle_count,
+            profit_usd,
+            bribe_usd,
+            top_mev_type: by_type
+                .into_iter()
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(mev_type, _)| mev_type)
+                .unwrap_or_default(),
+        })
+        .collect::<Vec<_>>();
+
+    leaderboard.sort_unstable_by(|a, b| b.profit_usd.total_cmp(&a.profit_usd));
+    leaderboard
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(eoa: u8, contract: Option<u8>, mev_type: MevType, profit_usd: f64) -> BundleHeader {
+        BundleHeader {
+            eoa: Address::with_last_byte(eoa),
+            mev_contract: contract.map(Address::with_last_byte),
+            mev_type,
+            profit_usd,
+            bribe_usd: 1.0,
+            ..Default::default()
+        }
+    }
+
+    /// Folds the blocks into per searcher stats like the writer does
+    fn apply(
+        stats: &mut FastHashMap<SearcherStatsKey, SearcherBucketStats>,
+        block_number: u64,
+        bundles: &[BundleHeader],
+    ) {
+        let bucket_start = searcher_stats_bucket(block_number);
+        for ((address, eoa_or_contract), headers) in bundles_by_searcher(bundles) {
+            stats
+                .entry(SearcherStatsKey::new(bucket_start, address, eoa_or_contract))
+                .or_insert_with(|| SearcherBucketStats::new(bucket_start, address, eoa_or_contract))
+                .apply_block(block_number, &headers);
+        }
+    }
+
+    #[test]
+    fn test_searcher_leaderboard() {
+        assert_eq!(searcher_stats_bucket(19_000_001), 18_997_200);
+
+        let mut stats = FastHashMap::default();
+        apply(
+            &mut stats,
+            1,
+            &[
+                header(1, Some(10), MevType::Sandwich, 100.0),
+                header(1, Some(10), MevType::Sandwich, 50.0),
+                header(2, None, MevType::AtomicArb, 120.0),
+            ],
+        );
+        assert_eq!(stats.len(), 3);
+
+        apply(
+            &mut stats,
+            SEARCHER_STATS_BUCKET_SIZE,
+            &[
+                header(1, Some(10), MevType::AtomicArb, 10.0),
+                header(2, None, MevType::AtomicArb, 20.0),
+            ],
+        );
+
+        let stats = stats.into_values().collect::<Vec<_>>();
+        let eoas = searcher_leaderboard(&stats, SearcherEoaContract::EOA, None);
+        assert_eq!(eoas.len(), 2);
+        assert_eq!(eoas[0].address, Address::with_last_byte(1));
+        assert_eq!(eoas[0].bundle_count, 3);
+        assert_eq!(eoas[0].profit_usd, 160.0);
+        assert_eq!(eoas[0].bribe_usd, 3.0);
+        assert_eq!(eoas[0].top_mev_type, MevType::Sandwich);
+
+        let arbers =
+            searcher_leaderboard(&stats, SearcherEoaContract::EOA, Some(MevType::AtomicArb));
+        assert_eq!(arbers[0].address, Address::with_last_byte(2));
+        assert_eq!(arbers[0].profit_usd, 140.0);
+
+        let contracts = searcher_leaderboard(&stats, SearcherEoaContract::Contract, None);
+        assert_eq!(contracts.len(), 1);
+        assert_eq!(contracts[0].bundle_count, 3);
+    }
+
+    #[test]
+    fn test_apply_and_revert_block() {
+        let sandwich = header(1, None, MevType::Sandwich, 100.0);
+        let arb = header(1, None, MevType::AtomicArb, 20.0);
+        let mut stats = SearcherBucketStats::new(0, sandwich.eoa, SearcherEoaContract::EOA);
+
+        assert!(stats.apply_block(2, &[&sandwich]));
+        assert!(stats.apply_block(1, &[&arb]));
+        // rerunning a block doesn't count it twice
+        assert!(!stats.apply_block(2, &[&sandwich]));
+        assert_eq!(stats.applied_blocks, vec![1, 2]);
+        assert_eq!(stats.pnl.len(), 2);
+        assert_eq!(stats.pnl[0].bundle_count, 1);
+        assert_eq!(stats.pnl[0].profit_usd, 100.0);
+
+        assert!(stats.revert_block(2, &[&sandwich]));
+        assert!(!stats.revert_block(2, &[&sandwich]));
+        assert_eq!(stats.pnl.len(), 1);
+        assert_eq!(stats.pnl[0].mev_type, MevType::AtomicArb);
+
+        assert!(stats.revert_block(1, &[&arb]));
+        assert!(stats.is_empty());
+        assert!(stats.pnl.is_empty());
+    }
+
+    #[test]
+    fn test_key_orders_by_bucket() {
+        let (start, end) = SearcherStatsKey::range(1, SEARCHER_STATS_BUCKET_SIZE);
+        let key = SearcherStatsKey::new(
+            SEARCHER_STATS_BUCKET_SIZE,
+            Address::repeat_byte(u8::MAX),
+            SearcherEoaContract::EOA,
+        );
+        assert!(start < key && key <= end);
+        assert!(
+            end < SearcherStatsKey::new(
+                2 * SEARCHER_STATS_BUCKET_SIZE,
+                Address::ZERO,
+                SearcherEoaContract::EOA
+            )
+        );
+    }
+}
@@ -1,11 +1,18 @@
-use alloy_primitives::Address;
+use alloy_primitives::{Address, TxHash};
 
 use crate::{
     db::{
-        address_metadata::AddressMetadata, address_to_protocol_info::ProtocolInfo,
-        builder::BuilderInfo, cex::trades::CexTradeMap, dex::DexQuotes, metadata::Metadata,
-        mev_block::MevBlockWithClassified, searcher::SearcherInfo,
+        address_metadata::AddressMetadata,
+        address_to_protocol_info::ProtocolInfo,
+        builder::BuilderInfo,
+        cex::trades::{CexTradeMap, PerpTickerMap},
+        dex::DexQuotes,
+        metadata::Metadata,
+        mev_block::MevBlockWithClassified,
+        searcher::SearcherInfo,
+        searcher_stats::SearcherBucketStats,
         token_info::TokenInfoWithAddress,
+        uniswap_v4_pool_key::UniswapV4PoolKey,
     },
     pair::Pair,
     structured_trace::TxTrace,
@@ -100,6 +107,8 @@ pub trait LibmdbxReader: Send + Sync + Unpin + 'static {
 
     fn get_cex_trades(&self, block: u64) -> eyre::Result<CexTradeMap>;
 
+    fn get_perp_tickers(&self, block: u64) -> eyre::Result<PerpTickerMap>;
+
     fn try_fetch_address_metadata(&self, address: Address)
         -> eyre::Result<Option<AddressMetadata>>;
 
@@ -127,7 +136,7 @@ pub trait LibmdbxReader: Send + Sync + Unpin + 'static {
     fn protocols_created_before(
         &self,
         start_block: u64,
-    ) -> eyre::Result<FastHashMap<(Address, Protocol), Pair>>;
+    ) -> eyre::Result<FastHashMap<(Address, Protocol), Vec<Pair>>>;
 
     fn protocols_created_range(
         &self,
@@ -155,4 +164,23 @@ pub trait LibmdbxReader: Send + Sync + Unpin + 'static {
     }
 
     fn load_trace(&self, block_num: u64) -> eyre::Result<Vec<TxTrace>>;
+
+    /// Returns the time, in unix microseconds, the given txes were first seen
+    /// in the mempool. Txes that were never seen are missing from the map
+    fn fetch_mempool_first_seen(
+        &self,
+        tx_hashes: &[TxHash],
+    ) -> eyre::Result<FastHashMap<TxHash, u64>>;
+
+    /// Returns the key of every uniswap v4 pool, keyed by the address the pool
+    /// is represented by
+    fn fetch_uniswap_v4_pool_keys(&self) -> eyre::Result<FastHashMap<Address, UniswapV4PoolKey>>;
+
+    /// Returns the stats of every searcher in the buckets that overlap the
+    /// block range
+    fn fetch_searcher_stats(
+        &self,
+        start_block: u64,
+        end_block: u64,
+    ) -> eyre::Result<Vec<SearcherBucketStats>>;
 }
@@ -1,12 +1,13 @@
-use alloy_primitives::Address;
+use alloy_primitives::{Address, TxHash};
 use futures::Future;
 
 use crate::{
     db::{
-        address_metadata::AddressMetadata, block_analysis::BlockAnalysis, builder::BuilderInfo,
-        dex::DexQuotes, searcher::SearcherInfo,
+        address_metadata::AddressMetadata, block_analysis::BlockAnalysis,
+        block_auction::BlockAuction, builder::BuilderInfo, dedup_decision::DedupDecision,
+        dex::DexQuotes, searcher::SearcherInfo, uniswap_v4_pool_key::UniswapV4PoolKey,
     },
-    mev::{Bundle, MevBlock},
+    mev::{Bundle, BundleHeader, MevBlock},
     normalized_actions::Action,
     structured_trace::TxTrace,
     BlockTree, Protocol,
@@ -26,6 +27,21 @@ pub trait DBWriter: Send + Unpin + 'static {
         self.inner().write_block_analysis(block_analysis)
     }
 
+    fn write_block_auction(
+        &self,
+        block_auction: BlockAuction,
+    ) -> impl Future<Output = eyre::Result<()>> + Send {
+        self.inner().write_block_auction(block_auction)
+    }
+
+    /// Records the bundles the composer's deduplication dropped
+    fn write_dedup_decisions(
+        &self,
+        decisions: Vec<DedupDecision>,
+    ) -> impl Future<Output = eyre::Result<()>> + Send {
+        self.inner().write_dedup_decisions(decisions)
+    }
+
     fn write_dex_quotes(
         &self,
         block_number: u64,
@@ -110,6 +126,15 @@ pub trait DBWriter: Send + Unpin + 'static {
             .insert_pool(block, address, tokens, curve_lp_token, classifier_name)
     }
 
+    /// Stores the key of a uniswap v4 pool next to its protocol info
+    fn insert_uniswap_v4_pool_key(
+        &self,
+        address: Address,
+        key: UniswapV4PoolKey,
+    ) -> impl Future<Output = eyre::Result<()>> + Send {
+        self.inner().insert_uniswap_v4_pool_key(address, key)
+    }
+
     fn insert_tree(
         &self,
         tree: BlockTree<Action>,
@@ -124,4 +149,29 @@ pub trait DBWriter: Send + Unpin + 'static {
     ) -> impl Future<Output = eyre::Result<()>> + Send {
         self.inner().save_traces(block, traces)
     }
+
+    /// Removes everything derived from the given block so that it can be
+    /// reprocessed after it was reorged out
+    fn revert_block(&self, block_number: u64) -> impl Future<Output = eyre::Result<()>> + Send {
+        self.inner().revert_block(block_number)
+    }
+
+    /// Records when pending txes were first seen in the mempool, in unix
+    /// microseconds. Txes that already have a first seen time keep it
+    fn write_mempool_first_seen(
+        &self,
+        first_seen: Vec<(TxHash, u64)>,
+    ) -> impl Future<Output = eyre::Result<()>> + Send {
+        self.inner().write_mempool_first_seen(first_seen)
+    }
+
+    /// Adds the bundles of the block to the pnl of their searchers in the
+    /// stats bucket the block falls in
+    fn write_searcher_stats(
+        &self,
+        block_number: u64,
+        bundles: Vec<BundleHeader>,
+    ) -> impl Future<Output = eyre::Result<()>> + Send {
+        self.inner().write_searcher_stats(block_number, bundles)
+    }
 }
@@ -0,0 +1,25 @@
+use alloy_primitives::Address;
+use redefined::Redefined;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    db::redefined_types::primitives::AddressRedefined, implement_table_value_codecs_with_zc,
+};
+
+/// The key a Uniswap V4 pool is identified by in the pool manager.
+///
+/// V4 pools are represented by the first 20 bytes of the hash of their key,
+/// which can't be reversed. The key is stored alongside the pool's protocol
+/// info so that its state can be loaded after a restart.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct UniswapV4PoolKey {
+    pub currency0:    Address,
+    pub currency1:    Address,
+    pub fee:          u32,
+    pub tick_spacing: i32,
+    pub hooks:        Address,
+}
+
+implement_table_value_codecs_with_zc!(UniswapV4PoolKeyRedefined);
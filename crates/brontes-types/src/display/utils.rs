@@ -63,6 +63,12 @@ pub fn display_sandwich(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result
             format_etherscan_url(tx_hash)
         )?;
 
+        if sandwich_data.is_multi_block() {
+            if let Some(block) = sandwich_data.frontrun_block_numbers.get(i) {
+                writeln!(f, "      - {}: {}", "Block".bright_blue(), block)?;
+            }
+        }
+
         // Frontrun swaps
         writeln!(f, "      - {}:", "Swaps".bright_blue())?;
         for (j, swap) in swaps.iter().enumerate() {
@@ -118,6 +124,10 @@ pub fn display_sandwich(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result
         format_etherscan_url(&sandwich_data.backrun_tx_hash)
     )?;
 
+    if sandwich_data.is_multi_block() {
+        writeln!(f, "     - {}: {}", "Block".bright_blue(), sandwich_data.backrun_block_number)?;
+    }
+
     writeln!(f, "     - {}:", "Swaps".bright_blue())?;
     for (i, swap) in sandwich_data.backrun_swaps.iter().enumerate() {
         writeln!(f, "        {}: {}", format!(" - {}", i + 1).green(), swap)?;
@@ -554,6 +564,292 @@ pub fn display_liquidation(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Resu
     Ok(())
 }
 
+pub fn display_long_tail(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result {
+    let ascii_header = indoc! {r#"
+
+         _                         _____     _ _
+        | |                       |_   _|   (_) |
+        | |     ___  _ __   __ _    | | __ _ _| |
+        | |    / _ \| '_ \ / _` |   | |/ _` | | |
+        | |___| (_) | | | | (_| |   | | (_| | | |
+        \_____/\___/|_| |_|\__, |   \_/\__,_|_|_|
+                            __/ |
+                           |___/
+
+    "#};
+
+    for line in ascii_header.lines() {
+        writeln!(f, "{}", line.bright_red())?;
+    }
+
+    let long_tail_data = match &bundle.data {
+        BundleData::LongTail(data) => data,
+        _ => panic!("Wrong bundle type"),
+    };
+
+    // MEV Bot Details
+    writeln!(f, "\n{}: \n", "Transaction Details".bold().underline().bright_yellow())?;
+    writeln!(f, "   - EOA: {}", bundle.header.eoa)?;
+
+    match bundle.header.mev_contract {
+        Some(contract) => {
+            writeln!(f, "   - Mev Contract: {}", contract)?;
+        }
+        None => {
+            writeln!(f, "   - Mev Contract: None")?;
+        }
+    }
+
+    // Pool Section
+    writeln!(f, "\n{}\n", "Sniped Pool".bright_yellow().underline())?;
+    writeln!(f, " - {}: {}", "Token".bright_blue(), long_tail_data.sniped_token)?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Pool".bright_blue(),
+        formate_etherscan_address_url(&long_tail_data.pool)
+    )?;
+    writeln!(f, " - {}: {}", "Created At Block".bright_blue(), long_tail_data.pool_creation_block)?;
+
+    // Entry Section
+    writeln!(f, "\n{}\n", "Entries".bright_yellow().underline())?;
+    for ((tx_hash, block), swaps) in long_tail_data
+        .entry_tx_hashes
+        .iter()
+        .zip(&long_tail_data.entry_block_numbers)
+        .zip(&long_tail_data.entry_swaps)
+    {
+        writeln!(f, " - {} (block {}):", format_etherscan_url(tx_hash).bright_blue(), block)?;
+        for (i, swap) in swaps.iter().enumerate() {
+            writeln!(f, "    {}: {}", format!(" - {}", i + 1).green(), swap)?;
+        }
+    }
+    writeln!(
+        f,
+        " - {}: {}",
+        "Entry Size (USD)".bright_white(),
+        format!("${:.2}", long_tail_data.entry_size_usd).bright_white()
+    )?;
+
+    // Exit Section
+    writeln!(f, "\n{}\n", "Exits".bright_yellow().underline())?;
+    for (tx_hash, swaps) in long_tail_data
+        .exit_tx_hashes
+        .iter()
+        .zip(&long_tail_data.exit_swaps)
+    {
+        writeln!(f, " - {}:", format_etherscan_url(tx_hash).bright_blue())?;
+        for (i, swap) in swaps.iter().enumerate() {
+            writeln!(f, "    {}: {}", format!(" - {}", i + 1).green(), swap)?;
+        }
+    }
+    writeln!(
+        f,
+        " - {}: {}",
+        "Exit Size (USD)".bright_white(),
+        format!("${:.2}", long_tail_data.exit_size_usd).bright_white()
+    )?;
+
+    // Profitability Section
+    writeln!(f, "\n{}\n", "Profitability".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Realized PnL (USD)".bright_white(),
+        format_profit(long_tail_data.realized_pnl_usd)
+            .to_string()
+            .bright_white()
+    )?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Unrealized PnL (USD)".bright_white(),
+        format_profit(long_tail_data.unrealized_pnl_usd)
+            .to_string()
+            .bright_white()
+    )?;
+    writeln!(
+        f,
+        " - {}: {}\n",
+        "Bribe (USD)".bright_white(),
+        format_bribe(bundle.header.bribe_usd)
+            .to_string()
+            .bright_red()
+    )?;
+
+    bundle
+        .header
+        .balance_deltas
+        .iter()
+        .for_each(|tx_delta| writeln!(f, "{}", tx_delta).expect("Failed to write balance deltas"));
+    Ok(())
+}
+
+pub fn display_nft(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result {
+    let ascii_header = indoc! {r#"
+
+         _   _  __ _
+        | \ | |/ _| |
+        |  \| | |_| |_
+        | . ` |  _| __|
+        | |\  | | | |_
+        \_| \_/_|  \__|
+
+    "#};
+
+    for line in ascii_header.lines() {
+        writeln!(f, "{}", line.bright_red())?;
+    }
+
+    let nft_data = match &bundle.data {
+        BundleData::Nft(data) => data,
+        _ => panic!("Wrong bundle type"),
+    };
+
+    // MEV Bot Details
+    writeln!(f, "\n{}: \n", "Transaction Details".bold().underline().bright_yellow())?;
+    writeln!(f, "   - EOA: {}", bundle.header.eoa)?;
+
+    match bundle.header.mev_contract {
+        Some(contract) => {
+            writeln!(f, "   - Mev Contract: {}", contract)?;
+        }
+        None => {
+            writeln!(f, "   - Mev Contract: None")?;
+        }
+    }
+    writeln!(f, "   - Kind: {}", nft_data.kind)?;
+    for tx_hash in &nft_data.tx_hashes {
+        writeln!(f, "   - Tx: {}", format_etherscan_url(tx_hash).bright_blue())?;
+    }
+
+    // Trades Section
+    writeln!(f, "\n{}\n", "Buys".bright_yellow().underline())?;
+    for (i, trade) in nft_data.buys.iter().enumerate() {
+        writeln!(f, "    {}: {}", format!(" - {}", i + 1).green(), trade)?;
+    }
+    writeln!(f, "\n{}\n", "Sells".bright_yellow().underline())?;
+    for (i, trade) in nft_data.sells.iter().enumerate() {
+        writeln!(f, "    {}: {}", format!(" - {}", i + 1).green(), trade)?;
+    }
+
+    // Profitability Section
+    writeln!(f, "\n{}\n", "Profitability".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Profit (ETH)".bright_white(),
+        format!("{:.6}", nft_data.profit_eth).bright_white()
+    )?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Profit (USD)".bright_white(),
+        format_profit(bundle.header.profit_usd)
+            .to_string()
+            .bright_white()
+    )?;
+    writeln!(
+        f,
+        " - {}: {}\n",
+        "Bribe (USD)".bright_white(),
+        format_bribe(bundle.header.bribe_usd)
+            .to_string()
+            .bright_red()
+    )?;
+
+    bundle
+        .header
+        .balance_deltas
+        .iter()
+        .for_each(|tx_delta| writeln!(f, "{}", tx_delta).expect("Failed to write balance deltas"));
+    Ok(())
+}
+
+pub fn display_cross_domain(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result {
+    let cross_domain_data = match &bundle.data {
+        BundleData::CrossDomain(data) => data,
+        _ => panic!("Wrong bundle type"),
+    };
+
+    writeln!(f, "\n{}\n", "Cross Domain Arbitrage".bold().bright_cyan())?;
+
+    // Tx details
+    writeln!(f, "{}: \n", "Transaction Details".bold().underline().bright_yellow())?;
+    writeln!(f, "   - Tx Index: {}", bundle.header.tx_index.to_string().bold())?;
+    writeln!(f, "   - EOA: {}", bundle.header.eoa)?;
+
+    match bundle.header.mev_contract {
+        Some(contract) => {
+            writeln!(f, "   - Mev Contract: {}", contract)?;
+        }
+        None => {
+            writeln!(f, "   - Mev Contract: None")?;
+        }
+    }
+    writeln!(f, "   - Etherscan: {}", format_etherscan_url(&cross_domain_data.tx_hash))?;
+
+    // Legs Section
+    writeln!(f, "\n{}\n", "Mainnet Swaps".bright_yellow().underline())?;
+    for (i, swap) in cross_domain_data.mainnet_swaps.iter().enumerate() {
+        writeln!(f, "    {}: {}", format!(" - {}", i + 1).green(), swap)?;
+    }
+    writeln!(f, "\n{}\n", "Bridge Actions".bright_yellow().underline())?;
+    for deposit in &cross_domain_data.deposits {
+        writeln!(f, "    - {}", deposit)?;
+    }
+    for fill in &cross_domain_data.fills {
+        writeln!(f, "    - {}", fill)?;
+    }
+    writeln!(f, "\n{}\n", "L2 Swaps".bright_yellow().underline())?;
+    for (i, swap) in cross_domain_data.l2_swaps.iter().enumerate() {
+        writeln!(
+            f,
+            "    {}: Chain {} - Swapped {} {} for {} {} in {:?} ({:?})",
+            format!(" - {}", i + 1).green(),
+            swap.chain_id.to_string().bold(),
+            format!("{:.4}", swap.amount_in).red(),
+            swap.token_in_symbol.bold(),
+            format!("{:.4}", swap.amount_out).green(),
+            swap.token_out_symbol.bold(),
+            swap.pool,
+            swap.tx_hash,
+        )?;
+    }
+
+    writeln!(f, " - {}:", "Gas Details".bright_blue())?;
+    cross_domain_data
+        .gas_details
+        .pretty_print_with_spaces(f, 8)?;
+
+    // Profitability Section
+    writeln!(f, "\n{}\n", "Profitability".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Bundle Profit (USD)".bright_white(),
+        format_profit(bundle.header.profit_usd)
+            .to_string()
+            .bright_white()
+    )?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Bribe (USD)".bright_white(),
+        format_bribe(bundle.header.bribe_usd)
+            .to_string()
+            .bright_red()
+    )?;
+
+    bundle
+        .header
+        .balance_deltas
+        .iter()
+        .for_each(|tx_delta| writeln!(f, "{}", tx_delta).expect("Failed to write balance deltas"));
+
+    Ok(())
+}
+
 pub fn display_jit_liquidity(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result {
     let ascii_header = indoc! {r#"
 
@@ -765,6 +1061,30 @@ pub fn display_cex_dex(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result {
         )?;
     }
 
+    writeln!(f, "  - {}", "Per Exchange PnL by Fee Tier:".bold().underline().purple())?;
+    for tier_pnl in &cex_dex_data.per_exchange_tier_pnl {
+        writeln!(
+            f,
+            "    - {} ({} tier): Maker: {:.6} Taker: {:.6}",
+            tier_pnl.exchange.to_string().bold().green(),
+            tier_pnl.tier,
+            tier_pnl.pnl_maker.clone().to_float(),
+            tier_pnl.pnl_taker.clone().to_float()
+        )?;
+    }
+
+    writeln!(f, "  - {}", "Perp Hedge PnL:".bold().underline().purple())?;
+    for hedge_pnl in &cex_dex_data.perp_hedge_pnl {
+        writeln!(
+            f,
+            "    - {}: Maker: {:.6} Taker: {:.6} Funding: {:.6}",
+            hedge_pnl.exchange.to_string().bold().green(),
+            hedge_pnl.pnl_maker.clone().to_float(),
+            hedge_pnl.pnl_taker.clone().to_float(),
+            hedge_pnl.funding_cost.clone().to_float()
+        )?;
+    }
+
     writeln!(f, "\n----------------------------------------")?;
     writeln!(f, "{}", "Arb Details".bold().red().underline())?;
 
@@ -924,6 +1244,9 @@ pub fn display_cex_dex_quotes(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::R
     writeln!(f, "\n{}", "Quote Details".bold().underline().bright_yellow())?;
     writeln!(f, "   - Exchange: {}", cex_dex_data.exchange.to_string().green())?;
     writeln!(f, "   - PnL (USD): {}", format!("{:.6}", cex_dex_data.pnl).cyan())?;
+    for tier_pnl in &cex_dex_data.tier_pnl {
+        writeln!(f, "      - {} Tier: {:.6}", tier_pnl.tier.bold(), tier_pnl.pnl)?;
+    }
 
     writeln!(f, "\n{}", "Swaps".bold().underline().bright_yellow())?;
     for (i, swap) in cex_dex_data.swaps.iter().enumerate() {
@@ -1004,6 +1327,39 @@ pub fn display_searcher_tx(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Resu
         .iter()
         .for_each(|tx_delta| writeln!(f, "{}", tx_delta).expect("Failed to write balance deltas"));
 
+    // Lending
+    let lending_actions = searcher_tx_data
+        .loans
+        .iter()
+        .map(|loan| (loan.trace_index, loan.to_string()))
+        .chain(
+            searcher_tx_data
+                .repayments
+                .iter()
+                .map(|repayment| (repayment.trace_index, repayment.to_string())),
+        )
+        .chain(
+            searcher_tx_data
+                .supplies
+                .iter()
+                .map(|supply| (supply.trace_index, supply.to_string())),
+        )
+        .chain(
+            searcher_tx_data
+                .withdrawals
+                .iter()
+                .map(|withdraw| (withdraw.trace_index, withdraw.to_string())),
+        )
+        .sorted_by_key(|(trace_index, _)| *trace_index)
+        .collect_vec();
+
+    if !lending_actions.is_empty() {
+        writeln!(f, "\n{}: \n", "Lending".underline().bright_yellow())?;
+        for (_, action) in lending_actions {
+            writeln!(f, "    - {}", action)?;
+        }
+    }
+
     // Gas Details
     writeln!(f, "\n{}: \n", "Gas Details".underline().bright_yellow())?;
 
@@ -199,6 +199,9 @@ pub struct MevCount {
     pub atomic_backrun_count: Option<u64>,
     pub liquidation_count:    Option<u64>,
     pub searcher_tx_count:    Option<u64>,
+    pub long_tail_count:      Option<u64>,
+    pub nft_count:            Option<u64>,
+    pub cross_domain_count:   Option<u64>,
 }
 
 impl MevCount {
@@ -231,9 +234,43 @@ impl MevCount {
             MevType::JitCexDex => {
                 self.jit_cex_dex_count = Some(self.jit_cex_dex_count.unwrap_or_default().add(1))
             }
+            MevType::LongTail => {
+                self.long_tail_count = Some(self.long_tail_count.unwrap_or_default().add(1))
+            }
+            MevType::Nft => self.nft_count = Some(self.nft_count.unwrap_or_default().add(1)),
+            MevType::CrossDomain => {
+                self.cross_domain_count = Some(self.cross_domain_count.unwrap_or_default().add(1))
+            }
             _ => {}
         }
     }
+
+    /// Undoes [`MevCount::increment_count`], clearing the type count once it
+    /// reaches zero
+    pub fn decrement_count(&mut self, mev_type: MevType) {
+        self.bundle_count = self.bundle_count.saturating_sub(1);
+        if let Some(count) = self.count_mut(mev_type) {
+            *count = count.map(|c| c.saturating_sub(1)).filter(|c| *c != 0);
+        }
+    }
+
+    fn count_mut(&mut self, mev_type: MevType) -> Option<&mut Option<u64>> {
+        match mev_type {
+            MevType::CexDexTrades => Some(&mut self.cex_dex_trade_count),
+            MevType::CexDexQuotes => Some(&mut self.cex_dex_quote_count),
+            MevType::Sandwich => Some(&mut self.sandwich_count),
+            MevType::AtomicArb => Some(&mut self.atomic_backrun_count),
+            MevType::Jit => Some(&mut self.jit_count),
+            MevType::JitSandwich => Some(&mut self.jit_sandwich_count),
+            MevType::Liquidation => Some(&mut self.liquidation_count),
+            MevType::SearcherTx => Some(&mut self.searcher_tx_count),
+            MevType::JitCexDex => Some(&mut self.jit_cex_dex_count),
+            MevType::LongTail => Some(&mut self.long_tail_count),
+            MevType::Nft => Some(&mut self.nft_count),
+            MevType::CrossDomain => Some(&mut self.cross_domain_count),
+            _ => None,
+        }
+    }
 }
 self_convert_redefined!(MevCount);
 
@@ -268,6 +305,15 @@ impl fmt::Display for MevCount {
         if let Some(count) = self.searcher_tx_count {
             writeln!(f, "    - Searcher TXs: {}", count.to_string().bold())?;
         }
+        if let Some(count) = self.long_tail_count {
+            writeln!(f, "    - Long Tail: {}", count.to_string().bold())?;
+        }
+        if let Some(count) = self.nft_count {
+            writeln!(f, "    - Nft: {}", count.to_string().bold())?;
+        }
+        if let Some(count) = self.cross_domain_count {
+            writeln!(f, "    - Cross Domain: {}", count.to_string().bold())?;
+        }
 
         Ok(())
     }
@@ -31,6 +31,9 @@ pub enum BundleData {
     CexDexQuote(CexDexQuote),
     CexDex(CexDex),
     Liquidation(Liquidation),
+    LongTail(LongTail),
+    Nft(NftMev),
+    CrossDomain(CrossDomainArb),
     Unknown(SearcherTx),
 }
 
@@ -50,6 +53,9 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.mev_type(),
             BundleData::CexDexQuote(m) => m.mev_type(),
             BundleData::Liquidation(m) => m.mev_type(),
+            BundleData::LongTail(m) => m.mev_type(),
+            BundleData::Nft(m) => m.mev_type(),
+            BundleData::CrossDomain(m) => m.mev_type(),
             BundleData::Unknown(m) => m.mev_type(),
         }
     }
@@ -63,6 +69,9 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.total_gas_paid(),
             BundleData::CexDexQuote(m) => m.total_gas_paid(),
             BundleData::Liquidation(m) => m.total_gas_paid(),
+            BundleData::LongTail(m) => m.total_gas_paid(),
+            BundleData::Nft(m) => m.total_gas_paid(),
+            BundleData::CrossDomain(m) => m.total_gas_paid(),
             BundleData::Unknown(s) => s.total_gas_paid(),
         }
     }
@@ -76,6 +85,9 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.total_priority_fee_paid(base_fee),
             BundleData::CexDexQuote(m) => m.total_priority_fee_paid(base_fee),
             BundleData::Liquidation(m) => m.total_priority_fee_paid(base_fee),
+            BundleData::LongTail(m) => m.total_priority_fee_paid(base_fee),
+            BundleData::Nft(m) => m.total_priority_fee_paid(base_fee),
+            BundleData::CrossDomain(m) => m.total_priority_fee_paid(base_fee),
             BundleData::Unknown(s) => s.total_priority_fee_paid(base_fee),
         }
     }
@@ -89,6 +101,9 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.bribe(),
             BundleData::CexDexQuote(m) => m.bribe(),
             BundleData::Liquidation(m) => m.bribe(),
+            BundleData::LongTail(m) => m.bribe(),
+            BundleData::Nft(m) => m.bribe(),
+            BundleData::CrossDomain(m) => m.bribe(),
             BundleData::Unknown(s) => s.bribe(),
         }
     }
@@ -102,6 +117,9 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.mev_transaction_hashes(),
             BundleData::CexDexQuote(m) => m.mev_transaction_hashes(),
             BundleData::Liquidation(m) => m.mev_transaction_hashes(),
+            BundleData::LongTail(m) => m.mev_transaction_hashes(),
+            BundleData::Nft(m) => m.mev_transaction_hashes(),
+            BundleData::CrossDomain(m) => m.mev_transaction_hashes(),
             BundleData::Unknown(s) => s.mev_transaction_hashes(),
         }
     }
@@ -115,6 +133,9 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.protocols(),
             BundleData::CexDexQuote(m) => m.protocols(),
             BundleData::Liquidation(m) => m.protocols(),
+            BundleData::LongTail(m) => m.protocols(),
+            BundleData::Nft(m) => m.protocols(),
+            BundleData::CrossDomain(m) => m.protocols(),
             BundleData::Unknown(s) => s.protocols(),
         }
     }
@@ -162,6 +183,24 @@ impl From<Liquidation> for BundleData {
     }
 }
 
+impl From<LongTail> for BundleData {
+    fn from(value: LongTail) -> Self {
+        Self::LongTail(value)
+    }
+}
+
+impl From<NftMev> for BundleData {
+    fn from(value: NftMev) -> Self {
+        Self::Nft(value)
+    }
+}
+
+impl From<CrossDomainArb> for BundleData {
+    fn from(value: CrossDomainArb) -> Self {
+        Self::CrossDomain(value)
+    }
+}
+
 impl Serialize for BundleData {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -175,6 +214,9 @@ impl Serialize for BundleData {
             BundleData::CexDex(cex_dex) => cex_dex.serialize(serializer),
             BundleData::CexDexQuote(cex_dex) => cex_dex.serialize(serializer),
             BundleData::Liquidation(liquidation) => liquidation.serialize(serializer),
+            BundleData::LongTail(long_tail) => long_tail.serialize(serializer),
+            BundleData::Nft(nft) => nft.serialize(serializer),
+            BundleData::CrossDomain(arb) => arb.serialize(serializer),
             BundleData::Unknown(s) => s.serialize(serializer),
         }
     }
@@ -190,6 +232,9 @@ impl InsertRow for BundleData {
             BundleData::CexDex(cex_dex) => cex_dex.get_column_names(),
             BundleData::CexDexQuote(cex_dex) => cex_dex.get_column_names(),
             BundleData::Liquidation(liquidation) => liquidation.get_column_names(),
+            BundleData::LongTail(long_tail) => long_tail.get_column_names(),
+            BundleData::Nft(nft) => nft.get_column_names(),
+            BundleData::CrossDomain(arb) => arb.get_column_names(),
             BundleData::Unknown(s) => s.get_column_names(),
         }
     }
@@ -68,6 +68,9 @@ impl fmt::Display for Bundle {
             MevType::Liquidation => display_liquidation(self, f)?,
             MevType::JitSandwich => display_jit_liquidity_sandwich(self, f)?,
             MevType::SearcherTx => display_searcher_tx(self, f)?,
+            MevType::LongTail => display_long_tail(self, f)?,
+            MevType::Nft => display_nft(self, f)?,
+            MevType::CrossDomain => display_cross_domain(self, f)?,
             MevType::Unknown => (),
         }
 
@@ -102,6 +105,9 @@ pub enum MevType {
     Liquidation,
     AtomicArb,
     SearcherTx,
+    LongTail,
+    Nft,
+    CrossDomain,
     #[default]
     Unknown,
 }
@@ -115,6 +121,9 @@ impl MevType {
             | MevType::AtomicArb
             | MevType::Liquidation
             | MevType::SearcherTx
+            | MevType::LongTail
+            | MevType::Nft
+            | MevType::CrossDomain
             | MevType::Unknown => false,
             MevType::CexDexRfq
             | MevType::CexDexTrades
@@ -135,6 +144,9 @@ impl MevType {
             MevType::JitSandwich => "jit-sandwich",
             MevType::SearcherTx => "searcher-tx",
             MevType::Liquidation => "liquidation",
+            MevType::LongTail => "long-tail",
+            MevType::Nft => "nft",
+            MevType::CrossDomain => "cross-domain",
             MevType::Unknown => "header",
         }
     }
@@ -154,6 +166,9 @@ impl From<String> for MevType {
             "JitSandwich" => MevType::JitSandwich,
             "AtomicArb" => MevType::AtomicArb,
             "SearcherTx" => MevType::SearcherTx,
+            "LongTail" => MevType::LongTail,
+            "Nft" => MevType::Nft,
+            "CrossDomain" => MevType::CrossDomain,
             _ => MevType::Unknown,
         }
     }
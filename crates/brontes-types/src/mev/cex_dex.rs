@@ -83,10 +83,38 @@ pub struct CexDex {
     pub per_exchange_details: Vec<Vec<ArbDetails>>,
     #[redefined(field((CexExchange, same)))]
     pub per_exchange_pnl: Vec<(CexExchange, (Rational, Rational))>,
+    /// The pnl of each exchange route under each reported fee tier
+    pub per_exchange_tier_pnl: Vec<FeeTierPnl>,
+    /// The pnl of hedging the swaps on the perp of each exchange instead
+    pub perp_hedge_pnl: Vec<PerpHedgePnl>,
     #[redefined(same_fields)]
     pub gas_details: GasDetails,
 }
 
+/// The maker & taker pnl of the route on an exchange with the fees of a tier
+/// of the cex fee schedule
+#[derive(Debug, Deserialize, PartialEq, Clone, Default, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct FeeTierPnl {
+    pub tier:      String,
+    #[redefined(same_fields)]
+    pub exchange:  CexExchange,
+    pub pnl_maker: Rational,
+    pub pnl_taker: Rational,
+}
+
+/// The maker & taker pnl of hedging the swaps on the perps of an exchange at
+/// the mark price, net of the funding paid over the holding horizon
+#[derive(Debug, Deserialize, PartialEq, Clone, Default, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct PerpHedgePnl {
+    #[redefined(same_fields)]
+    pub exchange:     CexExchange,
+    pub funding_cost: Rational,
+    pub pnl_maker:    Rational,
+    pub pnl_taker:    Rational,
+}
+
 impl Mev for CexDex {
     fn mev_type(&self) -> MevType {
         MevType::CexDexTrades
@@ -142,7 +170,7 @@ impl Serialize for CexDex {
     where
         S: Serializer,
     {
-        let mut ser_struct = serializer.serialize_struct("CexDex", 68)?;
+        let mut ser_struct = serializer.serialize_struct("CexDex", 76)?;
 
         ser_struct.serialize_field("tx_hash", &format!("{:?}", self.tx_hash))?;
         ser_struct.serialize_field("block_timestamp", &self.block_timestamp)?;
@@ -608,6 +636,72 @@ impl Serialize for CexDex {
         ser_struct.serialize_field("per_exchange_pnl.pnl_maker", &pnl_maker)?;
         ser_struct.serialize_field("per_exchange_pnl.pnl_taker", &pnl_taker)?;
 
+        ser_struct.serialize_field(
+            "per_exchange_tier_pnl.tier",
+            &self
+                .per_exchange_tier_pnl
+                .iter()
+                .map(|p| p.tier.clone())
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "per_exchange_tier_pnl.cex_exchange",
+            &self
+                .per_exchange_tier_pnl
+                .iter()
+                .map(|p| p.exchange.to_string())
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "per_exchange_tier_pnl.pnl_maker",
+            &self
+                .per_exchange_tier_pnl
+                .iter()
+                .map(|p| rational_to_u256_fraction(&p.pnl_maker).unwrap_or_default())
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "per_exchange_tier_pnl.pnl_taker",
+            &self
+                .per_exchange_tier_pnl
+                .iter()
+                .map(|p| rational_to_u256_fraction(&p.pnl_taker).unwrap_or_default())
+                .collect::<Vec<_>>(),
+        )?;
+
+        ser_struct.serialize_field(
+            "perp_hedge_pnl.cex_exchange",
+            &self
+                .perp_hedge_pnl
+                .iter()
+                .map(|p| p.exchange.to_string())
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "perp_hedge_pnl.funding_cost",
+            &self
+                .perp_hedge_pnl
+                .iter()
+                .map(|p| rational_to_u256_fraction(&p.funding_cost).unwrap_or_default())
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "perp_hedge_pnl.pnl_maker",
+            &self
+                .perp_hedge_pnl
+                .iter()
+                .map(|p| rational_to_u256_fraction(&p.pnl_maker).unwrap_or_default())
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "perp_hedge_pnl.pnl_taker",
+            &self
+                .perp_hedge_pnl
+                .iter()
+                .map(|p| rational_to_u256_fraction(&p.pnl_taker).unwrap_or_default())
+                .collect::<Vec<_>>(),
+        )?;
+
         let gas_details = (
             self.gas_details.coinbase_transfer,
             self.gas_details.priority_fee,
@@ -689,6 +783,14 @@ impl DbRow for CexDex {
         "per_exchange_pnl.cex_exchange",
         "per_exchange_pnl.pnl_maker",
         "per_exchange_pnl.pnl_taker",
+        "per_exchange_tier_pnl.tier",
+        "per_exchange_tier_pnl.cex_exchange",
+        "per_exchange_tier_pnl.pnl_maker",
+        "per_exchange_tier_pnl.pnl_taker",
+        "perp_hedge_pnl.cex_exchange",
+        "perp_hedge_pnl.funding_cost",
+        "perp_hedge_pnl.pnl_maker",
+        "perp_hedge_pnl.pnl_taker",
         "gas_details",
     ];
 }
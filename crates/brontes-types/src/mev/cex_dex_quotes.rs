@@ -35,10 +35,19 @@ pub struct CexDexQuote {
     #[redefined(same_fields)]
     pub exchange:          CexExchange,
     pub pnl:               f64,
+    /// The pnl under each reported tier of the cex fee schedule
+    pub tier_pnl:          Vec<QuoteFeeTierPnl>,
     #[redefined(same_fields)]
     pub gas_details:       GasDetails,
 }
 
+#[derive(Debug, Deserialize, PartialEq, Clone, Default, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct QuoteFeeTierPnl {
+    pub tier: String,
+    pub pnl:  f64,
+}
+
 impl Mev for CexDexQuote {
     fn mev_type(&self) -> MevType {
         MevType::CexDexQuotes
@@ -70,7 +79,7 @@ impl Serialize for CexDexQuote {
     where
         S: Serializer,
     {
-        let mut ser_struct = serializer.serialize_struct("CexDexQuote", 19)?;
+        let mut ser_struct = serializer.serialize_struct("CexDexQuote", 21)?;
         ser_struct.serialize_field("tx_hash", &format!("{:?}", self.tx_hash))?;
         ser_struct.serialize_field("block_timestamp", &self.block_timestamp)?;
         ser_struct.serialize_field("block_number", &self.block_number)?;
@@ -88,6 +97,18 @@ impl Serialize for CexDexQuote {
         ser_struct.serialize_field("swaps.amount_in", &swaps.amount_in)?;
         ser_struct.serialize_field("swaps.amount_out", &swaps.amount_out)?;
         ser_struct.serialize_field("pnl", &self.pnl)?;
+        ser_struct.serialize_field(
+            "tier_pnl.tier",
+            &self
+                .tier_pnl
+                .iter()
+                .map(|p| p.tier.clone())
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "tier_pnl.pnl",
+            &self.tier_pnl.iter().map(|p| p.pnl).collect::<Vec<_>>(),
+        )?;
         ser_struct.serialize_field("instant_mid_price", &self.instant_mid_price)?;
         ser_struct.serialize_field("t2_mid_price", &self.t2_mid_price)?;
         ser_struct.serialize_field("t12_mid_price", &self.t12_mid_price)?;
@@ -122,6 +143,8 @@ impl DbRow for CexDexQuote {
         "swaps.amount_in",
         "swaps.amount_out",
         "pnl",
+        "tier_pnl.tier",
+        "tier_pnl.pnl",
         "instant_mid_price",
         "t2_mid_price",
         "t12_mid_price",
@@ -0,0 +1,199 @@
+use ::clickhouse::DbRow;
+use ::serde::ser::{SerializeStruct, Serializer};
+use ahash::HashSet;
+use redefined::Redefined;
+use reth_primitives::B256;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use super::{Mev, MevType};
+use crate::{
+    db::{
+        l2_swaps::{L2Swap, L2SwapRedefined},
+        redefined_types::primitives::B256Redefined,
+    },
+    normalized_actions::{
+        ClickhouseVecNormalizedBridgeDeposit, ClickhouseVecNormalizedBridgeFill,
+        ClickhouseVecNormalizedSwap, NormalizedBridgeDeposit, NormalizedBridgeDepositRedefined,
+        NormalizedBridgeFill, NormalizedBridgeFillRedefined, NormalizedSwap,
+        NormalizedSwapRedefined,
+    },
+    GasDetails, Protocol,
+};
+
+/// A mainnet swap paired with an opposite swap of the same searcher on an L2,
+/// with the bridge deposits and fills of the mainnet transaction that move
+/// the inventory between the chains.
+#[serde_as]
+#[derive(Debug, Deserialize, PartialEq, Clone, Default, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct CrossDomainArb {
+    pub tx_hash:       B256,
+    pub block_number:  u64,
+    pub mainnet_swaps: Vec<NormalizedSwap>,
+    pub deposits:      Vec<NormalizedBridgeDeposit>,
+    pub fills:         Vec<NormalizedBridgeFill>,
+    pub l2_swaps:      Vec<L2Swap>,
+    #[redefined(same_fields)]
+    pub gas_details:   GasDetails,
+}
+
+impl Mev for CrossDomainArb {
+    fn total_gas_paid(&self) -> u128 {
+        self.gas_details.gas_paid()
+    }
+
+    fn total_priority_fee_paid(&self, base_fee: u128) -> u128 {
+        self.gas_details.priority_fee(base_fee) * self.gas_details.gas_used
+    }
+
+    fn bribe(&self) -> u128 {
+        self.gas_details.coinbase_transfer.unwrap_or(0)
+    }
+
+    fn mev_transaction_hashes(&self) -> Vec<B256> {
+        vec![self.tx_hash]
+    }
+
+    fn mev_type(&self) -> MevType {
+        MevType::CrossDomain
+    }
+
+    fn protocols(&self) -> HashSet<Protocol> {
+        self.mainnet_swaps
+            .iter()
+            .map(|swap| swap.protocol)
+            .chain(self.deposits.iter().map(|deposit| deposit.protocol))
+            .chain(self.fills.iter().map(|fill| fill.protocol))
+            .collect()
+    }
+}
+
+impl Serialize for CrossDomainArb {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser_struct = serializer.serialize_struct("CrossDomainArb", 38)?;
+        ser_struct.serialize_field("tx_hash", &format!("{:?}", self.tx_hash))?;
+        ser_struct.serialize_field("block_number", &self.block_number)?;
+
+        let swaps: ClickhouseVecNormalizedSwap = self
+            .mainnet_swaps
+            .clone()
+            .try_into()
+            .map_err(serde::ser::Error::custom)?;
+        ser_struct.serialize_field("mainnet_swaps.trace_idx", &swaps.trace_index)?;
+        ser_struct.serialize_field("mainnet_swaps.from", &swaps.from)?;
+        ser_struct.serialize_field("mainnet_swaps.recipient", &swaps.recipient)?;
+        ser_struct.serialize_field("mainnet_swaps.pool", &swaps.pool)?;
+        ser_struct.serialize_field("mainnet_swaps.token_in", &swaps.token_in)?;
+        ser_struct.serialize_field("mainnet_swaps.token_out", &swaps.token_out)?;
+        ser_struct.serialize_field("mainnet_swaps.amount_in", &swaps.amount_in)?;
+        ser_struct.serialize_field("mainnet_swaps.amount_out", &swaps.amount_out)?;
+
+        let deposits: ClickhouseVecNormalizedBridgeDeposit = self
+            .deposits
+            .clone()
+            .try_into()
+            .map_err(serde::ser::Error::custom)?;
+        ser_struct.serialize_field("deposits.trace_idx", &deposits.trace_index)?;
+        ser_struct.serialize_field("deposits.protocol", &deposits.protocol)?;
+        ser_struct.serialize_field("deposits.bridge", &deposits.bridge)?;
+        ser_struct.serialize_field("deposits.depositor", &deposits.depositor)?;
+        ser_struct.serialize_field("deposits.recipient", &deposits.recipient)?;
+        ser_struct
+            .serialize_field("deposits.destination_chain_id", &deposits.destination_chain_id)?;
+        ser_struct.serialize_field("deposits.token", &deposits.token)?;
+        ser_struct.serialize_field("deposits.amount", &deposits.amount)?;
+
+        let fills: ClickhouseVecNormalizedBridgeFill = self
+            .fills
+            .clone()
+            .try_into()
+            .map_err(serde::ser::Error::custom)?;
+        ser_struct.serialize_field("fills.trace_idx", &fills.trace_index)?;
+        ser_struct.serialize_field("fills.protocol", &fills.protocol)?;
+        ser_struct.serialize_field("fills.bridge", &fills.bridge)?;
+        ser_struct.serialize_field("fills.relayer", &fills.relayer)?;
+        ser_struct.serialize_field("fills.recipient", &fills.recipient)?;
+        ser_struct.serialize_field("fills.origin_chain_id", &fills.origin_chain_id)?;
+        ser_struct.serialize_field("fills.token", &fills.token)?;
+        ser_struct.serialize_field("fills.amount", &fills.amount)?;
+
+        let l2_field = |f: fn(&L2Swap) -> String| self.l2_swaps.iter().map(f).collect::<Vec<_>>();
+        ser_struct.serialize_field(
+            "l2_swaps.chain_id",
+            &self.l2_swaps.iter().map(|s| s.chain_id).collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "l2_swaps.block_number",
+            &self.l2_swaps.iter().map(|s| s.block_number).collect::<Vec<_>>(),
+        )?;
+        ser_struct
+            .serialize_field("l2_swaps.tx_hash", &l2_field(|s| format!("{:?}", s.tx_hash)))?;
+        ser_struct.serialize_field("l2_swaps.pool", &l2_field(|s| format!("{:?}", s.pool)))?;
+        ser_struct
+            .serialize_field("l2_swaps.token_in", &l2_field(|s| s.token_in_symbol.clone()))?;
+        ser_struct
+            .serialize_field("l2_swaps.token_out", &l2_field(|s| s.token_out_symbol.clone()))?;
+        ser_struct.serialize_field(
+            "l2_swaps.amount_in",
+            &self.l2_swaps.iter().map(|s| s.amount_in).collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field(
+            "l2_swaps.amount_out",
+            &self.l2_swaps.iter().map(|s| s.amount_out).collect::<Vec<_>>(),
+        )?;
+
+        let gas_details = (
+            self.gas_details.coinbase_transfer,
+            self.gas_details.priority_fee,
+            self.gas_details.gas_used,
+            self.gas_details.effective_gas_price,
+        );
+        ser_struct.serialize_field("gas_details", &gas_details)?;
+        ser_struct.end()
+    }
+}
+
+impl DbRow for CrossDomainArb {
+    const COLUMN_NAMES: &'static [&'static str] = &[
+        "tx_hash",
+        "block_number",
+        "mainnet_swaps.trace_idx",
+        "mainnet_swaps.from",
+        "mainnet_swaps.recipient",
+        "mainnet_swaps.pool",
+        "mainnet_swaps.token_in",
+        "mainnet_swaps.token_out",
+        "mainnet_swaps.amount_in",
+        "mainnet_swaps.amount_out",
+        "deposits.trace_idx",
+        "deposits.protocol",
+        "deposits.bridge",
+        "deposits.depositor",
+        "deposits.recipient",
+        "deposits.destination_chain_id",
+        "deposits.token",
+        "deposits.amount",
+        "fills.trace_idx",
+        "fills.protocol",
+        "fills.bridge",
+        "fills.relayer",
+        "fills.recipient",
+        "fills.origin_chain_id",
+        "fills.token",
+        "fills.amount",
+        "l2_swaps.chain_id",
+        "l2_swaps.block_number",
+        "l2_swaps.tx_hash",
+        "l2_swaps.pool",
+        "l2_swaps.token_in",
+        "l2_swaps.token_out",
+        "l2_swaps.amount_in",
+        "l2_swaps.amount_out",
+        "gas_details",
+    ];
+}
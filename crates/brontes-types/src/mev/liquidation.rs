@@ -113,6 +113,10 @@ impl Serialize for Liquidation {
             "liquidations.liquidated_collateral",
             &liquidations.liquidated_collateral,
         )?;
+        ser_struct.serialize_field(
+            "liquidations.collateral_discount",
+            &liquidations.collateral_discount,
+        )?;
 
         let gas_details = (
             self.gas_details.coinbase_transfer,
@@ -147,6 +151,7 @@ impl DbRow for Liquidation {
         "liquidations.debt_asset",
         "liquidations.covered_debt",
         "liquidations.liquidated_collateral",
+        "liquidations.collateral_discount",
         "gas_details",
     ];
 }
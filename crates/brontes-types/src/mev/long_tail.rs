@@ -0,0 +1,251 @@
+use std::fmt::Debug;
+
+use ::clickhouse::DbRow;
+use ::serde::ser::{SerializeStruct, Serializer};
+use ahash::HashSet;
+use redefined::Redefined;
+use reth_primitives::{Address, B256};
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use super::{Mev, MevType};
+use crate::{
+    db::{
+        redefined_types::primitives::*,
+        token_info::{TokenInfoWithAddress, TokenInfoWithAddressRedefined},
+    },
+    normalized_actions::*,
+    ClickhouseVecGasDetails, GasDetails, Protocol,
+};
+
+/// A sniper position in a freshly created pool.
+///
+/// The entries are the transactions that bought the sniped token out of the
+/// pool within a few blocks of its creation. The bundle is emitted in the
+/// block the sniper sells the token again, so the exits are always part of the
+/// block the bundle is stored under while the entries can be in any of the
+/// preceding blocks of the inspectors window. Snipes that are still held when
+/// their entries leave the window are emitted without exits, valued by their
+/// unrealized pnl.
+#[serde_as]
+#[derive(Debug, Deserialize, PartialEq, Clone, Default, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct LongTail {
+    pub block_number:        u64,
+    /// The token that was bought out of the new pool
+    pub sniped_token:        TokenInfoWithAddress,
+    pub pool:                Address,
+    pub pool_creation_block: u64,
+    pub entry_tx_hashes:     Vec<B256>,
+    pub entry_block_numbers: Vec<u64>,
+    pub entry_swaps:         Vec<Vec<NormalizedSwap>>,
+    #[redefined(same_fields)]
+    pub entry_gas_details:   Vec<GasDetails>,
+    /// Amount of the sniped token bought by the entries
+    pub entry_amount:        f64,
+    /// Usd value paid for the sniped token
+    pub entry_size_usd:      f64,
+    pub exit_tx_hashes:      Vec<B256>,
+    pub exit_swaps:          Vec<Vec<NormalizedSwap>>,
+    #[redefined(same_fields)]
+    pub exit_gas_details:    Vec<GasDetails>,
+    /// Amount of the sniped token sold by the exits
+    pub exit_amount:         f64,
+    /// Usd value received for the sniped token
+    pub exit_size_usd:       f64,
+    /// Exit value minus the cost basis of the sold tokens and the gas paid
+    pub realized_pnl_usd:    f64,
+    /// Value of the tokens still held minus their remaining cost basis
+    pub unrealized_pnl_usd:  f64,
+}
+
+impl LongTail {
+    fn gas_details(&self) -> impl Iterator<Item = &GasDetails> {
+        self.entry_gas_details
+            .iter()
+            .chain(self.exit_gas_details.iter())
+    }
+}
+
+impl Mev for LongTail {
+    fn mev_type(&self) -> MevType {
+        MevType::LongTail
+    }
+
+    fn total_gas_paid(&self) -> u128 {
+        self.gas_details().map(|gd| gd.gas_paid()).sum()
+    }
+
+    fn total_priority_fee_paid(&self, base_fee: u128) -> u128 {
+        // entries of previous blocks were paid at a different base fee
+        self.exit_gas_details
+            .iter()
+            .map(|gd| gd.priority_fee_paid(base_fee))
+            .sum()
+    }
+
+    fn bribe(&self) -> u128 {
+        self.gas_details()
+            .filter_map(|gd| gd.coinbase_transfer)
+            .sum()
+    }
+
+    fn mev_transaction_hashes(&self) -> Vec<B256> {
+        let mut txs = self.entry_tx_hashes.clone();
+        txs.extend(self.exit_tx_hashes.iter().copied());
+        txs
+    }
+
+    fn protocols(&self) -> HashSet<Protocol> {
+        self.entry_swaps
+            .iter()
+            .chain(self.exit_swaps.iter())
+            .flatten()
+            .map(|swap| swap.protocol)
+            .collect()
+    }
+}
+
+impl Serialize for LongTail {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser_struct = serializer.serialize_struct("LongTail", 40)?;
+        ser_struct.serialize_field("block_number", &self.block_number)?;
+        ser_struct.serialize_field("sniped_token", &self.sniped_token.clickhouse_fmt())?;
+        ser_struct.serialize_field("pool", &format!("{:?}", self.pool))?;
+        ser_struct.serialize_field("pool_creation_block", &self.pool_creation_block)?;
+
+        // entries
+        let entry_tx_hashes = self
+            .entry_tx_hashes
+            .iter()
+            .map(|tx| format!("{:?}", tx))
+            .collect::<Vec<_>>();
+        ser_struct.serialize_field("entry_tx_hashes", &entry_tx_hashes)?;
+        ser_struct.serialize_field("entry_block_numbers", &self.entry_block_numbers)?;
+
+        let entry_swaps: ClickhouseDoubleVecNormalizedSwap =
+            (self.entry_tx_hashes.clone(), self.entry_swaps.clone())
+                .try_into()
+                .map_err(serde::ser::Error::custom)?;
+        ser_struct.serialize_field("entry_swaps.tx_hash", &entry_swaps.tx_hash)?;
+        ser_struct.serialize_field("entry_swaps.trace_idx", &entry_swaps.trace_index)?;
+        ser_struct.serialize_field("entry_swaps.from", &entry_swaps.from)?;
+        ser_struct.serialize_field("entry_swaps.recipient", &entry_swaps.recipient)?;
+        ser_struct.serialize_field("entry_swaps.pool", &entry_swaps.pool)?;
+        ser_struct.serialize_field("entry_swaps.token_in", &entry_swaps.token_in)?;
+        ser_struct.serialize_field("entry_swaps.token_out", &entry_swaps.token_out)?;
+        ser_struct.serialize_field("entry_swaps.amount_in", &entry_swaps.amount_in)?;
+        ser_struct.serialize_field("entry_swaps.amount_out", &entry_swaps.amount_out)?;
+
+        let entry_gas_details: ClickhouseVecGasDetails =
+            (self.entry_tx_hashes.clone(), self.entry_gas_details.clone()).into();
+        ser_struct.serialize_field("entry_gas_details.tx_hash", &entry_gas_details.tx_hash)?;
+        ser_struct.serialize_field(
+            "entry_gas_details.coinbase_transfer",
+            &entry_gas_details.coinbase_transfer,
+        )?;
+        ser_struct
+            .serialize_field("entry_gas_details.priority_fee", &entry_gas_details.priority_fee)?;
+        ser_struct.serialize_field("entry_gas_details.gas_used", &entry_gas_details.gas_used)?;
+        ser_struct.serialize_field(
+            "entry_gas_details.effective_gas_price",
+            &entry_gas_details.effective_gas_price,
+        )?;
+        ser_struct.serialize_field("entry_amount", &self.entry_amount)?;
+        ser_struct.serialize_field("entry_size_usd", &self.entry_size_usd)?;
+
+        // exits
+        let exit_tx_hashes = self
+            .exit_tx_hashes
+            .iter()
+            .map(|tx| format!("{:?}", tx))
+            .collect::<Vec<_>>();
+        ser_struct.serialize_field("exit_tx_hashes", &exit_tx_hashes)?;
+
+        let exit_swaps: ClickhouseDoubleVecNormalizedSwap =
+            (self.exit_tx_hashes.clone(), self.exit_swaps.clone())
+                .try_into()
+                .map_err(serde::ser::Error::custom)?;
+        ser_struct.serialize_field("exit_swaps.tx_hash", &exit_swaps.tx_hash)?;
+        ser_struct.serialize_field("exit_swaps.trace_idx", &exit_swaps.trace_index)?;
+        ser_struct.serialize_field("exit_swaps.from", &exit_swaps.from)?;
+        ser_struct.serialize_field("exit_swaps.recipient", &exit_swaps.recipient)?;
+        ser_struct.serialize_field("exit_swaps.pool", &exit_swaps.pool)?;
+        ser_struct.serialize_field("exit_swaps.token_in", &exit_swaps.token_in)?;
+        ser_struct.serialize_field("exit_swaps.token_out", &exit_swaps.token_out)?;
+        ser_struct.serialize_field("exit_swaps.amount_in", &exit_swaps.amount_in)?;
+        ser_struct.serialize_field("exit_swaps.amount_out", &exit_swaps.amount_out)?;
+
+        let exit_gas_details: ClickhouseVecGasDetails =
+            (self.exit_tx_hashes.clone(), self.exit_gas_details.clone()).into();
+        ser_struct.serialize_field("exit_gas_details.tx_hash", &exit_gas_details.tx_hash)?;
+        ser_struct.serialize_field(
+            "exit_gas_details.coinbase_transfer",
+            &exit_gas_details.coinbase_transfer,
+        )?;
+        ser_struct
+            .serialize_field("exit_gas_details.priority_fee", &exit_gas_details.priority_fee)?;
+        ser_struct.serialize_field("exit_gas_details.gas_used", &exit_gas_details.gas_used)?;
+        ser_struct.serialize_field(
+            "exit_gas_details.effective_gas_price",
+            &exit_gas_details.effective_gas_price,
+        )?;
+        ser_struct.serialize_field("exit_amount", &self.exit_amount)?;
+        ser_struct.serialize_field("exit_size_usd", &self.exit_size_usd)?;
+
+        ser_struct.serialize_field("realized_pnl_usd", &self.realized_pnl_usd)?;
+        ser_struct.serialize_field("unrealized_pnl_usd", &self.unrealized_pnl_usd)?;
+
+        ser_struct.end()
+    }
+}
+
+impl DbRow for LongTail {
+    const COLUMN_NAMES: &'static [&'static str] = &[
+        "block_number",
+        "sniped_token",
+        "pool",
+        "pool_creation_block",
+        "entry_tx_hashes",
+        "entry_block_numbers",
+        "entry_swaps.tx_hash",
+        "entry_swaps.trace_idx",
+        "entry_swaps.from",
+        "entry_swaps.recipient",
+        "entry_swaps.pool",
+        "entry_swaps.token_in",
+        "entry_swaps.token_out",
+        "entry_swaps.amount_in",
+        "entry_swaps.amount_out",
+        "entry_gas_details.tx_hash",
+        "entry_gas_details.coinbase_transfer",
+        "entry_gas_details.priority_fee",
+        "entry_gas_details.gas_used",
+        "entry_gas_details.effective_gas_price",
+        "entry_amount",
+        "entry_size_usd",
+        "exit_tx_hashes",
+        "exit_swaps.tx_hash",
+        "exit_swaps.trace_idx",
+        "exit_swaps.from",
+        "exit_swaps.recipient",
+        "exit_swaps.pool",
+        "exit_swaps.token_in",
+        "exit_swaps.token_out",
+        "exit_swaps.amount_in",
+        "exit_swaps.amount_out",
+        "exit_gas_details.tx_hash",
+        "exit_gas_details.coinbase_transfer",
+        "exit_gas_details.priority_fee",
+        "exit_gas_details.gas_used",
+        "exit_gas_details.effective_gas_price",
+        "exit_amount",
+        "exit_size_usd",
+        "realized_pnl_usd",
+        "unrealized_pnl_usd",
+    ];
+}
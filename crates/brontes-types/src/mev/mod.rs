@@ -16,6 +16,12 @@ pub mod block;
 pub use block::*;
 pub mod searcher_tx;
 pub use searcher_tx::*;
+pub mod long_tail;
+pub use long_tail::*;
+pub mod nft;
+pub use nft::*;
+pub mod cross_domain;
+pub use cross_domain::*;
 
 pub mod cex_dex_quotes;
 pub use cex_dex_quotes::*;
@@ -0,0 +1,198 @@
+use std::fmt::Debug;
+
+use ::clickhouse::DbRow;
+use ::serde::ser::{SerializeStruct, Serializer};
+use ahash::HashSet;
+use redefined::{self_convert_redefined, Redefined};
+use reth_primitives::B256;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use super::{Mev, MevType};
+use crate::{
+    db::redefined_types::primitives::*, normalized_actions::*, ClickhouseVecGasDetails, GasDetails,
+    Protocol,
+};
+
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    rSerialize,
+    rDeserialize,
+    Archive,
+    strum::Display,
+)]
+pub enum NftMevKind {
+    /// An nft bought and sold again within the same transaction
+    #[default]
+    Arbitrage,
+    /// An nft bought under its market price and sold again in a later
+    /// transaction of the same block
+    Snipe,
+}
+
+self_convert_redefined!(NftMevKind);
+
+/// Nft arbitrage and snipes. Only trades paid in eth or weth are considered, so
+/// the profit is denominated in eth.
+///
+/// For an arbitrage there is a single transaction holding both the buys and
+/// the sells. A snipe has the buy transaction first and the sell transaction
+/// second.
+#[serde_as]
+#[derive(Debug, Deserialize, PartialEq, Clone, Default, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct NftMev {
+    pub block_number: u64,
+    #[redefined(same_fields)]
+    pub kind:         NftMevKind,
+    pub tx_hashes:    Vec<B256>,
+    pub buys:         Vec<NormalizedNftTrade>,
+    pub sells:        Vec<NormalizedNftTrade>,
+    #[redefined(same_fields)]
+    pub gas_details:  Vec<GasDetails>,
+    /// Proceeds of the sells minus the price of the buys and the gas paid
+    pub profit_eth:   f64,
+}
+
+impl Mev for NftMev {
+    fn mev_type(&self) -> MevType {
+        MevType::Nft
+    }
+
+    fn total_gas_paid(&self) -> u128 {
+        self.gas_details.iter().map(|gd| gd.gas_paid()).sum()
+    }
+
+    fn total_priority_fee_paid(&self, base_fee: u128) -> u128 {
+        self.gas_details
+            .iter()
+            .map(|gd| gd.priority_fee_paid(base_fee))
+            .sum()
+    }
+
+    fn bribe(&self) -> u128 {
+        self.gas_details
+            .iter()
+            .filter_map(|gd| gd.coinbase_transfer)
+            .sum()
+    }
+
+    fn mev_transaction_hashes(&self) -> Vec<B256> {
+        self.tx_hashes.clone()
+    }
+
+    fn protocols(&self) -> HashSet<Protocol> {
+        self.buys
+            .iter()
+            .chain(self.sells.iter())
+            .map(|trade| trade.protocol)
+            .collect()
+    }
+}
+
+impl Serialize for NftMev {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser_struct = serializer.serialize_struct("NftMev", 29)?;
+        ser_struct.serialize_field("block_number", &self.block_number)?;
+        ser_struct.serialize_field("kind", &self.kind.to_string())?;
+
+        let tx_hashes = self
+            .tx_hashes
+            .iter()
+            .map(|tx| format!("{:?}", tx))
+            .collect::<Vec<_>>();
+        ser_struct.serialize_field("tx_hashes", &tx_hashes)?;
+
+        let buys: ClickhouseVecNormalizedNftTrade = self
+            .buys
+            .clone()
+            .try_into()
+            .map_err(serde::ser::Error::custom)?;
+        ser_struct.serialize_field("buys.trace_idx", &buys.trace_index)?;
+        ser_struct.serialize_field("buys.marketplace", &buys.marketplace)?;
+        ser_struct.serialize_field("buys.buyer", &buys.buyer)?;
+        ser_struct.serialize_field("buys.seller", &buys.seller)?;
+        ser_struct.serialize_field("buys.collection", &buys.collection)?;
+        ser_struct.serialize_field("buys.token_id", &buys.token_id)?;
+        ser_struct.serialize_field("buys.amount", &buys.amount)?;
+        ser_struct.serialize_field("buys.payment_token", &buys.payment_token)?;
+        ser_struct.serialize_field("buys.price", &buys.price)?;
+        ser_struct.serialize_field("buys.fees", &buys.fees)?;
+
+        let sells: ClickhouseVecNormalizedNftTrade = self
+            .sells
+            .clone()
+            .try_into()
+            .map_err(serde::ser::Error::custom)?;
+        ser_struct.serialize_field("sells.trace_idx", &sells.trace_index)?;
+        ser_struct.serialize_field("sells.marketplace", &sells.marketplace)?;
+        ser_struct.serialize_field("sells.buyer", &sells.buyer)?;
+        ser_struct.serialize_field("sells.seller", &sells.seller)?;
+        ser_struct.serialize_field("sells.collection", &sells.collection)?;
+        ser_struct.serialize_field("sells.token_id", &sells.token_id)?;
+        ser_struct.serialize_field("sells.amount", &sells.amount)?;
+        ser_struct.serialize_field("sells.payment_token", &sells.payment_token)?;
+        ser_struct.serialize_field("sells.price", &sells.price)?;
+        ser_struct.serialize_field("sells.fees", &sells.fees)?;
+
+        let gas_details: ClickhouseVecGasDetails =
+            (self.tx_hashes.clone(), self.gas_details.clone()).into();
+        ser_struct.serialize_field("gas_details.tx_hash", &gas_details.tx_hash)?;
+        ser_struct
+            .serialize_field("gas_details.coinbase_transfer", &gas_details.coinbase_transfer)?;
+        ser_struct.serialize_field("gas_details.priority_fee", &gas_details.priority_fee)?;
+        ser_struct.serialize_field("gas_details.gas_used", &gas_details.gas_used)?;
+        ser_struct
+            .serialize_field("gas_details.effective_gas_price", &gas_details.effective_gas_price)?;
+
+        ser_struct.serialize_field("profit_eth", &self.profit_eth)?;
+
+        ser_struct.end()
+    }
+}
+
+impl DbRow for NftMev {
+    const COLUMN_NAMES: &'static [&'static str] = &[
+        "block_number",
+        "kind",
+        "tx_hashes",
+        "buys.trace_idx",
+        "buys.marketplace",
+        "buys.buyer",
+        "buys.seller",
+        "buys.collection",
+        "buys.token_id",
+        "buys.amount",
+        "buys.payment_token",
+        "buys.price",
+        "buys.fees",
+        "sells.trace_idx",
+        "sells.marketplace",
+        "sells.buyer",
+        "sells.seller",
+        "sells.collection",
+        "sells.token_id",
+        "sells.amount",
+        "sells.payment_token",
+        "sells.price",
+        "sells.fees",
+        "gas_details.tx_hash",
+        "gas_details.coinbase_transfer",
+        "gas_details.priority_fee",
+        "gas_details.gas_used",
+        "gas_details.effective_gas_price",
+        "profit_eth",
+    ];
+}
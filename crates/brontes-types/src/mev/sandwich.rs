@@ -70,6 +70,9 @@ pub struct Sandwich {
     /// Gas details for each frontrunning transaction.
     #[redefined(same_fields)]
     pub frontrun_gas_details:     Vec<GasDetails>,
+    /// Block number of each frontrunning transaction. Differs from
+    /// `block_number` when the sandwich spans multiple blocks.
+    pub frontrun_block_numbers:   Vec<u64>,
     /// Transaction hashes of the victim transactions, logically grouped by
     /// their corresponding frontrunning transaction. Each outer vector
     /// index corresponds to a frontrun transaction, grouping victims targeted
@@ -88,6 +91,18 @@ pub struct Sandwich {
     /// Gas details for each backrunning transaction.
     #[redefined(same_fields)]
     pub backrun_gas_details:      GasDetails,
+    /// Block number of the backrunning transaction.
+    pub backrun_block_number:     u64,
+}
+
+impl Sandwich {
+    /// True if the frontruns and the backrun didn't all land in the same
+    /// block.
+    pub fn is_multi_block(&self) -> bool {
+        self.frontrun_block_numbers
+            .iter()
+            .any(|block| *block != self.backrun_block_number)
+    }
 }
 
 /// calcuation for the loss per user
@@ -166,7 +181,7 @@ impl Serialize for Sandwich {
     where
         S: Serializer,
     {
-        let mut ser_struct = serializer.serialize_struct("Sandwich", 35)?;
+        let mut ser_struct = serializer.serialize_struct("Sandwich", 47)?;
         ser_struct.serialize_field("block_number", &self.block_number)?;
 
         // frontrun
@@ -207,6 +222,7 @@ impl Serialize for Sandwich {
             "frontrun_gas_details.effective_gas_price",
             &frontrun_gas_details.effective_gas_price,
         )?;
+        ser_struct.serialize_field("frontrun_block_numbers", &self.frontrun_block_numbers)?;
 
         // victims
         let victim_swaps: ClickhouseDoubleVecNormalizedSwap =
@@ -281,6 +297,7 @@ impl Serialize for Sandwich {
             "backrun_gas_details.effective_gas_price",
             &vec![self.backrun_gas_details.effective_gas_price],
         )?;
+        ser_struct.serialize_field("backrun_block_number", &self.backrun_block_number)?;
 
         ser_struct.end()
     }
@@ -304,6 +321,7 @@ impl DbRow for Sandwich {
         "frontrun_gas_details.priority_fee",
         "frontrun_gas_details.gas_used",
         "frontrun_gas_details.effective_gas_price",
+        "frontrun_block_numbers",
         "victim_swaps.tx_hash",
         "victim_swaps.trace_idx",
         "victim_swaps.from",
@@ -333,5 +351,6 @@ impl DbRow for Sandwich {
         "backrun_gas_details.priority_fee",
         "backrun_gas_details.gas_used",
         "backrun_gas_details.effective_gas_price",
+        "backrun_block_number",
     ];
 }
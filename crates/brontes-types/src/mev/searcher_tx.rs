@@ -25,6 +25,10 @@ pub struct SearcherTx {
     pub tx_hash:      B256,
     pub block_number: u64,
     pub transfers:    Vec<NormalizedTransfer>,
+    pub loans:        Vec<NormalizedLoan>,
+    pub repayments:   Vec<NormalizedRepayment>,
+    pub supplies:     Vec<NormalizedSupply>,
+    pub withdrawals:  Vec<NormalizedWithdraw>,
     #[redefined(same_fields)]
     pub gas_details:  GasDetails,
 }
@@ -60,7 +64,7 @@ impl Serialize for SearcherTx {
     where
         S: Serializer,
     {
-        let mut ser_struct = serializer.serialize_struct("SearcherTx", 9)?;
+        let mut ser_struct = serializer.serialize_struct("SearcherTx", 32)?;
 
         ser_struct.serialize_field("tx_hash", &format!("{:?}", self.tx_hash))?;
         ser_struct.serialize_field("block_number", &self.block_number)?;
@@ -77,6 +81,53 @@ impl Serialize for SearcherTx {
         ser_struct.serialize_field("transfers.amount", &victim_transfer.amount)?;
         ser_struct.serialize_field("transfers.fee", &victim_transfer.fee)?;
 
+        let loans: ClickhouseVecNormalizedLoan = self
+            .loans
+            .clone()
+            .try_into()
+            .map_err(serde::ser::Error::custom)?;
+        ser_struct.serialize_field("loans.trace_idx", &loans.trace_index)?;
+        ser_struct.serialize_field("loans.lender", &loans.lender)?;
+        ser_struct.serialize_field("loans.borrower", &loans.borrower)?;
+        ser_struct.serialize_field("loans.loaned_token", &loans.loaned_token)?;
+        ser_struct.serialize_field("loans.loan_amount", &loans.loan_amount)?;
+
+        let repayments: ClickhouseVecNormalizedRepayment = self
+            .repayments
+            .clone()
+            .try_into()
+            .map_err(serde::ser::Error::custom)?;
+        ser_struct.serialize_field("repayments.trace_idx", &repayments.trace_index)?;
+        ser_struct.serialize_field("repayments.lender", &repayments.lender)?;
+        ser_struct.serialize_field("repayments.borrower", &repayments.borrower)?;
+        ser_struct.serialize_field("repayments.payer", &repayments.payer)?;
+        ser_struct.serialize_field("repayments.repayed_token", &repayments.repayed_token)?;
+        ser_struct.serialize_field("repayments.repayment_amount", &repayments.repayment_amount)?;
+
+        let supplies: ClickhouseVecNormalizedSupply = self
+            .supplies
+            .clone()
+            .try_into()
+            .map_err(serde::ser::Error::custom)?;
+        ser_struct.serialize_field("supplies.trace_idx", &supplies.trace_index)?;
+        ser_struct.serialize_field("supplies.pool", &supplies.pool)?;
+        ser_struct.serialize_field("supplies.supplier", &supplies.supplier)?;
+        ser_struct.serialize_field("supplies.on_behalf_of", &supplies.on_behalf_of)?;
+        ser_struct.serialize_field("supplies.supplied_token", &supplies.supplied_token)?;
+        ser_struct.serialize_field("supplies.supply_amount", &supplies.supply_amount)?;
+
+        let withdrawals: ClickhouseVecNormalizedWithdraw = self
+            .withdrawals
+            .clone()
+            .try_into()
+            .map_err(serde::ser::Error::custom)?;
+        ser_struct.serialize_field("withdrawals.trace_idx", &withdrawals.trace_index)?;
+        ser_struct.serialize_field("withdrawals.pool", &withdrawals.pool)?;
+        ser_struct.serialize_field("withdrawals.supplier", &withdrawals.supplier)?;
+        ser_struct.serialize_field("withdrawals.recipient", &withdrawals.recipient)?;
+        ser_struct.serialize_field("withdrawals.withdrawn_token", &withdrawals.withdrawn_token)?;
+        ser_struct.serialize_field("withdrawals.withdraw_amount", &withdrawals.withdraw_amount)?;
+
         let gas_details = (
             self.gas_details.coinbase_transfer,
             self.gas_details.priority_fee,
@@ -100,6 +151,29 @@ impl DbRow for SearcherTx {
         "transfers.token",
         "transfers.amount",
         "transfers.fee",
+        "loans.trace_idx",
+        "loans.lender",
+        "loans.borrower",
+        "loans.loaned_token",
+        "loans.loan_amount",
+        "repayments.trace_idx",
+        "repayments.lender",
+        "repayments.borrower",
+        "repayments.payer",
+        "repayments.repayed_token",
+        "repayments.repayment_amount",
+        "supplies.trace_idx",
+        "supplies.pool",
+        "supplies.supplier",
+        "supplies.on_behalf_of",
+        "supplies.supplied_token",
+        "supplies.supply_amount",
+        "withdrawals.trace_idx",
+        "withdrawals.pool",
+        "withdrawals.supplier",
+        "withdrawals.recipient",
+        "withdrawals.withdrawn_token",
+        "withdrawals.withdraw_amount",
         "gas_details",
     ];
 }
@@ -0,0 +1,181 @@
+use std::fmt::{self, Debug};
+
+use alloy_primitives::U256;
+use clickhouse::Row;
+use colored::Colorize;
+use malachite::Rational;
+use redefined::Redefined;
+use reth_primitives::Address;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+
+use super::accounting::{AddressDeltas, TokenAccounting};
+use crate::{
+    db::{
+        redefined_types::{malachite::RationalRedefined, primitives::*},
+        token_info::{TokenInfoWithAddress, TokenInfoWithAddressRedefined},
+    },
+    rational_to_u256_fraction, Protocol,
+};
+
+/// Tokens sent from mainnet to another chain, either through a canonical
+/// bridge or as an intent that a relayer fills on the destination chain.
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct NormalizedBridgeDeposit {
+    #[redefined(same_fields)]
+    pub protocol:             Protocol,
+    pub trace_index:          u64,
+    pub bridge:               Address,
+    pub depositor:            Address,
+    /// The account credited on the destination chain
+    pub recipient:            Address,
+    pub destination_chain_id: u64,
+    pub token:                TokenInfoWithAddress,
+    pub amount:               Rational,
+    pub msg_value:            U256,
+}
+
+/// Tokens arriving on mainnet from another chain, either a relayer filling an
+/// intent or a canonical bridge finalizing a withdrawal.
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct NormalizedBridgeFill {
+    #[redefined(same_fields)]
+    pub protocol:        Protocol,
+    pub trace_index:     u64,
+    pub bridge:          Address,
+    /// The account paying out the tokens on mainnet, the relayer for intents
+    /// and the bridge itself for withdrawals
+    pub relayer:         Address,
+    pub recipient:       Address,
+    pub origin_chain_id: u64,
+    pub token:           TokenInfoWithAddress,
+    pub amount:          Rational,
+    pub msg_value:       U256,
+}
+
+/// The token legs of the bridge actions are classified as their own transfers
+/// in the child call frames, accounting for them here would double count them
+impl TokenAccounting for NormalizedBridgeDeposit {
+    fn apply_token_deltas(&self, _delta_map: &mut AddressDeltas) {}
+}
+
+impl TokenAccounting for NormalizedBridgeFill {
+    fn apply_token_deltas(&self, _delta_map: &mut AddressDeltas) {}
+}
+
+impl fmt::Display for NormalizedBridgeDeposit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Protocol {} - Bridge deposit of {} {} from {} to {} on chain {}",
+            self.protocol.to_string().bold(),
+            format!("{:.4}", self.amount).red(),
+            self.token.inner.symbol.bold(),
+            format!("{}", self.depositor).cyan(),
+            format!("{}", self.recipient).cyan(),
+            self.destination_chain_id.to_string().bold(),
+        )
+    }
+}
+
+impl fmt::Display for NormalizedBridgeFill {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Protocol {} - Bridge fill of {} {} from chain {} to {}",
+            self.protocol.to_string().bold(),
+            format!("{:.4}", self.amount).green(),
+            self.token.inner.symbol.bold(),
+            self.origin_chain_id.to_string().bold(),
+            format!("{}", self.recipient).cyan(),
+        )
+    }
+}
+
+pub struct ClickhouseVecNormalizedBridgeDeposit {
+    pub trace_index:          Vec<u64>,
+    pub protocol:             Vec<String>,
+    pub bridge:               Vec<String>,
+    pub depositor:            Vec<String>,
+    pub recipient:            Vec<String>,
+    pub destination_chain_id: Vec<u64>,
+    pub token:                Vec<(String, String)>,
+    pub amount:               Vec<([u8; 32], [u8; 32])>,
+}
+
+impl TryFrom<Vec<NormalizedBridgeDeposit>> for ClickhouseVecNormalizedBridgeDeposit {
+    type Error = eyre::Report;
+
+    fn try_from(value: Vec<NormalizedBridgeDeposit>) -> eyre::Result<Self> {
+        Ok(ClickhouseVecNormalizedBridgeDeposit {
+            trace_index:          value.iter().map(|val| val.trace_index).collect(),
+            protocol:             value.iter().map(|val| val.protocol.to_string()).collect(),
+            bridge:               value
+                .iter()
+                .map(|val| format!("{:?}", val.bridge))
+                .collect(),
+            depositor:            value
+                .iter()
+                .map(|val| format!("{:?}", val.depositor))
+                .collect(),
+            recipient:            value
+                .iter()
+                .map(|val| format!("{:?}", val.recipient))
+                .collect(),
+            destination_chain_id: value.iter().map(|val| val.destination_chain_id).collect(),
+            token:                value
+                .iter()
+                .map(|val| val.token.clickhouse_fmt())
+                .collect(),
+            amount:               value
+                .iter()
+                .map(|val| rational_to_u256_fraction(&val.amount))
+                .collect::<eyre::Result<Vec<_>>>()?,
+        })
+    }
+}
+
+pub struct ClickhouseVecNormalizedBridgeFill {
+    pub trace_index:     Vec<u64>,
+    pub protocol:        Vec<String>,
+    pub bridge:          Vec<String>,
+    pub relayer:         Vec<String>,
+    pub recipient:       Vec<String>,
+    pub origin_chain_id: Vec<u64>,
+    pub token:           Vec<(String, String)>,
+    pub amount:          Vec<([u8; 32], [u8; 32])>,
+}
+
+impl TryFrom<Vec<NormalizedBridgeFill>> for ClickhouseVecNormalizedBridgeFill {
+    type Error = eyre::Report;
+
+    fn try_from(value: Vec<NormalizedBridgeFill>) -> eyre::Result<Self> {
+        Ok(ClickhouseVecNormalizedBridgeFill {
+            trace_index:     value.iter().map(|val| val.trace_index).collect(),
+            protocol:        value.iter().map(|val| val.protocol.to_string()).collect(),
+            bridge:          value
+                .iter()
+                .map(|val| format!("{:?}", val.bridge))
+                .collect(),
+            relayer:         value
+                .iter()
+                .map(|val| format!("{:?}", val.relayer))
+                .collect(),
+            recipient:       value
+                .iter()
+                .map(|val| format!("{:?}", val.recipient))
+                .collect(),
+            origin_chain_id: value.iter().map(|val| val.origin_chain_id).collect(),
+            token:           value
+                .iter()
+                .map(|val| val.token.clickhouse_fmt())
+                .collect(),
+            amount:          value
+                .iter()
+                .map(|val| rational_to_u256_fraction(&val.amount))
+                .collect::<eyre::Result<Vec<_>>>()?,
+        })
+    }
+}
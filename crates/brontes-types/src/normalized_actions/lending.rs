@@ -1,33 +1,301 @@
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
 
 use alloy_primitives::U256;
 use clickhouse::Row;
+use colored::Colorize;
 use malachite::Rational;
+use redefined::Redefined;
 use reth_primitives::Address;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
 use serde::{Deserialize, Serialize};
 
-use crate::{db::token_info::TokenInfoWithAddress, FastHashMap, Protocol};
+use super::accounting::{AddressDeltas, TokenAccounting};
+use crate::{
+    db::{
+        redefined_types::{malachite::RationalRedefined, primitives::*},
+        token_info::{TokenInfoWithAddress, TokenInfoWithAddressRedefined},
+    },
+    rational_to_u256_fraction, Protocol,
+};
 
-#[derive(Debug, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+/// A borrow from a lending pool. The lender is the pool the debt is owed to.
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
 pub struct NormalizedLoan {
+    #[redefined(same_fields)]
     pub protocol:     Protocol,
     pub trace_index:  u64,
     pub lender:       Address,
+    /// The account the debt is accounted to, this isn't necessarily the
+    /// caller when borrowing on behalf of another account
     pub borrower:     Address,
     pub loaned_token: TokenInfoWithAddress,
     pub loan_amount:  Rational,
-    pub collateral:   FastHashMap<TokenInfoWithAddress, Rational>,
     pub msg_value:    U256,
 }
 
-#[derive(Debug, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+/// A repayment of debt to a lending pool.
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
 pub struct NormalizedRepayment {
+    #[redefined(same_fields)]
     pub protocol:         Protocol,
     pub trace_index:      u64,
     pub lender:           Address,
+    /// The account whose debt is repaid
     pub borrower:         Address,
+    /// The account paying the debt back
+    pub payer:            Address,
     pub repayed_token:    TokenInfoWithAddress,
     pub repayment_amount: Rational,
-    pub collateral:       FastHashMap<TokenInfoWithAddress, Rational>,
     pub msg_value:        U256,
 }
+
+/// A deposit into a lending pool, either to earn interest or to be used as
+/// collateral.
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct NormalizedSupply {
+    #[redefined(same_fields)]
+    pub protocol:       Protocol,
+    pub trace_index:    u64,
+    pub pool:           Address,
+    pub supplier:       Address,
+    /// The account that is credited with the deposit
+    pub on_behalf_of:   Address,
+    pub supplied_token: TokenInfoWithAddress,
+    pub supply_amount:  Rational,
+    pub msg_value:      U256,
+}
+
+/// A withdrawal of a previous deposit from a lending pool.
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct NormalizedWithdraw {
+    #[redefined(same_fields)]
+    pub protocol:        Protocol,
+    pub trace_index:     u64,
+    pub pool:            Address,
+    /// The account the deposit is withdrawn from
+    pub supplier:        Address,
+    pub recipient:       Address,
+    pub withdrawn_token: TokenInfoWithAddress,
+    pub withdraw_amount: Rational,
+    pub msg_value:       U256,
+}
+
+/// The token legs of the lending actions are classified as their own transfers
+/// in the child call frames, accounting for them here would double count them
+impl TokenAccounting for NormalizedLoan {
+    fn apply_token_deltas(&self, _delta_map: &mut AddressDeltas) {}
+}
+
+impl TokenAccounting for NormalizedRepayment {
+    fn apply_token_deltas(&self, _delta_map: &mut AddressDeltas) {}
+}
+
+impl TokenAccounting for NormalizedSupply {
+    fn apply_token_deltas(&self, _delta_map: &mut AddressDeltas) {}
+}
+
+impl TokenAccounting for NormalizedWithdraw {
+    fn apply_token_deltas(&self, _delta_map: &mut AddressDeltas) {}
+}
+
+impl fmt::Display for NormalizedLoan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Protocol {} - Loan of {} {} from {} to {}",
+            self.protocol.to_string().bold(),
+            format!("{:.4}", self.loan_amount).green(),
+            self.loaned_token.inner.symbol.bold(),
+            format!("{}", self.lender).cyan(),
+            format!("{}", self.borrower).cyan(),
+        )
+    }
+}
+
+impl fmt::Display for NormalizedRepayment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Protocol {} - Repayment of {} {} to {} for {}",
+            self.protocol.to_string().bold(),
+            format!("{:.4}", self.repayment_amount).red(),
+            self.repayed_token.inner.symbol.bold(),
+            format!("{}", self.lender).cyan(),
+            format!("{}", self.borrower).cyan(),
+        )
+    }
+}
+
+impl fmt::Display for NormalizedSupply {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Protocol {} - Supply of {} {} to {} for {}",
+            self.protocol.to_string().bold(),
+            format!("{:.4}", self.supply_amount).red(),
+            self.supplied_token.inner.symbol.bold(),
+            format!("{}", self.pool).cyan(),
+            format!("{}", self.on_behalf_of).cyan(),
+        )
+    }
+}
+
+impl fmt::Display for NormalizedWithdraw {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Protocol {} - Withdrawal of {} {} from {} to {}",
+            self.protocol.to_string().bold(),
+            format!("{:.4}", self.withdraw_amount).green(),
+            self.withdrawn_token.inner.symbol.bold(),
+            format!("{}", self.pool).cyan(),
+            format!("{}", self.recipient).cyan(),
+        )
+    }
+}
+
+pub struct ClickhouseVecNormalizedLoan {
+    pub trace_index:  Vec<u64>,
+    pub lender:       Vec<String>,
+    pub borrower:     Vec<String>,
+    pub loaned_token: Vec<(String, String)>,
+    pub loan_amount:  Vec<([u8; 32], [u8; 32])>,
+}
+
+impl TryFrom<Vec<NormalizedLoan>> for ClickhouseVecNormalizedLoan {
+    type Error = eyre::Report;
+
+    fn try_from(value: Vec<NormalizedLoan>) -> eyre::Result<Self> {
+        Ok(ClickhouseVecNormalizedLoan {
+            trace_index:  value.iter().map(|val| val.trace_index).collect(),
+            lender:       value
+                .iter()
+                .map(|val| format!("{:?}", val.lender))
+                .collect(),
+            borrower:     value
+                .iter()
+                .map(|val| format!("{:?}", val.borrower))
+                .collect(),
+            loaned_token: value
+                .iter()
+                .map(|val| val.loaned_token.clickhouse_fmt())
+                .collect(),
+            loan_amount:  value
+                .iter()
+                .map(|val| rational_to_u256_fraction(&val.loan_amount))
+                .collect::<eyre::Result<Vec<_>>>()?,
+        })
+    }
+}
+
+pub struct ClickhouseVecNormalizedRepayment {
+    pub trace_index:      Vec<u64>,
+    pub lender:           Vec<String>,
+    pub borrower:         Vec<String>,
+    pub payer:            Vec<String>,
+    pub repayed_token:    Vec<(String, String)>,
+    pub repayment_amount: Vec<([u8; 32], [u8; 32])>,
+}
+
+impl TryFrom<Vec<NormalizedRepayment>> for ClickhouseVecNormalizedRepayment {
+    type Error = eyre::Report;
+
+    fn try_from(value: Vec<NormalizedRepayment>) -> eyre::Result<Self> {
+        Ok(ClickhouseVecNormalizedRepayment {
+            trace_index:      value.iter().map(|val| val.trace_index).collect(),
+            lender:           value
+                .iter()
+                .map(|val| format!("{:?}", val.lender))
+                .collect(),
+            borrower:         value
+                .iter()
+                .map(|val| format!("{:?}", val.borrower))
+                .collect(),
+            payer:            value.iter().map(|val| format!("{:?}", val.payer)).collect(),
+            repayed_token:    value
+                .iter()
+                .map(|val| val.repayed_token.clickhouse_fmt())
+                .collect(),
+            repayment_amount: value
+                .iter()
+                .map(|val| rational_to_u256_fraction(&val.repayment_amount))
+                .collect::<eyre::Result<Vec<_>>>()?,
+        })
+    }
+}
+
+pub struct ClickhouseVecNormalizedSupply {
+    pub trace_index:    Vec<u64>,
+    pub pool:           Vec<String>,
+    pub supplier:       Vec<String>,
+    pub on_behalf_of:   Vec<String>,
+    pub supplied_token: Vec<(String, String)>,
+    pub supply_amount:  Vec<([u8; 32], [u8; 32])>,
+}
+
+impl TryFrom<Vec<NormalizedSupply>> for ClickhouseVecNormalizedSupply {
+    type Error = eyre::Report;
+
+    fn try_from(value: Vec<NormalizedSupply>) -> eyre::Result<Self> {
+        Ok(ClickhouseVecNormalizedSupply {
+            trace_index:    value.iter().map(|val| val.trace_index).collect(),
+            pool:           value.iter().map(|val| format!("{:?}", val.pool)).collect(),
+            supplier:       value
+                .iter()
+                .map(|val| format!("{:?}", val.supplier))
+                .collect(),
+            on_behalf_of:   value
+                .iter()
+                .map(|val| format!("{:?}", val.on_behalf_of))
+                .collect(),
+            supplied_token: value
+                .iter()
+                .map(|val| val.supplied_token.clickhouse_fmt())
+                .collect(),
+            supply_amount:  value
+                .iter()
+                .map(|val| rational_to_u256_fraction(&val.supply_amount))
+                .collect::<eyre::Result<Vec<_>>>()?,
+        })
+    }
+}
+
+pub struct ClickhouseVecNormalizedWithdraw {
+    pub trace_index:     Vec<u64>,
+    pub pool:            Vec<String>,
+    pub supplier:        Vec<String>,
+    pub recipient:       Vec<String>,
+    pub withdrawn_token: Vec<(String, String)>,
+    pub withdraw_amount: Vec<([u8; 32], [u8; 32])>,
+}
+
+impl TryFrom<Vec<NormalizedWithdraw>> for ClickhouseVecNormalizedWithdraw {
+    type Error = eyre::Report;
+
+    fn try_from(value: Vec<NormalizedWithdraw>) -> eyre::Result<Self> {
+        Ok(ClickhouseVecNormalizedWithdraw {
+            trace_index:     value.iter().map(|val| val.trace_index).collect(),
+            pool:            value.iter().map(|val| format!("{:?}", val.pool)).collect(),
+            supplier:        value
+                .iter()
+                .map(|val| format!("{:?}", val.supplier))
+                .collect(),
+            recipient:       value
+                .iter()
+                .map(|val| format!("{:?}", val.recipient))
+                .collect(),
+            withdrawn_token: value
+                .iter()
+                .map(|val| val.withdrawn_token.clickhouse_fmt())
+                .collect(),
+            withdraw_amount: value
+                .iter()
+                .map(|val| rational_to_u256_fraction(&val.withdraw_amount))
+                .collect::<eyre::Result<Vec<_>>>()?,
+        })
+    }
+}
@@ -3,7 +3,10 @@ use std::fmt::{self, Debug};
 use alloy_primitives::U256;
 use clickhouse::Row;
 use colored::Colorize;
-use malachite::Rational;
+use malachite::{
+    num::basic::traits::{One, Zero},
+    Rational,
+};
 use redefined::Redefined;
 use reth_primitives::Address;
 use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
@@ -16,7 +19,7 @@ use crate::{
         redefined_types::{malachite::RationalRedefined, primitives::*},
         token_info::{TokenInfoWithAddress, TokenInfoWithAddressRedefined},
     },
-    rational_to_u256_fraction, Protocol,
+    rational_to_u256_fraction, Protocol, ToFloatNearest,
 };
 
 #[derive(Default, Debug, Serialize, Clone, Row, PartialEq, Eq, Deserialize, Redefined)]
@@ -32,6 +35,9 @@ pub struct NormalizedLiquidation {
     pub debt_asset:            TokenInfoWithAddress,
     pub covered_debt:          Rational,
     pub liquidated_collateral: Rational,
+    /// The discount the collateral was bought at, see
+    /// [`NormalizedLiquidation::discount`]
+    pub collateral_discount:   Option<Rational>,
     pub msg_value:             U256,
 }
 
@@ -92,6 +98,18 @@ impl fmt::Display for NormalizedLiquidation {
 }
 
 impl NormalizedLiquidation {
+    /// The discount the collateral was bought at relative to the given price
+    /// of the collateral denominated in the debt asset, e.g `0.05` when the
+    /// liquidator paid 95% of the price.
+    pub fn discount(&self, collateral_price: &Rational) -> Option<Rational> {
+        if self.liquidated_collateral == Rational::ZERO || *collateral_price == Rational::ZERO {
+            return None
+        }
+        let paid_price = &self.covered_debt / &self.liquidated_collateral;
+
+        Some(Rational::ONE - paid_price / collateral_price)
+    }
+
     pub fn pretty_print(&self, f: &mut fmt::Formatter<'_>, spaces: usize) -> fmt::Result {
         let field_names = [
             "Protocol",
@@ -154,6 +172,7 @@ pub struct ClickhouseVecNormalizedLiquidation {
     pub debt_asset:            Vec<(String, String)>,
     pub covered_debt:          Vec<([u8; 32], [u8; 32])>,
     pub liquidated_collateral: Vec<([u8; 32], [u8; 32])>,
+    pub collateral_discount:   Vec<Option<f64>>,
 }
 
 impl TryFrom<Vec<NormalizedLiquidation>> for ClickhouseVecNormalizedLiquidation {
@@ -188,6 +207,35 @@ impl TryFrom<Vec<NormalizedLiquidation>> for ClickhouseVecNormalizedLiquidation
                 .iter()
                 .map(|val| rational_to_u256_fraction(&val.liquidated_collateral))
                 .collect::<eyre::Result<Vec<_>>>()?,
+            collateral_discount:   value
+                .iter()
+                .map(|val| {
+                    val.collateral_discount
+                        .as_ref()
+                        .map(|d| d.clone().to_float())
+                })
+                .collect(),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discount() {
+        let liquidation = NormalizedLiquidation {
+            covered_debt: Rational::from(1_900),
+            liquidated_collateral: Rational::ONE,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            liquidation.discount(&Rational::from(2_000)),
+            Some(Rational::from_signeds(1, 20))
+        );
+        assert_eq!(liquidation.discount(&Rational::ZERO), None);
+        assert_eq!(NormalizedLiquidation::default().discount(&Rational::from(2_000)), None);
+    }
+}
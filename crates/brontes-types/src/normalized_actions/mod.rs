@@ -1,6 +1,7 @@
 pub mod accounting;
 pub mod aggregator;
 pub mod batch;
+pub mod bridge;
 pub mod comparison;
 pub mod eth_transfer;
 pub mod flashloan;
@@ -8,6 +9,7 @@ pub mod lending;
 pub mod liquidation;
 pub mod liquidity;
 pub mod multi_callframe;
+pub mod nft;
 pub mod pool;
 pub mod self_destruct;
 pub mod swaps;
@@ -19,6 +21,7 @@ use accounting::{AddressDeltas, TokenAccounting};
 pub use aggregator::*;
 use alloy_primitives::{Address, Bytes, Log};
 pub use batch::*;
+pub use bridge::*;
 use clickhouse::InsertRow;
 pub use eth_transfer::*;
 pub use flashloan::*;
@@ -26,6 +29,7 @@ pub use lending::*;
 pub use liquidation::*;
 pub use liquidity::*;
 pub use multi_callframe::*;
+pub use nft::*;
 pub use pool::*;
 use reth_rpc_types::trace::parity::Action as TraceAction;
 pub use self_destruct::*;
@@ -97,6 +101,14 @@ impl NormalizedAction for Action {
             Self::NewPool(p) => p.trace_index,
             Self::PoolConfigUpdate(p) => p.trace_index,
             Self::Aggregator(a) => a.trace_index,
+            Self::NftTrade(n) => n.trace_index,
+            Self::NftTransfer(n) => n.trace_index,
+            Self::Loan(l) => l.trace_index,
+            Self::Repayment(r) => r.trace_index,
+            Self::Supply(s) => s.trace_index,
+            Self::Withdraw(w) => w.trace_index,
+            Self::BridgeDeposit(b) => b.trace_index,
+            Self::BridgeFill(b) => b.trace_index,
             Self::Revert => unreachable!("no trace index for revert"),
         }
     }
@@ -119,6 +131,14 @@ pub enum Action {
     NewPool(NormalizedNewPool),
     PoolConfigUpdate(NormalizedPoolConfigUpdate),
     Aggregator(NormalizedAggregator),
+    NftTrade(NormalizedNftTrade),
+    NftTransfer(NormalizedNftTransfer),
+    Loan(NormalizedLoan),
+    Repayment(NormalizedRepayment),
+    Supply(NormalizedSupply),
+    Withdraw(NormalizedWithdraw),
+    BridgeDeposit(NormalizedBridgeDeposit),
+    BridgeFill(NormalizedBridgeFill),
     Unclassified(TransactionTraceWithLogs),
     Revert,
 }
@@ -141,6 +161,14 @@ impl InsertRow for Action {
             Action::PoolConfigUpdate(_) => todo!(),
             Action::Unclassified(..) | Action::Revert => panic!(),
             Action::Aggregator(_) => NormalizedAggregator::COLUMN_NAMES,
+            Action::NftTrade(_) => NormalizedNftTrade::COLUMN_NAMES,
+            Action::NftTransfer(_) => NormalizedNftTransfer::COLUMN_NAMES,
+            Action::Loan(_) => NormalizedLoan::COLUMN_NAMES,
+            Action::Repayment(_) => NormalizedRepayment::COLUMN_NAMES,
+            Action::Supply(_) => NormalizedSupply::COLUMN_NAMES,
+            Action::Withdraw(_) => NormalizedWithdraw::COLUMN_NAMES,
+            Action::BridgeDeposit(_) => NormalizedBridgeDeposit::COLUMN_NAMES,
+            Action::BridgeFill(_) => NormalizedBridgeFill::COLUMN_NAMES,
         }
     }
 }
@@ -163,6 +191,14 @@ impl serde::Serialize for Action {
             Action::Liquidation(c) => c.serialize(serializer),
             Action::SelfDestruct(sd) => sd.serialize(serializer),
             Action::EthTransfer(et) => et.serialize(serializer),
+            Action::NftTrade(n) => n.serialize(serializer),
+            Action::NftTransfer(n) => n.serialize(serializer),
+            Action::Loan(l) => l.serialize(serializer),
+            Action::Repayment(r) => r.serialize(serializer),
+            Action::Supply(s) => s.serialize(serializer),
+            Action::Withdraw(w) => w.serialize(serializer),
+            Action::BridgeDeposit(b) => b.serialize(serializer),
+            Action::BridgeFill(b) => b.serialize(serializer),
             Action::Unclassified(trace) => (trace).serialize(serializer),
             action => format!("{:?}", action).serialize(serializer),
             //action => unreachable!("no action serialization for {action:?}"),
@@ -218,9 +254,42 @@ impl Action {
                     from: a.from,
                     ..Default::default()
                 }),
+                Self::NftTrade(n) => (!n.msg_value.is_zero()).then(|| NormalizedEthTransfer {
+                    value: n.msg_value,
+                    to: n.marketplace,
+                    from: n.buyer,
+                    ..Default::default()
+                }),
+                Self::Repayment(r) => (!r.msg_value.is_zero()).then(|| NormalizedEthTransfer {
+                    value: r.msg_value,
+                    to: r.lender,
+                    from: r.payer,
+                    ..Default::default()
+                }),
+                Self::Supply(s) => (!s.msg_value.is_zero()).then(|| NormalizedEthTransfer {
+                    value: s.msg_value,
+                    to: s.pool,
+                    from: s.supplier,
+                    ..Default::default()
+                }),
+                Self::BridgeDeposit(b) => (!b.msg_value.is_zero()).then(|| NormalizedEthTransfer {
+                    value: b.msg_value,
+                    to: b.bridge,
+                    from: b.depositor,
+                    ..Default::default()
+                }),
+                Self::BridgeFill(b) => (!b.msg_value.is_zero()).then(|| NormalizedEthTransfer {
+                    value: b.msg_value,
+                    to: b.bridge,
+                    from: b.relayer,
+                    ..Default::default()
+                }),
                 Self::Mint(_) => None,
                 Self::Burn(_) => None,
                 Self::Transfer(_) => None,
+                Self::NftTransfer(_) => None,
+                Self::Loan(_) => None,
+                Self::Withdraw(_) => None,
                 Self::Collect(_) => None,
                 Self::SelfDestruct(_) => None,
                 Self::EthTransfer(_) => None,
@@ -258,6 +327,14 @@ impl Action {
             Self::NewPool(p) => p.trace_index,
             Self::PoolConfigUpdate(p) => p.trace_index,
             Self::Aggregator(a) => a.trace_index,
+            Self::NftTrade(n) => n.trace_index,
+            Self::NftTransfer(n) => n.trace_index,
+            Self::Loan(l) => l.trace_index,
+            Self::Repayment(r) => r.trace_index,
+            Self::Supply(s) => s.trace_index,
+            Self::Withdraw(w) => w.trace_index,
+            Self::BridgeDeposit(b) => b.trace_index,
+            Self::BridgeFill(b) => b.trace_index,
             Self::Revert => return None,
         })
     }
@@ -325,6 +402,14 @@ impl Action {
             Action::Transfer(t) => t.to,
             Action::Collect(c) => c.pool,
             Action::Liquidation(c) => c.pool,
+            Action::NftTrade(n) => n.marketplace,
+            Action::NftTransfer(n) => n.to,
+            Action::Loan(l) => l.borrower,
+            Action::Repayment(r) => r.lender,
+            Action::Supply(s) => s.pool,
+            Action::Withdraw(w) => w.recipient,
+            Action::BridgeDeposit(b) => b.bridge,
+            Action::BridgeFill(b) => b.recipient,
             Action::SelfDestruct(c) => c.get_refund_address(),
             Action::Unclassified(t) => match &t.trace.action {
                 reth_rpc_types::trace::parity::Action::Call(c) => c.to,
@@ -351,6 +436,14 @@ impl Action {
             Action::Transfer(t) => t.from,
             Action::Collect(c) => c.from,
             Action::Liquidation(c) => c.liquidator,
+            Action::NftTrade(n) => n.buyer,
+            Action::NftTransfer(n) => n.from,
+            Action::Loan(l) => l.lender,
+            Action::Repayment(r) => r.payer,
+            Action::Supply(s) => s.supplier,
+            Action::Withdraw(w) => w.pool,
+            Action::BridgeDeposit(b) => b.depositor,
+            Action::BridgeFill(b) => b.relayer,
             Action::SelfDestruct(c) => c.get_address(),
             Action::Unclassified(t) => match &t.trace.action {
                 reth_rpc_types::trace::parity::Action::Call(c) => c.to,
@@ -431,6 +524,46 @@ impl Action {
         matches!(self, Action::PoolConfigUpdate(_))
     }
 
+    pub const fn is_nft_trade(&self) -> bool {
+        matches!(self, Action::NftTrade(_))
+    }
+
+    pub const fn is_nft_transfer(&self) -> bool {
+        matches!(self, Action::NftTransfer(_))
+    }
+
+    pub const fn is_loan(&self) -> bool {
+        matches!(self, Action::Loan(_))
+    }
+
+    pub const fn is_repayment(&self) -> bool {
+        matches!(self, Action::Repayment(_))
+    }
+
+    pub const fn is_supply(&self) -> bool {
+        matches!(self, Action::Supply(_))
+    }
+
+    pub const fn is_withdraw(&self) -> bool {
+        matches!(self, Action::Withdraw(_))
+    }
+
+    pub const fn is_bridge_deposit(&self) -> bool {
+        matches!(self, Action::BridgeDeposit(_))
+    }
+
+    pub const fn is_bridge_fill(&self) -> bool {
+        matches!(self, Action::BridgeFill(_))
+    }
+
+    pub const fn is_bridge(&self) -> bool {
+        self.is_bridge_deposit() || self.is_bridge_fill()
+    }
+
+    pub const fn is_lending(&self) -> bool {
+        self.is_loan() || self.is_repayment() || self.is_supply() || self.is_withdraw()
+    }
+
     pub const fn is_unclassified(&self) -> bool {
         matches!(self, Action::Unclassified(_))
     }
@@ -448,6 +581,13 @@ impl Action {
             Action::NewPool(p) => p.protocol,
             Action::PoolConfigUpdate(p) => p.protocol,
             Action::Aggregator(a) => a.protocol,
+            Action::NftTrade(n) => n.protocol,
+            Action::Loan(l) => l.protocol,
+            Action::Repayment(r) => r.protocol,
+            Action::Supply(s) => s.protocol,
+            Action::Withdraw(w) => w.protocol,
+            Action::BridgeDeposit(b) => b.protocol,
+            Action::BridgeFill(b) => b.protocol,
             _ => Protocol::Unknown,
         }
     }
@@ -526,7 +666,15 @@ extra_impls!(
     (FlashLoan, NormalizedFlashLoan),
     (Aggregator, NormalizedAggregator),
     (Batch, NormalizedBatch),
-    (NewPool, NormalizedNewPool)
+    (NewPool, NormalizedNewPool),
+    (NftTrade, NormalizedNftTrade),
+    (NftTransfer, NormalizedNftTransfer),
+    (Loan, NormalizedLoan),
+    (Repayment, NormalizedRepayment),
+    (Supply, NormalizedSupply),
+    (Withdraw, NormalizedWithdraw),
+    (BridgeDeposit, NormalizedBridgeDeposit),
+    (BridgeFill, NormalizedBridgeFill)
 );
 
 /// Custom impl for itering over swaps and swap with fee
@@ -578,6 +726,14 @@ impl TokenAccounting for Action {
             Action::SwapWithFee(swap_with_fee) => swap_with_fee.swap.apply_token_deltas(delta_map),
             Action::Collect(collect) => collect.apply_token_deltas(delta_map),
             Action::EthTransfer(eth_transfer) => eth_transfer.apply_token_deltas(delta_map),
+            Action::NftTrade(nft_trade) => nft_trade.apply_token_deltas(delta_map),
+            Action::NftTransfer(nft_transfer) => nft_transfer.apply_token_deltas(delta_map),
+            Action::Loan(loan) => loan.apply_token_deltas(delta_map),
+            Action::Repayment(repayment) => repayment.apply_token_deltas(delta_map),
+            Action::Supply(supply) => supply.apply_token_deltas(delta_map),
+            Action::Withdraw(withdraw) => withdraw.apply_token_deltas(delta_map),
+            Action::BridgeDeposit(deposit) => deposit.apply_token_deltas(delta_map),
+            Action::BridgeFill(fill) => fill.apply_token_deltas(delta_map),
             Action::Unclassified(_) => (), /* Potentially no token deltas to apply, adjust as */
             // necessary
             Action::SelfDestruct(_self_destruct) => (),
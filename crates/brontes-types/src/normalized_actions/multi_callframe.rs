@@ -36,6 +36,7 @@ pub enum MultiFrameAction {
     Batch,
     Liquidation,
     Aggregator,
+    Lending,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -66,6 +67,13 @@ impl MultiFrameRequest {
                 call_type: MultiFrameAction::Aggregator,
                 trace_idx,
             }),
+            Action::Loan(_) | Action::Repayment(_) | Action::Supply(_) | Action::Withdraw(_) => {
+                Some(Self {
+                    protocol: action.get_protocol(),
+                    call_type: MultiFrameAction::Lending,
+                    trace_idx,
+                })
+            }
             _ => None,
         }
     }
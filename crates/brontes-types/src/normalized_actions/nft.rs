@@ -0,0 +1,213 @@
+use std::fmt::{self, Debug};
+
+use clickhouse::Row;
+use colored::Colorize;
+use malachite::Rational;
+use redefined::{self_convert_redefined, Redefined};
+use reth_primitives::{Address, U256};
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+
+use super::accounting::{AddressDeltas, TokenAccounting};
+use crate::{
+    db::{
+        redefined_types::{malachite::*, primitives::*},
+        token_info::{TokenInfoWithAddress, TokenInfoWithAddressRedefined},
+    },
+    rational_to_u256_fraction, Protocol, ToFloatNearest,
+};
+
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    rSerialize,
+    rDeserialize,
+    Archive,
+)]
+pub enum NftStandard {
+    #[default]
+    Erc721,
+    Erc1155,
+}
+
+self_convert_redefined!(NftStandard);
+
+/// A transfer of an ERC-721 or ERC-1155 token, decoded from the `Transfer`,
+/// `TransferSingle` or `TransferBatch` logs of the collection.
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedNftTransfer {
+    pub trace_index: u64,
+    pub collection:  Address,
+    pub standard:    NftStandard,
+    pub from:        Address,
+    pub to:          Address,
+    pub token_id:    U256,
+    /// Always one for ERC-721 tokens
+    pub amount:      U256,
+}
+
+impl NormalizedNftTransfer {
+    pub fn is_mint(&self) -> bool {
+        self.from == Address::ZERO
+    }
+}
+
+/// Nfts are not fungible so they are left out of the token deltas
+impl TokenAccounting for NormalizedNftTransfer {
+    fn apply_token_deltas(&self, _delta_map: &mut AddressDeltas) {}
+}
+
+impl fmt::Display for NormalizedNftTransfer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Nft Transfer {} #{} from {} to {}",
+            format!("{}", self.collection).cyan(),
+            self.token_id,
+            self.from,
+            self.to
+        )
+    }
+}
+
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    rSerialize,
+    rDeserialize,
+    Archive,
+)]
+pub enum NftTradeKind {
+    /// A listing or bid filled on a marketplace
+    #[default]
+    Sale,
+    /// A sale of an nft that is the collateral of a loan, the proceeds repay
+    /// the loan
+    LockedSale,
+    /// A lender seizing the collateral of a defaulted loan. The price is the
+    /// principal of the loan
+    Liquidation,
+}
+
+self_convert_redefined!(NftTradeKind);
+
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct NormalizedNftTrade {
+    #[redefined(same_fields)]
+    pub protocol:      Protocol,
+    pub trace_index:   u64,
+    #[redefined(same_fields)]
+    pub kind:          NftTradeKind,
+    pub marketplace:   Address,
+    pub buyer:         Address,
+    pub seller:        Address,
+    pub collection:    Address,
+    pub token_id:      U256,
+    pub amount:        U256,
+    pub payment_token: TokenInfoWithAddress,
+    /// Total amount paid by the buyer
+    pub price:         Rational,
+    /// Part of the price paid out as marketplace fees and royalties
+    pub fees:          Rational,
+    pub msg_value:     U256,
+}
+
+impl NormalizedNftTrade {
+    /// Amount the seller ends up with after fees
+    pub fn seller_proceeds(&self) -> Rational {
+        &self.price - &self.fees
+    }
+
+    pub fn is_same_item(&self, other: &Self) -> bool {
+        self.collection == other.collection && self.token_id == other.token_id
+    }
+}
+
+/// The payment legs of a trade are classified as their own transfers in the
+/// child call frames, accounting for them here would double count them
+impl TokenAccounting for NormalizedNftTrade {
+    fn apply_token_deltas(&self, _delta_map: &mut AddressDeltas) {}
+}
+
+impl fmt::Display for NormalizedNftTrade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Protocol {} - {:?} of {} #{} from {} to {} for {} {} (fees: {})",
+            self.protocol.to_string().bold(),
+            self.kind,
+            format!("{}", self.collection).cyan(),
+            self.token_id,
+            format!("{}", self.seller).cyan(),
+            format!("{}", self.buyer).cyan(),
+            format!("{:.4}", self.price.clone().to_float()).green(),
+            self.payment_token.inner.symbol.bold(),
+            format!("{:.4}", self.fees.clone().to_float()).red(),
+        )
+    }
+}
+
+pub struct ClickhouseVecNormalizedNftTrade {
+    pub trace_index:   Vec<u64>,
+    pub marketplace:   Vec<String>,
+    pub buyer:         Vec<String>,
+    pub seller:        Vec<String>,
+    pub collection:    Vec<String>,
+    pub token_id:      Vec<String>,
+    pub amount:        Vec<String>,
+    pub payment_token: Vec<(String, String)>,
+    pub price:         Vec<([u8; 32], [u8; 32])>,
+    pub fees:          Vec<([u8; 32], [u8; 32])>,
+}
+
+impl TryFrom<Vec<NormalizedNftTrade>> for ClickhouseVecNormalizedNftTrade {
+    type Error = eyre::Report;
+
+    fn try_from(value: Vec<NormalizedNftTrade>) -> eyre::Result<Self> {
+        Ok(ClickhouseVecNormalizedNftTrade {
+            trace_index:   value.iter().map(|val| val.trace_index).collect(),
+            marketplace:   value
+                .iter()
+                .map(|val| format!("{:?}", val.marketplace))
+                .collect(),
+            buyer:         value.iter().map(|val| format!("{:?}", val.buyer)).collect(),
+            seller:        value
+                .iter()
+                .map(|val| format!("{:?}", val.seller))
+                .collect(),
+            collection:    value
+                .iter()
+                .map(|val| format!("{:?}", val.collection))
+                .collect(),
+            token_id:      value.iter().map(|val| val.token_id.to_string()).collect(),
+            amount:        value.iter().map(|val| val.amount.to_string()).collect(),
+            payment_token: value
+                .iter()
+                .map(|val| val.payment_token.clickhouse_fmt())
+                .collect(),
+            price:         value
+                .iter()
+                .map(|val| rational_to_u256_fraction(&val.price))
+                .collect::<eyre::Result<Vec<_>>>()?,
+            fees:          value
+                .iter()
+                .map(|val| rational_to_u256_fraction(&val.fees))
+                .collect::<eyre::Result<Vec<_>>>()?,
+        })
+    }
+}
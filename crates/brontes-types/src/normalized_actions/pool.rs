@@ -1,7 +1,7 @@
 use alloy_primitives::Address;
 use serde::Deserialize;
 
-use crate::Protocol;
+use crate::{db::uniswap_v4_pool_key::UniswapV4PoolKey, Protocol};
 
 #[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
 pub struct NormalizedNewPool {
@@ -24,6 +24,7 @@ impl TryFrom<NormalizedNewPool> for NormalizedPoolConfigUpdate {
             trace_index:  value.trace_index,
             protocol:     value.protocol,
             tokens:       value.tokens,
+            pool_key:     None,
         })
     }
 }
@@ -34,4 +35,7 @@ pub struct NormalizedPoolConfigUpdate {
     pub protocol:     Protocol,
     pub pool_address: Address,
     pub tokens:       Vec<Address>,
+    /// Set for Uniswap V4 pools, whose address can't be turned back into the
+    /// key their state is loaded with
+    pub pool_key:     Option<UniswapV4PoolKey>,
 }
@@ -2,6 +2,7 @@ use std::{hash::Hash, str::FromStr};
 
 use alloy_primitives::Address;
 use alloy_rlp::{BufMut, Decodable, Encodable};
+use itertools::Itertools;
 use redefined::Redefined;
 use reth_db::table::{Decode, Encode};
 use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
@@ -93,6 +94,16 @@ impl Pair {
     pub fn is_usd_stable_pair(&self) -> bool {
         USD_STABLES_BY_ADDRESS.contains(&self.0) && USD_STABLES_BY_ADDRESS.contains(&self.1)
     }
+
+    /// Every pair that can be made out of two of the tokens, in the order
+    /// they are given
+    pub fn all_pairs(tokens: &[Address]) -> Vec<Self> {
+        tokens
+            .iter()
+            .tuple_combinations()
+            .map(|(token0, token1)| Pair(*token0, *token1))
+            .collect()
+    }
 }
 
 impl Encode for Pair {
@@ -101,6 +101,20 @@ utils!(
         ClipperExchange,
         PropellerLabsSolver,
         Dodo,
+        UniswapV4,
+        Seaport,
+        BlurMarketplace,
+        BlurBlend,
+        CompoundV3,
+        MorphoBlue,
+        Spark,
+        Liquity,
+        MakerDog,
+        MakerClipper,
+        AcrossSpokePool,
+        ArbitrumBridge,
+        OptimismBridge,
+        BaseBridge,
         #[default]
         Unknown,
     }
@@ -146,6 +160,20 @@ impl Protocol {
             Protocol::ClipperExchange => ("ClipperExchange", ""),
             Protocol::PropellerLabsSolver => ("Propeller Labs Solver", ""),
             Protocol::Dodo => ("Dodo", "V1/V2"),
+            Protocol::UniswapV4 => ("Uniswap", "V4"),
+            Protocol::Seaport => ("Seaport", ""),
+            Protocol::BlurMarketplace => ("Blur", "Marketplace"),
+            Protocol::BlurBlend => ("Blur", "Blend"),
+            Protocol::CompoundV3 => ("Compound", "V3"),
+            Protocol::MorphoBlue => ("Morpho", "Blue"),
+            Protocol::Spark => ("Spark", ""),
+            Protocol::Liquity => ("Liquity", "V1"),
+            Protocol::MakerDog => ("Maker", "Dog"),
+            Protocol::MakerClipper => ("Maker", "Clipper"),
+            Protocol::AcrossSpokePool => ("Across", "SpokePool"),
+            Protocol::ArbitrumBridge => ("Arbitrum", "Bridge"),
+            Protocol::OptimismBridge => ("Optimism", "Bridge"),
+            Protocol::BaseBridge => ("Base", "Bridge"),
             Protocol::Unknown => ("Unknown", "Unknown"),
         }
     }
@@ -155,6 +183,10 @@ impl Protocol {
             "uniswapv2" => Protocol::UniswapV2,
             "sushiswapv2" => Protocol::SushiSwapV2,
             "uniswapv3" => Protocol::UniswapV3,
+            "uniswapv4" => Protocol::UniswapV4,
+            "seaport" => Protocol::Seaport,
+            "blurmarketplace" => Protocol::BlurMarketplace,
+            "blurblend" => Protocol::BlurBlend,
             "sushiswapv3" => Protocol::SushiSwapV3,
             "curve.fibase2" => Protocol::CurveBasePool2,
             "curve.fibase3" => Protocol::CurveBasePool3,
@@ -173,6 +205,16 @@ impl Protocol {
             "dodov1/v2" => Protocol::Dodo,
             "pancakeswapv2" => Protocol::PancakeSwapV2,
             "pancakeswapv3" => Protocol::PancakeSwapV3,
+            "compoundv3" => Protocol::CompoundV3,
+            "morphoblue" => Protocol::MorphoBlue,
+            "spark" => Protocol::Spark,
+            "liquityv1" => Protocol::Liquity,
+            "makerdog" => Protocol::MakerDog,
+            "makerclipper" => Protocol::MakerClipper,
+            "acrossspokepool" => Protocol::AcrossSpokePool,
+            "arbitrumbridge" => Protocol::ArbitrumBridge,
+            "optimismbridge" => Protocol::OptimismBridge,
+            "basebridge" => Protocol::BaseBridge,
             _ => Protocol::Unknown,
         }
     }
@@ -230,6 +272,20 @@ impl fmt::Display for Protocol {
                 Protocol::ClipperExchange => "Clipper",
                 Protocol::PropellerLabsSolver => "Propeller Labs",
                 Protocol::Dodo => "Dodo",
+                Protocol::UniswapV4 => "Uni V4",
+                Protocol::Seaport => "Seaport",
+                Protocol::BlurMarketplace => "Blur",
+                Protocol::BlurBlend => "Blur Blend",
+                Protocol::CompoundV3 => "Compound V3",
+                Protocol::MorphoBlue => "Morpho Blue",
+                Protocol::Spark => "Spark",
+                Protocol::Liquity => "Liquity",
+                Protocol::MakerDog => "Maker Dog",
+                Protocol::MakerClipper => "Maker Clipper",
+                Protocol::AcrossSpokePool => "Across",
+                Protocol::ArbitrumBridge => "Arbitrum Bridge",
+                Protocol::OptimismBridge => "Optimism Bridge",
+                Protocol::BaseBridge => "Base Bridge",
                 Protocol::Unknown => "Unknown",
             }
         )
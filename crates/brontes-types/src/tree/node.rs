@@ -1,11 +1,13 @@
+use std::fmt;
+
 use itertools::Itertools;
 use reth_primitives::Address;
 use tracing::{error, warn};
 
 use super::{types::NodeWithDataRef, NodeData};
 use crate::{
-    normalized_actions::{MultiCallFrameClassification, NodeDataIndex, NormalizedAction},
-    TreeSearchArgs, TreeSearchBuilder,
+    normalized_actions::{Action, MultiCallFrameClassification, NodeDataIndex, NormalizedAction},
+    ToFloatNearest, TreeSearchArgs, TreeSearchBuilder,
 };
 
 #[derive(Debug, Clone)]
@@ -575,4 +577,64 @@ impl Node {
                 .for_each(|i| i.collect(results, call, wanted_data, data))
         }
     }
+
+    /// Pretty prints the call frame, its classified actions and all of its
+    /// children after 'spaces' spaces. Each level of the tree is indented by
+    /// four more spaces.
+    pub fn pretty_print_with_spaces<V: NormalizedAction>(
+        &self,
+        data: &NodeData<V>,
+        f: &mut fmt::Formatter,
+        spaces: usize,
+    ) -> fmt::Result {
+        let space_str = " ".repeat(spaces);
+        writeln!(f, "{}[{}] {}", space_str, self.trace_address.iter().join(", "), self.address)?;
+
+        for action in data
+            .get_ref(self.data)
+            .into_iter()
+            .flatten()
+            .map(|action| action.get_action())
+            .filter(|action| !action.is_unclassified())
+        {
+            writeln!(f, "{}  -> {}", space_str, format_action(action))?;
+        }
+
+        for child in &self.inner {
+            child.pretty_print_with_spaces(data, f, spaces + 4)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn format_action(action: &Action) -> String {
+    match action {
+        Action::Swap(swap) => swap.to_string(),
+        Action::SwapWithFee(swap) => swap.swap.to_string(),
+        Action::Mint(mint) => mint.to_string(),
+        Action::Burn(burn) => burn.to_string(),
+        Action::Collect(collect) => collect.to_string(),
+        Action::Liquidation(liquidation) => liquidation.to_string(),
+        Action::NftTrade(nft_trade) => nft_trade.to_string(),
+        Action::NftTransfer(nft_transfer) => nft_transfer.to_string(),
+        Action::Loan(loan) => loan.to_string(),
+        Action::Repayment(repayment) => repayment.to_string(),
+        Action::Supply(supply) => supply.to_string(),
+        Action::Withdraw(withdraw) => withdraw.to_string(),
+        Action::BridgeDeposit(deposit) => deposit.to_string(),
+        Action::BridgeFill(fill) => fill.to_string(),
+        Action::Transfer(transfer) => format!(
+            "Transfer {:.4} {} from {} to {}",
+            transfer.amount.clone().to_float(),
+            transfer.token.symbol,
+            transfer.from,
+            transfer.to
+        ),
+        Action::EthTransfer(transfer) => {
+            format!("Eth Transfer {} Wei from {} to {}", transfer.value, transfer.from, transfer.to)
+        }
+        Action::Revert => "Revert".to_string(),
+        action => format!("{:?}", action),
+    }
 }
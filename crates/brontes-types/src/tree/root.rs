@@ -285,6 +285,22 @@ impl<V: NormalizedAction> Root<V> {
         self.head.finalize();
     }
 
+    /// Pretty prints the transaction with its gas details and classified call
+    /// tree after 'spaces' spaces.
+    pub fn pretty_print_with_spaces(&self, f: &mut fmt::Formatter, spaces: usize) -> fmt::Result {
+        let space_str = " ".repeat(spaces);
+        writeln!(f, "{}{}: {:?}", space_str, "Transaction".bright_blue(), self.tx_hash)?;
+        writeln!(f, "{} - {}: {}", space_str, "Position".bright_blue(), self.position)?;
+        writeln!(f, "{} - {}: {}", space_str, "Private".bright_blue(), self.private)?;
+
+        writeln!(f, "{} - {}:", space_str, "Gas Details".bright_blue())?;
+        self.gas_details.pretty_print_with_spaces(f, spaces + 4)?;
+
+        writeln!(f, "{} - {}:", space_str, "Call Tree".bright_blue())?;
+        self.head
+            .pretty_print_with_spaces(&self.data_store, f, spaces + 4)
+    }
+
     pub fn is_private(&self) -> bool {
         self.private
     }
@@ -325,6 +341,12 @@ impl<V: NormalizedAction> Root<V> {
     }
 }
 
+impl<V: NormalizedAction> Display for Root<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.pretty_print_with_spaces(f, 0)
+    }
+}
+
 #[derive(
     Debug,
     Clone,